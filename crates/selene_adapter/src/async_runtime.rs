@@ -0,0 +1,72 @@
+#![forbid(unsafe_code)]
+
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    AdapterError, AdapterRuntime, OnboardingContinueAdapterRequest,
+    OnboardingContinueAdapterResponse, VoiceTurnAdapterRequest, VoiceTurnAdapterResponse,
+};
+
+/// A shared `AdapterRuntime` for async callers. Each blocking call takes the runtime lock on
+/// tokio's blocking pool, so a waiting turn never holds up a reactor thread. Clones share one
+/// runtime.
+#[derive(Clone)]
+pub struct AsyncAdapterRuntime {
+    runtime: Arc<Mutex<AdapterRuntime>>,
+}
+
+impl AsyncAdapterRuntime {
+    pub fn new(runtime: Arc<Mutex<AdapterRuntime>>) -> Self {
+        Self { runtime }
+    }
+
+    pub fn runtime(&self) -> Arc<Mutex<AdapterRuntime>> {
+        self.runtime.clone()
+    }
+
+    pub async fn run_voice_turn(
+        &self,
+        request: VoiceTurnAdapterRequest,
+    ) -> Result<VoiceTurnAdapterResponse, AdapterError> {
+        self.spawn_blocking(move |runtime| runtime.run_voice_turn(request))
+            .await
+    }
+
+    pub async fn run_onboarding_continue(
+        &self,
+        request: OnboardingContinueAdapterRequest,
+    ) -> Result<OnboardingContinueAdapterResponse, AdapterError> {
+        self.spawn_blocking(move |runtime| runtime.run_onboarding_continue(request))
+            .await
+    }
+
+    pub async fn run_device_artifact_sync_worker_pass(
+        &self,
+        now_ns: Option<u64>,
+    ) -> Result<(), AdapterError> {
+        self.spawn_blocking(move |runtime| runtime.run_device_artifact_sync_worker_pass(now_ns))
+            .await
+    }
+
+    // A panic in the call is resumed on the awaiting task, as the blocking call would panic.
+    async fn spawn_blocking<T, F>(&self, call: F) -> Result<T, AdapterError>
+    where
+        F: FnOnce(&AdapterRuntime) -> Result<T, AdapterError> + Send + 'static,
+        T: Send + 'static,
+    {
+        let runtime = Arc::clone(&self.runtime);
+        let blocking_call = move || {
+            let runtime = runtime
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("runtime".to_string()))?;
+            call(&runtime)
+        };
+        match tokio::task::spawn_blocking(blocking_call).await {
+            Ok(result) => result,
+            Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+            Err(err) => Err(AdapterError::Validation(format!(
+                "adapter blocking call did not run: {err}"
+            ))),
+        }
+    }
+}
@@ -248,6 +248,13 @@ fn run_desktop_voice_e2e(cli: &CliArgs) -> Result<E2eSummary, String> {
         selene_text_final: None,
         audio_capture_ref: Some(wake_capture),
         visual_input_ref: None,
+        provenance_mode: None,
+        user_text_part_seq: None,
+        model_override: None,
+        pre_fetched_tool_response: None,
+        language_hint: None,
+        language_hint_confidence: None,
+        request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
     };
 
     if cli.mode != E2eMode::QuietControl {
@@ -285,7 +292,7 @@ fn run_desktop_voice_e2e(cli: &CliArgs) -> Result<E2eSummary, String> {
             },
             GreetingSummary {
                 named: false,
-                response_text: err,
+                response_text: err.to_string(),
                 tts_text: String::new(),
             },
             SafetySummary {
@@ -157,6 +157,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         selene_text_final: None,
         audio_capture_ref: Some(capture_ref_before.clone()),
         visual_input_ref: None,
+        provenance_mode: None,
+        user_text_part_seq: None,
+        model_override: None,
+        pre_fetched_tool_response: None,
+        language_hint: None,
+        language_hint_confidence: None,
+        request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
     };
     apply_foreground_wake_capture_attestation(&mut request, run_seed);
 
@@ -1128,6 +1135,13 @@ mod tests {
                 timing_overruns: Some(0),
             }),
             visual_input_ref: None,
+            provenance_mode: None,
+            user_text_part_seq: None,
+            model_override: None,
+            pre_fetched_tool_response: None,
+            language_hint: None,
+            language_hint_confidence: None,
+            request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
         }
     }
 
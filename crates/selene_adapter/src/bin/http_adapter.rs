@@ -19,29 +19,30 @@ use axum::{
 use base64::{engine::general_purpose::STANDARD as BASE64_STANDARD, Engine as _};
 use selene_adapter::{
     app_ui_assets, build_runtime_execution_envelope_for_voice_turn_request, AdapterHealthResponse,
-    AdapterProcessProvenance, AdapterRuntime, AdapterSyncHealth, DesktopOpenAiTtsEvidenceInput,
-    DesktopOpenAiTtsEvidenceStatus, DesktopRejectedVoiceEvidenceInput,
-    InviteLinkOpenAdapterRequest, InviteLinkOpenAdapterResponse, OnboardingContinueAdapterRequest,
-    OnboardingContinueAdapterResponse, PublicBrainTraceReportResponse, SessionAttachAdapterRequest,
-    SessionAttachAdapterResponse, SessionIdleCloseCheckAdapterRequest,
-    SessionIdleCloseCheckAdapterResponse, SessionPostureEvidenceAdapterRequest,
-    SessionPostureEvidenceAdapterResponse, SessionRecentListAdapterRequest,
-    SessionRecentListAdapterResponse, SessionRecoverAdapterRequest, SessionRecoverAdapterResponse,
-    SessionResumeAdapterRequest, SessionResumeAdapterResponse, UiChatTranscriptResponse,
-    UiHealthChecksResponse, UiHealthDetailFilter, UiHealthDetailResponse,
-    UiHealthReportQueryRequest, UiHealthReportQueryResponse, UiHealthSummary,
-    UiHealthTimelinePaging, UiInternalHistoryEvidenceResponse, VoiceTurnAdapterRequest,
-    VoiceTurnAdapterResponse, VoiceTurnIngressError, WakeProfileAvailabilityRefreshAdapterRequest,
-    WakeProfileAvailabilityRefreshAdapterResponse,
+    AdapterProcessProvenance, AdapterProviderHealth, AdapterProviderLatencyHealth, AdapterRuntime,
+    AdapterSyncHealth, DesktopOpenAiTtsEvidenceInput, DesktopOpenAiTtsEvidenceStatus,
+    DesktopRejectedVoiceEvidenceInput, InviteLinkOpenAdapterRequest, InviteLinkOpenAdapterResponse,
+    OnboardingContinueAdapterRequest, OnboardingContinueAdapterResponse,
+    PublicBrainTraceReportResponse, SessionAttachAdapterRequest, SessionAttachAdapterResponse,
+    SessionIdleCloseCheckAdapterRequest, SessionIdleCloseCheckAdapterResponse,
+    SessionPostureEvidenceAdapterRequest, SessionPostureEvidenceAdapterResponse,
+    SessionRecentListAdapterRequest, SessionRecentListAdapterResponse,
+    SessionRecoverAdapterRequest, SessionRecoverAdapterResponse, SessionResumeAdapterRequest,
+    SessionResumeAdapterResponse, UiChatTranscriptResponse, UiHealthChecksResponse,
+    UiHealthDetailFilter, UiHealthDetailResponse, UiHealthReportQueryRequest,
+    UiHealthReportQueryResponse, UiHealthSummary, UiHealthTimelinePaging,
+    UiInternalHistoryEvidenceResponse, UiTranscriptFilter, VoiceTurnAdapterRequest,
+    VoiceTurnAdapterResponse, VoiceTurnIngressError, VoiceTurnNextMove,
+    WakeProfileAvailabilityRefreshAdapterRequest, WakeProfileAvailabilityRefreshAdapterResponse,
 };
 use selene_engines::device_vault;
 use selene_engines::ph1e::startup_outbound_self_check_logs;
+use selene_kernel_contracts::provider_secrets::ProviderSecretId;
+use selene_kernel_contracts::runtime_execution::{FailureClass, RuntimeExecutionEnvelope};
 use selene_os::prob_slice1::{
     run_slice1_text_conversation_from_env, slice1_error_response, Slice1Error, Slice1ErrorClass,
     Slice1TextConversationRequest, Slice1TextConversationResponse,
 };
-use selene_kernel_contracts::provider_secrets::ProviderSecretId;
-use selene_kernel_contracts::runtime_execution::{FailureClass, RuntimeExecutionEnvelope};
 use sha2::{Digest, Sha256};
 
 #[derive(Debug, Clone, serde::Deserialize, Default)]
@@ -312,7 +313,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 ticker.tick().await;
                 let pass_result = match runtime_for_worker.lock() {
                     Ok(runtime) => runtime.run_device_artifact_sync_worker_pass(None),
-                    Err(_) => Err("adapter runtime lock poisoned".to_string()),
+                    Err(_) => Err(selene_adapter::AdapterError::LockPoisoned(
+                        "runtime".to_string(),
+                    )),
                 };
                 if let Err(err) = pass_result {
                     eprintln!("selene_adapter_http sync worker pass failed: {err}");
@@ -327,6 +330,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/app.css", get(app_css))
         .route("/app.js", get(app_js))
         .route("/healthz", get(healthz))
+        .route("/metrics", get(metrics))
         .route("/v1/ui/health/checks", get(ui_health_checks))
         .route("/v1/ui/health/detail/:check_id", get(ui_health_detail))
         .route("/v1/ui/health/report/query", post(ui_health_report_query))
@@ -379,7 +383,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!(
         "selene_adapter_http listening on http://{addr} (sync_worker_enabled={sync_worker_enabled} interval_ms={sync_worker_interval_ms})"
     );
-    axum::serve(listener, app).await?;
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    if let Ok(runtime) = runtime.lock() {
+        if let Err(err) = runtime.shutdown() {
+            eprintln!("selene_adapter_http shutdown flush failed: {err}");
+        }
+    }
     Ok(())
 }
 
@@ -471,17 +484,41 @@ async fn healthz(
         }
         Err(reason) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(health_error_response(reason)),
+            Json(health_error_response(reason.to_string())),
         ),
     }
 }
 
+async fn metrics(State(state): State<HttpAdapterState>) -> Response {
+    let body = match state.runtime.lock() {
+        Ok(runtime) => runtime.metrics_prometheus(),
+        Err(_) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "adapter runtime lock poisoned\n",
+            )
+                .into_response();
+        }
+    };
+    (
+        StatusCode::OK,
+        [(
+            header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        body,
+    )
+        .into_response()
+}
+
 fn health_error_response(reason: String) -> AdapterHealthResponse {
     AdapterHealthResponse {
         status: "error".to_string(),
         outcome: "UNHEALTHY".to_string(),
         reason: Some(reason),
         sync: AdapterSyncHealth::default(),
+        provider_latency: AdapterProviderLatencyHealth::default(),
+        provider: AdapterProviderHealth::default(),
         provenance: Some(adapter_process_provenance()),
     }
 }
@@ -525,7 +562,7 @@ async fn ui_health_checks(
         Ok(response) => (StatusCode::OK, Json(response)),
         Err(reason) => (
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ui_health_checks_error_response(reason)),
+            Json(ui_health_checks_error_response(reason.to_string())),
         ),
     }
 }
@@ -571,7 +608,10 @@ async fn ui_health_detail(
         Ok(response) => (StatusCode::OK, Json(response)),
         Err(reason) => (
             StatusCode::BAD_REQUEST,
-            Json(ui_health_detail_error_response(check_id, reason)),
+            Json(ui_health_detail_error_response(
+                check_id,
+                reason.to_string(),
+            )),
         ),
     }
 }
@@ -603,6 +643,7 @@ fn ui_health_detail_error_response(check_id: String, reason: String) -> UiHealth
 
 async fn ui_chat_transcript(
     State(state): State<HttpAdapterState>,
+    Query(filter): Query<UiTranscriptFilter>,
 ) -> (StatusCode, Json<UiChatTranscriptResponse>) {
     let runtime = match state.runtime.lock() {
         Ok(runtime) => runtime,
@@ -614,14 +655,18 @@ async fn ui_chat_transcript(
                     generated_at_ns: 0,
                     note: Some("adapter runtime lock poisoned".to_string()),
                     messages: Vec::new(),
+                    next_cursor: None,
                 }),
             );
         }
     };
-    (
-        StatusCode::OK,
-        Json(runtime.ui_chat_transcript_report(None)),
-    )
+    let response = runtime.ui_chat_transcript_report(None, Some(&filter));
+    let status = if response.status == "error" {
+        StatusCode::BAD_REQUEST
+    } else {
+        StatusCode::OK
+    };
+    (status, Json(response))
 }
 
 async fn ui_internal_history_evidence(
@@ -800,6 +845,7 @@ async fn run_voice_turn(
                 session_id: None,
                 turn_id: Some(request.turn_id),
                 session_state: None,
+                cause: None,
             },
         );
     };
@@ -844,6 +890,7 @@ async fn run_voice_turn(
                     session_id: None,
                     turn_id: Some(request.turn_id),
                     session_state: None,
+                    cause: None,
                 },
             )
         }
@@ -882,6 +929,9 @@ async fn run_voice_turn(
                     session_id: None,
                     turn_id: Some(request.turn_id),
                     session_state: None,
+                    cause: Some(Box::new(selene_adapter::AdapterError::LockPoisoned(
+                        "runtime".to_string(),
+                    ))),
                 },
             )
         }
@@ -1301,7 +1351,7 @@ async fn record_desktop_rejected_voice_evidence(
         return desktop_rejected_voice_error_response(
             StatusCode::BAD_REQUEST,
             &request,
-            err.as_str(),
+            &err.to_string(),
         );
     }
 
@@ -1471,7 +1521,7 @@ async fn run_invite_click(
     };
     match runtime.run_invite_link_open_and_start_onboarding(request) {
         Ok(response) => (StatusCode::OK, Json(response)).into_response(),
-        Err(reason) => invite_click_error_response(StatusCode::BAD_REQUEST, reason),
+        Err(reason) => invite_click_error_response(StatusCode::BAD_REQUEST, reason.to_string()),
     }
 }
 
@@ -1532,7 +1582,9 @@ async fn run_onboarding_continue(
     };
     match runtime.run_onboarding_continue(request) {
         Ok(response) => (StatusCode::OK, Json(response)).into_response(),
-        Err(reason) => onboarding_continue_error_response(StatusCode::BAD_REQUEST, reason),
+        Err(reason) => {
+            onboarding_continue_error_response(StatusCode::BAD_REQUEST, reason.to_string())
+        }
     }
 }
 
@@ -1587,7 +1639,7 @@ async fn run_session_attach(
     };
     match runtime.run_session_attach(request) {
         Ok(response) => (StatusCode::OK, Json(response)).into_response(),
-        Err(reason) => session_attach_error_response(StatusCode::BAD_REQUEST, reason),
+        Err(reason) => session_attach_error_response(StatusCode::BAD_REQUEST, reason.to_string()),
     }
 }
 
@@ -1642,7 +1694,7 @@ async fn run_session_resume(
     };
     match runtime.run_session_resume(request) {
         Ok(response) => (StatusCode::OK, Json(response)).into_response(),
-        Err(reason) => session_resume_error_response(StatusCode::BAD_REQUEST, reason),
+        Err(reason) => session_resume_error_response(StatusCode::BAD_REQUEST, reason.to_string()),
     }
 }
 
@@ -1697,7 +1749,7 @@ async fn run_session_recover(
     };
     match runtime.run_session_recover(request) {
         Ok(response) => (StatusCode::OK, Json(response)).into_response(),
-        Err(reason) => session_recover_error_response(StatusCode::BAD_REQUEST, reason),
+        Err(reason) => session_recover_error_response(StatusCode::BAD_REQUEST, reason.to_string()),
     }
 }
 
@@ -1752,7 +1804,9 @@ async fn run_session_recent_list(
     };
     match runtime.run_session_recent_list(request) {
         Ok(response) => (StatusCode::OK, Json(response)).into_response(),
-        Err(reason) => session_recent_list_error_response(StatusCode::BAD_REQUEST, reason),
+        Err(reason) => {
+            session_recent_list_error_response(StatusCode::BAD_REQUEST, reason.to_string())
+        }
     }
 }
 
@@ -1807,7 +1861,9 @@ async fn run_session_idle_close_check(
     };
     match runtime.run_session_idle_close_check(request) {
         Ok(response) => (StatusCode::OK, Json(response)).into_response(),
-        Err(reason) => session_idle_close_check_error_response(StatusCode::BAD_REQUEST, reason),
+        Err(reason) => {
+            session_idle_close_check_error_response(StatusCode::BAD_REQUEST, reason.to_string())
+        }
     }
 }
 
@@ -1862,7 +1918,9 @@ async fn run_session_posture_evidence(
     };
     match runtime.run_session_posture_evidence(request) {
         Ok(response) => (StatusCode::OK, Json(response)).into_response(),
-        Err(reason) => session_posture_evidence_error_response(StatusCode::BAD_REQUEST, reason),
+        Err(reason) => {
+            session_posture_evidence_error_response(StatusCode::BAD_REQUEST, reason.to_string())
+        }
     }
 }
 
@@ -1947,7 +2005,7 @@ async fn run_wake_profile_availability_refresh(
             StatusCode::BAD_REQUEST,
             Some(request_device_id),
             Some(request_wake_profile_id),
-            reason,
+            reason.to_string(),
         ),
     }
 }
@@ -2650,6 +2708,10 @@ fn voice_turn_security_reject_response(reject: SecurityReject, turn_id: Option<u
         deep_research: None,
         screen_lifecycle_action: None,
         session_lifecycle_action: None,
+        stt_mode: String::new(),
+        keep_listening: false,
+        next_move_kind: Some(VoiceTurnNextMove::Respond),
+        outcome_kind: None,
     };
     json_response_with_optional_retry_after(status, response, reject.retry_after_secs)
 }
@@ -2997,6 +3059,10 @@ fn voice_turn_ingress_error_response(status: StatusCode, error: VoiceTurnIngress
             deep_research: None,
             screen_lifecycle_action: None,
             session_lifecycle_action: None,
+            stt_mode: String::new(),
+            keep_listening: false,
+            next_move_kind: Some(VoiceTurnNextMove::Respond),
+            outcome_kind: None,
         }),
     )
         .into_response()
@@ -3552,6 +3618,13 @@ mod tests {
             selene_text_final: None,
             audio_capture_ref: None,
             visual_input_ref: None,
+            provenance_mode: None,
+            user_text_part_seq: None,
+            model_override: None,
+            pre_fetched_tool_response: None,
+            language_hint: None,
+            language_hint_confidence: None,
+            request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
         }
     }
 
@@ -3703,6 +3776,13 @@ mod tests {
                 timing_overruns: Some(0),
             }),
             visual_input_ref: None,
+            provenance_mode: None,
+            user_text_part_seq: None,
+            model_override: None,
+            pre_fetched_tool_response: None,
+            language_hint: None,
+            language_hint_confidence: None,
+            request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
         }
     }
 
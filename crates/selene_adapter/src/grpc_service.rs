@@ -0,0 +1,247 @@
+#![forbid(unsafe_code)]
+
+use std::sync::{Arc, Mutex};
+
+use tonic::{Request, Response, Status};
+
+use crate::grpc_api::{
+    voice_ingress_server::VoiceIngress, RunVoiceTurnProvenance, RunVoiceTurnRequest,
+    RunVoiceTurnResponse, RunVoiceTurnSourceRef, UiHealthReportPaging, UiHealthReportQueryRequest,
+    UiHealthReportQueryResponse, UiHealthReportRow,
+};
+use crate::{
+    AdapterError, AdapterRuntime, UiHealthReportQueryRequest as AdapterUiHealthReportQueryRequest,
+    UiHealthReportQueryResponse as AdapterUiHealthReportQueryResponse, VoiceTurnAdapterRequest,
+    VoiceTurnThreadPolicyFlags as AdapterVoiceTurnThreadPolicyFlags,
+};
+
+/// `selene.adapter.v1.VoiceIngress` over a shared `AdapterRuntime`. Serve it with
+/// `VoiceIngressServer::new`.
+#[derive(Clone)]
+pub struct GrpcVoiceIngress {
+    runtime: Arc<Mutex<AdapterRuntime>>,
+}
+
+impl GrpcVoiceIngress {
+    pub fn new(runtime: Arc<Mutex<AdapterRuntime>>) -> Self {
+        Self { runtime }
+    }
+
+    pub fn runtime(&self) -> Arc<Mutex<AdapterRuntime>> {
+        self.runtime.clone()
+    }
+}
+
+#[tonic::async_trait]
+impl VoiceIngress for GrpcVoiceIngress {
+    async fn run_voice_turn(
+        &self,
+        request: Request<RunVoiceTurnRequest>,
+    ) -> Result<Response<RunVoiceTurnResponse>, Status> {
+        let req = request.into_inner();
+        let adapter_request = VoiceTurnAdapterRequest {
+            correlation_id: req.correlation_id,
+            turn_id: req.turn_id,
+            device_turn_sequence: None,
+            app_platform: req.app_platform,
+            platform_version: None,
+            device_class: None,
+            runtime_client_version: None,
+            hardware_capability_profile: None,
+            network_profile: None,
+            claimed_capabilities: None,
+            integrity_status: None,
+            attestation_ref: None,
+            trigger: req.trigger,
+            actor_user_id: req.actor_user_id,
+            tenant_id: if req.tenant_id.trim().is_empty() {
+                None
+            } else {
+                Some(req.tenant_id)
+            },
+            device_id: if req.device_id.trim().is_empty() {
+                None
+            } else {
+                Some(req.device_id)
+            },
+            now_ns: if req.now_ns == 0 {
+                None
+            } else {
+                Some(req.now_ns)
+            },
+            thread_key: if req.thread_key.trim().is_empty() {
+                None
+            } else {
+                Some(req.thread_key)
+            },
+            project_id: if req.project_id.trim().is_empty() {
+                None
+            } else {
+                Some(req.project_id)
+            },
+            pinned_context_refs: if req.pinned_context_refs.is_empty() {
+                None
+            } else {
+                Some(req.pinned_context_refs)
+            },
+            thread_policy_flags: req.thread_policy_flags.map(|flags| {
+                AdapterVoiceTurnThreadPolicyFlags {
+                    privacy_mode: flags.privacy_mode,
+                    do_not_disturb: flags.do_not_disturb,
+                    strict_safety: flags.strict_safety,
+                }
+            }),
+            user_text_partial: None,
+            user_text_final: None,
+            selene_text_partial: None,
+            selene_text_final: None,
+            audio_capture_ref: None,
+            visual_input_ref: None,
+            provenance_mode: None,
+            user_text_part_seq: None,
+            model_override: None,
+            pre_fetched_tool_response: None,
+            language_hint: None,
+            language_hint_confidence: None,
+            request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
+        };
+
+        let runtime = self
+            .runtime
+            .lock()
+            .map_err(|_| Status::internal("adapter runtime lock poisoned"))?;
+        match runtime.run_voice_turn(adapter_request) {
+            Ok(out) => Ok(Response::new(RunVoiceTurnResponse {
+                status: out.status,
+                outcome: out.outcome,
+                reason: out.reason.unwrap_or_default(),
+                next_move: out.next_move,
+                response_text: out.response_text,
+                reason_code: out.reason_code,
+                provenance: out.provenance.map(|p| RunVoiceTurnProvenance {
+                    sources: p
+                        .sources
+                        .into_iter()
+                        .map(|s| RunVoiceTurnSourceRef {
+                            title: s.title,
+                            url: s.url,
+                        })
+                        .collect(),
+                    retrieved_at: p.retrieved_at,
+                    cache_status: p.cache_status,
+                }),
+            })),
+            Err(err @ AdapterError::RateLimited { .. }) => {
+                Err(Status::resource_exhausted(err.to_string()))
+            }
+            Err(reason) => Err(Status::invalid_argument(reason.to_string())),
+        }
+    }
+
+    async fn ui_health_report_query(
+        &self,
+        request: Request<UiHealthReportQueryRequest>,
+    ) -> Result<Response<UiHealthReportQueryResponse>, Status> {
+        let req = request.into_inner();
+        let company_ids = if req.company_ids.is_empty() {
+            None
+        } else {
+            Some(req.company_ids)
+        };
+        let country_codes = if req.country_codes.is_empty() {
+            None
+        } else {
+            Some(req.country_codes)
+        };
+        let adapter_request = AdapterUiHealthReportQueryRequest {
+            correlation_id: non_zero_u64(req.correlation_id),
+            turn_id: non_zero_u64(req.turn_id),
+            tenant_id: optional_string(req.tenant_id),
+            viewer_user_id: optional_string(req.viewer_user_id),
+            report_kind: optional_string(req.report_kind),
+            from_utc_ns: non_zero_u64(req.from_utc_ns),
+            to_utc_ns: non_zero_u64(req.to_utc_ns),
+            engine_owner_filter: optional_string(req.engine_owner_filter),
+            company_scope: optional_string(req.company_scope),
+            company_ids,
+            country_codes,
+            escalated_only: Some(req.escalated_only),
+            unresolved_only: Some(req.unresolved_only),
+            display_target: optional_string(req.display_target),
+            page_action: optional_string(req.page_action),
+            page_cursor: optional_string(req.page_cursor),
+            report_context_id: optional_string(req.report_context_id),
+            page_size: if req.page_size == 0 {
+                None
+            } else {
+                Some(req.page_size.min(u16::MAX as u32) as u16)
+            },
+        };
+
+        let runtime = self
+            .runtime
+            .lock()
+            .map_err(|_| Status::internal("adapter runtime lock poisoned"))?;
+        let out = runtime.ui_health_report_query(adapter_request, None);
+        Ok(Response::new(map_ui_health_report_query_response(out)))
+    }
+}
+
+fn optional_string(value: String) -> Option<String> {
+    if value.trim().is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn non_zero_u64(value: u64) -> Option<u64> {
+    if value == 0 {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+fn map_ui_health_report_query_response(
+    response: AdapterUiHealthReportQueryResponse,
+) -> UiHealthReportQueryResponse {
+    UiHealthReportQueryResponse {
+        status: response.status,
+        generated_at_ns: response.generated_at_ns,
+        reason_code: response.reason_code,
+        report_context_id: response.report_context_id.unwrap_or_default(),
+        report_revision: response.report_revision.unwrap_or_default(),
+        normalized_query: response.normalized_query.unwrap_or_default(),
+        rows: response
+            .rows
+            .into_iter()
+            .map(|row| UiHealthReportRow {
+                tenant_id: row.tenant_id,
+                issue_id: row.issue_id,
+                owner_engine_id: row.owner_engine_id,
+                severity: row.severity,
+                status: row.status,
+                latest_reason_code: row.latest_reason_code,
+                last_seen_at_ns: row.last_seen_at_ns,
+                bcast_id: row.bcast_id.unwrap_or_default(),
+                ack_state: row.ack_state.unwrap_or_default(),
+                issue_fingerprint: row.issue_fingerprint.unwrap_or_default(),
+                recurrence_observed: row.recurrence_observed,
+                impact_summary: row.impact_summary.unwrap_or_default(),
+                attempted_fix_actions: row.attempted_fix_actions,
+                current_monitoring_evidence: row.current_monitoring_evidence.unwrap_or_default(),
+                unresolved_reason_exact: row.unresolved_reason_exact.unwrap_or_default(),
+            })
+            .collect(),
+        paging: Some(UiHealthReportPaging {
+            has_next: response.paging.has_next,
+            has_prev: response.paging.has_prev,
+            next_cursor: response.paging.next_cursor.unwrap_or_default(),
+            prev_cursor: response.paging.prev_cursor.unwrap_or_default(),
+        }),
+        display_target_applied: response.display_target_applied.unwrap_or_default(),
+        remembered_display_target: response.remembered_display_target.unwrap_or_default(),
+        requires_clarification: response.requires_clarification.unwrap_or_default(),
+    }
+}
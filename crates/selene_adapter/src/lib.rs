@@ -1,19 +1,23 @@
 #![forbid(unsafe_code)]
 
-use std::collections::{BTreeMap, BTreeSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 use std::env;
 use std::fs::{self, File, OpenOptions};
 use std::hash::{Hash, Hasher};
 use std::io::{BufRead, BufReader, Write};
+use std::ops::{Deref, DerefMut};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use selene_engines::device_vault;
 use selene_engines::ph1_voice_id::{
-    EnrolledSpeaker as EngineEnrolledSpeaker, Ph1VoiceIdConfig as EngineVoiceIdConfig,
-    Ph1VoiceIdRuntime as EngineVoiceIdRuntime, VoiceIdObservation as EngineVoiceIdObservation,
+    reason_codes as voice_id_reason_codes, EnrolledSpeaker as EngineEnrolledSpeaker,
+    Ph1VoiceIdConfig as EngineVoiceIdConfig, Ph1VoiceIdRuntime as EngineVoiceIdRuntime,
+    VoiceIdObservation as EngineVoiceIdObservation,
 };
 use selene_engines::ph1c::{
     reason_codes as ph1c_reason_codes, Ph1cConfig as EnginePh1cConfig, Ph1cLiveProviderContext,
@@ -33,7 +37,7 @@ use selene_engines::ph1health::{
 use selene_engines::ph1k::{
     build_interrupt_feedback_signal, build_ph1k_to_ph1c_handoff, default_adaptive_policy_input,
     evaluate_interrupt_candidate, InterruptFeedbackSignalKind, InterruptInput, InterruptNoiseClass,
-    InterruptPhraseMatcher, PhraseDetection,
+    InterruptPhraseMatcher, PhraseDetection, DEFAULT_MIN_INTERRUPT_PHRASE_CONFIDENCE,
 };
 use selene_engines::ph1lang::{
     Ph1LangConfig as EnginePh1LangConfig, Ph1LangRuntime as EnginePh1LangRuntime,
@@ -54,7 +58,8 @@ use selene_engines::ph1srl::{
     Ph1SrlConfig as EnginePh1SrlConfig, Ph1SrlRuntime as EnginePh1SrlRuntime,
 };
 use selene_engines::ph1vision::{
-    Ph1VisionConfig as EnginePh1VisionConfig, Ph1VisionRuntime as EnginePh1VisionRuntime,
+    reason_codes as ph1vision_reason_codes, Ph1VisionConfig as EnginePh1VisionConfig,
+    Ph1VisionRuntime as EnginePh1VisionRuntime,
 };
 use selene_engines::ph1w::{
     reason_codes as ph1w_reason_codes, Ph1wOutputEvent, Ph1wRuntime, SourceLivenessHint,
@@ -75,14 +80,15 @@ use selene_kernel_contracts::ph1c::{
 };
 use selene_kernel_contracts::ph1context::{Ph1ContextRequest, Ph1ContextResponse};
 use selene_kernel_contracts::ph1d::{
-    Ph1dFailureKind, Ph1dOk, Ph1dProviderCallRequest, Ph1dProviderCallResponse,
+    Ph1dAnalysis, Ph1dFailureKind, Ph1dOk, Ph1dProviderCallRequest, Ph1dProviderCallResponse,
     Ph1dProviderErrorEvidence, Ph1dProviderInputPayloadKind, Ph1dProviderRouteClass,
     Ph1dProviderStatus, Ph1dProviderTask, Ph1dProviderTransportEvidence,
     Ph1dProviderValidationStatus, Ph1dRequest, Ph1dResponse, PolicyContextRef, RequestId,
     SafetyTier, SchemaHash, PH1D_PROVIDER_NORMALIZED_OUTPUT_SCHEMA_HASH_V1,
 };
 use selene_kernel_contracts::ph1e::{
-    CacheStatus, SearchImagePacket, ToolCatalogRef, ToolName, ToolResponse, ToolResult, ToolStatus,
+    CacheStatus, SearchImagePacket, SourceMetadata, SourceRef, ToolCatalogRef, ToolName,
+    ToolQueryHash, ToolRequestId, ToolResponse, ToolResult, ToolStatus, PH1E_CONTRACT_VERSION,
 };
 use selene_kernel_contracts::ph1f::{
     ConversationRole, ConversationSource, ConversationTurnId, ConversationTurnInput,
@@ -98,13 +104,13 @@ use selene_kernel_contracts::ph1health::{
     HealthReportQueryReadOk, HealthReportQueryReadRequest, HealthReportTimeRange, HealthSeverity,
     Ph1HealthRequest, Ph1HealthResponse,
 };
-use selene_kernel_contracts::ph1j::{CorrelationId, DeviceId, TurnId};
+use selene_kernel_contracts::ph1j::{CorrelationId, DeviceId, ProofFailureClass, TurnId};
 use selene_kernel_contracts::ph1k::{
     AdvancedAudioQualityMetrics, AudioDeviceId, AudioFormat, AudioStreamId, AudioStreamKind,
     AudioStreamRef, ChannelCount, Confidence, DeviceHealth, DeviceReliabilityScoreInput,
-    DeviceRoute, DeviceState, FrameDurationMs, InterruptLexiconPolicyBinding, InterruptLocaleTag,
-    PreRollBufferId, PreRollBufferRef, SampleFormat, SampleRateHz, SpeechLikeness,
-    TimingStats as Ph1kTimingStats, TtsPlaybackActiveEvent, VadEvent,
+    DeviceRoute, DeviceState, FrameDurationMs, InterruptCandidate, InterruptLexiconPolicyBinding,
+    InterruptLocaleTag, PreRollBufferId, PreRollBufferRef, SampleFormat, SampleRateHz,
+    SpeechLikeness, TimingStats as Ph1kTimingStats, TtsPlaybackActiveEvent, VadEvent,
 };
 use selene_kernel_contracts::ph1l::{
     Ph1lInput, SessionId, SessionSnapshot, TtsPlaybackState, UserActivitySignals,
@@ -116,8 +122,9 @@ use selene_kernel_contracts::ph1lang::{
 use selene_kernel_contracts::ph1learn::{LearnSignalType, WakeLearnSignalV1, WakeLearnTrigger};
 use selene_kernel_contracts::ph1link::{AppPlatform, TokenId};
 use selene_kernel_contracts::ph1m::{
-    MemoryContinuationDecisionKind, MemoryResumeTier, MemoryRetentionMode, MemoryThreadDigest,
-    Ph1mRecentArchiveRecallRequest, MEMORY_RESUME_HOT_WINDOW_MS,
+    MemoryCandidate, MemoryContinuationDecisionKind, MemoryResumeTier, MemoryRetentionMode,
+    MemorySensitivityFlag, MemoryThreadDigest, MemoryUsePolicy, Ph1mRecentArchiveRecallRequest,
+    MEMORY_RESUME_HOT_WINDOW_MS,
 };
 use selene_kernel_contracts::ph1n::{
     Chat as Ph1nChat, Clarify as Ph1nClarify, FieldKey, IntentType, Ph1nRequest, Ph1nResponse,
@@ -164,7 +171,7 @@ use selene_os::app_ingress::{
     AppSessionResumeRequest, AppVoiceIngressRequest, AppVoicePh1xBuildInput,
     AppVoiceTurnExecutionOutcome, AppVoiceTurnNextMove, AppWakeProfileAvailabilityRefreshRequest,
 };
-use selene_os::device_artifact_sync::DeviceArtifactSyncWorkerPassMetrics;
+use selene_os::device_artifact_sync::{DeviceArtifactSyncWorkerPassMetrics, SyncRetryPolicy};
 use selene_os::ph1_voice_id::{
     Ph1VoiceIdLiveConfig, VoiceIdContractMigrationConfig, VoiceIdentityEmbeddingGateGovernedConfig,
     VoiceIdentityEmbeddingGateProfile, VoiceIdentityEmbeddingGateProfiles,
@@ -213,12 +220,32 @@ use selene_storage::ph1f::{
 };
 use selene_storage::repo::Ph1jAuditRepo;
 use sha2::{Digest, Sha256};
+
+// Stage spans repeat the turn ids so an exporter that flattens spans still attributes each stage.
+macro_rules! voice_turn_stage_span {
+    ($name:literal, $correlation_id:expr, $turn_id:expr, $tenant_id:expr) => {
+        tracing::info_span!(
+            $name,
+            correlation_id = $correlation_id,
+            turn_id = $turn_id,
+            tenant_id = $tenant_id
+        )
+    };
+}
+
 pub mod grpc_api {
     tonic::include_proto!("selene.adapter.v1");
 }
 
 pub mod desktop_mic_producer;
 
+pub mod async_runtime;
+
+pub mod grpc_service;
+
+#[cfg(feature = "provider-http")]
+pub mod provider_http;
+
 pub mod app_ui_assets {
     pub const APP_HTML: &str = include_str!("web/app.html");
     pub const APP_CSS: &str = include_str!("web/app.css");
@@ -234,6 +261,16 @@ pub mod reason_codes {
     pub const ADAPTER_READ_ONLY_TOOL_FAIL_INCIDENT: ReasonCodeId = ReasonCodeId(0xAD70_0011);
     pub const ADAPTER_READ_ONLY_CLARIFY_LOOP_INCIDENT: ReasonCodeId = ReasonCodeId(0xAD70_0012);
     pub const ADAPTER_READ_ONLY_USER_CORRECTION_INCIDENT: ReasonCodeId = ReasonCodeId(0xAD70_0013);
+    pub const ADAPTER_PH1N_PASS_THROUGH_FALLBACK: ReasonCodeId = ReasonCodeId(0xAD70_0021);
+    pub const ADAPTER_STT_SNR_BELOW_FLOOR: ReasonCodeId = ReasonCodeId(0xAD70_0022);
+    pub const ADAPTER_VISION_SOURCE_KIND_DISALLOWED: ReasonCodeId = ReasonCodeId(0xAD70_0023);
+    pub const ADAPTER_LOW_LATENCY_TRANSCRIPT_CORRECTED: ReasonCodeId = ReasonCodeId(0xAD70_0024);
+    pub const ADAPTER_BUILDER_RUN_FAILED: ReasonCodeId = ReasonCodeId(0xAD70_0025);
+    pub const ADAPTER_TENANT_BLOCKED: ReasonCodeId = ReasonCodeId(0xAD70_0026);
+    pub const ADAPTER_MEMORY_PERMISSION_DECLINED: ReasonCodeId = ReasonCodeId(0xAD70_0027);
+    pub const ADAPTER_TRANSCRIPT_ERASED: ReasonCodeId = ReasonCodeId(0xAD70_0028);
+    pub const ADAPTER_HEALTH_ISSUES_OMITTED: ReasonCodeId = ReasonCodeId(0xAD70_0029);
+    pub const ADAPTER_PROVIDER_FAILOVER_ATTEMPT_FAILED: ReasonCodeId = ReasonCodeId(0xAD70_002A);
 }
 
 const DETERMINISTIC_TIME_CLARIFICATION_TOPIC: &str = "deterministic_time_clarification";
@@ -311,6 +348,21 @@ pub struct VoiceTurnThreadPolicyFlags {
     pub strict_safety: bool,
 }
 
+/// A tool result the client already holds for the thread's pending PH1.E tool request, such as
+/// a cached weather lookup. PH1.X answers from it instead of dispatching the tool again.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VoiceTurnPreFetchedToolResponse {
+    /// The pending tool request this result answers.
+    pub request_id: u64,
+    pub query_hash: u64,
+    /// `TIME` or `WEATHER`.
+    pub tool_kind: String,
+    /// The ISO 8601 local time for `TIME`, or the forecast summary for `WEATHER`.
+    pub result_text: String,
+    pub sources: Vec<VoiceTurnProvenanceSource>,
+    pub retrieved_at_unix_ms: u64,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct VoiceTurnAdapterRequest {
     pub correlation_id: u64,
@@ -340,6 +392,32 @@ pub struct VoiceTurnAdapterRequest {
     pub selene_text_final: Option<String>,
     pub audio_capture_ref: Option<VoiceTurnAudioCaptureRef>,
     pub visual_input_ref: Option<VoiceTurnVisualInputRef>,
+    pub provenance_mode: Option<String>,
+    pub user_text_part_seq: Option<u32>,
+    /// PH1.D model for this turn's public answer. Must be on the configured override allowlist.
+    pub model_override: Option<String>,
+    /// Used only when it matches the thread's pending tool request; otherwise the turn runs as
+    /// usual. A malformed result rejects the turn.
+    pub pre_fetched_tool_response: Option<VoiceTurnPreFetchedToolResponse>,
+    /// BCP-47 language of the audio. Outranks the capture locale for PH1.C; a malformed tag
+    /// rejects the turn.
+    pub language_hint: Option<String>,
+    /// `HIGH`, `MED` or `LOW`; `HIGH` when omitted. Only valid together with `language_hint`.
+    pub language_hint_confidence: Option<String>,
+    /// Shape of this request. One without a version is the unversioned v1 shape. The journal
+    /// always records `SCHEMA_VERSION`, and replay upgrades older entries with `migrate_request`.
+    #[serde(default = "legacy_voice_turn_request_schema_version")]
+    pub request_schema_version: u32,
+}
+
+impl VoiceTurnAdapterRequest {
+    /// Current request shape. Bump it with every new field, and add the step from the previous
+    /// version to `migrate_request`.
+    pub const SCHEMA_VERSION: u32 = 2;
+}
+
+fn legacy_voice_turn_request_schema_version() -> u32 {
+    1
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -376,6 +454,101 @@ pub struct VoiceTurnAdapterResponse {
     pub screen_lifecycle_action: Option<VoiceTurnScreenLifecycleActionPacket>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session_lifecycle_action: Option<VoiceTurnSessionLifecycleActionPacket>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub stt_mode: String,
+    #[serde(default)]
+    pub keep_listening: bool,
+    /// Typed `next_move`. Absent when `next_move` is outside `VoiceTurnNextMove`, such as
+    /// `listening_window_open` or a lifecycle action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_move_kind: Option<VoiceTurnNextMove>,
+    /// Typed `outcome`. Absent when `outcome` is outside `VoiceTurnOutcome`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outcome_kind: Option<VoiceTurnOutcome>,
+}
+
+/// Typed form of `VoiceTurnAdapterResponse.next_move`. Serializes to the same snake_case label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VoiceTurnNextMove {
+    Clarify,
+    DispatchSim,
+    DispatchTool,
+    Respond,
+    Wait,
+}
+
+impl VoiceTurnNextMove {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VoiceTurnNextMove::Clarify => "clarify",
+            VoiceTurnNextMove::DispatchSim => "dispatch_sim",
+            VoiceTurnNextMove::DispatchTool => "dispatch_tool",
+            VoiceTurnNextMove::Respond => "respond",
+            VoiceTurnNextMove::Wait => "wait",
+        }
+    }
+
+    // A simulation dispatch or tool answer decides the move before PH1.X's own next move.
+    fn from_execution(execution: &AppVoiceTurnExecutionOutcome) -> Self {
+        if execution.dispatch_outcome.is_some() {
+            return VoiceTurnNextMove::DispatchSim;
+        }
+        if execution.tool_response.is_some() {
+            return VoiceTurnNextMove::DispatchTool;
+        }
+        VoiceTurnNextMove::from(execution.next_move)
+    }
+}
+
+impl From<AppVoiceTurnNextMove> for VoiceTurnNextMove {
+    fn from(next_move: AppVoiceTurnNextMove) -> Self {
+        match next_move {
+            AppVoiceTurnNextMove::Confirm | AppVoiceTurnNextMove::Clarify => {
+                VoiceTurnNextMove::Clarify
+            }
+            AppVoiceTurnNextMove::Dispatch => VoiceTurnNextMove::DispatchSim,
+            AppVoiceTurnNextMove::NotInvokedDisabled
+            | AppVoiceTurnNextMove::Refused
+            | AppVoiceTurnNextMove::Respond => VoiceTurnNextMove::Respond,
+            AppVoiceTurnNextMove::Wait => VoiceTurnNextMove::Wait,
+        }
+    }
+}
+
+/// Typed form of `VoiceTurnAdapterResponse.outcome` for turns PH1.X answered. Serializes to the
+/// same SCREAMING_SNAKE_CASE label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VoiceTurnOutcome {
+    Final,
+    FinalTool,
+    DispatchSim,
+    VoiceDisabled,
+}
+
+impl VoiceTurnOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            VoiceTurnOutcome::Final => "FINAL",
+            VoiceTurnOutcome::FinalTool => "FINAL_TOOL",
+            VoiceTurnOutcome::DispatchSim => "DISPATCH_SIM",
+            VoiceTurnOutcome::VoiceDisabled => "VOICE_DISABLED",
+        }
+    }
+
+    fn from_execution(execution: &AppVoiceTurnExecutionOutcome) -> Self {
+        if execution.dispatch_outcome.is_some() {
+            return VoiceTurnOutcome::DispatchSim;
+        }
+        if execution.tool_response.is_some() {
+            return VoiceTurnOutcome::FinalTool;
+        }
+        if execution.next_move == AppVoiceTurnNextMove::NotInvokedDisabled {
+            return VoiceTurnOutcome::VoiceDisabled;
+        }
+        VoiceTurnOutcome::Final
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -416,6 +589,25 @@ pub struct DesktopRejectedVoiceEvidenceInput {
     pub request_id: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TranscriptSystemMessageKind {
+    SystemNotice,
+    ToolResult,
+}
+
+/// A client-authored transcript line that is neither the user nor Selene speaking.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TranscriptSystemMessageInput {
+    pub correlation_id: u64,
+    pub turn_id: u64,
+    pub session_id: Option<String>,
+    pub actor_user_id: String,
+    pub device_id: Option<String>,
+    pub kind: TranscriptSystemMessageKind,
+    pub text: String,
+    pub now_ns: Option<u64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct VoiceTurnScreenLifecycleActionPacket {
     pub canonical_intent: String,
@@ -873,7 +1065,7 @@ pub struct VoiceTurnResearchProofMetadata {
     pub private_query_saved: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct VoiceTurnIngressError {
     pub failure_class: FailureClass,
     pub reason_code: String,
@@ -881,8 +1073,25 @@ pub struct VoiceTurnIngressError {
     pub session_id: Option<String>,
     pub turn_id: Option<u64>,
     pub session_state: Option<String>,
+    /// The typed runtime error behind this failure, when there is one. It is not serialized
+    /// and does not take part in equality, so a replayed failure still matches the original.
+    #[serde(skip)]
+    pub cause: Option<Box<AdapterError>>,
+}
+
+impl PartialEq for VoiceTurnIngressError {
+    fn eq(&self, other: &Self) -> bool {
+        self.failure_class == other.failure_class
+            && self.reason_code == other.reason_code
+            && self.reason == other.reason
+            && self.session_id == other.session_id
+            && self.turn_id == other.turn_id
+            && self.session_state == other.session_state
+    }
 }
 
+impl Eq for VoiceTurnIngressError {}
+
 impl VoiceTurnIngressError {
     pub fn to_runtime_reason(&self) -> String {
         self.reason
@@ -891,12 +1100,37 @@ impl VoiceTurnIngressError {
     }
 }
 
+/// Request-scoped cancellation signal for `run_voice_turn_cancellable`. Clones share one flag,
+/// so the transport can keep a clone and cancel it when the client disconnects.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationFlag(Arc<AtomicBool>);
+
+impl CancellationFlag {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct AdapterPersistenceRuntime {
     legacy_journal_path: PathBuf,
     state_path: PathBuf,
     #[serde(skip)]
     state: Arc<Mutex<AdapterPersistenceState>>,
+    #[serde(skip)]
+    journal_lock: Arc<Mutex<()>>,
+    #[serde(skip)]
+    store_snapshot_seq: Arc<Mutex<u64>>,
+    #[serde(skip)]
+    entries_since_store_snapshot: Arc<AtomicU64>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -911,6 +1145,10 @@ struct AdapterPersistenceState {
     conversation_turn_records: Vec<ConversationTurnRecord>,
     #[serde(default)]
     internal_history_evidence_records: Vec<InternalHistoryEvidenceRecord>,
+    #[serde(default)]
+    legacy_journal_trimmed_entries: u64,
+    #[serde(default)]
+    legacy_journal_dropped_entries: u64,
     next_journal_sequence: u64,
     next_audit_sequence: u64,
     last_reconciled_at_ns: Option<u64>,
@@ -927,6 +1165,8 @@ impl Default for AdapterPersistenceState {
             audit_trail: Vec::new(),
             conversation_turn_records: Vec::new(),
             internal_history_evidence_records: Vec::new(),
+            legacy_journal_trimmed_entries: 0,
+            legacy_journal_dropped_entries: 0,
             next_journal_sequence: 1,
             next_audit_sequence: 1,
             last_reconciled_at_ns: None,
@@ -1053,6 +1293,81 @@ enum PersistenceInvocationMode {
     LegacyJournalReplay,
 }
 
+#[derive(Debug, Clone, Copy)]
+enum LegacyJournalWrite<'a> {
+    Skip,
+    Immediate,
+    // Collected by `run_voice_turn_batch` and written once when the batch ends.
+    Deferred(&'a AdapterTurnBatch),
+}
+
+// Journal lines and replay entries of a `run_voice_turn_batch` call. Replay entries reach the
+// shared cache only after the batch journal write, so a failed write leaves the cache as it was.
+#[derive(Debug, Default)]
+struct AdapterTurnBatch {
+    journal_lines: Mutex<Vec<String>>,
+    replay_entries: Mutex<Vec<((CorrelationId, TurnId), AdapterTurnReplayEntry)>>,
+}
+
+impl AdapterTurnBatch {
+    fn journal_line_count(&self) -> usize {
+        self.journal_lines
+            .lock()
+            .map(|lines| lines.len())
+            .unwrap_or(0)
+    }
+
+    fn replay_entry_count(&self) -> usize {
+        self.replay_entries
+            .lock()
+            .map(|entries| entries.len())
+            .unwrap_or(0)
+    }
+
+    // The newest entry an earlier turn of the batch left for `key`. It shadows the shared
+    // cache, as the insert would have replaced the cached entry in sequential calls.
+    fn latest_replay_entry(
+        &self,
+        key: (CorrelationId, TurnId),
+    ) -> Result<Option<AdapterTurnReplayEntry>, AdapterError> {
+        Ok(self
+            .replay_entries
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("batch replay".to_string()))?
+            .iter()
+            .rev()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map(|(_, entry)| entry.clone()))
+    }
+}
+
+// The store a voice turn runs against. `run_voice_turn_batch` locks the store once and lends it
+// to every turn of the batch; other callers lock it stage by stage.
+enum VoiceTurnStore<'a> {
+    Locked(MutexGuard<'a, Ph1fStore>),
+    Held(&'a mut Ph1fStore),
+}
+
+impl Deref for VoiceTurnStore<'_> {
+    type Target = Ph1fStore;
+
+    fn deref(&self) -> &Ph1fStore {
+        match self {
+            Self::Locked(store) => store,
+            Self::Held(store) => store,
+        }
+    }
+}
+
+impl DerefMut for VoiceTurnStore<'_> {
+    fn deref_mut(&mut self) -> &mut Ph1fStore {
+        match self {
+            Self::Locked(store) => store,
+            Self::Held(store) => store,
+        }
+    }
+}
+
 #[derive(Clone, Copy)]
 struct SyncImprovementBuilderContext<'a> {
     now: MonotonicTimeNs,
@@ -1071,6 +1386,8 @@ struct ReusedResponseTranscriptUpdate<'a> {
     device_id: &'a DeviceId,
     response: &'a VoiceTurnAdapterResponse,
     user_source: ConversationSource,
+    privacy_scope: PrivacyScope,
+    user_text_part_seq: Option<u32>,
     user_text_final: Option<&'a str>,
     selene_text_final: Option<&'a str>,
 }
@@ -1113,6 +1430,8 @@ struct ResolveSessionTurnStateInput<'a> {
     actor_user_id: &'a UserId,
     device_id: &'a DeviceId,
     trigger: OsVoiceTrigger,
+    app_platform: AppPlatform,
+    wake_gate_policy: AdapterWakeGatePolicy,
     ph1k: &'a Ph1kLiveSignalBundle,
     wake_event: Option<WakeDecision>,
     idempotency_key: &'a str,
@@ -1192,6 +1511,15 @@ pub struct OnboardingContinueAdapterResponse {
     pub onboarding_status: Option<String>,
 }
 
+/// The access-engine instance a completed onboarding session provisioned.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct OnboardingAccessInstanceLinkView {
+    pub onboarding_session_id: String,
+    pub access_engine_instance_id: String,
+    pub tenant_id: Option<String>,
+    pub completed_at_ns: u64,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SessionAttachAdapterRequest {
     pub correlation_id: u64,
@@ -1361,6 +1689,8 @@ pub struct AdapterSyncWorkerCounters {
     pub last_acked_count: u16,
     pub last_retry_scheduled_count: u16,
     pub last_dead_lettered_count: u16,
+    #[serde(default)]
+    pub last_backoff_ms: u32,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
@@ -1371,6 +1701,18 @@ pub struct AdapterSyncQueueCounters {
     pub dead_letter_count: u32,
     pub replay_due_count: u32,
     pub retry_pending_count: u32,
+    pub replay_due_oldest_at_ns: Option<u64>,
+}
+
+/// A dead-lettered device artifact sync job, as listed by `AdapterRuntime::list_dead_letters`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct DeadLetterRow {
+    pub sync_job_id: String,
+    pub sync_kind: String,
+    pub attempt_count: u16,
+    pub last_error: Option<String>,
+    pub user_id: Option<String>,
+    pub device_id: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
@@ -1390,6 +1732,72 @@ pub struct AdapterImprovementCounters {
     pub builder_not_invoked_total: u64,
     pub builder_errors_total: u64,
     pub last_builder_status: Option<String>,
+    #[serde(default)]
+    pub read_only_incidents: AdapterReadOnlyIncidentCounters,
+    #[serde(default)]
+    pub recent_builder_incidents: Vec<AdapterBuilderIncident>,
+    #[serde(default)]
+    pub empty_responses: AdapterEmptyResponseCounters,
+}
+
+// Turns whose `response_text` came back empty, keyed by `next_move`. Intentional silence
+// (wait, lifecycle actions) is kept apart from empties that should have spoken.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
+pub struct AdapterEmptyResponseCounters {
+    pub intentional_by_next_move: BTreeMap<String, u64>,
+    pub unexpected_by_next_move: BTreeMap<String, u64>,
+}
+
+// A refused or failed builder run, kept so the health timeline can show when it happened.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
+pub struct AdapterBuilderIncident {
+    pub at_ns: u64,
+    pub status: String,
+}
+
+const BUILDER_INCIDENT_HISTORY_LIMIT: usize = 20;
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
+pub struct AdapterReadOnlyIncidentCounters {
+    pub tool_fail: AdapterReadOnlyIncidentKindCounters,
+    pub clarify_loop: AdapterReadOnlyIncidentKindCounters,
+    pub user_correction: AdapterReadOnlyIncidentKindCounters,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
+pub struct AdapterReadOnlyIncidentKindCounters {
+    pub incidents_total: u64,
+    pub feedback_events_emitted_total: u64,
+    pub learn_artifacts_emitted_total: u64,
+    #[serde(default)]
+    pub suppressed_total: u64,
+}
+
+impl AdapterReadOnlyIncidentCounters {
+    fn kind_mut(&mut self, kind: ReadOnlyIncidentKind) -> &mut AdapterReadOnlyIncidentKindCounters {
+        match kind {
+            ReadOnlyIncidentKind::ToolFail => &mut self.tool_fail,
+            ReadOnlyIncidentKind::ClarifyLoop => &mut self.clarify_loop,
+            ReadOnlyIncidentKind::UserCorrection => &mut self.user_correction,
+        }
+    }
+
+    fn accumulate(&mut self, delta: &AdapterReadOnlyIncidentCounters) {
+        for (total, add) in [
+            (&mut self.tool_fail, &delta.tool_fail),
+            (&mut self.clarify_loop, &delta.clarify_loop),
+            (&mut self.user_correction, &delta.user_correction),
+        ] {
+            total.incidents_total = total.incidents_total.saturating_add(add.incidents_total);
+            total.feedback_events_emitted_total = total
+                .feedback_events_emitted_total
+                .saturating_add(add.feedback_events_emitted_total);
+            total.learn_artifacts_emitted_total = total
+                .learn_artifacts_emitted_total
+                .saturating_add(add.learn_artifacts_emitted_total);
+            total.suppressed_total = total.suppressed_total.saturating_add(add.suppressed_total);
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -1398,9 +1806,35 @@ pub struct AdapterHealthResponse {
     pub outcome: String,
     pub reason: Option<String>,
     pub sync: AdapterSyncHealth,
+    #[serde(default)]
+    pub provider_latency: AdapterProviderLatencyHealth,
+    #[serde(default)]
+    pub provider: AdapterProviderHealth,
     pub provenance: Option<AdapterProcessProvenance>,
 }
 
+/// Live STT provider circuit breaker. `circuit_state` is `CLOSED`, `OPEN` or `HALF_OPEN`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
+pub struct AdapterProviderHealth {
+    pub circuit_state: String,
+    pub consecutive_failures: u32,
+    pub failure_threshold: u32,
+    pub cooldown_ms: u64,
+    pub open_until_ns: Option<u64>,
+    pub trips_total: u64,
+    pub short_circuited_total: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
+pub struct AdapterProviderLatencyHealth {
+    pub status: String,
+    pub sample_count: u32,
+    pub window_size: u32,
+    pub p95_ms: Option<u32>,
+    pub warn_p95_ms: u32,
+    pub critical_p95_ms: u32,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct AdapterProcessProvenance {
     pub process_id: u32,
@@ -1563,6 +1997,30 @@ pub struct UiHealthReportQueryResponse {
     pub requires_clarification: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HealthReportDelta {
+    pub since_revision: u64,
+    pub report_revision: u64,
+    pub report_context_id: Option<String>,
+    pub baseline_found: bool,
+    pub added_issue_ids: Vec<String>,
+    pub changed_issue_ids: Vec<String>,
+    pub resolved_issue_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct BuilderArtifactView {
+    pub artifact_id: u64,
+    pub artifact_type: String,
+    pub artifact_version: u32,
+    pub package_hash: String,
+    pub status: String,
+    pub created_by: String,
+    pub payload_ref: String,
+    pub provenance_ref: String,
+    pub created_at_ns: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct UiTranscriptMessage {
     pub role: String,
@@ -1572,12 +2030,26 @@ pub struct UiTranscriptMessage {
     pub timestamp_ns: u64,
 }
 
+/// Narrows and pages `ui_chat_transcript_report`. Times are inclusive; `role` is
+/// `USER`, `SELENE` or `SYSTEM`. `cursor` is a previous response's `next_cursor`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Default)]
+pub struct UiTranscriptFilter {
+    pub correlation_id: Option<u64>,
+    pub from_ts_ns: Option<u64>,
+    pub to_ts_ns: Option<u64>,
+    pub role: Option<String>,
+    pub page_size: Option<u16>,
+    pub cursor: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct UiChatTranscriptResponse {
     pub status: String,
     pub generated_at_ns: u64,
     pub note: Option<String>,
     pub messages: Vec<UiTranscriptMessage>,
+    #[serde(default)]
+    pub next_cursor: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -1773,12 +2245,385 @@ struct AdapterPh1dProviderErrorEvidenceState {
     rows: Vec<Ph1dProviderErrorEvidence>,
 }
 
+/// Configuration for `AdapterRuntime`. `AdapterConfig::from_env` reads the same environment
+/// variables the env-driven constructors always have. Embedders can build or adjust one
+/// directly and pass it to `AdapterRuntime::from_config` without setting process env.
+#[derive(Debug, Clone)]
+pub struct AdapterConfig {
+    /// Legacy journal path; the persistence state lives next to it. `None` keeps the runtime
+    /// in memory with no journal.
+    pub journal_path: Option<PathBuf>,
+    pub auto_builder_enabled: bool,
+    pub ph1c_live_enabled: bool,
+    pub ph1c_streaming_enabled: bool,
+    pub stt_min_snr_db: Option<f32>,
+    pub tenant_feature_flags: BTreeMap<String, AdapterTenantFeatureFlags>,
+    pub ph1k_feedback_policy: AdapterPh1kFeedbackPolicy,
+    pub interrupt_confidence_policy: AdapterInterruptConfidencePolicy,
+    /// Pass the turn's accepted PH1.K interrupt candidate to PH1.X. PH1.X then cancels speech
+    /// and holds for the user instead of answering the turn.
+    pub ph1x_interruption_enabled: bool,
+    /// Pass the reason code of a thread's last rejected turn to PH1.X on the next turn, so it
+    /// can ask the user to try again instead of answering as if nothing happened.
+    pub ph1x_last_failure_enabled: bool,
+    /// Per-tenant limit on voice turns. `None` leaves turns unlimited.
+    pub tenant_rate_limit: Option<AdapterTenantRateLimitPolicy>,
+    pub tenant_ph1k_feedback_policies: BTreeMap<String, AdapterPh1kFeedbackPolicy>,
+    pub vision_source_kind_policy: AdapterVisionSourceKindPolicy,
+    /// Extra OCR route attempts after a retryable provider error. Once they run out the
+    /// vision stage is skipped like any other vision failure.
+    pub ocr_route_retry_budget: u8,
+    pub builder_ph1k_feedback_enabled: bool,
+    pub storage_commit_retry_limit: u32,
+    pub provenance_mode: VoiceTurnProvenanceMode,
+    pub fallback_tenant_id: String,
+    pub clock_skew_policy: AdapterClockSkewPolicy,
+    pub journal_entry_max_bytes: usize,
+    /// Replay only the newest N legacy journal entries at boot. `None` replays the whole journal.
+    pub journal_replay_max_entries: Option<usize>,
+    /// Write a store snapshot after every N journaled entries. `None` leaves snapshots to
+    /// explicit `write_store_snapshot` calls.
+    pub store_snapshot_every_entries: Option<usize>,
+    pub read_only_incident_cooldown_ns: u64,
+    pub sync_retry_policy: SyncRetryPolicy,
+    pub transcript_close_policy: AdapterTranscriptClosePolicy,
+    pub transcript_retention_policy: AdapterTranscriptRetentionPolicy,
+    /// Privacy scope for transcript rows of turns whose thread policy flags set `privacy_mode`.
+    pub privacy_mode_transcript_scope: PrivacyScope,
+    /// Most saved memories of the actor handed to PH1.X as memory candidates each turn. `0`
+    /// hands none. Turns on a `privacy_mode` thread never get any.
+    pub ph1x_memory_candidate_limit: usize,
+    pub builder_min_outcome_entries: usize,
+    pub provider_circuit_breaker_policy: AdapterProviderCircuitBreakerPolicy,
+    pub onboarding_field_value_max_len: usize,
+    /// Longest idempotency key the adapter writes to the store. Longer keys keep a prefix and
+    /// end in a hash of the full key, so distinct keys stay distinct.
+    pub idempotency_token_max_len: usize,
+    /// Report kind and company scope applied when a health report query omits them.
+    pub default_report_kind: HealthReportKind,
+    pub default_company_scope: HealthCompanyScope,
+    pub health_range_grace: AdapterHealthRangeGrace,
+    pub health_issue_deadlines: AdapterHealthIssueDeadlinePolicy,
+    /// Most synthesized health issues one report carries. Issues over the limit are dropped,
+    /// and a `health_issues_omitted` issue says how many.
+    pub health_issue_synth_limit: usize,
+    /// How long the sync worker may go without a pass before the `SYNC_WORKER_LIVENESS`
+    /// health check turns `AT_RISK`. Twice this turns it `CRITICAL`.
+    pub sync_worker_stale_after_ms: u64,
+    pub eval_csv_flush_policy: AdapterEvalCsvFlushPolicy,
+    /// Fleet tags from `SELENE_TELEMETRY_ENV`, `SELENE_TELEMETRY_REGION` and
+    /// `SELENE_TELEMETRY_DEPLOYMENT_ID`, added to every telemetry row.
+    pub telemetry_tags: AdapterTelemetryTags,
+    /// Client-facing refusal messages from `SELENE_ADAPTER_REFUSAL_MESSAGES`.
+    pub refusal_messages: AdapterRefusalMessages,
+    /// Reply for turns the OS skips because voice is disabled and that carry no text of their
+    /// own.
+    pub voice_disabled_message: String,
+    /// Separator between the tenant scope and the rest of a user id, as in `tenant_a:alice`.
+    pub tenant_delimiter: char,
+    /// Fail the turn when PH1.N fails. Off passes the transcript to PH1.X as plain chat.
+    pub nlp_failure_strict: bool,
+    pub provider_latency_policy: AdapterProviderLatencyPolicy,
+    /// Which triggers must run the wake session open step before the turn.
+    pub wake_gate_policy: AdapterWakeGatePolicy,
+    /// Destination for live PH1.C and PH1.K telemetry. `None` uses a `CsvTelemetrySink`
+    /// with `eval_csv_flush_policy`.
+    pub telemetry_sink: Option<Arc<dyn TelemetrySink>>,
+    pub tenant_access_policy: AdapterTenantAccessPolicy,
+    /// Extra interrupt phrases by locale, merged over the PH1.K built-ins.
+    pub interrupt_lexicon: BTreeMap<String, Vec<String>>,
+    /// Fallback locales, in order, for interrupt locales that have no phrases of their own.
+    pub interrupt_locale_fallbacks: BTreeMap<String, Vec<String>>,
+    pub unknown_app_platform_policy: AdapterUnknownAppPlatformPolicy,
+    /// Models a request may name in `model_override`. Empty rejects every override.
+    pub model_override_allowlist: BTreeSet<String>,
+    pub ph1d_live_adapter: Option<EnvPh1dLiveAdapter>,
+    /// Providers tried, in order, after the live adapter fails with a retryable error. The
+    /// env constructors leave it empty.
+    pub ph1d_live_fallback_providers: Vec<AdapterPh1dFallbackProvider>,
+    /// Most provider calls one live attempt makes across the adapter and its fallbacks.
+    pub ph1d_live_failover_max_attempts: usize,
+    pub runtime_node_id: String,
+    pub session_lease_ttl_ms: u64,
+}
+
+// The values `from_env` yields with no adapter env set, without the default store path: a
+// default config keeps the runtime in memory.
+impl Default for AdapterConfig {
+    fn default() -> Self {
+        Self {
+            journal_path: None,
+            auto_builder_enabled: true,
+            ph1c_live_enabled: true,
+            ph1c_streaming_enabled: true,
+            stt_min_snr_db: None,
+            tenant_feature_flags: BTreeMap::new(),
+            ph1k_feedback_policy: AdapterPh1kFeedbackPolicy::default(),
+            interrupt_confidence_policy: AdapterInterruptConfidencePolicy::default(),
+            ph1x_interruption_enabled: false,
+            ph1x_last_failure_enabled: false,
+            tenant_rate_limit: None,
+            tenant_ph1k_feedback_policies: BTreeMap::new(),
+            vision_source_kind_policy: AdapterVisionSourceKindPolicy::default(),
+            ocr_route_retry_budget: OCR_ROUTE_DEFAULT_RETRY_BUDGET,
+            builder_ph1k_feedback_enabled: false,
+            storage_commit_retry_limit: STORAGE_COMMIT_RETRY_LIMIT_DEFAULT,
+            provenance_mode: VoiceTurnProvenanceMode::default(),
+            fallback_tenant_id: DEFAULT_FALLBACK_TENANT_ID.to_string(),
+            clock_skew_policy: AdapterClockSkewPolicy::default(),
+            journal_entry_max_bytes: JOURNAL_ENTRY_DEFAULT_MAX_BYTES,
+            journal_replay_max_entries: None,
+            store_snapshot_every_entries: None,
+            read_only_incident_cooldown_ns: 0,
+            sync_retry_policy: SyncRetryPolicy::default(),
+            transcript_close_policy: AdapterTranscriptClosePolicy::default(),
+            transcript_retention_policy: AdapterTranscriptRetentionPolicy::default(),
+            privacy_mode_transcript_scope: PrivacyScope::PrivateDelivery,
+            ph1x_memory_candidate_limit: 0,
+            builder_min_outcome_entries: 1,
+            provider_circuit_breaker_policy: AdapterProviderCircuitBreakerPolicy::default(),
+            onboarding_field_value_max_len: ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN,
+            idempotency_token_max_len: IDEMPOTENCY_TOKEN_MAX_LEN_DEFAULT as usize,
+            default_report_kind: HealthReportKind::UnresolvedEscalated,
+            default_company_scope: HealthCompanyScope::TenantOnly,
+            health_range_grace: AdapterHealthRangeGrace::default(),
+            health_issue_deadlines: AdapterHealthIssueDeadlinePolicy::default(),
+            health_issue_synth_limit: HEALTH_ISSUE_SYNTH_DEFAULT_LIMIT as usize,
+            sync_worker_stale_after_ms: SYNC_WORKER_STALE_AFTER_DEFAULT_MS,
+            eval_csv_flush_policy: AdapterEvalCsvFlushPolicy::default(),
+            telemetry_tags: AdapterTelemetryTags::default(),
+            refusal_messages: AdapterRefusalMessages::default(),
+            voice_disabled_message: VOICE_DISABLED_DEFAULT_MESSAGE.to_string(),
+            tenant_delimiter: TENANT_DELIMITER_DEFAULT,
+            nlp_failure_strict: false,
+            provider_latency_policy: AdapterProviderLatencyPolicy::default(),
+            wake_gate_policy: AdapterWakeGatePolicy::default(),
+            telemetry_sink: None,
+            tenant_access_policy: AdapterTenantAccessPolicy::default(),
+            interrupt_lexicon: BTreeMap::new(),
+            interrupt_locale_fallbacks: BTreeMap::new(),
+            unknown_app_platform_policy: AdapterUnknownAppPlatformPolicy::default(),
+            model_override_allowlist: BTreeSet::new(),
+            ph1d_live_adapter: None,
+            ph1d_live_fallback_providers: Vec::new(),
+            ph1d_live_failover_max_attempts: PH1D_LIVE_FAILOVER_DEFAULT_MAX_ATTEMPTS,
+            runtime_node_id: RUNTIME_NODE_ID_DEFAULT.to_string(),
+            session_lease_ttl_ms: SESSION_LEASE_TTL_MS_DEFAULT,
+        }
+    }
+}
+
+impl AdapterConfig {
+    pub fn from_env() -> Self {
+        Self {
+            journal_path: Some(
+                env::var("SELENE_ADAPTER_STORE_PATH")
+                    .ok()
+                    .map(|v| v.trim().to_string())
+                    .filter(|v| !v.is_empty())
+                    .map(PathBuf::from)
+                    .unwrap_or_else(default_adapter_store_path),
+            ),
+            auto_builder_enabled: parse_auto_builder_enabled_from_env(),
+            ph1c_live_enabled: parse_bool_env("SELENE_PH1C_LIVE_ENABLED", true),
+            ph1c_streaming_enabled: parse_bool_env("SELENE_PH1C_STREAMING_ENABLED", true),
+            stt_min_snr_db: parse_stt_min_snr_db_from_env(),
+            tenant_feature_flags: parse_tenant_feature_flags_from_env(),
+            ph1k_feedback_policy: parse_ph1k_feedback_policy_from_env(),
+            interrupt_confidence_policy: parse_interrupt_confidence_policy_from_env(),
+            ph1x_interruption_enabled: parse_bool_env(
+                "SELENE_ADAPTER_PH1X_INTERRUPTION_ENABLED",
+                false,
+            ),
+            ph1x_last_failure_enabled: parse_bool_env(
+                "SELENE_ADAPTER_PH1X_LAST_FAILURE_ENABLED",
+                false,
+            ),
+            tenant_rate_limit: parse_tenant_rate_limit_policy_from_env(),
+            tenant_ph1k_feedback_policies: parse_tenant_ph1k_feedback_policies_from_env(),
+            vision_source_kind_policy: parse_vision_source_kind_policy_from_env(),
+            ocr_route_retry_budget: parse_u32_env("SELENE_ADAPTER_OCR_ROUTE_RETRY_BUDGET", 0, 10)
+                .map(|value| value as u8)
+                .unwrap_or(OCR_ROUTE_DEFAULT_RETRY_BUDGET),
+            builder_ph1k_feedback_enabled: parse_bool_env(
+                "SELENE_ADAPTER_BUILDER_PH1K_FEEDBACK_ENABLED",
+                false,
+            ),
+            storage_commit_retry_limit: parse_u32_env(
+                "SELENE_ADAPTER_STORAGE_COMMIT_RETRY_LIMIT",
+                0,
+                STORAGE_COMMIT_RETRY_LIMIT_MAX,
+            )
+            .unwrap_or(STORAGE_COMMIT_RETRY_LIMIT_DEFAULT),
+            provenance_mode: parse_provenance_mode_from_env(),
+            fallback_tenant_id: fallback_tenant_id_from(
+                env::var("SELENE_ADAPTER_FALLBACK_TENANT").ok().as_deref(),
+            ),
+            clock_skew_policy: parse_clock_skew_policy_from_env(),
+            journal_entry_max_bytes: parse_journal_entry_max_bytes_from_env(),
+            journal_replay_max_entries: parse_journal_replay_max_entries_from_env(),
+            store_snapshot_every_entries: parse_store_snapshot_every_entries_from_env(),
+            read_only_incident_cooldown_ns: parse_read_only_incident_cooldown_ns_from_env(),
+            sync_retry_policy: SyncRetryPolicy::from_env(),
+            transcript_close_policy: parse_transcript_close_policy_from_env(),
+            transcript_retention_policy: parse_transcript_retention_policy_from_env(),
+            privacy_mode_transcript_scope: parse_privacy_mode_transcript_scope_from_env(),
+            ph1x_memory_candidate_limit: parse_u32_env(
+                "SELENE_ADAPTER_PH1X_MEMORY_CANDIDATE_LIMIT",
+                0,
+                PH1X_MEMORY_CANDIDATE_LIMIT_MAX as u32,
+            )
+            .map(|value| value as usize)
+            .unwrap_or(0),
+            builder_min_outcome_entries: parse_builder_min_outcome_entries_from_env(),
+            provider_circuit_breaker_policy: parse_provider_circuit_breaker_policy_from_env(),
+            onboarding_field_value_max_len: parse_onboarding_field_value_max_len_from_env(),
+            idempotency_token_max_len: parse_u32_env(
+                "SELENE_ADAPTER_IDEMPOTENCY_TOKEN_MAX_LEN",
+                IDEMPOTENCY_TOKEN_MAX_LEN_MIN,
+                IDEMPOTENCY_TOKEN_MAX_LEN_DEFAULT,
+            )
+            .unwrap_or(IDEMPOTENCY_TOKEN_MAX_LEN_DEFAULT)
+                as usize,
+            default_report_kind: parse_default_report_kind_from_env(),
+            default_company_scope: parse_default_company_scope_from_env(),
+            health_range_grace: parse_health_range_grace_from_env(),
+            health_issue_deadlines: parse_health_issue_deadline_policy_from_env(),
+            health_issue_synth_limit: parse_health_issue_synth_limit_from_env(),
+            sync_worker_stale_after_ms: parse_u64_env(
+                "SELENE_ADAPTER_SYNC_WORKER_STALE_AFTER_MS",
+                SYNC_WORKER_STALE_AFTER_DEFAULT_MS,
+            ),
+            eval_csv_flush_policy: parse_eval_csv_flush_policy_from_env(),
+            telemetry_tags: AdapterTelemetryTags::from_env(),
+            refusal_messages: AdapterRefusalMessages::parse(
+                &env::var("SELENE_ADAPTER_REFUSAL_MESSAGES").unwrap_or_default(),
+            ),
+            voice_disabled_message: voice_disabled_message_from(
+                env::var("SELENE_ADAPTER_VOICE_DISABLED_MESSAGE")
+                    .ok()
+                    .as_deref(),
+            ),
+            tenant_delimiter: env::var("SELENE_ADAPTER_TENANT_DELIMITER")
+                .ok()
+                .and_then(|raw| parse_tenant_delimiter(&raw))
+                .unwrap_or(TENANT_DELIMITER_DEFAULT),
+            nlp_failure_strict: parse_bool_env("SELENE_ADAPTER_NLP_FAILURE_STRICT", false),
+            provider_latency_policy: AdapterProviderLatencyPolicy::from_env(),
+            wake_gate_policy: build_wake_gate_policy_from_env_var_map(|key| env::var(key).ok())
+                .unwrap_or_else(|err| {
+                    tracing::warn!("ignoring wake gate env: {err}");
+                    AdapterWakeGatePolicy::default()
+                }),
+            telemetry_sink: None,
+            interrupt_lexicon: parse_interrupt_lexicon_from_env(),
+            interrupt_locale_fallbacks: parse_interrupt_locale_fallbacks_from_env(),
+            tenant_access_policy: AdapterTenantAccessPolicy::from_env(),
+            unknown_app_platform_policy: parse_unknown_app_platform_policy_from_env(),
+            model_override_allowlist: parse_model_override_allowlist_from_env(),
+            ph1d_live_adapter: build_ph1d_live_adapter_from_env(),
+            ph1d_live_fallback_providers: Vec::new(),
+            ph1d_live_failover_max_attempts: parse_u32_env(
+                "SELENE_PH1D_LIVE_FAILOVER_MAX_ATTEMPTS",
+                1,
+                16,
+            )
+            .map(|value| value as usize)
+            .unwrap_or(PH1D_LIVE_FAILOVER_DEFAULT_MAX_ATTEMPTS),
+            runtime_node_id: runtime_node_id_from_env(),
+            session_lease_ttl_ms: parse_u64_env(
+                "SELENE_SESSION_LEASE_TTL_MS",
+                SESSION_LEASE_TTL_MS_DEFAULT,
+            ),
+        }
+    }
+}
+
+/// Error returned by the public `AdapterRuntime` methods.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdapterError {
+    /// A runtime lock was poisoned by a panic on another thread. Holds the lock's name, such
+    /// as `store` or `transcript`.
+    LockPoisoned(String),
+    /// A PH1.F store call failed.
+    Storage(StorageError),
+    /// The request, its inputs or the runtime state rejected the call.
+    Validation(String),
+    /// The persistence journal or state file could not be read, written or replayed.
+    Persistence(String),
+    /// The tenant's voice turn rate limit is used up. Nothing was run or journaled.
+    RateLimited { retry_after_ms: u64 },
+}
+
+impl AdapterError {
+    // Bootstrap and compaction failures are persistence errors unless they are already typed.
+    fn persistence(err: impl Into<AdapterError>) -> Self {
+        match err.into() {
+            AdapterError::Validation(detail) => AdapterError::Persistence(detail),
+            other => other,
+        }
+    }
+}
+
+impl std::fmt::Display for AdapterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AdapterError::LockPoisoned(lock) => write!(f, "adapter {lock} lock poisoned"),
+            AdapterError::Storage(err) => write!(f, "{err}"),
+            AdapterError::Validation(detail) | AdapterError::Persistence(detail) => {
+                f.write_str(detail)
+            }
+            AdapterError::RateLimited { retry_after_ms } => {
+                write!(
+                    f,
+                    "tenant rate limit exceeded; retry after {retry_after_ms} ms"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AdapterError {}
+
+impl From<StorageError> for AdapterError {
+    fn from(err: StorageError) -> Self {
+        AdapterError::Storage(err)
+    }
+}
+
+// Validation failures are still built as strings on internal paths.
+impl From<String> for AdapterError {
+    fn from(err: String) -> Self {
+        AdapterError::Validation(err)
+    }
+}
+
+impl From<VoiceTurnIngressError> for AdapterError {
+    fn from(err: VoiceTurnIngressError) -> Self {
+        let reason = err.to_runtime_reason();
+        err.cause
+            .map(|cause| *cause)
+            .unwrap_or(AdapterError::Validation(reason))
+    }
+}
+
+/// Lets callers written against the old `Result<_, String>` API keep using `?`. It gives the
+/// same text as `Display`.
+impl From<AdapterError> for String {
+    fn from(err: AdapterError) -> Self {
+        err.to_string()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AdapterRuntime {
     ingress: AppServerIngressRuntime,
     store: Arc<Mutex<Ph1fStore>>,
     session_retry_cache: Arc<Mutex<BTreeMap<AdapterRetryCacheKey, VoiceTurnAdapterResponse>>>,
+    turn_replay_cache: Arc<Mutex<AdapterTurnReplayCache>>,
     wake_guest_lane_state: Arc<Mutex<BTreeMap<WakeGuestLaneKey, WakeGuestLanePosture>>>,
+    ph1c_detected_locale_by_device: Arc<Mutex<BTreeMap<String, String>>>,
+    read_only_incident_fired_at: Arc<Mutex<BTreeMap<(String, &'static str), u64>>>,
     sync_worker_counters: Arc<Mutex<AdapterSyncWorkerCounters>>,
     improvement_counters: Arc<Mutex<AdapterImprovementCounters>>,
     transcript_state: Arc<Mutex<AdapterTranscriptState>>,
@@ -1786,16 +2631,73 @@ pub struct AdapterRuntime {
     ph1d_provider_transport_evidence_state: Arc<Mutex<AdapterPh1dProviderTransportEvidenceState>>,
     ph1d_provider_error_evidence_state: Arc<Mutex<AdapterPh1dProviderErrorEvidenceState>>,
     public_discourse_state: Arc<Mutex<AdapterPublicDiscourseState>>,
+    ph1x_thread_state_cache: Arc<Mutex<AdapterPh1xThreadStateCache>>,
     public_answer_state: Arc<Mutex<AdapterPublicAnswerState>>,
     active_session_context_state: Arc<Mutex<BTreeMap<String, String>>>,
     weather_context_state: Arc<Mutex<BTreeMap<String, String>>>,
     report_display_target_defaults: Arc<Mutex<BTreeMap<String, String>>>,
+    health_report_snapshot_state: Arc<Mutex<AdapterHealthReportSnapshotState>>,
+    health_subscription_state: Arc<Mutex<AdapterHealthSubscriptionState>>,
+    provider_latency_state: Arc<Mutex<AdapterProviderLatencyState>>,
+    artifact_version_state: Arc<Mutex<AdapterArtifactVersionState>>,
+    unknown_app_platform_counts: Arc<Mutex<BTreeMap<String, u64>>>,
+    ph1x_last_failure_reasons: Arc<Mutex<BTreeMap<(String, String), ReasonCodeId>>>,
     auto_builder_enabled: bool,
     ph1c_live_enabled: bool,
     ph1c_streaming_enabled: bool,
+    stt_min_snr_db: Option<f32>,
+    tenant_feature_flags: BTreeMap<String, AdapterTenantFeatureFlags>,
+    ph1k_feedback_policy: AdapterPh1kFeedbackPolicy,
+    interrupt_confidence_policy: AdapterInterruptConfidencePolicy,
+    ph1x_interruption_enabled: bool,
+    ph1x_last_failure_enabled: bool,
+    tenant_rate_limiter: TenantRateLimiter,
+    tenant_ph1k_feedback_policies: BTreeMap<String, AdapterPh1kFeedbackPolicy>,
+    vision_source_kind_policy: AdapterVisionSourceKindPolicy,
+    ocr_route_retry_budget: u8,
+    builder_ph1k_feedback_enabled: bool,
+    storage_commit_retry_limit: u32,
+    provenance_mode: VoiceTurnProvenanceMode,
+    fallback_tenant_id: String,
+    clock_skew_policy: AdapterClockSkewPolicy,
+    journal_entry_max_bytes: usize,
+    journal_replay_max_entries: Option<usize>,
+    store_snapshot_every_entries: Option<usize>,
+    read_only_incident_cooldown_ns: u64,
+    sync_retry_policy: SyncRetryPolicy,
+    transcript_close_policy: AdapterTranscriptClosePolicy,
+    transcript_retention_policy: AdapterTranscriptRetentionPolicy,
+    privacy_mode_transcript_scope: PrivacyScope,
+    ph1x_memory_candidate_limit: usize,
+    builder_min_outcome_entries: usize,
+    provider_circuit_breaker_policy: AdapterProviderCircuitBreakerPolicy,
+    provider_circuit_state: Arc<Mutex<AdapterProviderCircuitState>>,
+    onboarding_field_value_max_len: usize,
+    idempotency_token_max_len: usize,
+    default_report_kind: HealthReportKind,
+    default_company_scope: HealthCompanyScope,
+    health_range_grace: AdapterHealthRangeGrace,
+    health_issue_deadlines: AdapterHealthIssueDeadlinePolicy,
+    health_issue_synth_limit: usize,
+    sync_worker_stale_after_ms: u64,
+    telemetry_tags: AdapterTelemetryTags,
+    refusal_messages: AdapterRefusalMessages,
+    voice_disabled_message: String,
+    tenant_delimiter: char,
+    nlp_failure_strict: bool,
+    provider_latency_policy: AdapterProviderLatencyPolicy,
+    telemetry_sink: Arc<dyn TelemetrySink>,
+    tenant_access_policy: Arc<Mutex<AdapterTenantAccessPolicy>>,
+    interrupt_matcher: InterruptPhraseMatcher,
+    unknown_app_platform_policy: AdapterUnknownAppPlatformPolicy,
+    model_override_allowlist: BTreeSet<String>,
+    wake_gate_policy: AdapterWakeGatePolicy,
     ph1c_runtime: EnginePh1cRuntime,
+    ph1n_runtime: AdapterPh1nEngineHandle,
     ph1d_runtime: EnginePh1dRuntime,
     ph1d_live_adapter: Option<EnvPh1dLiveAdapter>,
+    ph1d_live_fallback_providers: Vec<AdapterPh1dFallbackProvider>,
+    ph1d_live_failover_max_attempts: usize,
     persistence: Option<AdapterPersistenceRuntime>,
     runtime_node_id: String,
     session_lease_ttl_ms: u64,
@@ -1807,6 +2709,73 @@ struct AdapterRetryCacheKey {
     idempotency_key: String,
 }
 
+const TURN_REPLAY_CACHE_MAX_ENTRIES: usize = 4096;
+
+// Responses of recently finished turns by `(correlation_id, turn_id)`, so a resubmitted turn
+// is answered without running the pipeline again. Only an identical resubmission from the
+// same actor hits: partials, finals and extra parts of a turn still run. The least recently
+// used entry is evicted first.
+#[derive(Debug, Clone)]
+struct AdapterTurnReplayEntry {
+    actor_user_id: UserId,
+    request_fingerprint: String,
+    response: VoiceTurnAdapterResponse,
+}
+
+impl AdapterTurnReplayEntry {
+    fn matches(&self, actor_user_id: &UserId, request_fingerprint: &str) -> bool {
+        self.actor_user_id == *actor_user_id && self.request_fingerprint == request_fingerprint
+    }
+}
+
+// Each entry carries a stamp from a counter. `recency` orders entries by stamp, so a touch or an
+// eviction is a map update instead of a scan.
+#[derive(Debug, Default)]
+struct AdapterTurnReplayCache {
+    responses: BTreeMap<(CorrelationId, TurnId), (u64, AdapterTurnReplayEntry)>,
+    recency: BTreeMap<u64, (CorrelationId, TurnId)>,
+    next_stamp: u64,
+}
+
+impl AdapterTurnReplayCache {
+    fn next_stamp(&mut self) -> u64 {
+        let stamp = self.next_stamp;
+        self.next_stamp = self.next_stamp.wrapping_add(1);
+        stamp
+    }
+
+    fn get(
+        &mut self,
+        key: (CorrelationId, TurnId),
+        actor_user_id: &UserId,
+        request_fingerprint: &str,
+    ) -> Option<VoiceTurnAdapterResponse> {
+        let stamp = self.next_stamp();
+        let (entry_stamp, entry) = self
+            .responses
+            .get_mut(&key)
+            .filter(|(_, entry)| entry.matches(actor_user_id, request_fingerprint))?;
+        self.recency.remove(entry_stamp);
+        *entry_stamp = stamp;
+        self.recency.insert(stamp, key);
+        Some(entry.response.clone())
+    }
+
+    fn insert(&mut self, key: (CorrelationId, TurnId), entry: AdapterTurnReplayEntry) {
+        let stamp = self.next_stamp();
+        if let Some((old_stamp, _)) = self.responses.insert(key, (stamp, entry)) {
+            self.recency.remove(&old_stamp);
+        }
+        self.recency.insert(stamp, key);
+        while self.responses.len() > TURN_REPLAY_CACHE_MAX_ENTRIES {
+            let Some((_, evicted)) = self.recency.pop_first() else {
+                break;
+            };
+            self.responses.remove(&evicted);
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 struct WakeGuestLaneKey {
     actor_user_id: String,
@@ -1829,25 +2798,161 @@ enum WakeGuestLaneTurnDecision {
     AllowPublicSafeGuest,
 }
 
+// v1 lines carry only the request. v2 adds a CRC32 of the serialized request so replay can
+// tell a torn or corrupted line from a valid one.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 struct AdapterJournalEntry {
     schema_version: u8,
     request: VoiceTurnAdapterRequest,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request_crc32: Option<u32>,
 }
 
 impl AdapterJournalEntry {
-    fn v1(request: VoiceTurnAdapterRequest) -> Self {
+    fn v2(mut request: VoiceTurnAdapterRequest) -> Result<Self, String> {
+        request.request_schema_version = VoiceTurnAdapterRequest::SCHEMA_VERSION;
+        let request_crc32 = journal_request_crc32(&request)?;
+        Ok(Self {
+            schema_version: 2,
+            request,
+            request_crc32: Some(request_crc32),
+        })
+    }
+
+    // The v2 checksum is taken over the request bytes as written, so it still verifies when
+    // the writing build's request shape is older than this one.
+    fn decode(line: &str) -> Result<Self, String> {
+        let raw: AdapterJournalLine<'_> =
+            serde_json::from_str(line).map_err(|err| err.to_string())?;
+        if raw.schema_version == 2 {
+            let Some(expected) = raw.request_crc32 else {
+                return Err("v2 journal entry is missing request_crc32".to_string());
+            };
+            let actual = crc32_ieee(raw.request.get().as_bytes());
+            if actual != expected {
+                return Err(format!(
+                    "journal entry checksum mismatch: expected {expected:08x}, computed {actual:08x}"
+                ));
+            }
+        }
+        let request = serde_json::from_str(raw.request.get()).map_err(|err| err.to_string())?;
+        Ok(Self {
+            schema_version: raw.schema_version,
+            request,
+            request_crc32: raw.request_crc32,
+        })
+    }
+}
+
+#[derive(serde::Deserialize)]
+struct AdapterJournalLine<'a> {
+    schema_version: u8,
+    #[serde(borrow)]
+    request: &'a serde_json::value::RawValue,
+    #[serde(default)]
+    request_crc32: Option<u32>,
+}
+
+/// Upgrades a journaled request to the current `VoiceTurnAdapterRequest` shape.
+///
+/// Replay must be migration-stable: a request written by any earlier build migrates to the
+/// request that build executed, and a current request comes back unchanged. A step may only
+/// fill what the older shape could not carry, with the value that build acted on.
+fn migrate_request(entry: AdapterJournalEntry) -> Result<VoiceTurnAdapterRequest, String> {
+    let mut request = entry.request;
+    if request.request_schema_version > VoiceTurnAdapterRequest::SCHEMA_VERSION {
+        return Err(format!(
+            "journal request schema_version={} is newer than this build's {}",
+            request.request_schema_version,
+            VoiceTurnAdapterRequest::SCHEMA_VERSION
+        ));
+    }
+    if request.request_schema_version < 2 {
+        // v1 is every request journaled before versioning. Its later fields are all optional,
+        // and a field missing from the line decodes to `None`, which is how that build ran it.
+        request.request_schema_version = 2;
+    }
+    Ok(request)
+}
+
+fn journal_request_crc32(request: &VoiceTurnAdapterRequest) -> Result<u32, String> {
+    let json = serde_json::to_vec(request)
+        .map_err(|err| format!("failed to encode adapter journal request: {err}"))?;
+    Ok(crc32_ieee(&json))
+}
+
+// Bitwise CRC-32 (IEEE 802.3, reflected). Journal lines are small, so no lookup table.
+fn crc32_ieee(bytes: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in bytes {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// First line of a compacted journal. Replay skips it; it only records when the journal was
+/// compacted and how many entries the compaction kept.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct AdapterJournalSnapshotHeader {
+    schema_version: u8,
+    snapshot: AdapterJournalSnapshot,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct AdapterJournalSnapshot {
+    compacted_at_ns: u64,
+    entry_count: u64,
+}
+
+impl AdapterJournalSnapshotHeader {
+    fn v1(compacted_at_ns: u64, entry_count: u64) -> Self {
         Self {
             schema_version: 1,
-            request,
+            snapshot: AdapterJournalSnapshot {
+                compacted_at_ns,
+                entry_count,
+            },
         }
     }
 }
 
+/// Sidecar copy of the whole store, written next to the journal by `write_store_snapshot`. It
+/// covers the first `journal_len_bytes` of the journal, which must still hash to
+/// `journal_prefix_crc32` when boot loads it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct AdapterStoreSnapshot {
+    schema_version: u8,
+    snapshot_seq: u64,
+    written_at_ns: u64,
+    journal_len_bytes: u64,
+    journal_prefix_crc32: u32,
+    store: Ph1fStore,
+}
+
+const ADAPTER_STORE_SNAPSHOT_SCHEMA_VERSION: u8 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct CompactionReport {
+    pub entries_before: u64,
+    pub entries_after: u64,
+    pub bytes_reclaimed: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct EraseReport {
+    pub ledger_rows_tombstoned: u64,
+    pub partial_events_removed: u64,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 enum AdapterTranscriptRole {
     User,
     Selene,
+    System,
 }
 
 impl AdapterTranscriptRole {
@@ -1855,8 +2960,16 @@ impl AdapterTranscriptRole {
         match self {
             AdapterTranscriptRole::User => "USER",
             AdapterTranscriptRole::Selene => "SELENE",
+            AdapterTranscriptRole::System => "SYSTEM",
         }
     }
+
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        [Self::User, Self::Selene, Self::System]
+            .into_iter()
+            .find(|role| role.as_str().eq_ignore_ascii_case(value))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -1864,6 +2977,8 @@ enum AdapterTranscriptSource {
     Ph1C,
     Ph1Write,
     UiText,
+    SystemNotice,
+    ToolResult,
 }
 
 impl AdapterTranscriptSource {
@@ -1872,8 +2987,22 @@ impl AdapterTranscriptSource {
             AdapterTranscriptSource::Ph1C => "PH1.C",
             AdapterTranscriptSource::Ph1Write => "PH1.WRITE",
             AdapterTranscriptSource::UiText => "UI.TEXT",
+            AdapterTranscriptSource::SystemNotice => "SYSTEM.NOTICE",
+            AdapterTranscriptSource::ToolResult => "TOOL.RESULT",
         }
     }
+
+    fn parse(value: &str) -> Option<Self> {
+        [
+            Self::Ph1C,
+            Self::Ph1Write,
+            Self::UiText,
+            Self::SystemNotice,
+            Self::ToolResult,
+        ]
+        .into_iter()
+        .find(|source| source.as_str() == value)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1881,6 +3010,9 @@ struct AdapterTranscriptEvent {
     seq: u64,
     correlation_id: CorrelationId,
     turn_id: TurnId,
+    session_id: Option<SessionId>,
+    user_id: UserId,
+    privacy_scope: PrivacyScope,
     role: AdapterTranscriptRole,
     source: AdapterTranscriptSource,
     finalized: bool,
@@ -1907,6 +3039,249 @@ struct AdapterTranscriptKey {
     source: AdapterTranscriptSource,
 }
 
+// Position of a message in the merged transcript order. Source is the last tie-break, so
+// two messages of one turn and role from different sources never share a cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct AdapterTranscriptCursor {
+    timestamp_ns: u64,
+    correlation_id: u128,
+    turn_id: u64,
+    role: AdapterTranscriptRole,
+    source: AdapterTranscriptSource,
+}
+
+impl AdapterTranscriptCursor {
+    fn for_event(event: &AdapterTranscriptEvent) -> Self {
+        Self {
+            timestamp_ns: event.timestamp_ns,
+            correlation_id: event.correlation_id.0,
+            turn_id: event.turn_id.0,
+            role: event.role,
+            source: event.source,
+        }
+    }
+
+    fn encode(&self) -> String {
+        format!(
+            "after:{}:{}:{}:{}:{}",
+            self.timestamp_ns,
+            self.correlation_id,
+            self.turn_id,
+            self.role.as_str(),
+            self.source.as_str()
+        )
+    }
+}
+
+fn parse_transcript_cursor(raw: &str) -> Result<AdapterTranscriptCursor, String> {
+    let invalid = || "invalid transcript cursor".to_string();
+    let mut parts = raw.trim().split(':');
+    if parts.next() != Some("after") {
+        return Err(invalid());
+    }
+    let (Some(timestamp_ns), Some(correlation_id), Some(turn_id), Some(role), Some(source), None) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return Err(invalid());
+    };
+    Ok(AdapterTranscriptCursor {
+        timestamp_ns: timestamp_ns.parse().map_err(|_| invalid())?,
+        correlation_id: correlation_id.parse().map_err(|_| invalid())?,
+        turn_id: turn_id.parse().map_err(|_| invalid())?,
+        role: AdapterTranscriptRole::parse(role).ok_or_else(invalid)?,
+        source: AdapterTranscriptSource::parse(source).ok_or_else(invalid)?,
+    })
+}
+
+/// What happens to a closing session's outstanding partial transcripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdapterTranscriptClosePolicy {
+    #[default]
+    Discard,
+    FinalizeLast,
+}
+
+impl AdapterTranscriptClosePolicy {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_uppercase().as_str() {
+            "DISCARD" => Some(AdapterTranscriptClosePolicy::Discard),
+            "FINALIZE_LAST" => Some(AdapterTranscriptClosePolicy::FinalizeLast),
+            _ => None,
+        }
+    }
+}
+
+const TRANSCRIPT_RETENTION_DEFAULT_MAX_EVENTS: usize = 4096;
+
+/// How many partial transcript events the adapter keeps in memory and for how long.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterTranscriptRetentionPolicy {
+    max_events: usize,
+    /// Partials older than this are dropped by `evict_expired_transcript_partials` and go
+    /// first when the buffer is full. `None` keeps them until capacity forces them out.
+    max_age_ns: Option<u64>,
+}
+
+impl Default for AdapterTranscriptRetentionPolicy {
+    fn default() -> Self {
+        Self {
+            max_events: TRANSCRIPT_RETENTION_DEFAULT_MAX_EVENTS,
+            max_age_ns: None,
+        }
+    }
+}
+
+impl AdapterTranscriptRetentionPolicy {
+    pub fn new(max_events: usize, max_age_ns: Option<u64>) -> Self {
+        Self {
+            max_events: max_events.max(1),
+            max_age_ns: max_age_ns.filter(|age| *age > 0),
+        }
+    }
+
+    fn expired(&self, event: &AdapterTranscriptEvent, now_ns: u64) -> bool {
+        self.max_age_ns
+            .is_some_and(|max_age_ns| now_ns.saturating_sub(event.timestamp_ns) > max_age_ns)
+    }
+
+    // Over capacity, finalized events go first, then expired ones, then partials a newer
+    // partial of the same turn and speaker has replaced. The newest partial per key only goes
+    // when nothing else is left, oldest first.
+    fn enforce_capacity(&self, events: &mut Vec<AdapterTranscriptEvent>, now_ns: u64) {
+        let excess = events.len().saturating_sub(self.max_events);
+        if excess == 0 {
+            return;
+        }
+        let mut newest_by_key: BTreeMap<AdapterTranscriptKey, u64> = BTreeMap::new();
+        for event in events.iter() {
+            let newest = newest_by_key.entry(event.key()).or_insert(event.seq);
+            *newest = (*newest).max(event.seq);
+        }
+        let mut ranked = events
+            .iter()
+            .map(|event| {
+                let tier = if event.finalized {
+                    0u8
+                } else if self.expired(event, now_ns) {
+                    1
+                } else if newest_by_key.get(&event.key()) != Some(&event.seq) {
+                    2
+                } else {
+                    3
+                };
+                (tier, event.seq)
+            })
+            .collect::<Vec<_>>();
+        ranked.sort_unstable();
+        let evicted = ranked
+            .into_iter()
+            .take(excess)
+            .map(|(_, seq)| seq)
+            .collect::<BTreeSet<_>>();
+        events.retain(|event| !evicted.contains(&event.seq));
+    }
+}
+
+/// Token-bucket limit on voice turns per tenant scope. Each tenant holds up to `burst`
+/// turns and earns `refill_per_sec` back every second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterTenantRateLimitPolicy {
+    refill_per_sec: u32,
+    burst: u32,
+}
+
+impl AdapterTenantRateLimitPolicy {
+    pub fn new(refill_per_sec: u32, burst: u32) -> Self {
+        Self {
+            refill_per_sec: refill_per_sec.max(1),
+            burst: burst.max(1),
+        }
+    }
+}
+
+// Tokens are counted in thousandths so slow refill rates still accrue between turns.
+const TENANT_RATE_TOKEN_MILLI: u64 = 1_000;
+
+#[derive(Debug, Clone, Copy)]
+struct TenantRateBucket {
+    tokens_milli: u64,
+    refilled_at_ns: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct TenantRateLimiter {
+    policy: Option<AdapterTenantRateLimitPolicy>,
+    buckets: Arc<Mutex<BTreeMap<String, TenantRateBucket>>>,
+}
+
+impl TenantRateLimiter {
+    fn new(policy: Option<AdapterTenantRateLimitPolicy>) -> Self {
+        Self {
+            policy,
+            buckets: Arc::new(Mutex::new(BTreeMap::new())),
+        }
+    }
+
+    // Takes one turn from the tenant's bucket, or says how long until one is available.
+    fn try_acquire(&self, tenant_scope: &str, now_ns: u64) -> Result<(), AdapterError> {
+        let Some(policy) = self.policy else {
+            return Ok(());
+        };
+        let capacity = u64::from(policy.burst) * TENANT_RATE_TOKEN_MILLI;
+        let mut buckets = self
+            .buckets
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("tenant rate limit".to_string()))?;
+        let bucket = buckets
+            .entry(tenant_scope.to_string())
+            .or_insert(TenantRateBucket {
+                tokens_milli: capacity,
+                refilled_at_ns: now_ns,
+            });
+        let elapsed_ns = now_ns.saturating_sub(bucket.refilled_at_ns);
+        let earned = u128::from(elapsed_ns) * u128::from(policy.refill_per_sec) / 1_000_000;
+        bucket.tokens_milli = u64::try_from(u128::from(bucket.tokens_milli) + earned)
+            .unwrap_or(u64::MAX)
+            .min(capacity);
+        bucket.refilled_at_ns = bucket.refilled_at_ns.max(now_ns);
+        if bucket.tokens_milli >= TENANT_RATE_TOKEN_MILLI {
+            bucket.tokens_milli -= TENANT_RATE_TOKEN_MILLI;
+            return Ok(());
+        }
+        // `refill_per_sec` tokens a second is `refill_per_sec` thousandths a millisecond.
+        let missing = TENANT_RATE_TOKEN_MILLI - bucket.tokens_milli;
+        Err(AdapterError::RateLimited {
+            retry_after_ms: missing.div_ceil(u64::from(policy.refill_per_sec)),
+        })
+    }
+}
+
+/// What happens to a voice turn whose `app_platform` is not one the adapter knows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdapterUnknownAppPlatformPolicy {
+    #[default]
+    Reject,
+    /// Run the turn as this platform, with its default device class and embedding-gate
+    /// profile, and count the raw value.
+    MapTo(AppPlatform),
+}
+
+impl AdapterUnknownAppPlatformPolicy {
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_uppercase().as_str() {
+            "REJECT" => Some(AdapterUnknownAppPlatformPolicy::Reject),
+            other => parse_app_platform(other)
+                .ok()
+                .map(AdapterUnknownAppPlatformPolicy::MapTo),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct AdapterTranscriptState {
     next_seq: u64,
@@ -1953,6 +3328,478 @@ struct AdapterPublicAnswerState {
     recent_jokes_by_scope: BTreeMap<String, Vec<String>>,
 }
 
+const PH1X_THREAD_STATE_CACHE_CAPACITY: usize = 256;
+
+// Write-through LRU over the PH1.X thread-state rows in `Ph1fStore`. Slots form a list linked
+// most-recent-first and `index` maps a key to its slot, so a hit, an insert and an eviction are
+// all O(1). The store ledger is append-only, so a ledger length other than `synced_ledger_len`
+// means some write bypassed the cache, and every entry is dropped. A fresh runtime starts empty
+// and reads the store.
+#[derive(Debug)]
+struct AdapterPh1xThreadStateCache {
+    capacity: usize,
+    index: HashMap<(UserId, String), usize>,
+    slots: Vec<Ph1xThreadStateCacheSlot>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    synced_ledger_len: usize,
+}
+
+#[derive(Debug)]
+struct Ph1xThreadStateCacheSlot {
+    key: (UserId, String),
+    thread_state: ThreadState,
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+impl Default for AdapterPh1xThreadStateCache {
+    fn default() -> Self {
+        Self::with_capacity(PH1X_THREAD_STATE_CACHE_CAPACITY)
+    }
+}
+
+impl AdapterPh1xThreadStateCache {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            index: HashMap::new(),
+            slots: Vec::new(),
+            head: None,
+            tail: None,
+            synced_ledger_len: 0,
+        }
+    }
+
+    fn sync_with_store(&mut self, ledger_len: usize) {
+        if ledger_len != self.synced_ledger_len {
+            self.index.clear();
+            self.slots.clear();
+            self.head = None;
+            self.tail = None;
+            self.synced_ledger_len = ledger_len;
+        }
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let (prev, next) = (self.slots[slot].prev, self.slots[slot].next);
+        match prev {
+            Some(prev) => self.slots[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.slots[next].prev = prev,
+            None => self.tail = prev,
+        }
+        self.slots[slot].prev = None;
+        self.slots[slot].next = None;
+    }
+
+    fn push_front(&mut self, slot: usize) {
+        self.slots[slot].next = self.head;
+        if let Some(head) = self.head {
+            self.slots[head].prev = Some(slot);
+        }
+        self.head = Some(slot);
+        if self.tail.is_none() {
+            self.tail = Some(slot);
+        }
+    }
+
+    fn get(&mut self, actor_user_id: &UserId, thread_key: &str) -> Option<ThreadState> {
+        let slot = *self
+            .index
+            .get(&(actor_user_id.clone(), thread_key.to_string()))?;
+        self.unlink(slot);
+        self.push_front(slot);
+        Some(self.slots[slot].thread_state.clone())
+    }
+
+    fn insert(&mut self, actor_user_id: &UserId, thread_key: &str, thread_state: ThreadState) {
+        let key = (actor_user_id.clone(), thread_key.to_string());
+        if let Some(&slot) = self.index.get(&key) {
+            self.slots[slot].thread_state = thread_state;
+            self.unlink(slot);
+            self.push_front(slot);
+            return;
+        }
+        let slot = match self.tail {
+            // Full: the least recently used slot is reused for the new key.
+            Some(tail) if self.slots.len() >= self.capacity => {
+                self.unlink(tail);
+                let evicted = std::mem::replace(&mut self.slots[tail].key, key.clone());
+                self.index.remove(&evicted);
+                self.slots[tail].thread_state = thread_state;
+                tail
+            }
+            _ => {
+                self.slots.push(Ph1xThreadStateCacheSlot {
+                    key: key.clone(),
+                    thread_state,
+                    prev: None,
+                    next: None,
+                });
+                self.slots.len() - 1
+            }
+        };
+        self.index.insert(key, slot);
+        self.push_front(slot);
+    }
+
+    fn remove(&mut self, actor_user_id: &UserId, thread_key: &str) {
+        let Some(slot) = self
+            .index
+            .remove(&(actor_user_id.clone(), thread_key.to_string()))
+        else {
+            return;
+        };
+        self.unlink(slot);
+        let last = self.slots.len() - 1;
+        if slot != last {
+            // Move the last slot into the hole and repoint its neighbours.
+            self.slots.swap(slot, last);
+            let (prev, next) = (self.slots[slot].prev, self.slots[slot].next);
+            match prev {
+                Some(prev) => self.slots[prev].next = Some(slot),
+                None => self.head = Some(slot),
+            }
+            match next {
+                Some(next) => self.slots[next].prev = Some(slot),
+                None => self.tail = Some(slot),
+            }
+            self.index.insert(self.slots[slot].key.clone(), slot);
+        }
+        self.slots.pop();
+    }
+
+    // `before`/`after` are the store ledger lengths around one upsert. Only an upsert that
+    // appended exactly one row is known to hold `thread_state`; an idempotent replay keeps
+    // the earlier row, so that entry is dropped instead.
+    fn record_write(
+        &mut self,
+        actor_user_id: &UserId,
+        thread_key: &str,
+        thread_state: ThreadState,
+        before: usize,
+        after: usize,
+    ) {
+        self.sync_with_store(before);
+        if after == before.saturating_add(1) {
+            self.insert(actor_user_id, thread_key, thread_state);
+        } else {
+            self.remove(actor_user_id, thread_key);
+        }
+        self.synced_ledger_len = after;
+    }
+}
+
+// Where the thread-state cache reads through to. `Ph1fStore` is the only production source;
+// tests wrap it to count the reads that get past the cache.
+trait Ph1xThreadStateSource {
+    fn ph1x_thread_state_ledger_len(&self) -> usize;
+
+    fn ph1x_thread_state(&self, actor_user_id: &UserId, thread_key: &str) -> ThreadState;
+}
+
+impl Ph1xThreadStateSource for Ph1fStore {
+    fn ph1x_thread_state_ledger_len(&self) -> usize {
+        self.ph1x_thread_state_ledger_rows().len()
+    }
+
+    fn ph1x_thread_state(&self, actor_user_id: &UserId, thread_key: &str) -> ThreadState {
+        load_ph1x_thread_state(self, actor_user_id, thread_key)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct AdapterHealthReportSnapshotState {
+    rows_by_revision: BTreeMap<(String, u64), Vec<UiHealthReportRow>>,
+}
+
+#[derive(Debug, Default)]
+struct AdapterHealthSubscriptionState {
+    subscribers: Vec<SyncSender<AdapterHealthResponse>>,
+    last_published_sync: Option<AdapterHealthSubscriptionMark>,
+    last_published_at_ns: Option<u64>,
+}
+
+// The parts of a sync health snapshot that count as a material change for subscribers;
+// pass_count and last_pass_at_ns move on every pass and are left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct AdapterHealthSubscriptionMark {
+    dequeued_total: u64,
+    acked_total: u64,
+    retry_scheduled_total: u64,
+    dead_lettered_total: u64,
+    queue: AdapterSyncQueueCounters,
+}
+
+#[derive(Debug, Clone, Default)]
+struct AdapterProviderLatencyState {
+    recent_latency_ms: VecDeque<u32>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct AdapterArtifactVersionState {
+    next_version_by_scope: BTreeMap<(String, ArtifactType), u32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdapterTenantFeatureFlags {
+    ph1c_live_enabled: Option<bool>,
+    ph1c_streaming_enabled: Option<bool>,
+    auto_builder_enabled: Option<bool>,
+}
+
+impl AdapterTenantFeatureFlags {
+    /// `None` leaves the runtime-wide setting in force for the tenant.
+    pub fn new(
+        ph1c_live_enabled: Option<bool>,
+        ph1c_streaming_enabled: Option<bool>,
+        auto_builder_enabled: Option<bool>,
+    ) -> Self {
+        Self {
+            ph1c_live_enabled,
+            ph1c_streaming_enabled,
+            auto_builder_enabled,
+        }
+    }
+}
+
+// Tenant gate consulted before any engine runs. The denylist always wins; when an
+// allowlist is configured, tenants absent from it are blocked as well.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdapterTenantAccessPolicy {
+    pub allowlist: Option<BTreeSet<String>>,
+    pub denylist: BTreeSet<String>,
+}
+
+impl AdapterTenantAccessPolicy {
+    pub fn from_env() -> Self {
+        Self::parse(
+            env::var("SELENE_ADAPTER_TENANT_ALLOWLIST").ok().as_deref(),
+            env::var("SELENE_ADAPTER_TENANT_DENYLIST").ok().as_deref(),
+        )
+    }
+
+    pub fn parse(allowlist: Option<&str>, denylist: Option<&str>) -> Self {
+        let allowlist = allowlist
+            .map(parse_tenant_id_list)
+            .filter(|tenants| !tenants.is_empty());
+        let denylist = denylist.map(parse_tenant_id_list).unwrap_or_default();
+        Self {
+            allowlist,
+            denylist,
+        }
+    }
+
+    fn blocked_reason(&self, tenant_id: Option<&str>) -> Option<&'static str> {
+        let tenant = tenant_id.map(str::trim).filter(|tenant| !tenant.is_empty());
+        if tenant.is_some_and(|tenant| self.denylist.contains(tenant)) {
+            return Some("tenant_denylisted");
+        }
+        match (&self.allowlist, tenant) {
+            (Some(allowlist), Some(tenant)) if allowlist.contains(tenant) => None,
+            (Some(_), _) => Some("tenant_not_allowlisted"),
+            (None, _) => None,
+        }
+    }
+}
+
+// Gates which PH1.K interrupt feedback signals the adapter derives from a live turn.
+// `min_confidence_delta` is the distance the phrase confidence must keep from the
+// interrupt phrase threshold; signals closer to the threshold are too ambiguous to emit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdapterPh1kFeedbackPolicy {
+    false_lexical_trigger_enabled: bool,
+    missed_lexical_trigger_enabled: bool,
+    wrong_confidence_band_enabled: bool,
+    min_confidence_delta: f32,
+}
+
+impl Default for AdapterPh1kFeedbackPolicy {
+    fn default() -> Self {
+        Self {
+            false_lexical_trigger_enabled: true,
+            missed_lexical_trigger_enabled: true,
+            wrong_confidence_band_enabled: true,
+            min_confidence_delta: 0.0,
+        }
+    }
+}
+
+impl AdapterPh1kFeedbackPolicy {
+    /// `min_confidence_delta` is clamped to `0.0..=1.0`.
+    pub fn new(
+        false_lexical_trigger_enabled: bool,
+        missed_lexical_trigger_enabled: bool,
+        wrong_confidence_band_enabled: bool,
+        min_confidence_delta: f32,
+    ) -> Self {
+        Self {
+            false_lexical_trigger_enabled,
+            missed_lexical_trigger_enabled,
+            wrong_confidence_band_enabled,
+            min_confidence_delta: min_confidence_delta.clamp(0.0, 1.0),
+        }
+    }
+
+    fn signal_kind_enabled(&self, kind: InterruptFeedbackSignalKind) -> bool {
+        match kind {
+            InterruptFeedbackSignalKind::FalseLexicalTrigger => self.false_lexical_trigger_enabled,
+            InterruptFeedbackSignalKind::MissedLexicalTrigger => {
+                self.missed_lexical_trigger_enabled
+            }
+            InterruptFeedbackSignalKind::WrongConfidenceBand => self.wrong_confidence_band_enabled,
+        }
+    }
+}
+
+// Minimum phrase confidence an interrupt candidate needs to be accepted, by TTS state.
+// Applied on top of PH1.K's own gates, so it can only reject candidates the engine emitted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdapterInterruptConfidencePolicy {
+    tts_playing_min_confidence: f32,
+    tts_stopped_min_confidence: f32,
+}
+
+impl Default for AdapterInterruptConfidencePolicy {
+    fn default() -> Self {
+        Self {
+            tts_playing_min_confidence: DEFAULT_MIN_INTERRUPT_PHRASE_CONFIDENCE,
+            tts_stopped_min_confidence: DEFAULT_MIN_INTERRUPT_PHRASE_CONFIDENCE,
+        }
+    }
+}
+
+impl AdapterInterruptConfidencePolicy {
+    /// Confidences are clamped to `0.0..=1.0`; a non-finite value keeps the default.
+    pub fn new(tts_playing_min_confidence: f32, tts_stopped_min_confidence: f32) -> Self {
+        let clamp = |confidence: f32| {
+            if confidence.is_finite() {
+                confidence.clamp(0.0, 1.0)
+            } else {
+                DEFAULT_MIN_INTERRUPT_PHRASE_CONFIDENCE
+            }
+        };
+        Self {
+            tts_playing_min_confidence: clamp(tts_playing_min_confidence),
+            tts_stopped_min_confidence: clamp(tts_stopped_min_confidence),
+        }
+    }
+
+    fn min_confidence(&self, tts_playback_active: bool) -> f32 {
+        if tts_playback_active {
+            self.tts_playing_min_confidence
+        } else {
+            self.tts_stopped_min_confidence
+        }
+    }
+}
+
+// Deployment-level PH1.VISION opt-in per source kind. A visual input is only processed
+// when both the turn's own opt-in and the policy for its source kind allow it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterVisionSourceKindPolicy {
+    image_enabled: bool,
+    screenshot_enabled: bool,
+    diagram_enabled: bool,
+}
+
+impl Default for AdapterVisionSourceKindPolicy {
+    fn default() -> Self {
+        Self {
+            image_enabled: true,
+            screenshot_enabled: true,
+            diagram_enabled: true,
+        }
+    }
+}
+
+impl AdapterVisionSourceKindPolicy {
+    pub fn new(image_enabled: bool, screenshot_enabled: bool, diagram_enabled: bool) -> Self {
+        Self {
+            image_enabled,
+            screenshot_enabled,
+            diagram_enabled,
+        }
+    }
+
+    fn allows(&self, kind: VisualSourceKind) -> bool {
+        match kind {
+            VisualSourceKind::Image => self.image_enabled,
+            VisualSourceKind::Screenshot => self.screenshot_enabled,
+            VisualSourceKind::Diagram => self.diagram_enabled,
+        }
+    }
+}
+
+/// What the clock skew check does with a `now_ns` outside the tolerance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdapterClockSkewAction {
+    Off,
+    Clamp,
+    Reject,
+}
+
+// Sanity check for client-supplied `now_ns` against the system clock. Off by default because
+// clients and replays drive turns with synthetic clocks; `deterministic_time` keeps that
+// working when a deployment enables the check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterClockSkewPolicy {
+    action: AdapterClockSkewAction,
+    tolerance_ns: u64,
+    deterministic_time: bool,
+}
+
+impl Default for AdapterClockSkewPolicy {
+    fn default() -> Self {
+        Self {
+            action: AdapterClockSkewAction::Off,
+            tolerance_ns: CLOCK_SKEW_DEFAULT_TOLERANCE_MS.saturating_mul(1_000_000),
+            deterministic_time: false,
+        }
+    }
+}
+
+impl AdapterClockSkewPolicy {
+    pub fn new(
+        action: AdapterClockSkewAction,
+        tolerance_ms: u64,
+        deterministic_time: bool,
+    ) -> Self {
+        Self {
+            action,
+            tolerance_ns: tolerance_ms.saturating_mul(1_000_000),
+            deterministic_time,
+        }
+    }
+
+    fn resolve_now_ns(&self, supplied_ns: u64, system_now_ns: u64) -> Result<u64, String> {
+        if self.action == AdapterClockSkewAction::Off || self.deterministic_time {
+            return Ok(supplied_ns);
+        }
+        let earliest_ns = system_now_ns.saturating_sub(self.tolerance_ns).max(1);
+        let latest_ns = system_now_ns.saturating_add(self.tolerance_ns);
+        if (earliest_ns..=latest_ns).contains(&supplied_ns) {
+            return Ok(supplied_ns);
+        }
+        let skew_ms = supplied_ns.abs_diff(system_now_ns) / 1_000_000;
+        match self.action {
+            AdapterClockSkewAction::Reject => Err(format!(
+                "now_ns skewed from system clock by {skew_ms}ms; tolerance is {}ms",
+                self.tolerance_ns / 1_000_000
+            )),
+            _ => {
+                tracing::warn!(
+                    "now_ns skewed from system clock by {skew_ms}ms; clamping to tolerance"
+                );
+                Ok(supplied_ns.clamp(earliest_ns, latest_ns))
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct AdapterPatternEngineRuntime {
     runtime: Ph1PatternRuntime,
@@ -2048,6 +3895,22 @@ impl Ph1nEngine for AdapterNlpEngineRuntime {
     }
 }
 
+// Shared PH1.N engine used to build the PH1.X input for voice turns.
+#[derive(Clone)]
+struct AdapterPh1nEngineHandle(Arc<dyn Ph1nEngine + Send + Sync>);
+
+impl Default for AdapterPh1nEngineHandle {
+    fn default() -> Self {
+        Self(Arc::new(AdapterNlpEngineRuntime::new()))
+    }
+}
+
+impl std::fmt::Debug for AdapterPh1nEngineHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AdapterPh1nEngineHandle")
+    }
+}
+
 #[derive(Debug, Clone)]
 struct AdapterLangEngineRuntime {
     runtime: EnginePh1LangRuntime,
@@ -2101,7 +3964,8 @@ struct SyncIssueRecord {
     attempt_count: u16,
     last_error: Option<String>,
     user_id: Option<UserId>,
-    device_id: DeviceId,
+    // `None` marks a queue-wide aggregate issue that belongs to no device.
+    device_id: Option<DeviceId>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -3617,7 +5481,7 @@ fn h380_detects_repeat(lower: &str) -> bool {
 }
 
 fn h380_detects_correction(lower: &str, original: &str) -> bool {
-    user_text_looks_like_correction(original)
+    user_text_looks_like_correction(original, None)
         || lower.starts_with("no ")
         || lower == "no"
         || lower.contains("not my question")
@@ -4577,6 +6441,54 @@ struct Ph1cLiveTurnOutcomeSummary {
     provider_call_trace: Vec<Ph1dProviderCallResponse>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VoiceTurnSttMode {
+    Live,
+    Upstream,
+    SkippedDisabled,
+    SkippedNoAdapter,
+    SkippedQualityGate,
+    SkippedLowSnr,
+    SkippedCircuitOpen,
+}
+
+impl VoiceTurnSttMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            VoiceTurnSttMode::Live => "LIVE",
+            VoiceTurnSttMode::Upstream => "UPSTREAM",
+            VoiceTurnSttMode::SkippedDisabled => "SKIPPED_DISABLED",
+            VoiceTurnSttMode::SkippedNoAdapter => "SKIPPED_NO_ADAPTER",
+            VoiceTurnSttMode::SkippedQualityGate => "SKIPPED_QUALITY_GATE",
+            VoiceTurnSttMode::SkippedLowSnr => "SKIPPED_LOW_SNR",
+            VoiceTurnSttMode::SkippedCircuitOpen => "SKIPPED_CIRCUIT_OPEN",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VoiceTurnProvenanceMode {
+    None,
+    #[default]
+    Structured,
+    Inline,
+}
+
+impl VoiceTurnProvenanceMode {
+    fn parse(value: &str) -> Result<Self, String> {
+        let normalized = value.trim().to_ascii_uppercase();
+        match normalized.as_str() {
+            "NONE" => Ok(VoiceTurnProvenanceMode::None),
+            "STRUCTURED" => Ok(VoiceTurnProvenanceMode::Structured),
+            "INLINE" => Ok(VoiceTurnProvenanceMode::Inline),
+            _ => Err(format!(
+                "invalid provenance_mode '{}'; expected NONE|STRUCTURED|INLINE",
+                value
+            )),
+        }
+    }
+}
+
 const SLICE3C_APPROVED_PH1D_MODEL_ID: &str = "gpt-5.5";
 const PH1D_PROVIDER_TRANSPORT_EVIDENCE_MAX_ROWS: usize = 20;
 const PH1D_PROVIDER_ERROR_EVIDENCE_MAX_ROWS: usize = 20;
@@ -4584,13 +6496,16 @@ const PH1D_PROVIDER_ERROR_BODY_MAX_CHARS: usize = 4096;
 const PH1D_PROVIDER_ERROR_MESSAGE_MAX_CHARS: usize = 240;
 const PH1D_CURL_HTTP_STATUS_MARKER: &str = "\nSELENE_HTTP_STATUS:";
 
+/// The PH1.D live adapter for the OpenAI Responses API. `AdapterConfig::from_env` builds it from
+/// `SELENE_PH1D_LIVE_*` and `OPENAI_*`; embedders can build one with `new`.
 #[derive(Clone)]
-struct EnvPh1dLiveAdapter {
+pub struct EnvPh1dLiveAdapter {
     provider_id: String,
     model_id: String,
     endpoint: String,
     api_key: String,
     timeout_ms: u32,
+    model_override_allowlist: BTreeSet<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -4615,11 +6530,50 @@ impl std::fmt::Debug for EnvPh1dLiveAdapter {
             .field("endpoint", &self.endpoint)
             .field("api_key", &"<redacted>")
             .field("timeout_ms", &self.timeout_ms)
+            .field("model_override_allowlist", &self.model_override_allowlist)
             .finish()
     }
 }
 
 impl EnvPh1dLiveAdapter {
+    /// `provider_id` must be `openai` or `openai_primary`, and `timeout_ms` must be in
+    /// `100..=120000`. Ids are trimmed and truncated like the env values.
+    pub fn new(
+        provider_id: &str,
+        model_id: &str,
+        endpoint: &str,
+        api_key: &str,
+        timeout_ms: u32,
+    ) -> Result<Self, AdapterError> {
+        let provider_id = truncate_ascii(provider_id.trim(), 64);
+        if provider_id != "openai" && provider_id != "openai_primary" {
+            return Err(AdapterError::Validation(format!(
+                "unsupported ph1d live provider_id={provider_id}"
+            )));
+        }
+        let model_id = truncate_ascii(model_id.trim(), 128);
+        let endpoint = truncate_ascii(endpoint.trim(), 256);
+        let api_key = api_key.trim().to_string();
+        if model_id.is_empty() || endpoint.is_empty() || api_key.is_empty() {
+            return Err(AdapterError::Validation(
+                "ph1d live adapter needs a model_id, endpoint and api_key".to_string(),
+            ));
+        }
+        if !(100..=120_000).contains(&timeout_ms) {
+            return Err(AdapterError::Validation(format!(
+                "ph1d live adapter timeout_ms={timeout_ms} is outside 100..=120000"
+            )));
+        }
+        Ok(Self {
+            provider_id,
+            model_id,
+            endpoint,
+            api_key,
+            timeout_ms,
+            model_override_allowlist: BTreeSet::new(),
+        })
+    }
+
     fn from_env() -> Result<Self, String> {
         let provider_id = env::var("SELENE_PH1D_LIVE_PROVIDER_ID")
             .ok()
@@ -4657,13 +6611,37 @@ impl EnvPh1dLiveAdapter {
             .and_then(|value| value.trim().parse::<u32>().ok())
             .filter(|value| (100..=120_000).contains(value))
             .unwrap_or(30_000);
-        Ok(Self {
-            provider_id,
-            model_id,
-            endpoint,
-            api_key,
-            timeout_ms,
-        })
+        Self::new(&provider_id, &model_id, &endpoint, &api_key, timeout_ms)
+            .map_err(|err| err.to_string())
+    }
+
+    /// Models a request may name instead of this adapter's `model_id`. Requests for any other
+    /// model are refused before they reach the provider.
+    pub fn with_model_override_allowlist(mut self, allowlist: BTreeSet<String>) -> Self {
+        self.model_override_allowlist = allowlist;
+        self
+    }
+
+    fn check_request_model_id(&self, request: &Ph1dProviderCallRequest) -> Result<(), String> {
+        if request.model_id == self.model_id
+            || self.model_override_allowlist.contains(&request.model_id)
+        {
+            return Ok(());
+        }
+        Err("adapter model_id does not match PH1.D request".to_string())
+    }
+
+    // The model transport evidence must show. Without an override that is the approved model;
+    // an override counts only once this adapter's allowlist has it.
+    fn approved_model_id<'a>(&self, model_override: Option<&'a str>) -> Result<&'a str, String> {
+        match model_override {
+            None => Ok(SLICE3C_APPROVED_PH1D_MODEL_ID),
+            Some(model) if self.model_override_allowlist.contains(model) => Ok(model),
+            Some(model) => Err(format!(
+                "model_override '{}' is not allowlisted",
+                truncate_ascii(model, 128)
+            )),
+        }
     }
 
     #[allow(dead_code)]
@@ -4680,6 +6658,8 @@ impl EnvPh1dLiveAdapter {
             tenant_id,
             input,
             "en",
+            None,
+            IDEMPOTENCY_TOKEN_MAX_LEN_DEFAULT as usize,
         )
     }
 
@@ -4690,16 +6670,18 @@ impl EnvPh1dLiveAdapter {
         tenant_id: &str,
         input: &str,
         output_language: &str,
+        model_override: Option<&str>,
+        idempotency_token_max_len: usize,
     ) -> Result<Ph1dProviderCallRequest, String> {
         let request_seed = format!(
             "ph1d_llm_interpret:{}:{}:{}:{}:{}",
             correlation_id.0, turn_id.0, tenant_id, output_language, input
         );
         let request_id = RequestId(stable_hash_u64(&request_seed));
-        let idempotency_key = sanitize_idempotency_token(&format!(
-            "ph1d_llm_interpret_{}_{}",
-            correlation_id.0, turn_id.0
-        ));
+        let idempotency_key = sanitize_idempotency_token(
+            &format!("ph1d_llm_interpret_{}_{}", correlation_id.0, turn_id.0),
+            idempotency_token_max_len,
+        );
         Ph1dProviderCallRequest::v1(
             correlation_id_to_u64(correlation_id),
             turn_id.0.max(1),
@@ -4709,7 +6691,7 @@ impl EnvPh1dLiveAdapter {
             Ph1dProviderTask::LlmInterpret,
             Ph1dProviderRouteClass::Primary,
             self.provider_id.clone(),
-            self.model_id.clone(),
+            model_override.map_or_else(|| self.model_id.clone(), str::to_string),
             self.timeout_ms,
             1,
             Some("ph1d_public_chat_answer_v1".to_string()),
@@ -4719,10 +6701,10 @@ impl EnvPh1dLiveAdapter {
             SchemaHash(stable_hash_u64("ph1d_public_chat_tool_catalog_none")),
             SchemaHash(stable_hash_u64("ph1d_public_chat_policy_context_standard")),
             Some(TranscriptHash(stable_hash_u64(input))),
-            sanitize_idempotency_token(&format!(
-                "ph1d_public_chat_input_{}_{}",
-                correlation_id.0, turn_id.0
-            )),
+            sanitize_idempotency_token(
+                &format!("ph1d_public_chat_input_{}_{}", correlation_id.0, turn_id.0),
+                idempotency_token_max_len,
+            ),
             Ph1dProviderInputPayloadKind::Text,
             SchemaHash(stable_hash_u64(input)),
             Some(truncate_ascii(input.trim(), 32_768)),
@@ -4752,9 +6734,7 @@ impl EnvPh1dLiveAdapter {
         if request.provider_id != self.provider_id {
             return Err("adapter provider_id does not match PH1.D request".to_string());
         }
-        if request.model_id != self.model_id {
-            return Err("adapter model_id does not match PH1.D request".to_string());
-        }
+        self.check_request_model_id(request)?;
         Ph1dProviderTransportEvidence::v1(
             request,
             response,
@@ -4776,9 +6756,7 @@ impl EnvPh1dLiveAdapter {
         if request.provider_id != self.provider_id {
             return Err("adapter provider_id does not match PH1.D request".to_string());
         }
-        if request.model_id != self.model_id {
-            return Err("adapter model_id does not match PH1.D request".to_string());
-        }
+        self.check_request_model_id(request)?;
         Ph1dProviderErrorEvidence::v1(
             request,
             safe_provider_endpoint(&self.endpoint),
@@ -4825,8 +6803,13 @@ impl EnvPh1dLiveAdapter {
                 ),
                 evidence: None,
             })?;
+        self.check_request_model_id(req)
+            .map_err(|message| Ph1dProviderExecuteFailure {
+                error: Ph1dProviderAdapterError::terminal(message),
+                evidence: None,
+            })?;
         let output_language = output_language_from_content_type(req.input_content_type.as_deref());
-        let payload = openai_llm_interpret_payload(&self.model_id, input, output_language.as_str());
+        let payload = openai_llm_interpret_payload(&req.model_id, input, output_language.as_str());
         let payload_text =
             serde_json::to_string(&payload).map_err(|_| Ph1dProviderExecuteFailure {
                 error: Ph1dProviderAdapterError::terminal(
@@ -4957,7 +6940,7 @@ impl EnvPh1dLiveAdapter {
             provider_call_id,
             self.provider_id.clone(),
             req.provider_task,
-            self.model_id.clone(),
+            req.model_id.clone(),
             Ph1dProviderStatus::Ok,
             latency_ms,
             0,
@@ -5141,22 +7124,26 @@ fn openai_responses_parameter_compatibility(
     }
 }
 
-fn openai_llm_interpret_payload(
-    model_id: &str,
-    user_input: &str,
-    output_language: &str,
-) -> serde_json::Value {
+fn ph1d_public_answer_system_instruction(output_language: &str) -> String {
     let response_language_instruction = if output_language.starts_with("zh") {
         "Answer in Chinese. Preserve any English, quoted, or technical terms exactly when the user used them. Do not translate unless the user explicitly asked for translation."
     } else {
         "Answer in English unless the user explicitly requested another language. Preserve Chinese, quoted, or technical terms exactly when the user used them."
     };
+    format!("You are Selene's PH1.D public-answer provider. Return exactly one compact JSON object with keys mode, response_text, reason_code. mode must be chat. {response_language_instruction} Answer directly and concisely. Do not include markdown fences, source lists, raw search payloads, HTML entities, provider names, tool names, Retrieved at timestamps, or internal policy text. Do not claim protected identity, memory, session, wake, payment, access, or device authority.")
+}
+
+fn openai_llm_interpret_payload(
+    model_id: &str,
+    user_input: &str,
+    output_language: &str,
+) -> serde_json::Value {
     let mut payload = serde_json::json!({
         "model": model_id,
         "input": [
             {
                 "role": "system",
-                "content": format!("You are Selene's PH1.D public-answer provider. Return exactly one compact JSON object with keys mode, response_text, reason_code. mode must be chat. {response_language_instruction} Answer directly and concisely. Do not include markdown fences, source lists, raw search payloads, HTML entities, provider names, tool names, Retrieved at timestamps, or internal policy text. Do not claim protected identity, memory, session, wake, payment, access, or device authority.")
+                "content": ph1d_public_answer_system_instruction(output_language)
             },
             {
                 "role": "user",
@@ -5517,9 +7504,314 @@ where
     }
 }
 
+const PROVIDER_CIRCUIT_DEFAULT_FAILURE_THRESHOLD: u32 = 5;
+const PROVIDER_CIRCUIT_DEFAULT_COOLDOWN_MS: u64 = 30_000;
+
+/// Consecutive live-provider failures that open the breaker, and how long it stays open
+/// before one half-open probe is let through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterProviderCircuitBreakerPolicy {
+    failure_threshold: u32,
+    cooldown_ns: u64,
+}
+
+impl Default for AdapterProviderCircuitBreakerPolicy {
+    fn default() -> Self {
+        Self {
+            failure_threshold: PROVIDER_CIRCUIT_DEFAULT_FAILURE_THRESHOLD,
+            cooldown_ns: PROVIDER_CIRCUIT_DEFAULT_COOLDOWN_MS.saturating_mul(1_000_000),
+        }
+    }
+}
+
+impl AdapterProviderCircuitBreakerPolicy {
+    /// A threshold of 0 is raised to 1.
+    pub fn new(failure_threshold: u32, cooldown_ms: u64) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown_ns: cooldown_ms.saturating_mul(1_000_000),
+        }
+    }
+}
+
+fn parse_provider_circuit_breaker_policy_from_env() -> AdapterProviderCircuitBreakerPolicy {
+    AdapterProviderCircuitBreakerPolicy::new(
+        parse_u32_env(
+            "SELENE_ADAPTER_PROVIDER_CIRCUIT_FAILURE_THRESHOLD",
+            1,
+            1_000,
+        )
+        .unwrap_or(PROVIDER_CIRCUIT_DEFAULT_FAILURE_THRESHOLD),
+        parse_u64_env(
+            "SELENE_ADAPTER_PROVIDER_CIRCUIT_COOLDOWN_MS",
+            PROVIDER_CIRCUIT_DEFAULT_COOLDOWN_MS,
+        ),
+    )
+}
+
+#[derive(Debug, Clone, Default)]
+struct AdapterProviderCircuitState {
+    consecutive_failures: u32,
+    open_until_ns: Option<u64>,
+    half_open: bool,
+    trips_total: u64,
+    short_circuited_total: u64,
+}
+
+impl AdapterProviderCircuitState {
+    // Open and still cooling down: refuse and count it. Cooldown over: let one probe through.
+    fn admit(&mut self, now_ns: u64) -> bool {
+        if let Some(open_until_ns) = self.open_until_ns {
+            if now_ns < open_until_ns {
+                self.short_circuited_total = self.short_circuited_total.saturating_add(1);
+                return false;
+            }
+            self.open_until_ns = None;
+            self.half_open = true;
+        }
+        true
+    }
+
+    fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.half_open = false;
+    }
+
+    fn record_failure(&mut self, policy: AdapterProviderCircuitBreakerPolicy, now_ns: u64) {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        if self.half_open || self.consecutive_failures >= policy.failure_threshold {
+            self.open_until_ns = Some(now_ns.saturating_add(policy.cooldown_ns));
+            self.half_open = false;
+            self.trips_total = self.trips_total.saturating_add(1);
+        }
+    }
+
+    fn health(
+        &self,
+        policy: AdapterProviderCircuitBreakerPolicy,
+        now_ns: u64,
+    ) -> AdapterProviderHealth {
+        let circuit_state = match self.open_until_ns {
+            Some(open_until_ns) if now_ns < open_until_ns => "OPEN",
+            Some(_) => "HALF_OPEN",
+            None if self.half_open => "HALF_OPEN",
+            None => "CLOSED",
+        };
+        AdapterProviderHealth {
+            circuit_state: circuit_state.to_string(),
+            consecutive_failures: self.consecutive_failures,
+            failure_threshold: policy.failure_threshold,
+            cooldown_ms: policy.cooldown_ns / 1_000_000,
+            open_until_ns: self.open_until_ns,
+            trips_total: self.trips_total,
+            short_circuited_total: self.short_circuited_total,
+        }
+    }
+}
+
+// Shares one breaker across turns. A call refused by an open breaker never reaches the
+// provider and is not counted as another failure. The refusal is retryable, so failover moves
+// on to the next provider.
+#[derive(Debug, Clone)]
+struct CircuitBreakerProviderAdapter<'a, A>
+where
+    A: Ph1dProviderAdapter,
+{
+    inner: &'a A,
+    policy: AdapterProviderCircuitBreakerPolicy,
+    state: Arc<Mutex<AdapterProviderCircuitState>>,
+    now_ns: u64,
+}
+
+impl<'a, A> CircuitBreakerProviderAdapter<'a, A>
+where
+    A: Ph1dProviderAdapter,
+{
+    fn new(
+        inner: &'a A,
+        policy: AdapterProviderCircuitBreakerPolicy,
+        state: Arc<Mutex<AdapterProviderCircuitState>>,
+        now_ns: u64,
+    ) -> Self {
+        Self {
+            inner,
+            policy,
+            state,
+            now_ns,
+        }
+    }
+
+    fn admit(&self) -> bool {
+        self.state
+            .lock()
+            .map(|mut state| state.admit(self.now_ns))
+            .unwrap_or(true)
+    }
+}
+
+impl<A> Ph1dProviderAdapter for CircuitBreakerProviderAdapter<'_, A>
+where
+    A: Ph1dProviderAdapter,
+{
+    fn execute(
+        &self,
+        req: &Ph1dProviderCallRequest,
+    ) -> Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError> {
+        if !self.admit() {
+            return Err(Ph1dProviderAdapterError::retryable(
+                "ph1d provider circuit open".to_string(),
+            ));
+        }
+        let out = self.inner.execute(req);
+        if let Ok(mut state) = self.state.lock() {
+            match &out {
+                Ok(_) => state.record_success(),
+                Err(_) => state.record_failure(self.policy, self.now_ns),
+            }
+        }
+        out
+    }
+}
+
+const PH1D_LIVE_FAILOVER_DEFAULT_MAX_ATTEMPTS: usize = 3;
+
+/// An extra live provider that PH1.C tries, in order, after the primary PH1.D live adapter
+/// returns a retryable error.
+#[derive(Clone)]
+pub struct AdapterPh1dFallbackProvider(Arc<dyn Ph1dProviderAdapter + Send + Sync>);
+
+impl AdapterPh1dFallbackProvider {
+    pub fn new(adapter: impl Ph1dProviderAdapter + Send + Sync + 'static) -> Self {
+        Self(Arc::new(adapter))
+    }
+}
+
+impl std::fmt::Debug for AdapterPh1dFallbackProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AdapterPh1dFallbackProvider")
+    }
+}
+
+impl Ph1dProviderAdapter for AdapterPh1dFallbackProvider {
+    fn execute(
+        &self,
+        req: &Ph1dProviderCallRequest,
+    ) -> Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError> {
+        self.0.execute(req)
+    }
+}
+
+// Tries each adapter in order. Only a retryable error moves on to the next one, and at most
+// `max_attempts` calls are made. Failed attempts go into `records` as error rows, and their
+// time is added to the latency of the call that succeeds.
+struct FailoverProviderAdapter<'a> {
+    adapters: Vec<Box<dyn Ph1dProviderAdapter + 'a>>,
+    max_attempts: usize,
+    records: Option<Arc<Mutex<Vec<Ph1dProviderCallResponse>>>>,
+}
+
+impl<'a> FailoverProviderAdapter<'a> {
+    fn new(
+        adapters: Vec<Box<dyn Ph1dProviderAdapter + 'a>>,
+        max_attempts: usize,
+        records: Option<Arc<Mutex<Vec<Ph1dProviderCallResponse>>>>,
+    ) -> Self {
+        Self {
+            adapters,
+            max_attempts: max_attempts.max(1),
+            records,
+        }
+    }
+
+    fn record_failed_attempt(&self, req: &Ph1dProviderCallRequest, latency_ms: u32) {
+        let Some(records) = self.records.as_ref() else {
+            return;
+        };
+        let Ok(row) = Ph1dProviderCallResponse::v1(
+            req.correlation_id,
+            req.turn_id,
+            req.request_id,
+            req.idempotency_key.clone(),
+            None,
+            req.provider_id.clone(),
+            req.provider_task,
+            req.model_id.clone(),
+            Ph1dProviderStatus::Error,
+            latency_ms,
+            0,
+            None,
+            None,
+            None,
+            Ph1dProviderValidationStatus::SchemaFail,
+            reason_codes::ADAPTER_PROVIDER_FAILOVER_ATTEMPT_FAILED,
+        ) else {
+            return;
+        };
+        if let Ok(mut records) = records.lock() {
+            records.push(row);
+        }
+    }
+}
+
+impl Ph1dProviderAdapter for FailoverProviderAdapter<'_> {
+    fn execute(
+        &self,
+        req: &Ph1dProviderCallRequest,
+    ) -> Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError> {
+        let mut failed_latency_ms = 0u32;
+        let mut last_err =
+            Ph1dProviderAdapterError::terminal("ph1d failover has no providers".to_string());
+        for adapter in self.adapters.iter().take(self.max_attempts) {
+            let start = Instant::now();
+            match adapter.execute(req) {
+                Ok(mut out) => {
+                    out.provider_latency_ms = out
+                        .provider_latency_ms
+                        .saturating_add(failed_latency_ms)
+                        .min(120_000);
+                    return Ok(out);
+                }
+                Err(err) => {
+                    let latency_ms = u32::try_from(start.elapsed().as_millis())
+                        .unwrap_or(u32::MAX)
+                        .min(120_000);
+                    failed_latency_ms = failed_latency_ms.saturating_add(latency_ms);
+                    self.record_failed_attempt(req, latency_ms);
+                    let retryable = err.retryable;
+                    last_err = err;
+                    if !retryable {
+                        break;
+                    }
+                }
+            }
+        }
+        Err(last_err)
+    }
+}
+
+// Where the turn's locale came from; it sets how much PH1.C trusts the language hint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ph1cLocaleSource {
+    ClientExplicit,
+    PriorDetection,
+    Guessed,
+}
+
+impl Ph1cLocaleSource {
+    fn language_hint_confidence(self) -> LanguageHintConfidence {
+        match self {
+            Ph1cLocaleSource::ClientExplicit => LanguageHintConfidence::High,
+            Ph1cLocaleSource::PriorDetection => LanguageHintConfidence::Med,
+            Ph1cLocaleSource::Guessed => LanguageHintConfidence::Low,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct Ph1kLiveSignalBundle {
     locale_tag: InterruptLocaleTag,
+    locale_source: Ph1cLocaleSource,
+    client_language_hint: Option<LanguageHint>,
     processed_stream_ref: AudioStreamRef,
     pre_roll_buffer_ref: PreRollBufferRef,
     vad_events: Vec<VadEvent>,
@@ -5547,86 +7839,35 @@ impl Default for AdapterRuntime {
                 panic!("selene_adapter persistent bootstrap required for runtime: {err}")
             });
         }
-        let ph1d_live_adapter = build_ph1d_live_adapter_from_env();
-        Self {
-            ingress: AppServerIngressRuntime::default(),
-            store: Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
-            session_retry_cache: Arc::new(Mutex::new(BTreeMap::new())),
-            wake_guest_lane_state: Arc::new(Mutex::new(BTreeMap::new())),
-            sync_worker_counters: Arc::new(Mutex::new(AdapterSyncWorkerCounters::default())),
-            improvement_counters: Arc::new(Mutex::new(AdapterImprovementCounters::default())),
-            transcript_state: Arc::new(Mutex::new(AdapterTranscriptState::default())),
-            public_brain_trace_state: Arc::new(Mutex::new(AdapterPublicBrainTraceState::default())),
-            ph1d_provider_transport_evidence_state: Arc::new(Mutex::new(
-                AdapterPh1dProviderTransportEvidenceState::default(),
-            )),
-            ph1d_provider_error_evidence_state: Arc::new(Mutex::new(
-                AdapterPh1dProviderErrorEvidenceState::default(),
-            )),
-            public_discourse_state: Arc::new(Mutex::new(AdapterPublicDiscourseState::default())),
-            public_answer_state: Arc::new(Mutex::new(AdapterPublicAnswerState::default())),
-            active_session_context_state: Arc::new(Mutex::new(BTreeMap::new())),
-            weather_context_state: Arc::new(Mutex::new(BTreeMap::new())),
-            report_display_target_defaults: Arc::new(Mutex::new(BTreeMap::new())),
-            auto_builder_enabled: true,
-            ph1c_live_enabled: parse_bool_env("SELENE_PH1C_LIVE_ENABLED", true),
-            ph1c_streaming_enabled: parse_bool_env("SELENE_PH1C_STREAMING_ENABLED", true),
-            ph1c_runtime: EnginePh1cRuntime::new(EnginePh1cConfig::mvp_desktop_v1()),
-            ph1d_runtime: EnginePh1dRuntime::new(selene_engines::ph1d::Ph1dConfig::mvp_v1()),
-            ph1d_live_adapter,
-            persistence: None,
-            runtime_node_id: runtime_node_id_from_env(),
-            session_lease_ttl_ms: parse_u64_env("SELENE_SESSION_LEASE_TTL_MS", 30_000),
-        }
+        Self::from_config_parts(
+            AppServerIngressRuntime::default(),
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+            AdapterConfig {
+                journal_path: None,
+                auto_builder_enabled: true,
+                ..AdapterConfig::from_env()
+            },
+        )
     }
 }
 
 impl AdapterRuntime {
     pub fn new(ingress: AppServerIngressRuntime, store: Arc<Mutex<Ph1fStore>>) -> Self {
         if !cfg!(test) {
-            let journal_path = env::var("SELENE_ADAPTER_STORE_PATH")
-                .ok()
-                .map(|v| v.trim().to_string())
-                .filter(|v| !v.is_empty())
-                .map(PathBuf::from)
-                .unwrap_or_else(default_adapter_store_path);
-            let auto_builder_enabled = parse_auto_builder_enabled_from_env();
-            return Self::new_with_persistence(ingress, store, journal_path, auto_builder_enabled)
+            return Self::from_config_with_ingress(ingress, store, AdapterConfig::from_env())
                 .unwrap_or_else(|err| {
                     panic!("selene_adapter persistent bootstrap required for runtime: {err}")
                 });
         }
-        let ph1d_live_adapter = build_ph1d_live_adapter_from_env();
-        Self {
+        Self::from_config_parts(
             ingress,
             store,
-            session_retry_cache: Arc::new(Mutex::new(BTreeMap::new())),
-            wake_guest_lane_state: Arc::new(Mutex::new(BTreeMap::new())),
-            sync_worker_counters: Arc::new(Mutex::new(AdapterSyncWorkerCounters::default())),
-            improvement_counters: Arc::new(Mutex::new(AdapterImprovementCounters::default())),
-            transcript_state: Arc::new(Mutex::new(AdapterTranscriptState::default())),
-            public_brain_trace_state: Arc::new(Mutex::new(AdapterPublicBrainTraceState::default())),
-            ph1d_provider_transport_evidence_state: Arc::new(Mutex::new(
-                AdapterPh1dProviderTransportEvidenceState::default(),
-            )),
-            ph1d_provider_error_evidence_state: Arc::new(Mutex::new(
-                AdapterPh1dProviderErrorEvidenceState::default(),
-            )),
-            public_discourse_state: Arc::new(Mutex::new(AdapterPublicDiscourseState::default())),
-            public_answer_state: Arc::new(Mutex::new(AdapterPublicAnswerState::default())),
-            active_session_context_state: Arc::new(Mutex::new(BTreeMap::new())),
-            weather_context_state: Arc::new(Mutex::new(BTreeMap::new())),
-            report_display_target_defaults: Arc::new(Mutex::new(BTreeMap::new())),
-            auto_builder_enabled: true,
-            ph1c_live_enabled: parse_bool_env("SELENE_PH1C_LIVE_ENABLED", true),
-            ph1c_streaming_enabled: parse_bool_env("SELENE_PH1C_STREAMING_ENABLED", true),
-            ph1c_runtime: EnginePh1cRuntime::new(EnginePh1cConfig::mvp_desktop_v1()),
-            ph1d_runtime: EnginePh1dRuntime::new(selene_engines::ph1d::Ph1dConfig::mvp_v1()),
-            ph1d_live_adapter,
-            persistence: None,
-            runtime_node_id: runtime_node_id_from_env(),
-            session_lease_ttl_ms: parse_u64_env("SELENE_SESSION_LEASE_TTL_MS", 30_000),
-        }
+            AdapterConfig {
+                journal_path: None,
+                auto_builder_enabled: true,
+                ..AdapterConfig::from_env()
+            },
+        )
     }
 
     pub fn new_with_persistence(
@@ -5635,17 +7876,85 @@ impl AdapterRuntime {
         journal_path: PathBuf,
         auto_builder_enabled: bool,
     ) -> Result<Self, String> {
-        let ph1d_live_adapter = build_ph1d_live_adapter_from_env();
-        let persistence = AdapterPersistenceRuntime {
-            legacy_journal_path: journal_path.clone(),
-            state_path: adapter_persistence_state_path(&journal_path),
-            state: Arc::new(Mutex::new(AdapterPersistenceState::default())),
-        };
-        let runtime = Self {
+        Self::new_with_persistence_with_config(
+            ingress,
+            store,
+            journal_path,
+            AdapterConfig {
+                auto_builder_enabled,
+                ..AdapterConfig::from_env()
+            },
+        )
+        .map_err(String::from)
+    }
+
+    /// Like `new_with_persistence`, but every other setting comes from `config`. The
+    /// journal at `journal_path` is bootstrapped and replayed first.
+    pub fn new_with_persistence_with_config(
+        ingress: AppServerIngressRuntime,
+        store: Arc<Mutex<Ph1fStore>>,
+        journal_path: PathBuf,
+        config: AdapterConfig,
+    ) -> Result<Self, AdapterError> {
+        Self::from_config_with_ingress(
+            ingress,
+            store,
+            AdapterConfig {
+                journal_path: Some(journal_path),
+                ..config
+            },
+        )
+    }
+
+    /// Builds a runtime from an explicit config instead of process env. With a
+    /// `journal_path` the persistence journal is bootstrapped and replayed first.
+    pub fn from_config(
+        config: AdapterConfig,
+        store: Arc<Mutex<Ph1fStore>>,
+    ) -> Result<Self, AdapterError> {
+        Self::from_config_with_ingress(AppServerIngressRuntime::default(), store, config)
+    }
+
+    fn from_config_with_ingress(
+        ingress: AppServerIngressRuntime,
+        store: Arc<Mutex<Ph1fStore>>,
+        config: AdapterConfig,
+    ) -> Result<Self, AdapterError> {
+        let runtime = Self::from_config_parts(ingress, store, config);
+        if runtime.persistence.is_some() {
+            runtime
+                .ensure_persistence_ready()
+                .map_err(AdapterError::persistence)?;
+            runtime
+                .bootstrap_persistence_runtime()
+                .map_err(AdapterError::persistence)?;
+        }
+        Ok(runtime)
+    }
+
+    fn from_config_parts(
+        ingress: AppServerIngressRuntime,
+        store: Arc<Mutex<Ph1fStore>>,
+        config: AdapterConfig,
+    ) -> Self {
+        let persistence = config
+            .journal_path
+            .map(|journal_path| AdapterPersistenceRuntime {
+                state_path: adapter_persistence_state_path(&journal_path),
+                legacy_journal_path: journal_path,
+                state: Arc::new(Mutex::new(AdapterPersistenceState::default())),
+                journal_lock: Arc::new(Mutex::new(())),
+                store_snapshot_seq: Arc::new(Mutex::new(0)),
+                entries_since_store_snapshot: Arc::new(AtomicU64::new(0)),
+            });
+        Self {
             ingress,
             store,
             session_retry_cache: Arc::new(Mutex::new(BTreeMap::new())),
+            turn_replay_cache: Arc::new(Mutex::new(AdapterTurnReplayCache::default())),
             wake_guest_lane_state: Arc::new(Mutex::new(BTreeMap::new())),
+            ph1c_detected_locale_by_device: Arc::new(Mutex::new(BTreeMap::new())),
+            read_only_incident_fired_at: Arc::new(Mutex::new(BTreeMap::new())),
             sync_worker_counters: Arc::new(Mutex::new(AdapterSyncWorkerCounters::default())),
             improvement_counters: Arc::new(Mutex::new(AdapterImprovementCounters::default())),
             transcript_state: Arc::new(Mutex::new(AdapterTranscriptState::default())),
@@ -5657,37 +7966,290 @@ impl AdapterRuntime {
                 AdapterPh1dProviderErrorEvidenceState::default(),
             )),
             public_discourse_state: Arc::new(Mutex::new(AdapterPublicDiscourseState::default())),
+            ph1x_thread_state_cache: Arc::new(Mutex::new(AdapterPh1xThreadStateCache::default())),
             public_answer_state: Arc::new(Mutex::new(AdapterPublicAnswerState::default())),
             active_session_context_state: Arc::new(Mutex::new(BTreeMap::new())),
             weather_context_state: Arc::new(Mutex::new(BTreeMap::new())),
             report_display_target_defaults: Arc::new(Mutex::new(BTreeMap::new())),
-            auto_builder_enabled,
-            ph1c_live_enabled: parse_bool_env("SELENE_PH1C_LIVE_ENABLED", true),
-            ph1c_streaming_enabled: parse_bool_env("SELENE_PH1C_STREAMING_ENABLED", true),
+            health_report_snapshot_state: Arc::new(Mutex::new(
+                AdapterHealthReportSnapshotState::default(),
+            )),
+            health_subscription_state: Arc::new(Mutex::new(
+                AdapterHealthSubscriptionState::default(),
+            )),
+            provider_latency_state: Arc::new(Mutex::new(AdapterProviderLatencyState::default())),
+            artifact_version_state: Arc::new(Mutex::new(AdapterArtifactVersionState::default())),
+            unknown_app_platform_counts: Arc::new(Mutex::new(BTreeMap::new())),
+            ph1x_last_failure_reasons: Arc::new(Mutex::new(BTreeMap::new())),
+            auto_builder_enabled: config.auto_builder_enabled,
+            ph1c_live_enabled: config.ph1c_live_enabled,
+            ph1c_streaming_enabled: config.ph1c_streaming_enabled,
+            stt_min_snr_db: config.stt_min_snr_db,
+            tenant_feature_flags: config.tenant_feature_flags,
+            ph1k_feedback_policy: config.ph1k_feedback_policy,
+            interrupt_confidence_policy: config.interrupt_confidence_policy,
+            ph1x_interruption_enabled: config.ph1x_interruption_enabled,
+            ph1x_last_failure_enabled: config.ph1x_last_failure_enabled,
+            tenant_rate_limiter: TenantRateLimiter::new(config.tenant_rate_limit),
+            tenant_ph1k_feedback_policies: config.tenant_ph1k_feedback_policies,
+            vision_source_kind_policy: config.vision_source_kind_policy,
+            ocr_route_retry_budget: config.ocr_route_retry_budget,
+            builder_ph1k_feedback_enabled: config.builder_ph1k_feedback_enabled,
+            storage_commit_retry_limit: config.storage_commit_retry_limit,
+            provenance_mode: config.provenance_mode,
+            fallback_tenant_id: config.fallback_tenant_id,
+            clock_skew_policy: config.clock_skew_policy,
+            journal_entry_max_bytes: config.journal_entry_max_bytes,
+            journal_replay_max_entries: config.journal_replay_max_entries,
+            store_snapshot_every_entries: config.store_snapshot_every_entries,
+            read_only_incident_cooldown_ns: config.read_only_incident_cooldown_ns,
+            sync_retry_policy: config.sync_retry_policy,
+            transcript_close_policy: config.transcript_close_policy,
+            transcript_retention_policy: config.transcript_retention_policy,
+            privacy_mode_transcript_scope: config.privacy_mode_transcript_scope,
+            ph1x_memory_candidate_limit: config.ph1x_memory_candidate_limit,
+            builder_min_outcome_entries: config.builder_min_outcome_entries,
+            provider_circuit_breaker_policy: config.provider_circuit_breaker_policy,
+            provider_circuit_state: Arc::new(Mutex::new(AdapterProviderCircuitState::default())),
+            onboarding_field_value_max_len: config.onboarding_field_value_max_len,
+            idempotency_token_max_len: config.idempotency_token_max_len,
+            default_report_kind: config.default_report_kind,
+            default_company_scope: config.default_company_scope,
+            health_range_grace: config.health_range_grace,
+            health_issue_deadlines: config.health_issue_deadlines,
+            health_issue_synth_limit: config.health_issue_synth_limit,
+            sync_worker_stale_after_ms: config.sync_worker_stale_after_ms,
+            telemetry_tags: config.telemetry_tags,
+            refusal_messages: config.refusal_messages,
+            voice_disabled_message: config.voice_disabled_message,
+            tenant_delimiter: config.tenant_delimiter,
+            nlp_failure_strict: config.nlp_failure_strict,
+            provider_latency_policy: config.provider_latency_policy,
+            telemetry_sink: config
+                .telemetry_sink
+                .unwrap_or_else(|| Arc::new(CsvTelemetrySink::new(config.eval_csv_flush_policy))),
+            interrupt_matcher: build_interrupt_phrase_matcher(
+                &config.interrupt_lexicon,
+                &config.interrupt_locale_fallbacks,
+            ),
+            tenant_access_policy: Arc::new(Mutex::new(config.tenant_access_policy)),
+            unknown_app_platform_policy: config.unknown_app_platform_policy,
+            model_override_allowlist: config.model_override_allowlist.clone(),
+            wake_gate_policy: config.wake_gate_policy,
             ph1c_runtime: EnginePh1cRuntime::new(EnginePh1cConfig::mvp_desktop_v1()),
+            ph1n_runtime: AdapterPh1nEngineHandle::default(),
             ph1d_runtime: EnginePh1dRuntime::new(selene_engines::ph1d::Ph1dConfig::mvp_v1()),
-            ph1d_live_adapter,
-            persistence: Some(persistence),
-            runtime_node_id: runtime_node_id_from_env(),
-            session_lease_ttl_ms: parse_u64_env("SELENE_SESSION_LEASE_TTL_MS", 30_000),
-        };
-        runtime.ensure_persistence_ready()?;
-        runtime.bootstrap_persistence_runtime()?;
-        Ok(runtime)
+            ph1d_live_adapter: config.ph1d_live_adapter.map(|adapter| {
+                adapter.with_model_override_allowlist(config.model_override_allowlist)
+            }),
+            ph1d_live_fallback_providers: config.ph1d_live_fallback_providers,
+            ph1d_live_failover_max_attempts: config.ph1d_live_failover_max_attempts,
+            persistence,
+            runtime_node_id: config.runtime_node_id,
+            session_lease_ttl_ms: config.session_lease_ttl_ms,
+        }
     }
 
     pub fn run_voice_turn(
         &self,
         request: VoiceTurnAdapterRequest,
-    ) -> Result<VoiceTurnAdapterResponse, String> {
+    ) -> Result<VoiceTurnAdapterResponse, AdapterError> {
+        self.check_tenant_rate_limit(&request)?;
         self.run_voice_turn_internal(
             request,
             None,
+            LegacyJournalWrite::Immediate,
             true,
+            PersistenceInvocationMode::Standard,
+            None,
+            None,
+            None,
+        )
+        .map_err(AdapterError::from)
+    }
+
+    // Charges the turn to its tenant scope before any store lock is taken. The scope comes
+    // from the raw request; a malformed actor is left for the turn's own validation.
+    fn check_tenant_rate_limit(
+        &self,
+        request: &VoiceTurnAdapterRequest,
+    ) -> Result<(), AdapterError> {
+        let Ok(actor_user_id) = UserId::new(request.actor_user_id.clone()) else {
+            return Ok(());
+        };
+        let device_id = DeviceId::new(request.device_id.clone().unwrap_or_else(|| {
+            format!(
+                "adapter_auto_{}",
+                stable_hash_hex_16(actor_user_id.as_str())
+            )
+        }))
+        .ok();
+        match resolve_tenant_scope(
+            request.tenant_id.clone(),
+            &actor_user_id,
+            device_id.as_ref(),
+            self.tenant_delimiter,
+        ) {
+            Some(tenant_scope) => self
+                .tenant_rate_limiter
+                .try_acquire(&tenant_scope, system_time_now_ns()),
+            None => Ok(()),
+        }
+    }
+
+    /// Runs `requests` in order through the same path as `run_voice_turn`, so transcripts and
+    /// session transitions match sequential calls. A failed turn does not stop the batch, and
+    /// each result sits at its request's index. Successful turns are journaled together in one
+    /// append and fsync after the last turn. If that write fails, those turns report the journal
+    /// error, as a sequential call would, and their responses stay out of the replay cache so a
+    /// resubmission runs them again. The store lock is taken once and held for every turn of
+    /// the batch, then released before the journal write.
+    pub fn run_voice_turn_batch(
+        &self,
+        requests: Vec<VoiceTurnAdapterRequest>,
+    ) -> Vec<Result<VoiceTurnAdapterResponse, AdapterError>> {
+        let batch = AdapterTurnBatch::default();
+        let mut journaled_turns = Vec::new();
+        let mut replay_ranges = Vec::with_capacity(requests.len());
+        let mut results = Vec::with_capacity(requests.len());
+        let mut store = match self.store.lock() {
+            Ok(store) => store,
+            Err(_) => {
+                return requests
+                    .iter()
+                    .map(|_| Err(AdapterError::LockPoisoned("store".to_string())))
+                    .collect();
+            }
+        };
+        for request in requests {
+            let replay_entries_before = batch.replay_entry_count();
+            let result = match self.check_tenant_rate_limit(&request) {
+                Ok(()) => {
+                    let lines_before = batch.journal_line_count();
+                    let result = self
+                        .run_voice_turn_internal(
+                            request,
+                            None,
+                            LegacyJournalWrite::Deferred(&batch),
+                            true,
+                            PersistenceInvocationMode::Standard,
+                            None,
+                            None,
+                            Some(&mut store),
+                        )
+                        .map_err(AdapterError::from);
+                    if batch.journal_line_count() > lines_before {
+                        journaled_turns.push(results.len());
+                    }
+                    result
+                }
+                Err(err) => Err(err),
+            };
+            replay_ranges.push(replay_entries_before..batch.replay_entry_count());
+            results.push(result);
+        }
+        drop(store);
+        let AdapterTurnBatch {
+            journal_lines,
+            replay_entries,
+        } = batch;
+        let lines = journal_lines
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if let Err(err) = self.write_legacy_journal_lines(&lines) {
+            for index in journaled_turns {
+                results[index] = Err(AdapterError::persistence(err.clone()));
+            }
+        }
+        let replay_entries = replay_entries
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        match self.turn_replay_cache.lock() {
+            Ok(mut cache) => {
+                for (range, result) in replay_ranges.into_iter().zip(&results) {
+                    if result.is_ok() {
+                        for (key, entry) in &replay_entries[range] {
+                            cache.insert(*key, entry.clone());
+                        }
+                    }
+                }
+            }
+            Err(_) => {
+                for (range, result) in replay_ranges.into_iter().zip(results.iter_mut()) {
+                    if result.is_ok() && !range.is_empty() {
+                        *result = Err(AdapterError::LockPoisoned("turn replay cache".to_string()));
+                    }
+                }
+            }
+        }
+        self.write_periodic_store_snapshot_if_due();
+        results
+    }
+
+    /// Like `run_voice_turn`, but checks `cancel` before the turn starts, before live STT and
+    /// before PH1.X/PH1.D execution. A cancelled turn stops at the next check and returns a
+    /// `CANCELLED` outcome. Ph1fStore is left as the turn found it, and no turn result or
+    /// retry-cache entry is committed for it.
+    pub fn run_voice_turn_cancellable(
+        &self,
+        request: VoiceTurnAdapterRequest,
+        cancel: &CancellationFlag,
+    ) -> Result<VoiceTurnAdapterResponse, AdapterError> {
+        self.check_tenant_rate_limit(&request)?;
+        let turn_id = Some(request.turn_id);
+        match self.run_voice_turn_internal(
+            request,
+            None,
+            LegacyJournalWrite::Immediate,
             true,
             PersistenceInvocationMode::Standard,
+            Some(cancel),
+            None,
+            None,
+        ) {
+            Err(err) if err.reason_code == VOICE_TURN_CANCELLED_REASON_CODE => {
+                Ok(cancelled_voice_turn_adapter_response(turn_id))
+            }
+            result => result.map_err(AdapterError::from),
+        }
+    }
+
+    /// Like `run_voice_turn`, but calls `on_partial` with each partial transcript chunk PH1.C
+    /// commits during live STT streaming, before the final response comes back. The store lock
+    /// is released while the stream runs, so `on_partial` may call back into the runtime
+    /// without deadlocking. Turns with an upstream transcript have no live partials.
+    pub fn run_voice_turn_streaming(
+        &self,
+        request: VoiceTurnAdapterRequest,
+        mut on_partial: impl FnMut(&str),
+    ) -> Result<VoiceTurnAdapterResponse, AdapterError> {
+        self.check_tenant_rate_limit(&request)?;
+        self.run_voice_turn_internal(
+            request,
+            None,
+            LegacyJournalWrite::Immediate,
+            true,
+            PersistenceInvocationMode::Standard,
+            None,
+            Some(&mut on_partial),
+            None,
         )
-        .map_err(|err| err.to_runtime_reason())
+        .map_err(AdapterError::from)
+    }
+
+    // Single place untenanted writes pick their tenant bucket, so every path stays on the
+    // configured `SELENE_ADAPTER_FALLBACK_TENANT`.
+    fn tenant_or_fallback<'a>(&'a self, tenant_id: Option<&'a str>, stage: &str) -> &'a str {
+        match tenant_id {
+            Some(tenant_id) => tenant_id,
+            None => {
+                tracing::warn!(
+                    "no tenant resolved for {stage}; using fallback tenant {}",
+                    self.fallback_tenant_id
+                );
+                self.fallback_tenant_id.as_str()
+            }
+        }
     }
 
     fn wake_guest_lane_key(
@@ -5707,11 +8269,11 @@ impl AdapterRuntime {
     fn wake_guest_lane_posture(
         &self,
         key: &WakeGuestLaneKey,
-    ) -> Result<Option<WakeGuestLanePosture>, String> {
+    ) -> Result<Option<WakeGuestLanePosture>, AdapterError> {
         let state = self
             .wake_guest_lane_state
             .lock()
-            .map_err(|_| "wake guest lane state lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("wake guest lane state".to_string()))?;
         Ok(state.get(key).cloned())
     }
 
@@ -5719,15 +8281,60 @@ impl AdapterRuntime {
         &self,
         key: WakeGuestLaneKey,
         posture: WakeGuestLanePosture,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let mut state = self
             .wake_guest_lane_state
             .lock()
-            .map_err(|_| "wake guest lane state lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("wake guest lane state".to_string()))?;
         state.insert(key, posture);
         Ok(())
     }
 
+    fn ph1c_detected_locale(&self, device_id: &DeviceId) -> Result<Option<String>, AdapterError> {
+        let state = self
+            .ph1c_detected_locale_by_device
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("ph1c detected locale".to_string()))?;
+        Ok(state.get(device_id.as_str()).cloned())
+    }
+
+    fn set_ph1c_detected_locale(
+        &self,
+        device_id: &DeviceId,
+        locale: String,
+    ) -> Result<(), AdapterError> {
+        let mut state = self
+            .ph1c_detected_locale_by_device
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("ph1c detected locale".to_string()))?;
+        state.insert(device_id.as_str().to_string(), locale);
+        Ok(())
+    }
+
+    // Returns true while `kind` is cooling down for the tenant; otherwise marks it fired at `now`.
+    fn read_only_incident_in_cooldown(
+        &self,
+        tenant_id: &str,
+        kind: ReadOnlyIncidentKind,
+        now: MonotonicTimeNs,
+    ) -> Result<bool, AdapterError> {
+        if self.read_only_incident_cooldown_ns == 0 {
+            return Ok(false);
+        }
+        let mut state = self
+            .read_only_incident_fired_at
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("read-only incident cooldown".to_string()))?;
+        let key = (tenant_id.to_string(), kind.tag());
+        if let Some(fired_at) = state.get(&key) {
+            if now.0.saturating_sub(*fired_at) < self.read_only_incident_cooldown_ns {
+                return Ok(true);
+            }
+        }
+        state.insert(key, now.0);
+        Ok(false)
+    }
+
     fn wake_guest_lane_turn_decision(
         &self,
         runtime_execution_envelope: &RuntimeExecutionEnvelope,
@@ -5737,7 +8344,7 @@ impl AdapterRuntime {
         device_id: &DeviceId,
         user_text_final: Option<&str>,
         thread_policy_flags: Option<&VoiceTurnThreadPolicyFlags>,
-    ) -> Result<Option<WakeGuestLaneTurnDecision>, String> {
+    ) -> Result<Option<WakeGuestLaneTurnDecision>, AdapterError> {
         if !continuing_speech_identity_prompt_session_surface(session_attach_outcome) {
             return Ok(None);
         }
@@ -5832,10 +8439,14 @@ impl AdapterRuntime {
         }
     }
 
+    // `VoiceTurnIngressError` is the wire error plus a boxed cause; it sits at the lint threshold.
+    #[allow(clippy::result_large_err)]
     pub fn run_voice_turn_ingress(
         &self,
         request: VoiceTurnAdapterRequest,
     ) -> Result<VoiceTurnAdapterResponse, VoiceTurnIngressError> {
+        let mut request = request;
+        self.apply_unknown_app_platform_policy(&mut request);
         let allow_identity_auto_provision =
             request.app_platform.trim().eq_ignore_ascii_case("DESKTOP");
         let runtime_execution_envelope =
@@ -5854,12 +8465,16 @@ impl AdapterRuntime {
         self.run_voice_turn_internal(
             request,
             Some(runtime_execution_envelope),
-            true,
+            LegacyJournalWrite::Immediate,
             allow_identity_auto_provision,
             PersistenceInvocationMode::Standard,
+            None,
+            None,
+            None,
         )
     }
 
+    #[allow(clippy::result_large_err)]
     pub fn run_voice_turn_ingress_with_execution_envelope(
         &self,
         request: VoiceTurnAdapterRequest,
@@ -5870,16 +8485,19 @@ impl AdapterRuntime {
         self.run_voice_turn_internal(
             request,
             Some(runtime_execution_envelope),
-            true,
+            LegacyJournalWrite::Immediate,
             allow_identity_auto_provision,
             PersistenceInvocationMode::Standard,
+            None,
+            None,
+            None,
         )
     }
 
     pub fn run_invite_link_open_and_start_onboarding(
         &self,
         request: InviteLinkOpenAdapterRequest,
-    ) -> Result<InviteLinkOpenAdapterResponse, String> {
+    ) -> Result<InviteLinkOpenAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let app_platform = parse_app_platform(&request.app_platform)?;
         let token_id = TokenId::new(request.token_id.clone())
@@ -5900,12 +8518,12 @@ impl AdapterRuntime {
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let now = MonotonicTimeNs(system_time_now_ns().max(1));
         let outcome = self
             .ingress
             .run_invite_link_open_and_start_onboarding(&mut store, ingress_request, now)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         Ok(InviteLinkOpenAdapterResponse {
             status: "ok".to_string(),
@@ -5921,7 +8539,7 @@ impl AdapterRuntime {
     pub fn run_onboarding_continue(
         &self,
         request: OnboardingContinueAdapterRequest,
-    ) -> Result<OnboardingContinueAdapterResponse, String> {
+    ) -> Result<OnboardingContinueAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let onboarding_session_id = OnboardingSessionId::new(request.onboarding_session_id)
             .map_err(|err| format!("invalid onboarding_session_id: {err:?}"))?;
@@ -5941,6 +8559,7 @@ impl AdapterRuntime {
                 photo_blob_ref: request.photo_blob_ref,
                 sender_decision: request.sender_decision,
             },
+            self.onboarding_field_value_max_len,
         )?;
         let ingress_request = AppOnboardingContinueRequest::v1(
             correlation_id,
@@ -5954,12 +8573,12 @@ impl AdapterRuntime {
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let now = MonotonicTimeNs(system_time_now_ns().max(1));
         let outcome = self
             .ingress
             .run_onboarding_continue(&mut store, ingress_request, now)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         Ok(OnboardingContinueAdapterResponse {
             status: "ok".to_string(),
@@ -5981,10 +8600,32 @@ impl AdapterRuntime {
         })
     }
 
+    /// Looks up the access-engine instance a completed onboarding session provisioned, for
+    /// post-onboarding provisioning audits. Returns `None` until the session completes.
+    pub fn onboarding_access_engine_instance(
+        &self,
+        onboarding_session_id: &str,
+    ) -> Result<Option<OnboardingAccessInstanceLinkView>, AdapterError> {
+        let onboarding_session_id = OnboardingSessionId::new(onboarding_session_id.to_string())
+            .map_err(|err| format!("invalid onboarding_session_id: {err:?}"))?;
+        let store = self
+            .store
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+        Ok(store
+            .ph1onb_access_instance_link(&onboarding_session_id)
+            .map(|link| OnboardingAccessInstanceLinkView {
+                onboarding_session_id: link.onboarding_session_id.as_str().to_string(),
+                access_engine_instance_id: link.access_engine_instance_id.clone(),
+                tenant_id: link.tenant_id.clone(),
+                completed_at_ns: link.completed_at.0,
+            }))
+    }
+
     pub fn run_session_resume(
         &self,
         request: SessionResumeAdapterRequest,
-    ) -> Result<SessionResumeAdapterResponse, String> {
+    ) -> Result<SessionResumeAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let session_id = request
             .session_id
@@ -6004,12 +8645,12 @@ impl AdapterRuntime {
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let now = MonotonicTimeNs(system_time_now_ns().max(1));
         let outcome = self
             .ingress
             .run_session_resume(&mut store, ingress_request, now)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         Ok(SessionResumeAdapterResponse {
             status: "ok".to_string(),
@@ -6028,7 +8669,7 @@ impl AdapterRuntime {
     pub fn run_session_recent_list(
         &self,
         request: SessionRecentListAdapterRequest,
-    ) -> Result<SessionRecentListAdapterResponse, String> {
+    ) -> Result<SessionRecentListAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let device_id = DeviceId::new(request.device_id.clone())
             .map_err(|err| format!("invalid device_id: {err:?}"))?;
@@ -6039,11 +8680,11 @@ impl AdapterRuntime {
         let store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let outcome = self
             .ingress
             .run_session_recent_list(&store, ingress_request)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         Ok(SessionRecentListAdapterResponse {
             status: "ok".to_string(),
@@ -6067,7 +8708,7 @@ impl AdapterRuntime {
     pub fn run_session_idle_close_check(
         &self,
         request: SessionIdleCloseCheckAdapterRequest,
-    ) -> Result<SessionIdleCloseCheckAdapterResponse, String> {
+    ) -> Result<SessionIdleCloseCheckAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let actor_user_id = UserId::new(request.actor_user_id.clone())
             .map_err(|err| format!("invalid actor_user_id: {err:?}"))?;
@@ -6084,11 +8725,11 @@ impl AdapterRuntime {
         device_id: DeviceId,
         correlation_id: CorrelationId,
         now: MonotonicTimeNs,
-    ) -> Result<SessionIdleCloseCheckAdapterResponse, String> {
+    ) -> Result<SessionIdleCloseCheckAdapterResponse, AdapterError> {
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let turn_id = TurnId(correlation_id.0.max(1) as u64);
         let outcome = idle_close_stage6_session_for_actor(
             &mut store,
@@ -6100,6 +8741,7 @@ impl AdapterRuntime {
             request.tts_playback_active,
             &self.runtime_node_id,
             self.session_lease_ttl_ms,
+            self.idempotency_token_max_len,
         )?;
 
         let Some(outcome) = outcome else {
@@ -6120,6 +8762,14 @@ impl AdapterRuntime {
                 correlation_id,
                 turn_id,
                 outcome.session_id,
+                self.idempotency_token_max_len,
+            )?;
+            self.settle_transcript_partials_for_closed_session(
+                &mut store,
+                now,
+                &actor_user_id,
+                &device_id,
+                outcome.session_id,
             )?;
         }
 
@@ -6154,10 +8804,95 @@ impl AdapterRuntime {
         Ok(response)
     }
 
+    /// Explicitly ends the actor's open session ("end conversation"). PH1.L is stepped to
+    /// closed, the snapshot is persisted and the session-scoped context for `thread_key` is
+    /// cleared. Returns `false` without changing anything when no open session exists, so
+    /// repeating the call is safe.
+    pub fn close_session(
+        &self,
+        user_id: &str,
+        device_id: &str,
+        thread_key: Option<&str>,
+        now_ns: u64,
+    ) -> Result<bool, AdapterError> {
+        let actor_user_id = UserId::new(user_id.to_string())
+            .map_err(|err| format!("invalid actor_user_id: {err:?}"))?;
+        let device_id = DeviceId::new(device_id.to_string())
+            .map_err(|err| format!("invalid device_id: {err:?}"))?;
+        let now = MonotonicTimeNs(now_ns.max(1));
+        let correlation_id = CorrelationId(u128::from(now.0));
+        let turn_id = TurnId(now.0);
+        let thread_key = resolve_adapter_thread_key(thread_key);
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+        let Some(record) = canonical_actor_session_selection(&store, &actor_user_id)?
+            .latest_recoverable
+            .filter(|record| record.session_state != SessionState::Closed)
+        else {
+            return Ok(false);
+        };
+        let (_, session_state) = step_stage6_session_closed(
+            &mut store,
+            now,
+            correlation_id,
+            turn_id,
+            &actor_user_id,
+            &device_id,
+            record.session_id,
+            "api_close",
+            &self.runtime_node_id,
+            self.session_lease_ttl_ms,
+            self.idempotency_token_max_len,
+        )?;
+        let closed = session_state == SessionState::Closed;
+        if closed {
+            append_stage8_session_sleep_boundary_evidence(
+                &mut store,
+                now,
+                correlation_id,
+                turn_id,
+                record.session_id,
+                self.idempotency_token_max_len,
+            )?;
+            self.settle_transcript_partials_for_closed_session(
+                &mut store,
+                now,
+                &actor_user_id,
+                &device_id,
+                record.session_id,
+            )?;
+        }
+        let thread_state = load_ph1x_thread_state_cached(
+            &self.ph1x_thread_state_cache,
+            &*store,
+            &actor_user_id,
+            &thread_key,
+        );
+        clear_stage6_session_scoped_contexts(
+            &self.active_session_context_state,
+            &self.weather_context_state,
+            &self.public_discourse_state,
+            &self.ph1x_thread_state_cache,
+            &mut store,
+            now,
+            correlation_id,
+            turn_id,
+            &actor_user_id,
+            &thread_key,
+            thread_state,
+            self.idempotency_token_max_len,
+        )?;
+        drop(store);
+        self.sync_internal_history_evidence_to_persistence()?;
+        Ok(closed)
+    }
+
     pub fn run_session_posture_evidence(
         &self,
         request: SessionPostureEvidenceAdapterRequest,
-    ) -> Result<SessionPostureEvidenceAdapterResponse, String> {
+    ) -> Result<SessionPostureEvidenceAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let session_id = request
             .session_id
@@ -6177,11 +8912,11 @@ impl AdapterRuntime {
         let store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let mut evidence = self
             .ingress
             .run_session_posture_evidence(&store, ingress_request)
-            .map_err(storage_error_to_string)?
+            .map_err(AdapterError::Storage)?
             .evidence;
         drop(store);
 
@@ -6230,7 +8965,7 @@ impl AdapterRuntime {
     pub fn run_session_attach(
         &self,
         request: SessionAttachAdapterRequest,
-    ) -> Result<SessionAttachAdapterResponse, String> {
+    ) -> Result<SessionAttachAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let session_id = request
             .session_id
@@ -6250,12 +8985,12 @@ impl AdapterRuntime {
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let now = MonotonicTimeNs(system_time_now_ns().max(1));
         let outcome = self
             .ingress
             .run_session_attach(&mut store, ingress_request, now)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         Ok(SessionAttachAdapterResponse {
             status: "ok".to_string(),
@@ -6274,7 +9009,7 @@ impl AdapterRuntime {
     pub fn run_session_recover(
         &self,
         request: SessionRecoverAdapterRequest,
-    ) -> Result<SessionRecoverAdapterResponse, String> {
+    ) -> Result<SessionRecoverAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let session_id = request
             .session_id
@@ -6294,12 +9029,12 @@ impl AdapterRuntime {
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let now = MonotonicTimeNs(system_time_now_ns().max(1));
         let outcome = self
             .ingress
             .run_session_recover(&mut store, ingress_request, now)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         Ok(SessionRecoverAdapterResponse {
             status: "ok".to_string(),
@@ -6318,7 +9053,7 @@ impl AdapterRuntime {
     pub fn run_wake_profile_availability_refresh(
         &self,
         request: WakeProfileAvailabilityRefreshAdapterRequest,
-    ) -> Result<WakeProfileAvailabilityRefreshAdapterResponse, String> {
+    ) -> Result<WakeProfileAvailabilityRefreshAdapterResponse, AdapterError> {
         let correlation_id = CorrelationId(u128::from(request.correlation_id));
         let device_id = DeviceId::new(request.device_id.clone())
             .map_err(|err| format!("invalid device_id: {err:?}"))?;
@@ -6334,12 +9069,12 @@ impl AdapterRuntime {
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let now = MonotonicTimeNs(system_time_now_ns().max(1));
         let outcome = self
             .ingress
             .run_wake_profile_availability_refresh(&mut store, ingress_request, now)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         Ok(WakeProfileAvailabilityRefreshAdapterResponse {
             status: "ok".to_string(),
@@ -6358,14 +9093,14 @@ impl AdapterRuntime {
         &self,
         session_id: &str,
         device_id: &str,
-    ) -> Result<SessionPosturePersistenceEvidence, String> {
+    ) -> Result<SessionPosturePersistenceEvidence, AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(SessionPosturePersistenceEvidence::default());
         };
         let guard = persistence
             .state
             .lock()
-            .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
         validate_persistence_state_integrity(&guard)
             .map_err(|err| format!("persistence state integrity check failed: {err}"))?;
 
@@ -6417,31 +9152,130 @@ impl AdapterRuntime {
             .map(|assertion| format!("{assertion:?}"))
     }
 
-    pub fn run_device_artifact_sync_worker_pass(&self, now_ns: Option<u64>) -> Result<(), String> {
+    pub fn run_device_artifact_sync_worker_pass(
+        &self,
+        now_ns: Option<u64>,
+    ) -> Result<(), AdapterError> {
         let now_ns = now_ns.unwrap_or_else(system_time_now_ns).max(1);
         let _ = self.run_device_artifact_sync_worker_pass_internal(now_ns)?;
+        self.publish_health_update(now_ns)
+    }
+
+    /// Dead-lettered sync jobs, in queue order.
+    pub fn list_dead_letters(&self) -> Result<Vec<DeadLetterRow>, AdapterError> {
+        let store = self
+            .store
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+        Ok(store
+            .device_artifact_sync_dead_letter_rows()
+            .into_iter()
+            .map(|row| DeadLetterRow {
+                sync_job_id: row.sync_job_id.clone(),
+                sync_kind: format!("{:?}", row.sync_kind),
+                attempt_count: row.attempt_count,
+                last_error: row.last_error.clone(),
+                user_id: row.user_id.as_ref().map(|id| id.as_str().to_string()),
+                device_id: row.device_id.as_str().to_string(),
+            })
+            .collect())
+    }
+
+    /// Moves a dead-lettered sync job back to `Queued` with its attempt count reset, so the
+    /// next worker pass sends it again. Fails if the job is unknown or not dead-lettered.
+    pub fn requeue_dead_letter(&self, sync_job_id: &str) -> Result<(), AdapterError> {
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+        store.device_artifact_sync_requeue_dead_letter_commit(sync_job_id)?;
+        Ok(())
+    }
+
+    /// Subscribes to health snapshots pushed after sync worker passes.
+    ///
+    /// A snapshot is sent when sync worker totals or queue counts change, at most once per
+    /// coalescing window; changes inside the window go out with the first pass after it.
+    /// The channel is bounded. A subscriber whose channel is full misses that snapshot and
+    /// gets the latest one on the next send. Dropping the receiver unsubscribes.
+    pub fn subscribe_health(&self) -> Receiver<AdapterHealthResponse> {
+        let (tx, rx) = sync_channel(HEALTH_SUBSCRIPTION_CHANNEL_CAPACITY);
+        if let Ok(mut state) = self.health_subscription_state.lock() {
+            state.subscribers.push(tx);
+        }
+        rx
+    }
+
+    fn publish_health_update(&self, now_ns: u64) -> Result<(), AdapterError> {
+        let has_subscribers = !self
+            .health_subscription_state
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("health subscription".to_string()))?
+            .subscribers
+            .is_empty();
+        if !has_subscribers {
+            return Ok(());
+        }
+        let report = self.health_report(Some(now_ns))?;
+        let mark = health_subscription_mark(&report.sync);
+
+        let mut state = self
+            .health_subscription_state
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("health subscription".to_string()))?;
+        if state.last_published_sync.as_ref() == Some(&mark) {
+            return Ok(());
+        }
+        if state.last_published_at_ns.is_some_and(|last| {
+            now_ns.saturating_sub(last) < HEALTH_SUBSCRIPTION_COALESCE_WINDOW_NS
+        }) {
+            return Ok(());
+        }
+        state
+            .subscribers
+            .retain(|tx| match tx.try_send(report.clone()) {
+                Ok(()) | Err(TrySendError::Full(_)) => true,
+                Err(TrySendError::Disconnected(_)) => false,
+            });
+        state.last_published_sync = Some(mark);
+        state.last_published_at_ns = Some(now_ns);
         Ok(())
     }
 
-    pub fn health_report(&self, now_ns: Option<u64>) -> Result<AdapterHealthResponse, String> {
+    pub fn health_report(
+        &self,
+        now_ns: Option<u64>,
+    ) -> Result<AdapterHealthResponse, AdapterError> {
         let now_ns = now_ns.unwrap_or_else(system_time_now_ns).max(1);
         let now = MonotonicTimeNs(now_ns);
         let store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         let queue = snapshot_sync_queue_counters(&store, now);
         drop(store);
         let worker = self
             .sync_worker_counters
             .lock()
-            .map_err(|_| "adapter sync worker counters lock poisoned".to_string())?
+            .map_err(|_| AdapterError::LockPoisoned("sync worker counters".to_string()))?
             .clone();
         let improvement = self
             .improvement_counters
             .lock()
-            .map_err(|_| "adapter improvement counters lock poisoned".to_string())?
+            .map_err(|_| AdapterError::LockPoisoned("improvement counters".to_string()))?
             .clone();
+        let provider_latency = {
+            let state = self
+                .provider_latency_state
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("provider latency".to_string()))?;
+            provider_latency_health(&state.recent_latency_ms, self.provider_latency_policy)
+        };
+        let provider = self
+            .provider_circuit_state
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("provider circuit".to_string()))?
+            .health(self.provider_circuit_breaker_policy, system_time_now_ns());
 
         Ok(AdapterHealthResponse {
             status: "ok".to_string(),
@@ -6452,24 +9286,58 @@ impl AdapterRuntime {
                 queue,
                 improvement,
             },
+            provider_latency,
+            provider,
             provenance: None,
         })
     }
 
+    /// Sync worker, sync queue and improvement counters in Prometheus text exposition format,
+    /// for a scrape endpoint. Metric names carry the `selene_adapter_` prefix.
+    pub fn metrics_prometheus(&self) -> String {
+        match self.health_report(None) {
+            Ok(health) => render_sync_health_prometheus(&health.sync),
+            Err(err) => format!("# selene_adapter metrics unavailable: {err}\n"),
+        }
+    }
+
+    /// Flushes the telemetry sink, such as PH1.K eval rows buffered under
+    /// `SELENE_ADAPTER_EVAL_CSV_FLUSH`. Call it before the process exits; dropping the last
+    /// runtime clone does the same for the CSV sink on a best-effort basis.
+    pub fn shutdown(&self) -> Result<(), AdapterError> {
+        self.telemetry_sink.flush()
+    }
+
+    pub fn record_provider_latency_ms(&self, latency_ms: u32) -> Result<(), AdapterError> {
+        let window = self.provider_latency_policy.window;
+        let mut state = self
+            .provider_latency_state
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("provider latency".to_string()))?;
+        state.recent_latency_ms.push_back(latency_ms);
+        while state.recent_latency_ms.len() > window {
+            state.recent_latency_ms.pop_front();
+        }
+        Ok(())
+    }
     pub fn ui_health_checks_report(
         &self,
         now_ns: Option<u64>,
-    ) -> Result<UiHealthChecksResponse, String> {
+    ) -> Result<UiHealthChecksResponse, AdapterError> {
         let now_ns = now_ns.unwrap_or_else(system_time_now_ns).max(1);
         let health = self.health_report(Some(now_ns))?;
-        Ok(build_ui_health_checks_response(&health, now_ns))
+        Ok(build_ui_health_checks_response(
+            &health,
+            now_ns,
+            self.sync_worker_stale_after_ms.saturating_mul(1_000_000),
+        ))
     }
 
     pub fn ui_health_detail_report(
         &self,
         check_id: &str,
         now_ns: Option<u64>,
-    ) -> Result<UiHealthDetailResponse, String> {
+    ) -> Result<UiHealthDetailResponse, AdapterError> {
         self.ui_health_detail_report_filtered(check_id, UiHealthDetailFilter::default(), now_ns)
     }
 
@@ -6478,17 +9346,22 @@ impl AdapterRuntime {
         check_id: &str,
         filter: UiHealthDetailFilter,
         now_ns: Option<u64>,
-    ) -> Result<UiHealthDetailResponse, String> {
+    ) -> Result<UiHealthDetailResponse, AdapterError> {
         if let (Some(from), Some(to)) = (filter.from_utc_ns, filter.to_utc_ns) {
             if from > to {
-                return Err(
+                return Err(AdapterError::Validation(
                     "invalid health detail date range: from_utc_ns is after to_utc_ns".to_string(),
-                );
+                ));
             }
         }
         let now_ns = now_ns.unwrap_or_else(system_time_now_ns).max(1);
         let health = self.health_report(Some(now_ns))?;
-        let mut detail = build_ui_health_detail_response(&health, check_id, now_ns)?;
+        let mut detail = build_ui_health_detail_response(
+            &health,
+            check_id,
+            now_ns,
+            &self.health_issue_deadlines,
+        )?;
         detail.issues = filter_health_issues(&detail.issues, &filter);
         detail.active_issue_id =
             select_active_issue_id(&detail.issues, filter.selected_issue_id.as_deref());
@@ -6504,32 +9377,57 @@ impl AdapterRuntime {
         Ok(detail)
     }
 
-    pub fn ui_chat_transcript_report(&self, now_ns: Option<u64>) -> UiChatTranscriptResponse {
+    /// Merged chat transcript: finals from the conversation ledger, then any partials whose
+    /// turn has no final yet. With a filter, the merged list is narrowed and paged in
+    /// `(timestamp_ns, correlation_id, turn_id, role, source)` order; `next_cursor` resumes
+    /// after the last message returned.
+    pub fn ui_chat_transcript_report(
+        &self,
+        now_ns: Option<u64>,
+        filter: Option<&UiTranscriptFilter>,
+    ) -> UiChatTranscriptResponse {
         let now_ns = now_ns.unwrap_or_else(system_time_now_ns).max(1);
+        let error_response = |note: String| UiChatTranscriptResponse {
+            status: "error".to_string(),
+            generated_at_ns: now_ns,
+            note: Some(note),
+            messages: Vec::new(),
+            next_cursor: None,
+        };
+        let default_filter = UiTranscriptFilter::default();
+        let filter = filter.unwrap_or(&default_filter);
+        let role_filter = match filter.role.as_deref() {
+            Some(raw) => match AdapterTranscriptRole::parse(raw) {
+                Some(role) => Some(role),
+                None => return error_response(format!("invalid transcript role '{raw}'")),
+            },
+            None => None,
+        };
+        let cursor = match filter.cursor.as_deref().map(parse_transcript_cursor) {
+            Some(Ok(cursor)) => Some(cursor),
+            Some(Err(err)) => return error_response(err),
+            None => None,
+        };
         let final_events = match self.store.lock() {
-            Ok(store) => store
-                .conversation_ledger()
-                .iter()
-                .filter_map(adapter_transcript_event_from_record)
-                .collect::<Vec<_>>(),
+            Ok(store) => {
+                let tombstoned = tombstoned_conversation_turn_ids(&store);
+                store
+                    .conversation_ledger()
+                    .iter()
+                    .filter(|record| !tombstoned.contains(&record.conversation_turn_id))
+                    .filter_map(adapter_transcript_event_from_record)
+                    .collect::<Vec<_>>()
+            }
             Err(_) => {
-                return UiChatTranscriptResponse {
-                    status: "error".to_string(),
-                    generated_at_ns: now_ns,
-                    note: Some("adapter store lock poisoned".to_string()),
-                    messages: Vec::new(),
-                };
+                return error_response(AdapterError::LockPoisoned("store".to_string()).to_string())
             }
         };
         let partial_events = match self.transcript_state.lock() {
             Ok(state) => state.events.clone(),
             Err(_) => {
-                return UiChatTranscriptResponse {
-                    status: "error".to_string(),
-                    generated_at_ns: now_ns,
-                    note: Some("adapter transcript lock poisoned".to_string()),
-                    messages: Vec::new(),
-                };
+                return error_response(
+                    AdapterError::LockPoisoned("transcript".to_string()).to_string(),
+                )
             }
         };
 
@@ -6563,49 +9461,45 @@ impl AdapterRuntime {
             partial_by_key.insert(key, event);
         }
 
-        let mut ordered = Vec::new();
-        for (_, event) in final_by_key {
-            ordered.push((
-                event.timestamp_ns,
-                event.correlation_id.0,
-                event.turn_id.0,
-                event.role,
-                UiTranscriptMessage {
-                    role: event.role.as_str().to_string(),
-                    source: event.source.as_str().to_string(),
-                    finalized: true,
-                    text: event.text,
-                    timestamp_ns: event.timestamp_ns,
-                },
-            ));
-        }
-        for (_, event) in partial_by_key {
-            ordered.push((
-                event.timestamp_ns,
-                event.correlation_id.0,
-                event.turn_id.0,
-                event.role,
-                UiTranscriptMessage {
-                    role: event.role.as_str().to_string(),
-                    source: event.source.as_str().to_string(),
-                    finalized: false,
-                    text: event.text,
-                    timestamp_ns: event.timestamp_ns,
-                },
-            ));
-        }
-
-        ordered.sort_by(|left, right| {
-            left.0
-                .cmp(&right.0)
-                .then_with(|| left.1.cmp(&right.1))
-                .then_with(|| left.2.cmp(&right.2))
-                .then_with(|| left.3.cmp(&right.3))
-        });
-        let messages = ordered
-            .into_iter()
-            .map(|(_, _, _, _, msg)| msg)
+        let mut ordered = final_by_key
+            .into_values()
+            .map(|event| (true, event))
+            .chain(partial_by_key.into_values().map(|event| (false, event)))
+            .map(|(finalized, event)| {
+                (
+                    AdapterTranscriptCursor::for_event(&event),
+                    UiTranscriptMessage {
+                        role: event.role.as_str().to_string(),
+                        source: event.source.as_str().to_string(),
+                        finalized,
+                        text: event.text,
+                        timestamp_ns: event.timestamp_ns,
+                    },
+                )
+            })
+            .filter(|(position, _)| {
+                filter
+                    .correlation_id
+                    .is_none_or(|id| position.correlation_id == u128::from(id))
+                    && filter
+                        .from_ts_ns
+                        .is_none_or(|from| position.timestamp_ns >= from)
+                    && filter.to_ts_ns.is_none_or(|to| position.timestamp_ns <= to)
+                    && role_filter.is_none_or(|role| position.role == role)
+                    && cursor.is_none_or(|cursor| *position > cursor)
+            })
             .collect::<Vec<_>>();
+        ordered.sort_by_key(|(position, _)| *position);
+
+        let mut next_cursor = None;
+        if let Some(page_size) = filter.page_size {
+            let page_size = page_size.clamp(1, 200) as usize;
+            if ordered.len() > page_size {
+                ordered.truncate(page_size);
+                next_cursor = ordered.last().map(|(position, _)| position.encode());
+            }
+        }
+        let messages = ordered.into_iter().map(|(_, msg)| msg).collect::<Vec<_>>();
         let note = if messages.is_empty() {
             Some("No transcript messages yet.".to_string())
         } else {
@@ -6617,7 +9511,104 @@ impl AdapterRuntime {
             generated_at_ns: now_ns,
             note,
             messages,
+            next_cursor,
+        }
+    }
+
+    /// Erases a user's chat transcript. Every conversation ledger row the user owns gets a
+    /// `Tombstone` row, which hides it from `ui_chat_transcript_report`, and the original row's
+    /// text is replaced with `[REDACTED]` in the store and the persisted state, as are the recent
+    /// archive thread digests built from it. The user's open partials are dropped from memory.
+    /// The journal is then compacted, which drops the user's legacy journal entries, and an
+    /// existing store snapshot is rewritten from the redacted store.
+    pub fn erase_user_transcript(
+        &self,
+        actor_user_id: &UserId,
+    ) -> Result<EraseReport, AdapterError> {
+        let now = MonotonicTimeNs(system_time_now_ns().max(1));
+        let (ledger_rows_tombstoned, redacted_rows) = {
+            let mut store = self
+                .store
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+            let tombstoned = tombstoned_conversation_turn_ids(&store);
+            let originals = store
+                .conversation_ledger()
+                .iter()
+                .filter(|row| {
+                    row.user_id == *actor_user_id
+                        && row.source != ConversationSource::Tombstone
+                        && !tombstoned.contains(&row.conversation_turn_id)
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            for original in &originals {
+                let input = ConversationTurnInput::v1(
+                    now,
+                    original.correlation_id,
+                    original.turn_id,
+                    original.session_id,
+                    original.user_id.clone(),
+                    original.device_id.clone(),
+                    ConversationRole::Selene,
+                    ConversationSource::Tombstone,
+                    "[REDACTED]".to_string(),
+                    stable_hash_hex_16("[REDACTED]"),
+                    original.privacy_scope,
+                    Some(sanitize_idempotency_token(
+                        &format!(
+                            "adapter_transcript_erase:{}",
+                            original.conversation_turn_id.0
+                        ),
+                        self.idempotency_token_max_len,
+                    )),
+                    Some(original.conversation_turn_id),
+                    Some(reason_codes::ADAPTER_TRANSCRIPT_ERASED),
+                )
+                .map_err(|err| format!("invalid transcript tombstone input: {err:?}"))?;
+                store
+                    .append_conversation_turn(input)
+                    .map_err(AdapterError::Storage)?;
+            }
+            // Recent archive threads are built from these rows, so their digests go too.
+            let archive_thread_ids = originals
+                .iter()
+                .filter_map(|row| {
+                    let session_id = row.session_id?;
+                    Some([
+                        recent_archive_thread_id_for_session(session_id),
+                        recent_archive_thread_id_for_session_turn(session_id, row.turn_id),
+                    ])
+                })
+                .flatten()
+                .collect::<BTreeSet<_>>();
+            store.redact_memory_threads(actor_user_id, &archive_thread_ids);
+            (
+                originals.len() as u64,
+                store.redact_tombstoned_conversation_turns(),
+            )
+        };
+        let partial_events_removed = {
+            let mut state = self
+                .transcript_state
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("transcript".to_string()))?;
+            let before = state.events.len();
+            state.events.retain(|event| event.user_id != *actor_user_id);
+            before.saturating_sub(state.events.len()) as u64
+        };
+        self.redact_persisted_user_text(actor_user_id, &redacted_rows)?;
+        self.sync_internal_history_evidence_to_persistence()?;
+        self.compact_journal_internal()?;
+        if let Some(persistence) = self.persistence.as_ref() {
+            if adapter_store_snapshot_path(&persistence.legacy_journal_path).exists() {
+                self.write_store_snapshot_internal()?;
+            }
         }
+        Ok(EraseReport {
+            ledger_rows_tombstoned,
+            partial_events_removed,
+        })
     }
 
     pub fn ui_internal_history_evidence_report(
@@ -6629,7 +9620,7 @@ impl AdapterRuntime {
             return UiInternalHistoryEvidenceResponse {
                 status: "error".to_string(),
                 generated_at_ns: now_ns,
-                note: Some(err),
+                note: Some(err.to_string()),
                 total_events: 0,
                 events: Vec::new(),
             };
@@ -6657,7 +9648,7 @@ impl AdapterRuntime {
                     return UiInternalHistoryEvidenceResponse {
                         status: "error".to_string(),
                         generated_at_ns: now_ns,
-                        note: Some("adapter store lock poisoned".to_string()),
+                        note: Some(AdapterError::LockPoisoned("store".to_string()).to_string()),
                         total_events: 0,
                         events: Vec::new(),
                     };
@@ -6681,7 +9672,7 @@ impl AdapterRuntime {
     pub fn record_desktop_openai_tts_evidence(
         &self,
         input: DesktopOpenAiTtsEvidenceInput,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let Some(turn_id) = input.turn_id.filter(|value| *value > 0) else {
             return Ok(());
         };
@@ -6713,7 +9704,7 @@ impl AdapterRuntime {
         response.playback_failure_ref = input.failure_reason.as_ref().map(|reason| {
             format!(
                 "openai_tts_fail_closed:{}",
-                sanitize_idempotency_token(reason)
+                sanitize_idempotency_token(reason, self.idempotency_token_max_len)
             )
         });
         response.spoken_matches_final_answer = Some(matches!(
@@ -6728,29 +9719,29 @@ impl AdapterRuntime {
         ));
         refs.device_surface_provenance_refs.push(format!(
             "desktop_tts_device:{}",
-            sanitize_idempotency_token(&input.device_id)
+            sanitize_idempotency_token(&input.device_id, self.idempotency_token_max_len)
         ));
         refs.device_surface_provenance_refs.push(format!(
             "desktop_tts_actor:{}",
-            sanitize_idempotency_token(&input.actor_user_id)
+            sanitize_idempotency_token(&input.actor_user_id, self.idempotency_token_max_len)
         ));
         refs.replay_integrity_refs.push(format!(
             "openai_tts_request:{}",
             input
                 .request_id
                 .as_deref()
-                .map(sanitize_idempotency_token)
+                .map(|value| sanitize_idempotency_token(value, self.idempotency_token_max_len))
                 .unwrap_or_else(|| format!("{}:{}", correlation_id.0, turn_id.0))
         ));
         refs.replay_integrity_refs.push(format!(
             "openai_tts_model:{}:voice:{}",
-            sanitize_idempotency_token(&input.model),
-            sanitize_idempotency_token(&input.voice)
+            sanitize_idempotency_token(&input.model, self.idempotency_token_max_len),
+            sanitize_idempotency_token(&input.voice, self.idempotency_token_max_len)
         ));
         if let Some(reason) = input.failure_reason.as_deref() {
             refs.audit_refs.push(format!(
                 "openai_tts_fail_closed_reason:{}",
-                sanitize_idempotency_token(reason)
+                sanitize_idempotency_token(reason, self.idempotency_token_max_len)
             ));
         }
 
@@ -6771,34 +9762,96 @@ impl AdapterRuntime {
             LiveContextEvidenceRefs::none(),
             MemoryEvidenceRefs::none(),
             refs,
-            Some(sanitize_idempotency_token(&format!(
-                "stage7_desktop_openai_tts:{:?}:{}:{}",
-                input.status, correlation_id.0, turn_id.0
-            ))),
+            Some(sanitize_idempotency_token(
+                &format!(
+                    "stage7_desktop_openai_tts:{:?}:{}:{}",
+                    input.status, correlation_id.0, turn_id.0
+                ),
+                self.idempotency_token_max_len,
+            )),
         )
         .map_err(|err| format!("invalid desktop OpenAI TTS evidence: {err:?}"))?;
 
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         store
             .append_internal_history_evidence(evidence)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
         drop(store);
         self.sync_internal_history_evidence_to_persistence()?;
         Ok(())
     }
 
+    // System messages are not replayable voice turns, so they never enter the request journal.
+    // The row is written to the persistence state with the rest of the conversation ledger and
+    // comes back from there on restart.
+    pub fn record_transcript_system_message(
+        &self,
+        input: TranscriptSystemMessageInput,
+    ) -> Result<(), AdapterError> {
+        if input.correlation_id == 0 || input.turn_id == 0 {
+            return Err(AdapterError::Validation(
+                "invalid_transcript_system_message_identity".to_string(),
+            ));
+        }
+        let now = MonotonicTimeNs(input.now_ns.unwrap_or_else(system_time_now_ns).max(1));
+        let correlation_id = CorrelationId(input.correlation_id.into());
+        let turn_id = TurnId(input.turn_id);
+        let actor_user_id = UserId::new(input.actor_user_id.trim().to_string())
+            .map_err(|err| format!("invalid transcript system message actor_user_id: {err:?}"))?;
+        let device_id = input
+            .device_id
+            .as_deref()
+            .map(|value| DeviceId::new(value.trim().to_string()))
+            .transpose()
+            .map_err(|err| format!("invalid transcript system message device_id: {err:?}"))?;
+        let session_id = input
+            .session_id
+            .as_deref()
+            .and_then(|value| value.trim().parse::<u128>().ok())
+            .filter(|value| *value > 0)
+            .map(SessionId);
+        let source = match input.kind {
+            TranscriptSystemMessageKind::SystemNotice => ConversationSource::SystemNotice,
+            TranscriptSystemMessageKind::ToolResult => ConversationSource::ToolResult,
+        };
+
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+        append_transcript_final_conversation_turn(
+            &mut store,
+            now,
+            correlation_id,
+            turn_id,
+            &actor_user_id,
+            device_id.as_ref(),
+            session_id,
+            ConversationRole::System,
+            source,
+            PrivacyScope::PublicChat,
+            None,
+            &input.text,
+            self.idempotency_token_max_len,
+        )?;
+        drop(store);
+        self.sync_internal_history_evidence_to_persistence()
+    }
+
     pub fn record_desktop_rejected_voice_evidence(
         &self,
         input: DesktopRejectedVoiceEvidenceInput,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         if input.correlation_id == 0
             || input.actor_user_id.trim().is_empty()
             || input.device_id.trim().is_empty()
         {
-            return Err("invalid_desktop_rejected_voice_evidence_identity".to_string());
+            return Err(AdapterError::Validation(
+                "invalid_desktop_rejected_voice_evidence_identity".to_string(),
+            ));
         }
 
         let now = MonotonicTimeNs(system_time_now_ns().max(1));
@@ -6819,30 +9872,40 @@ impl AdapterRuntime {
             .and_then(|value| value.trim().parse::<u128>().ok())
             .filter(|value| *value > 0)
             .map(SessionId);
-        let tenant_id =
-            resolve_tenant_scope(input.tenant_id.clone(), &actor_user_id, Some(&device_id))
-                .unwrap_or_else(|| "tenant_default".to_string());
-        let source = sanitize_idempotency_token(&input.source);
-        let reason = sanitize_idempotency_token(&input.rejected_reason);
+        let tenant_id = self
+            .tenant_or_fallback(
+                resolve_tenant_scope(
+                    input.tenant_id.clone(),
+                    &actor_user_id,
+                    Some(&device_id),
+                    self.tenant_delimiter,
+                )
+                .as_deref(),
+                "desktop_rejected_voice_evidence",
+            )
+            .to_string();
+        let source = sanitize_idempotency_token(&input.source, self.idempotency_token_max_len);
+        let reason =
+            sanitize_idempotency_token(&input.rejected_reason, self.idempotency_token_max_len);
         let evidence_class = input
             .evidence_class
             .as_deref()
-            .map(sanitize_idempotency_token)
+            .map(|value| sanitize_idempotency_token(value, self.idempotency_token_max_len))
             .unwrap_or_else(|| "class_not_provided".to_string());
         let request_ref = input
             .request_id
             .as_deref()
-            .map(sanitize_idempotency_token)
+            .map(|value| sanitize_idempotency_token(value, self.idempotency_token_max_len))
             .unwrap_or_else(|| format!("{}:{}", correlation_id.0, turn_id.0));
-        let idempotency_key = sanitize_idempotency_token(&format!(
-            "ph1c_desktop_reject_{}_{}",
-            correlation_id.0, turn_id.0
-        ));
+        let idempotency_key = sanitize_idempotency_token(
+            &format!("ph1c_desktop_reject_{}_{}", correlation_id.0, turn_id.0),
+            self.idempotency_token_max_len,
+        );
 
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         ensure_actor_identity_and_device(
             &mut store,
             &actor_user_id,
@@ -6865,7 +9928,7 @@ impl AdapterRuntime {
                 input.transcript_hash,
                 idempotency_key,
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
         let mut refs = InternalHistoryEvidenceRefs::none();
         refs.audit_refs.push(format!(
             "desktop_rejected_voice_reason:{source}:{reason}:{evidence_class}"
@@ -6891,15 +9954,18 @@ impl AdapterRuntime {
             LiveContextEvidenceRefs::none(),
             MemoryEvidenceRefs::none(),
             refs,
-            Some(sanitize_idempotency_token(&format!(
-                "stage7_desktop_voice_reject_note_{}_{}",
-                correlation_id.0, turn_id.0
-            ))),
+            Some(sanitize_idempotency_token(
+                &format!(
+                    "stage7_desktop_voice_reject_note_{}_{}",
+                    correlation_id.0, turn_id.0
+                ),
+                self.idempotency_token_max_len,
+            )),
         )
         .map_err(|err| format!("invalid desktop rejected voice evidence note: {err:?}"))?;
         store
             .append_internal_history_evidence(note)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
         drop(store);
         self.sync_internal_history_evidence_to_persistence()?;
         Ok(())
@@ -6916,7 +9982,9 @@ impl AdapterRuntime {
                 return PublicBrainTraceReportResponse {
                     status: "error".to_string(),
                     generated_at_ns: now_ns,
-                    note: Some("adapter public brain trace lock poisoned".to_string()),
+                    note: Some(
+                        AdapterError::LockPoisoned("public brain trace".to_string()).to_string(),
+                    ),
                     traces: Vec::new(),
                 };
             }
@@ -6951,13 +10019,15 @@ impl AdapterRuntime {
     fn record_ph1d_provider_transport_evidence(
         &self,
         evidence: Ph1dProviderTransportEvidence,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let mut state = self
             .ph1d_provider_transport_evidence_state
             .lock()
-            .map_err(|_| "adapter ph1d provider transport evidence lock poisoned".to_string())?;
-        eprintln!(
-            "selene_adapter ph1d_provider_transport_evidence provider_id={} expected_model_id={} actual_model_id={} provider_attempt_count={} provider_network_dispatch_count={} fallback_model_used={} cheaper_model_used={} unapproved_model_used={} raw_provider_output_exposed={} protected_execution_authorized={} ph1x_directive_ref={} ph1write_output_ref={}",
+            .map_err(|_| {
+                AdapterError::LockPoisoned("ph1d provider transport evidence".to_string())
+            })?;
+        tracing::info!(
+            "ph1d_provider_transport_evidence provider_id={} expected_model_id={} actual_model_id={} provider_attempt_count={} provider_network_dispatch_count={} fallback_model_used={} cheaper_model_used={} unapproved_model_used={} raw_provider_output_exposed={} protected_execution_authorized={} ph1x_directive_ref={} ph1write_output_ref={}",
             evidence.provider_id,
             evidence.expected_model_id,
             evidence.actual_model_id,
@@ -6985,13 +10055,13 @@ impl AdapterRuntime {
     fn record_ph1d_provider_error_evidence(
         &self,
         evidence: Ph1dProviderErrorEvidence,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let mut state = self
             .ph1d_provider_error_evidence_state
             .lock()
-            .map_err(|_| "adapter ph1d provider error evidence lock poisoned".to_string())?;
-        eprintln!(
-            "selene_adapter ph1d_provider_error_evidence provider_id={} endpoint={} model_sent={} http_status={} error_type={} error_code={} provider_attempt_count={} provider_network_dispatch_count={} raw_body_retained={} secret_exposed={} prompt_exposed={} protected_execution_authorized={} sanitized_message={}",
+            .map_err(|_| AdapterError::LockPoisoned("ph1d provider error evidence".to_string()))?;
+        tracing::warn!(
+            "ph1d_provider_error_evidence provider_id={} endpoint={} model_sent={} http_status={} error_type={} error_code={} provider_attempt_count={} provider_network_dispatch_count={} raw_body_retained={} secret_exposed={} prompt_exposed={} protected_execution_authorized={} sanitized_message={}",
             evidence.provider_id,
             evidence.endpoint,
             evidence.model_sent,
@@ -7017,11 +10087,11 @@ impl AdapterRuntime {
         Ok(())
     }
 
-    fn record_public_brain_trace(&self, trace: PublicBrainTraceRow) -> Result<(), String> {
+    fn record_public_brain_trace(&self, trace: PublicBrainTraceRow) -> Result<(), AdapterError> {
         let mut state = self
             .public_brain_trace_state
             .lock()
-            .map_err(|_| "adapter public brain trace lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("public brain trace".to_string()))?;
         state.traces.push(trace);
         let max = h410_public_brain_trace_max_entries();
         if state.traces.len() > max {
@@ -7035,12 +10105,12 @@ impl AdapterRuntime {
         &self,
         actor_user_id: &UserId,
         thread_key: &str,
-    ) -> Result<AdapterPublicDiscourseFrame, String> {
+    ) -> Result<AdapterPublicDiscourseFrame, AdapterError> {
         let key = h411_public_discourse_scope_key(actor_user_id, thread_key);
         let state = self
             .public_discourse_state
             .lock()
-            .map_err(|_| "adapter public discourse lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("public discourse".to_string()))?;
         Ok(state.frames.get(&key).cloned().unwrap_or_default())
     }
 
@@ -7051,12 +10121,12 @@ impl AdapterRuntime {
         captured_text: &str,
         response_text: &str,
         route_label: Option<&str>,
-    ) -> Result<AdapterPublicDiscourseFrame, String> {
+    ) -> Result<AdapterPublicDiscourseFrame, AdapterError> {
         let key = h411_public_discourse_scope_key(actor_user_id, thread_key);
         let mut state = self
             .public_discourse_state
             .lock()
-            .map_err(|_| "adapter public discourse lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("public discourse".to_string()))?;
         let frame = state.frames.entry(key).or_default();
         h411_update_public_discourse_frame(frame, captured_text, response_text, route_label);
         Ok(frame.clone())
@@ -7066,6 +10136,15 @@ impl AdapterRuntime {
         &self,
         request: UiHealthReportQueryRequest,
         now_ns: Option<u64>,
+    ) -> UiHealthReportQueryResponse {
+        self.run_ui_health_report_query(request, now_ns, true)
+    }
+
+    fn run_ui_health_report_query(
+        &self,
+        request: UiHealthReportQueryRequest,
+        now_ns: Option<u64>,
+        remember_display_target: bool,
     ) -> UiHealthReportQueryResponse {
         let now_ns = now_ns.unwrap_or_else(system_time_now_ns).max(1);
         let viewer_user_id = request
@@ -7109,8 +10188,10 @@ impl AdapterRuntime {
             }
         };
 
-        if let Ok(mut remembered) = self.report_display_target_defaults.lock() {
-            remembered.insert(viewer_user_id, display_target_applied.clone());
+        if remember_display_target {
+            if let Ok(mut remembered) = self.report_display_target_defaults.lock() {
+                remembered.insert(viewer_user_id, display_target_applied.clone());
+            }
         }
 
         let health = match self.health_report(Some(now_ns)) {
@@ -7134,7 +10215,7 @@ impl AdapterRuntime {
                     },
                     display_target_applied: Some(display_target_applied),
                     remembered_display_target: remembered_target,
-                    requires_clarification: Some(err),
+                    requires_clarification: Some(err.to_string()),
                 };
             }
         };
@@ -7167,8 +10248,9 @@ impl AdapterRuntime {
 
         let from_ns = request
             .from_utc_ns
-            .unwrap_or(now_ns.saturating_sub(30 * 24 * 60 * 60 * 1_000_000_000));
+            .unwrap_or(now_ns.saturating_sub(ui_health_report_default_window_ns()));
         let to_ns = request.to_utc_ns.unwrap_or(now_ns);
+        let (from_ns, to_ns) = self.health_range_grace.normalize(from_ns, to_ns, now_ns);
         let time_range =
             match HealthReportTimeRange::v1(MonotonicTimeNs(from_ns), MonotonicTimeNs(to_ns)) {
                 Ok(v) => v,
@@ -7197,7 +10279,7 @@ impl AdapterRuntime {
             };
 
         let envelope = match HealthReadEnvelope::v1(
-            CorrelationId(request.correlation_id.unwrap_or(now_ns) as u128),
+            CorrelationId(ui_health_report_correlation_id(&request) as u128),
             TurnId(request.turn_id.unwrap_or(1)),
             MonotonicTimeNs(now_ns),
         ) {
@@ -7226,7 +10308,13 @@ impl AdapterRuntime {
             }
         };
 
-        let issue_events = synth_health_issue_events(&health, &tenant_id, now_ns);
+        let issue_events = synth_health_issue_events(
+            &health,
+            &tenant_id,
+            now_ns,
+            &self.health_issue_deadlines,
+            self.health_issue_synth_limit,
+        );
         let report_request = HealthReportQueryReadRequest::v1(
             envelope,
             tenant_id,
@@ -7234,10 +10322,10 @@ impl AdapterRuntime {
                 .viewer_user_id
                 .clone()
                 .unwrap_or_else(|| "viewer_01".to_string()),
-            parse_report_kind(request.report_kind.as_deref()),
+            parse_report_kind(request.report_kind.as_deref(), self.default_report_kind),
             time_range,
             request.engine_owner_filter.clone(),
-            parse_company_scope(request.company_scope.as_deref()),
+            parse_company_scope(request.company_scope.as_deref(), self.default_company_scope),
             parse_company_ids(request.company_ids.as_ref()),
             parse_country_codes(request.country_codes.as_ref()),
             request.escalated_only.unwrap_or(false),
@@ -7323,6 +10411,192 @@ impl AdapterRuntime {
         }
     }
 
+    /// Runs the UI health report query over every page and renders all rows as CSV with a
+    /// header row. Unlike `ui_health_report_query` it does not remember the viewer's display
+    /// target.
+    pub fn ui_health_report_export_csv(
+        &self,
+        request: UiHealthReportQueryRequest,
+        now_ns: Option<u64>,
+    ) -> Result<String, AdapterError> {
+        let now_ns = now_ns.unwrap_or_else(system_time_now_ns).max(1);
+        let (_, rows) = self.collect_health_report_rows(request, now_ns, false)?;
+        let mut out = String::from(UI_HEALTH_REPORT_CSV_HEADER);
+        out.push('\n');
+        for row in &rows {
+            out.push_str(&ui_health_report_csv_row(row));
+            out.push('\n');
+        }
+        Ok(out)
+    }
+
+    // Returns the first page, for its revision and context id, and the rows of every page.
+    fn collect_health_report_rows(
+        &self,
+        request: UiHealthReportQueryRequest,
+        now_ns: u64,
+        remember_display_target: bool,
+    ) -> Result<(UiHealthReportQueryResponse, Vec<UiHealthReportRow>), AdapterError> {
+        let mut page_request = request;
+        page_request.page_action = Some("FIRST".to_string());
+        page_request.page_cursor = None;
+        page_request.report_context_id = None;
+        let mut first = self.run_ui_health_report_query(
+            page_request.clone(),
+            Some(now_ns),
+            remember_display_target,
+        );
+        if first.status != "ok" {
+            return Err(AdapterError::Validation(format!(
+                "health report query failed: {}",
+                first.reason_code
+            )));
+        }
+        let mut rows = std::mem::take(&mut first.rows);
+        let mut next_cursor = first
+            .paging
+            .next_cursor
+            .clone()
+            .filter(|_| first.paging.has_next);
+        let mut pages = 1usize;
+        while let Some(cursor) = next_cursor.take() {
+            if pages >= HEALTH_REPORT_CHANGES_MAX_PAGES {
+                return Err(AdapterError::Validation(
+                    "health report paging exceeded bounded page count".to_string(),
+                ));
+            }
+            page_request.page_action = Some("NEXT".to_string());
+            page_request.page_cursor = Some(cursor);
+            page_request.report_context_id = first.report_context_id.clone();
+            let page = self.run_ui_health_report_query(
+                page_request.clone(),
+                Some(now_ns),
+                remember_display_target,
+            );
+            if page.status != "ok" {
+                return Err(AdapterError::Validation(format!(
+                    "health report query failed: {}",
+                    page.reason_code
+                )));
+            }
+            rows.extend(page.rows);
+            next_cursor = page.paging.next_cursor.filter(|_| page.paging.has_next);
+            pages += 1;
+        }
+        Ok((first, rows))
+    }
+
+    pub fn health_report_changes(
+        &self,
+        since_revision: u64,
+        request: UiHealthReportQueryRequest,
+        now_ns: Option<u64>,
+    ) -> Result<HealthReportDelta, AdapterError> {
+        let now_ns = now_ns.unwrap_or_else(system_time_now_ns).max(1);
+        let tenant_key = request
+            .tenant_id
+            .as_deref()
+            .unwrap_or("tenant_a")
+            .trim()
+            .to_string();
+
+        let (first, rows) = self.collect_health_report_rows(request, now_ns, true)?;
+        let Some(report_revision) = first.report_revision else {
+            return Err(AdapterError::Validation(
+                first
+                    .requires_clarification
+                    .unwrap_or_else(|| "health report query returned no revision".to_string()),
+            ));
+        };
+        let report_context_id = first.report_context_id;
+
+        let mut state = self
+            .health_report_snapshot_state
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("health report snapshot".to_string()))?;
+        let baseline = state
+            .rows_by_revision
+            .get(&(tenant_key.clone(), since_revision))
+            .cloned();
+        let baseline_found = baseline.is_some();
+        let (added_issue_ids, changed_issue_ids, resolved_issue_ids) =
+            diff_health_report_rows(baseline.as_deref().unwrap_or(&[]), &rows);
+        state
+            .rows_by_revision
+            .insert((tenant_key, report_revision), rows);
+        while state.rows_by_revision.len() > HEALTH_REPORT_SNAPSHOT_MAX_ENTRIES {
+            let Some(oldest) = state
+                .rows_by_revision
+                .keys()
+                .min_by_key(|(_, revision)| *revision)
+                .cloned()
+            else {
+                break;
+            };
+            state.rows_by_revision.remove(&oldest);
+        }
+
+        Ok(HealthReportDelta {
+            since_revision,
+            report_revision,
+            report_context_id,
+            baseline_found,
+            added_issue_ids,
+            changed_issue_ids,
+            resolved_issue_ids,
+        })
+    }
+
+    pub fn list_builder_artifacts(
+        &self,
+        tenant_id: &str,
+        artifact_type: Option<String>,
+        limit: usize,
+    ) -> Result<Vec<BuilderArtifactView>, AdapterError> {
+        let tenant_id = tenant_id.trim();
+        if tenant_id.is_empty() {
+            return Err(AdapterError::Validation(
+                "tenant_id must not be empty".to_string(),
+            ));
+        }
+        let artifact_types = match artifact_type.as_deref() {
+            Some(raw) => vec![parse_builder_artifact_type(raw)
+                .ok_or_else(|| format!("unknown builder artifact_type '{}'", raw.trim()))?],
+            None => BUILDER_ARTIFACT_TYPES.to_vec(),
+        };
+        let store = self
+            .store
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+        let mut rows = artifact_types
+            .into_iter()
+            .flat_map(|artifact_type| {
+                store.ph1learn_artifact_rows(ArtifactScopeType::Tenant, tenant_id, artifact_type)
+            })
+            .collect::<Vec<_>>();
+        rows.sort_by(|a, b| {
+            b.created_at
+                .0
+                .cmp(&a.created_at.0)
+                .then(b.artifact_id.cmp(&a.artifact_id))
+        });
+        Ok(rows
+            .into_iter()
+            .take(limit)
+            .map(|row| BuilderArtifactView {
+                artifact_id: row.artifact_id,
+                artifact_type: artifact_type_label(row.artifact_type).to_string(),
+                artifact_version: row.artifact_version.0,
+                package_hash: row.package_hash.clone(),
+                status: artifact_status_label(row.status).to_string(),
+                created_by: row.created_by.clone(),
+                payload_ref: row.payload_ref.clone(),
+                provenance_ref: row.provenance_ref.clone(),
+                created_at_ns: row.created_at.0,
+            })
+            .collect())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn record_transcript_updates(
         &self,
@@ -7334,25 +10608,33 @@ impl AdapterRuntime {
         device_id: Option<&DeviceId>,
         session_id: Option<SessionId>,
         user_source: ConversationSource,
+        privacy_scope: PrivacyScope,
+        user_text_part_seq: Option<u32>,
         user_text_partial: Option<String>,
         user_text_final: Option<String>,
         selene_text_partial: Option<String>,
         selene_text_final: Option<String>,
         update_recent_archive_digest: bool,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let user_transcript_source = match user_source {
             ConversationSource::VoiceTranscript => AdapterTranscriptSource::Ph1C,
             ConversationSource::TypedText => AdapterTranscriptSource::UiText,
-            ConversationSource::SeleneOutput | ConversationSource::Tombstone => {
-                return Err(format!(
+            ConversationSource::SeleneOutput
+            | ConversationSource::Tombstone
+            | ConversationSource::SystemNotice
+            | ConversationSource::ToolResult => {
+                return Err(AdapterError::Validation(format!(
                     "invalid committed user conversation source: {user_source:?}"
-                ))
+                )))
             }
         };
         if let Some(text) = user_text_partial {
             self.push_transcript_partial_event(
                 correlation_id,
                 turn_id,
+                session_id,
+                actor_user_id,
+                privacy_scope,
                 AdapterTranscriptRole::User,
                 user_transcript_source,
                 text,
@@ -7363,6 +10645,9 @@ impl AdapterRuntime {
             self.push_transcript_partial_event(
                 correlation_id,
                 turn_id,
+                session_id,
+                actor_user_id,
+                privacy_scope,
                 AdapterTranscriptRole::Selene,
                 AdapterTranscriptSource::Ph1Write,
                 text,
@@ -7370,18 +10655,36 @@ impl AdapterRuntime {
             )?;
         }
         if let Some(text) = user_text_final {
-            append_transcript_final_conversation_turn(
-                store,
-                now,
-                correlation_id,
-                turn_id,
-                actor_user_id,
-                device_id,
-                session_id,
-                ConversationRole::User,
-                user_source,
-                &text,
-            )?;
+            let corrected = user_source == ConversationSource::VoiceTranscript
+                && user_text_part_seq.is_none_or(|part| part == 0)
+                && append_low_latency_transcript_correction(
+                    store,
+                    now,
+                    correlation_id,
+                    turn_id,
+                    actor_user_id,
+                    device_id,
+                    session_id,
+                    &text,
+                    self.idempotency_token_max_len,
+                )?;
+            if !corrected {
+                append_transcript_final_conversation_turn(
+                    store,
+                    now,
+                    correlation_id,
+                    turn_id,
+                    actor_user_id,
+                    device_id,
+                    session_id,
+                    ConversationRole::User,
+                    user_source,
+                    privacy_scope,
+                    user_text_part_seq,
+                    &text,
+                    self.idempotency_token_max_len,
+                )?;
+            }
             self.clear_transcript_partials_for_key(
                 correlation_id,
                 turn_id,
@@ -7400,7 +10703,10 @@ impl AdapterRuntime {
                 session_id,
                 ConversationRole::Selene,
                 ConversationSource::SeleneOutput,
+                privacy_scope,
+                None,
                 &text,
+                self.idempotency_token_max_len,
             )?;
             self.clear_transcript_partials_for_key(
                 correlation_id,
@@ -7416,6 +10722,7 @@ impl AdapterRuntime {
                 actor_user_id,
                 session_id,
                 turn_id,
+                self.idempotency_token_max_len,
             )?;
         }
         Ok(())
@@ -7423,15 +10730,13 @@ impl AdapterRuntime {
 
     fn record_reused_response_transcript_finals(
         &self,
+        held_store: &mut Option<&mut Ph1fStore>,
         update: ReusedResponseTranscriptUpdate<'_>,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         if update.user_text_final.is_none() && update.selene_text_final.is_none() {
             return Ok(());
         }
-        let mut store = self
-            .store
-            .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+        let mut store = self.lock_turn_store(held_store)?;
         self.record_transcript_updates(
             &mut store,
             update.now,
@@ -7441,6 +10746,8 @@ impl AdapterRuntime {
             Some(update.device_id),
             adapter_response_session_id(update.response)?,
             update.user_source,
+            update.privacy_scope,
+            update.user_text_part_seq,
             None,
             update.user_text_final.map(str::to_string),
             None,
@@ -7449,46 +10756,65 @@ impl AdapterRuntime {
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn push_transcript_partial_event(
         &self,
         correlation_id: CorrelationId,
         turn_id: TurnId,
+        session_id: Option<SessionId>,
+        actor_user_id: &UserId,
+        privacy_scope: PrivacyScope,
         role: AdapterTranscriptRole,
         source: AdapterTranscriptSource,
         text: String,
         timestamp_ns: u64,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let mut state = self
             .transcript_state
             .lock()
-            .map_err(|_| "adapter transcript lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("transcript".to_string()))?;
         let seq = state.next_seq;
         state.next_seq = state.next_seq.saturating_add(1);
         state.events.push(AdapterTranscriptEvent {
             seq,
             correlation_id,
             turn_id,
+            session_id,
+            user_id: actor_user_id.clone(),
+            privacy_scope,
             role,
             source,
             finalized: false,
             text,
             timestamp_ns,
         });
-        const MAX_TRANSCRIPT_EVENTS: usize = 4096;
-        if state.events.len() > MAX_TRANSCRIPT_EVENTS {
-            let drop_count = state.events.len().saturating_sub(MAX_TRANSCRIPT_EVENTS);
-            state.events.drain(0..drop_count);
-        }
+        self.transcript_retention_policy
+            .enforce_capacity(&mut state.events, timestamp_ns);
         Ok(())
     }
 
+    /// Drops partial transcripts older than the retention policy's `max_age_ns` as of
+    /// `now_ns` and returns how many were dropped. Meant for a periodic background call; a
+    /// policy without a max age drops nothing.
+    pub fn evict_expired_transcript_partials(&self, now_ns: u64) -> Result<usize, AdapterError> {
+        let mut state = self
+            .transcript_state
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("transcript".to_string()))?;
+        let before = state.events.len();
+        state
+            .events
+            .retain(|event| !self.transcript_retention_policy.expired(event, now_ns));
+        Ok(before - state.events.len())
+    }
+
     fn clear_transcript_partials_for_key(
         &self,
         correlation_id: CorrelationId,
         turn_id: TurnId,
         role: AdapterTranscriptRole,
         source: AdapterTranscriptSource,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let key = AdapterTranscriptKey {
             correlation_id: correlation_id.0,
             turn_id: turn_id.0,
@@ -7498,34 +10824,101 @@ impl AdapterRuntime {
         let mut state = self
             .transcript_state
             .lock()
-            .map_err(|_| "adapter transcript lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("transcript".to_string()))?;
         state
             .events
             .retain(|event| event.finalized || event.key() != key);
         Ok(())
     }
 
+    /// Removes the partial transcripts still open on a closing session's turns. Under
+    /// `FinalizeLast` the latest partial per turn and speaker is committed as the final row
+    /// first; under `Discard` it is dropped.
+    fn settle_transcript_partials_for_closed_session(
+        &self,
+        store: &mut Ph1fStore,
+        now: MonotonicTimeNs,
+        actor_user_id: &UserId,
+        device_id: &DeviceId,
+        session_id: SessionId,
+    ) -> Result<(), AdapterError> {
+        let mut latest_by_key: BTreeMap<AdapterTranscriptKey, AdapterTranscriptEvent> =
+            BTreeMap::new();
+        {
+            let mut state = self
+                .transcript_state
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("transcript".to_string()))?;
+            state.events.retain(|event| {
+                if event.finalized || event.session_id != Some(session_id) {
+                    return true;
+                }
+                let newer = latest_by_key
+                    .get(&event.key())
+                    .is_none_or(|existing| existing.seq < event.seq);
+                if newer {
+                    latest_by_key.insert(event.key(), event.clone());
+                }
+                false
+            });
+        }
+        if self.transcript_close_policy == AdapterTranscriptClosePolicy::Discard {
+            return Ok(());
+        }
+        for event in latest_by_key.into_values() {
+            append_transcript_final_conversation_turn(
+                store,
+                now,
+                event.correlation_id,
+                event.turn_id,
+                actor_user_id,
+                Some(device_id),
+                Some(session_id),
+                adapter_transcript_role_to_storage(event.role),
+                adapter_transcript_source_to_storage(event.source),
+                event.privacy_scope,
+                None,
+                &event.text,
+                self.idempotency_token_max_len,
+            )?;
+        }
+        Ok(())
+    }
+
     fn run_device_artifact_sync_worker_pass_internal(
         &self,
         now_ns: u64,
-    ) -> Result<DeviceArtifactSyncWorkerPassMetrics, String> {
+    ) -> Result<DeviceArtifactSyncWorkerPassMetrics, AdapterError> {
+        let _pass_span = tracing::info_span!("sync_worker_pass", now_ns).entered();
         let correlation_id = CorrelationId(now_ns as u128);
         let turn_id = TurnId(now_ns);
         let now = MonotonicTimeNs(now_ns);
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+        let dispatch_stage = tracing::info_span!("sync_dispatch").entered();
         let metrics = self
             .ingress
-            .run_device_artifact_sync_worker_pass_with_metrics(
+            .run_device_artifact_sync_worker_pass_with_retry_policy(
                 &mut store,
                 now,
                 correlation_id,
                 turn_id,
+                &self.sync_retry_policy,
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
+        tracing::debug!(
+            dequeued = metrics.dequeued_count,
+            acked = metrics.acked_count,
+            retry_scheduled = metrics.retry_scheduled_count,
+            dead_lettered = metrics.dead_lettered_count,
+            pull_errors = metrics.pull_error_count,
+            "sync worker dispatch finished"
+        );
+        drop(dispatch_stage);
         let queue_after = snapshot_sync_queue_counters(&store, now);
+        let improvement_stage = tracing::info_span!("sync_improvement").entered();
         let improvement = match self.emit_sync_improvement_events(
             &mut store,
             now,
@@ -7536,7 +10929,7 @@ impl AdapterRuntime {
         ) {
             Ok(v) => v,
             Err(err) => {
-                eprintln!("selene_adapter sync improvement emit failed: {err}");
+                tracing::error!("sync improvement emit failed: {err}");
                 SyncImprovementEmissionResult {
                     feedback_events_emitted: 0,
                     learn_artifacts_emitted: 0,
@@ -7555,12 +10948,13 @@ impl AdapterRuntime {
                 outcome_entries: &improvement.builder_input_entries,
             },
         ) {
-            eprintln!("selene_adapter builder auto-run failed: {err}");
+            tracing::error!("builder auto-run failed: {err}");
         }
+        drop(improvement_stage);
         drop(store);
         self.record_sync_worker_metrics(now_ns, &metrics)?;
         if let Err(err) = self.record_sync_improvement_metrics(&improvement) {
-            eprintln!("selene_adapter sync improvement metrics update failed: {err}");
+            tracing::error!("sync improvement metrics update failed: {err}");
         }
         Ok(metrics)
     }
@@ -7569,11 +10963,11 @@ impl AdapterRuntime {
         &self,
         now_ns: u64,
         metrics: &DeviceArtifactSyncWorkerPassMetrics,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let mut counters = self
             .sync_worker_counters
             .lock()
-            .map_err(|_| "adapter sync worker counters lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("sync worker counters".to_string()))?;
         counters.pass_count = counters.pass_count.saturating_add(1);
         counters.dequeued_total = counters
             .dequeued_total
@@ -7592,17 +10986,18 @@ impl AdapterRuntime {
         counters.last_acked_count = metrics.acked_count;
         counters.last_retry_scheduled_count = metrics.retry_scheduled_count;
         counters.last_dead_lettered_count = metrics.dead_lettered_count;
+        counters.last_backoff_ms = metrics.last_backoff_ms;
         Ok(())
     }
 
     fn record_sync_improvement_metrics(
         &self,
         emitted: &SyncImprovementEmissionResult,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let mut counters = self
             .improvement_counters
             .lock()
-            .map_err(|_| "adapter improvement counters lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("improvement counters".to_string()))?;
         counters.feedback_events_emitted_total = counters
             .feedback_events_emitted_total
             .saturating_add(emitted.feedback_events_emitted);
@@ -7612,6 +11007,47 @@ impl AdapterRuntime {
         Ok(())
     }
 
+    fn record_read_only_incident_metrics(
+        &self,
+        delta: &AdapterReadOnlyIncidentCounters,
+    ) -> Result<(), AdapterError> {
+        let mut counters = self
+            .improvement_counters
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("improvement counters".to_string()))?;
+        counters.read_only_incidents.accumulate(delta);
+        Ok(())
+    }
+
+    // Versions are seeded from the ledger once per (tenant, type), then handed out
+    // under the runtime lock so concurrent passes never share a version.
+    fn allocate_artifact_version(
+        &self,
+        store: &Ph1fStore,
+        tenant_id: &str,
+        artifact_type: ArtifactType,
+    ) -> Result<u32, AdapterError> {
+        let mut state = self
+            .artifact_version_state
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("artifact version state".to_string()))?;
+        let next_version = state
+            .next_version_by_scope
+            .entry((tenant_id.to_string(), artifact_type))
+            .or_insert_with(|| {
+                store
+                    .ph1learn_artifact_rows(ArtifactScopeType::Tenant, tenant_id, artifact_type)
+                    .iter()
+                    .map(|row| row.artifact_version.0)
+                    .max()
+                    .unwrap_or(0)
+                    .saturating_add(1)
+            });
+        let allocated = *next_version;
+        *next_version = allocated.saturating_add(1);
+        Ok(allocated)
+    }
+
     fn emit_sync_improvement_events(
         &self,
         store: &mut Ph1fStore,
@@ -7620,12 +11056,11 @@ impl AdapterRuntime {
         turn_id: TurnId,
         _metrics: &DeviceArtifactSyncWorkerPassMetrics,
         queue_after: &AdapterSyncQueueCounters,
-    ) -> Result<SyncImprovementEmissionResult, String> {
+    ) -> Result<SyncImprovementEmissionResult, AdapterError> {
         let issue_records = collect_sync_issue_records_for_pass(store, now, queue_after);
         let mut feedback_events_emitted = 0u64;
         let mut learn_artifacts_emitted = 0u64;
         let mut builder_input_entries = Vec::new();
-        let mut next_version_by_scope: BTreeMap<(String, ArtifactType), u32> = BTreeMap::new();
 
         for issue in issue_records {
             let (outcome_type, reason_code) = match issue.issue_kind {
@@ -7640,10 +11075,13 @@ impl AdapterRuntime {
                 ),
             };
             let issue_tag = sync_issue_tag(issue.issue_kind);
-            let issue_idem = sanitize_idempotency_token(&format!(
-                "sync_issue:{}:{}:{}:{}",
-                issue_tag, issue.sync_job_id, issue.attempt_count, now.0
-            ));
+            let issue_idem = sanitize_idempotency_token(
+                &format!(
+                    "sync_issue:{}:{}:{}:{}",
+                    issue_tag, issue.sync_job_id, issue.attempt_count, now.0
+                ),
+                self.idempotency_token_max_len,
+            );
 
             let outcome_entry = match OsOutcomeUtilizationEntry::v1(
                 "PH1.FEEDBACK".to_string(),
@@ -7658,7 +11096,7 @@ impl AdapterRuntime {
             ) {
                 Ok(entry) => entry,
                 Err(err) => {
-                    eprintln!("selene_adapter outcome entry build failed: {err:?}");
+                    tracing::error!("outcome entry build failed: {err:?}");
                     continue;
                 }
             };
@@ -7675,20 +11113,23 @@ impl AdapterRuntime {
                     decision_delta: true,
                     reason_code,
                     idempotency_key: Some(issue_idem.clone()),
+                    telemetry_tags: BTreeMap::new(),
                 })
             {
-                eprintln!(
-                    "selene_adapter outcome utilization append failed: {}",
-                    storage_error_to_string(err)
-                );
+                tracing::error!("outcome utilization append failed: {err:?}");
                 continue;
             }
             builder_input_entries.push(outcome_entry);
 
-            let Some(user_id) = issue.user_id.clone() else {
+            // Aggregate issues stop here: they feed the builder but never land on a
+            // device's feedback history.
+            let (Some(user_id), Some(device_id)) = (issue.user_id.clone(), issue.device_id.clone())
+            else {
                 continue;
             };
-            let Some(tenant_id) = tenant_scope_from_user_id(&user_id).map(str::to_string) else {
+            let Some(tenant_id) =
+                tenant_scope_from_user_id(&user_id, self.tenant_delimiter).map(str::to_string)
+            else {
                 continue;
             };
 
@@ -7697,50 +11138,31 @@ impl AdapterRuntime {
                 SyncIssueKind::DeadLetter => ("VoiceIdDriftAlert", "VoiceIdDriftAlert"),
                 SyncIssueKind::ReplayDue => ("VoiceIdDriftAlert", "VoiceIdDriftAlert"),
             };
-            match store.ph1feedback_event_commit(
-                now,
-                tenant_id.clone(),
-                correlation_id,
-                turn_id,
-                None,
-                user_id.clone(),
-                issue.device_id.clone(),
-                feedback_event_type.to_string(),
-                learn_signal_type.to_string(),
-                reason_code,
-                issue_idem.clone(),
-            ) {
+            match retry_transient_storage_commit(self.storage_commit_retry_limit, || {
+                store.ph1feedback_event_commit(
+                    now,
+                    tenant_id.clone(),
+                    correlation_id,
+                    turn_id,
+                    None,
+                    user_id.clone(),
+                    device_id.clone(),
+                    feedback_event_type.to_string(),
+                    learn_signal_type.to_string(),
+                    reason_code,
+                    issue_idem.clone(),
+                )
+            }) {
                 Ok(_) => {
                     feedback_events_emitted = feedback_events_emitted.saturating_add(1);
                 }
                 Err(err) => {
-                    eprintln!(
-                        "selene_adapter feedback emit failed: {}",
-                        storage_error_to_string(err)
-                    );
+                    tracing::error!("feedback emit failed: {err:?}");
                 }
             }
 
             let artifact_type = artifact_type_for_sync_issue(issue.issue_kind);
-            let version_key = (tenant_id.clone(), artifact_type);
-            let next_version = if let Some(existing) = next_version_by_scope.get_mut(&version_key) {
-                *existing = existing.saturating_add(1);
-                *existing
-            } else {
-                let rows = store.ph1learn_artifact_rows(
-                    ArtifactScopeType::Tenant,
-                    &tenant_id,
-                    artifact_type,
-                );
-                let base = rows
-                    .iter()
-                    .map(|row| row.artifact_version.0)
-                    .max()
-                    .unwrap_or(0)
-                    .saturating_add(1);
-                next_version_by_scope.insert(version_key, base);
-                base
-            };
+            let next_version = self.allocate_artifact_version(store, &tenant_id, artifact_type)?;
             let package_hash = stable_hash_hex_16(&format!(
                 "{tenant}:{job}:{kind:?}:{issue}:{attempt}:{err}",
                 tenant = tenant_id,
@@ -7768,10 +11190,13 @@ impl AdapterRuntime {
                 ),
                 128,
             );
-            let learn_idem = sanitize_idempotency_token(&format!(
-                "learn_sync:{}:{}:{}",
-                issue_tag, issue.sync_job_id, issue.attempt_count
-            ));
+            let learn_idem = sanitize_idempotency_token(
+                &format!(
+                    "learn_sync:{}:{}:{}",
+                    issue_tag, issue.sync_job_id, issue.attempt_count
+                ),
+                self.idempotency_token_max_len,
+            );
             match store.ph1learn_artifact_commit(
                 now,
                 tenant_id.clone(),
@@ -7790,10 +11215,7 @@ impl AdapterRuntime {
                     learn_artifacts_emitted = learn_artifacts_emitted.saturating_add(1);
                 }
                 Err(err) => {
-                    eprintln!(
-                        "selene_adapter learn artifact emit failed: {}",
-                        storage_error_to_string(err)
-                    );
+                    tracing::error!("learn artifact emit failed: {err:?}");
                 }
             }
         }
@@ -7805,26 +11227,62 @@ impl AdapterRuntime {
         })
     }
 
+    // With `builder_ph1k_feedback_enabled`, recent PH1.K interrupt feedback rides along with
+    // the issue entries so the builder can learn interrupt tuning. Off by default.
+    fn builder_outcome_entries(
+        &self,
+        store: &Ph1fStore,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        window_start: MonotonicTimeNs,
+        tenant_id: Option<&str>,
+        outcome_entries: &[OsOutcomeUtilizationEntry],
+    ) -> Vec<OsOutcomeUtilizationEntry> {
+        let mut entries = outcome_entries.to_vec();
+        if self.builder_ph1k_feedback_enabled {
+            entries.extend(ph1k_feedback_builder_entries(
+                store,
+                correlation_id,
+                turn_id,
+                window_start,
+                tenant_id,
+            ));
+        }
+        entries
+    }
+
     fn maybe_run_builder_for_sync_improvements(
         &self,
         store: &mut Ph1fStore,
         ctx: SyncImprovementBuilderContext<'_>,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         if !self.auto_builder_enabled {
-            self.record_builder_status("DISABLED", BuilderStatusKind::NotInvoked)?;
+            self.record_builder_status(ctx.now, "DISABLED", BuilderStatusKind::NotInvoked)?;
             return Ok(());
         }
         if ctx.outcome_entries.is_empty() {
-            self.record_builder_status("NO_SYNC_ISSUES", BuilderStatusKind::NotInvoked)?;
+            self.record_builder_status(ctx.now, "NO_SYNC_ISSUES", BuilderStatusKind::NotInvoked)?;
             return Ok(());
         }
         let severe = ctx.metrics.dead_lettered_count > 0 || ctx.queue_after.replay_due_count > 0;
         if !severe {
-            self.record_builder_status("SKIPPED_NON_SEVERE", BuilderStatusKind::NotInvoked)?;
+            self.record_builder_status(
+                ctx.now,
+                "SKIPPED_NON_SEVERE",
+                BuilderStatusKind::NotInvoked,
+            )?;
+            return Ok(());
+        }
+        if ctx.outcome_entries.len() < self.builder_min_outcome_entries {
+            self.record_builder_status(
+                ctx.now,
+                "NOT_INVOKED_INSUFFICIENT_SIGNAL",
+                BuilderStatusKind::NotInvoked,
+            )?;
             return Ok(());
         }
 
-        self.record_builder_status("RUNNING", BuilderStatusKind::RunStarted)?;
+        self.record_builder_status(ctx.now, "RUNNING", BuilderStatusKind::RunStarted)?;
         let orchestrator = Ph1BuilderOrchestrator::new(
             Ph1BuilderConfig::mvp_v1(true),
             AdapterPatternEngineRuntime::new(),
@@ -7833,13 +11291,21 @@ impl AdapterRuntime {
         )
         .map_err(|err| format!("failed to initialize builder orchestrator: {err:?}"))?;
         let window_start = MonotonicTimeNs(ctx.now.0.saturating_sub(60_000_000_000));
+        let outcome_entries = self.builder_outcome_entries(
+            store,
+            ctx.correlation_id,
+            ctx.turn_id,
+            window_start,
+            None,
+            ctx.outcome_entries,
+        );
         let builder_input = BuilderOfflineInput::v1(
             ctx.correlation_id,
             ctx.turn_id,
             window_start,
             ctx.now,
             ctx.now,
-            ctx.outcome_entries.to_vec(),
+            outcome_entries,
             None,
             None,
             None,
@@ -7852,30 +11318,140 @@ impl AdapterRuntime {
 
         match orchestrator.run_offline(store, &builder_input) {
             Ok(BuilderOrchestrationOutcome::Completed(_)) => {
-                self.record_builder_status("COMPLETED", BuilderStatusKind::Completed)?;
+                self.record_builder_status(ctx.now, "COMPLETED", BuilderStatusKind::Completed)?;
             }
             Ok(BuilderOrchestrationOutcome::Refused(refuse)) => {
                 self.record_builder_status(
+                    ctx.now,
                     &format!("REFUSED:{}:{}", refuse.stage, refuse.reason_code.0),
                     BuilderStatusKind::Refused,
                 )?;
             }
             Ok(BuilderOrchestrationOutcome::NotInvokedDisabled) => {
-                self.record_builder_status("NOT_INVOKED_DISABLED", BuilderStatusKind::NotInvoked)?;
+                self.record_builder_status(
+                    ctx.now,
+                    "NOT_INVOKED_DISABLED",
+                    BuilderStatusKind::NotInvoked,
+                )?;
             }
             Ok(BuilderOrchestrationOutcome::NotInvokedNoSignals) => {
                 self.record_builder_status(
+                    ctx.now,
                     "NOT_INVOKED_NO_SIGNALS",
                     BuilderStatusKind::NotInvoked,
                 )?;
             }
             Err(err) => {
-                self.record_builder_status(&format!("ERROR:{err:?}"), BuilderStatusKind::Error)?;
+                self.record_builder_status(
+                    ctx.now,
+                    &format!("ERROR:{err:?}"),
+                    BuilderStatusKind::Error,
+                )?;
             }
         }
         Ok(())
     }
 
+    // Operator-triggered builder run over stored outcome rows. Unlike the sync and read-only
+    // paths it skips the severity and `auto_builder_enabled` gates.
+    pub fn run_builder_over_window(
+        &self,
+        window_start_ns: u64,
+        window_end_ns: u64,
+    ) -> Result<BuilderOrchestrationOutcome, AdapterError> {
+        if window_end_ns < window_start_ns {
+            return Err(AdapterError::Validation(
+                "builder window end must not precede its start".to_string(),
+            ));
+        }
+        let now_ns = system_time_now_ns().max(1);
+        let now = MonotonicTimeNs(now_ns);
+        let correlation_id = CorrelationId(now_ns as u128);
+        let turn_id = TurnId(now_ns);
+        let mut store = self
+            .store
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+        let outcome_entries = outcome_ledger_builder_entries(
+            &store,
+            correlation_id,
+            turn_id,
+            window_start_ns,
+            window_end_ns,
+        );
+
+        self.record_builder_status(now, "RUNNING_MANUAL", BuilderStatusKind::RunStarted)?;
+        let orchestrator = Ph1BuilderOrchestrator::new(
+            Ph1BuilderConfig::mvp_v1(true),
+            AdapterPatternEngineRuntime::new(),
+            AdapterRllEngineRuntime::new(),
+            DeterministicBuilderSandboxValidator,
+        )
+        .map_err(|err| format!("failed to initialize builder orchestrator: {err:?}"))?;
+        let builder_input = BuilderOfflineInput::v1(
+            correlation_id,
+            turn_id,
+            MonotonicTimeNs(window_start_ns),
+            MonotonicTimeNs(window_end_ns),
+            now,
+            outcome_entries,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            true,
+        )
+        .map_err(|err| format!("failed to build builder offline input: {err:?}"))?;
+
+        match orchestrator.run_offline(&mut *store, &builder_input) {
+            Ok(outcome) => {
+                match &outcome {
+                    BuilderOrchestrationOutcome::Completed(_) => {
+                        self.record_builder_status(
+                            now,
+                            "COMPLETED_MANUAL",
+                            BuilderStatusKind::Completed,
+                        )?;
+                    }
+                    BuilderOrchestrationOutcome::Refused(refuse) => {
+                        self.record_builder_status(
+                            now,
+                            &format!("REFUSED_MANUAL:{}:{}", refuse.stage, refuse.reason_code.0),
+                            BuilderStatusKind::Refused,
+                        )?;
+                    }
+                    BuilderOrchestrationOutcome::NotInvokedDisabled => {
+                        self.record_builder_status(
+                            now,
+                            "NOT_INVOKED_DISABLED_MANUAL",
+                            BuilderStatusKind::NotInvoked,
+                        )?;
+                    }
+                    BuilderOrchestrationOutcome::NotInvokedNoSignals => {
+                        self.record_builder_status(
+                            now,
+                            "NOT_INVOKED_NO_SIGNALS_MANUAL",
+                            BuilderStatusKind::NotInvoked,
+                        )?;
+                    }
+                }
+                Ok(outcome)
+            }
+            Err(err) => {
+                self.record_builder_status(
+                    now,
+                    &format!("ERROR_MANUAL:{err:?}"),
+                    BuilderStatusKind::Error,
+                )?;
+                Err(AdapterError::Validation(format!(
+                    "manual builder run failed: {err:?}"
+                )))
+            }
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn emit_read_only_lane_incidents_and_maybe_run_builder(
         &self,
@@ -7887,12 +11463,20 @@ impl AdapterRuntime {
         tenant_id: Option<&str>,
         device_id: &DeviceId,
         user_text_final: Option<&str>,
+        locale: Option<&str>,
+        privacy_mode: bool,
         execution_outcome: &AppVoiceTurnExecutionOutcome,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let Some(tenant_id) = tenant_id else {
             return Ok(());
         };
-        let incidents = detect_read_only_turn_incidents(user_text_final, execution_outcome);
+        let redact_user_text = privacy_mode && !privacy_evidence_raw_text_allowed();
+        let incidents = detect_read_only_turn_incidents(
+            user_text_final,
+            locale,
+            redact_user_text,
+            execution_outcome,
+        );
         if incidents.is_empty() {
             return Ok(());
         }
@@ -7901,45 +11485,57 @@ impl AdapterRuntime {
         let mut learn_artifacts_emitted = 0u64;
         let mut builder_input_entries = Vec::new();
         let mut severe_incident_observed = false;
+        let mut incidents_by_kind = AdapterReadOnlyIncidentCounters::default();
 
         for incident in incidents {
+            let kind_counters = incidents_by_kind.kind_mut(incident.kind);
+            kind_counters.incidents_total = kind_counters.incidents_total.saturating_add(1);
+            if self.read_only_incident_in_cooldown(tenant_id, incident.kind, now)? {
+                kind_counters.suppressed_total = kind_counters.suppressed_total.saturating_add(1);
+                continue;
+            }
             severe_incident_observed |= incident.kind.severe();
             let feedback_event_type = feedback_event_type_str(incident.kind.feedback_event_type());
             let learn_signal_type = learn_signal_type_str(incident.kind.learn_signal_type());
             let issue_tag = incident.kind.tag();
 
-            let feedback_idem = sanitize_idempotency_token(&format!(
-                "ro_feedback_{}_{}_{}",
-                issue_tag, correlation_id.0, turn_id.0
-            ));
-            match store.ph1feedback_event_commit(
-                now,
-                tenant_id.to_string(),
-                correlation_id,
-                turn_id,
-                None,
-                actor_user_id.clone(),
-                device_id.clone(),
-                feedback_event_type.to_string(),
-                learn_signal_type.to_string(),
-                incident.reason_code,
-                feedback_idem,
-            ) {
+            let feedback_idem = sanitize_idempotency_token(
+                &format!(
+                    "ro_feedback_{}_{}_{}",
+                    issue_tag, correlation_id.0, turn_id.0
+                ),
+                self.idempotency_token_max_len,
+            );
+            match retry_transient_storage_commit(self.storage_commit_retry_limit, || {
+                store.ph1feedback_event_commit(
+                    now,
+                    tenant_id.to_string(),
+                    correlation_id,
+                    turn_id,
+                    None,
+                    actor_user_id.clone(),
+                    device_id.clone(),
+                    feedback_event_type.to_string(),
+                    learn_signal_type.to_string(),
+                    incident.reason_code,
+                    feedback_idem.clone(),
+                )
+            }) {
                 Ok(_) => {
                     feedback_events_emitted = feedback_events_emitted.saturating_add(1);
+                    kind_counters.feedback_events_emitted_total = kind_counters
+                        .feedback_events_emitted_total
+                        .saturating_add(1);
                 }
                 Err(err) => {
-                    eprintln!(
-                        "selene_adapter read-only feedback emit failed: {}",
-                        storage_error_to_string(err)
-                    );
+                    tracing::error!("read-only feedback emit failed: {err:?}");
                 }
             }
 
-            let learn_idem = sanitize_idempotency_token(&format!(
-                "ro_learn_{}_{}_{}",
-                issue_tag, correlation_id.0, turn_id.0
-            ));
+            let learn_idem = sanitize_idempotency_token(
+                &format!("ro_learn_{}_{}_{}", issue_tag, correlation_id.0, turn_id.0),
+                self.idempotency_token_max_len,
+            );
             match store.ph1feedback_learn_signal_bundle_commit(
                 now,
                 tenant_id.to_string(),
@@ -7958,19 +11554,22 @@ impl AdapterRuntime {
             ) {
                 Ok(_) => {
                     learn_artifacts_emitted = learn_artifacts_emitted.saturating_add(1);
+                    kind_counters.learn_artifacts_emitted_total = kind_counters
+                        .learn_artifacts_emitted_total
+                        .saturating_add(1);
                 }
                 Err(err) => {
-                    eprintln!(
-                        "selene_adapter read-only learn bundle emit failed: {}",
-                        storage_error_to_string(err)
-                    );
+                    tracing::error!("read-only learn bundle emit failed: {err:?}");
                 }
             }
 
-            let outcome_idem = sanitize_idempotency_token(&format!(
-                "ro_outcome_{}_{}_{}",
-                issue_tag, correlation_id.0, turn_id.0
-            ));
+            let outcome_idem = sanitize_idempotency_token(
+                &format!(
+                    "ro_outcome_{}_{}_{}",
+                    issue_tag, correlation_id.0, turn_id.0
+                ),
+                self.idempotency_token_max_len,
+            );
             if let Err(err) =
                 store.append_outcome_utilization_ledger_row(OutcomeUtilizationLedgerRowInput {
                     created_at: now,
@@ -7984,12 +11583,10 @@ impl AdapterRuntime {
                     decision_delta: true,
                     reason_code: incident.reason_code,
                     idempotency_key: Some(outcome_idem.clone()),
+                    telemetry_tags: BTreeMap::new(),
                 })
             {
-                eprintln!(
-                    "selene_adapter read-only outcome utilization append failed: {}",
-                    storage_error_to_string(err)
-                );
+                tracing::error!("read-only outcome utilization append failed: {err:?}");
                 continue;
             }
 
@@ -8006,7 +11603,7 @@ impl AdapterRuntime {
             ) {
                 Ok(entry) => builder_input_entries.push(entry),
                 Err(err) => {
-                    eprintln!("selene_adapter read-only outcome entry build failed: {err:?}");
+                    tracing::error!("read-only outcome entry build failed: {err:?}");
                 }
             }
         }
@@ -8017,16 +11614,17 @@ impl AdapterRuntime {
             builder_input_entries,
         };
         if let Err(err) = self.record_sync_improvement_metrics(&emission) {
-            eprintln!(
-                "selene_adapter read-only incident metrics update failed: {}",
-                err
-            );
+            tracing::error!("read-only incident metrics update failed: {}", err);
+        }
+        if let Err(err) = self.record_read_only_incident_metrics(&incidents_by_kind) {
+            tracing::error!("read-only incident kind metrics update failed: {}", err);
         }
         self.maybe_run_builder_for_read_only_incidents(
             store,
             now,
             correlation_id,
             turn_id,
+            tenant_id,
             severe_incident_observed,
             &emission.builder_input_entries,
         )?;
@@ -8034,32 +11632,39 @@ impl AdapterRuntime {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn maybe_run_builder_for_read_only_incidents(
         &self,
         store: &mut Ph1fStore,
         now: MonotonicTimeNs,
         correlation_id: CorrelationId,
         turn_id: TurnId,
+        tenant_id: &str,
         severe_incident_observed: bool,
         outcome_entries: &[OsOutcomeUtilizationEntry],
-    ) -> Result<(), String> {
-        if !self.auto_builder_enabled {
-            self.record_builder_status("DISABLED", BuilderStatusKind::NotInvoked)?;
+    ) -> Result<(), AdapterError> {
+        if !self.auto_builder_enabled_for_tenant(Some(tenant_id)) {
+            self.record_builder_status(now, "DISABLED", BuilderStatusKind::NotInvoked)?;
             return Ok(());
         }
         if outcome_entries.is_empty() {
-            self.record_builder_status("NO_READ_ONLY_INCIDENTS", BuilderStatusKind::NotInvoked)?;
+            self.record_builder_status(
+                now,
+                "NO_READ_ONLY_INCIDENTS",
+                BuilderStatusKind::NotInvoked,
+            )?;
             return Ok(());
         }
         if !severe_incident_observed {
             self.record_builder_status(
+                now,
                 "SKIPPED_NON_SEVERE_READ_ONLY",
                 BuilderStatusKind::NotInvoked,
             )?;
             return Ok(());
         }
 
-        self.record_builder_status("RUNNING_READ_ONLY", BuilderStatusKind::RunStarted)?;
+        self.record_builder_status(now, "RUNNING_READ_ONLY", BuilderStatusKind::RunStarted)?;
         let orchestrator = Ph1BuilderOrchestrator::new(
             Ph1BuilderConfig::mvp_v1(true),
             AdapterPatternEngineRuntime::new(),
@@ -8068,13 +11673,21 @@ impl AdapterRuntime {
         )
         .map_err(|err| format!("failed to initialize builder orchestrator: {err:?}"))?;
         let window_start = MonotonicTimeNs(now.0.saturating_sub(60_000_000_000));
+        let outcome_entries = self.builder_outcome_entries(
+            store,
+            correlation_id,
+            turn_id,
+            window_start,
+            Some(tenant_id),
+            outcome_entries,
+        );
         let builder_input = BuilderOfflineInput::v1(
             correlation_id,
             turn_id,
             window_start,
             now,
             now,
-            outcome_entries.to_vec(),
+            outcome_entries,
             None,
             None,
             None,
@@ -8087,10 +11700,15 @@ impl AdapterRuntime {
 
         match orchestrator.run_offline(store, &builder_input) {
             Ok(BuilderOrchestrationOutcome::Completed(_)) => {
-                self.record_builder_status("COMPLETED_READ_ONLY", BuilderStatusKind::Completed)?;
+                self.record_builder_status(
+                    now,
+                    "COMPLETED_READ_ONLY",
+                    BuilderStatusKind::Completed,
+                )?;
             }
             Ok(BuilderOrchestrationOutcome::Refused(refuse)) => {
                 self.record_builder_status(
+                    now,
                     &format!(
                         "REFUSED_READ_ONLY:{}:{}",
                         refuse.stage, refuse.reason_code.0
@@ -8100,18 +11718,21 @@ impl AdapterRuntime {
             }
             Ok(BuilderOrchestrationOutcome::NotInvokedDisabled) => {
                 self.record_builder_status(
+                    now,
                     "NOT_INVOKED_DISABLED_READ_ONLY",
                     BuilderStatusKind::NotInvoked,
                 )?;
             }
             Ok(BuilderOrchestrationOutcome::NotInvokedNoSignals) => {
                 self.record_builder_status(
+                    now,
                     "NOT_INVOKED_NO_SIGNALS_READ_ONLY",
                     BuilderStatusKind::NotInvoked,
                 )?;
             }
             Err(err) => {
                 self.record_builder_status(
+                    now,
                     &format!("ERROR_READ_ONLY:{err:?}"),
                     BuilderStatusKind::Error,
                 )?;
@@ -8121,11 +11742,16 @@ impl AdapterRuntime {
         Ok(())
     }
 
-    fn record_builder_status(&self, status: &str, kind: BuilderStatusKind) -> Result<(), String> {
+    fn record_builder_status(
+        &self,
+        now: MonotonicTimeNs,
+        status: &str,
+        kind: BuilderStatusKind,
+    ) -> Result<(), AdapterError> {
         let mut counters = self
             .improvement_counters
             .lock()
-            .map_err(|_| "adapter improvement counters lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("improvement counters".to_string()))?;
         match kind {
             BuilderStatusKind::RunStarted => {
                 counters.builder_runs_total = counters.builder_runs_total.saturating_add(1);
@@ -8146,9 +11772,173 @@ impl AdapterRuntime {
             }
         }
         counters.last_builder_status = Some(truncate_ascii(status, 256));
+        if matches!(kind, BuilderStatusKind::Refused | BuilderStatusKind::Error) {
+            counters
+                .recent_builder_incidents
+                .push(AdapterBuilderIncident {
+                    at_ns: now.0,
+                    status: truncate_ascii(status, 256),
+                });
+            let overflow = counters
+                .recent_builder_incidents
+                .len()
+                .saturating_sub(BUILDER_INCIDENT_HISTORY_LIMIT);
+            counters.recent_builder_incidents.drain(..overflow);
+        }
         Ok(())
     }
 
+    /// Re-reads `SELENE_ADAPTER_TENANT_ALLOWLIST` / `SELENE_ADAPTER_TENANT_DENYLIST` so list
+    /// edits take effect on the next turn without restarting the adapter.
+    pub fn reload_tenant_access_policy_from_env(&self) -> Result<(), AdapterError> {
+        self.set_tenant_access_policy(AdapterTenantAccessPolicy::from_env())
+    }
+
+    pub fn set_tenant_access_policy(
+        &self,
+        policy: AdapterTenantAccessPolicy,
+    ) -> Result<(), AdapterError> {
+        let mut current = self
+            .tenant_access_policy
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("tenant access policy".to_string()))?;
+        *current = policy;
+        Ok(())
+    }
+
+    fn tenant_access_blocked_reason(
+        &self,
+        tenant_id: Option<&str>,
+    ) -> Result<Option<&'static str>, AdapterError> {
+        let policy = self
+            .tenant_access_policy
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("tenant access policy".to_string()))?;
+        Ok(policy.blocked_reason(tenant_id))
+    }
+
+    /// Raw `app_platform` values that were mapped by the unknown-platform policy, with
+    /// how many turns carried each one.
+    pub fn unknown_app_platform_counts(&self) -> BTreeMap<String, u64> {
+        self.unknown_app_platform_counts
+            .lock()
+            .map(|counts| counts.clone())
+            .unwrap_or_default()
+    }
+
+    // Remembers why a thread's turn was rejected so the thread's next turn can tell PH1.X.
+    fn record_ph1x_last_failure(
+        &self,
+        actor_user_id: &str,
+        thread_key: &str,
+        reason_code: ReasonCodeId,
+    ) {
+        if !self.ph1x_last_failure_enabled {
+            return;
+        }
+        if let Ok(mut reasons) = self.ph1x_last_failure_reasons.lock() {
+            reasons.insert(
+                (actor_user_id.to_string(), thread_key.to_string()),
+                reason_code,
+            );
+        }
+    }
+
+    // Each recorded failure reaches PH1.X once; taking it clears it for later turns.
+    fn take_ph1x_last_failure(
+        &self,
+        actor_user_id: &str,
+        thread_key: &str,
+    ) -> Option<ReasonCodeId> {
+        if !self.ph1x_last_failure_enabled {
+            return None;
+        }
+        self.ph1x_last_failure_reasons
+            .lock()
+            .ok()?
+            .remove(&(actor_user_id.to_string(), thread_key.to_string()))
+    }
+
+    // Rewrites an unknown `app_platform` to the configured fallback before anything parses
+    // it, so the envelope, journal and engines all see the same platform.
+    fn apply_unknown_app_platform_policy(&self, request: &mut VoiceTurnAdapterRequest) {
+        let AdapterUnknownAppPlatformPolicy::MapTo(fallback) = self.unknown_app_platform_policy
+        else {
+            return;
+        };
+        if parse_app_platform(&request.app_platform).is_ok() {
+            return;
+        }
+        let raw = truncate_ascii(request.app_platform.trim(), 32).to_ascii_uppercase();
+        if let Ok(mut counts) = self.unknown_app_platform_counts.lock() {
+            *counts.entry(raw).or_default() += 1;
+        }
+        request.app_platform = fallback.as_str().to_string();
+    }
+
+    fn transcript_privacy_scope(&self, flags: Option<&VoiceTurnThreadPolicyFlags>) -> PrivacyScope {
+        if flags.is_some_and(|flags| flags.privacy_mode) {
+            self.privacy_mode_transcript_scope
+        } else {
+            PrivacyScope::PublicChat
+        }
+    }
+
+    // A blank override means none; anything else must be allowlisted or the turn is rejected.
+    fn resolve_model_override(&self, requested: Option<&str>) -> Result<Option<String>, String> {
+        let Some(model) = requested.map(str::trim).filter(|model| !model.is_empty()) else {
+            return Ok(None);
+        };
+        if !self.model_override_allowlist.contains(model) {
+            return Err(format!(
+                "model_override '{}' is not allowlisted",
+                truncate_ascii(model, 128)
+            ));
+        }
+        Ok(Some(model.to_string()))
+    }
+
+    fn tenant_feature_flags_for(
+        &self,
+        tenant_id: Option<&str>,
+    ) -> Option<&AdapterTenantFeatureFlags> {
+        tenant_id
+            .map(str::trim)
+            .filter(|tenant| !tenant.is_empty())
+            .and_then(|tenant| self.tenant_feature_flags.get(tenant))
+    }
+
+    fn ph1c_live_enabled_for_tenant(&self, tenant_id: Option<&str>) -> bool {
+        self.tenant_feature_flags_for(tenant_id)
+            .and_then(|flags| flags.ph1c_live_enabled)
+            .unwrap_or(self.ph1c_live_enabled)
+    }
+
+    fn ph1c_streaming_enabled_for_tenant(&self, tenant_id: Option<&str>) -> bool {
+        self.tenant_feature_flags_for(tenant_id)
+            .and_then(|flags| flags.ph1c_streaming_enabled)
+            .unwrap_or(self.ph1c_streaming_enabled)
+    }
+
+    fn auto_builder_enabled_for_tenant(&self, tenant_id: Option<&str>) -> bool {
+        self.tenant_feature_flags_for(tenant_id)
+            .and_then(|flags| flags.auto_builder_enabled)
+            .unwrap_or(self.auto_builder_enabled)
+    }
+
+    fn ph1k_feedback_policy_for_tenant(
+        &self,
+        tenant_id: Option<&str>,
+    ) -> AdapterPh1kFeedbackPolicy {
+        tenant_id
+            .map(str::trim)
+            .filter(|tenant| !tenant.is_empty())
+            .and_then(|tenant| self.tenant_ph1k_feedback_policies.get(tenant))
+            .copied()
+            .unwrap_or(self.ph1k_feedback_policy)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn run_ph1c_live_turn(
         &self,
         correlation_id: CorrelationId,
@@ -8157,24 +11947,60 @@ impl AdapterRuntime {
         tenant_id: Option<&str>,
         session_state: SessionState,
         ph1k: &Ph1kLiveSignalBundle,
-    ) -> Option<Ph1cLiveTurnOutcomeSummary> {
-        if !self.ph1c_live_enabled {
-            return None;
+        mut partial_sink: Option<&mut dyn FnMut(&str)>,
+    ) -> (VoiceTurnSttMode, Option<Ph1cLiveTurnOutcomeSummary>) {
+        if !self.ph1c_live_enabled_for_tenant(tenant_id) {
+            return (VoiceTurnSttMode::SkippedDisabled, None);
         }
         let Some(adapter) = self.ph1d_live_adapter.as_ref() else {
-            return Some(ph1c_live_reject_summary(
-                ph1c_reason_codes::STT_FAIL_PROVIDER_CIRCUIT_OPEN,
-                Ph1cRetryAdvice::SwitchToText,
-            ));
+            return (
+                VoiceTurnSttMode::SkippedNoAdapter,
+                Some(ph1c_live_reject_summary(
+                    ph1c_reason_codes::STT_FAIL_PROVIDER_CIRCUIT_OPEN,
+                    Ph1cRetryAdvice::SwitchToText,
+                )),
+            );
         };
-        let tenant_id = tenant_id.unwrap_or("tenant_default");
+        if self
+            .stt_min_snr_db
+            .is_some_and(|floor| ph1k.ph1c_handoff.quality_metrics.snr_db < floor)
+        {
+            return (
+                VoiceTurnSttMode::SkippedLowSnr,
+                Some(ph1c_live_reject_summary(
+                    reason_codes::ADAPTER_STT_SNR_BELOW_FLOOR,
+                    Ph1cRetryAdvice::QuietEnv,
+                )),
+            );
+        }
+        // The breaker runs on the runtime clock; `now` comes from the request.
+        let breaker_adapter = CircuitBreakerProviderAdapter::new(
+            adapter,
+            self.provider_circuit_breaker_policy,
+            Arc::clone(&self.provider_circuit_state),
+            system_time_now_ns(),
+        );
+        // With fallbacks the open breaker only refuses the primary inside the failover chain.
+        if self.ph1d_live_fallback_providers.is_empty() && !breaker_adapter.admit() {
+            return (
+                VoiceTurnSttMode::SkippedCircuitOpen,
+                Some(ph1c_live_reject_summary(
+                    ph1c_reason_codes::STT_FAIL_PROVIDER_CIRCUIT_OPEN,
+                    Ph1cRetryAdvice::SwitchToText,
+                )),
+            );
+        }
+        let tenant_id = self.tenant_or_fallback(tenant_id, "ph1c_live_turn");
         let ph1c_request = match build_ph1c_live_request(ph1k, session_state) {
             Ok(req) => req,
             Err(_) => {
-                return Some(ph1c_live_reject_summary(
-                    ph1c_reason_codes::STT_FAIL_POLICY_RESTRICTED,
-                    Ph1cRetryAdvice::SwitchToText,
-                ));
+                return (
+                    VoiceTurnSttMode::SkippedQualityGate,
+                    Some(ph1c_live_reject_summary(
+                        ph1c_reason_codes::STT_FAIL_POLICY_RESTRICTED,
+                        Ph1cRetryAdvice::SwitchToText,
+                    )),
+                );
             }
         };
         let mut live = Ph1cLiveProviderContext::mvp_openai_google_v1(
@@ -8182,8 +12008,10 @@ impl AdapterRuntime {
             turn_id.0.max(1),
             truncate_ascii(tenant_id, 64),
         );
-        live.idempotency_key =
-            sanitize_idempotency_token(&format!("ph1c_live_{}_{}", correlation_id.0, turn_id.0));
+        live.idempotency_key = sanitize_idempotency_token(
+            &format!("ph1c_live_{}_{}", correlation_id.0, turn_id.0),
+            self.idempotency_token_max_len,
+        );
         live.tenant_vocabulary_pack_id =
             Some(format!("tenant_vocab_{}", truncate_ascii(tenant_id, 48)));
         live.user_vocabulary_pack_id = Some(format!(
@@ -8191,19 +12019,43 @@ impl AdapterRuntime {
             truncate_ascii(actor_user_id.as_str(), 48)
         ));
         let provider_records = Arc::new(Mutex::new(Vec::<Ph1dProviderCallResponse>::new()));
+        let mut provider_chain: Vec<Box<dyn Ph1dProviderAdapter + '_>> =
+            vec![Box::new(breaker_adapter)];
+        provider_chain.extend(
+            self.ph1d_live_fallback_providers
+                .iter()
+                .cloned()
+                .map(|provider| Box::new(provider) as Box<dyn Ph1dProviderAdapter>),
+        );
+        let failover_adapter = FailoverProviderAdapter::new(
+            provider_chain,
+            self.ph1d_live_failover_max_attempts,
+            (!self.ph1d_live_fallback_providers.is_empty()).then(|| Arc::clone(&provider_records)),
+        );
         let recording_adapter =
-            RecordingPh1dProviderAdapter::new(adapter, Arc::clone(&provider_records));
+            RecordingPh1dProviderAdapter::new(&failover_adapter, Arc::clone(&provider_records));
 
-        if self.ph1c_streaming_enabled {
-            let stream_commit = self.ph1c_runtime.run_stream_via_live_provider_adapter(
-                &ph1c_request,
-                &live,
-                &recording_adapter,
+        if self.ph1c_streaming_enabled_for_tenant(Some(tenant_id)) {
+            let mut forward_partial = |chunk: &str| {
+                if let Some(on_partial) = partial_sink.as_mut() {
+                    on_partial(chunk);
+                }
+            };
+            let stream_commit = self
+                .ph1c_runtime
+                .run_stream_via_live_provider_adapter_with_partials(
+                    &ph1c_request,
+                    &live,
+                    &recording_adapter,
+                    &mut forward_partial,
+                );
+            return (
+                VoiceTurnSttMode::Live,
+                Some(summarize_ph1c_stream_commit(
+                    stream_commit,
+                    snapshot_provider_calls(&provider_records),
+                )),
             );
-            return Some(summarize_ph1c_stream_commit(
-                stream_commit,
-                snapshot_provider_calls(&provider_records),
-            ));
         }
 
         let response = self.ph1c_runtime.run_via_live_provider_adapter(
@@ -8215,14 +12067,17 @@ impl AdapterRuntime {
             Ph1cResponse::TranscriptOk(ok) => Some(ok.transcript_text.clone()),
             Ph1cResponse::TranscriptReject(_) => None,
         };
-        Some(Ph1cLiveTurnOutcomeSummary {
-            response,
-            partial_text: None,
-            final_text,
-            finalized: true,
-            low_latency_commit: false,
-            provider_call_trace: snapshot_provider_calls(&provider_records),
-        })
+        (
+            VoiceTurnSttMode::Live,
+            Some(Ph1cLiveTurnOutcomeSummary {
+                response,
+                partial_text: None,
+                final_text,
+                finalized: true,
+                low_latency_commit: false,
+                provider_call_trace: snapshot_provider_calls(&provider_records),
+            }),
+        )
     }
 
     fn run_committed_voice_ph1c_gate(
@@ -8315,17 +12170,17 @@ impl AdapterRuntime {
         device_id: Option<&DeviceId>,
         session_id: Option<SessionId>,
         ph1c: &Ph1cLiveTurnOutcomeSummary,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let (Some(tenant_id), Some(device_id)) = (tenant_id, device_id) else {
             return Ok(());
         };
 
         match &ph1c.response {
             Ph1cResponse::TranscriptOk(ok) => {
-                let idempotency_key = sanitize_idempotency_token(&format!(
-                    "ph1c_ok_{}_{}",
-                    correlation_id.0, turn_id.0
-                ));
+                let idempotency_key = sanitize_idempotency_token(
+                    &format!("ph1c_ok_{}_{}", correlation_id.0, turn_id.0),
+                    self.idempotency_token_max_len,
+                );
                 store
                     .ph1c_transcript_ok_commit(
                         now,
@@ -8341,13 +12196,13 @@ impl AdapterRuntime {
                         ok.confidence_bucket,
                         idempotency_key,
                     )
-                    .map_err(storage_error_to_string)?;
+                    .map_err(AdapterError::Storage)?;
             }
             Ph1cResponse::TranscriptReject(reject) => {
-                let idempotency_key = sanitize_idempotency_token(&format!(
-                    "ph1c_reject_{}_{}",
-                    correlation_id.0, turn_id.0
-                ));
+                let idempotency_key = sanitize_idempotency_token(
+                    &format!("ph1c_reject_{}_{}", correlation_id.0, turn_id.0),
+                    self.idempotency_token_max_len,
+                );
                 let transcript_hash = ph1c.final_text.as_ref().map(|v| stable_hash_hex_16(v));
                 store
                     .ph1c_transcript_reject_commit(
@@ -8363,7 +12218,7 @@ impl AdapterRuntime {
                         transcript_hash,
                         idempotency_key,
                     )
-                    .map_err(storage_error_to_string)?;
+                    .map_err(AdapterError::Storage)?;
             }
         }
         Ok(())
@@ -8381,13 +12236,17 @@ impl AdapterRuntime {
         device_id: Option<&DeviceId>,
         session_id: Option<SessionId>,
         ph1c: &Ph1cLiveTurnOutcomeSummary,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let (Some(tenant_id), Some(device_id)) = (tenant_id, device_id) else {
             return Ok(());
         };
         let (feedback_event_type, reason_code) = match &ph1c.response {
             Ph1cResponse::TranscriptReject(reject) => {
-                (FeedbackEventType::SttReject, reject.reason_code)
+                if reject.reason_code == reason_codes::ADAPTER_STT_SNR_BELOW_FLOOR {
+                    (FeedbackEventType::SttRetry, reject.reason_code)
+                } else {
+                    (FeedbackEventType::SttReject, reject.reason_code)
+                }
             }
             Ph1cResponse::TranscriptOk(_) => return Ok(()),
         };
@@ -8396,12 +12255,12 @@ impl AdapterRuntime {
         else {
             return Ok(());
         };
-        let feedback_idem = sanitize_idempotency_token(&format!(
-            "ph1c_feedback_{}_{}",
-            correlation_id.0, turn_id.0
-        ));
-        store
-            .ph1feedback_event_commit(
+        let feedback_idem = sanitize_idempotency_token(
+            &format!("ph1c_feedback_{}_{}", correlation_id.0, turn_id.0),
+            self.idempotency_token_max_len,
+        );
+        retry_transient_storage_commit(self.storage_commit_retry_limit, || {
+            store.ph1feedback_event_commit(
                 now,
                 tenant_id.to_string(),
                 correlation_id,
@@ -8412,9 +12271,10 @@ impl AdapterRuntime {
                 feedback_event_type.to_string(),
                 learn_signal_type.to_string(),
                 reason_code,
-                feedback_idem,
+                feedback_idem.clone(),
             )
-            .map_err(storage_error_to_string)?;
+        })
+        .map_err(AdapterError::Storage)?;
 
         let ingest_latency_ms = match &ph1c.response {
             Ph1cResponse::TranscriptOk(ok) => ok
@@ -8428,8 +12288,10 @@ impl AdapterRuntime {
                 .map(|meta| meta.total_latency_ms.min(2_000))
                 .unwrap_or(0),
         };
-        let learn_idem =
-            sanitize_idempotency_token(&format!("ph1c_learn_{}_{}", correlation_id.0, turn_id.0));
+        let learn_idem = sanitize_idempotency_token(
+            &format!("ph1c_learn_{}_{}", correlation_id.0, turn_id.0),
+            self.idempotency_token_max_len,
+        );
         let evidence_ref = truncate_ascii(
             ph1c.final_text
                 .as_deref()
@@ -8453,7 +12315,7 @@ impl AdapterRuntime {
                 ingest_latency_ms,
                 learn_idem,
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
         Ok(())
     }
 
@@ -8472,9 +12334,9 @@ impl AdapterRuntime {
         transcript_text: Option<&str>,
         os_outcome: &OsVoiceLiveTurnOutcome,
         language_packet: Option<&LanguagePacket>,
-    ) -> Result<(), String> {
+    ) -> Result<Option<Ph1dAnalysis>, AdapterError> {
         let (Some(tenant_id), Some(device_id)) = (tenant_id, device_id) else {
-            return Ok(());
+            return Ok(None);
         };
 
         let transcript_text = transcript_text
@@ -8535,12 +12397,12 @@ impl AdapterRuntime {
                         actor_user_id.clone(),
                         device_id.clone(),
                         chat.reason_code,
-                        sanitize_idempotency_token(&format!(
-                            "ph1d_chat:{}:{}",
-                            correlation_id.0, turn_id.0
-                        )),
+                        sanitize_idempotency_token(
+                            &format!("ph1d_chat:{}:{}", correlation_id.0, turn_id.0),
+                            self.idempotency_token_max_len,
+                        ),
                     )
-                    .map_err(storage_error_to_string)?;
+                    .map_err(AdapterError::Storage)?;
             }
             Ph1dResponse::Ok(Ph1dOk::Intent(intent)) => {
                 store
@@ -8554,12 +12416,12 @@ impl AdapterRuntime {
                         device_id.clone(),
                         truncate_ascii(&format!("{:?}", intent.refined_intent_type), 64),
                         intent.reason_code,
-                        sanitize_idempotency_token(&format!(
-                            "ph1d_intent:{}:{}",
-                            correlation_id.0, turn_id.0
-                        )),
+                        sanitize_idempotency_token(
+                            &format!("ph1d_intent:{}:{}", correlation_id.0, turn_id.0),
+                            self.idempotency_token_max_len,
+                        ),
                     )
-                    .map_err(storage_error_to_string)?;
+                    .map_err(AdapterError::Storage)?;
             }
             Ph1dResponse::Ok(Ph1dOk::Clarify(clarify)) => {
                 let missing = clarify
@@ -8578,12 +12440,12 @@ impl AdapterRuntime {
                         device_id.clone(),
                         truncate_ascii(&missing, 64),
                         clarify.reason_code,
-                        sanitize_idempotency_token(&format!(
-                            "ph1d_clarify:{}:{}",
-                            correlation_id.0, turn_id.0
-                        )),
+                        sanitize_idempotency_token(
+                            &format!("ph1d_clarify:{}:{}", correlation_id.0, turn_id.0),
+                            self.idempotency_token_max_len,
+                        ),
                     )
-                    .map_err(storage_error_to_string)?;
+                    .map_err(AdapterError::Storage)?;
             }
             Ph1dResponse::Ok(Ph1dOk::Analysis(analysis)) => {
                 store
@@ -8597,12 +12459,13 @@ impl AdapterRuntime {
                         device_id.clone(),
                         truncate_ascii(&analysis.short_analysis, 64),
                         analysis.reason_code,
-                        sanitize_idempotency_token(&format!(
-                            "ph1d_analysis:{}:{}",
-                            correlation_id.0, turn_id.0
-                        )),
+                        sanitize_idempotency_token(
+                            &format!("ph1d_analysis:{}:{}", correlation_id.0, turn_id.0),
+                            self.idempotency_token_max_len,
+                        ),
                     )
-                    .map_err(storage_error_to_string)?;
+                    .map_err(AdapterError::Storage)?;
+                return Ok(Some(analysis));
             }
             Ph1dResponse::Fail(fail) => {
                 store
@@ -8616,16 +12479,16 @@ impl AdapterRuntime {
                         device_id.clone(),
                         ph1d_fail_code(fail.kind).to_string(),
                         fail.reason_code,
-                        sanitize_idempotency_token(&format!(
-                            "ph1d_fail:{}:{}",
-                            correlation_id.0, turn_id.0
-                        )),
+                        sanitize_idempotency_token(
+                            &format!("ph1d_fail:{}:{}", correlation_id.0, turn_id.0),
+                            self.idempotency_token_max_len,
+                        ),
                     )
-                    .map_err(storage_error_to_string)?;
+                    .map_err(AdapterError::Storage)?;
             }
         }
 
-        Ok(())
+        Ok(None)
     }
 
     fn maybe_run_ph1d_public_answer(
@@ -8639,6 +12502,7 @@ impl AdapterRuntime {
         user_text: Option<&str>,
         execution_outcome: &mut AppVoiceTurnExecutionOutcome,
         language_packet: Option<&LanguagePacket>,
+        model_override: Option<&str>,
     ) -> Option<String> {
         if !execution_outcome_is_public_no_intent(execution_outcome) {
             return None;
@@ -8652,16 +12516,17 @@ impl AdapterRuntime {
                 adapter,
                 correlation_id,
                 turn_id,
-                tenant_id.unwrap_or("tenant_default"),
+                self.tenant_or_fallback(tenant_id, "ph1d_public_answer"),
                 session_state,
                 user_text,
                 language_packet,
+                model_override,
             ) {
                 Ok(answer) => answer,
                 Err(err) => {
-                    eprintln!("selene_adapter ph1d public answer failed: {err}");
+                    tracing::error!("ph1d public answer failed: {err}");
                     if public_provider_internals_question(user_text)
-                        && err.contains("ForbiddenOutput")
+                        && err.to_string().contains("ForbiddenOutput")
                     {
                         safe_public_provider_internals_refusal()
                     } else {
@@ -8718,13 +12583,16 @@ impl AdapterRuntime {
         session_state: SessionState,
         user_text: &str,
         language_packet: Option<&LanguagePacket>,
-    ) -> Result<String, String> {
+        model_override: Option<&str>,
+    ) -> Result<String, AdapterError> {
         let provider_request = adapter.build_llm_interpret_request_for_language(
             correlation_id,
             turn_id,
             tenant_id,
             user_text,
             ph1d_language_tag_for_build1c(language_packet).as_str(),
+            model_override,
+            self.idempotency_token_max_len,
         )?;
         let provider_response = adapter
             .execute_with_error_evidence(&provider_request)
@@ -8740,7 +12608,9 @@ impl AdapterRuntime {
         if provider_response.provider_status != Ph1dProviderStatus::Ok
             || provider_response.validation_status != Ph1dProviderValidationStatus::SchemaOk
         {
-            return Err("provider_call_not_schema_ok".to_string());
+            return Err(AdapterError::Validation(
+                "provider_call_not_schema_ok".to_string(),
+            ));
         }
         let ph1x_directive_ref =
             format!("ph1x:live_public_answer:{}:{}", correlation_id.0, turn_id.0);
@@ -8751,7 +12621,7 @@ impl AdapterRuntime {
         let provider_transport_evidence = adapter.build_transport_evidence_for_provider_response(
             &provider_request,
             &provider_response,
-            SLICE3C_APPROVED_PH1D_MODEL_ID,
+            adapter.approved_model_id(model_override)?,
             1,
             1,
             &ph1x_directive_ref,
@@ -8800,11 +12670,13 @@ impl AdapterRuntime {
                 self.record_ph1d_provider_transport_evidence(provider_transport_evidence)?;
                 Ok(chat.response_text)
             }
-            Ph1dResponse::Ok(_) => Err("ph1d provider returned non-chat mode".to_string()),
-            Ph1dResponse::Fail(fail) => Err(format!(
+            Ph1dResponse::Ok(_) => Err(AdapterError::Validation(
+                "ph1d provider returned non-chat mode".to_string(),
+            )),
+            Ph1dResponse::Fail(fail) => Err(AdapterError::Validation(format!(
                 "ph1d runtime fail kind={:?} reason_code={}",
                 fail.kind, fail.reason_code.0
-            )),
+            ))),
         }
     }
 
@@ -8822,7 +12694,7 @@ impl AdapterRuntime {
         provider_calls: &[Ph1dProviderCallResponse],
         final_transcript: Option<String>,
         language_locale: Option<String>,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let (Some(tenant_id), Some(device_id)) = (tenant_id, device_id) else {
             return Ok(());
         };
@@ -8840,12 +12712,12 @@ impl AdapterRuntime {
             else {
                 continue;
             };
-            let feedback_idem = sanitize_idempotency_token(&format!(
-                "ph1d_feedback_{}_{}_{}",
-                correlation_id.0, turn_id.0, idx
-            ));
-            store
-                .ph1feedback_event_commit(
+            let feedback_idem = sanitize_idempotency_token(
+                &format!("ph1d_feedback_{}_{}_{}", correlation_id.0, turn_id.0, idx),
+                self.idempotency_token_max_len,
+            );
+            retry_transient_storage_commit(self.storage_commit_retry_limit, || {
+                store.ph1feedback_event_commit(
                     now,
                     tenant_id.to_string(),
                     correlation_id,
@@ -8856,13 +12728,14 @@ impl AdapterRuntime {
                     feedback_event_type.to_string(),
                     learn_signal_type.to_string(),
                     provider_call.reason_code,
-                    feedback_idem,
+                    feedback_idem.clone(),
                 )
-                .map_err(storage_error_to_string)?;
-            let learn_idem = sanitize_idempotency_token(&format!(
-                "ph1d_learn_{}_{}_{}",
-                correlation_id.0, turn_id.0, idx
-            ));
+            })
+            .map_err(AdapterError::Storage)?;
+            let learn_idem = sanitize_idempotency_token(
+                &format!("ph1d_learn_{}_{}_{}", correlation_id.0, turn_id.0, idx),
+                self.idempotency_token_max_len,
+            );
             let evidence = truncate_ascii(
                 final_transcript
                     .as_deref()
@@ -8893,11 +12766,30 @@ impl AdapterRuntime {
                     provider_call.provider_latency_ms.min(2_000),
                     learn_idem,
                 )
-                .map_err(storage_error_to_string)?;
+                .map_err(AdapterError::Storage)?;
         }
         Ok(())
     }
 
+    fn emit_voice_refusal_telemetry(
+        &self,
+        now: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        voice_outcome: &OsVoiceLiveTurnOutcome,
+    ) {
+        let OsVoiceLiveTurnOutcome::Refused(refuse) = voice_outcome else {
+            return;
+        };
+        self.telemetry_sink.record(TelemetryEvent::VoiceRefused {
+            captured_at: now,
+            correlation_id,
+            turn_id,
+            reason_code: refuse.reason_code,
+            message: refuse.message.clone(),
+        });
+    }
+
     fn emit_ph1c_live_telemetry(
         &self,
         store: &mut Ph1fStore,
@@ -8906,8 +12798,8 @@ impl AdapterRuntime {
         turn_id: TurnId,
         ph1c: &Ph1cLiveTurnOutcomeSummary,
         tenant_id: Option<&str>,
-    ) -> Result<(), String> {
-        let tenant_id = tenant_id.unwrap_or("tenant_default");
+    ) -> Result<(), AdapterError> {
+        let tenant_id = self.tenant_or_fallback(tenant_id, "ph1c_live_telemetry");
         let (outcome_type, reason_code, latency_ms, decision_delta) = match &ph1c.response {
             Ph1cResponse::TranscriptOk(ok) => (
                 if ph1c.low_latency_commit {
@@ -8935,10 +12827,13 @@ impl AdapterRuntime {
                 false,
             ),
         };
-        let idempotency_key = sanitize_idempotency_token(&format!(
-            "ph1c_live_telemetry:{}:{}:{}:{}",
-            tenant_id, correlation_id.0, turn_id.0, outcome_type
-        ));
+        let idempotency_key = sanitize_idempotency_token(
+            &format!(
+                "ph1c_live_telemetry:{}:{}:{}:{}",
+                tenant_id, correlation_id.0, turn_id.0, outcome_type
+            ),
+            self.idempotency_token_max_len,
+        );
         store
             .append_outcome_utilization_ledger_row(OutcomeUtilizationLedgerRowInput {
                 created_at: now,
@@ -8952,22 +12847,23 @@ impl AdapterRuntime {
                 decision_delta,
                 reason_code,
                 idempotency_key: Some(idempotency_key),
+                telemetry_tags: self.telemetry_tags.store_tags(),
             })
-            .map_err(storage_error_to_string)?;
-        if let Err(err) = append_ph1c_live_telemetry_csv(
-            now,
-            correlation_id,
-            turn_id,
-            tenant_id,
-            outcome_type,
-            reason_code,
-            latency_ms,
-            decision_delta,
-            ph1c.finalized,
-            ph1c.low_latency_commit,
-        ) {
-            eprintln!("selene_adapter ph1c live telemetry csv append failed: {err}");
-        }
+            .map_err(AdapterError::Storage)?;
+        self.telemetry_sink
+            .record(TelemetryEvent::Ph1cLive(Ph1cLiveTelemetryEvent {
+                captured_at: now,
+                correlation_id,
+                turn_id,
+                tenant_id: tenant_id.to_string(),
+                outcome_type: outcome_type.to_string(),
+                reason_code,
+                latency_ms,
+                decision_delta,
+                finalized: ph1c.finalized,
+                low_latency_commit: ph1c.low_latency_commit,
+                telemetry_tags: self.telemetry_tags.store_tags(),
+            }));
         Ok(())
     }
 
@@ -8982,8 +12878,11 @@ impl AdapterRuntime {
         device_id: &DeviceId,
         session_id: Option<SessionId>,
         bundle: &Ph1kLiveSignalBundle,
-    ) -> Result<(), String> {
-        let tenant_id = truncate_ascii(tenant_id.unwrap_or("tenant_default"), 64);
+    ) -> Result<(), AdapterError> {
+        let tenant_id = truncate_ascii(
+            self.tenant_or_fallback(tenant_id, "ph1k_live_runtime_events"),
+            64,
+        );
         let processed_stream_id = Some(bundle.processed_stream_ref.stream_id.0);
         let pre_roll_buffer_id = Some(bundle.pre_roll_buffer_ref.buffer_id.0);
         let device_health = storage_device_health_from_bundle(bundle);
@@ -9014,12 +12913,15 @@ impl AdapterRuntime {
                 None,
                 None,
                 None,
-                sanitize_idempotency_token(&format!(
-                    "ph1k_runtime:{}:{}:stream_refs",
-                    correlation_id.0, turn_id.0
-                )),
+                sanitize_idempotency_token(
+                    &format!(
+                        "ph1k_runtime:{}:{}:stream_refs",
+                        correlation_id.0, turn_id.0
+                    ),
+                    self.idempotency_token_max_len,
+                ),
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         for (idx, vad) in bundle.vad_events.iter().enumerate() {
             store
@@ -9048,12 +12950,15 @@ impl AdapterRuntime {
                     None,
                     None,
                     None,
-                    sanitize_idempotency_token(&format!(
-                        "ph1k_runtime:{}:{}:vad:{}",
-                        correlation_id.0, turn_id.0, idx
-                    )),
+                    sanitize_idempotency_token(
+                        &format!(
+                            "ph1k_runtime:{}:{}:vad:{}",
+                            correlation_id.0, turn_id.0, idx
+                        ),
+                        self.idempotency_token_max_len,
+                    ),
                 )
-                .map_err(storage_error_to_string)?;
+                .map_err(AdapterError::Storage)?;
         }
 
         store
@@ -9082,12 +12987,15 @@ impl AdapterRuntime {
                 None,
                 None,
                 None,
-                sanitize_idempotency_token(&format!(
-                    "ph1k_runtime:{}:{}:device_state",
-                    correlation_id.0, turn_id.0
-                )),
+                sanitize_idempotency_token(
+                    &format!(
+                        "ph1k_runtime:{}:{}:device_state",
+                        correlation_id.0, turn_id.0
+                    ),
+                    self.idempotency_token_max_len,
+                ),
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         store
             .ph1k_runtime_event_commit(
@@ -9115,12 +13023,15 @@ impl AdapterRuntime {
                 None,
                 None,
                 None,
-                sanitize_idempotency_token(&format!(
-                    "ph1k_runtime:{}:{}:timing_stats",
-                    correlation_id.0, turn_id.0
-                )),
+                sanitize_idempotency_token(
+                    &format!(
+                        "ph1k_runtime:{}:{}:timing_stats",
+                        correlation_id.0, turn_id.0
+                    ),
+                    self.idempotency_token_max_len,
+                ),
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         store
             .ph1k_runtime_event_commit(
@@ -9148,12 +13059,15 @@ impl AdapterRuntime {
                 Some(bundle.interrupt_input.aec_unstable),
                 Some(bundle.interrupt_input.device_changed),
                 Some(bundle.interrupt_input.stream_gap_detected),
-                sanitize_idempotency_token(&format!(
-                    "ph1k_runtime:{}:{}:degradation",
-                    correlation_id.0, turn_id.0
-                )),
+                sanitize_idempotency_token(
+                    &format!(
+                        "ph1k_runtime:{}:{}:degradation",
+                        correlation_id.0, turn_id.0
+                    ),
+                    self.idempotency_token_max_len,
+                ),
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         store
             .ph1k_runtime_event_commit(
@@ -9181,12 +13095,12 @@ impl AdapterRuntime {
                 None,
                 None,
                 None,
-                sanitize_idempotency_token(&format!(
-                    "ph1k_runtime:{}:{}:tts_active",
-                    correlation_id.0, turn_id.0
-                )),
+                sanitize_idempotency_token(
+                    &format!("ph1k_runtime:{}:{}:tts_active", correlation_id.0, turn_id.0),
+                    self.idempotency_token_max_len,
+                ),
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
 
         if let Some(candidate) = bundle.interrupt_decision.candidate.as_ref() {
             let interrupt_extended = Ph1kInterruptCandidateExtendedFields {
@@ -9273,12 +13187,15 @@ impl AdapterRuntime {
                     None,
                     None,
                     None,
-                    sanitize_idempotency_token(&format!(
-                        "ph1k_runtime:{}:{}:interrupt_candidate:{}",
-                        correlation_id.0, turn_id.0, candidate.phrase_id.0
-                    )),
+                    sanitize_idempotency_token(
+                        &format!(
+                            "ph1k_runtime:{}:{}:interrupt_candidate:{}",
+                            correlation_id.0, turn_id.0, candidate.phrase_id.0
+                        ),
+                        self.idempotency_token_max_len,
+                    ),
                 )
-                .map_err(storage_error_to_string)?;
+                .map_err(AdapterError::Storage)?;
         }
 
         Ok(())
@@ -9296,25 +13213,9 @@ impl AdapterRuntime {
         device_id: &DeviceId,
         session_id: Option<SessionId>,
         bundle: &Ph1kLiveSignalBundle,
-    ) -> Result<(), String> {
-        let feedback_kind = if let Some(candidate) = bundle.interrupt_decision.candidate.as_ref() {
-            if !bundle.tts_playback.active {
-                Some(InterruptFeedbackSignalKind::FalseLexicalTrigger)
-            } else if matches!(
-                candidate.candidate_confidence_band,
-                selene_kernel_contracts::ph1k::InterruptCandidateConfidenceBand::Low
-            ) {
-                Some(InterruptFeedbackSignalKind::WrongConfidenceBand)
-            } else {
-                None
-            }
-        } else if bundle.tts_playback.active && bundle.interrupt_input.detection.is_some() {
-            Some(InterruptFeedbackSignalKind::MissedLexicalTrigger)
-        } else {
-            None
-        };
-
-        let Some(feedback_kind) = feedback_kind else {
+    ) -> Result<(), AdapterError> {
+        let policy = self.ph1k_feedback_policy_for_tenant(tenant_id);
+        let Some(feedback_kind) = ph1k_feedback_signal_kind(bundle, &policy) else {
             return Ok(());
         };
 
@@ -9355,7 +13256,10 @@ impl AdapterRuntime {
             failover_from_device: None,
             failover_to_device: None,
         };
-        let tenant_id = truncate_ascii(tenant_id.unwrap_or("tenant_default"), 64);
+        let tenant_id = truncate_ascii(
+            self.tenant_or_fallback(tenant_id, "ph1k_feedback_capture"),
+            64,
+        );
         store
             .ph1k_feedback_capture_commit(
                 now,
@@ -9366,14 +13270,175 @@ impl AdapterRuntime {
                 actor_user_id.clone(),
                 device_id.clone(),
                 capture_input,
-                sanitize_idempotency_token(&format!(
-                    "ph1k_feedback:{}:{}:{}",
-                    correlation_id.0,
-                    turn_id.0,
-                    interrupt_feedback_kind_label(feedback_kind)
-                )),
+                sanitize_idempotency_token(
+                    &format!(
+                        "ph1k_feedback:{}:{}:{}",
+                        correlation_id.0,
+                        turn_id.0,
+                        interrupt_feedback_kind_label(feedback_kind)
+                    ),
+                    self.idempotency_token_max_len,
+                ),
+            )
+            .map_err(AdapterError::Storage)?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn emit_ph1c_multi_speaker_feedback(
+        &self,
+        store: &mut Ph1fStore,
+        now: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        tenant_id: Option<&str>,
+        actor_user_id: &UserId,
+        device_id: &DeviceId,
+        session_id: Option<SessionId>,
+        bundle: &Ph1kLiveSignalBundle,
+    ) -> Result<(), AdapterError> {
+        let Some(tenant_id) = tenant_id else {
+            return Ok(());
+        };
+        if ph1c_speaker_overlap_class(bundle) != SpeakerOverlapClass::MultiSpeaker {
+            return Ok(());
+        }
+        retry_transient_storage_commit(self.storage_commit_retry_limit, || {
+            store.ph1feedback_event_commit(
+                now,
+                tenant_id.to_string(),
+                correlation_id,
+                turn_id,
+                session_id,
+                actor_user_id.clone(),
+                device_id.clone(),
+                feedback_event_type_str(FeedbackEventType::VoiceIdMultiSpeaker).to_string(),
+                learn_signal_type_str(LearnSignalType::VoiceIdMultiSpeaker).to_string(),
+                voice_id_reason_codes::VID_FAIL_MULTI_SPEAKER_PRESENT,
+                sanitize_idempotency_token(
+                    &format!("ph1c_multi_speaker_{}_{}", correlation_id.0, turn_id.0),
+                    self.idempotency_token_max_len,
+                ),
+            )
+        })
+        .map_err(AdapterError::Storage)?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn emit_ph1vision_skipped_feedback(
+        &self,
+        store: &mut Ph1fStore,
+        now: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        tenant_id: Option<&str>,
+        actor_user_id: &UserId,
+        device_id: &DeviceId,
+        session_id: Option<SessionId>,
+        reason_code: ReasonCodeId,
+    ) -> Result<(), AdapterError> {
+        let Some(tenant_id) = tenant_id else {
+            return Ok(());
+        };
+        retry_transient_storage_commit(self.storage_commit_retry_limit, || {
+            store.ph1feedback_event_commit(
+                now,
+                tenant_id.to_string(),
+                correlation_id,
+                turn_id,
+                session_id,
+                actor_user_id.clone(),
+                device_id.clone(),
+                feedback_event_type_str(FeedbackEventType::ToolFail).to_string(),
+                learn_signal_type_str(LearnSignalType::ToolFail).to_string(),
+                reason_code,
+                sanitize_idempotency_token(
+                    &format!("ph1vision_skipped_{}_{}", correlation_id.0, turn_id.0),
+                    self.idempotency_token_max_len,
+                ),
+            )
+        })
+        .map_err(AdapterError::Storage)?;
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn emit_ph1n_pass_through_feedback(
+        &self,
+        store: &mut Ph1fStore,
+        now: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        tenant_id: Option<&str>,
+        actor_user_id: &UserId,
+        device_id: &DeviceId,
+        session_id: Option<SessionId>,
+    ) -> Result<(), AdapterError> {
+        let Some(tenant_id) = tenant_id else {
+            return Ok(());
+        };
+        retry_transient_storage_commit(self.storage_commit_retry_limit, || {
+            store.ph1feedback_event_commit(
+                now,
+                tenant_id.to_string(),
+                correlation_id,
+                turn_id,
+                session_id,
+                actor_user_id.clone(),
+                device_id.clone(),
+                feedback_event_type_str(FeedbackEventType::ToolFail).to_string(),
+                learn_signal_type_str(LearnSignalType::ToolFail).to_string(),
+                reason_codes::ADAPTER_PH1N_PASS_THROUGH_FALLBACK,
+                sanitize_idempotency_token(
+                    &format!("ph1n_pass_through_{}_{}", correlation_id.0, turn_id.0),
+                    self.idempotency_token_max_len,
+                ),
             )
-            .map_err(storage_error_to_string)?;
+        })
+        .map_err(AdapterError::Storage)?;
+        Ok(())
+    }
+
+    // A "no" to a memory-permission prompt is memory-consent friction, not a task
+    // cancellation, so it is recorded as MemoryOverride for the learn loop.
+    #[allow(clippy::too_many_arguments)]
+    fn emit_memory_permission_declined_feedback(
+        &self,
+        store: &mut Ph1fStore,
+        now: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        tenant_id: Option<&str>,
+        actor_user_id: &UserId,
+        device_id: &DeviceId,
+        session_id: Option<SessionId>,
+    ) -> Result<(), AdapterError> {
+        let Some(tenant_id) = tenant_id else {
+            return Ok(());
+        };
+        retry_transient_storage_commit(self.storage_commit_retry_limit, || {
+            store.ph1feedback_event_commit(
+                now,
+                tenant_id.to_string(),
+                correlation_id,
+                turn_id,
+                session_id,
+                actor_user_id.clone(),
+                device_id.clone(),
+                feedback_event_type_str(FeedbackEventType::MemoryOverride).to_string(),
+                learn_signal_type_str(LearnSignalType::UserCorrection).to_string(),
+                reason_codes::ADAPTER_MEMORY_PERMISSION_DECLINED,
+                sanitize_idempotency_token(
+                    &format!(
+                        "memory_permission_declined_{}_{}",
+                        correlation_id.0, turn_id.0
+                    ),
+                    self.idempotency_token_max_len,
+                ),
+            )
+        })
+        .map_err(AdapterError::Storage)?;
         Ok(())
     }
 
@@ -9385,8 +13450,12 @@ impl AdapterRuntime {
         tenant_scope: Option<&str>,
         base_transcript_text: Option<&str>,
     ) -> Result<(), String> {
-        let Some(vision_turn_input) =
-            build_vision_turn_input_from_adapter_request(request, correlation_id, turn_id)?
+        let Some(vision_turn_input) = build_vision_turn_input_from_adapter_request(
+            request,
+            &self.vision_source_kind_policy,
+            correlation_id,
+            turn_id,
+        )?
         else {
             return Ok(());
         };
@@ -9421,6 +13490,7 @@ impl AdapterRuntime {
             "PH1.D live provider adapter unavailable for PH1.VISION OCR path".to_string()
         })?;
         let mut ocr_route_config = Ph1OsOcrRouteConfig::openai_default();
+        ocr_route_config.retry_budget = self.ocr_route_retry_budget;
         if let Some(tenant_scope) = tenant_scope {
             ocr_route_config.tenant_id = truncate_ascii(tenant_scope, 64);
         }
@@ -9475,19 +13545,124 @@ impl AdapterRuntime {
         }
     }
 
+    #[allow(clippy::too_many_arguments, clippy::result_large_err)]
     fn run_voice_turn_internal(
         &self,
         request: VoiceTurnAdapterRequest,
         runtime_execution_envelope: Option<RuntimeExecutionEnvelope>,
-        persist_on_success: bool,
+        journal_write: LegacyJournalWrite<'_>,
         allow_identity_auto_provision: bool,
         persistence_mode: PersistenceInvocationMode,
+        cancel: Option<&CancellationFlag>,
+        partial_sink: Option<&mut dyn FnMut(&str)>,
+        mut held_store: Option<&mut Ph1fStore>,
     ) -> Result<VoiceTurnAdapterResponse, VoiceTurnIngressError> {
+        let correlation_id = request.correlation_id;
+        let _turn_span = tracing::info_span!(
+            "voice_turn",
+            correlation_id,
+            turn_id = request.turn_id,
+            tenant_id = tracing::field::Empty
+        )
+        .entered();
+        let response = self.execute_voice_turn_internal(
+            request,
+            runtime_execution_envelope,
+            journal_write,
+            allow_identity_auto_provision,
+            persistence_mode,
+            cancel,
+            partial_sink,
+            held_store.as_deref_mut(),
+        )?;
+        if persistence_mode != PersistenceInvocationMode::LegacyJournalReplay {
+            self.record_empty_response_turn(correlation_id, &response);
+            // A batch holds the store lock and writes the snapshot after its last turn.
+            if held_store.is_none() {
+                self.write_periodic_store_snapshot_if_due();
+            }
+        }
+        tracing::debug!(
+            next_move = %response.next_move,
+            outcome = %response.outcome,
+            "voice turn finished"
+        );
+        Ok(response)
+    }
+
+    fn record_empty_response_turn(&self, correlation_id: u64, response: &VoiceTurnAdapterResponse) {
+        if !response.response_text.trim().is_empty() {
+            return;
+        }
+        let intentional = empty_response_is_intentional(&response.next_move);
+        if !intentional {
+            tracing::warn!(
+                correlation_id,
+                turn_id = response.turn_id.unwrap_or_default(),
+                next_move = %response.next_move,
+                outcome = %response.outcome,
+                reason_code = %response.reason_code,
+                "unexpected empty response"
+            );
+        }
+        let Ok(mut counters) = self.improvement_counters.lock() else {
+            return;
+        };
+        let by_next_move = if intentional {
+            &mut counters.empty_responses.intentional_by_next_move
+        } else {
+            &mut counters.empty_responses.unexpected_by_next_move
+        };
+        let count = by_next_move.entry(response.next_move.clone()).or_default();
+        *count = count.saturating_add(1);
+    }
+
+    fn lock_turn_store<'a>(
+        &'a self,
+        held_store: &'a mut Option<&mut Ph1fStore>,
+    ) -> Result<VoiceTurnStore<'a>, AdapterError> {
+        match held_store.as_deref_mut() {
+            Some(store) => Ok(VoiceTurnStore::Held(store)),
+            None => self
+                .store
+                .lock()
+                .map(VoiceTurnStore::Locked)
+                .map_err(|_| AdapterError::LockPoisoned("store".to_string())),
+        }
+    }
+
+    // Puts back the store a cancelled turn started from. The thread-state cache is dropped too:
+    // its ledger-length check cannot tell a rolled-back ledger from one that grew back.
+    fn restore_cancelled_turn_store(&self, store: &mut Ph1fStore, checkpoint: Option<Ph1fStore>) {
+        let Some(checkpoint) = checkpoint else {
+            return;
+        };
+        *store = checkpoint;
+        if let Ok(mut cache) = self.ph1x_thread_state_cache.lock() {
+            *cache = AdapterPh1xThreadStateCache::default();
+        }
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::result_large_err)]
+    fn execute_voice_turn_internal(
+        &self,
+        request: VoiceTurnAdapterRequest,
+        runtime_execution_envelope: Option<RuntimeExecutionEnvelope>,
+        journal_write: LegacyJournalWrite<'_>,
+        allow_identity_auto_provision: bool,
+        persistence_mode: PersistenceInvocationMode,
+        cancel: Option<&CancellationFlag>,
+        partial_sink: Option<&mut dyn FnMut(&str)>,
+        mut held_store: Option<&mut Ph1fStore>,
+    ) -> Result<VoiceTurnAdapterResponse, VoiceTurnIngressError> {
+        let mut request = request;
+        self.apply_unknown_app_platform_policy(&mut request);
         let request_for_journal = request.clone();
         let response_turn_id = Some(request.turn_id);
         let mut user_text_partial =
             sanitize_transcript_text_option(request.user_text_partial.clone());
         let mut user_text_final = sanitize_transcript_text_option(request.user_text_final.clone());
+        let user_text_part_seq = request.user_text_part_seq.filter(|part| *part > 0);
         let upstream_transcript_supplied = user_text_final.is_some();
         let committed_user_source =
             committed_user_conversation_source_for_request(&request, upstream_transcript_supplied);
@@ -9497,8 +13672,32 @@ impl AdapterRuntime {
         let pre_session_error = |reason: String| {
             classify_voice_turn_runtime_error(&reason, response_turn_id, None, None)
         };
+        let pre_session_failure = |err: AdapterError| {
+            classify_voice_turn_adapter_error(err, response_turn_id, None, None)
+        };
         let app_platform = parse_app_platform(&request.app_platform).map_err(pre_session_error)?;
         let trigger = parse_trigger(&request.trigger).map_err(pre_session_error)?;
+        let provenance_mode = match request.provenance_mode.as_deref() {
+            Some(value) => VoiceTurnProvenanceMode::parse(value).map_err(pre_session_error)?,
+            None => self.provenance_mode,
+        };
+        // Replay never calls PH1.D, so a journaled override is not re-checked against an
+        // allowlist that may have changed since.
+        let transcript_privacy_scope =
+            self.transcript_privacy_scope(request.thread_policy_flags.as_ref());
+        let model_override = if persistence_mode == PersistenceInvocationMode::LegacyJournalReplay {
+            None
+        } else {
+            self.resolve_model_override(request.model_override.as_deref())
+                .map_err(pre_session_error)?
+        };
+        let pre_fetched_tool_response = request
+            .pre_fetched_tool_response
+            .as_ref()
+            .map(pre_fetched_tool_response_to_ph1e)
+            .transpose()
+            .map_err(pre_session_error)?;
+        request_language_hint(&request).map_err(pre_session_error)?;
         let actor_user_id = UserId::new(request.actor_user_id.clone())
             .map_err(|err| pre_session_error(format!("invalid actor_user_id: {err:?}")))?;
         let request_device_id = request
@@ -9517,7 +13716,13 @@ impl AdapterRuntime {
             .max(1);
         let platform_context = normalize_platform_runtime_context(&request, app_platform, trigger)
             .map_err(pre_session_error)?;
-        let now = MonotonicTimeNs(request.now_ns.unwrap_or(1));
+        let now = MonotonicTimeNs(match request.now_ns {
+            Some(now_ns) => self
+                .clock_skew_policy
+                .resolve_now_ns(now_ns, system_time_now_ns())
+                .map_err(pre_session_error)?,
+            None => 1,
+        });
         let runtime_device_id = match request_device_id {
             Some(id) => id,
             None => DeviceId::new(format!(
@@ -9529,10 +13734,9 @@ impl AdapterRuntime {
             })?,
         };
         let actor_user_id = {
-            let store = self
-                .store
-                .lock()
-                .map_err(|_| pre_session_error("adapter store lock poisoned".to_string()))?;
+            let store = self.lock_turn_store(&mut held_store).map_err(|_| {
+                pre_session_failure(AdapterError::LockPoisoned("store".to_string()))
+            })?;
             resolve_effective_desktop_actor_identity(
                 &store,
                 &actor_user_id,
@@ -9541,6 +13745,66 @@ impl AdapterRuntime {
                 trigger,
             )
         };
+        let tenant_id_for_ph1c = resolve_tenant_scope(
+            request.tenant_id.clone(),
+            &actor_user_id,
+            Some(&runtime_device_id),
+            self.tenant_delimiter,
+        );
+        // Gated before any cached or persisted result is reused, so a tenant blocked after its
+        // first request gets no replays. Journal replay re-applies turns that were already
+        // admitted; only live turns are gated.
+        if persistence_mode != PersistenceInvocationMode::LegacyJournalReplay {
+            if let Some(blocked_reason) = self
+                .tenant_access_blocked_reason(tenant_id_for_ph1c.as_deref())
+                .map_err(pre_session_failure)?
+            {
+                return Err(voice_turn_ingress_error(
+                    FailureClass::PolicyViolation,
+                    "TENANT_BLOCKED".to_string(),
+                    Some(format!(
+                        "tenant_blocked reason_code={} reason={} tenant_id={}",
+                        reason_codes::ADAPTER_TENANT_BLOCKED.0,
+                        blocked_reason,
+                        tenant_id_for_ph1c.as_deref().unwrap_or("unknown")
+                    )),
+                    None,
+                    response_turn_id,
+                    None,
+                ));
+            }
+        }
+        let replay_fingerprint = turn_replay_fingerprint(&request_for_journal);
+        if persistence_mode != PersistenceInvocationMode::LegacyJournalReplay {
+            let batch_entry = match journal_write {
+                LegacyJournalWrite::Deferred(batch) => batch
+                    .latest_replay_entry((correlation_id, turn_id))
+                    .map_err(pre_session_failure)?,
+                LegacyJournalWrite::Skip | LegacyJournalWrite::Immediate => None,
+            };
+            let replayed = match batch_entry {
+                Some(entry) => entry
+                    .matches(&actor_user_id, &replay_fingerprint)
+                    .then_some(entry.response),
+                None => self
+                    .turn_replay_cache
+                    .lock()
+                    .map_err(|_| {
+                        pre_session_failure(AdapterError::LockPoisoned(
+                            "turn replay cache".to_string(),
+                        ))
+                    })?
+                    .get(
+                        (correlation_id, turn_id),
+                        &actor_user_id,
+                        &replay_fingerprint,
+                    ),
+            };
+            if let Some(mut response) = replayed {
+                response.session_attach_outcome = Some(SessionAttachOutcome::RetryReusedResult);
+                return Ok(response);
+            }
+        }
         let mut runtime_execution_envelope = match runtime_execution_envelope {
             Some(envelope) => {
                 RuntimeExecutionEnvelope::v1_with_platform_context_device_turn_sequence_and_attach_outcome(
@@ -9584,11 +13848,12 @@ impl AdapterRuntime {
                     &actor_user_id,
                     &runtime_execution_envelope.idempotency_key,
                 )
-                .map_err(pre_session_error)?
+                .map_err(pre_session_failure)?
             {
                 let reused_result = match reused_result {
                     Ok(response) => {
                         self.record_reused_response_transcript_finals(
+                            &mut held_store,
                             ReusedResponseTranscriptUpdate {
                                 now,
                                 correlation_id,
@@ -9597,11 +13862,13 @@ impl AdapterRuntime {
                                 device_id: &runtime_device_id,
                                 response: &response,
                                 user_source: committed_user_source,
+                                privacy_scope: transcript_privacy_scope,
+                                user_text_part_seq,
                                 user_text_final: user_text_final.as_deref(),
                                 selene_text_final: selene_text_final.as_deref(),
                             },
                         )
-                        .map_err(pre_session_error)?;
+                        .map_err(pre_session_failure)?;
                         Ok(response)
                     }
                     Err(error) => Err(error),
@@ -9609,6 +13876,9 @@ impl AdapterRuntime {
                 return reused_result;
             }
         }
+        if cancel.is_some_and(CancellationFlag::is_cancelled) {
+            return Err(voice_turn_cancelled_error(response_turn_id));
+        }
         let prepared_persistence = self
             .prepare_persistence_operation(
                 &request,
@@ -9616,7 +13886,7 @@ impl AdapterRuntime {
                 now,
                 persistence_mode,
             )
-            .map_err(pre_session_error)?;
+            .map_err(pre_session_failure)?;
         if let Some(prepared) = prepared_persistence.as_ref() {
             runtime_execution_envelope = runtime_execution_envelope
                 .with_persistence_state(Some(prepared.persistence_state.clone()))
@@ -9631,9 +13901,10 @@ impl AdapterRuntime {
             &actor_user_id,
             &runtime_execution_envelope.idempotency_key,
         )
-        .map_err(pre_session_error)?
+        .map_err(pre_session_failure)?
         {
             let result = match self.record_reused_response_transcript_finals(
+                &mut held_store,
                 ReusedResponseTranscriptUpdate {
                     now,
                     correlation_id,
@@ -9642,12 +13913,14 @@ impl AdapterRuntime {
                     device_id: &runtime_device_id,
                     response: &response,
                     user_source: committed_user_source,
+                    privacy_scope: transcript_privacy_scope,
+                    user_text_part_seq,
                     user_text_final: user_text_final.as_deref(),
                     selene_text_final: selene_text_final.as_deref(),
                 },
             ) {
                 Ok(()) => Ok(response),
-                Err(err) => Err(pre_session_error(err)),
+                Err(err) => Err(pre_session_failure(err)),
             };
             if let Some(prepared) = prepared_persistence.as_ref() {
                 self.finalize_persistence_operation(
@@ -9657,7 +13930,7 @@ impl AdapterRuntime {
                     &runtime_execution_envelope.idempotency_key,
                     &result,
                 )
-                .map_err(pre_session_error)?;
+                .map_err(pre_session_failure)?;
             }
             return result;
         }
@@ -9665,11 +13938,15 @@ impl AdapterRuntime {
         let stage7_user_text_for_fallback = user_text_final.clone();
         let mut stage8_fresh_memory_bridge_for_fallback: Option<Stage8FreshMemoryBridge> = None;
         let mut stage8_5c_candidate_decision_for_fallback: Option<Stage8_5CandidateDecision> = None;
+        let mut stt_mode_for_response: Option<VoiceTurnSttMode> = None;
         let execution_result = (|| {
-            let mut store = self
-                .store
-                .lock()
-                .map_err(|_| pre_session_error("adapter store lock poisoned".to_string()))?;
+            let mut store = self.lock_turn_store(&mut held_store).map_err(|_| {
+                pre_session_failure(AdapterError::LockPoisoned("store".to_string()))
+            })?;
+            // A cancelled turn leaves Ph1fStore as it found it. Cancellable turns never stream
+            // partials, so the store lock is held from here to the last cancel check and the
+            // checkpoint can be put back without losing another turn's writes.
+            let cancel_checkpoint = cancel.map(|_| store.clone());
             ensure_actor_identity_and_device(
                 &mut store,
                 &actor_user_id,
@@ -9678,20 +13955,35 @@ impl AdapterRuntime {
                 now,
                 allow_identity_auto_provision,
             )
-            .map_err(pre_session_error)?;
-            let tenant_id_for_ph1c = resolve_tenant_scope(
-                request.tenant_id.clone(),
-                &actor_user_id,
-                Some(&runtime_device_id),
-            );
-            let ph1k_bundle = build_ph1k_live_signal_bundle(
+            .map_err(pre_session_failure)?;
+            tracing::Span::current().record("tenant_id", tenant_id_for_ph1c.as_deref());
+            let prior_detected_locale = self
+                .ph1c_detected_locale(&runtime_device_id)
+                .map_err(pre_session_failure)?;
+            let ph1k_stage = voice_turn_stage_span!(
+                "ph1k_bundle",
+                request.correlation_id,
+                request.turn_id,
+                tenant_id_for_ph1c.as_deref()
+            )
+            .entered();
+            let mut ph1k_bundle = build_ph1k_live_signal_bundle(
                 &store,
                 &request,
                 now,
                 tenant_id_for_ph1c.as_deref(),
                 Some(&runtime_device_id),
+                prior_detected_locale.as_deref(),
+                &self.interrupt_matcher,
             )
             .map_err(pre_session_error)?;
+            apply_interrupt_confidence_policy(&mut ph1k_bundle, &self.interrupt_confidence_policy)
+                .map_err(pre_session_error)?;
+            tracing::debug!(
+                interrupt_candidate = ph1k_bundle.interrupt_decision.candidate.is_some(),
+                "ph1k live signal bundle built"
+            );
+            drop(ph1k_stage);
             let device_owner_user_id = store
                 .get_device(&runtime_device_id)
                 .map(|device| device.user_id.clone());
@@ -9701,7 +13993,8 @@ impl AdapterRuntime {
                 &actor_user_id,
                 &runtime_device_id,
                 app_platform,
-                trigger,
+                self.wake_gate_policy
+                    .requires_session_open_step(trigger, app_platform),
                 &ph1k_bundle,
             )
             .map_err(pre_session_error)?;
@@ -9721,8 +14014,9 @@ impl AdapterRuntime {
                         session_id_for_reject,
                         &ph1k_bundle,
                         wake_eval,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(pre_session_error)?;
+                    .map_err(pre_session_failure)?;
                     commit_wake_learn_signal(
                         &mut store,
                         now,
@@ -9733,8 +14027,9 @@ impl AdapterRuntime {
                         trigger,
                         &ph1k_bundle,
                         wake_eval,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(pre_session_error)?;
+                    .map_err(pre_session_failure)?;
                     return Err(voice_turn_ingress_error(
                         FailureClass::PolicyViolation,
                         format!("WAKE_REJECTED_{}", wake_eval.decision.reason_code.0),
@@ -9757,6 +14052,8 @@ impl AdapterRuntime {
                     actor_user_id: &actor_user_id,
                     device_id: &runtime_device_id,
                     trigger,
+                    app_platform,
+                    wake_gate_policy: self.wake_gate_policy,
                     ph1k: &ph1k_bundle,
                     wake_event: wake_evaluation.as_ref().map(|wake| wake.decision.clone()),
                     idempotency_key: &runtime_execution_envelope.idempotency_key,
@@ -9765,8 +14062,9 @@ impl AdapterRuntime {
                     session_lease_ttl_ms: self.session_lease_ttl_ms,
                     session_retry_cache: &self.session_retry_cache,
                 },
+                self.idempotency_token_max_len,
             )
-            .map_err(pre_session_error)?
+            .map_err(pre_session_failure)?
             {
                 AdapterSessionResolution::Proceed(state) => state,
                 AdapterSessionResolution::Retry(response) => return Ok(response),
@@ -9784,6 +14082,14 @@ impl AdapterRuntime {
                     Some(response_session_state),
                 )
             };
+            let post_session_failure = |err: AdapterError| {
+                classify_voice_turn_adapter_error(
+                    err,
+                    response_turn_id,
+                    response_session_id.clone(),
+                    Some(response_session_state),
+                )
+            };
             runtime_execution_envelope = runtime_execution_envelope
                 .with_session_device_turn_and_attach_outcome(
                     session_turn_state.session_snapshot.session_id,
@@ -9807,8 +14113,9 @@ impl AdapterRuntime {
                     session_turn_state.session_id_for_commits,
                     &ph1k_bundle,
                     wake_eval,
+                    self.idempotency_token_max_len,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 commit_wake_learn_signal(
                     &mut store,
                     now,
@@ -9819,20 +14126,23 @@ impl AdapterRuntime {
                     trigger,
                     &ph1k_bundle,
                     wake_eval,
+                    self.idempotency_token_max_len,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
             }
             let voice_id_request = build_voice_id_request_from_ph1k_bundle(
                 now,
                 &ph1k_bundle,
                 session_turn_state.session_snapshot,
                 session_turn_state.wake_event.clone(),
+                device_trust_level_from_history(&store, &runtime_device_id),
                 device_owner_user_id.clone(),
             )
             .map_err(|err| post_session_error(format!("voice request build failed: {err:?}")))?;
             let voice_id_observation = build_live_voice_id_observation(
                 &store,
                 tenant_id_for_ph1c.as_deref(),
+                self.tenant_delimiter,
                 &actor_user_id,
                 &runtime_device_id,
                 app_platform,
@@ -9858,8 +14168,9 @@ impl AdapterRuntime {
                     &runtime_execution_envelope.idempotency_key,
                     &self.runtime_node_id,
                     self.session_lease_ttl_ms,
+                    self.idempotency_token_max_len,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 commit_stage34m_activation_only_wake_session_audit(
                     &mut store,
                     now,
@@ -9870,11 +14181,13 @@ impl AdapterRuntime {
                     session_turn_state.session_id_for_commits,
                     tenant_id_for_ph1c.as_deref(),
                     wake_evaluation.as_ref(),
+                    self.idempotency_token_max_len,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 let voice_id_posture = activation_handoff_voice_id_posture(
                     &store,
                     tenant_id_for_ph1c.as_deref(),
+                    self.tenant_delimiter,
                     &actor_user_id,
                     &runtime_device_id,
                     &voice_id_request,
@@ -9891,10 +14204,21 @@ impl AdapterRuntime {
                 );
                 return Ok(response);
             }
+            if cancel.is_some_and(CancellationFlag::is_cancelled) {
+                self.restore_cancelled_turn_store(&mut store, cancel_checkpoint);
+                return Err(voice_turn_cancelled_error(response_turn_id));
+            }
+            let ph1c_stage = voice_turn_stage_span!(
+                "ph1c_live",
+                request.correlation_id,
+                request.turn_id,
+                tenant_id_for_ph1c.as_deref()
+            )
+            .entered();
             let committed_voice_ph1c_gate_used =
                 upstream_transcript_supplied && is_desktop_committed_voice_request(&request);
-            let ph1c_live_outcome = if upstream_transcript_supplied {
-                if committed_voice_ph1c_gate_used {
+            let (turn_stt_mode, ph1c_live_outcome) = if upstream_transcript_supplied {
+                let committed_gate_outcome = if committed_voice_ph1c_gate_used {
                     match (
                         request.audio_capture_ref.as_ref(),
                         user_text_final.as_deref(),
@@ -9912,17 +14236,52 @@ impl AdapterRuntime {
                     }
                 } else {
                     None
-                }
+                };
+                let stt_mode = if committed_gate_outcome
+                    .as_ref()
+                    .is_some_and(|ph1c| matches!(ph1c.response, Ph1cResponse::TranscriptReject(_)))
+                {
+                    VoiceTurnSttMode::SkippedQualityGate
+                } else {
+                    VoiceTurnSttMode::Upstream
+                };
+                (stt_mode, committed_gate_outcome)
             } else {
-                self.run_ph1c_live_turn(
-                    correlation_id,
-                    turn_id,
-                    &actor_user_id,
-                    tenant_id_for_ph1c.as_deref(),
-                    session_turn_state.session_snapshot.session_state,
-                    &ph1k_bundle,
-                )
+                match partial_sink {
+                    // The partial callback may call back into the runtime, so the store
+                    // stays unlocked while live STT streams.
+                    Some(on_partial) => {
+                        drop(store);
+                        let live_turn = self.run_ph1c_live_turn(
+                            correlation_id,
+                            turn_id,
+                            &actor_user_id,
+                            tenant_id_for_ph1c.as_deref(),
+                            session_turn_state.session_snapshot.session_state,
+                            &ph1k_bundle,
+                            Some(on_partial),
+                        );
+                        store = self.lock_turn_store(&mut held_store).map_err(|_| {
+                            post_session_failure(AdapterError::LockPoisoned("store".to_string()))
+                        })?;
+                        live_turn
+                    }
+                    None => self.run_ph1c_live_turn(
+                        correlation_id,
+                        turn_id,
+                        &actor_user_id,
+                        tenant_id_for_ph1c.as_deref(),
+                        session_turn_state.session_snapshot.session_state,
+                        &ph1k_bundle,
+                        None,
+                    ),
+                }
             };
+            if cancel.is_some_and(CancellationFlag::is_cancelled) {
+                self.restore_cancelled_turn_store(&mut store, cancel_checkpoint);
+                return Err(voice_turn_cancelled_error(response_turn_id));
+            }
+            stt_mode_for_response = Some(turn_stt_mode);
             if let Some(ph1c) = ph1c_live_outcome.as_ref() {
                 if user_text_partial.is_none() {
                     user_text_partial = ph1c.partial_text.clone();
@@ -9941,19 +14300,24 @@ impl AdapterRuntime {
                     session_turn_state.session_id_for_commits,
                     ph1c,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
             }
-            if committed_voice_ph1c_gate_used {
+            tracing::debug!(stt_mode = ?turn_stt_mode, "ph1c live stage finished");
+            drop(ph1c_stage);
+            let snr_floor_rejected = turn_stt_mode == VoiceTurnSttMode::SkippedLowSnr;
+            if committed_voice_ph1c_gate_used || snr_floor_rejected {
                 if let Some(ph1c) = ph1c_live_outcome.as_ref() {
                     if let Ph1cResponse::TranscriptReject(reject) = &ph1c.response {
-                        let ignored_unsafe_transcript =
-                            committed_voice_reject_should_ignore_user_surface(
+                        let ignored_unsafe_transcript = !snr_floor_rejected
+                            && committed_voice_reject_should_ignore_user_surface(
                                 user_text_final.as_deref(),
                                 request.audio_capture_ref.as_ref(),
                                 reject.reason_code,
                             );
                         let response_text = if ignored_unsafe_transcript {
                             String::new()
+                        } else if snr_floor_rejected {
+                            ph1c_low_snr_response_text(request.audio_capture_ref.as_ref())
                         } else {
                             ph1c_voice_retry_response_text(
                                 request.audio_capture_ref.as_ref(),
@@ -9961,6 +14325,11 @@ impl AdapterRuntime {
                             )
                         };
                         if !ignored_unsafe_transcript {
+                            self.record_ph1x_last_failure(
+                                actor_user_id.as_str(),
+                                &resolve_adapter_thread_key(request.thread_key.as_deref()),
+                                reject.reason_code,
+                            );
                             self.record_transcript_updates(
                                 &mut store,
                                 now,
@@ -9970,13 +14339,15 @@ impl AdapterRuntime {
                                 Some(&runtime_device_id),
                                 session_turn_state.session_id_for_commits,
                                 committed_user_source,
+                                transcript_privacy_scope,
+                                user_text_part_seq,
                                 user_text_partial.clone(),
                                 user_text_final.clone(),
                                 None,
                                 Some(response_text.clone()),
                                 false,
                             )
-                            .map_err(post_session_error)?;
+                            .map_err(post_session_failure)?;
                         }
                         self.emit_ph1c_gold_capture_and_learning(
                             &mut store,
@@ -9989,7 +14360,7 @@ impl AdapterRuntime {
                             session_turn_state.session_id_for_commits,
                             ph1c,
                         )
-                        .map_err(post_session_error)?;
+                        .map_err(post_session_failure)?;
                         self.emit_ph1c_live_telemetry(
                             &mut store,
                             now,
@@ -9998,7 +14369,7 @@ impl AdapterRuntime {
                             ph1c,
                             tenant_id_for_ph1c.as_deref(),
                         )
-                        .map_err(post_session_error)?;
+                        .map_err(post_session_failure)?;
                         finalize_session_turn_record(
                             &mut store,
                             now,
@@ -10011,8 +14382,9 @@ impl AdapterRuntime {
                             &runtime_execution_envelope.idempotency_key,
                             &self.runtime_node_id,
                             self.session_lease_ttl_ms,
+                            self.idempotency_token_max_len,
                         )
-                        .map_err(post_session_error)?;
+                        .map_err(post_session_failure)?;
                         let response = VoiceTurnAdapterResponse {
                             status: "ok".to_string(),
                             outcome: if ignored_unsafe_transcript {
@@ -10034,6 +14406,9 @@ impl AdapterRuntime {
                             reason: Some(if ignored_unsafe_transcript {
                                 "voice transcript rejected as unsafe noise before runtime entry"
                                     .to_string()
+                            } else if snr_floor_rejected {
+                                "capture SNR below floor; transcription skipped before provider call"
+                                    .to_string()
                             } else {
                                 "voice transcript rejected before runtime entry".to_string()
                             }),
@@ -10059,6 +14434,11 @@ impl AdapterRuntime {
                             deep_research: None,
                             screen_lifecycle_action: None,
                             session_lifecycle_action: None,
+                            stt_mode: String::new(),
+                            keep_listening: false,
+                            next_move_kind: (!ignored_unsafe_transcript)
+                                .then_some(VoiceTurnNextMove::Clarify),
+                            outcome_kind: None,
                         };
                         if !ignored_unsafe_transcript {
                             if let Some(trace) = h410_build_public_brain_trace(
@@ -10071,12 +14451,12 @@ impl AdapterRuntime {
                                 None,
                             ) {
                                 self.record_public_brain_trace(trace)
-                                    .map_err(post_session_error)?;
+                                    .map_err(post_session_failure)?;
                             }
                         }
                         drop(store);
-                        self.sync_internal_history_evidence_to_persistence()
-                            .map_err(post_session_error)?;
+                        self.sync_internal_history_evidence_for_turn(&mut held_store)
+                            .map_err(post_session_failure)?;
                         return Ok(response);
                     }
                 }
@@ -10092,7 +14472,7 @@ impl AdapterRuntime {
                     user_text_final.as_deref(),
                     request.thread_policy_flags.as_ref(),
                 )
-                .map_err(post_session_error)?
+                .map_err(post_session_failure)?
             {
                 match decision {
                     WakeGuestLaneTurnDecision::Respond(response) => {
@@ -10105,13 +14485,15 @@ impl AdapterRuntime {
                             Some(&runtime_device_id),
                             session_turn_state.session_id_for_commits,
                             committed_user_source,
+                            transcript_privacy_scope,
+                            user_text_part_seq,
                             user_text_partial.clone(),
                             user_text_final.clone(),
                             None,
                             Some(response.response_text.clone()),
                             false,
                         )
-                        .map_err(post_session_error)?;
+                        .map_err(post_session_failure)?;
                         finalize_session_turn_record(
                             &mut store,
                             now,
@@ -10124,8 +14506,9 @@ impl AdapterRuntime {
                             &runtime_execution_envelope.idempotency_key,
                             &self.runtime_node_id,
                             self.session_lease_ttl_ms,
+                            self.idempotency_token_max_len,
                         )
-                        .map_err(post_session_error)?;
+                        .map_err(post_session_failure)?;
                         return Ok(response);
                     }
                     WakeGuestLaneTurnDecision::AllowPublicSafeGuest => {
@@ -10148,6 +14531,7 @@ impl AdapterRuntime {
                     let voice_id_posture_after_retry = activation_handoff_voice_id_posture(
                         &store,
                         tenant_id_for_ph1c.as_deref(),
+                        self.tenant_delimiter,
                         &actor_user_id,
                         &runtime_device_id,
                         &voice_id_request,
@@ -10165,10 +14549,11 @@ impl AdapterRuntime {
                                     ),
                                     WakeGuestLanePosture::UnknownGuestUnverified,
                                 )
-                                .map_err(post_session_error)?;
+                                .map_err(post_session_failure)?;
                                 if let Some(response) = continuing_speech_identity_prompt_response(
                                     &store,
                                     tenant_id_for_ph1c.as_deref(),
+                                    self.tenant_delimiter,
                                     &actor_user_id,
                                     &runtime_device_id,
                                     &runtime_execution_envelope,
@@ -10190,13 +14575,15 @@ impl AdapterRuntime {
                                         Some(&runtime_device_id),
                                         session_turn_state.session_id_for_commits,
                                         committed_user_source,
+                                        transcript_privacy_scope,
+                                        user_text_part_seq,
                                         user_text_partial.clone(),
                                         user_text_final.clone(),
                                         None,
                                         Some(response.response_text.clone()),
                                         false,
                                     )
-                                    .map_err(post_session_error)?;
+                                    .map_err(post_session_failure)?;
                                     finalize_session_turn_record(
                                         &mut store,
                                         now,
@@ -10209,8 +14596,9 @@ impl AdapterRuntime {
                                         &runtime_execution_envelope.idempotency_key,
                                         &self.runtime_node_id,
                                         self.session_lease_ttl_ms,
+                                        self.idempotency_token_max_len,
                                     )
-                                    .map_err(post_session_error)?;
+                                    .map_err(post_session_failure)?;
                                     return Ok(response);
                                 }
                             }
@@ -10219,15 +14607,61 @@ impl AdapterRuntime {
                     }
                 }
             }
-            self.run_ph1vision_os_orchestration_step(
+            let vision_stage = voice_turn_stage_span!(
+                "ph1vision",
+                request.correlation_id,
+                request.turn_id,
+                tenant_id_for_ph1c.as_deref()
+            )
+            .entered();
+            if let Some(source_kind) =
+                disallowed_visual_source_kind(&request, &self.vision_source_kind_policy)
+            {
+                tracing::warn!(
+                    "ph1vision stage skipped: source_kind {source_kind:?} disallowed by policy"
+                );
+                self.emit_ph1vision_skipped_feedback(
+                    &mut store,
+                    now,
+                    correlation_id,
+                    turn_id,
+                    tenant_id_for_ph1c.as_deref(),
+                    &actor_user_id,
+                    &runtime_device_id,
+                    session_turn_state.session_id_for_commits,
+                    reason_codes::ADAPTER_VISION_SOURCE_KIND_DISALLOWED,
+                )
+                .map_err(post_session_failure)?;
+            } else if let Err(err) = self.run_ph1vision_os_orchestration_step(
                 &request,
                 correlation_id,
                 turn_id,
                 tenant_id_for_ph1c.as_deref(),
                 user_text_final.as_deref(),
-            )
-            .map_err(post_session_error)?;
+            ) {
+                if ph1vision_failure_strict() || !voice_turn_has_non_vision_input(&request) {
+                    return Err(post_session_error(err));
+                }
+                tracing::warn!("ph1vision stage skipped: {err}");
+                self.emit_ph1vision_skipped_feedback(
+                    &mut store,
+                    now,
+                    correlation_id,
+                    turn_id,
+                    tenant_id_for_ph1c.as_deref(),
+                    &actor_user_id,
+                    &runtime_device_id,
+                    session_turn_state.session_id_for_commits,
+                    ph1vision_reason_codes::PH1_VISION_INTERNAL_PIPELINE_ERROR,
+                )
+                .map_err(post_session_failure)?;
+            }
+            drop(vision_stage);
 
+            if cancel.is_some_and(CancellationFlag::is_cancelled) {
+                self.restore_cancelled_turn_store(&mut store, cancel_checkpoint);
+                return Err(voice_turn_cancelled_error(response_turn_id));
+            }
             self.commit_ph1k_live_runtime_events(
                 &mut store,
                 now,
@@ -10238,7 +14672,7 @@ impl AdapterRuntime {
                 session_turn_state.session_id_for_commits,
                 &ph1k_bundle,
             )
-            .map_err(post_session_error)?;
+            .map_err(post_session_failure)?;
             self.emit_ph1k_feedback_capture(
                 &mut store,
                 now,
@@ -10250,17 +14684,36 @@ impl AdapterRuntime {
                 session_turn_state.session_id_for_commits,
                 &ph1k_bundle,
             )
-            .map_err(post_session_error)?;
-            if let Err(err) = append_ph1k_live_eval_snapshot_csv(
-                &store,
+            .map_err(post_session_failure)?;
+            self.emit_ph1c_multi_speaker_feedback(
+                &mut store,
                 now,
                 correlation_id,
                 turn_id,
-                tenant_id_for_ph1c.as_deref().unwrap_or("tenant_default"),
+                tenant_id_for_ph1c.as_deref(),
+                &actor_user_id,
+                &runtime_device_id,
+                session_turn_state.session_id_for_commits,
                 &ph1k_bundle,
-            ) {
-                eprintln!("selene_adapter ph1k live eval csv append failed: {err}");
-            }
+            )
+            .map_err(post_session_failure)?;
+            self.telemetry_sink
+                .record(TelemetryEvent::Ph1kLiveEvalSnapshot {
+                    correlation_id,
+                    turn_id,
+                    csv_row: ph1k_live_eval_snapshot_csv_row(
+                        &store,
+                        now,
+                        correlation_id,
+                        turn_id,
+                        self.tenant_or_fallback(
+                            tenant_id_for_ph1c.as_deref(),
+                            "ph1k_live_eval_snapshot",
+                        ),
+                        &ph1k_bundle,
+                    ),
+                    telemetry_tags: self.telemetry_tags.store_tags(),
+                });
 
             let ingress_request = AppVoiceIngressRequest::v1_with_runtime_execution_envelope(
                 correlation_id,
@@ -10277,7 +14730,12 @@ impl AdapterRuntime {
             )
             .map_err(|err| post_session_error(format!("invalid ingress request: {err:?}")))?;
             let thread_key = resolve_adapter_thread_key(request.thread_key.as_deref());
-            let mut base_thread_state = load_ph1x_thread_state(&store, &actor_user_id, &thread_key);
+            let mut base_thread_state = load_ph1x_thread_state_cached(
+                &self.ph1x_thread_state_cache,
+                &*store,
+                &actor_user_id,
+                &thread_key,
+            );
             if request.project_id.is_some() || request.pinned_context_refs.is_some() {
                 let project_id = resolve_adapter_project_id(request.project_id.as_deref());
                 let pinned_context_refs =
@@ -10313,7 +14771,7 @@ impl AdapterRuntime {
                 session_turn_state.session_attach_outcome,
                 base_thread_state,
             )
-            .map_err(post_session_error)?;
+            .map_err(post_session_failure)?;
             if app_platform == AppPlatform::Desktop {
                 if let Some(screen_lifecycle_intent) = user_text_final
                     .as_deref()
@@ -10336,8 +14794,9 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &self.runtime_node_id,
                         self.session_lease_ttl_ms,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     let response = stage4_screen_lifecycle_adapter_response(
                         &runtime_execution_envelope,
                         session_turn_state.session_snapshot.session_state,
@@ -10351,7 +14810,7 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &response,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     return Ok(response);
                 }
                 if let Some(session_lifecycle_intent) = user_text_final
@@ -10375,12 +14834,14 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &self.runtime_node_id,
                         self.session_lease_ttl_ms,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     clear_stage6_session_scoped_contexts(
                         &self.active_session_context_state,
                         &self.weather_context_state,
                         &self.public_discourse_state,
+                        &self.ph1x_thread_state_cache,
                         &mut store,
                         now,
                         correlation_id,
@@ -10388,8 +14849,9 @@ impl AdapterRuntime {
                         &actor_user_id,
                         &thread_key,
                         base_thread_state,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     let response = stage6_session_lifecycle_adapter_response(
                         &runtime_execution_envelope,
                         closed_session_state
@@ -10404,7 +14866,7 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &response,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     return Ok(response);
                 }
             }
@@ -10440,6 +14902,7 @@ impl AdapterRuntime {
                     &actor_user_id,
                     &thread_key,
                     user_text_final.as_deref(),
+                    self.idempotency_token_max_len,
                 )
                 .map_err(post_session_error)?
             } else {
@@ -10463,13 +14926,15 @@ impl AdapterRuntime {
                     Some(&runtime_device_id),
                     session_turn_state.session_id_for_commits,
                     committed_user_source,
+                    transcript_privacy_scope,
+                    user_text_part_seq,
                     user_text_partial.clone(),
                     user_text_final.clone(),
                     selene_text_partial.clone(),
                     selene_text_final.clone().or(Some(response_text.clone())),
                     true,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 append_stage7_runtime_response_evidence(
                     &mut store,
                     now,
@@ -10483,8 +14948,9 @@ impl AdapterRuntime {
                     false,
                     stage8_5c_candidate_decision.as_ref(),
                     stage8_fresh_memory_bridge.as_ref(),
+                    self.idempotency_token_max_len,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 finalize_session_turn_record(
                     &mut store,
                     now,
@@ -10497,8 +14963,9 @@ impl AdapterRuntime {
                     &runtime_execution_envelope.idempotency_key,
                     &self.runtime_node_id,
                     self.session_lease_ttl_ms,
+                    self.idempotency_token_max_len,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 let response = VoiceTurnAdapterResponse {
                     status: "ok".to_string(),
                     outcome: "FINAL".to_string(),
@@ -10527,6 +14994,10 @@ impl AdapterRuntime {
                     deep_research: None,
                     screen_lifecycle_action: None,
                     session_lifecycle_action: None,
+                    stt_mode: String::new(),
+                    keep_listening: false,
+                    next_move_kind: Some(VoiceTurnNextMove::Respond),
+                    outcome_kind: Some(VoiceTurnOutcome::Final),
                 };
                 cache_authoritative_turn_response(
                     &self.session_retry_cache,
@@ -10534,7 +15005,7 @@ impl AdapterRuntime {
                     &runtime_execution_envelope.idempotency_key,
                     &response,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 return Ok(response);
             }
             let h380_understanding_text = active_context_followup_rewrite
@@ -10550,7 +15021,7 @@ impl AdapterRuntime {
             });
             let h411_discourse_frame_before = self
                 .public_discourse_frame(&actor_user_id, &thread_key)
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
             let h384_explicit_deep_research = user_text_final
                 .as_deref()
                 .is_some_and(h384_explicit_deep_research_request);
@@ -10585,13 +15056,15 @@ impl AdapterRuntime {
                         Some(&runtime_device_id),
                         session_turn_state.session_id_for_commits,
                         committed_user_source,
+                        transcript_privacy_scope,
+                        user_text_part_seq,
                         user_text_partial.clone(),
                         user_text_final.clone(),
                         selene_text_partial.clone(),
                         selene_text_final.clone().or(Some(response_text.clone())),
                         false,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     finalize_session_turn_record(
                         &mut store,
                         now,
@@ -10604,8 +15077,9 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &self.runtime_node_id,
                         self.session_lease_ttl_ms,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     let response = VoiceTurnAdapterResponse {
                         status: "ok".to_string(),
                         outcome: "FINAL".to_string(),
@@ -10638,6 +15112,10 @@ impl AdapterRuntime {
                         deep_research: None,
                         screen_lifecycle_action: None,
                         session_lifecycle_action: None,
+                        stt_mode: String::new(),
+                        keep_listening: false,
+                        next_move_kind: Some(VoiceTurnNextMove::Respond),
+                        outcome_kind: Some(VoiceTurnOutcome::Final),
                     };
                     cache_authoritative_turn_response(
                         &self.session_retry_cache,
@@ -10645,7 +15123,7 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &response,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     return Ok(response);
                 }
                 if h406_public_non_actionable_declarative_statement(captured_text) {
@@ -10660,13 +15138,15 @@ impl AdapterRuntime {
                         Some(&runtime_device_id),
                         session_turn_state.session_id_for_commits,
                         committed_user_source,
+                        transcript_privacy_scope,
+                        user_text_part_seq,
                         user_text_partial.clone(),
                         user_text_final.clone(),
                         selene_text_partial.clone(),
                         selene_text_final.clone().or(Some(response_text.clone())),
                         true,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     append_stage7_runtime_response_evidence(
                         &mut store,
                         now,
@@ -10680,8 +15160,9 @@ impl AdapterRuntime {
                         false,
                         stage8_5c_candidate_decision.as_ref(),
                         stage8_fresh_memory_bridge.as_ref(),
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     finalize_session_turn_record(
                         &mut store,
                         now,
@@ -10694,8 +15175,9 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &self.runtime_node_id,
                         self.session_lease_ttl_ms,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     let response = VoiceTurnAdapterResponse {
                         status: "ok".to_string(),
                         outcome: "FINAL".to_string(),
@@ -10724,6 +15206,10 @@ impl AdapterRuntime {
                         deep_research: None,
                         screen_lifecycle_action: None,
                         session_lifecycle_action: None,
+                        stt_mode: String::new(),
+                        keep_listening: false,
+                        next_move_kind: Some(VoiceTurnNextMove::Respond),
+                        outcome_kind: Some(VoiceTurnOutcome::Final),
                     };
                     cache_authoritative_turn_response(
                         &self.session_retry_cache,
@@ -10731,7 +15217,7 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &response,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     return Ok(response);
                 }
             }
@@ -10762,6 +15248,7 @@ impl AdapterRuntime {
                     );
                     persist_ph1x_thread_state(
                         &mut store,
+                        &self.ph1x_thread_state_cache,
                         now,
                         PersistPh1xThreadStateInput {
                             actor_user_id: &actor_user_id,
@@ -10771,8 +15258,9 @@ impl AdapterRuntime {
                             correlation_id,
                             turn_id,
                         },
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     self.record_transcript_updates(
                         &mut store,
                         now,
@@ -10782,13 +15270,15 @@ impl AdapterRuntime {
                         Some(&runtime_device_id),
                         session_turn_state.session_id_for_commits,
                         committed_user_source,
+                        transcript_privacy_scope,
+                        user_text_part_seq,
                         user_text_partial.clone(),
                         user_text_final.clone(),
                         selene_text_partial.clone(),
                         selene_text_final.clone().or(Some(response_text.clone())),
                         true,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     append_stage7_runtime_response_evidence(
                         &mut store,
                         now,
@@ -10802,8 +15292,9 @@ impl AdapterRuntime {
                         protected_fail_closed,
                         stage8_5c_candidate_decision.as_ref(),
                         stage8_fresh_memory_bridge.as_ref(),
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     finalize_session_turn_record(
                         &mut store,
                         now,
@@ -10816,8 +15307,9 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &self.runtime_node_id,
                         self.session_lease_ttl_ms,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     let response = VoiceTurnAdapterResponse {
                         status: "ok".to_string(),
                         outcome: "FINAL".to_string(),
@@ -10846,6 +15338,10 @@ impl AdapterRuntime {
                         deep_research: None,
                         screen_lifecycle_action: None,
                         session_lifecycle_action: None,
+                        stt_mode: String::new(),
+                        keep_listening: false,
+                        next_move_kind: Some(VoiceTurnNextMove::Respond),
+                        outcome_kind: Some(VoiceTurnOutcome::Final),
                     };
                     let h411_discourse_frame_after = self
                         .record_public_discourse_turn(
@@ -10855,7 +15351,7 @@ impl AdapterRuntime {
                             &response.response_text,
                             Some("PUBLIC_DISCOURSE"),
                         )
-                        .map_err(post_session_error)?;
+                        .map_err(post_session_failure)?;
                     if let Some(trace) = h410_build_public_brain_trace(
                         &request_for_journal,
                         user_text_final.as_deref(),
@@ -10866,7 +15362,7 @@ impl AdapterRuntime {
                         Some(&h411_discourse_frame_after),
                     ) {
                         self.record_public_brain_trace(trace)
-                            .map_err(post_session_error)?;
+                            .map_err(post_session_failure)?;
                     }
                     return Ok(response);
                 }
@@ -10899,6 +15395,7 @@ impl AdapterRuntime {
                     );
                     persist_ph1x_thread_state(
                         &mut store,
+                        &self.ph1x_thread_state_cache,
                         now,
                         PersistPh1xThreadStateInput {
                             actor_user_id: &actor_user_id,
@@ -10908,8 +15405,9 @@ impl AdapterRuntime {
                             correlation_id,
                             turn_id,
                         },
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     self.record_transcript_updates(
                         &mut store,
                         now,
@@ -10919,13 +15417,15 @@ impl AdapterRuntime {
                         Some(&runtime_device_id),
                         session_turn_state.session_id_for_commits,
                         committed_user_source,
+                        transcript_privacy_scope,
+                        user_text_part_seq,
                         user_text_partial.clone(),
                         user_text_final.clone(),
                         selene_text_partial.clone(),
                         selene_text_final.clone().or(Some(response_text.clone())),
                         true,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     append_stage7_runtime_response_evidence(
                         &mut store,
                         now,
@@ -10939,8 +15439,9 @@ impl AdapterRuntime {
                         false,
                         stage8_5c_candidate_decision.as_ref(),
                         stage8_fresh_memory_bridge.as_ref(),
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     finalize_session_turn_record(
                         &mut store,
                         now,
@@ -10953,8 +15454,9 @@ impl AdapterRuntime {
                         &runtime_execution_envelope.idempotency_key,
                         &self.runtime_node_id,
                         self.session_lease_ttl_ms,
+                        self.idempotency_token_max_len,
                     )
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
                     let response = VoiceTurnAdapterResponse {
                         status: "ok".to_string(),
                         outcome: "FINAL".to_string(),
@@ -10983,6 +15485,10 @@ impl AdapterRuntime {
                         deep_research: None,
                         screen_lifecycle_action: None,
                         session_lifecycle_action: None,
+                        stt_mode: String::new(),
+                        keep_listening: false,
+                        next_move_kind: Some(VoiceTurnNextMove::Respond),
+                        outcome_kind: Some(VoiceTurnOutcome::Final),
                     };
                     if let Some(trace) = h410_build_public_brain_trace(
                         &request_for_journal,
@@ -10994,7 +15500,7 @@ impl AdapterRuntime {
                         Some(&h411_discourse_frame_before),
                     ) {
                         self.record_public_brain_trace(trace)
-                            .map_err(post_session_error)?;
+                            .map_err(post_session_failure)?;
                     }
                     return Ok(response);
                 }
@@ -11017,24 +15523,39 @@ impl AdapterRuntime {
                     )
                 })
                 .or_else(|| user_text_final.clone());
-            let (nlp_output, language_packet) = build_nlp_output_for_voice_turn(
-                &request,
-                nlp_transcript_text.as_deref(),
-                tenant_id_for_ph1c.as_deref(),
-                &base_thread_state,
-                committed_turn_followup.as_ref(),
-                if h384_explicit_deep_research {
-                    None
-                } else {
-                    h380_understanding.as_ref()
-                },
-                h411_discourse_frame_before
-                    .answer_language_preference
-                    .as_deref(),
-            )
-            .map_err(post_session_error)?;
-            let confirm_answer =
-                infer_confirm_answer_from_user_text(&base_thread_state, user_text_final.as_deref());
+            let (nlp_output, language_packet, nlp_pass_through_reason) =
+                build_nlp_output_for_voice_turn(
+                    &request,
+                    nlp_transcript_text.as_deref(),
+                    tenant_id_for_ph1c.as_deref(),
+                    &base_thread_state,
+                    committed_turn_followup.as_ref(),
+                    if h384_explicit_deep_research {
+                        None
+                    } else {
+                        h380_understanding.as_ref()
+                    },
+                    h411_discourse_frame_before
+                        .answer_language_preference
+                        .as_deref(),
+                    self.ph1n_runtime.0.as_ref(),
+                    self.nlp_failure_strict,
+                )
+                .map_err(post_session_error)?;
+            if let Some(reason) = nlp_pass_through_reason.as_deref() {
+                tracing::warn!("ph1n pass-through fallback: {reason}");
+                self.emit_ph1n_pass_through_feedback(
+                    &mut store,
+                    now,
+                    correlation_id,
+                    turn_id,
+                    tenant_id_for_ph1c.as_deref(),
+                    &actor_user_id,
+                    &runtime_device_id,
+                    session_turn_state.session_id_for_commits,
+                )
+                .map_err(post_session_failure)?;
+            }
             let locale = language_packet
                 .as_ref()
                 .map(|packet| packet.output_language_selected.clone())
@@ -11046,26 +15567,86 @@ impl AdapterRuntime {
                         .map(|raw| truncate_ascii(raw.trim(), 16))
                         .filter(|value| !value.is_empty())
                 });
+            let confirm_answer = infer_confirm_answer_from_user_text(
+                &base_thread_state,
+                user_text_final.as_deref(),
+                locale.as_deref(),
+            );
+            if confirm_answer == Some(ConfirmAnswer::No)
+                && matches!(
+                    base_thread_state.pending,
+                    Some(PendingState::MemoryPermission { .. })
+                )
+            {
+                self.emit_memory_permission_declined_feedback(
+                    &mut store,
+                    now,
+                    correlation_id,
+                    turn_id,
+                    tenant_id_for_ph1c.as_deref(),
+                    &actor_user_id,
+                    &runtime_device_id,
+                    session_turn_state.session_id_for_commits,
+                )
+                .map_err(post_session_failure)?;
+            }
             let post_turn_base_thread_state = base_thread_state.clone();
+            let tool_response = pre_fetched_tool_response.filter(|tool_response| {
+                matches!(
+                    base_thread_state.pending,
+                    Some(PendingState::Tool { request_id, .. }) if request_id == tool_response.request_id
+                )
+            });
+            let thread_privacy_mode = base_thread_state
+                .thread_policy_flags
+                .is_some_and(|flags| flags.force_privacy_mode);
+            let memory_candidates = load_ph1x_memory_candidates(
+                &store,
+                &actor_user_id,
+                now,
+                self.ph1x_memory_candidate_limit,
+                thread_privacy_mode,
+            );
             let x_build = AppVoicePh1xBuildInput {
                 now,
                 thread_key: Some(thread_key.clone()),
                 thread_state: base_thread_state,
                 session_state: session_turn_state.session_snapshot.session_state,
                 policy_context_ref: PolicyContextRef::v1(false, false, SafetyTier::Standard),
-                memory_candidates: Vec::new(),
+                memory_candidates,
                 confirm_answer,
                 nlp_output: Some(nlp_output.clone()),
-                tool_response: None,
-                interruption: None,
+                tool_response,
+                interruption: if self.ph1x_interruption_enabled {
+                    ph1x_interruption_from_ph1k_bundle(&ph1k_bundle)
+                } else {
+                    None
+                },
                 locale,
                 language_packet: language_packet.clone(),
-                last_failure_reason_code: None,
+                last_failure_reason_code: self
+                    .take_ph1x_last_failure(actor_user_id.as_str(), &thread_key),
             };
+            if cancel.is_some_and(CancellationFlag::is_cancelled) {
+                self.restore_cancelled_turn_store(&mut store, cancel_checkpoint);
+                return Err(voice_turn_cancelled_error(response_turn_id));
+            }
+            let ingress_stage = voice_turn_stage_span!(
+                "ingress",
+                request.correlation_id,
+                request.turn_id,
+                tenant_id_for_ph1c.as_deref()
+            )
+            .entered();
             let mut execution_outcome = self
                 .ingress
                 .run_voice_turn_end_to_end(&mut store, ingress_request, x_build)
-                .map_err(|err| post_session_error(storage_error_to_string(err)))?;
+                .map_err(|err| post_session_failure(AdapterError::Storage(err)))?;
+            tracing::debug!(
+                tool_response = execution_outcome.tool_response.is_some(),
+                "ingress end-to-end run finished"
+            );
+            drop(ingress_stage);
             apply_language_continuity_to_execution_outcome(
                 language_packet.as_ref(),
                 user_text_final.as_deref(),
@@ -11080,21 +15661,41 @@ impl AdapterRuntime {
                 user_text_final.as_deref(),
                 &mut execution_outcome,
             );
-            self.commit_ph1d_runtime_outcome(
-                &mut store,
-                now,
-                correlation_id,
-                turn_id,
-                &actor_user_id,
-                tenant_id_for_ph1c.as_deref(),
-                Some(&runtime_device_id),
-                session_turn_state.session_id_for_commits,
-                session_turn_state.session_snapshot.session_state,
-                user_text_final.as_deref(),
-                &execution_outcome.voice_outcome,
-                language_packet.as_ref(),
+            let ph1d_stage = voice_turn_stage_span!(
+                "ph1d_commit",
+                request.correlation_id,
+                request.turn_id,
+                tenant_id_for_ph1c.as_deref()
             )
-            .map_err(post_session_error)?;
+            .entered();
+            let ph1d_analysis = self
+                .commit_ph1d_runtime_outcome(
+                    &mut store,
+                    now,
+                    correlation_id,
+                    turn_id,
+                    &actor_user_id,
+                    tenant_id_for_ph1c.as_deref(),
+                    Some(&runtime_device_id),
+                    session_turn_state.session_id_for_commits,
+                    session_turn_state.session_snapshot.session_state,
+                    user_text_final.as_deref(),
+                    &execution_outcome.voice_outcome,
+                    language_packet.as_ref(),
+                )
+                .map_err(post_session_failure)?;
+            tracing::debug!(
+                analysis = ph1d_analysis.is_some(),
+                "ph1d runtime outcome committed"
+            );
+            drop(ph1d_stage);
+            if let Some(analysis) = ph1d_analysis.as_ref() {
+                execution_outcome.response_text = response_text_with_ph1d_analysis(
+                    execution_outcome.response_text.take(),
+                    analysis,
+                    language_packet.as_ref(),
+                );
+            }
             let mut ph1d_public_answer_text =
                 if persistence_mode == PersistenceInvocationMode::LegacyJournalReplay {
                     None
@@ -11113,6 +15714,7 @@ impl AdapterRuntime {
                         ph1d_public_user_text,
                         &mut execution_outcome,
                         language_packet.as_ref(),
+                        model_override.as_deref(),
                     )
                 };
             apply_language_continuity_to_execution_outcome(
@@ -11162,6 +15764,7 @@ impl AdapterRuntime {
                 );
                 persist_ph1x_thread_state(
                     &mut store,
+                    &self.ph1x_thread_state_cache,
                     now,
                     PersistPh1xThreadStateInput {
                         actor_user_id: &actor_user_id,
@@ -11171,8 +15774,9 @@ impl AdapterRuntime {
                         correlation_id,
                         turn_id,
                     },
+                    self.idempotency_token_max_len,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 remember_latest_weather_place(
                     &self.weather_context_state,
                     &actor_user_id,
@@ -11181,6 +15785,13 @@ impl AdapterRuntime {
                 )
                 .map_err(post_session_error)?;
             }
+            let read_only_stage = voice_turn_stage_span!(
+                "read_only_incidents",
+                request.correlation_id,
+                request.turn_id,
+                tenant_id_for_ph1c.as_deref()
+            )
+            .entered();
             if let Err(err) = self.emit_read_only_lane_incidents_and_maybe_run_builder(
                 &mut store,
                 now,
@@ -11190,10 +15801,16 @@ impl AdapterRuntime {
                 tenant_id_for_ph1c.as_deref(),
                 &runtime_device_id,
                 user_text_final.as_deref(),
+                request
+                    .audio_capture_ref
+                    .as_ref()
+                    .and_then(|capture| capture.locale_tag.as_deref()),
+                thread_privacy_mode,
                 &execution_outcome,
             ) {
-                eprintln!("selene_adapter read-only incident emission failed: {err}");
+                tracing::error!("read-only incident emission failed: {err}");
             }
+            drop(read_only_stage);
             let h410_captured_final_for_trace = user_text_final.clone();
             let update_recent_archive_digest = recent_archive_digest_should_update_for_runtime_turn(
                 user_text_final.as_deref(),
@@ -11238,13 +15855,15 @@ impl AdapterRuntime {
                 Some(&runtime_device_id),
                 session_turn_state.session_id_for_commits,
                 committed_user_source,
+                transcript_privacy_scope,
+                user_text_part_seq,
                 user_text_partial,
                 user_text_final,
                 selene_text_partial,
                 selene_text_final_for_transcript,
                 update_recent_archive_digest,
             )
-            .map_err(post_session_error)?;
+            .map_err(post_session_failure)?;
             append_stage7_runtime_response_evidence(
                 &mut store,
                 now,
@@ -11258,8 +15877,9 @@ impl AdapterRuntime {
                 protected_fail_closed_for_stage7,
                 stage8_5c_candidate_decision.as_ref(),
                 stage8_fresh_memory_bridge.as_ref(),
+                self.idempotency_token_max_len,
             )
-            .map_err(post_session_error)?;
+            .map_err(post_session_failure)?;
             if let Some(ph1c) = ph1c_live_outcome.as_ref() {
                 self.emit_ph1c_gold_capture_and_learning(
                     &mut store,
@@ -11272,7 +15892,7 @@ impl AdapterRuntime {
                     session_turn_state.session_id_for_commits,
                     ph1c,
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
                 self.emit_ph1d_gold_capture_and_learning(
                     &mut store,
                     now,
@@ -11286,7 +15906,15 @@ impl AdapterRuntime {
                     ph1c.final_text.clone(),
                     ph1c_language_locale(&ph1c.response),
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
+                if let Some(locale) = ph1c_language_locale(&ph1c.response) {
+                    self.set_ph1c_detected_locale(&runtime_device_id, locale)
+                        .map_err(post_session_failure)?;
+                }
+                for provider_call in &ph1c.provider_call_trace {
+                    self.record_provider_latency_ms(provider_call.provider_latency_ms)
+                        .map_err(post_session_failure)?;
+                }
                 self.emit_ph1c_live_telemetry(
                     &mut store,
                     now,
@@ -11295,7 +15923,7 @@ impl AdapterRuntime {
                     ph1c,
                     tenant_id_for_ph1c.as_deref(),
                 )
-                .map_err(post_session_error)?;
+                .map_err(post_session_failure)?;
             }
             let session_project_context_source = execution_outcome
                 .ph1x_response
@@ -11317,8 +15945,9 @@ impl AdapterRuntime {
                 &runtime_execution_envelope.idempotency_key,
                 &self.runtime_node_id,
                 self.session_lease_ttl_ms,
+                self.idempotency_token_max_len,
             )
-            .map_err(post_session_error)?;
+            .map_err(post_session_failure)?;
             let h410_response_text_for_trace =
                 execution_outcome.response_text.clone().unwrap_or_default();
             let h411_discourse_frame_after =
@@ -11330,7 +15959,7 @@ impl AdapterRuntime {
                         &h410_response_text_for_trace,
                         Some(outcome_label(&execution_outcome)),
                     )
-                    .map_err(post_session_error)?
+                    .map_err(post_session_failure)?
                 } else {
                     h411_discourse_frame_before.clone()
                 };
@@ -11343,10 +15972,20 @@ impl AdapterRuntime {
                 &h410_response_text_for_trace,
                 Some(&h411_discourse_frame_after),
             );
-            let response = execution_outcome_to_adapter_response(execution_outcome);
+            self.emit_voice_refusal_telemetry(
+                now,
+                correlation_id,
+                turn_id,
+                &execution_outcome.voice_outcome,
+            );
+            let response = execution_outcome_to_adapter_response(
+                execution_outcome,
+                &self.refusal_messages,
+                &self.voice_disabled_message,
+            );
             if let Some(trace) = h410_trace {
                 self.record_public_brain_trace(trace)
-                    .map_err(post_session_error)?;
+                    .map_err(post_session_failure)?;
             }
             if let Some(proof_log) = build1c_language_audit_proof_log(
                 language_packet.as_ref(),
@@ -11354,7 +15993,7 @@ impl AdapterRuntime {
                 Some(response.response_text.as_str()),
                 false,
             ) {
-                eprintln!("selene_adapter build1c_language_audit_proof {proof_log}");
+                tracing::info!("build1c_language_audit_proof {proof_log}");
             }
             cache_authoritative_turn_response(
                 &self.session_retry_cache,
@@ -11362,13 +16001,38 @@ impl AdapterRuntime {
                 &runtime_execution_envelope.idempotency_key,
                 &response,
             )
-            .map_err(post_session_error)?;
-            if persist_on_success {
-                self.append_legacy_journal_entry(request_for_journal.clone())
-                    .map_err(post_session_error)?;
+            .map_err(post_session_failure)?;
+            match journal_write {
+                LegacyJournalWrite::Skip => {}
+                LegacyJournalWrite::Immediate => self
+                    .append_legacy_journal_entry(request_for_journal.clone())
+                    .map_err(post_session_failure)?,
+                LegacyJournalWrite::Deferred(batch) => {
+                    if let Some(line) = self
+                        .encode_legacy_journal_line(request_for_journal.clone())
+                        .map_err(post_session_failure)?
+                    {
+                        batch
+                            .journal_lines
+                            .lock()
+                            .map_err(|_| {
+                                post_session_failure(AdapterError::LockPoisoned(
+                                    "batch journal".to_string(),
+                                ))
+                            })?
+                            .push(line);
+                    }
+                }
             }
             Ok(response)
         })();
+        let execution_result = execution_result.map(|mut response| {
+            if let Some(stt_mode) = stt_mode_for_response {
+                response.stt_mode = stt_mode.as_str().to_string();
+            }
+            apply_provenance_mode(&mut response, provenance_mode);
+            response
+        });
 
         if let Some(prepared) = prepared_persistence.as_ref() {
             self.finalize_persistence_operation(
@@ -11378,7 +16042,7 @@ impl AdapterRuntime {
                 &runtime_execution_envelope.idempotency_key,
                 &execution_result,
             )
-            .map_err(pre_session_error)?;
+            .map_err(pre_session_failure)?;
         }
 
         if let Ok(response) = &execution_result {
@@ -11393,10 +16057,9 @@ impl AdapterRuntime {
             {
                 let session_id =
                     adapter_response_session_id(response).map_err(pre_session_error)?;
-                let mut store = self
-                    .store
-                    .lock()
-                    .map_err(|_| pre_session_error("adapter store lock poisoned".to_string()))?;
+                let mut store = self.lock_turn_store(&mut held_store).map_err(|_| {
+                    pre_session_failure(AdapterError::LockPoisoned("store".to_string()))
+                })?;
                 append_stage7_runtime_response_evidence(
                     &mut store,
                     now,
@@ -11410,12 +16073,38 @@ impl AdapterRuntime {
                     protected_fail_closed,
                     stage8_5c_candidate_decision_for_fallback.as_ref(),
                     stage8_fresh_memory_bridge_for_fallback.as_ref(),
+                    self.idempotency_token_max_len,
                 )
-                .map_err(pre_session_error)?;
+                .map_err(pre_session_failure)?;
             }
             if persistence_mode != PersistenceInvocationMode::LegacyJournalReplay {
-                self.sync_internal_history_evidence_to_persistence()
-                    .map_err(pre_session_error)?;
+                self.sync_internal_history_evidence_for_turn(&mut held_store)
+                    .map_err(pre_session_failure)?;
+                let replay_entry = AdapterTurnReplayEntry {
+                    actor_user_id: actor_user_id.clone(),
+                    request_fingerprint: replay_fingerprint,
+                    response: response.clone(),
+                };
+                match journal_write {
+                    LegacyJournalWrite::Deferred(batch) => batch
+                        .replay_entries
+                        .lock()
+                        .map_err(|_| {
+                            pre_session_failure(AdapterError::LockPoisoned(
+                                "batch replay".to_string(),
+                            ))
+                        })?
+                        .push(((correlation_id, turn_id), replay_entry)),
+                    LegacyJournalWrite::Skip | LegacyJournalWrite::Immediate => self
+                        .turn_replay_cache
+                        .lock()
+                        .map_err(|_| {
+                            pre_session_failure(AdapterError::LockPoisoned(
+                                "turn replay cache".to_string(),
+                            ))
+                        })?
+                        .insert((correlation_id, turn_id), replay_entry),
+                }
             }
         }
 
@@ -11427,7 +16116,7 @@ impl AdapterRuntime {
         now: MonotonicTimeNs,
         actor_user_id: &UserId,
         idempotency_key: &str,
-    ) -> Result<Option<Result<VoiceTurnAdapterResponse, VoiceTurnIngressError>>, String> {
+    ) -> Result<Option<Result<VoiceTurnAdapterResponse, VoiceTurnIngressError>>, AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(None);
         };
@@ -11436,7 +16125,7 @@ impl AdapterRuntime {
         let mut guard = persistence
             .state
             .lock()
-            .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
         validate_persistence_state_integrity(&guard).map_err(|err| {
             format!(
                 "persistence state integrity check failed before authoritative outcome reuse: {err}"
@@ -11528,7 +16217,7 @@ impl AdapterRuntime {
         runtime_execution_envelope: &RuntimeExecutionEnvelope,
         now: MonotonicTimeNs,
         persistence_mode: PersistenceInvocationMode,
-    ) -> Result<Option<PreparedPersistenceOperation>, String> {
+    ) -> Result<Option<PreparedPersistenceOperation>, AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(None);
         };
@@ -11542,7 +16231,7 @@ impl AdapterRuntime {
         let mut guard = persistence
             .state
             .lock()
-            .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
         validate_persistence_state_integrity(&guard).map_err(|err| {
             format!("persistence state integrity check failed before prepare: {err}")
         })?;
@@ -11617,7 +16306,9 @@ impl AdapterRuntime {
                             "persistence_quarantine_required outbox_request_mismatch",
                             Some("outbox request mismatch quarantined local state".to_string()),
                         );
-                        return Err(governance_runtime_reason(&decision));
+                        return Err(AdapterError::Validation(governance_runtime_reason(
+                            &decision,
+                        )));
                     }
                     append_persistence_operation_journal_locked(
                         &mut guard,
@@ -11777,7 +16468,9 @@ impl AdapterRuntime {
                         "persistence_quarantine_required replay_request_mismatch",
                         Some("replay request mismatch quarantined local persistence".to_string()),
                     );
-                    return Err(governance_runtime_reason(&decision));
+                    return Err(AdapterError::Validation(governance_runtime_reason(
+                        &decision,
+                    )));
                 }
                 {
                     let existing = guard
@@ -11887,14 +16580,14 @@ impl AdapterRuntime {
         runtime_device_id: &DeviceId,
         idempotency_key: &str,
         result: &Result<VoiceTurnAdapterResponse, VoiceTurnIngressError>,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(());
         };
         let mut guard = persistence
             .state
             .lock()
-            .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
         validate_persistence_state_integrity(&guard).map_err(|err| {
             format!("persistence state integrity check failed before finalize: {err}")
         })?;
@@ -12113,11 +16806,11 @@ impl AdapterRuntime {
     fn sync_authoritative_outcomes_into_retry_cache(
         &self,
         state: &AdapterPersistenceState,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let mut cache = self
             .session_retry_cache
             .lock()
-            .map_err(|_| "adapter retry cache lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("retry cache".to_string()))?;
         cache.clear();
         for outcome in state.authoritative_outcomes.values() {
             if let AdapterPersistedAuthoritativeResult::Success(response) = &outcome.result {
@@ -12134,7 +16827,7 @@ impl AdapterRuntime {
         Ok(())
     }
 
-    fn reconcile_pending_outbox_records(&self) -> Result<(), String> {
+    fn reconcile_pending_outbox_records(&self) -> Result<(), AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(());
         };
@@ -12142,7 +16835,7 @@ impl AdapterRuntime {
             let mut guard = persistence
                 .state
                 .lock()
-                .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+                .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
             validate_persistence_state_integrity(&guard).map_err(|err| {
                 format!(
                     "persistence state integrity check failed before reconciliation replay: {err}"
@@ -12166,7 +16859,7 @@ impl AdapterRuntime {
                 let mut guard = persistence
                     .state
                     .lock()
-                    .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+                    .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
                 let Some(record) = guard.outbox_records.get(&operation_id).cloned() else {
                     continue;
                 };
@@ -12292,15 +16985,18 @@ impl AdapterRuntime {
             let _ = self.run_voice_turn_internal(
                 record.request,
                 None,
-                true,
+                LegacyJournalWrite::Immediate,
                 true,
                 PersistenceInvocationMode::ExistingOutboxReplay,
+                None,
+                None,
+                None,
             );
         }
         let mut guard = persistence
             .state
             .lock()
-            .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
         guard.last_reconciled_at_ns = Some(1);
         let next_recovery_mode = recompute_persistence_recovery_mode_locked(&guard);
         set_persistence_recovery_mode_locked(
@@ -12313,7 +17009,7 @@ impl AdapterRuntime {
         self.save_persistence_state_to_disk_locked(&guard)
     }
 
-    pub fn default_from_env() -> Result<Self, String> {
+    pub fn default_from_env() -> Result<Self, AdapterError> {
         let mut executor = SimulationExecutor::default();
         if let Some(global_profiles) =
             build_embedding_gate_profiles_from_env_var_map(|key| env::var(key).ok())?
@@ -12324,6 +17020,7 @@ impl AdapterRuntime {
                     tenant_overrides: BTreeMap::new(),
                 },
                 contract_migration: VoiceIdContractMigrationConfig::mvp_default(),
+                require_reauth_on_untrusted_device: false,
             };
             executor.set_voice_id_live_config(config);
         }
@@ -12331,22 +17028,28 @@ impl AdapterRuntime {
         let mut store = Ph1fStore::new_in_memory();
         bootstrap_desktop_controlled_wake_profile_from_env(&mut store)?;
         let store = Arc::new(Mutex::new(store));
-        let journal_path = env::var("SELENE_ADAPTER_STORE_PATH")
-            .ok()
-            .map(|v| v.trim().to_string())
-            .filter(|v| !v.is_empty())
-            .map(PathBuf::from)
-            .unwrap_or_else(default_adapter_store_path);
-        let auto_builder_enabled = parse_auto_builder_enabled_from_env();
-
-        Self::new_with_persistence(ingress, store, journal_path, auto_builder_enabled)
+        // Unlike `AdapterConfig::from_env`, the env constructor refuses to start on a bad
+        // wake gate value.
+        let config = AdapterConfig {
+            wake_gate_policy: build_wake_gate_policy_from_env_var_map(|key| env::var(key).ok())?,
+            ..AdapterConfig::from_env()
+        };
+        Self::from_config_with_ingress(ingress, store, config)
     }
 
     fn ensure_persistence_ready(&self) -> Result<(), String> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(());
         };
+        let write_probe_enabled =
+            parse_bool_env("SELENE_ADAPTER_PERSISTENCE_WRITE_PROBE_ENABLED", true);
         for path in [&persistence.legacy_journal_path, &persistence.state_path] {
+            if path.is_dir() {
+                return Err(format!(
+                    "adapter persistence path '{}' is a directory; expected a file path",
+                    path.display()
+                ));
+            }
             if let Some(parent) = path.parent() {
                 fs::create_dir_all(parent).map_err(|err| {
                     format!(
@@ -12356,6 +17059,9 @@ impl AdapterRuntime {
                     )
                 })?;
             }
+            if write_probe_enabled {
+                probe_persistence_path_writable(path)?;
+            }
             if !path.exists() {
                 File::create(path).map_err(|err| {
                     format!(
@@ -12369,7 +17075,7 @@ impl AdapterRuntime {
         Ok(())
     }
 
-    fn bootstrap_persistence_runtime(&self) -> Result<(), String> {
+    fn bootstrap_persistence_runtime(&self) -> Result<(), AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(());
         };
@@ -12382,10 +17088,16 @@ impl AdapterRuntime {
                 Some("persistence bootstrap entered quarantined local state".to_string()),
             );
         }
+        let replay_enabled = parse_bool_env("SELENE_ADAPTER_LEGACY_JOURNAL_REPLAY_ENABLED", true);
+        let replay_from_offset = if replay_enabled {
+            self.load_store_snapshot_into_store()?
+        } else {
+            0
+        };
         self.restore_persisted_conversation_turns_into_store(&state)?;
         self.restore_persisted_internal_history_evidence_into_store(&state)?;
-        if parse_bool_env("SELENE_ADAPTER_LEGACY_JOURNAL_REPLAY_ENABLED", true) {
-            self.replay_legacy_journal_into_store(&mut state)?;
+        if replay_enabled {
+            self.replay_legacy_journal_into_store(&mut state, replay_from_offset)?;
         }
         self.merge_store_conversation_turns_into_state(&mut state)?;
         self.merge_store_internal_history_evidence_into_state(&mut state)?;
@@ -12393,7 +17105,7 @@ impl AdapterRuntime {
             let mut guard = persistence
                 .state
                 .lock()
-                .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+                .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
             *guard = state;
             self.sync_authoritative_outcomes_into_retry_cache(&guard)?;
             self.save_persistence_state_to_disk_locked(&guard)?;
@@ -12405,48 +17117,48 @@ impl AdapterRuntime {
     fn restore_persisted_conversation_turns_into_store(
         &self,
         state: &AdapterPersistenceState,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         if state.conversation_turn_records.is_empty() {
             return Ok(());
         }
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         store
             .replace_conversation_turn_records_from_replay(&state.conversation_turn_records)
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
         Ok(())
     }
 
     fn restore_persisted_internal_history_evidence_into_store(
         &self,
         state: &AdapterPersistenceState,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         if state.internal_history_evidence_records.is_empty() {
             return Ok(());
         }
         let mut store = self
             .store
             .lock()
-            .map_err(|_| "adapter store lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
         store
             .replace_internal_history_evidence_records_from_replay(
                 &state.internal_history_evidence_records,
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
         Ok(())
     }
 
     fn merge_store_conversation_turns_into_state(
         &self,
         state: &mut AdapterPersistenceState,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, AdapterError> {
         let records = {
             let store = self
                 .store
                 .lock()
-                .map_err(|_| "adapter store lock poisoned".to_string())?;
+                .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
             store.conversation_ledger().to_vec()
         };
         merge_conversation_turn_records_locked(state, records)
@@ -12455,18 +17167,69 @@ impl AdapterRuntime {
     fn merge_store_internal_history_evidence_into_state(
         &self,
         state: &mut AdapterPersistenceState,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, AdapterError> {
         let records = {
             let store = self
                 .store
                 .lock()
-                .map_err(|_| "adapter store lock poisoned".to_string())?;
+                .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
             store.internal_history_evidence_ledger().to_vec()
         };
         merge_internal_history_evidence_records_locked(state, records)
     }
 
-    fn sync_internal_history_evidence_to_persistence(&self) -> Result<(), String> {
+    // Puts redacted rows over their originals in the persisted state, whose append-only merge
+    // would otherwise refuse them as a conflict, and redacts the text of the user's settled
+    // outbox requests. A request still pending cloud acknowledgement keeps its text, since
+    // reconciliation replays it.
+    fn redact_persisted_user_text(
+        &self,
+        actor_user_id: &UserId,
+        redacted_rows: &[ConversationTurnRecord],
+    ) -> Result<(), AdapterError> {
+        let Some(persistence) = self.persistence.as_ref() else {
+            return Ok(());
+        };
+        let mut guard = persistence
+            .state
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
+        let mut changed = false;
+        for record in redacted_rows {
+            let index = record.conversation_turn_id.0.saturating_sub(1) as usize;
+            if let Some(existing) = guard.conversation_turn_records.get_mut(index) {
+                if existing.conversation_turn_id == record.conversation_turn_id
+                    && existing != record
+                {
+                    *existing = record.clone();
+                    changed = true;
+                }
+            }
+        }
+        for record in guard.outbox_records.values_mut().filter(|record| {
+            record.actor_user_id == actor_user_id.as_str()
+                && record.cleared_from_active_outbox_at_ns.is_some()
+        }) {
+            let request = &mut record.request;
+            for text in [
+                &mut request.user_text_partial,
+                &mut request.user_text_final,
+                &mut request.selene_text_partial,
+                &mut request.selene_text_final,
+            ] {
+                if text.as_deref().is_some_and(|text| text != "[REDACTED]") {
+                    *text = Some("[REDACTED]".to_string());
+                    changed = true;
+                }
+            }
+        }
+        if changed {
+            self.save_persistence_state_to_disk_locked(&guard)?;
+        }
+        Ok(())
+    }
+
+    fn sync_internal_history_evidence_to_persistence(&self) -> Result<(), AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(());
         };
@@ -12474,16 +17237,48 @@ impl AdapterRuntime {
             let store = self
                 .store
                 .lock()
-                .map_err(|_| "adapter store lock poisoned".to_string())?;
+                .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
             (
                 store.conversation_ledger().to_vec(),
                 store.internal_history_evidence_ledger().to_vec(),
             )
         };
+        self.merge_internal_history_evidence_into_persistence(
+            persistence,
+            conversation_records,
+            internal_history_records,
+        )
+    }
+
+    // Same as `sync_internal_history_evidence_to_persistence`, but reads the store a batch
+    // already holds instead of locking it again.
+    fn sync_internal_history_evidence_for_turn(
+        &self,
+        held_store: &mut Option<&mut Ph1fStore>,
+    ) -> Result<(), AdapterError> {
+        let Some(store) = held_store.as_deref() else {
+            return self.sync_internal_history_evidence_to_persistence();
+        };
+        let Some(persistence) = self.persistence.as_ref() else {
+            return Ok(());
+        };
+        self.merge_internal_history_evidence_into_persistence(
+            persistence,
+            store.conversation_ledger().to_vec(),
+            store.internal_history_evidence_ledger().to_vec(),
+        )
+    }
+
+    fn merge_internal_history_evidence_into_persistence(
+        &self,
+        persistence: &AdapterPersistenceRuntime,
+        conversation_records: Vec<ConversationTurnRecord>,
+        internal_history_records: Vec<InternalHistoryEvidenceRecord>,
+    ) -> Result<(), AdapterError> {
         let mut guard = persistence
             .state
             .lock()
-            .map_err(|_| "adapter persistence state lock poisoned".to_string())?;
+            .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
         validate_persistence_state_integrity(&guard).map_err(|err| {
             format!("persistence state integrity check failed before evidence sync: {err}")
         })?;
@@ -12581,126 +17376,242 @@ impl AdapterRuntime {
     fn save_persistence_state_to_disk_locked(
         &self,
         state: &AdapterPersistenceState,
-    ) -> Result<(), String> {
+    ) -> Result<(), AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(());
         };
         validate_persistence_state_integrity(state).map_err(|err| {
-            format!("refusing to persist adapter state with integrity violation: {err}")
+            AdapterError::Persistence(format!(
+                "refusing to persist adapter state with integrity violation: {err}"
+            ))
+        })?;
+        let json = serde_json::to_vec_pretty(state).map_err(|err| {
+            AdapterError::Persistence(format!("failed encoding adapter persistence state: {err}"))
         })?;
-        let json = serde_json::to_vec_pretty(state)
-            .map_err(|err| format!("failed encoding adapter persistence state: {err}"))?;
         let tmp_path = persistence.state_path.with_extension("json.tmp");
         let mut file = File::create(&tmp_path).map_err(|err| {
-            format!(
+            AdapterError::Persistence(format!(
                 "failed creating adapter persistence temp file '{}': {}",
                 tmp_path.display(),
                 err
-            )
+            ))
         })?;
         file.write_all(&json)
             .and_then(|_| file.sync_data())
             .map_err(|err| {
-                format!(
+                AdapterError::Persistence(format!(
                     "failed writing adapter persistence temp file '{}': {}",
                     tmp_path.display(),
                     err
-                )
+                ))
             })?;
         fs::rename(&tmp_path, &persistence.state_path).map_err(|err| {
-            format!(
+            AdapterError::Persistence(format!(
                 "failed replacing adapter persistence state '{}': {}",
                 persistence.state_path.display(),
                 err
-            )
+            ))
         })?;
         Ok(())
     }
 
+    // Returns the journal offset replay starts from: the length a loaded snapshot covers, or
+    // `0` for a full replay when the snapshot is missing, unreadable, from another schema, or
+    // no longer matches the journal (for example after `compact_journal`).
+    fn load_store_snapshot_into_store(&self) -> Result<u64, AdapterError> {
+        let Some(persistence) = self.persistence.as_ref() else {
+            return Ok(0);
+        };
+        let snapshot_path = adapter_store_snapshot_path(&persistence.legacy_journal_path);
+        let encoded = match fs::read(&snapshot_path) {
+            Ok(encoded) => encoded,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+            Err(err) => {
+                tracing::warn!(
+                    "store snapshot '{}' is unreadable; replaying the full journal: {err}",
+                    snapshot_path.display()
+                );
+                return Ok(0);
+            }
+        };
+        let snapshot = match serde_json::from_slice::<AdapterStoreSnapshot>(&encoded) {
+            Ok(snapshot) if snapshot.schema_version == ADAPTER_STORE_SNAPSHOT_SCHEMA_VERSION => {
+                snapshot
+            }
+            Ok(snapshot) => {
+                tracing::warn!(
+                    "store snapshot '{}' has unsupported schema_version={}; replaying the full journal",
+                    snapshot_path.display(),
+                    snapshot.schema_version
+                );
+                return Ok(0);
+            }
+            Err(err) => {
+                tracing::warn!(
+                    "store snapshot '{}' does not decode; replaying the full journal: {err}",
+                    snapshot_path.display()
+                );
+                return Ok(0);
+            }
+        };
+        {
+            let mut snapshot_seq = persistence
+                .store_snapshot_seq
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("store snapshot".to_string()))?;
+            *snapshot_seq = (*snapshot_seq).max(snapshot.snapshot_seq);
+        }
+        let journal = fs::read(&persistence.legacy_journal_path).map_err(|err| {
+            format!(
+                "failed to open adapter store journal '{}': {}",
+                persistence.legacy_journal_path.display(),
+                err
+            )
+        })?;
+        let covered_prefix = usize::try_from(snapshot.journal_len_bytes)
+            .ok()
+            .and_then(|len| journal.get(..len));
+        if covered_prefix.map(crc32_ieee) != Some(snapshot.journal_prefix_crc32) {
+            tracing::warn!(
+                "store snapshot {} no longer matches the journal; replaying the full journal",
+                snapshot.snapshot_seq
+            );
+            return Ok(0);
+        }
+        *self
+            .store
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store".to_string()))? = snapshot.store;
+        Ok(snapshot.journal_len_bytes)
+    }
+
     fn replay_legacy_journal_into_store(
         &self,
         state: &mut AdapterPersistenceState,
+        replay_from_offset: u64,
     ) -> Result<(), String> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(());
         };
-        let file = File::open(&persistence.legacy_journal_path).map_err(|err| {
+        let journal = fs::read(&persistence.legacy_journal_path).map_err(|err| {
             format!(
                 "failed to open adapter store journal '{}': {}",
                 persistence.legacy_journal_path.display(),
                 err
             )
         })?;
-        for (line_no, line_result) in BufReader::new(file).lines().enumerate() {
-            let line = line_result.map_err(|err| {
-                format!(
-                    "failed reading adapter store journal '{}' at line {}: {}",
-                    persistence.legacy_journal_path.display(),
-                    line_no + 1,
-                    err
-                )
-            })?;
-            if line.trim().is_empty() {
+        let mut lines = Vec::new();
+        let mut line_start = 0usize;
+        for raw in journal.split_inclusive(|byte| *byte == b'\n') {
+            lines.push((line_start, raw));
+            line_start += raw.len();
+        }
+        let terminal_line_no = lines
+            .iter()
+            .rposition(|(_, raw)| !raw.trim_ascii().is_empty());
+        // Decode the whole journal first so a corrupt middle line still fails boot, but keep only
+        // the newest entries when a replay cap is configured.
+        let mut pending = VecDeque::new();
+        let mut skipped_entries = 0usize;
+        let mut corrupt_terminal_line = None;
+        for (line_no, (line_offset, raw)) in lines.into_iter().enumerate() {
+            let line = match std::str::from_utf8(raw) {
+                Ok(line) => line.trim_end_matches(['\n', '\r']),
+                Err(err) => {
+                    if Some(line_no) == terminal_line_no {
+                        corrupt_terminal_line =
+                            Some((line_no, line_offset, format!("line is not utf-8: {err}")));
+                        break;
+                    }
+                    return Err(format!(
+                        "adapter store journal '{}' is corrupt at line {}: line is not utf-8: {err}",
+                        persistence.legacy_journal_path.display(),
+                        line_no + 1
+                    ));
+                }
+            };
+            if line.trim().is_empty() || (line_offset as u64) < replay_from_offset {
+                continue;
+            }
+            if let Ok(header) = serde_json::from_str::<AdapterJournalSnapshotHeader>(line) {
+                if header.schema_version != 1 {
+                    return Err(format!(
+                        "unsupported adapter store journal snapshot schema_version={} at line {}",
+                        header.schema_version,
+                        line_no + 1
+                    ));
+                }
                 continue;
             }
-            let entry: AdapterJournalEntry = match serde_json::from_str(&line) {
+            let entry = match AdapterJournalEntry::decode(line) {
                 Ok(entry) => entry,
+                Err(err) if Some(line_no) == terminal_line_no => {
+                    corrupt_terminal_line = Some((line_no, line_offset, err));
+                    break;
+                }
                 Err(err) => {
-                    state.recovery_mode = PersistenceRecoveryMode::QuarantinedLocalState;
-                    append_persistence_audit_locked(
-                        state,
-                        MonotonicTimeNs((line_no as u64).saturating_add(1)),
-                        AdapterPersistenceAuditDecision::QuarantineAction,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        None,
-                        Some(PersistenceConflictSeverity::QuarantineRequired),
-                        &self.runtime_node_id,
-                        Some(format!(
-                            "legacy journal replay quarantined at line {}: {err}",
-                            line_no + 1
-                        )),
-                    );
-                    let quarantine_path = quarantined_persistence_path(
-                        &persistence.legacy_journal_path,
-                        "legacy_journal_corrupt",
-                    );
-                    fs::rename(&persistence.legacy_journal_path, &quarantine_path).map_err(
-                        |rename_err| {
-                            format!(
-                                "failed quarantining legacy journal '{}' -> '{}': {}",
-                                persistence.legacy_journal_path.display(),
-                                quarantine_path.display(),
-                                rename_err
-                            )
-                        },
-                    )?;
-                    File::create(&persistence.legacy_journal_path).map_err(|create_err| {
-                        format!(
-                            "failed recreating legacy journal '{}': {}",
-                            persistence.legacy_journal_path.display(),
-                            create_err
-                        )
-                    })?;
-                    return Ok(());
+                    return Err(format!(
+                        "adapter store journal '{}' is corrupt at line {}: {err}",
+                        persistence.legacy_journal_path.display(),
+                        line_no + 1
+                    ));
                 }
             };
-            if entry.schema_version != 1 {
+            if !matches!(entry.schema_version, 1 | 2) {
                 return Err(format!(
                     "unsupported adapter store journal schema_version={} at line {}",
                     entry.schema_version,
                     line_no + 1
                 ));
             }
+            let request = migrate_request(entry).map_err(|err| {
+                format!(
+                    "adapter store journal '{}' cannot replay line {}: {err}",
+                    persistence.legacy_journal_path.display(),
+                    line_no + 1
+                )
+            })?;
+            pending.push_back((line_no, request));
+            if let Some(max_entries) = self.journal_replay_max_entries {
+                if pending.len() > max_entries {
+                    pending.pop_front();
+                    skipped_entries += 1;
+                }
+            }
+        }
+        if skipped_entries > 0 {
+            tracing::warn!(
+                "journal replay capped at {} entries; skipped {skipped_entries} older entries",
+                pending.len()
+            );
+            append_persistence_audit_locked(
+                state,
+                MonotonicTimeNs(1),
+                AdapterPersistenceAuditDecision::LegacyJournalReplay,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                &self.runtime_node_id,
+                Some(format!(
+                    "skipped {skipped_entries} older legacy journal entries (replay cap {})",
+                    pending.len()
+                )),
+            );
+        }
+        for (line_no, request) in pending {
             self.run_voice_turn_internal(
-                entry.request,
+                request,
                 None,
-                false,
+                LegacyJournalWrite::Skip,
                 true,
                 PersistenceInvocationMode::LegacyJournalReplay,
+                None,
+                None,
+                None,
             )
             .map_err(|err| {
                 format!(
@@ -12724,16 +17635,347 @@ impl AdapterRuntime {
                 Some(format!("replayed legacy journal line {}", line_no + 1)),
             );
         }
+        if let Some((line_no, line_offset, reason)) = corrupt_terminal_line {
+            return self.drop_corrupt_terminal_journal_line(state, line_no, line_offset, &reason);
+        }
+        Ok(())
+    }
+
+    // A crash mid-append leaves a half-written last line. Replay skips it and truncates the
+    // journal back to the last complete entry, so the next append does not land on the tail.
+    fn drop_corrupt_terminal_journal_line(
+        &self,
+        state: &mut AdapterPersistenceState,
+        line_no: usize,
+        line_offset: usize,
+        err: &str,
+    ) -> Result<(), String> {
+        let Some(persistence) = self.persistence.as_ref() else {
+            return Ok(());
+        };
+        OpenOptions::new()
+            .write(true)
+            .open(&persistence.legacy_journal_path)
+            .and_then(|file| file.set_len(line_offset as u64))
+            .map_err(|truncate_err| {
+                format!(
+                    "failed truncating corrupt tail of adapter store journal '{}': {}",
+                    persistence.legacy_journal_path.display(),
+                    truncate_err
+                )
+            })?;
+        tracing::error!(
+            "journal line {} is truncated or corrupt; skipped on replay: {err}",
+            line_no + 1
+        );
+        append_persistence_audit_locked(
+            state,
+            MonotonicTimeNs((line_no as u64).saturating_add(1)),
+            AdapterPersistenceAuditDecision::LegacyJournalReplay,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &self.runtime_node_id,
+            Some(format!(
+                "skipped corrupt terminal legacy journal line {}: {err}",
+                line_no + 1
+            )),
+        );
         Ok(())
     }
 
-    fn append_legacy_journal_entry(&self, request: VoiceTurnAdapterRequest) -> Result<(), String> {
+    /// Rewrites the legacy journal so each `(correlation_id, turn_id)` keeps only its latest
+    /// entry, in the slot of that turn's first entry, behind a snapshot header line. The new
+    /// journal is written to a temp file and renamed over the old one, so a crash leaves one
+    /// or the other. A journal with an undecodable line is left untouched. Entries from users
+    /// erased through `erase_user_transcript` are dropped.
+    pub fn compact_journal(&self) -> Result<CompactionReport, AdapterError> {
+        self.compact_journal_internal()
+            .map_err(AdapterError::persistence)
+    }
+
+    fn compact_journal_internal(&self) -> Result<CompactionReport, AdapterError> {
+        let Some(persistence) = self.persistence.as_ref() else {
+            return Ok(CompactionReport::default());
+        };
+        // Read from the Tombstone rows, which are persisted, so an erase made before a restart
+        // is still applied.
+        let erased_user_ids = {
+            let store = self
+                .store
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+            erased_conversation_user_ids(&store)
+        };
+        let _journal_guard = persistence
+            .journal_lock
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("journal".to_string()))?;
+        let journal = match fs::read_to_string(&persistence.legacy_journal_path) {
+            Ok(journal) => journal,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(CompactionReport::default());
+            }
+            Err(err) => {
+                return Err(AdapterError::Persistence(format!(
+                    "failed reading adapter store journal '{}' for compaction: {}",
+                    persistence.legacy_journal_path.display(),
+                    err
+                )));
+            }
+        };
+
+        let mut entries_before = 0u64;
+        let mut kept_lines: Vec<&str> = Vec::new();
+        let mut slot_by_turn: BTreeMap<(u64, u64), usize> = BTreeMap::new();
+        for (line_no, line) in journal.lines().enumerate() {
+            if line.trim().is_empty()
+                || serde_json::from_str::<AdapterJournalSnapshotHeader>(line).is_ok()
+            {
+                continue;
+            }
+            let entry: AdapterJournalEntry = serde_json::from_str(line).map_err(|err| {
+                format!(
+                    "refusing to compact adapter store journal '{}': line {} does not decode: {err}",
+                    persistence.legacy_journal_path.display(),
+                    line_no + 1
+                )
+            })?;
+            entries_before = entries_before.saturating_add(1);
+            if erased_user_ids.contains(&entry.request.actor_user_id) {
+                continue;
+            }
+            let turn_key = (entry.request.correlation_id, entry.request.turn_id);
+            match slot_by_turn.get(&turn_key) {
+                Some(slot) => kept_lines[*slot] = line,
+                None => {
+                    slot_by_turn.insert(turn_key, kept_lines.len());
+                    kept_lines.push(line);
+                }
+            }
+        }
+
+        let entries_after = kept_lines.len() as u64;
+        let header = serde_json::to_string(&AdapterJournalSnapshotHeader::v1(
+            system_time_now_ns(),
+            entries_after,
+        ))
+        .map_err(|err| format!("failed encoding adapter journal snapshot header: {err}"))?;
+        let mut compacted = String::with_capacity(journal.len());
+        compacted.push_str(&header);
+        compacted.push('\n');
+        for line in &kept_lines {
+            compacted.push_str(line);
+            compacted.push('\n');
+        }
+
+        let tmp_path = persistence.legacy_journal_path.with_extension("jsonl.tmp");
+        let mut file = File::create(&tmp_path).map_err(|err| {
+            format!(
+                "failed creating adapter journal compaction temp file '{}': {}",
+                tmp_path.display(),
+                err
+            )
+        })?;
+        file.write_all(compacted.as_bytes())
+            .and_then(|_| file.sync_data())
+            .map_err(|err| {
+                format!(
+                    "failed writing adapter journal compaction temp file '{}': {}",
+                    tmp_path.display(),
+                    err
+                )
+            })?;
+        fs::rename(&tmp_path, &persistence.legacy_journal_path).map_err(|err| {
+            format!(
+                "failed replacing adapter store journal '{}': {}",
+                persistence.legacy_journal_path.display(),
+                err
+            )
+        })?;
+        Ok(CompactionReport {
+            entries_before,
+            entries_after,
+            bytes_reclaimed: (journal.len() as u64).saturating_sub(compacted.len() as u64),
+        })
+    }
+
+    /// Writes the whole store to a snapshot file next to the journal, tagged with the next
+    /// `snapshot_seq` and the journal length it covers. At boot the snapshot is loaded and only
+    /// the journal entries appended after it are replayed. The file is written to a temp path
+    /// and renamed into place. Returns the new `snapshot_seq`, or `0` without a journal.
+    pub fn write_store_snapshot(&self) -> Result<u64, AdapterError> {
+        self.write_store_snapshot_internal()
+            .map_err(AdapterError::persistence)
+    }
+
+    fn write_store_snapshot_internal(&self) -> Result<u64, AdapterError> {
+        let Some(persistence) = self.persistence.as_ref() else {
+            return Ok(0);
+        };
+        let mut snapshot_seq = persistence
+            .store_snapshot_seq
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("store snapshot".to_string()))?;
+        // Both locks are held while the snapshot is cut, so the store copy and the journal
+        // prefix it covers agree. A turn that has written the store but not yet its journal line
+        // is replayed again on top of the snapshot; store writes are keyed by idempotency key,
+        // so the second apply changes nothing.
+        let (store, journal) = {
+            let store = self
+                .store
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("store".to_string()))?;
+            let _journal_guard = persistence
+                .journal_lock
+                .lock()
+                .map_err(|_| AdapterError::LockPoisoned("journal".to_string()))?;
+            let journal = match fs::read(&persistence.legacy_journal_path) {
+                Ok(journal) => journal,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+                Err(err) => {
+                    return Err(AdapterError::Persistence(format!(
+                        "failed reading adapter store journal '{}' for snapshot: {}",
+                        persistence.legacy_journal_path.display(),
+                        err
+                    )));
+                }
+            };
+            (store.clone(), journal)
+        };
+        let snapshot = AdapterStoreSnapshot {
+            schema_version: ADAPTER_STORE_SNAPSHOT_SCHEMA_VERSION,
+            snapshot_seq: snapshot_seq.saturating_add(1),
+            written_at_ns: system_time_now_ns(),
+            journal_len_bytes: journal.len() as u64,
+            journal_prefix_crc32: crc32_ieee(&journal),
+            store,
+        };
+        let encoded = serde_json::to_vec(&snapshot)
+            .map_err(|err| format!("failed encoding adapter store snapshot: {err}"))?;
+        let snapshot_path = adapter_store_snapshot_path(&persistence.legacy_journal_path);
+        let tmp_path = snapshot_path.with_extension("json.tmp");
+        let mut file = File::create(&tmp_path).map_err(|err| {
+            format!(
+                "failed creating adapter store snapshot temp file '{}': {}",
+                tmp_path.display(),
+                err
+            )
+        })?;
+        file.write_all(&encoded)
+            .and_then(|_| file.sync_data())
+            .map_err(|err| {
+                format!(
+                    "failed writing adapter store snapshot temp file '{}': {}",
+                    tmp_path.display(),
+                    err
+                )
+            })?;
+        fs::rename(&tmp_path, &snapshot_path).map_err(|err| {
+            format!(
+                "failed replacing adapter store snapshot '{}': {}",
+                snapshot_path.display(),
+                err
+            )
+        })?;
+        *snapshot_seq = snapshot.snapshot_seq;
+        persistence
+            .entries_since_store_snapshot
+            .store(0, Ordering::SeqCst);
+        Ok(snapshot.snapshot_seq)
+    }
+
+    // Runs once the turn has released the store lock. A failed periodic snapshot only makes the
+    // next boot replay more of the journal, so it is logged and the turn still succeeds.
+    fn write_periodic_store_snapshot_if_due(&self) {
+        let (Some(persistence), Some(every)) =
+            (self.persistence.as_ref(), self.store_snapshot_every_entries)
+        else {
+            return;
+        };
+        if persistence
+            .entries_since_store_snapshot
+            .load(Ordering::SeqCst)
+            < every as u64
+        {
+            return;
+        }
+        if let Err(err) = self.write_store_snapshot_internal() {
+            tracing::warn!("periodic store snapshot failed: {err}");
+        }
+    }
+
+    fn append_legacy_journal_entry(
+        &self,
+        request: VoiceTurnAdapterRequest,
+    ) -> Result<(), AdapterError> {
+        match self.encode_legacy_journal_line(request)? {
+            Some(line) => self.write_legacy_journal_lines(&[line]),
+            None => Ok(()),
+        }
+    }
+
+    // `None` when there is no journal or the entry is too large to journal even after trimming.
+    fn encode_legacy_journal_line(
+        &self,
+        request: VoiceTurnAdapterRequest,
+    ) -> Result<Option<String>, AdapterError> {
+        let Some(persistence) = self.persistence.as_ref() else {
+            return Ok(None);
+        };
+        let json = match encode_journal_entry_within_cap(request, self.journal_entry_max_bytes)? {
+            AdapterJournalEntryEncoding::Full(json) => json,
+            AdapterJournalEntryEncoding::Trimmed(json) => {
+                let mut state = persistence
+                    .state
+                    .lock()
+                    .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
+                state.legacy_journal_trimmed_entries =
+                    state.legacy_journal_trimmed_entries.saturating_add(1);
+                tracing::warn!(
+                    "journal entry over {} bytes; stored trimmed replay form",
+                    self.journal_entry_max_bytes
+                );
+                json
+            }
+            AdapterJournalEntryEncoding::Oversized(bytes) => {
+                let mut state = persistence
+                    .state
+                    .lock()
+                    .map_err(|_| AdapterError::LockPoisoned("persistence state".to_string()))?;
+                state.legacy_journal_dropped_entries =
+                    state.legacy_journal_dropped_entries.saturating_add(1);
+                tracing::warn!(
+                    "journal entry still {bytes} bytes after trimming (cap {}); not journaled",
+                    self.journal_entry_max_bytes
+                );
+                return Ok(None);
+            }
+        };
+        Ok(Some(json))
+    }
+
+    // All lines go out in one buffered append with a single fsync.
+    fn write_legacy_journal_lines(&self, lines: &[String]) -> Result<(), AdapterError> {
         let Some(persistence) = self.persistence.as_ref() else {
             return Ok(());
         };
-        let entry = AdapterJournalEntry::v1(request);
-        let json = serde_json::to_string(&entry)
-            .map_err(|err| format!("failed to encode adapter journal entry: {err}"))?;
+        if lines.is_empty() {
+            return Ok(());
+        }
+        let mut buffer = String::new();
+        for line in lines {
+            buffer.push_str(line);
+            buffer.push('\n');
+        }
+        let _journal_guard = persistence
+            .journal_lock
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("journal".to_string()))?;
         let mut file = OpenOptions::new()
             .append(true)
             .create(true)
@@ -12745,8 +17987,7 @@ impl AdapterRuntime {
                     err
                 )
             })?;
-        file.write_all(json.as_bytes())
-            .and_then(|_| file.write_all(b"\n"))
+        file.write_all(buffer.as_bytes())
             .and_then(|_| file.sync_data())
             .map_err(|err| {
                 format!(
@@ -12755,14 +17996,106 @@ impl AdapterRuntime {
                     err
                 )
             })?;
+        persistence
+            .entries_since_store_snapshot
+            .fetch_add(lines.len() as u64, Ordering::SeqCst);
         Ok(())
     }
 }
 
+const JOURNAL_ENTRY_DEFAULT_MAX_BYTES: usize = 262_144;
+const JOURNAL_ENTRY_MIN_MAX_BYTES: usize = 4_096;
+
+fn parse_journal_entry_max_bytes_from_env() -> usize {
+    env::var("SELENE_ADAPTER_JOURNAL_ENTRY_MAX_BYTES")
+        .ok()
+        .and_then(|raw| raw.trim().parse::<usize>().ok())
+        .map(|bytes| bytes.max(JOURNAL_ENTRY_MIN_MAX_BYTES))
+        .unwrap_or(JOURNAL_ENTRY_DEFAULT_MAX_BYTES)
+}
+
+fn parse_journal_replay_max_entries_from_env() -> Option<usize> {
+    env::var("SELENE_ADAPTER_JOURNAL_REPLAY_MAX_ENTRIES")
+        .ok()
+        .and_then(|raw| raw.trim().parse::<usize>().ok())
+        .filter(|max| *max > 0)
+}
+
+fn parse_store_snapshot_every_entries_from_env() -> Option<usize> {
+    env::var("SELENE_ADAPTER_STORE_SNAPSHOT_EVERY_ENTRIES")
+        .ok()
+        .and_then(|raw| raw.trim().parse::<usize>().ok())
+        .filter(|every| *every > 0)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum AdapterJournalEntryEncoding {
+    Full(String),
+    Trimmed(String),
+    Oversized(usize),
+}
+
+// An entry over the cap drops what replay does not need: visual token lists and partial
+// transcripts. Identity, timing, final transcripts and capture refs stay, so the trimmed
+// line still replays the same turn. If that is not enough the entry is not journaled.
+fn encode_journal_entry_within_cap(
+    request: VoiceTurnAdapterRequest,
+    max_bytes: usize,
+) -> Result<AdapterJournalEntryEncoding, String> {
+    let encode = |request: &VoiceTurnAdapterRequest| {
+        serde_json::to_string(&AdapterJournalEntry::v2(request.clone())?)
+            .map_err(|err| format!("failed to encode adapter journal entry: {err}"))
+    };
+    let mut request = request;
+    let json = encode(&request)?;
+    if json.len() <= max_bytes {
+        return Ok(AdapterJournalEntryEncoding::Full(json));
+    }
+    if let Some(visual_input_ref) = request.visual_input_ref.as_mut() {
+        visual_input_ref.visible_tokens.clear();
+    }
+    request.user_text_partial = None;
+    request.selene_text_partial = None;
+    let json = encode(&request)?;
+    if json.len() <= max_bytes {
+        Ok(AdapterJournalEntryEncoding::Trimmed(json))
+    } else {
+        Ok(AdapterJournalEntryEncoding::Oversized(json.len()))
+    }
+}
+
 fn adapter_persistence_state_path(journal_path: &Path) -> PathBuf {
     PathBuf::from(format!("{}.state.json", journal_path.display()))
 }
 
+fn adapter_store_snapshot_path(journal_path: &Path) -> PathBuf {
+    PathBuf::from(format!("{}.snapshot.json", journal_path.display()))
+}
+
+// Writes and removes a marker next to the persistence file, so a read-only
+// mount fails at bootstrap instead of on the first journal append.
+fn probe_persistence_path_writable(path: &Path) -> Result<(), String> {
+    let not_writable = |err: std::io::Error| {
+        format!(
+            "adapter persistence path '{}' is not writable: {}",
+            path.display(),
+            err
+        )
+    };
+    let marker_path = PathBuf::from(format!("{}.write_probe", path.display()));
+    File::create(&marker_path)
+        .and_then(|mut marker| marker.write_all(b"probe"))
+        .map_err(not_writable)?;
+    fs::remove_file(&marker_path).map_err(not_writable)?;
+    if path.exists() {
+        OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map_err(not_writable)?;
+    }
+    Ok(())
+}
+
 fn quarantined_persistence_path(path: &Path, suffix: &str) -> PathBuf {
     PathBuf::from(format!("{}.{}.quarantined", path.display(), suffix))
 }
@@ -12809,35 +18142,35 @@ fn validate_optional_persistence_ascii_token(
 fn merge_conversation_turn_records_locked(
     state: &mut AdapterPersistenceState,
     mut records: Vec<ConversationTurnRecord>,
-) -> Result<bool, String> {
+) -> Result<bool, AdapterError> {
     records.sort_by_key(|record| record.conversation_turn_id.0);
     let mut changed = false;
     for record in records {
-        record
-            .validate()
-            .map_err(|err| format!("invalid conversation turn record: {err:?}"))?;
+        record.validate().map_err(|err| {
+            AdapterError::Persistence(format!("invalid conversation turn record: {err:?}"))
+        })?;
         let expected_next = state.conversation_turn_records.len().saturating_add(1) as u64;
         if record.conversation_turn_id.0 < expected_next {
             let index = record.conversation_turn_id.0.saturating_sub(1) as usize;
             let Some(existing) = state.conversation_turn_records.get(index) else {
-                return Err(format!(
+                return Err(AdapterError::Persistence(format!(
                     "conversation turn_id {} is below next expected {} but missing from durable state",
                     record.conversation_turn_id.0, expected_next
-                ));
+                )));
             };
             if existing != &record {
-                return Err(format!(
+                return Err(AdapterError::Persistence(format!(
                     "conversation turn_id {} conflicts with durable append-only state",
                     record.conversation_turn_id.0
-                ));
+                )));
             }
             continue;
         }
         if record.conversation_turn_id.0 != expected_next {
-            return Err(format!(
+            return Err(AdapterError::Persistence(format!(
                 "conversation turn_id {} must append as contiguous turn_id {}",
                 record.conversation_turn_id.0, expected_next
-            ));
+            )));
         }
         state.conversation_turn_records.push(record);
         changed = true;
@@ -12848,13 +18181,13 @@ fn merge_conversation_turn_records_locked(
 fn merge_internal_history_evidence_records_locked(
     state: &mut AdapterPersistenceState,
     mut records: Vec<InternalHistoryEvidenceRecord>,
-) -> Result<bool, String> {
+) -> Result<bool, AdapterError> {
     records.sort_by_key(|record| record.internal_history_event_id.0);
     let mut changed = false;
     for record in records {
-        record
-            .validate()
-            .map_err(|err| format!("invalid internal history evidence record: {err:?}"))?;
+        record.validate().map_err(|err| {
+            AdapterError::Persistence(format!("invalid internal history evidence record: {err:?}"))
+        })?;
         let expected_next = state
             .internal_history_evidence_records
             .len()
@@ -12862,24 +18195,24 @@ fn merge_internal_history_evidence_records_locked(
         if record.internal_history_event_id.0 < expected_next {
             let index = record.internal_history_event_id.0.saturating_sub(1) as usize;
             let Some(existing) = state.internal_history_evidence_records.get(index) else {
-                return Err(format!(
+                return Err(AdapterError::Persistence(format!(
                     "internal history evidence event_id {} is below next expected {} but missing from durable state",
                     record.internal_history_event_id.0, expected_next
-                ));
+                )));
             };
             if existing != &record {
-                return Err(format!(
+                return Err(AdapterError::Persistence(format!(
                     "internal history evidence event_id {} conflicts with durable append-only state",
                     record.internal_history_event_id.0
-                ));
+                )));
             }
             continue;
         }
         if record.internal_history_event_id.0 != expected_next {
-            return Err(format!(
+            return Err(AdapterError::Persistence(format!(
                 "internal history evidence event_id {} must append as contiguous event_id {}",
                 record.internal_history_event_id.0, expected_next
-            ));
+            )));
         }
         state.internal_history_evidence_records.push(record);
         changed = true;
@@ -13657,8 +18990,116 @@ fn parse_embedding_gate_profile(
 ) -> Result<VoiceIdentityEmbeddingGateProfile, String> {
     match value.trim().to_ascii_lowercase().as_str() {
         "required" => Ok(VoiceIdentityEmbeddingGateProfile::required()),
+        "required_unless_trusted" => {
+            Ok(VoiceIdentityEmbeddingGateProfile::required_unless_trusted_device())
+        }
         "optional" => Ok(VoiceIdentityEmbeddingGateProfile::optional()),
-        _ => Err(format!("{key} must be 'required' or 'optional'")),
+        _ => Err(format!(
+            "{key} must be 'required', 'optional', or 'required_unless_trusted'"
+        )),
+    }
+}
+
+/// Whether a trigger runs the wake session open step. `Default` leaves it to the platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WakeGateRequirement {
+    Default,
+    Required,
+    Skipped,
+}
+
+/// Wake session open step requirements per trigger, from `SELENE_WAKE_GATE_*`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterWakeGatePolicy {
+    explicit: WakeGateRequirement,
+    wake_word: WakeGateRequirement,
+    desktop_trusted_session: bool,
+}
+
+impl Default for AdapterWakeGatePolicy {
+    fn default() -> Self {
+        Self::mvp_default()
+    }
+}
+
+impl AdapterWakeGatePolicy {
+    /// `desktop_trusted_session` skips the open step for every desktop turn.
+    pub fn new(
+        explicit: WakeGateRequirement,
+        wake_word: WakeGateRequirement,
+        desktop_trusted_session: bool,
+    ) -> Self {
+        Self {
+            explicit,
+            wake_word,
+            desktop_trusted_session,
+        }
+    }
+
+    fn mvp_default() -> Self {
+        Self {
+            explicit: WakeGateRequirement::Default,
+            wake_word: WakeGateRequirement::Default,
+            desktop_trusted_session: false,
+        }
+    }
+
+    fn requires_session_open_step(
+        &self,
+        trigger: OsVoiceTrigger,
+        app_platform: AppPlatform,
+    ) -> bool {
+        if self.desktop_trusted_session && app_platform == AppPlatform::Desktop {
+            return false;
+        }
+        let requirement = match trigger {
+            OsVoiceTrigger::Explicit => self.explicit,
+            OsVoiceTrigger::WakeWord => self.wake_word,
+        };
+        match requirement {
+            WakeGateRequirement::Required => true,
+            WakeGateRequirement::Skipped => false,
+            WakeGateRequirement::Default => {
+                trigger_requires_session_open_step(ph1l_turn_trigger_from_os(trigger))
+            }
+        }
+    }
+}
+
+fn build_wake_gate_policy_from_env_var_map<F>(
+    mut env_getter: F,
+) -> Result<AdapterWakeGatePolicy, String>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut policy = AdapterWakeGatePolicy::mvp_default();
+    if let Some(v) = env_getter("SELENE_WAKE_GATE_EXPLICIT") {
+        policy.explicit = parse_wake_gate_requirement("SELENE_WAKE_GATE_EXPLICIT", &v)?;
+    }
+    if let Some(v) = env_getter("SELENE_WAKE_GATE_WAKE_WORD") {
+        policy.wake_word = parse_wake_gate_requirement("SELENE_WAKE_GATE_WAKE_WORD", &v)?;
+    }
+    if let Some(v) = env_getter("SELENE_WAKE_GATE_DESKTOP_TRUSTED") {
+        policy.desktop_trusted_session = match v.trim().to_ascii_lowercase().as_str() {
+            "true" => true,
+            "false" => false,
+            _ => {
+                return Err("SELENE_WAKE_GATE_DESKTOP_TRUSTED must be 'true' or 'false'".to_string())
+            }
+        };
+    }
+    Ok(policy)
+}
+
+fn parse_wake_gate_requirement(
+    key: &'static str,
+    value: &str,
+) -> Result<WakeGateRequirement, String> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "default" => Ok(WakeGateRequirement::Default),
+        "required" => Ok(WakeGateRequirement::Required),
+        "skip" => Ok(WakeGateRequirement::Skipped),
+        _ => Err(format!("{key} must be 'default', 'required' or 'skip'")),
     }
 }
 
@@ -14198,6 +19639,53 @@ fn voice_turn_ingress_error(
         session_id,
         turn_id,
         session_state: session_state.map(session_state_to_api_value),
+        cause: None,
+    }
+}
+
+const VOICE_TURN_CANCELLED_REASON_CODE: &str = "TURN_CANCELLED";
+
+// Retryable, so finalize leaves the outbox operation open and a resend of the same
+// idempotency key runs the turn instead of replaying the cancellation.
+fn voice_turn_cancelled_error(turn_id: Option<u64>) -> VoiceTurnIngressError {
+    voice_turn_ingress_error(
+        FailureClass::RetryableRuntime,
+        VOICE_TURN_CANCELLED_REASON_CODE.to_string(),
+        Some("voice turn cancelled by client".to_string()),
+        None,
+        turn_id,
+        None,
+    )
+}
+
+fn cancelled_voice_turn_adapter_response(turn_id: Option<u64>) -> VoiceTurnAdapterResponse {
+    VoiceTurnAdapterResponse {
+        status: "cancelled".to_string(),
+        outcome: "CANCELLED".to_string(),
+        session_id: None,
+        turn_id,
+        session_state: None,
+        session_attach_outcome: None,
+        failure_class: None,
+        reason: Some("voice turn cancelled by client".to_string()),
+        next_move: "none".to_string(),
+        response_text: String::new(),
+        reason_code: VOICE_TURN_CANCELLED_REASON_CODE.to_string(),
+        provenance: None,
+        tts_text: String::new(),
+        source_chips: Vec::new(),
+        source_cards: Vec::new(),
+        image_cards: Vec::new(),
+        answer_class: None,
+        metadata_safe_for_user: true,
+        trace_id: None,
+        deep_research: None,
+        screen_lifecycle_action: None,
+        session_lifecycle_action: None,
+        stt_mode: String::new(),
+        keep_listening: false,
+        next_move_kind: None,
+        outcome_kind: None,
     }
 }
 
@@ -14419,8 +19907,6 @@ fn classify_voice_turn_runtime_error(
         FailureClass::RetryableRuntime
     } else if reason.contains("session_conflict") {
         FailureClass::SessionConflict
-    } else if reason.contains("lock poisoned") {
-        FailureClass::RetryableRuntime
     } else if reason.starts_with("invalid ")
         || reason.starts_with("missing_")
         || reason.starts_with("stale_request")
@@ -14441,6 +19927,30 @@ fn classify_voice_turn_runtime_error(
     )
 }
 
+// Poisoned locks are always retryable; everything else goes through the reason classifier.
+// The typed error rides along as the failure's `cause`.
+fn classify_voice_turn_adapter_error(
+    err: AdapterError,
+    turn_id: Option<u64>,
+    session_id: Option<String>,
+    session_state: Option<SessionState>,
+) -> VoiceTurnIngressError {
+    let reason = err.to_string();
+    let mut failure = match err {
+        AdapterError::LockPoisoned(_) => voice_turn_ingress_error(
+            FailureClass::RetryableRuntime,
+            canonical_reason_code_token(&reason, FailureClass::RetryableRuntime),
+            Some(reason),
+            session_id,
+            turn_id,
+            session_state,
+        ),
+        _ => classify_voice_turn_runtime_error(&reason, turn_id, session_id, session_state),
+    };
+    failure.cause = Some(Box::new(err));
+    failure
+}
+
 fn fallback_runtime_execution_envelope_for_voice_turn_request(
     request: &VoiceTurnAdapterRequest,
 ) -> Result<RuntimeExecutionEnvelope, ContractViolation> {
@@ -14554,10 +20064,38 @@ fn onboarding_next_step_to_api_value(next_step: OnboardingNextStep) -> String {
     .to_string()
 }
 
+const ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN: usize = 4_096;
+const ONBOARDING_FIELD_VALUE_MIN_MAX_LEN: usize = 64;
+
+fn parse_onboarding_field_value_max_len_from_env() -> usize {
+    env::var("SELENE_ADAPTER_ONBOARDING_FIELD_MAX_LEN")
+        .ok()
+        .and_then(|raw| raw.trim().parse::<usize>().ok())
+        .map(|len| len.max(ONBOARDING_FIELD_VALUE_MIN_MAX_LEN))
+        .unwrap_or(ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN)
+}
+
+fn check_onboarding_value_len(
+    name: &str,
+    value: Option<&String>,
+    max_len: usize,
+) -> Result<(), String> {
+    match value {
+        Some(value) if value.len() > max_len => Err(format!(
+            "{name} is {} bytes, over the onboarding limit of {max_len} bytes",
+            value.len()
+        )),
+        _ => Ok(()),
+    }
+}
+
 fn parse_onboarding_continue_action(
     action: &str,
     input: OnboardingContinueActionInput,
+    max_value_len: usize,
 ) -> Result<AppOnboardingContinueAction, String> {
+    check_onboarding_value_len("field_value", input.field_value.as_ref(), max_value_len)?;
+    check_onboarding_value_len("receipt_ref", input.receipt_ref.as_ref(), max_value_len)?;
     let OnboardingContinueActionInput {
         field_value,
         receipt_kind,
@@ -15114,7 +20652,9 @@ fn build_nlp_output_for_voice_turn(
     committed_turn_followup: Option<&H379FollowupDecision>,
     h380_understanding: Option<&H380TurnUnderstandingPacket>,
     answer_language_preference: Option<&str>,
-) -> Result<(Ph1nResponse, Option<LanguagePacket>), String> {
+    nlp_engine: &dyn Ph1nEngine,
+    nlp_failure_strict: bool,
+) -> Result<(Ph1nResponse, Option<LanguagePacket>, Option<String>), String> {
     let correlation_id = CorrelationId(request.correlation_id.into());
     let turn_id = TurnId(request.turn_id);
     let captured_language_text = request.user_text_final.as_deref().or(transcript_text);
@@ -15130,6 +20670,7 @@ fn build_nlp_output_for_voice_turn(
             return Ok((
                 Ph1nResponse::Clarify(language_clarify_for_build1c(&context.packet)?),
                 Some(context.packet.clone()),
+                None,
             ));
         }
     }
@@ -15156,6 +20697,7 @@ fn build_nlp_output_for_voice_turn(
                         .map_err(|err| format!("invalid H380 live chat response: {err:?}"))?,
                 ),
                 language_context.map(|context| context.packet),
+                None,
             ));
         }
     }
@@ -15175,18 +20717,41 @@ fn build_nlp_output_for_voice_turn(
                     .map_err(|err| format!("invalid H379 follow-up chat response: {err:?}"))?,
                 ),
                 language_context.map(|context| context.packet),
+                None,
             ));
         }
     }
-    let nlp_request = build_base_nlp_request_for_vision_handoff(
+    let language_packet = language_context
+        .as_ref()
+        .map(|context| context.packet.clone());
+    let nlp_result = build_base_nlp_request_for_vision_handoff(
         request,
         effective_transcript,
         runtime_tenant_scope,
-    )?;
-    AdapterNlpEngineRuntime::new()
-        .run(&nlp_request)
-        .map_err(|err| format!("ph1n runtime failed while building PH1.X input: {err:?}"))
-        .map(|output| (output, language_context.map(|context| context.packet)))
+    )
+    .and_then(|nlp_request| {
+        nlp_engine
+            .run(&nlp_request)
+            .map_err(|err| format!("ph1n runtime failed while building PH1.X input: {err:?}"))
+    });
+    match nlp_result {
+        Ok(output) => Ok((output, language_packet, None)),
+        Err(err) if nlp_failure_strict => Err(err),
+        Err(err) => {
+            // Pass the transcript through as chat so PH1.X still answers the turn.
+            let pass_through_text = sanitize_transcript_text_option(
+                effective_transcript
+                    .map(str::to_string)
+                    .or_else(|| request.user_text_final.clone()),
+            )
+            .ok_or_else(|| format!("{err}; no transcript for pass-through NLP fallback"))?;
+            let chat = Ph1nChat::v1(pass_through_text, ph1n_reason_codes::N_CHAT_NO_INTENT)
+                .map_err(|chat_err| {
+                    format!("{err}; pass-through NLP fallback invalid: {chat_err:?}")
+                })?;
+            Ok((Ph1nResponse::Chat(chat), language_packet, Some(err)))
+        }
+    }
 }
 
 fn apply_language_continuity_to_execution_outcome(
@@ -15498,6 +21063,40 @@ fn public_failure_text_for_language(language_packet: Option<&LanguagePacket>) ->
     }
 }
 
+// PH1.D analysis is internal-only, so `short_analysis` is never spoken. A turn that reaches an
+// analysis outcome with nothing to say gets a line that hands the turn back to the user; the
+// turn ends here, so the line must not promise a later answer.
+fn response_text_with_ph1d_analysis(
+    response_text: Option<String>,
+    analysis: &Ph1dAnalysis,
+    language_packet: Option<&LanguagePacket>,
+) -> Option<String> {
+    if response_text
+        .as_deref()
+        .is_some_and(|text| !text.trim().is_empty())
+    {
+        return response_text;
+    }
+    Some(ph1d_analysis_response_text(analysis, language_packet))
+}
+
+fn ph1d_analysis_response_text(
+    analysis: &Ph1dAnalysis,
+    language_packet: Option<&LanguagePacket>,
+) -> String {
+    let use_chinese = language_packet.is_some_and(LanguagePacket::output_language_is_chinese);
+    match (analysis.short_analysis.starts_with("route:"), use_chinese) {
+        (true, true) => "我现在还答不上来。你可以换个方式再问一次吗？".to_string(),
+        (true, false) => {
+            "I don't have an answer for that yet. Could you ask it another way?".to_string()
+        }
+        (false, true) => "我想清楚了，请告诉我下一步要做什么。".to_string(),
+        (false, false) => {
+            "I've thought that through. What would you like me to do next?".to_string()
+        }
+    }
+}
+
 fn language_clarify_for_build1c(packet: &LanguagePacket) -> Result<Ph1nClarify, String> {
     let question = if packet.output_language_is_chinese() || packet.input_language_is_chinese() {
         "你想让我用中文还是英文回答？"
@@ -15736,7 +21335,7 @@ fn reset_session_scoped_active_context_if_needed(
     session_id: Option<SessionId>,
     session_attach_outcome: SessionAttachOutcome,
     thread_state: ThreadState,
-) -> Result<ThreadState, String> {
+) -> Result<ThreadState, AdapterError> {
     let Some(session_id) = session_id else {
         return Ok(thread_state);
     };
@@ -15779,6 +21378,7 @@ fn clear_stage6_session_scoped_contexts(
     active_session_context_state: &Arc<Mutex<BTreeMap<String, String>>>,
     weather_context_state: &Arc<Mutex<BTreeMap<String, String>>>,
     public_discourse_state: &Arc<Mutex<AdapterPublicDiscourseState>>,
+    ph1x_thread_state_cache: &Arc<Mutex<AdapterPh1xThreadStateCache>>,
     store: &mut Ph1fStore,
     now: MonotonicTimeNs,
     correlation_id: CorrelationId,
@@ -15786,7 +21386,8 @@ fn clear_stage6_session_scoped_contexts(
     actor_user_id: &UserId,
     thread_key: &str,
     thread_state: ThreadState,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     active_session_context_state
         .lock()
         .map_err(|_| "active_session_context_state_poisoned".to_string())?
@@ -15795,6 +21396,7 @@ fn clear_stage6_session_scoped_contexts(
     forget_public_discourse_frame(public_discourse_state, actor_user_id, thread_key)?;
     persist_ph1x_thread_state(
         store,
+        ph1x_thread_state_cache,
         now,
         PersistPh1xThreadStateInput {
             actor_user_id,
@@ -15804,6 +21406,7 @@ fn clear_stage6_session_scoped_contexts(
             correlation_id,
             turn_id,
         },
+        idempotency_token_max_len,
     )
 }
 
@@ -15811,11 +21414,11 @@ fn forget_public_discourse_frame(
     public_discourse_state: &Arc<Mutex<AdapterPublicDiscourseState>>,
     actor_user_id: &UserId,
     thread_key: &str,
-) -> Result<(), String> {
+) -> Result<(), AdapterError> {
     let key = h411_public_discourse_scope_key(actor_user_id, thread_key);
     let mut state = public_discourse_state
         .lock()
-        .map_err(|_| "adapter public discourse lock poisoned".to_string())?;
+        .map_err(|_| AdapterError::LockPoisoned("public discourse".to_string()))?;
     state.frames.remove(&key);
     Ok(())
 }
@@ -16075,7 +21678,7 @@ fn classify_h379_committed_turn_followup(
     if normalized.is_empty() {
         return None;
     }
-    let correction_detected = user_text_looks_like_correction(text);
+    let correction_detected = user_text_looks_like_correction(text, None);
     let provenance = looks_like_h379_tool_provenance_question(text, normalized.as_str());
     let meaning = looks_like_h379_meaning_or_rephrase_request(text, normalized.as_str());
     let repeat = looks_like_h379_repeat_request(normalized.as_str());
@@ -16412,8 +22015,36 @@ fn last_turn_route_class_for_execution(
     LastTurnRouteClass::PublicChat
 }
 
+fn ph1vision_failure_strict() -> bool {
+    parse_bool_env("SELENE_ADAPTER_VISION_FAILURE_STRICT", false)
+}
+
+fn voice_turn_has_non_vision_input(request: &VoiceTurnAdapterRequest) -> bool {
+    request.audio_capture_ref.is_some()
+        || request
+            .user_text_final
+            .as_deref()
+            .is_some_and(|text| !text.trim().is_empty())
+}
+
+// Returns the source kind of an opted-in visual input that `policy` disallows, so the
+// caller can record why PH1.VISION was skipped.
+fn disallowed_visual_source_kind(
+    request: &VoiceTurnAdapterRequest,
+    policy: &AdapterVisionSourceKindPolicy,
+) -> Option<VisualSourceKind> {
+    let visual = request.visual_input_ref.as_ref()?;
+    if !visual.turn_opt_in_enabled {
+        return None;
+    }
+    parse_visual_source_kind(visual.source_kind.as_deref())
+        .ok()
+        .filter(|kind| !policy.allows(*kind))
+}
+
 fn build_vision_turn_input_from_adapter_request(
     request: &VoiceTurnAdapterRequest,
+    policy: &AdapterVisionSourceKindPolicy,
     correlation_id: CorrelationId,
     turn_id: TurnId,
 ) -> Result<Option<VisionTurnInput>, String> {
@@ -16424,6 +22055,9 @@ fn build_vision_turn_input_from_adapter_request(
         return Ok(None);
     }
     let source_kind = parse_visual_source_kind(visual.source_kind.as_deref())?;
+    if !policy.allows(source_kind) {
+        return Ok(None);
+    }
     let source_id = visual
         .source_id
         .as_deref()
@@ -16497,10 +22131,12 @@ fn ensure_actor_identity_and_device(
     app_platform: AppPlatform,
     now: MonotonicTimeNs,
     allow_auto_provision: bool,
-) -> Result<(), String> {
+) -> Result<(), AdapterError> {
     if store.get_identity(actor_user_id).is_none() {
         if !allow_auto_provision {
-            return Err("auth_identity_unknown".to_string());
+            return Err(AdapterError::Validation(
+                "auth_identity_unknown".to_string(),
+            ));
         }
         store
             .insert_identity(IdentityRecord::v1(
@@ -16510,12 +22146,12 @@ fn ensure_actor_identity_and_device(
                 now,
                 IdentityStatus::Active,
             ))
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
     }
     if let Some(device_id) = device_id {
         if store.get_device(device_id).is_none() {
             if !allow_auto_provision {
-                return Err("auth_device_unknown".to_string());
+                return Err(AdapterError::Validation("auth_device_unknown".to_string()));
             }
             store
                 .insert_device(
@@ -16528,7 +22164,7 @@ fn ensure_actor_identity_and_device(
                     )
                     .map_err(|err| format!("invalid device record: {err:?}"))?,
                 )
-                .map_err(storage_error_to_string)?;
+                .map_err(AdapterError::Storage)?;
         }
     }
     Ok(())
@@ -16579,13 +22215,15 @@ fn empty_observation() -> EngineVoiceIdObservation {
     }
 }
 
-fn voice_outcome_reason(outcome: &OsVoiceLiveTurnOutcome) -> Option<String> {
+fn voice_outcome_reason(
+    outcome: &OsVoiceLiveTurnOutcome,
+    refusal_messages: &AdapterRefusalMessages,
+) -> Option<String> {
     match outcome {
         OsVoiceLiveTurnOutcome::NotInvokedDisabled => None,
-        OsVoiceLiveTurnOutcome::Refused(refuse) => Some(format!(
-            "os_refuse reason_code={} message={}",
-            refuse.reason_code.0, refuse.message
-        )),
+        OsVoiceLiveTurnOutcome::Refused(refuse) => {
+            Some(refusal_messages.message_for(refuse.reason_code))
+        }
         OsVoiceLiveTurnOutcome::Forwarded(bundle) => match &bundle.voice_identity_assertion {
             Ph1VoiceIdResponse::SpeakerAssertionOk(ok) => Some(format!(
                 "voice_identity=OK score_bp={} user_id={}",
@@ -16601,34 +22239,39 @@ fn voice_outcome_reason(outcome: &OsVoiceLiveTurnOutcome) -> Option<String> {
 }
 
 fn next_move_label(execution: &AppVoiceTurnExecutionOutcome) -> &'static str {
-    if execution.dispatch_outcome.is_some() {
-        return "dispatch_sim";
-    }
-    if execution.tool_response.is_some() {
-        return "dispatch_tool";
-    }
-    match execution.next_move {
-        AppVoiceTurnNextMove::Confirm | AppVoiceTurnNextMove::Clarify => "clarify",
-        AppVoiceTurnNextMove::Dispatch => "dispatch_sim",
-        AppVoiceTurnNextMove::NotInvokedDisabled
-        | AppVoiceTurnNextMove::Refused
-        | AppVoiceTurnNextMove::Respond
-        | AppVoiceTurnNextMove::Wait => "respond",
-    }
+    VoiceTurnNextMove::from_execution(execution).as_str()
+}
+
+// Next moves that are silent by design: PH1.X holding for the user, and desktop or session
+// lifecycle actions that the client carries out without speech.
+fn empty_response_is_intentional(next_move: &str) -> bool {
+    matches!(
+        next_move,
+        "wait" | "desktop_lifecycle_action" | "session_lifecycle_action"
+    )
+}
+
+// A Wait outcome means the assistant is deliberately holding for the user (for example, to let
+// them finish a thought). The client keeps the mic open and nothing is spoken; the text PH1.X
+// attaches to a Wait directive is its internal reason, not a closing line.
+fn execution_outcome_is_wait(execution: &AppVoiceTurnExecutionOutcome) -> bool {
+    next_move_label(execution) == "wait"
 }
 
 fn outcome_label(execution: &AppVoiceTurnExecutionOutcome) -> &'static str {
-    if execution.dispatch_outcome.is_some() {
-        return "DISPATCH_SIM";
-    }
-    if execution.tool_response.is_some() {
-        return "FINAL_TOOL";
-    }
-    "FINAL"
+    VoiceTurnOutcome::from_execution(execution).as_str()
+}
+
+// Window during which a tenant's repeat of one read-only incident kind is only counted, not
+// re-emitted. `0` (the default) turns the cooldown off.
+fn parse_read_only_incident_cooldown_ns_from_env() -> u64 {
+    parse_u64_env("SELENE_ADAPTER_READ_ONLY_INCIDENT_COOLDOWN_MS", 0).saturating_mul(1_000_000)
 }
 
 fn detect_read_only_turn_incidents(
     user_text_final: Option<&str>,
+    locale: Option<&str>,
+    redact_user_text: bool,
     execution: &AppVoiceTurnExecutionOutcome,
 ) -> Vec<ReadOnlyIncidentRecord> {
     if execution.dispatch_outcome.is_some() {
@@ -16693,11 +22336,11 @@ fn detect_read_only_turn_incidents(
     }
 
     if let Some(text) = user_text_final {
-        if user_text_looks_like_correction(text) {
+        if user_text_looks_like_correction(text, locale) {
             incidents.push(ReadOnlyIncidentRecord {
                 kind: ReadOnlyIncidentKind::UserCorrection,
                 reason_code: reason_codes::ADAPTER_READ_ONLY_USER_CORRECTION_INCIDENT,
-                evidence_ref: truncate_ascii(text.trim(), 128),
+                evidence_ref: read_only_user_text_evidence_ref(text, redact_user_text),
                 provenance_ref: "user_text:correction_phrase".to_string(),
             });
         }
@@ -16706,6 +22349,19 @@ fn detect_read_only_turn_incidents(
     incidents
 }
 
+fn privacy_evidence_raw_text_allowed() -> bool {
+    parse_bool_env("SELENE_ADAPTER_PRIVACY_EVIDENCE_RAW_TEXT_ALLOWED", false)
+}
+
+fn read_only_user_text_evidence_ref(text: &str, redact: bool) -> String {
+    if redact {
+        let digest = sha256_hex_for_build1c(text.trim());
+        format!("user_text:sha256:{}", &digest[..16])
+    } else {
+        truncate_ascii(text.trim(), 128)
+    }
+}
+
 fn cache_status_label(cache_status: CacheStatus) -> &'static str {
     match cache_status {
         CacheStatus::Hit => "hit",
@@ -19077,10 +24733,138 @@ fn adapter_looks_like_raw_image_asset_url(url: &str) -> bool {
     )
 }
 
+const VOICE_DISABLED_REASON: &str =
+    "voice_outcome=NOT_INVOKED_DISABLED voice turn processing is disabled";
+const VOICE_DISABLED_DEFAULT_MESSAGE: &str =
+    "Voice is turned off right now, so I can't help with that by voice.";
+
+fn voice_disabled_message_from(raw: Option<&str>) -> String {
+    raw.map(str::trim)
+        .filter(|message| !message.is_empty())
+        .map(|message| truncate_utf8(message, 240))
+        .unwrap_or_else(|| VOICE_DISABLED_DEFAULT_MESSAGE.to_string())
+}
+
+const REFUSAL_DEFAULT_MESSAGE: &str = "I can't help with that.";
+
+/// Client-facing messages for OS refusals. The client only sees these; the raw OS refusal
+/// goes to telemetry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdapterRefusalMessages {
+    pub by_reason_code: BTreeMap<u32, String>,
+    /// Replaces the built-in default for reason codes without their own message.
+    pub fallback: Option<String>,
+}
+
+impl AdapterRefusalMessages {
+    // `SELENE_ADAPTER_REFUSAL_MESSAGES` entries look like `0xAD700026:Not on this account.;*:No.`,
+    // with codes in decimal or `0x` hex and `*` replacing the default. The first message for a
+    // code wins.
+    fn parse(raw: &str) -> Self {
+        let mut messages = Self::default();
+        for entry in raw.split(';') {
+            let Some((code, message)) = entry.split_once(':') else {
+                continue;
+            };
+            let message = message.trim();
+            if message.is_empty() {
+                continue;
+            }
+            let code = code.trim();
+            if code == "*" {
+                messages.fallback = Some(truncate_utf8(message, 240));
+                continue;
+            }
+            let parsed = match code.strip_prefix("0x").or_else(|| code.strip_prefix("0X")) {
+                Some(hex) => u32::from_str_radix(&hex.replace('_', ""), 16).ok(),
+                None => code.parse::<u32>().ok(),
+            };
+            if let Some(parsed) = parsed {
+                messages
+                    .by_reason_code
+                    .entry(parsed)
+                    .or_insert_with(|| truncate_utf8(message, 240));
+            }
+        }
+        messages
+    }
+
+    fn message_for(&self, reason_code: ReasonCodeId) -> String {
+        self.by_reason_code
+            .get(&reason_code.0)
+            .or(self.fallback.as_ref())
+            .cloned()
+            .unwrap_or_else(|| REFUSAL_DEFAULT_MESSAGE.to_string())
+    }
+}
+
+const PROVENANCE_INLINE_SOURCE_LIMIT: usize = 5;
+
+fn apply_provenance_mode(response: &mut VoiceTurnAdapterResponse, mode: VoiceTurnProvenanceMode) {
+    match mode {
+        VoiceTurnProvenanceMode::Structured => {}
+        VoiceTurnProvenanceMode::None => response.provenance = None,
+        VoiceTurnProvenanceMode::Inline => {
+            let Some(citations) = response
+                .provenance
+                .as_ref()
+                .and_then(inline_provenance_citations)
+            else {
+                return;
+            };
+            if !response.response_text.is_empty() {
+                response.response_text.push_str("\n\n");
+            }
+            response.response_text.push_str(&citations);
+        }
+    }
+}
+
+// The citation list only goes into the display text; `tts_text` stays as spoken.
+fn inline_provenance_citations(provenance: &VoiceTurnProvenance) -> Option<String> {
+    let citations = provenance
+        .sources
+        .iter()
+        .filter(|source| !source.url.trim().is_empty())
+        .take(PROVENANCE_INLINE_SOURCE_LIMIT)
+        .enumerate()
+        .map(|(idx, source)| {
+            let title = source.title.trim();
+            let url = source.url.trim();
+            if title.is_empty() {
+                format!("[{}] {url}", idx + 1)
+            } else {
+                format!("[{}] {title} ({url})", idx + 1)
+            }
+        })
+        .collect::<Vec<_>>();
+    if citations.is_empty() {
+        return None;
+    }
+    Some(format!("Sources: {}", citations.join("; ")))
+}
+
 fn execution_outcome_to_adapter_response(
     execution: AppVoiceTurnExecutionOutcome,
+    refusal_messages: &AdapterRefusalMessages,
+    voice_disabled_message: &str,
 ) -> VoiceTurnAdapterResponse {
-    let response_text = execution.response_text.clone().unwrap_or_default();
+    let voice_disabled = execution.next_move == AppVoiceTurnNextMove::NotInvokedDisabled;
+    let keep_listening = execution_outcome_is_wait(&execution);
+    let response_text = if keep_listening {
+        String::new()
+    } else if let OsVoiceLiveTurnOutcome::Refused(refuse) = &execution.voice_outcome {
+        refusal_messages.message_for(refuse.reason_code)
+    } else if voice_disabled
+        && execution
+            .response_text
+            .as_deref()
+            .is_none_or(|text| text.trim().is_empty())
+    {
+        voice_disabled_message.to_string()
+    } else {
+        execution.response_text.clone().unwrap_or_default()
+    };
     let (source_chips, source_cards, image_cards, answer_class, trace_id) =
         stage5_web_presentation_from_tool_response(execution.tool_response.as_ref());
     VoiceTurnAdapterResponse {
@@ -19094,7 +24878,11 @@ fn execution_outcome_to_adapter_response(
         session_state: Some(session_state_to_api_value(execution.session_state)),
         session_attach_outcome: execution.runtime_execution_envelope.session_attach_outcome,
         failure_class: None,
-        reason: voice_outcome_reason(&execution.voice_outcome),
+        reason: if voice_disabled {
+            Some(VOICE_DISABLED_REASON.to_string())
+        } else {
+            voice_outcome_reason(&execution.voice_outcome, refusal_messages)
+        },
         next_move: next_move_label(&execution).to_string(),
         response_text: response_text.clone(),
         reason_code: execution
@@ -19118,6 +24906,10 @@ fn execution_outcome_to_adapter_response(
             .and_then(deep_research_metadata_from_tool_response),
         screen_lifecycle_action: None,
         session_lifecycle_action: None,
+        stt_mode: String::new(),
+        keep_listening,
+        next_move_kind: Some(VoiceTurnNextMove::from_execution(&execution)),
+        outcome_kind: Some(VoiceTurnOutcome::from_execution(&execution)),
     }
 }
 
@@ -19159,6 +24951,10 @@ fn stage4_screen_lifecycle_adapter_response(
             evidence: evidence.to_string(),
         }),
         session_lifecycle_action: None,
+        stt_mode: String::new(),
+        keep_listening: false,
+        next_move_kind: None,
+        outcome_kind: None,
     }
 }
 
@@ -19200,6 +24996,10 @@ fn stage6_session_lifecycle_adapter_response(
             source: "ph1l_runtime_session_classifier".to_string(),
             evidence: evidence.to_string(),
         }),
+        stt_mode: String::new(),
+        keep_listening: false,
+        next_move_kind: None,
+        outcome_kind: None,
     }
 }
 
@@ -20182,32 +25982,200 @@ fn sanitize_transcript_text_option(value: Option<String>) -> Option<String> {
         .filter(|v| !v.trim().is_empty())
 }
 
-fn user_text_looks_like_correction(text: &str) -> bool {
-    let normalized = text.trim().to_ascii_lowercase();
+// Phrases that mark a user turn as correcting Selene, for one locale. Seeded with the locales
+// PH1.K ships built-in interrupt phrases for; any other locale uses the English entry.
+#[derive(Debug, Clone, Copy)]
+struct CorrectionLexicon {
+    locale: &'static str,
+    prefixes: &'static [&'static str],
+    fragments: &'static [&'static str],
+}
+
+const CORRECTION_LEXICONS: [CorrectionLexicon; 4] = [
+    CorrectionLexicon {
+        locale: "en-US",
+        prefixes: &[
+            "no ",
+            "no,",
+            "actually",
+            "i meant",
+            "sorry, i meant",
+            "let me correct",
+            "correction:",
+            "that's not",
+        ],
+        fragments: &[" i meant ", " correction ", " not that"],
+    },
+    CorrectionLexicon {
+        locale: "es-ES",
+        prefixes: &[
+            "no ",
+            "no,",
+            "en realidad",
+            "quería decir",
+            "quise decir",
+            "me refería",
+            "corrijo",
+            "eso no es",
+        ],
+        fragments: &[
+            " quería decir ",
+            " quise decir ",
+            " me refería ",
+            " corrección ",
+        ],
+    },
+    CorrectionLexicon {
+        locale: "tr-TR",
+        prefixes: &[
+            "hayır",
+            "aslında",
+            "demek istediğim",
+            "yani demek istediğim",
+            "düzeltme:",
+            "öyle değil",
+        ],
+        fragments: &[" aslında ", " demek istedim", " demek istediğim "],
+    },
+    CorrectionLexicon {
+        locale: "zh-CN",
+        prefixes: &[
+            "不对",
+            "不是这个",
+            "我是说",
+            "我的意思是",
+            "更正",
+            "纠正一下",
+        ],
+        fragments: &["不对", "我是说", "我的意思是", "不是这个"],
+    },
+];
+
+impl CorrectionLexicon {
+    fn for_locale(locale: Option<&str>) -> &'static CorrectionLexicon {
+        locale
+            .map(str::trim)
+            .filter(|locale| !locale.is_empty())
+            .and_then(|locale| {
+                CORRECTION_LEXICONS
+                    .iter()
+                    .find(|lexicon| locale_matches(lexicon.locale, locale))
+            })
+            .unwrap_or(&CORRECTION_LEXICONS[0])
+    }
+
+    fn matches(&self, normalized: &str) -> bool {
+        self.prefixes
+            .iter()
+            .any(|prefix| normalized.starts_with(prefix))
+            || self
+                .fragments
+                .iter()
+                .any(|fragment| normalized.contains(fragment))
+    }
+}
+
+fn user_text_looks_like_correction(text: &str, locale: Option<&str>) -> bool {
+    let normalized = text.trim().to_lowercase();
     if normalized.is_empty() {
         return false;
     }
-    const PREFIXES: [&str; 8] = [
-        "no ",
-        "no,",
-        "actually",
-        "i meant",
-        "sorry, i meant",
-        "let me correct",
-        "correction:",
-        "that's not",
-    ];
-    if PREFIXES.iter().any(|prefix| normalized.starts_with(prefix)) {
-        return true;
+    CorrectionLexicon::for_locale(locale).matches(&normalized)
+}
+
+// Yes/no answers to a pending confirm, for one locale. Exact matches cover the whole
+// utterance; prefixes cover an answer followed by more text. Seeded with the same locales as
+// `CORRECTION_LEXICONS`; any other locale uses the English entry.
+#[derive(Debug, Clone, Copy)]
+struct ConfirmLexicon {
+    locale: &'static str,
+    yes_exact: &'static [&'static str],
+    no_exact: &'static [&'static str],
+    yes_prefixes: &'static [&'static str],
+    no_prefixes: &'static [&'static str],
+}
+
+const CONFIRM_LEXICONS: [ConfirmLexicon; 4] = [
+    ConfirmLexicon {
+        locale: "en-US",
+        yes_exact: &[
+            "yes", "y", "yeah", "yep", "confirm", "correct", "ok", "okay",
+        ],
+        no_exact: &["no", "n", "nope", "nah", "cancel", "stop", "don't"],
+        yes_prefixes: &["yes,", "yes.", "confirm,", "confirm."],
+        no_prefixes: &["no,", "no.", "cancel,"],
+    },
+    ConfirmLexicon {
+        locale: "es-ES",
+        yes_exact: &[
+            "sí",
+            "si",
+            "claro",
+            "vale",
+            "de acuerdo",
+            "confirmo",
+            "correcto",
+        ],
+        no_exact: &["no", "nop", "cancela", "cancelar", "para", "no gracias"],
+        yes_prefixes: &["sí,", "sí.", "si,", "claro,", "vale,"],
+        no_prefixes: &["no,", "no.", "cancela,"],
+    },
+    ConfirmLexicon {
+        locale: "tr-TR",
+        yes_exact: &["evet", "tamam", "olur", "doğru", "onaylıyorum", "tabii"],
+        no_exact: &[
+            "hayır",
+            "hayir",
+            "yok",
+            "iptal",
+            "vazgeç",
+            "dur",
+            "istemiyorum",
+        ],
+        yes_prefixes: &["evet,", "evet.", "tamam,"],
+        no_prefixes: &["hayır,", "hayır.", "hayir,", "iptal,"],
+    },
+    ConfirmLexicon {
+        locale: "zh-CN",
+        yes_exact: &["是", "是的", "对", "对的", "好", "好的", "确认", "可以"],
+        no_exact: &["不", "不是", "不要", "不用", "取消", "算了"],
+        yes_prefixes: &["是，", "是。", "好的，", "确认，"],
+        no_prefixes: &["不，", "不。", "不要，", "取消，"],
+    },
+];
+
+impl ConfirmLexicon {
+    fn for_locale(locale: Option<&str>) -> &'static ConfirmLexicon {
+        locale
+            .map(str::trim)
+            .filter(|locale| !locale.is_empty())
+            .and_then(|locale| {
+                CONFIRM_LEXICONS
+                    .iter()
+                    .find(|lexicon| locale_matches(lexicon.locale, locale))
+            })
+            .unwrap_or(&CONFIRM_LEXICONS[0])
+    }
+
+    fn answer(&self, normalized: &str) -> Option<ConfirmAnswer> {
+        let matches = |exact: &[&str], prefixes: &[&str]| {
+            exact.contains(&normalized)
+                || prefixes.iter().any(|prefix| normalized.starts_with(prefix))
+        };
+        if matches(self.yes_exact, self.yes_prefixes) {
+            return Some(ConfirmAnswer::Yes);
+        }
+        if matches(self.no_exact, self.no_prefixes) {
+            return Some(ConfirmAnswer::No);
+        }
+        None
     }
-    normalized.contains(" i meant ")
-        || normalized.contains(" correction ")
-        || normalized.contains(" not that")
 }
 
 fn infer_confirm_answer_from_user_text(
     thread_state: &ThreadState,
     user_text_final: Option<&str>,
+    locale: Option<&str>,
 ) -> Option<ConfirmAnswer> {
     let awaiting_confirm = thread_state.return_check_pending
         || matches!(
@@ -20218,39 +26186,36 @@ fn infer_confirm_answer_from_user_text(
         return None;
     }
 
-    let normalized = user_text_final?.trim().to_ascii_lowercase();
+    let normalized = user_text_final?.trim().to_lowercase();
     if normalized.is_empty() {
         return None;
     }
-
-    const YES_EXACT: [&str; 8] = [
-        "yes", "y", "yeah", "yep", "confirm", "correct", "ok", "okay",
-    ];
-    const NO_EXACT: [&str; 7] = ["no", "n", "nope", "nah", "cancel", "stop", "don't"];
-    const YES_PREFIXES: [&str; 4] = ["yes,", "yes.", "confirm,", "confirm."];
-    const NO_PREFIXES: [&str; 3] = ["no,", "no.", "cancel,"];
-
-    if YES_EXACT.contains(&normalized.as_str())
-        || YES_PREFIXES
-            .iter()
-            .any(|prefix| normalized.starts_with(prefix))
-    {
-        return Some(ConfirmAnswer::Yes);
-    }
-    if NO_EXACT.contains(&normalized.as_str())
-        || NO_PREFIXES
-            .iter()
-            .any(|prefix| normalized.starts_with(prefix))
-    {
-        return Some(ConfirmAnswer::No);
-    }
-    None
+    ConfirmLexicon::for_locale(locale).answer(&normalized)
 }
 
 fn adapter_transcript_role_from_storage(role: ConversationRole) -> AdapterTranscriptRole {
     match role {
         ConversationRole::User => AdapterTranscriptRole::User,
         ConversationRole::Selene => AdapterTranscriptRole::Selene,
+        ConversationRole::System => AdapterTranscriptRole::System,
+    }
+}
+
+fn adapter_transcript_role_to_storage(role: AdapterTranscriptRole) -> ConversationRole {
+    match role {
+        AdapterTranscriptRole::User => ConversationRole::User,
+        AdapterTranscriptRole::Selene => ConversationRole::Selene,
+        AdapterTranscriptRole::System => ConversationRole::System,
+    }
+}
+
+fn adapter_transcript_source_to_storage(source: AdapterTranscriptSource) -> ConversationSource {
+    match source {
+        AdapterTranscriptSource::Ph1C => ConversationSource::VoiceTranscript,
+        AdapterTranscriptSource::Ph1Write => ConversationSource::SeleneOutput,
+        AdapterTranscriptSource::UiText => ConversationSource::TypedText,
+        AdapterTranscriptSource::SystemNotice => ConversationSource::SystemNotice,
+        AdapterTranscriptSource::ToolResult => ConversationSource::ToolResult,
     }
 }
 
@@ -20261,10 +26226,29 @@ fn adapter_transcript_source_from_storage(
         ConversationSource::VoiceTranscript => Some(AdapterTranscriptSource::Ph1C),
         ConversationSource::SeleneOutput => Some(AdapterTranscriptSource::Ph1Write),
         ConversationSource::TypedText => Some(AdapterTranscriptSource::UiText),
+        ConversationSource::SystemNotice => Some(AdapterTranscriptSource::SystemNotice),
+        ConversationSource::ToolResult => Some(AdapterTranscriptSource::ToolResult),
         ConversationSource::Tombstone => None,
     }
 }
 
+fn tombstoned_conversation_turn_ids(store: &Ph1fStore) -> BTreeSet<ConversationTurnId> {
+    store
+        .conversation_ledger()
+        .iter()
+        .filter_map(|row| row.tombstone_of_conversation_turn_id)
+        .collect()
+}
+
+fn erased_conversation_user_ids(store: &Ph1fStore) -> BTreeSet<String> {
+    store
+        .conversation_ledger()
+        .iter()
+        .filter(|row| row.source == ConversationSource::Tombstone)
+        .map(|row| row.user_id.as_str().to_string())
+        .collect()
+}
+
 fn adapter_transcript_event_from_record(
     record: &selene_kernel_contracts::ph1f::ConversationTurnRecord,
 ) -> Option<AdapterTranscriptEvent> {
@@ -20273,6 +26257,9 @@ fn adapter_transcript_event_from_record(
         seq: record.conversation_turn_id.0,
         correlation_id: record.correlation_id,
         turn_id: record.turn_id,
+        session_id: record.session_id,
+        user_id: record.user_id.clone(),
+        privacy_scope: record.privacy_scope,
         role: adapter_transcript_role_from_storage(record.role),
         source,
         finalized: true,
@@ -20392,7 +26379,9 @@ fn stage7_modality_for_conversation_source(
     match source {
         ConversationSource::VoiceTranscript => Some(InternalHistoryModality::Voice),
         ConversationSource::TypedText => Some(InternalHistoryModality::Typed),
-        ConversationSource::SeleneOutput => Some(InternalHistoryModality::System),
+        ConversationSource::SeleneOutput
+        | ConversationSource::SystemNotice
+        | ConversationSource::ToolResult => Some(InternalHistoryModality::System),
         ConversationSource::Tombstone => None,
     }
 }
@@ -20409,9 +26398,10 @@ fn stage7_speaker_for_user_source(
         ConversationSource::VoiceTranscript => {
             SpeakerEvidenceRefs::voice_unknown(actor_user_id.clone(), device_id.cloned())
         }
-        ConversationSource::SeleneOutput | ConversationSource::Tombstone => {
-            SpeakerEvidenceRefs::none()
-        }
+        ConversationSource::SeleneOutput
+        | ConversationSource::Tombstone
+        | ConversationSource::SystemNotice
+        | ConversationSource::ToolResult => SpeakerEvidenceRefs::none(),
     }
 }
 
@@ -20457,6 +26447,7 @@ impl Stage8FreshMemoryBridge {
         correlation_id: CorrelationId,
         turn_id: TurnId,
         resolution: EngineFreshMemoryContinuationResolution,
+        idempotency_token_max_len: usize,
     ) -> Option<Self> {
         if matches!(
             resolution.memory_continuation_decision.decision,
@@ -20468,7 +26459,7 @@ impl Stage8FreshMemoryBridge {
         let family = resolution
             .tool_family
             .as_deref()
-            .map(sanitize_idempotency_token)
+            .map(|value| sanitize_idempotency_token(value, idempotency_token_max_len))
             .unwrap_or_else(|| "fresh".to_string());
         let mut memory_refs = MemoryEvidenceRefs::none();
         memory_refs.memory_recall_request_ref = resolution.memory_recall_request_ref.clone();
@@ -20659,6 +26650,7 @@ fn stage8_fresh_memory_bridge_from_store(
     actor_user_id: &UserId,
     thread_key: &str,
     user_text: Option<&str>,
+    idempotency_token_max_len: usize,
 ) -> Result<Option<Stage8FreshMemoryBridge>, String> {
     let Some(user_text) = user_text.map(str::trim).filter(|value| !value.is_empty()) else {
         return Ok(None);
@@ -20683,6 +26675,7 @@ fn stage8_fresh_memory_bridge_from_store(
         correlation_id,
         turn_id,
         resolution,
+        idempotency_token_max_len,
     ))
 }
 
@@ -20830,7 +26823,8 @@ fn append_stage7_runtime_response_evidence(
     protected_fail_closed: bool,
     stage8_5c_decision: Option<&Stage8_5CandidateDecision>,
     stage8_fresh_memory: Option<&Stage8FreshMemoryBridge>,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let Some(selene_text) = selene_text.map(str::trim).filter(|value| !value.is_empty()) else {
         return Ok(());
     };
@@ -20916,7 +26910,7 @@ fn append_stage7_runtime_response_evidence(
         {
             refs.tool_provider_refs.push(format!(
                 "ph1e_provider:{}",
-                sanitize_idempotency_token(provider_hint)
+                sanitize_idempotency_token(provider_hint, idempotency_token_max_len)
             ));
         }
         refs.source_refs
@@ -20972,15 +26966,18 @@ fn append_stage7_runtime_response_evidence(
         ph1x,
         memory,
         refs,
-        Some(sanitize_idempotency_token(&format!(
-            "stage7_response_evidence:{}:{}:{}",
-            correlation_id.0, turn_id.0, selene_turn_id.0
-        ))),
+        Some(sanitize_idempotency_token(
+            &format!(
+                "stage7_response_evidence:{}:{}:{}",
+                correlation_id.0, turn_id.0, selene_turn_id.0
+            ),
+            idempotency_token_max_len,
+        )),
     )
     .map_err(|err| format!("invalid Stage 7 response evidence: {err:?}"))?;
     store
         .append_internal_history_evidence(evidence)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
 
     if let Some(tool) = tool_response {
         append_stage7_tool_evidence(
@@ -20993,6 +26990,7 @@ fn append_stage7_runtime_response_evidence(
             selene_text,
             tool,
             stage8_fresh_memory,
+            idempotency_token_max_len,
         )?;
     }
     if let Some(stage8) = stage8_fresh_memory {
@@ -21005,6 +27003,7 @@ fn append_stage7_runtime_response_evidence(
             Some(selene_turn_id),
             selene_text,
             stage8,
+            idempotency_token_max_len,
         )?;
     }
     if protected_fail_closed {
@@ -21017,6 +27016,7 @@ fn append_stage7_runtime_response_evidence(
             user_source,
             user_text,
             selene_text,
+            idempotency_token_max_len,
         )?;
     }
     Ok(())
@@ -21033,7 +27033,8 @@ fn append_stage7_tool_evidence(
     selene_text: &str,
     tool_response: &ToolResponse,
     stage8_fresh_memory: Option<&Stage8FreshMemoryBridge>,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let family = stage7_tool_family_label(tool_response);
     let response_hash = stable_hash_hex_16(selene_text);
     let mut refs = InternalHistoryEvidenceRefs::none();
@@ -21058,7 +27059,7 @@ fn append_stage7_tool_evidence(
     {
         refs.tool_provider_refs.push(format!(
             "ph1e_provider:{}",
-            sanitize_idempotency_token(provider_hint)
+            sanitize_idempotency_token(provider_hint, idempotency_token_max_len)
         ));
     }
     refs.source_refs
@@ -21107,15 +27108,18 @@ fn append_stage7_tool_evidence(
             .map(|stage8| stage8.memory_refs.clone())
             .unwrap_or_else(MemoryEvidenceRefs::none),
         refs,
-        Some(sanitize_idempotency_token(&format!(
-            "stage7_tool_evidence:{}:{}:{}",
-            correlation_id.0, turn_id.0, tool_response.request_id.0
-        ))),
+        Some(sanitize_idempotency_token(
+            &format!(
+                "stage7_tool_evidence:{}:{}:{}",
+                correlation_id.0, turn_id.0, tool_response.request_id.0
+            ),
+            idempotency_token_max_len,
+        )),
     )
     .map_err(|err| format!("invalid Stage 7 tool evidence: {err:?}"))?;
     store
         .append_internal_history_evidence(evidence)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -21129,7 +27133,8 @@ fn append_stage8_fresh_memory_evidence(
     conversation_turn_id: Option<ConversationTurnId>,
     selene_text: &str,
     stage8: &Stage8FreshMemoryBridge,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let mut refs = InternalHistoryEvidenceRefs::none();
     refs.audit_refs.push(format!(
         "ph1m_fresh_memory_decision:{}:{}",
@@ -21170,15 +27175,18 @@ fn append_stage8_fresh_memory_evidence(
         ph1x,
         stage8.memory_refs.clone(),
         refs,
-        Some(sanitize_idempotency_token(&format!(
-            "stage8_fresh_memory_evidence:{}:{}",
-            correlation_id.0, turn_id.0
-        ))),
+        Some(sanitize_idempotency_token(
+            &format!(
+                "stage8_fresh_memory_evidence:{}:{}",
+                correlation_id.0, turn_id.0
+            ),
+            idempotency_token_max_len,
+        )),
     )
     .map_err(|err| format!("invalid Stage 8 fresh memory evidence: {err:?}"))?;
     store
         .append_internal_history_evidence(evidence)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -21188,7 +27196,8 @@ fn append_stage8_session_sleep_boundary_evidence(
     correlation_id: CorrelationId,
     turn_id: TurnId,
     session_id: SessionId,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let mut memory = MemoryEvidenceRefs::none();
     memory.fresh_memory_handoff_ref = Some(format!(
         "ph1m_fresh_handoff_boundary:sleep:{}:{}",
@@ -21220,15 +27229,18 @@ fn append_stage8_session_sleep_boundary_evidence(
         LiveContextEvidenceRefs::none(),
         memory,
         refs,
-        Some(sanitize_idempotency_token(&format!(
-            "stage8_sleep_boundary:{}:{}:{}",
-            session_id.0, correlation_id.0, turn_id.0
-        ))),
+        Some(sanitize_idempotency_token(
+            &format!(
+                "stage8_sleep_boundary:{}:{}:{}",
+                session_id.0, correlation_id.0, turn_id.0
+            ),
+            idempotency_token_max_len,
+        )),
     )
     .map_err(|err| format!("invalid Stage 8 sleep boundary evidence: {err:?}"))?;
     store
         .append_internal_history_evidence(evidence)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -21242,7 +27254,8 @@ fn append_stage7_protected_fail_closed_evidence(
     user_source: ConversationSource,
     user_text: Option<&str>,
     selene_text: &str,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let Some(user_turn_id) = stage7_conversation_turn_id_for(
         store,
         correlation_id,
@@ -21314,15 +27327,18 @@ fn append_stage7_protected_fail_closed_evidence(
         ph1x,
         memory,
         refs,
-        Some(sanitize_idempotency_token(&format!(
-            "stage7_protected_fail_closed:{}:{}:{}",
-            correlation_id.0, turn_id.0, user_turn_id.0
-        ))),
+        Some(sanitize_idempotency_token(
+            &format!(
+                "stage7_protected_fail_closed:{}:{}:{}",
+                correlation_id.0, turn_id.0, user_turn_id.0
+            ),
+            idempotency_token_max_len,
+        )),
     )
     .map_err(|err| format!("invalid Stage 7 protected fail-closed evidence: {err:?}"))?;
     store
         .append_internal_history_evidence(evidence)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -21337,8 +27353,11 @@ fn append_transcript_final_conversation_turn(
     session_id: Option<SessionId>,
     role: ConversationRole,
     source: ConversationSource,
+    privacy_scope: PrivacyScope,
+    part_seq: Option<u32>,
     text: &str,
-) -> Result<Option<ConversationTurnId>, String> {
+    idempotency_token_max_len: usize,
+) -> Result<Option<ConversationTurnId>, AdapterError> {
     let text = truncate_ascii(text.trim(), 8192);
     if text.is_empty() {
         return Ok(None);
@@ -21354,21 +27373,34 @@ fn append_transcript_final_conversation_turn(
     }) {
         return Ok(Some(existing.conversation_turn_id));
     }
-    let idempotency_key = sanitize_idempotency_token(&format!(
-        "adapter_transcript:{}:{}:{}:{}",
-        correlation_id.0,
-        turn_id.0,
-        match role {
-            ConversationRole::User => "USER",
-            ConversationRole::Selene => "SELENE",
-        },
-        match source {
-            ConversationSource::VoiceTranscript => "PH1.C",
-            ConversationSource::TypedText => "UI.TEXT",
-            ConversationSource::SeleneOutput => "PH1.WRITE",
-            ConversationSource::Tombstone => "TOMBSTONE",
-        }
-    ));
+    // Parts after the first of a multi-part message get their own key; a single message (no
+    // part, or part 0) keeps the original key so existing rows still dedupe.
+    let part_suffix = match part_seq {
+        Some(part) if part > 0 => format!(":part{part}"),
+        _ => String::new(),
+    };
+    let idempotency_key = sanitize_idempotency_token(
+        &format!(
+            "adapter_transcript:{}:{}:{}:{}{}",
+            correlation_id.0,
+            turn_id.0,
+            match role {
+                ConversationRole::User => "USER",
+                ConversationRole::Selene => "SELENE",
+                ConversationRole::System => "SYSTEM",
+            },
+            match source {
+                ConversationSource::VoiceTranscript => "PH1.C",
+                ConversationSource::TypedText => "UI.TEXT",
+                ConversationSource::SeleneOutput => "PH1.WRITE",
+                ConversationSource::Tombstone => "TOMBSTONE",
+                ConversationSource::SystemNotice => "SYSTEM.NOTICE",
+                ConversationSource::ToolResult => "TOOL.RESULT",
+            },
+            part_suffix
+        ),
+        idempotency_token_max_len,
+    );
     let input = ConversationTurnInput::v1(
         now,
         correlation_id,
@@ -21380,7 +27412,7 @@ fn append_transcript_final_conversation_turn(
         source,
         text.clone(),
         text_hash,
-        PrivacyScope::PublicChat,
+        privacy_scope,
         Some(idempotency_key),
         None,
         None,
@@ -21388,17 +27420,136 @@ fn append_transcript_final_conversation_turn(
     .map_err(|err| format!("invalid transcript conversation input: {err:?}"))?;
     let conversation_turn_id = store
         .append_conversation_turn(input)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(Some(conversation_turn_id))
 }
 
+// A low-latency commit finalizes the turn on an early partial, so the provider's later final
+// arrives on a follow-up request for the same turn. When its words differ from the displayed
+// USER text (case and punctuation alone do not count), it is stored as a supplementary row and
+// a PH1C_LIVE_TRANSCRIPT_CORRECTED event links it to the original row. The transcript view
+// shows the newest final per turn, so clients pick up the corrected text. Returns `false` when
+// the turn had no low-latency commit or no earlier USER row, leaving the normal append to run.
+#[allow(clippy::too_many_arguments)]
+fn append_low_latency_transcript_correction(
+    store: &mut Ph1fStore,
+    now: MonotonicTimeNs,
+    correlation_id: CorrelationId,
+    turn_id: TurnId,
+    actor_user_id: &UserId,
+    device_id: Option<&DeviceId>,
+    session_id: Option<SessionId>,
+    text: &str,
+    idempotency_token_max_len: usize,
+) -> Result<bool, AdapterError> {
+    let low_latency_committed = store.outcome_utilization_ledger_rows().iter().any(|row| {
+        row.correlation_id == correlation_id
+            && row.turn_id == turn_id
+            && row.outcome_type == "PH1C_LIVE_TRANSCRIPT_OK_LOW_LATENCY"
+    });
+    if !low_latency_committed {
+        return Ok(false);
+    }
+    let user_rows = store
+        .conversation_ledger()
+        .iter()
+        .filter(|row| {
+            row.correlation_id == correlation_id
+                && row.turn_id == turn_id
+                && row.role == ConversationRole::User
+                && row.source == ConversationSource::VoiceTranscript
+                && row.tombstone_of_conversation_turn_id.is_none()
+        })
+        .collect::<Vec<_>>();
+    let (Some(original), Some(displayed)) = (user_rows.first(), user_rows.last()) else {
+        return Ok(false);
+    };
+    let original_conversation_turn_id = original.conversation_turn_id;
+    let text = truncate_ascii(text.trim(), 8192);
+    if text.is_empty()
+        || transcript_words_normalized(&text) == transcript_words_normalized(&displayed.text)
+    {
+        return Ok(true);
+    }
+
+    let text_hash = stable_hash_hex_16(&text);
+    let idempotency_key = sanitize_idempotency_token(
+        &format!(
+            "adapter_transcript:{}:{}:USER:PH1.C:correction:{}",
+            correlation_id.0, turn_id.0, text_hash
+        ),
+        idempotency_token_max_len,
+    );
+    let input = ConversationTurnInput::v1(
+        now,
+        correlation_id,
+        turn_id,
+        session_id,
+        actor_user_id.clone(),
+        device_id.cloned(),
+        ConversationRole::User,
+        ConversationSource::VoiceTranscript,
+        text,
+        text_hash,
+        original.privacy_scope,
+        Some(idempotency_key),
+        None,
+        None,
+    )
+    .map_err(|err| format!("invalid transcript correction input: {err:?}"))?;
+    let corrected_conversation_turn_id = store
+        .append_conversation_turn(input)
+        .map_err(AdapterError::Storage)?;
+
+    let mut telemetry_tags = BTreeMap::new();
+    telemetry_tags.insert(
+        "original_conversation_turn_id".to_string(),
+        original_conversation_turn_id.0.to_string(),
+    );
+    telemetry_tags.insert(
+        "corrected_conversation_turn_id".to_string(),
+        corrected_conversation_turn_id.0.to_string(),
+    );
+    store
+        .append_outcome_utilization_ledger_row(OutcomeUtilizationLedgerRowInput {
+            created_at: now,
+            correlation_id,
+            turn_id,
+            engine_id: "PH1.C".to_string(),
+            outcome_type: "PH1C_LIVE_TRANSCRIPT_CORRECTED".to_string(),
+            action_class: OsOutcomeActionClass::AuditOnly,
+            consumed_by: "PH1.C.SUPERIORITY".to_string(),
+            latency_cost_ms: 0,
+            decision_delta: true,
+            reason_code: reason_codes::ADAPTER_LOW_LATENCY_TRANSCRIPT_CORRECTED,
+            idempotency_key: Some(sanitize_idempotency_token(
+                &format!(
+                    "ph1c_transcript_correction:{}:{}:{}",
+                    correlation_id.0, turn_id.0, corrected_conversation_turn_id.0
+                ),
+                idempotency_token_max_len,
+            )),
+            telemetry_tags,
+        })
+        .map_err(AdapterError::Storage)?;
+    Ok(true)
+}
+
+fn transcript_words_normalized(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
 fn update_recent_archive_digest_from_conversation_ledger(
     store: &mut Ph1fStore,
     now: MonotonicTimeNs,
     actor_user_id: &UserId,
     session_id: Option<SessionId>,
     turn_id: TurnId,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let Some(session_id) = session_id else {
         return Ok(());
     };
@@ -21432,10 +27583,11 @@ fn update_recent_archive_digest_from_conversation_ledger(
             "adapter_recent_archive_digest:{}:{}",
             session_id.0, turn_id.0
         ),
+        idempotency_token_max_len,
     )?;
     store
         .ph1m_upsert_thread_refs_for_user_turn_with_session(actor_user_id, &thread_id, turn_id, now)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
 
     let turn_rows = rows
         .iter()
@@ -21454,6 +27606,7 @@ fn update_recent_archive_digest_from_conversation_ledger(
                 "adapter_recent_archive_turn_digest:{}:{}",
                 session_id.0, turn_id.0
             ),
+            idempotency_token_max_len,
         )?;
         store
             .ph1m_upsert_thread_refs_for_user_turn_with_session(
@@ -21462,7 +27615,7 @@ fn update_recent_archive_digest_from_conversation_ledger(
                 turn_id,
                 now,
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
     }
     Ok(())
 }
@@ -21491,7 +27644,8 @@ fn upsert_recent_archive_digest_from_rows(
     thread_id: &str,
     rows: &[RecentArchiveConversationRow],
     idempotency_key: &str,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let thread_title = recent_archive_thread_title_from_rows(rows);
     let summary_bullets = recent_archive_summary_bullets_from_rows(rows);
     let use_count = u32::try_from(rows.len().min(1_000_000)).unwrap_or(1_000_000);
@@ -21512,9 +27666,9 @@ fn upsert_recent_archive_digest_from_rows(
             digest,
             selene_storage::ph1f::MemoryThreadEventKind::ThreadDigestUpsert,
             selene_engines::ph1m::reason_codes::M_THREAD_DIGEST_UPSERTED,
-            sanitize_idempotency_token(idempotency_key),
+            sanitize_idempotency_token(idempotency_key, idempotency_token_max_len),
         )
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -21593,6 +27747,7 @@ fn recent_archive_summary_bullets_from_rows(rows: &[RecentArchiveConversationRow
                     match row.role {
                         ConversationRole::User => "User",
                         ConversationRole::Selene => "Selene",
+                        ConversationRole::System => "System",
                     },
                     truncate_utf8_bytes(text, 232)
                 ))
@@ -21676,8 +27831,37 @@ fn truncate_utf8_bytes(value: &str, max_bytes: usize) -> String {
     out
 }
 
-fn storage_error_to_string(err: StorageError) -> String {
-    format!("{err:?}")
+const STORAGE_COMMIT_RETRY_LIMIT_DEFAULT: u32 = 2;
+const STORAGE_COMMIT_RETRY_LIMIT_MAX: u32 = 5;
+
+fn storage_error_is_transient(err: &StorageError) -> bool {
+    matches!(
+        err,
+        StorageError::ProofFailure {
+            class: ProofFailureClass::ProofWriteFailure
+                | ProofFailureClass::ProofStorageUnavailable
+                | ProofFailureClass::ProofVerificationUnavailable,
+            ..
+        }
+    )
+}
+
+// Only for commits that carry an idempotency key: a retry after a partially applied attempt
+// dedupes in the store instead of writing twice. Non-transient errors fail on the first try.
+fn retry_transient_storage_commit<T>(
+    retry_limit: u32,
+    mut commit: impl FnMut() -> Result<T, StorageError>,
+) -> Result<T, StorageError> {
+    let mut retries = 0_u32;
+    loop {
+        match commit() {
+            Err(err) if retries < retry_limit && storage_error_is_transient(&err) => {
+                retries = retries.saturating_add(1);
+                tracing::warn!("transient storage error, retry {retries}/{retry_limit}: {err:?}");
+            }
+            result => return result,
+        }
+    }
 }
 
 fn snapshot_sync_queue_counters(
@@ -21704,10 +27888,234 @@ fn snapshot_sync_queue_counters(
             }
         }
     }
-    counters.replay_due_count = store.device_artifact_sync_replay_due_rows(now).len() as u32;
+    let replay_due_rows = store.device_artifact_sync_replay_due_rows(now);
+    counters.replay_due_count = replay_due_rows.len() as u32;
+    counters.replay_due_oldest_at_ns = replay_due_rows
+        .iter()
+        .map(|row| row.lease_expires_at.unwrap_or(row.enqueued_at).0)
+        .min();
     counters
 }
 
+// Prometheus text exposition (format 0.0.4). Running totals are counters; queue snapshots and
+// values derived from them are gauges.
+fn render_sync_health_prometheus(sync: &AdapterSyncHealth) -> String {
+    let worker = &sync.worker;
+    let queue = &sync.queue;
+    let improvement = &sync.improvement;
+    let mut out = String::new();
+    for (name, help, value) in [
+        (
+            "sync_worker_passes_total",
+            "Sync worker passes run.",
+            worker.pass_count,
+        ),
+        (
+            "sync_dequeued_total",
+            "Sync jobs dequeued.",
+            worker.dequeued_total,
+        ),
+        ("sync_acked_total", "Sync jobs acked.", worker.acked_total),
+        (
+            "sync_retry_scheduled_total",
+            "Sync jobs scheduled for retry.",
+            worker.retry_scheduled_total,
+        ),
+        (
+            "sync_dead_lettered_total",
+            "Sync jobs moved to dead-letter.",
+            worker.dead_lettered_total,
+        ),
+        (
+            "feedback_events_emitted_total",
+            "Improvement feedback events emitted.",
+            improvement.feedback_events_emitted_total,
+        ),
+        (
+            "learn_artifacts_emitted_total",
+            "Improvement learn artifacts emitted.",
+            improvement.learn_artifacts_emitted_total,
+        ),
+        (
+            "builder_runs_total",
+            "Builder runs attempted.",
+            improvement.builder_runs_total,
+        ),
+        (
+            "builder_completed_total",
+            "Builder runs completed.",
+            improvement.builder_completed_total,
+        ),
+        (
+            "builder_refused_total",
+            "Builder runs refused.",
+            improvement.builder_refused_total,
+        ),
+        (
+            "builder_not_invoked_total",
+            "Builder runs not invoked.",
+            improvement.builder_not_invoked_total,
+        ),
+        (
+            "builder_errors_total",
+            "Builder runs that failed.",
+            improvement.builder_errors_total,
+        ),
+    ] {
+        push_prometheus_metric(
+            &mut out,
+            name,
+            "counter",
+            help,
+            &[(String::new(), value.to_string())],
+        );
+    }
+
+    let incidents = &improvement.read_only_incidents;
+    let by_kind = [
+        ("tool_fail", &incidents.tool_fail),
+        ("clarify_loop", &incidents.clarify_loop),
+        ("user_correction", &incidents.user_correction),
+    ];
+    type KindField = fn(&AdapterReadOnlyIncidentKindCounters) -> u64;
+    let kind_metrics: [(&str, &str, KindField); 4] = [
+        (
+            "read_only_incidents_total",
+            "Read-only lane incidents.",
+            |c| c.incidents_total,
+        ),
+        (
+            "read_only_incident_feedback_events_total",
+            "Feedback events emitted for read-only incidents.",
+            |c| c.feedback_events_emitted_total,
+        ),
+        (
+            "read_only_incident_learn_artifacts_total",
+            "Learn artifacts emitted for read-only incidents.",
+            |c| c.learn_artifacts_emitted_total,
+        ),
+        (
+            "read_only_incidents_suppressed_total",
+            "Read-only incidents suppressed inside the cooldown.",
+            |c| c.suppressed_total,
+        ),
+    ];
+    for (name, help, field) in kind_metrics {
+        let samples: Vec<(String, String)> = by_kind
+            .iter()
+            .map(|(kind, counters)| (format!("kind=\"{kind}\""), field(counters).to_string()))
+            .collect();
+        push_prometheus_metric(&mut out, name, "counter", help, &samples);
+    }
+
+    let empty = &improvement.empty_responses;
+    let empty_samples: Vec<(String, String)> = [
+        ("intentional", &empty.intentional_by_next_move),
+        ("unexpected", &empty.unexpected_by_next_move),
+    ]
+    .into_iter()
+    .flat_map(|(class, by_next_move)| {
+        by_next_move.iter().map(move |(next_move, count)| {
+            (
+                format!(
+                    "class=\"{class}\",next_move=\"{}\"",
+                    escape_prometheus_label_value(next_move)
+                ),
+                count.to_string(),
+            )
+        })
+    })
+    .collect();
+    push_prometheus_metric(
+        &mut out,
+        "empty_responses_total",
+        "counter",
+        "Turns that returned an empty response_text.",
+        &empty_samples,
+    );
+
+    let queue_depth = u64::from(queue.queued_count) + u64::from(queue.in_flight_count);
+    let completion_ratio = if improvement.builder_runs_total == 0 {
+        0.0
+    } else {
+        improvement.builder_completed_total as f64 / improvement.builder_runs_total as f64
+    };
+    for (name, help, value) in [
+        (
+            "sync_queue_queued",
+            "Sync jobs waiting in the queue.",
+            queue.queued_count.to_string(),
+        ),
+        (
+            "sync_queue_in_flight",
+            "Sync jobs leased to the worker.",
+            queue.in_flight_count.to_string(),
+        ),
+        (
+            "sync_queue_acked",
+            "Sync jobs acked and kept in the queue.",
+            queue.acked_count.to_string(),
+        ),
+        (
+            "sync_queue_dead_letter",
+            "Sync jobs in dead-letter.",
+            queue.dead_letter_count.to_string(),
+        ),
+        (
+            "sync_queue_replay_due",
+            "Sync jobs whose lease expired before an ack.",
+            queue.replay_due_count.to_string(),
+        ),
+        (
+            "sync_queue_retry_pending",
+            "In-flight sync jobs carrying a send error.",
+            queue.retry_pending_count.to_string(),
+        ),
+        (
+            "sync_queue_depth",
+            "Sync jobs not yet acked or dead-lettered.",
+            queue_depth.to_string(),
+        ),
+        (
+            "sync_last_backoff_ms",
+            "Retry delay chosen in the last worker pass.",
+            worker.last_backoff_ms.to_string(),
+        ),
+        (
+            "builder_completion_ratio",
+            "Completed builder runs over attempted runs; 0 before the first run.",
+            completion_ratio.to_string(),
+        ),
+    ] {
+        push_prometheus_metric(&mut out, name, "gauge", help, &[(String::new(), value)]);
+    }
+    out
+}
+
+fn push_prometheus_metric(
+    out: &mut String,
+    name: &str,
+    kind: &str,
+    help: &str,
+    samples: &[(String, String)],
+) {
+    out.push_str(&format!("# HELP selene_adapter_{name} {help}\n"));
+    out.push_str(&format!("# TYPE selene_adapter_{name} {kind}\n"));
+    for (labels, value) in samples {
+        if labels.is_empty() {
+            out.push_str(&format!("selene_adapter_{name} {value}\n"));
+        } else {
+            out.push_str(&format!("selene_adapter_{name}{{{labels}}} {value}\n"));
+        }
+    }
+}
+
+fn escape_prometheus_label_value(raw: &str) -> String {
+    raw.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 fn collect_sync_issue_records_for_pass(
     store: &Ph1fStore,
     now: MonotonicTimeNs,
@@ -21724,7 +28132,7 @@ fn collect_sync_issue_records_for_pass(
                 attempt_count: row.attempt_count,
                 last_error: row.last_error.clone(),
                 user_id: row.user_id.clone(),
-                device_id: row.device_id.clone(),
+                device_id: Some(row.device_id.clone()),
             });
             continue;
         }
@@ -21736,7 +28144,7 @@ fn collect_sync_issue_records_for_pass(
                 attempt_count: row.attempt_count,
                 last_error: row.last_error.clone(),
                 user_id: row.user_id.clone(),
-                device_id: row.device_id.clone(),
+                device_id: Some(row.device_id.clone()),
             });
         }
     }
@@ -21748,8 +28156,7 @@ fn collect_sync_issue_records_for_pass(
             attempt_count: queue_after.replay_due_count as u16,
             last_error: Some("replay_due".to_string()),
             user_id: None,
-            device_id: DeviceId::new("adapter_sync_aggregate_device")
-                .expect("static aggregate device id must be valid"),
+            device_id: None,
         });
     }
     out
@@ -21771,6 +28178,96 @@ fn sync_issue_latency_ms(kind: SyncIssueKind) -> u32 {
     }
 }
 
+const PH1K_FEEDBACK_BUILDER_ENTRY_LIMIT: usize = 64;
+const MANUAL_BUILDER_ENTRY_LIMIT: usize = 512;
+
+fn ph1k_feedback_outcome_type(kind: Ph1kFeedbackIssueKind) -> &'static str {
+    match kind {
+        Ph1kFeedbackIssueKind::FalseInterrupt => "VOICE_PH1K_FALSE_INTERRUPT",
+        Ph1kFeedbackIssueKind::MissedInterrupt => "VOICE_PH1K_MISSED_INTERRUPT",
+        Ph1kFeedbackIssueKind::WrongDegradationClassification => {
+            "VOICE_PH1K_WRONG_DEGRADATION_CLASS"
+        }
+        Ph1kFeedbackIssueKind::BadFailoverSelection => "VOICE_PH1K_BAD_FAILOVER_SELECTION",
+    }
+}
+
+// Builder input entries must carry the builder run's correlation and turn ids, so each
+// recent PH1.K feedback capture is restated under them. `tenant_id` is `None` for the sync
+// worker pass, which has no tenant and reads every tenant's captures.
+fn ph1k_feedback_builder_entries(
+    store: &Ph1fStore,
+    correlation_id: CorrelationId,
+    turn_id: TurnId,
+    window_start: MonotonicTimeNs,
+    tenant_id: Option<&str>,
+) -> Vec<OsOutcomeUtilizationEntry> {
+    let rows = store
+        .ph1k_feedback_capture_rows()
+        .iter()
+        .filter(|row| {
+            row.created_at.0 >= window_start.0
+                && tenant_id.is_none_or(|tenant| row.tenant_id == tenant)
+        })
+        .collect::<Vec<_>>();
+    rows[rows.len().saturating_sub(PH1K_FEEDBACK_BUILDER_ENTRY_LIMIT)..]
+        .iter()
+        .filter_map(|row| {
+            OsOutcomeUtilizationEntry::v1(
+                "PH1.K".to_string(),
+                ph1k_feedback_outcome_type(row.issue_kind).to_string(),
+                correlation_id,
+                turn_id,
+                OsOutcomeActionClass::QueueLearn,
+                "PH1.LEARN".to_string(),
+                0,
+                true,
+                row.reason_code,
+            )
+            .map_err(|err| {
+                tracing::error!("ph1k feedback builder entry build failed: {err:?}");
+            })
+            .ok()
+        })
+        .collect()
+}
+
+// Stored outcome rows in `[window_start_ns, window_end_ns]`, re-keyed to the manual run's
+// correlation and turn so they fit one builder input. Keeps the newest rows when over the cap.
+fn outcome_ledger_builder_entries(
+    store: &Ph1fStore,
+    correlation_id: CorrelationId,
+    turn_id: TurnId,
+    window_start_ns: u64,
+    window_end_ns: u64,
+) -> Vec<OsOutcomeUtilizationEntry> {
+    let rows = store
+        .outcome_utilization_ledger_rows()
+        .iter()
+        .filter(|row| row.created_at.0 >= window_start_ns && row.created_at.0 <= window_end_ns)
+        .collect::<Vec<_>>();
+    rows[rows.len().saturating_sub(MANUAL_BUILDER_ENTRY_LIMIT)..]
+        .iter()
+        .filter_map(|row| {
+            OsOutcomeUtilizationEntry::v1(
+                row.engine_id.clone(),
+                row.outcome_type.clone(),
+                correlation_id,
+                turn_id,
+                row.action_class,
+                row.consumed_by.clone(),
+                row.latency_cost_ms,
+                row.decision_delta,
+                row.reason_code,
+            )
+            .map_err(|err| {
+                tracing::error!("manual builder entry build failed: {err:?}");
+            })
+            .ok()
+        })
+        .collect()
+}
+
 fn artifact_type_for_sync_issue(kind: SyncIssueKind) -> ArtifactType {
     match kind {
         SyncIssueKind::Retry => ArtifactType::VoiceIdProfileDeltaPack,
@@ -21779,15 +28276,76 @@ fn artifact_type_for_sync_issue(kind: SyncIssueKind) -> ArtifactType {
     }
 }
 
-fn tenant_scope_from_user_id(user_id: &UserId) -> Option<&str> {
-    let (tenant_scope, _) = user_id.as_str().split_once(':')?;
+const BUILDER_ARTIFACT_TYPES: [ArtifactType; 10] = [
+    ArtifactType::WakePack,
+    ArtifactType::EmoAffectPack,
+    ArtifactType::EmoPolicyPack,
+    ArtifactType::SttRoutingPolicyPack,
+    ArtifactType::SttAdaptationProfile,
+    ArtifactType::TtsRoutingPolicyPack,
+    ArtifactType::VoiceIdThresholdPack,
+    ArtifactType::VoiceIdConfusionPairPack,
+    ArtifactType::VoiceIdSpoofPolicyPack,
+    ArtifactType::VoiceIdProfileDeltaPack,
+];
+
+fn artifact_type_label(artifact_type: ArtifactType) -> &'static str {
+    match artifact_type {
+        ArtifactType::WakePack => "WAKE_PACK",
+        ArtifactType::EmoAffectPack => "EMO_AFFECT_PACK",
+        ArtifactType::EmoPolicyPack => "EMO_POLICY_PACK",
+        ArtifactType::SttVocabPack => "STT_VOCAB_PACK",
+        ArtifactType::SttRoutingPolicyPack => "STT_ROUTING_POLICY_PACK",
+        ArtifactType::SttAdaptationProfile => "STT_ADAPTATION_PROFILE",
+        ArtifactType::TtsPronunciationPack => "TTS_PRONUNCIATION_PACK",
+        ArtifactType::TtsRoutingPolicyPack => "TTS_ROUTING_POLICY_PACK",
+        ArtifactType::VoiceIdThresholdPack => "VOICE_ID_THRESHOLD_PACK",
+        ArtifactType::VoiceIdConfusionPairPack => "VOICE_ID_CONFUSION_PAIR_PACK",
+        ArtifactType::VoiceIdSpoofPolicyPack => "VOICE_ID_SPOOF_POLICY_PACK",
+        ArtifactType::VoiceIdProfileDeltaPack => "VOICE_ID_PROFILE_DELTA_PACK",
+    }
+}
+
+fn parse_builder_artifact_type(raw: &str) -> Option<ArtifactType> {
+    let raw = raw.trim();
+    BUILDER_ARTIFACT_TYPES
+        .into_iter()
+        .find(|artifact_type| artifact_type_label(*artifact_type).eq_ignore_ascii_case(raw))
+}
+
+fn artifact_status_label(status: ArtifactStatus) -> &'static str {
+    match status {
+        ArtifactStatus::Active => "ACTIVE",
+        ArtifactStatus::RolledBack => "ROLLED_BACK",
+        ArtifactStatus::Deprecated => "DEPRECATED",
+    }
+}
+
+const TENANT_DELIMITER_DEFAULT: char = ':';
+
+// The delimiter must be one non-alphanumeric, non-whitespace char; anything else keeps `:`.
+fn parse_tenant_delimiter(raw: &str) -> Option<char> {
+    let mut chars = raw.trim().chars();
+    let delimiter = chars.next()?;
+    if chars.next().is_some() || delimiter.is_alphanumeric() || delimiter.is_whitespace() {
+        return None;
+    }
+    Some(delimiter)
+}
+
+fn tenant_scope_from_user_id(user_id: &UserId, delimiter: char) -> Option<&str> {
+    let (tenant_scope, _) = user_id.as_str().split_once(delimiter)?;
     if tenant_scope.trim().is_empty() {
         return None;
     }
     Some(tenant_scope)
 }
 
-fn sanitize_idempotency_token(value: &str) -> String {
+const IDEMPOTENCY_TOKEN_MAX_LEN_DEFAULT: u32 = 128;
+const IDEMPOTENCY_TOKEN_MAX_LEN_MIN: u32 = 32;
+const IDEMPOTENCY_TOKEN_HASH_SUFFIX_LEN: usize = 16;
+
+fn sanitize_idempotency_token(value: &str, max_len: usize) -> String {
     let mut out = String::with_capacity(value.len());
     for c in value.chars() {
         if c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.') {
@@ -21797,10 +28355,19 @@ fn sanitize_idempotency_token(value: &str) -> String {
         }
     }
     if out.is_empty() {
-        "sync_idem".to_string()
-    } else {
-        truncate_ascii(&out, 128)
+        return "sync_idem".to_string();
     }
+    if out.len() <= max_len {
+        return out;
+    }
+    // Hash the raw input so tokens sharing a long prefix stay distinct after truncation.
+    let suffix = sha256_hex_for_build1c(value);
+    let prefix_len = max_len.saturating_sub(IDEMPOTENCY_TOKEN_HASH_SUFFIX_LEN + 1);
+    format!(
+        "{}_{}",
+        truncate_ascii(&out, prefix_len),
+        &suffix[..IDEMPOTENCY_TOKEN_HASH_SUFFIX_LEN]
+    )
 }
 
 fn resolve_adapter_thread_key(value: Option<&str>) -> String {
@@ -21917,7 +28484,8 @@ fn commit_stage34m_activation_only_wake_session_audit(
     session_id: Option<SessionId>,
     tenant_id: Option<&str>,
     wake_evaluation: Option<&WakeEvaluation>,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let Some(session_id) = session_id else {
         return Ok(());
     };
@@ -21961,15 +28529,18 @@ fn commit_stage34m_activation_only_wake_session_audit(
         turn_id,
         payload,
         None,
-        Some(sanitize_idempotency_token(&format!(
-            "stage34m_activation_only_session_open:{}:{}:{}",
-            correlation_id.0, turn_id.0, session_id.0
-        ))),
+        Some(sanitize_idempotency_token(
+            &format!(
+                "stage34m_activation_only_session_open:{}:{}:{}",
+                correlation_id.0, turn_id.0, session_id.0
+            ),
+            idempotency_token_max_len,
+        )),
     )
     .map_err(|err| format!("invalid stage34m activation-only audit event: {err:?}"))?;
     store
         .append_audit_row(input)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -22491,6 +29062,10 @@ fn wake_guest_lane_response(
         deep_research: None,
         screen_lifecycle_action: None,
         session_lifecycle_action: None,
+        stt_mode: String::new(),
+        keep_listening: false,
+        next_move_kind: None,
+        outcome_kind: None,
     }
 }
 
@@ -22633,6 +29208,7 @@ fn display_name_from_confirmed_user_id(user_id: &UserId) -> Option<String> {
 fn activation_handoff_enrolled_speakers(
     store: &Ph1fStore,
     tenant_scope: Option<&str>,
+    tenant_delimiter: char,
 ) -> Result<Vec<EngineEnrolledSpeaker>, String> {
     let mut enrolled = Vec::new();
     for profile in store.ph1vid_voice_profile_rows() {
@@ -22640,7 +29216,8 @@ fn activation_handoff_enrolled_speakers(
             continue;
         };
         if let Some(tenant_scope) = tenant_scope {
-            let Some((profile_tenant, _)) = device.user_id.as_str().split_once(':') else {
+            let Some(profile_tenant) = tenant_scope_from_user_id(&device.user_id, tenant_delimiter)
+            else {
                 continue;
             };
             if profile_tenant != tenant_scope {
@@ -22674,6 +29251,7 @@ fn activation_handoff_enrolled_speakers(
 fn activation_handoff_voice_id_observation(
     store: &Ph1fStore,
     tenant_scope: Option<&str>,
+    tenant_delimiter: char,
     actor_user_id: &UserId,
     device_id: &DeviceId,
     base_observation: EngineVoiceIdObservation,
@@ -22700,7 +29278,9 @@ fn activation_handoff_voice_id_observation(
                 return false;
             }
             if let Some(tenant_scope) = tenant_scope {
-                let Some((profile_tenant, _)) = device.user_id.as_str().split_once(':') else {
+                let Some(profile_tenant) =
+                    tenant_scope_from_user_id(&device.user_id, tenant_delimiter)
+                else {
                     return false;
                 };
                 if profile_tenant != tenant_scope {
@@ -22732,18 +29312,20 @@ fn activation_handoff_voice_id_observation(
 fn activation_handoff_voice_id_posture(
     store: &Ph1fStore,
     tenant_scope: Option<&str>,
+    tenant_delimiter: char,
     actor_user_id: &UserId,
     device_id: &DeviceId,
     voice_id_request: &Ph1VoiceIdRequest,
     base_observation: EngineVoiceIdObservation,
 ) -> Result<Option<WakeVoiceIdGreetingPosture>, String> {
-    let enrolled = activation_handoff_enrolled_speakers(store, tenant_scope)?;
+    let enrolled = activation_handoff_enrolled_speakers(store, tenant_scope, tenant_delimiter)?;
     if enrolled.is_empty() {
         return Ok(None);
     }
     let observation = activation_handoff_voice_id_observation(
         store,
         tenant_scope,
+        tenant_delimiter,
         actor_user_id,
         device_id,
         base_observation,
@@ -22751,7 +29333,10 @@ fn activation_handoff_voice_id_posture(
     if observation.primary_fingerprint.is_none() && observation.primary_embedding.is_none() {
         return Ok(None);
     }
-    let mut runtime = EngineVoiceIdRuntime::new(EngineVoiceIdConfig::mvp_v1(), enrolled)
+    // Match the live Voice ID runtime: an untrusted device does not force step-up here either.
+    let mut config = EngineVoiceIdConfig::mvp_v1();
+    config.require_reauth_on_untrusted_device = false;
+    let mut runtime = EngineVoiceIdRuntime::new(config, enrolled)
         .map_err(|err| format!("activation handoff Voice ID runtime invalid: {err:?}"))?;
     let assertion = runtime.run(voice_id_request, observation);
     let Ph1VoiceIdResponse::SpeakerAssertionOk(ok) = assertion else {
@@ -22778,6 +29363,7 @@ fn activation_handoff_voice_id_posture(
 fn continuing_speech_identity_prompt_response(
     store: &Ph1fStore,
     tenant_scope: Option<&str>,
+    tenant_delimiter: char,
     actor_user_id: &UserId,
     device_id: &DeviceId,
     runtime_execution_envelope: &RuntimeExecutionEnvelope,
@@ -22807,6 +29393,7 @@ fn continuing_speech_identity_prompt_response(
     if activation_handoff_voice_id_posture(
         store,
         tenant_scope,
+        tenant_delimiter,
         actor_user_id,
         device_id,
         voice_id_request,
@@ -22848,6 +29435,10 @@ fn continuing_speech_identity_prompt_response(
         deep_research: None,
         screen_lifecycle_action: None,
         session_lifecycle_action: None,
+        stt_mode: String::new(),
+        keep_listening: false,
+        next_move_kind: None,
+        outcome_kind: None,
     }))
 }
 
@@ -22892,6 +29483,10 @@ fn activation_greeting_handoff_response(
         deep_research: None,
         screen_lifecycle_action: None,
         session_lifecycle_action: None,
+        stt_mode: String::new(),
+        keep_listening: false,
+        next_move_kind: None,
+        outcome_kind: None,
     }
 }
 
@@ -23117,10 +29712,10 @@ fn evaluate_wake_for_turn(
     actor_user_id: &UserId,
     device_id: &DeviceId,
     app_platform: AppPlatform,
-    trigger: OsVoiceTrigger,
+    requires_session_open_step: bool,
     ph1k: &Ph1kLiveSignalBundle,
 ) -> Result<Option<WakeEvaluation>, String> {
-    if trigger != OsVoiceTrigger::WakeWord {
+    if !requires_session_open_step {
         return Ok(None);
     }
     if app_platform == AppPlatform::Ios {
@@ -23165,7 +29760,8 @@ fn commit_wake_runtime_event(
     session_id: Option<SessionId>,
     ph1k: &Ph1kLiveSignalBundle,
     wake_eval: &WakeEvaluation,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let decision = &wake_eval.decision;
     let wake_event_id = format!(
         "wake_evt_{}_{}_{}",
@@ -23177,13 +29773,16 @@ fn commit_wake_runtime_event(
             "reject"
         }
     );
-    let idempotency_key = sanitize_idempotency_token(&format!(
-        "adapter_wake_runtime:{}:{}:{}:{}",
-        correlation_id.0,
-        turn_id.0,
-        if decision.accepted { 1 } else { 0 },
-        decision.reason_code.0
-    ));
+    let idempotency_key = sanitize_idempotency_token(
+        &format!(
+            "adapter_wake_runtime:{}:{}:{}:{}",
+            correlation_id.0,
+            turn_id.0,
+            if decision.accepted { 1 } else { 0 },
+            decision.reason_code.0
+        ),
+        idempotency_token_max_len,
+    );
     store
         .ph1w_runtime_event_commit(
             now,
@@ -23205,7 +29804,7 @@ fn commit_wake_runtime_event(
             Some(wake_eval.window_end_ns),
             idempotency_key,
         )
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -23262,7 +29861,8 @@ fn commit_wake_learn_signal(
     trigger: OsVoiceTrigger,
     ph1k: &Ph1kLiveSignalBundle,
     wake_eval: &WakeEvaluation,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let decision = &wake_eval.decision;
     let event_type = map_wake_decision_to_learn_signal_type(decision, wake_eval.threshold_used_bp);
     let signal_id = format!(
@@ -23275,10 +29875,13 @@ fn commit_wake_learn_signal(
             "reject"
         }
     );
-    let idempotency_key = sanitize_idempotency_token(&format!(
-        "adapter_wake_learn:{}:{}:{}:{}",
-        correlation_id.0, turn_id.0, decision.reason_code.0, wake_eval.window_start_ns.0
-    ));
+    let idempotency_key = sanitize_idempotency_token(
+        &format!(
+            "adapter_wake_learn:{}:{}:{}:{}",
+            correlation_id.0, turn_id.0, decision.reason_code.0, wake_eval.window_start_ns.0
+        ),
+        idempotency_token_max_len,
+    );
     let wake_window_id = wake_window_id_for_signal(device_id, wake_eval);
     let score_bp = confidence_to_basis_points(decision.strong_score)
         .or(confidence_to_basis_points(decision.light_score));
@@ -23315,7 +29918,7 @@ fn commit_wake_learn_signal(
     .map_err(|err| format!("wake learn signal contract invalid: {err:?}"))?;
     store
         .wake_learn_signal_commit_and_enqueue(now, signal)
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -23325,11 +29928,14 @@ fn resolve_session_turn_state(
     correlation_id: CorrelationId,
     turn_id: TurnId,
     input: ResolveSessionTurnStateInput<'_>,
-) -> Result<AdapterSessionResolution, String> {
+    idempotency_token_max_len: usize,
+) -> Result<AdapterSessionResolution, AdapterError> {
     let ResolveSessionTurnStateInput {
         actor_user_id,
         device_id,
         trigger,
+        app_platform,
+        wake_gate_policy,
         ph1k,
         wake_event,
         idempotency_key,
@@ -23356,18 +29962,23 @@ fn resolve_session_turn_state(
         store
             .upsert_session_lifecycle(
                 closed,
-                Some(sanitize_idempotency_token(&format!(
-                    "adapter_session_legacy_soft_closed_seal:{}:{}",
-                    correlation_id.0, turn_id.0
-                ))),
+                Some(sanitize_idempotency_token(
+                    &format!(
+                        "adapter_session_legacy_soft_closed_seal:{}:{}",
+                        correlation_id.0, turn_id.0
+                    ),
+                    idempotency_token_max_len,
+                )),
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
         selection = canonical_actor_session_selection(store, actor_user_id)?;
     }
     if let Some(existing) = selection.latest_recoverable.as_ref() {
         match existing.device_turn_sequence_for(device_id) {
             Some(previous_sequence) if device_turn_sequence < previous_sequence => {
-                return Err("session_conflict stale_device_turn".to_string());
+                return Err(AdapterError::Validation(
+                    "session_conflict stale_device_turn".to_string(),
+                ));
             }
             Some(previous_sequence) if device_turn_sequence == previous_sequence => {
                 if existing.last_idempotency_key_for(device_id) == Some(idempotency_key) {
@@ -23379,7 +29990,9 @@ fn resolve_session_turn_state(
                     .ok_or_else(|| "retry_result_missing".to_string())?;
                     return Ok(AdapterSessionResolution::Retry(response));
                 }
-                return Err("session_conflict duplicate_device_turn_sequence".to_string());
+                return Err(AdapterError::Validation(
+                    "session_conflict duplicate_device_turn_sequence".to_string(),
+                ));
             }
             _ => {}
         }
@@ -23392,6 +30005,7 @@ fn resolve_session_turn_state(
             existing,
             runtime_node_id,
             session_lease_ttl_ms,
+            idempotency_token_max_len,
         )?;
     }
     let next_session_id_seed = store
@@ -23453,16 +30067,19 @@ fn resolve_session_turn_state(
                 runtime_node_id,
                 session_lease_ttl_ms,
                 false,
+                idempotency_token_max_len,
             )?;
         }
     }
 
-    let ph1l_turn_trigger = ph1l_turn_trigger_from_os(trigger);
-    let wake_event = if trigger_requires_session_open_step(ph1l_turn_trigger) {
-        wake_event
-    } else {
-        None
-    };
+    // The wake-gate policy decides whether this turn takes the PH1.L wake open step; a turn
+    // that skips it steps the lifecycle like an explicit activation.
+    let (ph1l_turn_trigger, wake_event) =
+        if wake_gate_policy.requires_session_open_step(trigger, app_platform) {
+            (Ph1lTurnTrigger::WakeWord, wake_event)
+        } else {
+            (Ph1lTurnTrigger::Explicit, None)
+        };
     let active_prev_session_id = lifecycle.session_id();
     let active_out = ph1l_step_voice_turn(
         &mut lifecycle,
@@ -23492,6 +30109,7 @@ fn resolve_session_turn_state(
         runtime_node_id,
         session_lease_ttl_ms,
         true,
+        idempotency_token_max_len,
     )?;
     let session_id_for_commits = if active_out.snapshot.session_state == SessionState::Closed {
         active_prev_session_id
@@ -23581,10 +30199,10 @@ fn cached_retry_response_for_actor(
     session_retry_cache: &Arc<Mutex<BTreeMap<AdapterRetryCacheKey, VoiceTurnAdapterResponse>>>,
     actor_user_id: &UserId,
     idempotency_key: &str,
-) -> Result<Option<VoiceTurnAdapterResponse>, String> {
+) -> Result<Option<VoiceTurnAdapterResponse>, AdapterError> {
     let cache = session_retry_cache
         .lock()
-        .map_err(|_| "adapter retry cache lock poisoned".to_string())?;
+        .map_err(|_| AdapterError::LockPoisoned("retry cache".to_string()))?;
     Ok(cache
         .get(&AdapterRetryCacheKey {
             actor_user_id: actor_user_id.clone(),
@@ -23602,10 +30220,10 @@ fn cache_authoritative_turn_response(
     actor_user_id: &UserId,
     idempotency_key: &str,
     response: &VoiceTurnAdapterResponse,
-) -> Result<(), String> {
+) -> Result<(), AdapterError> {
     let mut cache = session_retry_cache
         .lock()
-        .map_err(|_| "adapter retry cache lock poisoned".to_string())?;
+        .map_err(|_| AdapterError::LockPoisoned("retry cache".to_string()))?;
     cache.insert(
         AdapterRetryCacheKey {
             actor_user_id: actor_user_id.clone(),
@@ -23626,15 +30244,20 @@ fn acquire_or_refresh_session_lease(
     session_record: &SessionRecord,
     runtime_node_id: &str,
     session_lease_ttl_ms: u64,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let mut leased_record = session_record.clone();
     if leased_record.lease_is_active_at(now) {
         if leased_record.lease_owner_id.as_deref() != Some(runtime_node_id) {
-            return Err("session_conflict session_lease_conflict".to_string());
+            return Err(AdapterError::Validation(
+                "session_conflict session_lease_conflict".to_string(),
+            ));
         }
         if let Some(active_turn_id) = leased_record.active_turn_id {
             if active_turn_id != turn_id {
-                return Err("session_conflict session_single_writer_conflict".to_string());
+                return Err(AdapterError::Validation(
+                    "session_conflict session_single_writer_conflict".to_string(),
+                ));
             }
         }
     }
@@ -23647,12 +30270,15 @@ fn acquire_or_refresh_session_lease(
     store
         .upsert_session_lifecycle(
             leased_record,
-            Some(sanitize_idempotency_token(&format!(
-                "adapter_session_lease:{}:{}:{}",
-                correlation_id.0, turn_id.0, session_record.session_id.0
-            ))),
+            Some(sanitize_idempotency_token(
+                &format!(
+                    "adapter_session_lease:{}:{}:{}",
+                    correlation_id.0, turn_id.0, session_record.session_id.0
+                ),
+                idempotency_token_max_len,
+            )),
         )
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -23669,7 +30295,8 @@ fn finalize_session_turn_record(
     idempotency_key: &str,
     runtime_node_id: &str,
     session_lease_ttl_ms: u64,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let Some(session_id) = session_id else {
         return Ok(());
     };
@@ -23705,12 +30332,15 @@ fn finalize_session_turn_record(
     store
         .upsert_session_lifecycle(
             record,
-            Some(sanitize_idempotency_token(&format!(
-                "adapter_session_finalize:{}:{}:{}",
-                correlation_id.0, turn_id.0, session_id.0
-            ))),
+            Some(sanitize_idempotency_token(
+                &format!(
+                    "adapter_session_finalize:{}:{}:{}",
+                    correlation_id.0, turn_id.0, session_id.0
+                ),
+                idempotency_token_max_len,
+            )),
         )
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -23728,7 +30358,8 @@ fn persist_session_snapshot(
     runtime_node_id: &str,
     session_lease_ttl_ms: u64,
     hold_active_turn: bool,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let session_id = if out.snapshot.session_state == SessionState::Closed {
         previous_session_id
     } else {
@@ -23777,12 +30408,15 @@ fn persist_session_snapshot(
     store
         .upsert_session_lifecycle(
             record,
-            Some(sanitize_idempotency_token(&format!(
-                "adapter_session:{}:{}:{}:{}",
-                correlation_id.0, turn_id.0, stage, session_id.0
-            ))),
+            Some(sanitize_idempotency_token(
+                &format!(
+                    "adapter_session:{}:{}:{}:{}",
+                    correlation_id.0, turn_id.0, stage, session_id.0
+                ),
+                idempotency_token_max_len,
+            )),
         )
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
@@ -23799,7 +30433,8 @@ fn close_stage6_session_for_actor(
     idempotency_key: &str,
     runtime_node_id: &str,
     session_lease_ttl_ms: u64,
-) -> Result<Option<SessionState>, String> {
+    idempotency_token_max_len: usize,
+) -> Result<Option<SessionState>, AdapterError> {
     let selection = canonical_actor_session_selection(store, actor_user_id)?;
     let target_session_id = session_id_for_commits.or_else(|| {
         selection
@@ -23810,6 +30445,52 @@ fn close_stage6_session_for_actor(
     let Some(target_session_id) = target_session_id else {
         return Ok(None);
     };
+    let (previous_session_id, session_state) = step_stage6_session_closed(
+        store,
+        now,
+        correlation_id,
+        turn_id,
+        actor_user_id,
+        device_id,
+        target_session_id,
+        "manual_close",
+        runtime_node_id,
+        session_lease_ttl_ms,
+        idempotency_token_max_len,
+    )?;
+    finalize_session_turn_record(
+        store,
+        now,
+        correlation_id,
+        turn_id,
+        device_id,
+        previous_session_id,
+        None,
+        device_turn_sequence,
+        idempotency_key,
+        runtime_node_id,
+        session_lease_ttl_ms,
+        idempotency_token_max_len,
+    )?;
+    Ok(Some(session_state))
+}
+
+// Steps PH1.L for `target_session_id` with an explicit close and persists the resulting
+// snapshot. Returns the session id PH1.L held before the step and the new session state.
+#[allow(clippy::too_many_arguments)]
+fn step_stage6_session_closed(
+    store: &mut Ph1fStore,
+    now: MonotonicTimeNs,
+    correlation_id: CorrelationId,
+    turn_id: TurnId,
+    actor_user_id: &UserId,
+    device_id: &DeviceId,
+    target_session_id: SessionId,
+    stage: &str,
+    runtime_node_id: &str,
+    session_lease_ttl_ms: u64,
+    idempotency_token_max_len: usize,
+) -> Result<(Option<SessionId>, SessionState), AdapterError> {
     let starting_state = store
         .get_session(&target_session_id)
         .map(|record| record.session_state)
@@ -23855,25 +30536,13 @@ fn close_stage6_session_for_actor(
         device_id,
         previous_session_id,
         &out,
-        "manual_close",
+        stage,
         runtime_node_id,
         session_lease_ttl_ms,
         false,
+        idempotency_token_max_len,
     )?;
-    finalize_session_turn_record(
-        store,
-        now,
-        correlation_id,
-        turn_id,
-        device_id,
-        previous_session_id,
-        None,
-        device_turn_sequence,
-        idempotency_key,
-        runtime_node_id,
-        session_lease_ttl_ms,
-    )?;
-    Ok(Some(out.snapshot.session_state))
+    Ok((previous_session_id, out.snapshot.session_state))
 }
 
 #[derive(Debug, Clone)]
@@ -23895,7 +30564,8 @@ fn idle_close_stage6_session_for_actor(
     tts_playback_active: bool,
     runtime_node_id: &str,
     session_lease_ttl_ms: u64,
-) -> Result<Option<Stage6IdleCloseCheckOutcome>, String> {
+    idempotency_token_max_len: usize,
+) -> Result<Option<Stage6IdleCloseCheckOutcome>, AdapterError> {
     let selection = canonical_actor_session_selection(store, actor_user_id)?;
     let Some(record) = selection.latest_recoverable else {
         return Ok(None);
@@ -23961,6 +30631,7 @@ fn idle_close_stage6_session_for_actor(
             runtime_node_id,
             session_lease_ttl_ms,
             false,
+            idempotency_token_max_len,
         )?;
         return Ok(Some(Stage6IdleCloseCheckOutcome {
             session_id: record.session_id,
@@ -24001,11 +30672,89 @@ fn load_ph1x_thread_state(
         .unwrap_or_else(ThreadState::empty_v1)
 }
 
+fn load_ph1x_thread_state_cached<S: Ph1xThreadStateSource + ?Sized>(
+    cache: &Arc<Mutex<AdapterPh1xThreadStateCache>>,
+    source: &S,
+    actor_user_id: &UserId,
+    thread_key: &str,
+) -> ThreadState {
+    let Ok(mut cache) = cache.lock() else {
+        return source.ph1x_thread_state(actor_user_id, thread_key);
+    };
+    cache.sync_with_store(source.ph1x_thread_state_ledger_len());
+    if let Some(thread_state) = cache.get(actor_user_id, thread_key) {
+        return thread_state;
+    }
+    let thread_state = source.ph1x_thread_state(actor_user_id, thread_key);
+    cache.insert(actor_user_id, thread_key, thread_state.clone());
+    thread_state
+}
+
+const PH1X_MEMORY_CANDIDATE_LIMIT_MAX: usize = 32;
+
+fn load_ph1x_memory_candidates(
+    store: &Ph1fStore,
+    actor_user_id: &UserId,
+    now: MonotonicTimeNs,
+    limit: usize,
+    privacy_mode: bool,
+) -> Vec<MemoryCandidate> {
+    if limit == 0 || privacy_mode {
+        return Vec::new();
+    }
+    let mut records = store
+        .memory_current()
+        .values()
+        .filter(|record| {
+            &record.user_id == actor_user_id
+                && record.active
+                && record.memory_value.is_some()
+                && record
+                    .expires_at
+                    .is_none_or(|expires_at| expires_at.0 > now.0)
+                && record.sensitivity_flag != MemorySensitivityFlag::Sensitive
+                && record.use_policy != MemoryUsePolicy::UserRequestedOnly
+        })
+        .collect::<Vec<_>>();
+    records.sort_by_key(|record| std::cmp::Reverse(record.last_seen_at.0));
+    records
+        .into_iter()
+        .filter_map(|record| {
+            let memory_value = record.memory_value.clone()?;
+            let evidence_quote = store
+                .memory_ledger_rows()
+                .iter()
+                .rev()
+                .find(|row| {
+                    &row.user_id == actor_user_id && row.event.memory_key == record.memory_key
+                })
+                .and_then(|row| row.event.evidence_quote.clone())
+                .filter(|quote| !quote.trim().is_empty())
+                .unwrap_or_else(|| truncate_ascii(&memory_value.verbatim, 256));
+            MemoryCandidate::v1(
+                record.memory_key.clone(),
+                memory_value,
+                record.confidence,
+                record.last_seen_at,
+                evidence_quote,
+                record.provenance.clone(),
+                record.sensitivity_flag,
+                record.use_policy,
+                record.expires_at,
+            )
+            .ok()
+        })
+        .take(limit)
+        .collect()
+}
+
 fn persist_ph1x_thread_state(
     store: &mut Ph1fStore,
+    cache: &Arc<Mutex<AdapterPh1xThreadStateCache>>,
     now: MonotonicTimeNs,
     input: PersistPh1xThreadStateInput<'_>,
-) -> Result<(), String> {
+    idempotency_token_max_len: usize,
+) -> Result<(), AdapterError> {
     let PersistPh1xThreadStateInput {
         actor_user_id,
         thread_key,
@@ -24014,20 +30763,34 @@ fn persist_ph1x_thread_state(
         correlation_id,
         turn_id,
     } = input;
-    let idempotency_key = sanitize_idempotency_token(&format!(
-        "adapter_ph1x_thread_state:{}:{}:{}",
-        correlation_id.0, turn_id.0, thread_key
-    ));
+    let idempotency_key = sanitize_idempotency_token(
+        &format!(
+            "adapter_ph1x_thread_state:{}:{}:{}",
+            correlation_id.0, turn_id.0, thread_key
+        ),
+        idempotency_token_max_len,
+    );
+    let ledger_len_before = store.ph1x_thread_state_ledger_len();
     let _ = store
         .ph1x_thread_state_upsert_commit(
             now,
             actor_user_id.clone(),
             thread_key.to_string(),
-            thread_state,
+            thread_state.clone(),
             reason_code,
             idempotency_key,
         )
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
+    cache
+        .lock()
+        .map_err(|_| AdapterError::LockPoisoned("ph1x thread state cache".to_string()))?
+        .record_write(
+            actor_user_id,
+            thread_key,
+            thread_state,
+            ledger_len_before,
+            store.ph1x_thread_state_ledger_len(),
+        );
     Ok(())
 }
 
@@ -24048,6 +30811,13 @@ fn stable_hash_hex_16(value: &str) -> String {
     format!("{:016x}", hasher.finish())
 }
 
+// Retries may carry a fresh `now_ns`, so it is left out.
+fn turn_replay_fingerprint(request: &VoiceTurnAdapterRequest) -> String {
+    let mut request = request.clone();
+    request.now_ns = None;
+    stable_hash_hex_16(&serde_json::to_string(&request).unwrap_or_default())
+}
+
 fn stable_voice_profile_seed_u64(parts: &[&str]) -> u64 {
     const OFFSET: u64 = 0xcbf29ce484222325;
     const PRIME: u64 = 0x100000001b3;
@@ -24087,12 +30857,15 @@ fn parse_u64_env(key: &str, default: u64) -> u64 {
         .unwrap_or(default)
 }
 
+const RUNTIME_NODE_ID_DEFAULT: &str = "adapter_runtime_node_v1";
+const SESSION_LEASE_TTL_MS_DEFAULT: u64 = 30_000;
+
 fn runtime_node_id_from_env() -> String {
     env::var("SELENE_RUNTIME_NODE_ID")
         .ok()
         .map(|value| truncate_ascii(value.trim(), 128))
         .filter(|value| !value.is_empty())
-        .unwrap_or_else(|| "adapter_runtime_node_v1".to_string())
+        .unwrap_or_else(|| RUNTIME_NODE_ID_DEFAULT.to_string())
 }
 
 fn build_ph1d_live_adapter_from_env() -> Option<EnvPh1dLiveAdapter> {
@@ -24103,7 +30876,7 @@ fn build_ph1d_live_adapter_from_env() -> Option<EnvPh1dLiveAdapter> {
     match EnvPh1dLiveAdapter::from_env() {
         Ok(adapter) => Some(adapter),
         Err(err) => {
-            eprintln!("selene_adapter ph1d live adapter bootstrap failed: {err:?}");
+            tracing::error!("ph1d live adapter bootstrap failed: {err:?}");
             None
         }
     }
@@ -24117,17 +30890,60 @@ fn resolve_tenant_scope(
     explicit_tenant_id: Option<String>,
     actor_user_id: &UserId,
     device_id: Option<&DeviceId>,
+    tenant_delimiter: char,
 ) -> Option<String> {
     explicit_tenant_id
         .map(|v| truncate_ascii(v.trim(), 64))
         .filter(|v| !v.is_empty())
-        .or_else(|| tenant_scope_from_user_id(actor_user_id).map(str::to_string))
+        .or_else(|| tenant_scope_from_user_id(actor_user_id, tenant_delimiter).map(str::to_string))
         .or_else(|| {
             device_id
                 .map(|d| truncate_ascii(&format!("tenant_{}", stable_hash_hex_16(d.as_str())), 64))
         })
 }
 
+fn parse_tenant_rate_limit_policy_from_env() -> Option<AdapterTenantRateLimitPolicy> {
+    let refill_per_sec = parse_u32_env("SELENE_ADAPTER_TENANT_RATE_LIMIT_PER_SEC", 0, 100_000)
+        .filter(|rate| *rate > 0)?;
+    let burst = parse_u32_env("SELENE_ADAPTER_TENANT_RATE_LIMIT_BURST", 1, 100_000)
+        .unwrap_or(refill_per_sec);
+    Some(AdapterTenantRateLimitPolicy::new(refill_per_sec, burst))
+}
+
+const CLOCK_SKEW_DEFAULT_TOLERANCE_MS: u64 = 300_000;
+
+fn parse_clock_skew_policy_from_env() -> AdapterClockSkewPolicy {
+    parse_clock_skew_policy(
+        env::var("SELENE_ADAPTER_CLOCK_SKEW_POLICY").ok().as_deref(),
+        parse_u64_env(
+            "SELENE_ADAPTER_CLOCK_SKEW_TOLERANCE_MS",
+            CLOCK_SKEW_DEFAULT_TOLERANCE_MS,
+        ),
+        parse_bool_env("SELENE_ADAPTER_DETERMINISTIC_TIME", false),
+    )
+}
+
+fn parse_clock_skew_policy(
+    action: Option<&str>,
+    tolerance_ms: u64,
+    deterministic_time: bool,
+) -> AdapterClockSkewPolicy {
+    let action = match action.map(|raw| raw.trim().to_ascii_uppercase()).as_deref() {
+        Some("CLAMP") => AdapterClockSkewAction::Clamp,
+        Some("REJECT") => AdapterClockSkewAction::Reject,
+        _ => AdapterClockSkewAction::Off,
+    };
+    AdapterClockSkewPolicy::new(action, tolerance_ms, deterministic_time)
+}
+
+const DEFAULT_FALLBACK_TENANT_ID: &str = "tenant_default";
+
+fn fallback_tenant_id_from(raw: Option<&str>) -> String {
+    raw.map(|value| truncate_ascii(value.trim(), 64))
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| DEFAULT_FALLBACK_TENANT_ID.to_string())
+}
+
 fn parse_device_route_label(value: &str) -> Option<DeviceRoute> {
     match value.trim().to_ascii_uppercase().as_str() {
         "BUILT_IN" | "BUILTIN" => Some(DeviceRoute::BuiltIn),
@@ -24168,26 +30984,118 @@ fn locale_matches(lhs: &str, rhs: &str) -> bool {
             == rhs_key.split('-').next().unwrap_or(rhs_key.as_str())
 }
 
+// A client capture's own locale is explicit. Without one, the device's last PH1.C detection
+// is inherited; the adapter-synthesized desktop capture only carries a default guess.
 fn resolve_interrupt_locale_tag_from_capture(
     capture: &VoiceTurnAudioCaptureRef,
-) -> Result<InterruptLocaleTag, String> {
-    let raw = capture
+    synthesized_capture: bool,
+    prior_detected_locale: Option<&str>,
+) -> Result<(InterruptLocaleTag, Ph1cLocaleSource), String> {
+    let capture_locale = capture
         .locale_tag
         .as_deref()
         .map(str::trim)
+        .filter(|v| !v.is_empty());
+    let (raw, source) = match (capture_locale, prior_detected_locale.map(str::trim)) {
+        (Some(raw), _) if synthesized_capture => (raw, Ph1cLocaleSource::Guessed),
+        (Some(raw), _) => (raw, Ph1cLocaleSource::ClientExplicit),
+        (None, Some(prior)) if !prior.is_empty() => (prior, Ph1cLocaleSource::PriorDetection),
+        _ => return Err("ph1k live capture missing locale_tag".to_string()),
+    };
+    let locale_tag = InterruptLocaleTag::new(truncate_ascii(raw, 32))
+        .map_err(|err| format!("ph1k locale_tag invalid: {err:?}"))?;
+    Ok((locale_tag, source))
+}
+
+/// Reads an interrupt lexicon file: a JSON object mapping locale tags to phrase lists, such as
+/// `{"pt-BR": ["espera", "para"]}`. The phrases extend the PH1.K built-ins for that locale.
+pub fn load_interrupt_lexicon(path: &Path) -> Result<BTreeMap<String, Vec<String>>, String> {
+    let raw = fs::read_to_string(path).map_err(|err| {
+        format!(
+            "failed to read interrupt lexicon '{}': {err}",
+            path.display()
+        )
+    })?;
+    serde_json::from_str(&raw).map_err(|err| {
+        format!(
+            "interrupt lexicon '{}' must be a JSON object of locale -> phrase list: {err}",
+            path.display()
+        )
+    })
+}
+
+fn parse_interrupt_lexicon_from_env() -> BTreeMap<String, Vec<String>> {
+    let Some(path) = env::var("SELENE_ADAPTER_INTERRUPT_LEXICON_PATH")
+        .ok()
+        .map(|v| v.trim().to_string())
         .filter(|v| !v.is_empty())
-        .ok_or_else(|| "ph1k live capture missing locale_tag".to_string())?;
-    InterruptLocaleTag::new(truncate_ascii(raw, 32))
-        .map_err(|err| format!("ph1k locale_tag invalid: {err:?}"))
+    else {
+        return BTreeMap::new();
+    };
+    load_interrupt_lexicon(Path::new(&path)).unwrap_or_else(|err| {
+        tracing::warn!("{err}; using built-in interrupt phrases only");
+        BTreeMap::new()
+    })
+}
+
+// `SELENE_ADAPTER_INTERRUPT_LOCALE_FALLBACKS` entries look like `pt-BR:pt,es,en;ca-ES:es`.
+fn parse_interrupt_locale_fallbacks_from_env() -> BTreeMap<String, Vec<String>> {
+    env::var("SELENE_ADAPTER_INTERRUPT_LOCALE_FALLBACKS")
+        .map(|raw| parse_interrupt_locale_fallbacks(&raw))
+        .unwrap_or_default()
+}
+
+fn parse_interrupt_locale_fallbacks(raw: &str) -> BTreeMap<String, Vec<String>> {
+    let mut out = BTreeMap::new();
+    for entry in raw.split(';') {
+        let Some((locale, chain_raw)) = entry.split_once(':') else {
+            continue;
+        };
+        let locale = locale.trim();
+        if locale.is_empty() {
+            continue;
+        }
+        let chain = chain_raw
+            .split(',')
+            .map(str::trim)
+            .filter(|fallback| !fallback.is_empty())
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        if !chain.is_empty() {
+            out.insert(locale.to_string(), chain);
+        }
+    }
+    out
+}
+
+fn build_interrupt_phrase_matcher(
+    lexicon: &BTreeMap<String, Vec<String>>,
+    locale_fallbacks: &BTreeMap<String, Vec<String>>,
+) -> InterruptPhraseMatcher {
+    let matcher =
+        InterruptPhraseMatcher::built_in_with_extra_phrases(lexicon).unwrap_or_else(|err| {
+            tracing::warn!(
+                "interrupt lexicon rejected: {err:?}; using built-in interrupt phrases only"
+            );
+            InterruptPhraseMatcher::built_in()
+        });
+    matcher
+        .clone()
+        .with_locale_fallbacks(locale_fallbacks)
+        .unwrap_or_else(|err| {
+            tracing::warn!("interrupt locale fallbacks rejected: {err:?}; ignoring them");
+            matcher
+        })
 }
 
 fn build_interrupt_matcher_and_binding(
     _store: &Ph1fStore,
     _tenant_scope: Option<&str>,
     _device_id: Option<&DeviceId>,
+    base_matcher: &InterruptPhraseMatcher,
     locale_tag: &InterruptLocaleTag,
 ) -> Result<(InterruptPhraseMatcher, InterruptLexiconPolicyBinding), String> {
-    let matcher = InterruptPhraseMatcher::built_in();
+    let matcher = base_matcher.clone();
     let default_binding = matcher.default_policy_binding();
     let binding = InterruptLexiconPolicyBinding::v1(
         default_binding.policy_profile_id,
@@ -24205,6 +31113,8 @@ fn build_ph1k_live_signal_bundle(
     now: MonotonicTimeNs,
     tenant_scope: Option<&str>,
     device_id: Option<&DeviceId>,
+    prior_detected_locale: Option<&str>,
+    interrupt_matcher: &InterruptPhraseMatcher,
 ) -> Result<Ph1kLiveSignalBundle, String> {
     let synthesized_capture_ref;
     let capture = if let Some(capture) = request.audio_capture_ref.as_ref() {
@@ -24216,9 +31126,18 @@ fn build_ph1k_live_signal_bundle(
             .ok_or_else(|| "ph1k live capture bundle is required for voice turns".to_string())?
     };
 
-    let locale_tag = resolve_interrupt_locale_tag_from_capture(capture)?;
-    let (matcher, binding) =
-        build_interrupt_matcher_and_binding(store, tenant_scope, device_id, &locale_tag)?;
+    let (locale_tag, locale_source) = resolve_interrupt_locale_tag_from_capture(
+        capture,
+        request.audio_capture_ref.is_none(),
+        prior_detected_locale,
+    )?;
+    let (matcher, binding) = build_interrupt_matcher_and_binding(
+        store,
+        tenant_scope,
+        device_id,
+        interrupt_matcher,
+        &locale_tag,
+    )?;
     let selected_mic_raw = capture
         .selected_mic
         .as_deref()
@@ -24447,6 +31366,8 @@ fn build_ph1k_live_signal_bundle(
 
     Ok(Ph1kLiveSignalBundle {
         locale_tag,
+        locale_source,
+        client_language_hint: request_language_hint(request)?,
         processed_stream_ref,
         pre_roll_buffer_ref,
         vad_events,
@@ -24488,6 +31409,7 @@ fn synthesized_desktop_explicit_capture_ref(
 fn build_live_voice_id_observation(
     store: &Ph1fStore,
     tenant_scope: Option<&str>,
+    tenant_delimiter: char,
     actor_user_id: &UserId,
     device_id: &DeviceId,
     app_platform: AppPlatform,
@@ -24511,7 +31433,9 @@ fn build_live_voice_id_observation(
                 return false;
             }
             if let Some(tenant_scope) = tenant_scope {
-                let Some((profile_tenant, _)) = device.user_id.as_str().split_once(':') else {
+                let Some(profile_tenant) =
+                    tenant_scope_from_user_id(&device.user_id, tenant_delimiter)
+                else {
                     return false;
                 };
                 if profile_tenant != tenant_scope {
@@ -24544,15 +31468,37 @@ pub fn validate_voice_turn_capture_bundle_for_live_path(
 ) -> Result<(), String> {
     let store = Ph1fStore::new_in_memory();
     let now = MonotonicTimeNs(request.now_ns.unwrap_or_else(system_time_now_ns).max(1));
-    let _ = build_ph1k_live_signal_bundle(&store, request, now, None, None)?;
+    let _ = build_ph1k_live_signal_bundle(
+        &store,
+        request,
+        now,
+        None,
+        None,
+        None,
+        &InterruptPhraseMatcher::built_in(),
+    )?;
     Ok(())
 }
 
+// A device is trusted only once onboarding has confirmed it as someone's primary device;
+// anything else (unknown, or onboarding still pending) is treated as untrusted.
+fn device_trust_level_from_history(store: &Ph1fStore, device_id: &DeviceId) -> DeviceTrustLevel {
+    let confirmed_primary = store.ph1onb_session_rows().values().any(|rec| {
+        rec.primary_device_confirmed && rec.primary_device_device_id.as_ref() == Some(device_id)
+    });
+    if confirmed_primary {
+        DeviceTrustLevel::Trusted
+    } else {
+        DeviceTrustLevel::Untrusted
+    }
+}
+
 fn build_voice_id_request_from_ph1k_bundle(
     now: MonotonicTimeNs,
     ph1k: &Ph1kLiveSignalBundle,
     session_snapshot: SessionSnapshot,
     wake_event: Option<WakeDecision>,
+    device_trust_level: DeviceTrustLevel,
     device_owner_user_id: Option<UserId>,
 ) -> Result<Ph1VoiceIdRequest, selene_kernel_contracts::ContractViolation> {
     Ph1VoiceIdRequest::v1(
@@ -24563,11 +31509,111 @@ fn build_voice_id_request_from_ph1k_bundle(
         session_snapshot,
         wake_event,
         ph1k.tts_playback.active,
-        DeviceTrustLevel::Trusted,
+        device_trust_level,
         device_owner_user_id,
     )
 }
 
+const PH1C_CROSS_TALK_DOUBLE_TALK_MIN: f32 = 0.65;
+
+fn ph1c_speaker_overlap_class(ph1k: &Ph1kLiveSignalBundle) -> SpeakerOverlapClass {
+    if ph1k.tts_playback.active {
+        SpeakerOverlapClass::InterruptionOverlap
+    } else if ph1k.ph1c_handoff.quality_metrics.double_talk_score >= PH1C_CROSS_TALK_DOUBLE_TALK_MIN
+    {
+        // Double-talk without our own playback means a second human voice, not echo.
+        SpeakerOverlapClass::MultiSpeaker
+    } else {
+        SpeakerOverlapClass::SingleSpeaker
+    }
+}
+
+/// How the PH1.C speaker-overlap hint confidence is derived from the PH1.K signals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Ph1cOverlapConfidenceProfile {
+    /// `0.88 + 0.1 * vad_confidence`; ignores double-talk and playback state.
+    VadLegacy,
+    /// Weighs VAD confidence against how well double-talk and TTS playback back the
+    /// chosen overlap class.
+    SignalWeighted,
+}
+
+fn ph1c_overlap_confidence_profile_from_env() -> Ph1cOverlapConfidenceProfile {
+    match env::var("SELENE_ADAPTER_PH1C_OVERLAP_CONFIDENCE_PROFILE")
+        .ok()
+        .map(|v| v.trim().to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("signal_weighted") => Ph1cOverlapConfidenceProfile::SignalWeighted,
+        _ => Ph1cOverlapConfidenceProfile::VadLegacy,
+    }
+}
+
+// SignalWeighted: 0.4 + 0.3 * vad + 0.3 * class evidence, where class evidence is
+// double-talk for MultiSpeaker, 0.5 + 0.5 * double-talk for InterruptionOverlap (our own
+// playback is already evidence), and 1 - double-talk for SingleSpeaker.
+fn ph1c_speaker_overlap_confidence(
+    profile: Ph1cOverlapConfidenceProfile,
+    overlap_class: SpeakerOverlapClass,
+    vad_confidence: f32,
+    double_talk_score: f32,
+    tts_playback_active: bool,
+) -> f32 {
+    let vad = vad_confidence.clamp(0.0, 1.0);
+    let double_talk = double_talk_score.clamp(0.0, 1.0);
+    match profile {
+        Ph1cOverlapConfidenceProfile::VadLegacy => (0.88 + (vad * 0.1)).clamp(0.0, 1.0),
+        Ph1cOverlapConfidenceProfile::SignalWeighted => {
+            let class_evidence = match overlap_class {
+                SpeakerOverlapClass::MultiSpeaker => double_talk,
+                SpeakerOverlapClass::InterruptionOverlap if tts_playback_active => {
+                    0.5 + (double_talk * 0.5)
+                }
+                SpeakerOverlapClass::InterruptionOverlap => double_talk,
+                SpeakerOverlapClass::SingleSpeaker => 1.0 - double_talk,
+                SpeakerOverlapClass::Unknown => 0.0,
+            };
+            (0.4 + (vad * 0.3) + (class_evidence * 0.3)).clamp(0.0, 1.0)
+        }
+    }
+}
+
+// A client-supplied hint outranks whatever locale the capture carries. Without a confidence it
+// counts as `HIGH`, the same as any other explicit client locale.
+fn request_language_hint(
+    request: &VoiceTurnAdapterRequest,
+) -> Result<Option<LanguageHint>, String> {
+    let Some(raw_tag) = request.language_hint.as_deref() else {
+        if request.language_hint_confidence.is_some() {
+            return Err("invalid language_hint_confidence: language_hint is missing".to_string());
+        }
+        return Ok(None);
+    };
+    let tag = raw_tag.trim();
+    let well_formed = !tag.is_empty()
+        && tag.split('-').all(|subtag| {
+            (1..=8).contains(&subtag.len()) && subtag.chars().all(|ch| ch.is_ascii_alphanumeric())
+        })
+        && tag.split('-').next().is_some_and(|primary| {
+            (2..=3).contains(&primary.len()) && primary.chars().all(|ch| ch.is_ascii_alphabetic())
+        });
+    if !well_formed {
+        return Err(format!("invalid language_hint '{raw_tag}'"));
+    }
+    let language_tag =
+        LanguageTag::new(tag).map_err(|err| format!("invalid language_hint: {err:?}"))?;
+    let confidence = match request.language_hint_confidence.as_deref().map(str::trim) {
+        None => LanguageHintConfidence::High,
+        Some(value) if value.eq_ignore_ascii_case("HIGH") => LanguageHintConfidence::High,
+        Some(value) if value.eq_ignore_ascii_case("MED") => LanguageHintConfidence::Med,
+        Some(value) if value.eq_ignore_ascii_case("LOW") => LanguageHintConfidence::Low,
+        Some(value) => {
+            return Err(format!("invalid language_hint_confidence '{value}'"));
+        }
+    };
+    Ok(Some(LanguageHint::v1(language_tag, confidence)))
+}
+
 fn build_ph1c_live_request(
     ph1k: &Ph1kLiveSignalBundle,
     session_state: SessionState,
@@ -24582,11 +31628,15 @@ fn build_ph1c_live_request(
     )
     .map_err(|err| format!("ph1c bounded audio segment invalid: {err:?}"))?;
 
-    let language_hint = Some(LanguageHint::v1(
-        LanguageTag::new(ph1k.locale_tag.as_str().to_string())
-            .map_err(|err| format!("ph1c language tag invalid: {err:?}"))?,
-        LanguageHintConfidence::Med,
-    ));
+    let language_hint = match ph1k.client_language_hint.clone() {
+        Some(hint) => hint,
+        None => LanguageHint::v1(
+            LanguageTag::new(ph1k.locale_tag.as_str().to_string())
+                .map_err(|err| format!("ph1c language tag invalid: {err:?}"))?,
+            ph1k.locale_source.language_hint_confidence(),
+        ),
+    };
+    let language_hint = Some(language_hint);
     let noise_level_hint = Some(
         NoiseLevelHint::new(
             (ph1k.ph1c_handoff.quality_metrics.packet_loss_pct / 100.0).clamp(0.0, 1.0),
@@ -24597,14 +31647,18 @@ fn build_ph1c_live_request(
         VadQualityHint::new(ph1k.interrupt_input.vad_confidence.clamp(0.0, 1.0))
             .map_err(|err| format!("ph1c vad hint invalid: {err:?}"))?,
     );
+    let overlap_class = ph1c_speaker_overlap_class(ph1k);
+    let overlap_confidence = ph1c_speaker_overlap_confidence(
+        ph1c_overlap_confidence_profile_from_env(),
+        overlap_class,
+        ph1k.interrupt_input.vad_confidence,
+        ph1k.ph1c_handoff.quality_metrics.double_talk_score,
+        ph1k.tts_playback.active,
+    );
     let speaker_overlap_hint = Some(
         SpeakerOverlapHint::v1(
-            if ph1k.tts_playback.active {
-                SpeakerOverlapClass::InterruptionOverlap
-            } else {
-                SpeakerOverlapClass::SingleSpeaker
-            },
-            Confidence::new((0.88 + (ph1k.interrupt_input.vad_confidence * 0.1)).clamp(0.0, 1.0))
+            overlap_class,
+            Confidence::new(overlap_confidence)
                 .map_err(|err| format!("ph1c overlap confidence invalid: {err:?}"))?,
         )
         .map_err(|err| format!("ph1c overlap hint invalid: {err:?}"))?,
@@ -24654,6 +31708,121 @@ fn interrupt_noise_class_label(noise_class: Option<InterruptNoiseClass>) -> &'st
     }
 }
 
+// Drops an engine interrupt candidate whose phrase confidence is under the minimum for the
+// current TTS state, and rebuilds the PH1.C handoff so it no longer carries the candidate.
+fn apply_interrupt_confidence_policy(
+    bundle: &mut Ph1kLiveSignalBundle,
+    policy: &AdapterInterruptConfidencePolicy,
+) -> Result<(), String> {
+    let Some(candidate) = bundle.interrupt_decision.candidate.as_ref() else {
+        return Ok(());
+    };
+    if candidate.phrase_confidence.0 >= policy.min_confidence(bundle.tts_playback.active) {
+        return Ok(());
+    }
+    bundle.interrupt_decision.candidate = None;
+    bundle.interrupt_decision.reason_code =
+        selene_engines::ph1k::reason_codes::K_INTERRUPT_NOISE_GATE_REJECTED;
+    bundle.ph1c_handoff =
+        build_ph1k_to_ph1c_handoff(&bundle.interrupt_input, &bundle.interrupt_decision)
+            .map_err(|err| format!("ph1k->ph1c handoff invalid: {err:?}"))?;
+    Ok(())
+}
+
+// The user interrupted when PH1.K accepted an interrupt candidate, after the confidence policy,
+// while TTS was playing. PH1.X takes that candidate unchanged as its interruption input.
+// Builds the PH1.E response a client-supplied tool result stands for. Only results PH1.X can
+// speak without a provider are accepted, and the PH1.E contract checks still apply.
+fn pre_fetched_tool_response_to_ph1e(
+    input: &VoiceTurnPreFetchedToolResponse,
+) -> Result<ToolResponse, String> {
+    let result_text = input.result_text.trim().to_string();
+    let tool_result = match input.tool_kind.trim().to_ascii_uppercase().as_str() {
+        "TIME" => ToolResult::Time {
+            local_time_iso: result_text,
+        },
+        "WEATHER" => ToolResult::Weather {
+            summary: result_text,
+        },
+        other => {
+            return Err(format!(
+                "invalid pre_fetched_tool_response: unsupported tool_kind '{other}'"
+            ))
+        }
+    };
+    let source_metadata = SourceMetadata {
+        schema_version: PH1E_CONTRACT_VERSION,
+        provider_hint: None,
+        retrieved_at_unix_ms: input.retrieved_at_unix_ms,
+        sources: input
+            .sources
+            .iter()
+            .map(|source| SourceRef {
+                title: source.title.clone(),
+                url: source.url.clone(),
+            })
+            .collect(),
+        web_answer_verification: None,
+    };
+    ToolResponse::ok_v1(
+        ToolRequestId(input.request_id),
+        ToolQueryHash(input.query_hash),
+        tool_result,
+        source_metadata,
+        None,
+        selene_engines::ph1e::reason_codes::E_OK_TOOL_RESULT,
+        CacheStatus::Hit,
+    )
+    .map_err(|err| format!("invalid pre_fetched_tool_response: {err:?}"))
+}
+
+fn ph1x_interruption_from_ph1k_bundle(bundle: &Ph1kLiveSignalBundle) -> Option<InterruptCandidate> {
+    if !bundle.tts_playback.active {
+        return None;
+    }
+    bundle.interrupt_decision.candidate.clone()
+}
+
+fn ph1k_feedback_signal_kind(
+    bundle: &Ph1kLiveSignalBundle,
+    policy: &AdapterPh1kFeedbackPolicy,
+) -> Option<InterruptFeedbackSignalKind> {
+    let candidate = bundle.interrupt_decision.candidate.as_ref();
+    let kind = if let Some(candidate) = candidate {
+        if !bundle.tts_playback.active {
+            InterruptFeedbackSignalKind::FalseLexicalTrigger
+        } else if matches!(
+            candidate.candidate_confidence_band,
+            selene_kernel_contracts::ph1k::InterruptCandidateConfidenceBand::Low
+        ) {
+            InterruptFeedbackSignalKind::WrongConfidenceBand
+        } else {
+            return None;
+        }
+    } else if bundle.tts_playback.active && bundle.interrupt_input.detection.is_some() {
+        InterruptFeedbackSignalKind::MissedLexicalTrigger
+    } else {
+        return None;
+    };
+    if !policy.signal_kind_enabled(kind) {
+        return None;
+    }
+    let phrase_confidence = match candidate {
+        Some(candidate) => candidate.phrase_confidence.0,
+        None => bundle
+            .interrupt_input
+            .detection
+            .as_ref()
+            .map(|detection| detection.confidence)
+            .unwrap_or(0.0),
+    };
+    let delta = (phrase_confidence - DEFAULT_MIN_INTERRUPT_PHRASE_CONFIDENCE).abs();
+    if delta < policy.min_confidence_delta {
+        return None;
+    }
+    Some(kind)
+}
+
 fn interrupt_feedback_kind_label(kind: InterruptFeedbackSignalKind) -> &'static str {
     match kind {
         InterruptFeedbackSignalKind::FalseLexicalTrigger => "false_lexical",
@@ -24732,6 +31901,85 @@ fn eval_commit_hash() -> String {
         .unwrap_or_else(|| "live_runtime".to_string())
 }
 
+/// Static fleet tags added to telemetry CSV rows and store-backed telemetry. Unset tags stay
+/// empty in CSVs and are omitted from the store.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AdapterTelemetryTags {
+    pub env: String,
+    pub region: String,
+    pub deployment_id: String,
+}
+
+const TELEMETRY_TAGS_CSV_HEADER: &str = "env,region,deployment_id";
+
+impl AdapterTelemetryTags {
+    fn from_env() -> Self {
+        Self {
+            env: telemetry_tag_from_env("SELENE_TELEMETRY_ENV"),
+            region: telemetry_tag_from_env("SELENE_TELEMETRY_REGION"),
+            deployment_id: telemetry_tag_from_env("SELENE_TELEMETRY_DEPLOYMENT_ID"),
+        }
+    }
+
+    fn from_store_tags(tags: &BTreeMap<String, String>) -> Self {
+        let tag = |key: &str| tags.get(key).cloned().unwrap_or_default();
+        Self {
+            env: tag("env"),
+            region: tag("region"),
+            deployment_id: tag("deployment_id"),
+        }
+    }
+
+    // The tag columns, with a leading comma, for a row appended to `csv_path`. A file whose
+    // header predates the tags gets none, so its rows keep the header's column count.
+    fn csv_columns(&self, csv_path: &Path) -> String {
+        if !telemetry_csv_header_has_tags(csv_path) {
+            return String::new();
+        }
+        format!(",{},{},{}", self.env, self.region, self.deployment_id)
+    }
+
+    fn store_tags(&self) -> BTreeMap<String, String> {
+        [
+            ("env", &self.env),
+            ("region", &self.region),
+            ("deployment_id", &self.deployment_id),
+        ]
+        .into_iter()
+        .filter(|(_, value)| !value.is_empty())
+        .map(|(key, value)| (key.to_string(), value.clone()))
+        .collect()
+    }
+}
+
+// A missing or empty file gets the current header, which has the tag columns.
+fn telemetry_csv_header_has_tags(csv_path: &Path) -> bool {
+    let Ok(file) = File::open(csv_path) else {
+        return true;
+    };
+    let mut header = String::new();
+    match BufReader::new(file).read_line(&mut header) {
+        Ok(0) | Err(_) => true,
+        Ok(_) => header.trim_end().ends_with(TELEMETRY_TAGS_CSV_HEADER),
+    }
+}
+
+fn telemetry_tag_from_env(key: &str) -> String {
+    let raw = env::var(key).unwrap_or_default();
+    let sanitized = raw
+        .trim()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.' | ':') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+    truncate_ascii(&sanitized, 64)
+}
+
 fn resolve_repo_root_from_cwd() -> Option<PathBuf> {
     let cwd = env::current_dir().ok()?;
     for ancestor in cwd.ancestors() {
@@ -24742,60 +31990,319 @@ fn resolve_repo_root_from_cwd() -> Option<PathBuf> {
     None
 }
 
-fn append_ph1k_live_eval_snapshot_csv(
-    store: &Ph1fStore,
-    now: MonotonicTimeNs,
-    correlation_id: CorrelationId,
-    turn_id: TurnId,
-    tenant_id: &str,
-    bundle: &Ph1kLiveSignalBundle,
-) -> Result<(), String> {
-    let default_csv_path = resolve_repo_root_from_cwd()
-        .map(|root| root.join(".dev/ph1k_live_eval_snapshot.csv"))
-        .unwrap_or_else(|| PathBuf::from(".dev/ph1k_live_eval_snapshot.csv"));
-    let csv_path = env::var("SELENE_PH1K_LIVE_EVAL_PATH")
-        .ok()
-        .map(|v| v.trim().to_string())
-        .filter(|v| !v.is_empty())
-        .map(PathBuf::from)
-        .unwrap_or(default_csv_path);
-    if let Some(parent) = csv_path.parent() {
-        if !parent.as_os_str().is_empty() {
-            fs::create_dir_all(parent).map_err(|err| {
-                format!(
-                    "failed to create ph1k eval csv directory '{}': {}",
-                    parent.display(),
-                    err
-                )
-            })?;
+/// When buffered PH1.K eval CSV rows are written out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdapterEvalCsvFlushPolicy {
+    #[default]
+    PerRow,
+    /// Write once this many rows are buffered.
+    EveryRows(usize),
+    /// Write only on `AdapterRuntime::shutdown` or when the runtime is dropped.
+    OnShutdown,
+}
+
+impl AdapterEvalCsvFlushPolicy {
+    fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        match value.as_str() {
+            "per_row" => Some(AdapterEvalCsvFlushPolicy::PerRow),
+            "on_shutdown" => Some(AdapterEvalCsvFlushPolicy::OnShutdown),
+            _ => value
+                .strip_prefix("every:")
+                .and_then(|rows| rows.trim().parse::<usize>().ok())
+                .filter(|rows| *rows > 0)
+                .map(AdapterEvalCsvFlushPolicy::EveryRows),
         }
     }
-    let needs_header = !csv_path.exists()
-        || fs::metadata(&csv_path)
+}
+
+fn parse_eval_csv_flush_policy_from_env() -> AdapterEvalCsvFlushPolicy {
+    env::var("SELENE_ADAPTER_EVAL_CSV_FLUSH")
+        .ok()
+        .and_then(|raw| AdapterEvalCsvFlushPolicy::parse(&raw))
+        .unwrap_or_default()
+}
+
+// One `append_rows` call is one open, write and flush of the target file.
+trait EvalCsvSink: std::fmt::Debug + Send {
+    fn append_rows(&mut self, path: &Path, header: &str, rows: &[String]) -> Result<(), String>;
+}
+
+#[derive(Debug, Default)]
+struct FileEvalCsvSink;
+
+impl EvalCsvSink for FileEvalCsvSink {
+    fn append_rows(&mut self, path: &Path, header: &str, rows: &[String]) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent).map_err(|err| {
+                    format!(
+                        "failed to create eval csv directory '{}': {}",
+                        parent.display(),
+                        err
+                    )
+                })?;
+            }
+        }
+        let needs_header = fs::metadata(path)
             .map(|meta| meta.len() == 0)
             .unwrap_or(true);
-    let mut file = OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&csv_path)
-        .map_err(|err| {
-            format!(
-                "failed to open ph1k eval csv '{}' for append: {}",
-                csv_path.display(),
-                err
-            )
-        })?;
-    if needs_header {
-        file.write_all(b"captured_at_utc,commit_hash,window_min,locale_tag,device_route,noise_class,overlap_speech,active_session_hours,interrupt_events,false_interrupt_count,missed_interrupt_count,false_interrupt_rate_per_hour,missed_interrupt_rate_pct,end_of_speech_p95_ms,capture_to_ph1c_handoff_p95_ms,device_failover_recovery_p95_ms,noisy_recovery_success_pct,multilingual_interrupt_recall_pct,audit_completeness_pct,tenant_isolation_pct\n")
+        let mut out = String::new();
+        if needs_header {
+            out.push_str(header);
+        }
+        for row in rows {
+            out.push_str(row);
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
             .map_err(|err| {
                 format!(
-                    "failed to write ph1k eval csv header '{}': {}",
-                    csv_path.display(),
+                    "failed to open eval csv '{}' for append: {}",
+                    path.display(),
                     err
                 )
             })?;
+        file.write_all(out.as_bytes()).map_err(|err| {
+            format!(
+                "failed to append eval csv rows '{}': {}",
+                path.display(),
+                err
+            )
+        })?;
+        file.flush()
+            .map_err(|err| format!("failed to flush eval csv '{}': {}", path.display(), err))
+    }
+}
+
+#[derive(Debug)]
+struct EvalCsvBuffer {
+    policy: AdapterEvalCsvFlushPolicy,
+    sink: Box<dyn EvalCsvSink>,
+    path: Option<PathBuf>,
+    header: String,
+    pending: Vec<String>,
+}
+
+impl EvalCsvBuffer {
+    fn new(policy: AdapterEvalCsvFlushPolicy, sink: Box<dyn EvalCsvSink>) -> Self {
+        Self {
+            policy,
+            sink,
+            path: None,
+            header: String::new(),
+            pending: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, path: PathBuf, header: String, row: String) -> Result<(), String> {
+        if self.path.as_ref().is_some_and(|current| *current != path) {
+            self.flush()?;
+        }
+        self.path = Some(path);
+        self.header = header;
+        self.pending.push(row);
+        let due = match self.policy {
+            AdapterEvalCsvFlushPolicy::PerRow => true,
+            AdapterEvalCsvFlushPolicy::EveryRows(rows) => self.pending.len() >= rows,
+            AdapterEvalCsvFlushPolicy::OnShutdown => false,
+        };
+        if due {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    // Pending rows are dropped even when the write fails, so a broken path cannot grow the
+    // buffer without bound.
+    fn flush(&mut self) -> Result<(), String> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let rows = std::mem::take(&mut self.pending);
+        match self.path.as_deref() {
+            Some(path) => self.sink.append_rows(path, &self.header, &rows),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for EvalCsvBuffer {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            tracing::error!("eval csv flush on drop failed: {err}");
+        }
+    }
+}
+
+/// Live telemetry the adapter hands to its `TelemetrySink`, one event per record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TelemetryEvent {
+    /// One PH1.C live transcript outcome.
+    Ph1cLive(Ph1cLiveTelemetryEvent),
+    /// A PH1.K live eval snapshot taken after a turn, as one CSV line in the
+    /// `ph1k_live_eval_snapshot.csv` column order, without the tag columns.
+    Ph1kLiveEvalSnapshot {
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        csv_row: String,
+        /// Fleet tags that are set, added as the last columns when the file's header has them.
+        telemetry_tags: BTreeMap<String, String>,
+    },
+    /// An OS voice refusal with its raw reason code and message, which the client response
+    /// replaces with a user-facing message.
+    VoiceRefused {
+        captured_at: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        reason_code: ReasonCodeId,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ph1cLiveTelemetryEvent {
+    pub captured_at: MonotonicTimeNs,
+    pub correlation_id: CorrelationId,
+    pub turn_id: TurnId,
+    pub tenant_id: String,
+    pub outcome_type: String,
+    pub reason_code: ReasonCodeId,
+    pub latency_ms: u32,
+    pub decision_delta: bool,
+    pub finalized: bool,
+    pub low_latency_commit: bool,
+    /// Fleet tags that are set, keyed `env`, `region` and `deployment_id`.
+    pub telemetry_tags: BTreeMap<String, String>,
+}
+
+/// Where live PH1.C and PH1.K telemetry goes. `record` cannot fail the turn, so a sink logs
+/// its own write errors.
+pub trait TelemetrySink: std::fmt::Debug + Send + Sync {
+    fn record(&self, event: TelemetryEvent);
+
+    /// Writes out anything still buffered. `AdapterRuntime::shutdown` calls it.
+    fn flush(&self) -> Result<(), AdapterError> {
+        Ok(())
     }
+}
+
+/// The default sink. PH1.C events go to `SELENE_PH1C_LIVE_TELEMETRY_PATH` and PH1.K
+/// snapshots to `SELENE_PH1K_LIVE_EVAL_PATH`, both under the repo's `.dev/` when unset.
+/// PH1.K rows are buffered under the given flush policy.
+#[derive(Debug)]
+pub struct CsvTelemetrySink {
+    ph1k_eval_csv: Mutex<EvalCsvBuffer>,
+}
+
+impl CsvTelemetrySink {
+    pub fn new(eval_csv_flush_policy: AdapterEvalCsvFlushPolicy) -> Self {
+        Self::with_eval_csv_sink(eval_csv_flush_policy, Box::new(FileEvalCsvSink))
+    }
+
+    fn with_eval_csv_sink(
+        eval_csv_flush_policy: AdapterEvalCsvFlushPolicy,
+        eval_csv_sink: Box<dyn EvalCsvSink>,
+    ) -> Self {
+        Self {
+            ph1k_eval_csv: Mutex::new(EvalCsvBuffer::new(eval_csv_flush_policy, eval_csv_sink)),
+        }
+    }
+}
 
+impl TelemetrySink for CsvTelemetrySink {
+    fn record(&self, event: TelemetryEvent) {
+        match event {
+            TelemetryEvent::Ph1cLive(event) => {
+                if let Err(err) = append_ph1c_live_telemetry_csv(&event) {
+                    tracing::error!("ph1c live telemetry csv append failed: {err}");
+                }
+            }
+            TelemetryEvent::Ph1kLiveEvalSnapshot {
+                csv_row,
+                telemetry_tags,
+                ..
+            } => {
+                let csv_path = ph1k_live_eval_csv_path();
+                let csv_row = format!(
+                    "{}{}\n",
+                    csv_row.trim_end_matches('\n'),
+                    AdapterTelemetryTags::from_store_tags(&telemetry_tags).csv_columns(&csv_path)
+                );
+                let pushed = self
+                    .ph1k_eval_csv
+                    .lock()
+                    .map_err(|_| AdapterError::LockPoisoned("eval csv".to_string()))
+                    .and_then(|mut buffer| {
+                        buffer
+                            .push(csv_path, ph1k_live_eval_csv_header(), csv_row)
+                            .map_err(AdapterError::Persistence)
+                    });
+                if let Err(err) = pushed {
+                    tracing::error!("ph1k live eval csv append failed: {err}");
+                }
+            }
+            TelemetryEvent::VoiceRefused {
+                correlation_id,
+                turn_id,
+                reason_code,
+                message,
+                ..
+            } => {
+                tracing::info!(
+                    "os_refuse correlation_id={} turn_id={} reason_code={} message={}",
+                    correlation_id.0,
+                    turn_id.0,
+                    reason_code.0,
+                    message
+                );
+            }
+        }
+    }
+
+    fn flush(&self) -> Result<(), AdapterError> {
+        self.ph1k_eval_csv
+            .lock()
+            .map_err(|_| AdapterError::LockPoisoned("eval csv".to_string()))?
+            .flush()
+            .map_err(AdapterError::Persistence)
+    }
+}
+
+/// Drops every event, for deployments without a writable telemetry location.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NullTelemetrySink;
+
+impl TelemetrySink for NullTelemetrySink {
+    fn record(&self, _event: TelemetryEvent) {}
+}
+
+fn ph1k_live_eval_csv_path() -> PathBuf {
+    let default_csv_path = resolve_repo_root_from_cwd()
+        .map(|root| root.join(".dev/ph1k_live_eval_snapshot.csv"))
+        .unwrap_or_else(|| PathBuf::from(".dev/ph1k_live_eval_snapshot.csv"));
+    env::var("SELENE_PH1K_LIVE_EVAL_PATH")
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or(default_csv_path)
+}
+
+fn ph1k_live_eval_csv_header() -> String {
+    format!("captured_at_utc,commit_hash,window_min,locale_tag,device_route,noise_class,overlap_speech,active_session_hours,interrupt_events,false_interrupt_count,missed_interrupt_count,false_interrupt_rate_per_hour,missed_interrupt_rate_pct,end_of_speech_p95_ms,capture_to_ph1c_handoff_p95_ms,device_failover_recovery_p95_ms,noisy_recovery_success_pct,multilingual_interrupt_recall_pct,audit_completeness_pct,tenant_isolation_pct,{TELEMETRY_TAGS_CSV_HEADER}\n")
+}
+
+fn ph1k_live_eval_snapshot_csv_row(
+    store: &Ph1fStore,
+    now: MonotonicTimeNs,
+    correlation_id: CorrelationId,
+    turn_id: TurnId,
+    tenant_id: &str,
+    bundle: &Ph1kLiveSignalBundle,
+) -> String {
     let captured_at = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .map(|d| d.as_secs().to_string())
@@ -24976,7 +32483,7 @@ fn append_ph1k_live_eval_snapshot_csv(
             * 100.0)
             / turn_rows.len() as f32
     };
-    let line = format!(
+    format!(
         "{},{},{},{},{},{},{},{:.4},{},{},{},{:.4},{:.2},{},{},{},{:.2},{:.2},{:.2},{:.2}\n",
         captured_at,
         eval_commit_hash(),
@@ -24998,24 +32505,7 @@ fn append_ph1k_live_eval_snapshot_csv(
         multilingual_interrupt_recall_pct,
         audit_completeness_pct,
         tenant_isolation_pct,
-    );
-    file.write_all(line.as_bytes()).map_err(|err| {
-        format!(
-            "failed to append ph1k eval csv row '{}': {}",
-            csv_path.display(),
-            err
-        )
-    })?;
-    file.flush().map_err(|err| {
-        format!(
-            "failed to flush ph1k eval csv '{}': {}",
-            csv_path.display(),
-            err
-        )
-    })?;
-
-    let _ = (correlation_id, turn_id);
-    Ok(())
+    )
 }
 
 fn ph1c_live_reject_summary(
@@ -25906,6 +33396,19 @@ fn ph1c_voice_retry_response_text(
     }
 }
 
+fn ph1c_low_snr_response_text(capture: Option<&VoiceTurnAudioCaptureRef>) -> String {
+    let locale = capture
+        .and_then(|capture| capture.locale_tag.as_deref())
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    if locale.starts_with("zh") {
+        "这里太吵了。请换到安静一点的地方再试。".to_string()
+    } else {
+        "It's too noisy here—try moving somewhere quieter.".to_string()
+    }
+}
+
 fn summarize_ph1c_stream_commit(
     stream_commit: Ph1cStreamCommit,
     provider_call_trace: Vec<Ph1dProviderCallResponse>,
@@ -25936,19 +33439,7 @@ fn snapshot_provider_calls(
     records.lock().map(|rows| rows.clone()).unwrap_or_default()
 }
 
-#[allow(clippy::too_many_arguments)]
-fn append_ph1c_live_telemetry_csv(
-    now: MonotonicTimeNs,
-    correlation_id: CorrelationId,
-    turn_id: TurnId,
-    tenant_id: &str,
-    outcome_type: &str,
-    reason_code: ReasonCodeId,
-    latency_ms: u32,
-    decision_delta: bool,
-    finalized: bool,
-    low_latency_commit: bool,
-) -> Result<(), String> {
+fn append_ph1c_live_telemetry_csv(event: &Ph1cLiveTelemetryEvent) -> Result<(), String> {
     let default_csv_path = resolve_repo_root_from_cwd()
         .map(|root| root.join(".dev/ph1c_live_telemetry.csv"))
         .unwrap_or_else(|| PathBuf::from(".dev/ph1c_live_telemetry.csv"));
@@ -25969,6 +33460,8 @@ fn append_ph1c_live_telemetry_csv(
             })?;
         }
     }
+    let tag_columns =
+        AdapterTelemetryTags::from_store_tags(&event.telemetry_tags).csv_columns(&csv_path);
     let needs_header = !csv_path.exists()
         || fs::metadata(&csv_path)
             .map(|meta| meta.len() == 0)
@@ -25985,10 +33478,10 @@ fn append_ph1c_live_telemetry_csv(
             )
         })?;
     if needs_header {
-        file.write_all(
-            b"captured_at_ns,correlation_id,turn_id,tenant_id,outcome_type,reason_code,latency_ms,decision_delta,finalized,low_latency_commit\n",
-        )
-        .map_err(|err| {
+        let header = format!(
+            "captured_at_ns,correlation_id,turn_id,tenant_id,outcome_type,reason_code,latency_ms,decision_delta,finalized,low_latency_commit,{TELEMETRY_TAGS_CSV_HEADER}\n",
+        );
+        file.write_all(header.as_bytes()).map_err(|err| {
             format!(
                 "failed to write telemetry csv header '{}': {}",
                 csv_path.display(),
@@ -25997,17 +33490,18 @@ fn append_ph1c_live_telemetry_csv(
         })?;
     }
     let line = format!(
-        "{},{},{},{},{},{},{},{},{},{}\n",
-        now.0,
-        correlation_id.0,
-        turn_id.0,
-        tenant_id,
-        outcome_type,
-        reason_code.0,
-        latency_ms,
-        if decision_delta { "1" } else { "0" },
-        if finalized { "1" } else { "0" },
-        if low_latency_commit { "1" } else { "0" }
+        "{},{},{},{},{},{},{},{},{},{}{}\n",
+        event.captured_at.0,
+        event.correlation_id.0,
+        event.turn_id.0,
+        event.tenant_id,
+        event.outcome_type,
+        event.reason_code.0,
+        event.latency_ms,
+        if event.decision_delta { "1" } else { "0" },
+        if event.finalized { "1" } else { "0" },
+        if event.low_latency_commit { "1" } else { "0" },
+        tag_columns,
     );
     file.write_all(line.as_bytes()).map_err(|err| {
         format!(
@@ -26171,6 +33665,254 @@ fn parse_auto_builder_enabled_from_env() -> bool {
     }
 }
 
+fn parse_builder_min_outcome_entries_from_env() -> usize {
+    usize::try_from(parse_u64_env(
+        "SELENE_ADAPTER_BUILDER_MIN_OUTCOME_ENTRIES",
+        1,
+    ))
+    .unwrap_or(1)
+}
+
+// Unset or unparsable disables the SNR floor.
+fn parse_stt_min_snr_db_from_env() -> Option<f32> {
+    env::var("SELENE_ADAPTER_STT_MIN_SNR_DB")
+        .ok()
+        .and_then(|raw| raw.trim().parse::<f32>().ok())
+        .filter(|v| v.is_finite())
+}
+
+fn parse_tenant_id_list(raw: &str) -> BTreeSet<String> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|tenant| !tenant.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn parse_tenant_feature_flags_from_env() -> BTreeMap<String, AdapterTenantFeatureFlags> {
+    env::var("SELENE_ADAPTER_TENANT_FEATURE_FLAGS")
+        .map(|raw| parse_tenant_feature_flags(&raw))
+        .unwrap_or_default()
+}
+
+fn parse_tenant_feature_flags(raw: &str) -> BTreeMap<String, AdapterTenantFeatureFlags> {
+    let mut out = BTreeMap::new();
+    for entry in raw.split(';') {
+        let Some((tenant, flags_raw)) = entry.split_once(':') else {
+            continue;
+        };
+        let tenant = tenant.trim();
+        if tenant.is_empty() {
+            continue;
+        }
+        let flags: &mut AdapterTenantFeatureFlags = out.entry(tenant.to_string()).or_default();
+        for flag in flags_raw.split(',') {
+            let Some((name, value)) = flag.split_once('=') else {
+                continue;
+            };
+            let value = match value.trim().to_ascii_lowercase().as_str() {
+                "1" | "true" | "on" | "yes" => true,
+                "0" | "false" | "off" | "no" => false,
+                _ => continue,
+            };
+            match name.trim().to_ascii_lowercase().as_str() {
+                "ph1c_live_enabled" => flags.ph1c_live_enabled = Some(value),
+                "ph1c_streaming_enabled" => flags.ph1c_streaming_enabled = Some(value),
+                "auto_builder_enabled" => flags.auto_builder_enabled = Some(value),
+                _ => {}
+            }
+        }
+    }
+    out
+}
+
+fn parse_ph1k_feedback_policy_from_env() -> AdapterPh1kFeedbackPolicy {
+    let mut policy = AdapterPh1kFeedbackPolicy::default();
+    if let Ok(raw) = env::var("SELENE_ADAPTER_PH1K_FEEDBACK_POLICY") {
+        apply_ph1k_feedback_policy_settings(&mut policy, &raw);
+    }
+    policy
+}
+
+fn parse_tenant_ph1k_feedback_policies_from_env() -> BTreeMap<String, AdapterPh1kFeedbackPolicy> {
+    env::var("SELENE_ADAPTER_TENANT_PH1K_FEEDBACK_POLICY")
+        .map(|raw| parse_tenant_ph1k_feedback_policies(&raw, parse_ph1k_feedback_policy_from_env()))
+        .unwrap_or_default()
+}
+
+// Tenant entries start from `base` (the global policy), so a tenant only lists what it changes.
+fn parse_tenant_ph1k_feedback_policies(
+    raw: &str,
+    base: AdapterPh1kFeedbackPolicy,
+) -> BTreeMap<String, AdapterPh1kFeedbackPolicy> {
+    let mut out = BTreeMap::new();
+    for entry in raw.split(';') {
+        let Some((tenant, settings_raw)) = entry.split_once(':') else {
+            continue;
+        };
+        let tenant = tenant.trim();
+        if tenant.is_empty() {
+            continue;
+        }
+        let policy = out.entry(tenant.to_string()).or_insert(base);
+        apply_ph1k_feedback_policy_settings(policy, settings_raw);
+    }
+    out
+}
+
+fn apply_ph1k_feedback_policy_settings(policy: &mut AdapterPh1kFeedbackPolicy, raw: &str) {
+    for setting in raw.split(',') {
+        let Some((name, value)) = setting.split_once('=') else {
+            continue;
+        };
+        let name = name.trim().to_ascii_lowercase();
+        if name == "min_confidence_delta" {
+            if let Ok(delta) = value.trim().parse::<f32>() {
+                if delta.is_finite() {
+                    policy.min_confidence_delta = delta.clamp(0.0, 1.0);
+                }
+            }
+            continue;
+        }
+        let value = match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "on" | "yes" => true,
+            "0" | "false" | "off" | "no" => false,
+            _ => continue,
+        };
+        match name.as_str() {
+            "false_lexical_trigger" => policy.false_lexical_trigger_enabled = value,
+            "missed_lexical_trigger" => policy.missed_lexical_trigger_enabled = value,
+            "wrong_confidence_band" => policy.wrong_confidence_band_enabled = value,
+            _ => {}
+        }
+    }
+}
+
+fn parse_interrupt_confidence_policy_from_env() -> AdapterInterruptConfidencePolicy {
+    env::var("SELENE_ADAPTER_INTERRUPT_MIN_CONFIDENCE")
+        .map(|raw| parse_interrupt_confidence_policy(&raw))
+        .unwrap_or_default()
+}
+
+fn parse_interrupt_confidence_policy(raw: &str) -> AdapterInterruptConfidencePolicy {
+    let mut policy = AdapterInterruptConfidencePolicy::default();
+    for setting in raw.split(',') {
+        let Some((name, value)) = setting.split_once('=') else {
+            continue;
+        };
+        let Some(confidence) = value
+            .trim()
+            .parse::<f32>()
+            .ok()
+            .filter(|confidence| confidence.is_finite())
+            .map(|confidence| confidence.clamp(0.0, 1.0))
+        else {
+            continue;
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "playing" => policy.tts_playing_min_confidence = confidence,
+            "stopped" => policy.tts_stopped_min_confidence = confidence,
+            _ => {}
+        }
+    }
+    policy
+}
+
+fn parse_provenance_mode_from_env() -> VoiceTurnProvenanceMode {
+    env::var("SELENE_ADAPTER_PROVENANCE_MODE")
+        .ok()
+        .and_then(|raw| VoiceTurnProvenanceMode::parse(&raw).ok())
+        .unwrap_or_default()
+}
+
+fn parse_privacy_mode_transcript_scope_from_env() -> PrivacyScope {
+    let Some(raw) = env::var("SELENE_ADAPTER_PRIVACY_MODE_TRANSCRIPT_SCOPE")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+    else {
+        return PrivacyScope::PrivateDelivery;
+    };
+    privacy_scope_from_label(&raw).unwrap_or_else(|| {
+        tracing::warn!(
+            "SELENE_ADAPTER_PRIVACY_MODE_TRANSCRIPT_SCOPE '{raw}' is not a privacy scope; using PRIVATE_DELIVERY"
+        );
+        PrivacyScope::PrivateDelivery
+    })
+}
+
+fn privacy_scope_from_label(raw: &str) -> Option<PrivacyScope> {
+    match raw.trim().to_ascii_uppercase().as_str() {
+        "PUBLIC_CHAT" => Some(PrivacyScope::PublicChat),
+        "PRIVATE_DELIVERY" => Some(PrivacyScope::PrivateDelivery),
+        _ => None,
+    }
+}
+
+fn parse_transcript_close_policy_from_env() -> AdapterTranscriptClosePolicy {
+    env::var("SELENE_ADAPTER_TRANSCRIPT_CLOSE_POLICY")
+        .ok()
+        .and_then(|raw| AdapterTranscriptClosePolicy::parse(&raw))
+        .unwrap_or_default()
+}
+
+fn parse_transcript_retention_policy_from_env() -> AdapterTranscriptRetentionPolicy {
+    let max_age_ms = parse_u64_env("SELENE_ADAPTER_TRANSCRIPT_MAX_AGE_MS", 0);
+    AdapterTranscriptRetentionPolicy::new(
+        parse_u32_env("SELENE_ADAPTER_TRANSCRIPT_MAX_EVENTS", 1, 1_000_000)
+            .map(|value| value as usize)
+            .unwrap_or(TRANSCRIPT_RETENTION_DEFAULT_MAX_EVENTS),
+        (max_age_ms > 0).then(|| max_age_ms.saturating_mul(1_000_000)),
+    )
+}
+
+fn parse_unknown_app_platform_policy_from_env() -> AdapterUnknownAppPlatformPolicy {
+    env::var("SELENE_ADAPTER_UNKNOWN_APP_PLATFORM")
+        .ok()
+        .and_then(|raw| AdapterUnknownAppPlatformPolicy::parse(&raw))
+        .unwrap_or_default()
+}
+
+fn parse_model_override_allowlist_from_env() -> BTreeSet<String> {
+    env::var("SELENE_ADAPTER_MODEL_OVERRIDE_ALLOWLIST")
+        .map(|raw| {
+            raw.split(',')
+                .map(str::trim)
+                .filter(|model| !model.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+const OCR_ROUTE_DEFAULT_RETRY_BUDGET: u8 = 1;
+
+fn parse_vision_source_kind_policy_from_env() -> AdapterVisionSourceKindPolicy {
+    env::var("SELENE_ADAPTER_VISION_SOURCE_KIND_POLICY")
+        .map(|raw| parse_vision_source_kind_policy(&raw))
+        .unwrap_or_default()
+}
+
+fn parse_vision_source_kind_policy(raw: &str) -> AdapterVisionSourceKindPolicy {
+    let mut policy = AdapterVisionSourceKindPolicy::default();
+    for setting in raw.split(',') {
+        let Some((name, value)) = setting.split_once('=') else {
+            continue;
+        };
+        let value = match value.trim().to_ascii_lowercase().as_str() {
+            "1" | "true" | "on" | "yes" => true,
+            "0" | "false" | "off" | "no" => false,
+            _ => continue,
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "image" => policy.image_enabled = value,
+            "screenshot" => policy.screenshot_enabled = value,
+            "diagram" => policy.diagram_enabled = value,
+            _ => {}
+        }
+    }
+    policy
+}
+
 fn system_time_now_ns() -> u64 {
     let nanos = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -26193,7 +33935,9 @@ fn default_adapter_store_path() -> PathBuf {
     PathBuf::from(".selene/adapter/voice_turns.jsonl")
 }
 
-fn bootstrap_desktop_controlled_wake_profile_from_env(store: &mut Ph1fStore) -> Result<(), String> {
+fn bootstrap_desktop_controlled_wake_profile_from_env(
+    store: &mut Ph1fStore,
+) -> Result<(), AdapterError> {
     if !parse_bool_env("SELENE_DESKTOP_CONTROLLED_WAKE_BOOTSTRAP_ENABLED", false) {
         return Ok(());
     }
@@ -26249,7 +33993,7 @@ fn bootstrap_desktop_controlled_wake_profile_from_env(store: &mut Ph1fStore) ->
             180_000,
             format!("{label}_wake_start"),
         )
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     for seq in 1_u16..=3 {
         store
             .ph1w_enroll_sample_commit(
@@ -26267,7 +34011,7 @@ fn bootstrap_desktop_controlled_wake_profile_from_env(store: &mut Ph1fStore) ->
                 None,
                 format!("{label}_wake_sample_{seq}"),
             )
-            .map_err(storage_error_to_string)?;
+            .map_err(AdapterError::Storage)?;
     }
     store
         .ph1w_enroll_complete_commit(
@@ -26276,40 +34020,322 @@ fn bootstrap_desktop_controlled_wake_profile_from_env(store: &mut Ph1fStore) ->
             format!("{label}_wake_profile"),
             format!("{label}_wake_complete"),
         )
-        .map_err(storage_error_to_string)?;
+        .map_err(AdapterError::Storage)?;
     Ok(())
 }
 
+const UI_HEALTH_REPORT_DEFAULT_WINDOW_NS: u64 = 30 * 24 * 60 * 60 * 1_000_000_000;
+
+fn ui_health_report_default_window_ns() -> u64 {
+    parse_u64_env(
+        "SELENE_ADAPTER_HEALTH_DEFAULT_WINDOW_NS",
+        UI_HEALTH_REPORT_DEFAULT_WINDOW_NS,
+    )
+}
+
+const HEALTH_RANGE_DEFAULT_MIN_WINDOW_MS: u64 = 60_000;
+const HEALTH_RANGE_DEFAULT_FUTURE_TOLERANCE_MS: u64 = 30_000;
+
+/// Grace applied to a health report query's `from_utc_ns`/`to_utc_ns` before the range is
+/// validated. A zero in either field turns that adjustment off.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterHealthRangeGrace {
+    /// A point query (`from == to`) is widened back to a window this long.
+    pub min_window_ns: u64,
+    /// A `to` at most this far past `now` is pulled back to `now`.
+    pub future_tolerance_ns: u64,
+}
+
+impl Default for AdapterHealthRangeGrace {
+    fn default() -> Self {
+        Self {
+            min_window_ns: HEALTH_RANGE_DEFAULT_MIN_WINDOW_MS.saturating_mul(1_000_000),
+            future_tolerance_ns: HEALTH_RANGE_DEFAULT_FUTURE_TOLERANCE_MS.saturating_mul(1_000_000),
+        }
+    }
+}
+
+impl AdapterHealthRangeGrace {
+    // Only fixes ranges that are empty or skewed by a small amount. An inverted range is
+    // returned as is so validation still rejects it.
+    fn normalize(&self, from_ns: u64, to_ns: u64, now_ns: u64) -> (u64, u64) {
+        if from_ns > to_ns {
+            return (from_ns, to_ns);
+        }
+        let mut to_ns = to_ns;
+        if to_ns > now_ns && to_ns - now_ns <= self.future_tolerance_ns {
+            to_ns = now_ns.max(from_ns);
+        }
+        let mut from_ns = from_ns;
+        if from_ns == to_ns && self.min_window_ns > 0 {
+            from_ns = to_ns.saturating_sub(self.min_window_ns).max(1);
+        }
+        (from_ns, to_ns)
+    }
+}
+
+fn parse_health_range_grace_from_env() -> AdapterHealthRangeGrace {
+    // Unlike `parse_u64_env`, zero is kept: it switches the adjustment off.
+    let ms_env = |key: &str, default_ms: u64| {
+        env::var(key)
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(default_ms)
+            .saturating_mul(1_000_000)
+    };
+    AdapterHealthRangeGrace {
+        min_window_ns: ms_env(
+            "SELENE_ADAPTER_HEALTH_RANGE_MIN_WINDOW_MS",
+            HEALTH_RANGE_DEFAULT_MIN_WINDOW_MS,
+        ),
+        future_tolerance_ns: ms_env(
+            "SELENE_ADAPTER_HEALTH_RANGE_FUTURE_TOLERANCE_MS",
+            HEALTH_RANGE_DEFAULT_FUTURE_TOLERANCE_MS,
+        ),
+    }
+}
+
+const HEALTH_ISSUE_DEFAULT_DEADLINE_MS: u64 = 15 * 60 * 1_000;
+
+/// How long a synthesized health issue may stay unresolved before its deadline passes and
+/// PH1.HEALTH escalates it, by severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterHealthIssueDeadlinePolicy {
+    pub critical_ns: u64,
+    pub warn_ns: u64,
+    pub info_ns: u64,
+}
+
+impl Default for AdapterHealthIssueDeadlinePolicy {
+    fn default() -> Self {
+        let default_ns = HEALTH_ISSUE_DEFAULT_DEADLINE_MS.saturating_mul(1_000_000);
+        Self {
+            critical_ns: default_ns,
+            warn_ns: default_ns,
+            info_ns: default_ns,
+        }
+    }
+}
+
+impl AdapterHealthIssueDeadlinePolicy {
+    fn deadline_ns(&self, severity: HealthSeverity) -> u64 {
+        match severity {
+            HealthSeverity::Critical => self.critical_ns,
+            HealthSeverity::Warn => self.warn_ns,
+            HealthSeverity::Info => self.info_ns,
+        }
+    }
+
+    // Detail rows carry display labels; `HIGH` and `MEDIUM` sit at the warn tier.
+    fn ui_deadline_ns(&self, severity: &str) -> u64 {
+        match severity {
+            "CRITICAL" => self.critical_ns,
+            "HIGH" | "MEDIUM" => self.warn_ns,
+            _ => self.info_ns,
+        }
+    }
+}
+
+fn parse_health_issue_deadline_policy_from_env() -> AdapterHealthIssueDeadlinePolicy {
+    let ms_env =
+        |key: &str| parse_u64_env(key, HEALTH_ISSUE_DEFAULT_DEADLINE_MS).saturating_mul(1_000_000);
+    AdapterHealthIssueDeadlinePolicy {
+        critical_ns: ms_env("SELENE_ADAPTER_HEALTH_ISSUE_DEADLINE_CRITICAL_MS"),
+        warn_ns: ms_env("SELENE_ADAPTER_HEALTH_ISSUE_DEADLINE_WARN_MS"),
+        info_ns: ms_env("SELENE_ADAPTER_HEALTH_ISSUE_DEADLINE_INFO_MS"),
+    }
+}
+
+const HEALTH_ISSUE_SYNTH_DEFAULT_LIMIT: u64 = 16;
+
+const SYNC_WORKER_STALE_AFTER_DEFAULT_MS: u64 = 30_000;
+
+fn parse_health_issue_synth_limit_from_env() -> usize {
+    usize::try_from(parse_u64_env(
+        "SELENE_ADAPTER_HEALTH_ISSUE_SYNTH_LIMIT",
+        HEALTH_ISSUE_SYNTH_DEFAULT_LIMIT,
+    ))
+    .unwrap_or(usize::MAX)
+}
+
+fn health_severity_rank(severity: HealthSeverity) -> u8 {
+    match severity {
+        HealthSeverity::Critical => 2,
+        HealthSeverity::Warn => 1,
+        HealthSeverity::Info => 0,
+    }
+}
+
+// Client-supplied ids stay authoritative. Otherwise the id is derived from the query's own
+// fields, so identical queries share one correlation id and their commits stay idempotent.
+fn ui_health_report_correlation_id(request: &UiHealthReportQueryRequest) -> u64 {
+    if let Some(correlation_id) = request.correlation_id {
+        return correlation_id;
+    }
+    let stable_fields = serde_json::to_string(request).unwrap_or_default();
+    stable_voice_profile_seed_u64(&["ui_health_report_query", &stable_fields]).max(1)
+}
+
+const HEALTH_REPORT_CHANGES_MAX_PAGES: usize = 64;
+const HEALTH_REPORT_SNAPSHOT_MAX_ENTRIES: usize = 32;
+const HEALTH_SUBSCRIPTION_CHANNEL_CAPACITY: usize = 8;
+const HEALTH_SUBSCRIPTION_COALESCE_WINDOW_NS: u64 = 1_000_000_000;
+
+const UI_HEALTH_REPORT_CSV_HEADER: &str = "tenant_id,issue_id,owner_engine_id,severity,status,latest_reason_code,last_seen_at_ns,bcast_id,ack_state,issue_fingerprint,recurrence_observed,impact_summary,attempted_fix_actions,current_monitoring_evidence,unresolved_reason_exact";
+
+// Quotes a field holding a comma, quote or line break, doubling any quotes inside.
+fn csv_escape_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+// Missing values are empty cells, and `attempted_fix_actions` is joined with `;`.
+fn ui_health_report_csv_row(row: &UiHealthReportRow) -> String {
+    let optional = |value: &Option<String>| value.clone().unwrap_or_default();
+    [
+        row.tenant_id.clone(),
+        row.issue_id.clone(),
+        row.owner_engine_id.clone(),
+        row.severity.clone(),
+        row.status.clone(),
+        row.latest_reason_code.clone(),
+        row.last_seen_at_ns.to_string(),
+        optional(&row.bcast_id),
+        optional(&row.ack_state),
+        optional(&row.issue_fingerprint),
+        row.recurrence_observed.to_string(),
+        optional(&row.impact_summary),
+        row.attempted_fix_actions.join(";"),
+        optional(&row.current_monitoring_evidence),
+        optional(&row.unresolved_reason_exact),
+    ]
+    .iter()
+    .map(|field| csv_escape_field(field))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+fn health_subscription_mark(sync: &AdapterSyncHealth) -> AdapterHealthSubscriptionMark {
+    AdapterHealthSubscriptionMark {
+        dequeued_total: sync.worker.dequeued_total,
+        acked_total: sync.worker.acked_total,
+        retry_scheduled_total: sync.worker.retry_scheduled_total,
+        dead_lettered_total: sync.worker.dead_lettered_total,
+        queue: sync.queue.clone(),
+    }
+}
+
+fn health_report_row_unresolved(row: &UiHealthReportRow) -> bool {
+    row.status != "RESOLVED"
+}
+
+fn health_report_rows_differ(before: &UiHealthReportRow, after: &UiHealthReportRow) -> bool {
+    let mut before = before.clone();
+    before.last_seen_at_ns = after.last_seen_at_ns;
+    before != *after
+}
+
+fn diff_health_report_rows(
+    before: &[UiHealthReportRow],
+    after: &[UiHealthReportRow],
+) -> (Vec<String>, Vec<String>, Vec<String>) {
+    let before_by_id = before
+        .iter()
+        .map(|row| (row.issue_id.as_str(), row))
+        .collect::<BTreeMap<_, _>>();
+    let after_by_id = after
+        .iter()
+        .map(|row| (row.issue_id.as_str(), row))
+        .collect::<BTreeMap<_, _>>();
+
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    let mut resolved = Vec::new();
+    for (issue_id, row) in &after_by_id {
+        match before_by_id.get(issue_id) {
+            None => added.push((*issue_id).to_string()),
+            Some(prior) => {
+                if health_report_row_unresolved(prior) && !health_report_row_unresolved(row) {
+                    resolved.push((*issue_id).to_string());
+                } else if health_report_rows_differ(prior, row) {
+                    changed.push((*issue_id).to_string());
+                }
+            }
+        }
+    }
+    for (issue_id, prior) in &before_by_id {
+        if !after_by_id.contains_key(issue_id) && health_report_row_unresolved(prior) {
+            resolved.push((*issue_id).to_string());
+        }
+    }
+    resolved.sort();
+    (added, changed, resolved)
+}
+
 fn parse_tenant_id(raw: Option<&str>) -> Result<TenantId, String> {
     let tenant = raw.unwrap_or("tenant_a").trim();
     TenantId::new(tenant.to_string()).map_err(|err| format!("invalid tenant_id: {err:?}"))
 }
 
-fn parse_report_kind(raw: Option<&str>) -> HealthReportKind {
-    match raw
-        .unwrap_or("UNRESOLVED_ESCALATED")
-        .trim()
-        .to_ascii_uppercase()
-        .as_str()
-    {
-        "MISSED_STT" => HealthReportKind::MissedStt,
-        "ISSUE_STATUS" => HealthReportKind::IssueStatus,
-        _ => HealthReportKind::UnresolvedEscalated,
+fn report_kind_from_label(raw: &str) -> Option<HealthReportKind> {
+    match raw.trim().to_ascii_uppercase().as_str() {
+        "UNRESOLVED_ESCALATED" => Some(HealthReportKind::UnresolvedEscalated),
+        "MISSED_STT" => Some(HealthReportKind::MissedStt),
+        "ISSUE_STATUS" => Some(HealthReportKind::IssueStatus),
+        _ => None,
     }
 }
 
-fn parse_company_scope(raw: Option<&str>) -> HealthCompanyScope {
-    match raw
-        .unwrap_or("TENANT_ONLY")
-        .trim()
-        .to_ascii_uppercase()
-        .as_str()
-    {
-        "CROSS_TENANT_TENANT_ROWS" => HealthCompanyScope::CrossTenantTenantRows,
-        _ => HealthCompanyScope::TenantOnly,
+fn company_scope_from_label(raw: &str) -> Option<HealthCompanyScope> {
+    match raw.trim().to_ascii_uppercase().as_str() {
+        "TENANT_ONLY" => Some(HealthCompanyScope::TenantOnly),
+        "CROSS_TENANT_TENANT_ROWS" => Some(HealthCompanyScope::CrossTenantTenantRows),
+        _ => None,
     }
 }
 
+// A missing or unrecognised request value takes the configured default.
+fn parse_report_kind(raw: Option<&str>, default: HealthReportKind) -> HealthReportKind {
+    raw.and_then(report_kind_from_label).unwrap_or(default)
+}
+
+fn parse_company_scope(raw: Option<&str>, default: HealthCompanyScope) -> HealthCompanyScope {
+    raw.and_then(company_scope_from_label).unwrap_or(default)
+}
+
+fn parse_default_report_kind_from_env() -> HealthReportKind {
+    let Some(raw) = env::var("SELENE_ADAPTER_DEFAULT_REPORT_KIND")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+    else {
+        return HealthReportKind::UnresolvedEscalated;
+    };
+    report_kind_from_label(&raw).unwrap_or_else(|| {
+        tracing::warn!(
+            "SELENE_ADAPTER_DEFAULT_REPORT_KIND '{raw}' is not a report kind; using UNRESOLVED_ESCALATED"
+        );
+        HealthReportKind::UnresolvedEscalated
+    })
+}
+
+fn parse_default_company_scope_from_env() -> HealthCompanyScope {
+    let Some(raw) = env::var("SELENE_ADAPTER_DEFAULT_COMPANY_SCOPE")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+    else {
+        return HealthCompanyScope::TenantOnly;
+    };
+    company_scope_from_label(&raw).unwrap_or_else(|| {
+        tracing::warn!(
+            "SELENE_ADAPTER_DEFAULT_COMPANY_SCOPE '{raw}' is not a company scope; using TENANT_ONLY"
+        );
+        HealthCompanyScope::TenantOnly
+    })
+}
+
 fn parse_page_action(raw: Option<&str>) -> HealthPageAction {
     match raw.unwrap_or("FIRST").trim().to_ascii_uppercase().as_str() {
         "NEXT" => HealthPageAction::Next,
@@ -26410,12 +34436,15 @@ fn synth_health_issue_events(
     health: &AdapterHealthResponse,
     tenant: &TenantId,
     now_ns: u64,
+    deadlines: &AdapterHealthIssueDeadlinePolicy,
+    max_issues: usize,
 ) -> Vec<HealthIssueEvent> {
     let mut out = Vec::new();
 
     struct HealthIssueEventSeed<'a> {
         tenant: &'a TenantId,
         now_ns: u64,
+        deadlines: &'a AdapterHealthIssueDeadlinePolicy,
         issue_id: &'a str,
         engine_owner_id: &'a str,
         severity: HealthSeverity,
@@ -26435,6 +34464,7 @@ fn synth_health_issue_events(
         let HealthIssueEventSeed {
             tenant,
             now_ns,
+            deadlines,
             issue_id,
             engine_owner_id,
             severity,
@@ -26475,7 +34505,7 @@ fn synth_health_issue_events(
                 None
             },
             Some(MonotonicTimeNs(
-                now_ns.saturating_add(15 * 60 * 1_000_000_000),
+                now_ns.saturating_add(deadlines.deadline_ns(severity)),
             )),
             None,
             None,
@@ -26515,6 +34545,7 @@ fn synth_health_issue_events(
             HealthIssueEventSeed {
                 tenant,
                 now_ns,
+                deadlines,
                 issue_id: "sync_dead_letter",
                 engine_owner_id: "PH1.OS",
                 severity: HealthSeverity::Critical,
@@ -26543,6 +34574,7 @@ fn synth_health_issue_events(
             HealthIssueEventSeed {
                 tenant,
                 now_ns,
+                deadlines,
                 issue_id: "sync_retry_backlog",
                 engine_owner_id: "PH1.OS",
                 severity: HealthSeverity::Warn,
@@ -26569,6 +34601,7 @@ fn synth_health_issue_events(
             HealthIssueEventSeed {
                 tenant,
                 now_ns,
+                deadlines,
                 issue_id: "sync_replay_due",
                 engine_owner_id: "PH1.OS",
                 severity: HealthSeverity::Critical,
@@ -26589,12 +34622,93 @@ fn synth_health_issue_events(
         );
     }
 
+    if let Some(incident) = health
+        .sync
+        .improvement
+        .recent_builder_incidents
+        .last()
+        .filter(|_| builder_health_status(health) != "HEALTHY")
+    {
+        let refused = incident.status.starts_with("REFUSED");
+        add_event(
+            &mut out,
+            HealthIssueEventSeed {
+                tenant,
+                now_ns,
+                deadlines,
+                issue_id: "builder_run_failed",
+                engine_owner_id: "PH1.BUILDER",
+                severity: if refused {
+                    HealthSeverity::Warn
+                } else {
+                    HealthSeverity::Critical
+                },
+                status: HealthIssueStatus::Open,
+                reason_code: builder_incident_reason_code(&incident.status)
+                    .filter(|code| *code != 0)
+                    .map(ReasonCodeId)
+                    .unwrap_or(reason_codes::ADAPTER_BUILDER_RUN_FAILED),
+                bcast_id: None,
+                ack_state: None,
+                impact_summary: Some("The latest builder run was refused or failed.".to_string()),
+                attempted_fix_actions: vec!["builder auto-run".to_string()],
+                current_monitoring_evidence: Some(format!(
+                    "builder_status={} at_ns={}",
+                    incident.status, incident.at_ns
+                )),
+                unresolved_reason_exact: Some("builder has not completed a run since".to_string()),
+                issue_fingerprint: Some("builder_run_failed_fingerprint".to_string()),
+                recurrence_observed: Some(
+                    health.sync.improvement.recent_builder_incidents.len() > 1,
+                ),
+            },
+        );
+    }
+
+    if out.len() > max_issues {
+        // Critical issues first, then the most recently started. Ties keep generation order.
+        out.sort_by_key(|event| {
+            (
+                std::cmp::Reverse(health_severity_rank(event.severity)),
+                std::cmp::Reverse(event.started_at.0),
+            )
+        });
+        let omitted = out.len() - max_issues;
+        out.truncate(max_issues);
+        add_event(
+            &mut out,
+            HealthIssueEventSeed {
+                tenant,
+                now_ns,
+                deadlines,
+                issue_id: "health_issues_omitted",
+                engine_owner_id: "PH1.HEALTH",
+                severity: HealthSeverity::Info,
+                status: HealthIssueStatus::Open,
+                reason_code: reason_codes::ADAPTER_HEALTH_ISSUES_OMITTED,
+                bcast_id: None,
+                ack_state: None,
+                impact_summary: Some(format!(
+                    "{omitted} lower-priority health issues were left out of this report."
+                )),
+                attempted_fix_actions: vec!["cap synthesized issues".to_string()],
+                current_monitoring_evidence: Some(format!(
+                    "omitted_issue_count={omitted} synth_issue_limit={max_issues}"
+                )),
+                unresolved_reason_exact: Some("synthesized issue limit reached".to_string()),
+                issue_fingerprint: Some("health_issues_omitted_fingerprint".to_string()),
+                recurrence_observed: None,
+            },
+        );
+    }
+
     if out.is_empty() {
         add_event(
             &mut out,
             HealthIssueEventSeed {
                 tenant,
                 now_ns,
+                deadlines,
                 issue_id: "health_nominal",
                 engine_owner_id: "PH1.HEALTH",
                 severity: HealthSeverity::Info,
@@ -26615,20 +34729,24 @@ fn synth_health_issue_events(
     out
 }
 
-const UI_HEALTH_CHECKS: [(&str, &str); 8] = [
+const UI_HEALTH_CHECKS: [(&str, &str); 11] = [
     ("VOICE", "Voice"),
     ("WAKE", "Wake"),
     ("SYNC", "Sync"),
+    ("SYNC_REPLAY_DUE", "Sync Replay Due"),
+    ("SYNC_WORKER_LIVENESS", "Sync Worker Liveness"),
     ("STT", "STT"),
     ("TTS", "TTS"),
     ("DELIVERY", "Delivery"),
     ("BUILDER", "Builder"),
+    ("PROVIDER_LATENCY", "Provider Latency"),
     ("MEMORY", "Memory"),
 ];
 
 fn build_ui_health_checks_response(
     health: &AdapterHealthResponse,
     generated_at_ns: u64,
+    sync_worker_stale_after_ns: u64,
 ) -> UiHealthChecksResponse {
     let sync_open = health
         .sync
@@ -26649,6 +34767,20 @@ fn build_ui_health_checks_response(
         };
     let builder_status = builder_health_status(health);
     let builder_open = if builder_status == "HEALTHY" { 0 } else { 1 };
+    let provider_latency_status = provider_latency_check_status(health);
+    let provider_latency_open = if provider_latency_status == "HEALTHY" {
+        0
+    } else {
+        1
+    };
+    let sync_replay_due_status = sync_replay_due_check_status(health, generated_at_ns);
+    let sync_worker_liveness_status =
+        sync_worker_liveness_check_status(health, generated_at_ns, sync_worker_stale_after_ns);
+    let sync_worker_liveness_open = if sync_worker_liveness_status == "HEALTHY" {
+        0
+    } else {
+        1
+    };
 
     let checks = UI_HEALTH_CHECKS
         .iter()
@@ -26659,11 +34791,26 @@ fn build_ui_health_checks_response(
                     sync_open,
                     health.sync.worker.last_pass_at_ns,
                 ),
+                "SYNC_REPLAY_DUE" => (
+                    sync_replay_due_status.to_string(),
+                    health.sync.queue.replay_due_count,
+                    health.sync.worker.last_pass_at_ns,
+                ),
+                "SYNC_WORKER_LIVENESS" => (
+                    sync_worker_liveness_status.to_string(),
+                    sync_worker_liveness_open,
+                    health.sync.worker.last_pass_at_ns,
+                ),
                 "BUILDER" => (
                     builder_status.to_string(),
                     builder_open,
                     health.sync.worker.last_pass_at_ns,
                 ),
+                "PROVIDER_LATENCY" => (
+                    provider_latency_status.to_string(),
+                    provider_latency_open,
+                    health.sync.worker.last_pass_at_ns,
+                ),
                 _ => ("HEALTHY".to_string(), 0, health.sync.worker.last_pass_at_ns),
             };
             UiHealthCheckRow {
@@ -26687,16 +34834,18 @@ fn build_ui_health_detail_response(
     health: &AdapterHealthResponse,
     check_id: &str,
     generated_at_ns: u64,
+    deadlines: &AdapterHealthIssueDeadlinePolicy,
 ) -> Result<UiHealthDetailResponse, String> {
     let Some((normalized, label)) = normalize_ui_health_check_id(check_id) else {
         return Err(format!(
-            "invalid health check id '{}'; expected one of VOICE|WAKE|SYNC|STT|TTS|DELIVERY|BUILDER|MEMORY",
+            "invalid health check id '{}'; expected one of VOICE|WAKE|SYNC|SYNC_REPLAY_DUE|SYNC_WORKER_LIVENESS|STT|TTS|DELIVERY|BUILDER|PROVIDER_LATENCY|MEMORY",
             check_id
         ));
     };
     let (summary, issues, timeline) = match normalized {
-        "SYNC" => build_sync_detail(health),
-        "BUILDER" => build_builder_detail(health),
+        "SYNC" => build_sync_detail(health, deadlines),
+        "SYNC_REPLAY_DUE" => build_sync_replay_due_detail(health, deadlines),
+        "BUILDER" => build_builder_detail(health, deadlines),
         _ => (
             UiHealthSummary {
                 open_issues: 0,
@@ -26902,6 +35051,14 @@ fn normalize_ui_health_check_id(raw: &str) -> Option<(&'static str, &'static str
         .copied()
 }
 
+// Refusals are recorded as `REFUSED[_READ_ONLY]:<stage>:<reason_code>`; errors carry no code.
+fn builder_incident_reason_code(status: &str) -> Option<u32> {
+    if !status.starts_with("REFUSED") {
+        return None;
+    }
+    status.rsplit(':').next()?.parse().ok()
+}
+
 fn builder_health_status(health: &AdapterHealthResponse) -> &'static str {
     let last = health
         .sync
@@ -26918,8 +35075,160 @@ fn builder_health_status(health: &AdapterHealthResponse) -> &'static str {
     }
 }
 
+const PROVIDER_LATENCY_WINDOW_DEFAULT: u64 = 50;
+const PROVIDER_LATENCY_MIN_SAMPLES: usize = 5;
+const PROVIDER_LATENCY_WARN_P95_MS_DEFAULT: u64 = 1_500;
+const PROVIDER_LATENCY_CRITICAL_P95_MS_DEFAULT: u64 = 4_000;
+
+const PROVIDER_LATENCY_WINDOW_MAX: usize = 10_000;
+
+/// Provider latency samples kept for the p95 and the p95 levels that turn
+/// `provider_latency.status` to `WARN` and `CRITICAL`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdapterProviderLatencyPolicy {
+    window: usize,
+    warn_p95_ms: u32,
+    critical_p95_ms: u32,
+}
+
+impl Default for AdapterProviderLatencyPolicy {
+    fn default() -> Self {
+        Self::new(
+            PROVIDER_LATENCY_WINDOW_DEFAULT as usize,
+            PROVIDER_LATENCY_WARN_P95_MS_DEFAULT as u32,
+            PROVIDER_LATENCY_CRITICAL_P95_MS_DEFAULT as u32,
+        )
+    }
+}
+
+impl AdapterProviderLatencyPolicy {
+    /// The window is capped at 10,000 samples, and a critical level below the warn level is
+    /// raised to it. A window of 0 would never hold a sample, so it is rejected with a warning
+    /// and the default of 50 is used.
+    pub fn new(window: usize, warn_p95_ms: u32, critical_p95_ms: u32) -> Self {
+        let window = if window == 0 {
+            tracing::warn!(
+                "provider latency window of 0 rejected; using {PROVIDER_LATENCY_WINDOW_DEFAULT}"
+            );
+            PROVIDER_LATENCY_WINDOW_DEFAULT as usize
+        } else {
+            window.min(PROVIDER_LATENCY_WINDOW_MAX)
+        };
+        Self {
+            window,
+            warn_p95_ms,
+            critical_p95_ms: critical_p95_ms.max(warn_p95_ms),
+        }
+    }
+
+    fn from_env() -> Self {
+        // Read without `parse_u64_env`, which drops 0 silently, so `new` can warn about it.
+        let window = env::var("SELENE_ADAPTER_PROVIDER_LATENCY_WINDOW")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(PROVIDER_LATENCY_WINDOW_DEFAULT);
+        let warn = parse_u64_env(
+            "SELENE_ADAPTER_PROVIDER_LATENCY_WARN_P95_MS",
+            PROVIDER_LATENCY_WARN_P95_MS_DEFAULT,
+        );
+        let critical = parse_u64_env(
+            "SELENE_ADAPTER_PROVIDER_LATENCY_CRITICAL_P95_MS",
+            PROVIDER_LATENCY_CRITICAL_P95_MS_DEFAULT,
+        );
+        Self::new(
+            window.min(PROVIDER_LATENCY_WINDOW_MAX as u64) as usize,
+            warn.min(u32::MAX as u64) as u32,
+            critical.min(u32::MAX as u64) as u32,
+        )
+    }
+}
+
+fn provider_latency_health(
+    samples_ms: &VecDeque<u32>,
+    policy: AdapterProviderLatencyPolicy,
+) -> AdapterProviderLatencyHealth {
+    let AdapterProviderLatencyPolicy {
+        window: window_size,
+        warn_p95_ms,
+        critical_p95_ms,
+    } = policy;
+    let window = samples_ms
+        .iter()
+        .rev()
+        .take(window_size)
+        .copied()
+        .collect::<Vec<_>>();
+    let p95_ms = percentile_p95_u32(&window);
+    let status = match p95_ms {
+        Some(p95) if window.len() >= PROVIDER_LATENCY_MIN_SAMPLES && p95 >= critical_p95_ms => {
+            "CRITICAL"
+        }
+        Some(p95) if window.len() >= PROVIDER_LATENCY_MIN_SAMPLES && p95 >= warn_p95_ms => "WARN",
+        _ => "OK",
+    };
+    AdapterProviderLatencyHealth {
+        status: status.to_string(),
+        sample_count: window.len().min(u32::MAX as usize) as u32,
+        window_size: window_size.min(u32::MAX as usize) as u32,
+        p95_ms,
+        warn_p95_ms,
+        critical_p95_ms,
+    }
+}
+
+fn provider_latency_check_status(health: &AdapterHealthResponse) -> &'static str {
+    match health.provider_latency.status.as_str() {
+        "CRITICAL" => "CRITICAL",
+        "WARN" => "AT_RISK",
+        _ => "HEALTHY",
+    }
+}
+
+const SYNC_REPLAY_DUE_CRITICAL_COUNT: u32 = 10;
+const SYNC_REPLAY_DUE_CRITICAL_AGE_NS: u64 = 15 * 60 * 1_000_000_000;
+
+// Any replay-due job is a risk. A large backlog, or one whose oldest lease expired long ago,
+// points at a stuck downstream and escalates to critical.
+fn sync_replay_due_check_status(health: &AdapterHealthResponse, now_ns: u64) -> &'static str {
+    let queue = &health.sync.queue;
+    if queue.replay_due_count == 0 {
+        return "HEALTHY";
+    }
+    let oldest_age_ns = queue
+        .replay_due_oldest_at_ns
+        .map(|oldest| now_ns.saturating_sub(oldest))
+        .unwrap_or(0);
+    if queue.replay_due_count >= SYNC_REPLAY_DUE_CRITICAL_COUNT
+        || oldest_age_ns >= SYNC_REPLAY_DUE_CRITICAL_AGE_NS
+    {
+        "CRITICAL"
+    } else {
+        "AT_RISK"
+    }
+}
+
+// No pass yet means the worker has not started, which is not a stall.
+fn sync_worker_liveness_check_status(
+    health: &AdapterHealthResponse,
+    now_ns: u64,
+    stale_after_ns: u64,
+) -> &'static str {
+    let Some(last_pass_at_ns) = health.sync.worker.last_pass_at_ns else {
+        return "HEALTHY";
+    };
+    let since_last_pass_ns = now_ns.saturating_sub(last_pass_at_ns);
+    if since_last_pass_ns >= stale_after_ns.saturating_mul(2) {
+        "CRITICAL"
+    } else if since_last_pass_ns >= stale_after_ns {
+        "AT_RISK"
+    } else {
+        "HEALTHY"
+    }
+}
+
 fn build_sync_detail(
     health: &AdapterHealthResponse,
+    deadlines: &AdapterHealthIssueDeadlinePolicy,
 ) -> (
     UiHealthSummary,
     Vec<UiHealthIssueRow>,
@@ -26928,6 +35237,8 @@ fn build_sync_detail(
     let mut issues = Vec::new();
     let mut timeline = Vec::new();
     let at_ns = health.sync.worker.last_pass_at_ns;
+    let deadline_at =
+        |severity: &str| at_ns.map(|v| v.saturating_add(deadlines.ui_deadline_ns(severity)));
 
     if health.sync.queue.retry_pending_count > 0 {
         issues.push(UiHealthIssueRow {
@@ -26940,7 +35251,7 @@ fn build_sync_detail(
             status: "OPEN".to_string(),
             resolution_state: "UNRESOLVED".to_string(),
             blocker: Some("Retry queue backlog not drained.".to_string()),
-            unresolved_deadline_at_ns: at_ns.map(|v| v.saturating_add(15 * 60 * 1_000_000_000)),
+            unresolved_deadline_at_ns: deadline_at("MEDIUM"),
         });
         timeline.push(UiHealthTimelineEntry {
             issue_id: "sync_retry_backlog".to_string(),
@@ -26950,7 +35261,7 @@ fn build_sync_detail(
             reason_code: reason_codes::ADAPTER_SYNC_RETRY.0.to_string(),
             evidence_ref: Some("sync.queue.retry_pending_count".to_string()),
             blocker: Some("Retry queue backlog not drained.".to_string()),
-            unresolved_deadline_at_ns: at_ns.map(|v| v.saturating_add(15 * 60 * 1_000_000_000)),
+            unresolved_deadline_at_ns: deadline_at("MEDIUM"),
         });
     }
     if health.sync.queue.dead_letter_count > 0 {
@@ -26964,7 +35275,7 @@ fn build_sync_detail(
             status: "ESCALATED".to_string(),
             resolution_state: "UNRESOLVED".to_string(),
             blocker: Some("Dead-letter queue is non-zero.".to_string()),
-            unresolved_deadline_at_ns: at_ns.map(|v| v.saturating_add(15 * 60 * 1_000_000_000)),
+            unresolved_deadline_at_ns: deadline_at("CRITICAL"),
         });
         timeline.push(UiHealthTimelineEntry {
             issue_id: "sync_dead_letter".to_string(),
@@ -26974,7 +35285,7 @@ fn build_sync_detail(
             reason_code: reason_codes::ADAPTER_SYNC_DEADLETTER.0.to_string(),
             evidence_ref: Some("sync.queue.dead_letter_count".to_string()),
             blocker: Some("Dead-letter queue is non-zero.".to_string()),
-            unresolved_deadline_at_ns: at_ns.map(|v| v.saturating_add(15 * 60 * 1_000_000_000)),
+            unresolved_deadline_at_ns: deadline_at("CRITICAL"),
         });
     }
     if health.sync.queue.replay_due_count > 0 {
@@ -26988,7 +35299,7 @@ fn build_sync_detail(
             status: "OPEN".to_string(),
             resolution_state: "UNRESOLVED".to_string(),
             blocker: Some("Replay-due queue exceeds threshold.".to_string()),
-            unresolved_deadline_at_ns: at_ns.map(|v| v.saturating_add(15 * 60 * 1_000_000_000)),
+            unresolved_deadline_at_ns: deadline_at("CRITICAL"),
         });
         timeline.push(UiHealthTimelineEntry {
             issue_id: "sync_replay_due".to_string(),
@@ -26998,7 +35309,7 @@ fn build_sync_detail(
             reason_code: reason_codes::ADAPTER_SYNC_REPLAY_DUE.0.to_string(),
             evidence_ref: Some("sync.queue.replay_due_count".to_string()),
             blocker: Some("Replay-due queue exceeds threshold.".to_string()),
-            unresolved_deadline_at_ns: at_ns.map(|v| v.saturating_add(15 * 60 * 1_000_000_000)),
+            unresolved_deadline_at_ns: deadline_at("CRITICAL"),
         });
     }
 
@@ -27029,8 +35340,39 @@ fn build_sync_detail(
     (summary, issues, timeline)
 }
 
+fn build_sync_replay_due_detail(
+    health: &AdapterHealthResponse,
+    deadlines: &AdapterHealthIssueDeadlinePolicy,
+) -> (
+    UiHealthSummary,
+    Vec<UiHealthIssueRow>,
+    Vec<UiHealthTimelineEntry>,
+) {
+    let (_, issues, timeline) = build_sync_detail(health, deadlines);
+    let issues = issues
+        .into_iter()
+        .filter(|issue| issue.issue_id == "sync_replay_due")
+        .collect::<Vec<_>>();
+    let timeline = timeline
+        .into_iter()
+        .filter(|entry| entry.issue_id == "sync_replay_due" || entry.issue_id == "sync_nominal")
+        .collect::<Vec<_>>();
+    let summary = UiHealthSummary {
+        open_issues: issues.len() as u32,
+        critical_open_count: issues
+            .iter()
+            .filter(|issue| issue.severity == "CRITICAL")
+            .count() as u32,
+        auto_resolved_24h_count: 0,
+        escalated_24h_count: 0,
+        mttr_ms: None,
+    };
+    (summary, issues, timeline)
+}
+
 fn build_builder_detail(
     health: &AdapterHealthResponse,
+    deadlines: &AdapterHealthIssueDeadlinePolicy,
 ) -> (
     UiHealthSummary,
     Vec<UiHealthIssueRow>,
@@ -27046,15 +35388,17 @@ fn build_builder_detail(
         .clone()
         .unwrap_or_else(|| "NO_BUILDER_ACTIVITY".to_string());
     let status = builder_health_status(health);
+    let severity = if status == "CRITICAL" {
+        "CRITICAL"
+    } else {
+        "HIGH"
+    };
+    let deadline_at = at_ns.map(|v| v.saturating_add(deadlines.ui_deadline_ns(severity)));
 
     if status != "HEALTHY" {
         issues.push(UiHealthIssueRow {
             issue_id: "builder_health".to_string(),
-            severity: if status == "CRITICAL" {
-                "CRITICAL".to_string()
-            } else {
-                "HIGH".to_string()
-            },
+            severity: severity.to_string(),
             issue_type: "BUILDER_STATUS".to_string(),
             engine_owner: "PH1.BUILDER".to_string(),
             first_seen_at_ns: at_ns,
@@ -27062,7 +35406,7 @@ fn build_builder_detail(
             status: "OPEN".to_string(),
             resolution_state: "UNRESOLVED".to_string(),
             blocker: Some("Builder status is outside healthy range.".to_string()),
-            unresolved_deadline_at_ns: at_ns.map(|v| v.saturating_add(15 * 60 * 1_000_000_000)),
+            unresolved_deadline_at_ns: deadline_at,
         });
     }
 
@@ -27081,9 +35425,33 @@ fn build_builder_detail(
         unresolved_deadline_at_ns: if status == "HEALTHY" {
             None
         } else {
-            at_ns.map(|v| v.saturating_add(15 * 60 * 1_000_000_000))
+            deadline_at
         },
     });
+    for incident in health
+        .sync
+        .improvement
+        .recent_builder_incidents
+        .iter()
+        .rev()
+    {
+        timeline.push(UiHealthTimelineEntry {
+            issue_id: "builder_health".to_string(),
+            at_ns: Some(incident.at_ns),
+            action_id: if incident.status.starts_with("REFUSED") {
+                "BUILDER_RUN_REFUSED".to_string()
+            } else {
+                "BUILDER_RUN_ERROR".to_string()
+            },
+            result: incident.status.clone(),
+            reason_code: builder_incident_reason_code(&incident.status)
+                .unwrap_or(0)
+                .to_string(),
+            evidence_ref: Some("sync.improvement.recent_builder_incidents".to_string()),
+            blocker: None,
+            unresolved_deadline_at_ns: None,
+        });
+    }
     let summary = UiHealthSummary {
         open_issues: issues.len() as u32,
         critical_open_count: issues
@@ -27680,6 +36048,13 @@ fn session_posture_evidence_response_returns_current_device_fields_for_session()
         selene_text_final: None,
         audio_capture_ref: None,
         visual_input_ref: None,
+        provenance_mode: None,
+        user_text_part_seq: None,
+        model_override: None,
+        pre_fetched_tool_response: None,
+        language_hint: None,
+        language_hint_confidence: None,
+        request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
     };
     guard.outbox_records.insert(
         operation_id.clone(),
@@ -27744,6 +36119,10 @@ fn session_posture_evidence_response_returns_current_device_fields_for_session()
                 deep_research: None,
                 screen_lifecycle_action: None,
                 session_lifecycle_action: None,
+                stt_mode: String::new(),
+                keep_listening: false,
+                next_move_kind: Some(VoiceTurnNextMove::Respond),
+                outcome_kind: None,
             }),
         },
     );
@@ -27991,7 +36370,7 @@ mod tests {
     };
     use selene_kernel_contracts::ph1n::FieldKey;
     use selene_kernel_contracts::ph1onb::{
-        OnboardingSessionId, ONB_ACCESS_INSTANCE_CREATE_COMMIT, ONB_COMPLETE_COMMIT,
+        OnboardingSessionId, ProofType, ONB_ACCESS_INSTANCE_CREATE_COMMIT, ONB_COMPLETE_COMMIT,
         ONB_EMPLOYEE_PHOTO_CAPTURE_SEND_COMMIT, ONB_EMPLOYEE_SENDER_VERIFY_COMMIT,
         ONB_PRIMARY_DEVICE_CONFIRM_COMMIT, ONB_SESSION_START_DRAFT, ONB_TERMS_ACCEPT_COMMIT,
     };
@@ -28012,6 +36391,7 @@ mod tests {
     use selene_kernel_contracts::ph1x::{
         PendingState, ThreadPolicyFlags, ThreadState as KernelThreadState,
     };
+    use selene_os::device_artifact_sync::DeviceArtifactSyncSenderRuntime;
     use selene_storage::ph1f::{
         AccessDeviceTrustLevel, AccessLifecycleState, AccessMode, AccessVerificationLevel,
         DeviceRecord, DeviceRevocationKind, DeviceRevocationPostureInput, DeviceSyncDecision,
@@ -28157,6 +36537,13 @@ mod tests {
                 timing_overruns: Some(0),
             }),
             visual_input_ref: None,
+            provenance_mode: None,
+            user_text_part_seq: None,
+            model_override: None,
+            pre_fetched_tool_response: None,
+            language_hint: None,
+            language_hint_confidence: None,
+            request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
         }
     }
 
@@ -29626,6 +38013,7 @@ mod tests {
         let path = std::env::temp_dir().join(format!("selene_adapter_{label}_{seed}.jsonl"));
         let _ = std::fs::remove_file(&path);
         let _ = std::fs::remove_file(adapter_persistence_state_path(&path));
+        let _ = std::fs::remove_file(adapter_store_snapshot_path(&path));
         path
     }
 
@@ -29648,14 +38036,11 @@ mod tests {
 
     pub(super) fn cleanup_persistence_files_for_test(journal_path: &Path) {
         let _ = std::fs::remove_file(journal_path);
+        let _ = std::fs::remove_file(adapter_store_snapshot_path(journal_path));
         let state_path = adapter_persistence_state_path(journal_path);
         let _ = std::fs::remove_file(&state_path);
         let _ = std::fs::remove_file(quarantined_persistence_path(&state_path, "state_corrupt"));
         let _ = std::fs::remove_file(quarantined_persistence_path(&state_path, "state_integrity"));
-        let _ = std::fs::remove_file(quarantined_persistence_path(
-            journal_path,
-            "legacy_journal_corrupt",
-        ));
     }
 
     fn with_scoped_runtime_node_id<T>(node_id: &str, f: impl FnOnce() -> T) -> T {
@@ -30192,6 +38577,7 @@ mod tests {
         });
         let input = build_vision_turn_input_from_adapter_request(
             &request,
+            &AdapterVisionSourceKindPolicy::default(),
             CorrelationId(request.correlation_id as u128),
             TurnId(request.turn_id),
         )
@@ -30223,6 +38609,7 @@ mod tests {
         });
         let input = build_vision_turn_input_from_adapter_request(
             &request,
+            &AdapterVisionSourceKindPolicy::default(),
             CorrelationId(request.correlation_id as u128),
             TurnId(request.turn_id),
         )
@@ -30230,6 +38617,174 @@ mod tests {
         assert!(input.is_none());
     }
 
+    #[test]
+    fn at_adapter_vision_02b_source_kind_policy_skips_photos_and_keeps_screenshots() {
+        let policy = parse_vision_source_kind_policy("image=false");
+        assert!(!policy.allows(VisualSourceKind::Image));
+        assert!(policy.allows(VisualSourceKind::Screenshot));
+        assert!(policy.allows(VisualSourceKind::Diagram));
+
+        let runtime = AdapterRuntime {
+            ph1d_live_adapter: None,
+            vision_source_kind_policy: policy,
+            ..AdapterRuntime::default()
+        };
+        let vision_request = |correlation_id: u64, turn_id: u64, source_kind: &str| {
+            let mut req = base_request();
+            req.correlation_id = correlation_id;
+            req.turn_id = turn_id;
+            req.now_ns = Some(turn_id);
+            req.user_text_final = Some("What does this show?".to_string());
+            req.visual_input_ref = Some(VoiceTurnVisualInputRef {
+                turn_opt_in_enabled: true,
+                source_id: Some(format!("vision_source_kind_{correlation_id}")),
+                source_kind: Some(source_kind.to_string()),
+                image_ref: Some("image://capture_kind_policy".to_string()),
+                blob_ref: None,
+                visible_tokens: vec![],
+            });
+            req
+        };
+
+        let photo = vision_request(10_321, 20_321, "IMAGE");
+        assert_eq!(
+            disallowed_visual_source_kind(&photo, &policy),
+            Some(VisualSourceKind::Image)
+        );
+        assert!(build_vision_turn_input_from_adapter_request(
+            &photo,
+            &policy,
+            CorrelationId(photo.correlation_id as u128),
+            TurnId(photo.turn_id),
+        )
+        .unwrap()
+        .is_none());
+        let screenshot = vision_request(10_322, 20_322, "SCREENSHOT");
+        assert_eq!(disallowed_visual_source_kind(&screenshot, &policy), None);
+        assert!(build_vision_turn_input_from_adapter_request(
+            &screenshot,
+            &policy,
+            CorrelationId(screenshot.correlation_id as u128),
+            TurnId(screenshot.turn_id),
+        )
+        .unwrap()
+        .is_some());
+
+        let out = runtime
+            .run_voice_turn(photo)
+            .expect("disallowed photo must not fail the text turn");
+        assert_eq!(out.status, "ok");
+        let store = runtime.store.lock().expect("store lock should succeed");
+        let photo_rows = store.ph1feedback_audit_rows(CorrelationId(10_321));
+        assert!(photo_rows.iter().any(|row| {
+            feedback_event_type_matches(row, "ToolFail")
+                && row.reason_code == reason_codes::ADAPTER_VISION_SOURCE_KIND_DISALLOWED
+        }));
+    }
+
+    #[test]
+    fn at_adapter_vision_03_bootstrap_failure_skips_vision_and_keeps_text_turn() {
+        static ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+        let env_lock = ENV_LOCK.get_or_init(|| Mutex::new(()));
+        let _guard = env_lock.lock().expect("env lock poisoned");
+        let runtime = AdapterRuntime {
+            ph1d_live_adapter: None,
+            ..AdapterRuntime::default()
+        };
+        let vision_request = |correlation_id: u64, turn_id: u64| {
+            let mut req = base_request();
+            req.correlation_id = correlation_id;
+            req.turn_id = turn_id;
+            req.now_ns = Some(turn_id);
+            req.user_text_final = Some("What is on this invoice?".to_string());
+            req.visual_input_ref = Some(VoiceTurnVisualInputRef {
+                turn_opt_in_enabled: true,
+                source_id: Some("vision_source_adapter_3".to_string()),
+                source_kind: Some("IMAGE".to_string()),
+                image_ref: Some("image://invoice_capture_003".to_string()),
+                blob_ref: None,
+                visible_tokens: vec![VoiceTurnVisualTokenRef {
+                    token: "invoice".to_string(),
+                    x: None,
+                    y: None,
+                    w: None,
+                    h: None,
+                }],
+            });
+            req
+        };
+
+        let out = runtime
+            .run_voice_turn(vision_request(10_301, 20_301))
+            .expect("text turn must continue when vision bootstrap fails");
+        assert_eq!(out.status, "ok");
+        let store = runtime.store.lock().expect("store lock should succeed");
+        let feedback_rows = store.ph1feedback_audit_rows(CorrelationId(10_301));
+        assert!(feedback_rows.iter().any(|row| {
+            feedback_event_type_matches(row, "ToolFail")
+                && row.reason_code == ph1vision_reason_codes::PH1_VISION_INTERNAL_PIPELINE_ERROR
+        }));
+        drop(store);
+
+        let scope = ScopedEnvVar::set("SELENE_ADAPTER_VISION_FAILURE_STRICT", "true");
+        let strict = runtime.run_voice_turn(vision_request(10_302, 20_302));
+        drop(scope);
+        assert!(strict.is_err());
+
+        let mut vision_only = vision_request(10_303, 20_303);
+        vision_only.user_text_final = None;
+        vision_only.audio_capture_ref = None;
+        assert!(!voice_turn_has_non_vision_input(&vision_only));
+    }
+
+    struct FailingPh1nEngine;
+
+    impl Ph1nEngine for FailingPh1nEngine {
+        fn run(&self, _req: &Ph1nRequest) -> Result<Ph1nResponse, ContractViolation> {
+            Err(ContractViolation::InvalidValue {
+                field: "ph1n_request.transcript_ok",
+                reason: "forced test failure",
+            })
+        }
+    }
+
+    #[test]
+    fn at_adapter_nlp_01_engine_failure_passes_transcript_through_to_ph1x() {
+        let runtime = AdapterRuntime {
+            ph1n_runtime: AdapterPh1nEngineHandle(Arc::new(FailingPh1nEngine)),
+            ..AdapterRuntime::default()
+        };
+        let nlp_request = |correlation_id: u64, turn_id: u64| {
+            let mut req = base_request();
+            req.correlation_id = correlation_id;
+            req.turn_id = turn_id;
+            req.now_ns = Some(turn_id);
+            req.user_text_final = Some("Tell me something encouraging".to_string());
+            req
+        };
+
+        let out = runtime
+            .run_voice_turn(nlp_request(10_311, 20_311))
+            .expect("turn must continue when PH1.N fails");
+        assert_eq!(out.status, "ok");
+        assert!(!out.response_text.trim().is_empty());
+        let store = runtime.store.lock().expect("store lock should succeed");
+        let feedback_rows = store.ph1feedback_audit_rows(CorrelationId(10_311));
+        assert!(feedback_rows.iter().any(|row| {
+            feedback_event_type_matches(row, "ToolFail")
+                && row.reason_code == reason_codes::ADAPTER_PH1N_PASS_THROUGH_FALLBACK
+        }));
+        drop(store);
+
+        let strict_runtime = AdapterRuntime {
+            ph1n_runtime: AdapterPh1nEngineHandle(Arc::new(FailingPh1nEngine)),
+            nlp_failure_strict: true,
+            ..AdapterRuntime::default()
+        };
+        let strict = strict_runtime.run_voice_turn(nlp_request(10_312, 20_312));
+        assert!(strict.is_err());
+    }
+
     #[test]
     fn run2_desktop_request_builder_sets_runtime_tenant_for_nlp() {
         let mut request = base_request();
@@ -30241,8 +38796,12 @@ mod tests {
 
         let actor_user_id =
             UserId::new(request.actor_user_id.clone()).expect("actor user id must parse");
-        let runtime_tenant_scope =
-            resolve_tenant_scope(request.tenant_id.clone(), &actor_user_id, None);
+        let runtime_tenant_scope = resolve_tenant_scope(
+            request.tenant_id.clone(),
+            &actor_user_id,
+            None,
+            TENANT_DELIMITER_DEFAULT,
+        );
         let nlp_req = build_base_nlp_request_for_vision_handoff(
             &request,
             request.user_text_final.as_deref(),
@@ -30356,7 +38915,8 @@ mod tests {
                 app_instance_id: "ios_instance_run1_adapter_bad".to_string(),
                 deep_link_nonce: "nonce_run1_adapter_bad".to_string(),
             })
-            .expect_err("bad signature must fail closed");
+            .expect_err("bad signature must fail closed")
+            .to_string();
         assert!(err.contains("TOKEN_SIGNATURE_INVALID"));
     }
 
@@ -30628,11 +39188,18 @@ mod tests {
             Some("ACCESSINSTANCECREATED")
         );
         assert!(access.access_engine_instance_id.is_some());
+        assert_eq!(
+            runtime
+                .onboarding_access_engine_instance(&onboarding_session_id)
+                .expect("link lookup should succeed"),
+            None,
+            "the link is recorded only once onboarding completes"
+        );
 
         let complete = runtime
             .run_onboarding_continue(OnboardingContinueAdapterRequest {
                 correlation_id: 72_001,
-                onboarding_session_id,
+                onboarding_session_id: onboarding_session_id.clone(),
                 idempotency_key: "runc-adapter-complete".to_string(),
                 tenant_id: Some("tenant_1".to_string()),
                 action: "COMPLETE_COMMIT".to_string(),
@@ -30654,6 +39221,18 @@ mod tests {
         assert_eq!(complete.onboarding_status.as_deref(), Some("COMPLETE"));
         assert!(complete.access_engine_instance_id.is_some());
         assert!(complete.voice_artifact_sync_receipt_ref.is_some());
+
+        let link = runtime
+            .onboarding_access_engine_instance(&onboarding_session_id)
+            .expect("link lookup should succeed")
+            .expect("completed onboarding must link its access-engine instance");
+        assert_eq!(link.onboarding_session_id, onboarding_session_id);
+        assert_eq!(
+            Some(link.access_engine_instance_id),
+            complete.access_engine_instance_id
+        );
+        assert_eq!(link.tenant_id.as_deref(), Some("tenant_1"));
+        assert!(link.completed_at_ns > 0);
     }
 
     #[test]
@@ -31169,7 +39748,8 @@ mod tests {
                 photo_blob_ref: None,
                 sender_decision: None,
             })
-            .expect_err("access should fail before sender verification");
+            .expect_err("access should fail before sender verification")
+            .to_string();
         assert!(access_err.contains("ONB_SENDER_VERIFICATION_REQUIRED_BEFORE_ACCESS_PROVISION"));
 
         let photo = runtime
@@ -31261,7 +39841,8 @@ mod tests {
                 photo_blob_ref: None,
                 sender_decision: None,
             })
-            .expect_err("access should fail before voice enrollment");
+            .expect_err("access should fail before voice enrollment")
+            .to_string();
         assert!(
             access_before_voice_err.contains("ONB_VOICE_ENROLL_REQUIRED_BEFORE_ACCESS_PROVISION")
         );
@@ -31309,7 +39890,8 @@ mod tests {
                 photo_blob_ref: None,
                 sender_decision: None,
             })
-            .expect_err("access should fail before emo/persona lock");
+            .expect_err("access should fail before emo/persona lock")
+            .to_string();
         assert!(
             access_before_emo_err.contains("ONB_EMO_PERSONA_LOCK_REQUIRED_BEFORE_ACCESS_PROVISION")
         );
@@ -31334,7 +39916,8 @@ mod tests {
                 photo_blob_ref: None,
                 sender_decision: None,
             })
-            .expect_err("complete should fail before emo/persona lock");
+            .expect_err("complete should fail before emo/persona lock")
+            .to_string();
         assert!(complete_before_emo_err.contains("ONB_EMO_PERSONA_LOCK_REQUIRED_BEFORE_COMPLETE"));
 
         let emo = runtime
@@ -31380,7 +39963,8 @@ mod tests {
                 photo_blob_ref: None,
                 sender_decision: None,
             })
-            .expect_err("complete should fail before access provisioning");
+            .expect_err("complete should fail before access provisioning")
+            .to_string();
         assert!(
             complete_before_access_err.contains("ONB_ACCESS_PROVISION_REQUIRED_BEFORE_COMPLETE")
         );
@@ -31658,7 +40242,8 @@ mod tests {
                 photo_blob_ref: None,
                 sender_decision: None,
             })
-            .expect_err("complete must fail before wake enrollment completes");
+            .expect_err("complete must fail before wake enrollment completes")
+            .to_string();
         assert!(complete_before_wake.contains("ONB_WAKE_ENROLL_REQUIRED_BEFORE_COMPLETE"));
 
         let wake_start = runtime
@@ -31861,7 +40446,8 @@ mod tests {
                 photo_blob_ref: None,
                 sender_decision: None,
             })
-            .expect_err("ios wake enroll action must fail closed");
+            .expect_err("ios wake enroll action must fail closed")
+            .to_string();
         assert!(err.contains("ios_wake_disabled"));
     }
 
@@ -31873,6 +40459,7 @@ mod tests {
                 photo_blob_ref: Some("blob:photo:test".to_string()),
                 ..OnboardingContinueActionInput::default()
             },
+            ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN,
         )
         .expect("photo capture action must parse");
         assert!(matches!(
@@ -31886,6 +40473,7 @@ mod tests {
                 sender_decision: Some("CONFIRM".to_string()),
                 ..OnboardingContinueActionInput::default()
             },
+            ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN,
         )
         .expect("sender verify action must parse");
         assert!(matches!(
@@ -31901,6 +40489,7 @@ mod tests {
                 device_id: Some("wake_device_1".to_string()),
                 ..OnboardingContinueActionInput::default()
             },
+            ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN,
         )
         .expect("wake start action must parse");
         assert!(matches!(
@@ -31914,6 +40503,7 @@ mod tests {
                 sender_decision: Some("MAYBE".to_string()),
                 ..OnboardingContinueActionInput::default()
             },
+            ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN,
         )
         .expect_err("invalid sender decision must fail");
         assert!(err.contains("sender_decision must be CONFIRM or REJECT"));
@@ -31929,11 +40519,60 @@ mod tests {
                 payload_hash: Some(format!("{:064x}", 1)),
                 ..OnboardingContinueActionInput::default()
             },
+            ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN,
         )
         .expect_err("missing signer must fail action parsing");
         assert_eq!(err, "signer is required for PLATFORM_SETUP_RECEIPT");
     }
 
+    #[test]
+    fn rund2_onboarding_continue_adapter_rejects_over_long_field_values() {
+        let err = parse_onboarding_continue_action(
+            "ASK_MISSING_SUBMIT",
+            OnboardingContinueActionInput {
+                field_value: Some("x".repeat(ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN + 1)),
+                ..OnboardingContinueActionInput::default()
+            },
+            ONBOARDING_FIELD_VALUE_DEFAULT_MAX_LEN,
+        )
+        .expect_err("over-long field_value must fail action parsing");
+        assert_eq!(
+            err,
+            "field_value is 4097 bytes, over the onboarding limit of 4096 bytes"
+        );
+
+        let runtime = AdapterRuntime {
+            onboarding_field_value_max_len: 64,
+            ..AdapterRuntime::default()
+        };
+        let err = runtime
+            .run_onboarding_continue(OnboardingContinueAdapterRequest {
+                correlation_id: 75_101,
+                onboarding_session_id: "onb_rund2_adapter".to_string(),
+                idempotency_key: "rund2-adapter-receipt".to_string(),
+                tenant_id: Some("tenant_1".to_string()),
+                action: "PLATFORM_SETUP_RECEIPT".to_string(),
+                field_value: None,
+                receipt_kind: Some("install_launch_handshake".to_string()),
+                receipt_ref: Some(format!("receipt:{}", "r".repeat(64))),
+                signer: Some("selene_mobile_app".to_string()),
+                payload_hash: Some(format!("{:064x}", 1)),
+                terms_version_id: None,
+                accepted: None,
+                device_id: None,
+                proof_ok: None,
+                sample_seed: None,
+                photo_blob_ref: None,
+                sender_decision: None,
+            })
+            .expect_err("over-long receipt_ref must fail before reaching the onboarding store")
+            .to_string();
+        assert_eq!(
+            err,
+            "receipt_ref is 72 bytes, over the onboarding limit of 64 bytes"
+        );
+    }
+
     fn synthetic_health_for_detail_tests() -> AdapterHealthResponse {
         AdapterHealthResponse {
             status: "ok".to_string(),
@@ -31951,6 +40590,7 @@ mod tests {
                     last_acked_count: 1,
                     last_retry_scheduled_count: 1,
                     last_dead_lettered_count: 1,
+                    last_backoff_ms: 30_000,
                 },
                 queue: AdapterSyncQueueCounters {
                     queued_count: 4,
@@ -31959,9 +40599,12 @@ mod tests {
                     dead_letter_count: 1,
                     replay_due_count: 1,
                     retry_pending_count: 2,
+                    replay_due_oldest_at_ns: Some(400),
                 },
                 improvement: AdapterImprovementCounters::default(),
             },
+            provider_latency: AdapterProviderLatencyHealth::default(),
+            provider: AdapterProviderHealth::default(),
             provenance: None,
         }
     }
@@ -31983,6 +40626,229 @@ mod tests {
             .contains("voice_identity="));
     }
 
+    #[test]
+    fn at_adapter_01b0_cancel_mid_turn_stops_at_the_next_stage_check() {
+        // Flips the flag when the turn opens the named stage span, so the check that follows
+        // that stage sees a cancel that arrived mid-turn.
+        struct CancelOnStageSpan {
+            stage: &'static str,
+            cancel: CancellationFlag,
+            next_id: AtomicU64,
+            opened_after_cancel: Arc<Mutex<Vec<&'static str>>>,
+        }
+        impl tracing::Subscriber for CancelOnStageSpan {
+            fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+                let name = span.metadata().name();
+                if self.cancel.is_cancelled() {
+                    self.opened_after_cancel
+                        .lock()
+                        .expect("span log lock must not poison")
+                        .push(name);
+                } else if name == self.stage {
+                    self.cancel.cancel();
+                }
+                tracing::span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst))
+            }
+            fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+            fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {
+            }
+            fn event(&self, _event: &tracing::Event<'_>) {}
+            fn enter(&self, _span: &tracing::span::Id) {}
+            fn exit(&self, _span: &tracing::span::Id) {}
+        }
+
+        // Each stage is followed by a check, so the next span must never open: the check before
+        // STT after `ph1k_bundle`, the one before the PH1.C commits after `ph1c_live`, and the one
+        // before the PH1.K commits after `ph1vision`.
+        for (idx, (stage, skipped_stage)) in [
+            ("ph1k_bundle", "ph1c_live"),
+            ("ph1c_live", "ph1vision"),
+            ("ph1vision", "ingress"),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let runtime = AdapterRuntime::default();
+            let mut req = base_request();
+            req.correlation_id = 10_118 + idx as u64;
+            req.turn_id = 20_118 + idx as u64;
+            req.user_text_final = Some("Summarize adapter readiness.".to_string());
+            let sessions_before = runtime
+                .store
+                .lock()
+                .expect("store lock must not poison")
+                .session_rows()
+                .clone();
+            let cancel = CancellationFlag::new();
+            let opened_after_cancel = Arc::new(Mutex::new(Vec::new()));
+            let subscriber = CancelOnStageSpan {
+                stage,
+                cancel: cancel.clone(),
+                next_id: AtomicU64::new(1),
+                opened_after_cancel: Arc::clone(&opened_after_cancel),
+            };
+            let out = tracing::subscriber::with_default(subscriber, || {
+                runtime.run_voice_turn_cancellable(req.clone(), &cancel)
+            })
+            .expect("turn cancelled mid-turn must return a cancelled outcome");
+            assert!(cancel.is_cancelled(), "{stage} span must have been opened");
+            assert!(
+                !opened_after_cancel
+                    .lock()
+                    .expect("span log lock must not poison")
+                    .contains(&skipped_stage),
+                "cancel during {stage} must stop before {skipped_stage}"
+            );
+            assert_eq!(out.status, "cancelled", "cancel during {stage}");
+            assert_eq!(out.reason_code, VOICE_TURN_CANCELLED_REASON_CODE);
+            assert_eq!(out.turn_id, Some(req.turn_id));
+            assert!(out.response_text.is_empty());
+            {
+                let store = runtime.store.lock().expect("store lock must not poison");
+                assert!(
+                    store.conversation_ledger().is_empty(),
+                    "a turn cancelled during {stage} must not append transcript rows"
+                );
+                assert!(
+                    store.ph1x_thread_state_ledger_rows().is_empty(),
+                    "a turn cancelled during {stage} must not reach PH1.X"
+                );
+                assert!(
+                    store.ph1k_runtime_event_rows().is_empty(),
+                    "a turn cancelled during {stage} must not commit PH1.K events"
+                );
+                assert_eq!(
+                    store.session_rows(),
+                    &sessions_before,
+                    "a turn cancelled during {stage} must not open or attach a session"
+                );
+                assert!(
+                    store.wake_learn_signal_rows().is_empty(),
+                    "a turn cancelled during {stage} must not commit wake rows"
+                );
+                assert!(
+                    store
+                        .get_device(&DeviceId::new(req.device_id.clone().unwrap()).unwrap())
+                        .is_none(),
+                    "a turn cancelled during {stage} must not provision its device"
+                );
+            }
+
+            let out = runtime
+                .run_voice_turn_cancellable(req, &CancellationFlag::new())
+                .expect("uncancelled retry must run the turn");
+            assert_eq!(out.status, "ok", "retry after cancel during {stage}");
+            assert_eq!(out.outcome, "FINAL");
+            assert!(!runtime
+                .store
+                .lock()
+                .expect("store lock must not poison")
+                .ph1k_runtime_event_rows()
+                .is_empty());
+        }
+    }
+
+    #[test]
+    fn at_adapter_01b_cancelled_turn_stops_before_provider_stage() {
+        let runtime = AdapterRuntime::default();
+        let mut req = base_request();
+        req.user_text_final = Some("Summarize adapter readiness.".to_string());
+        let cancel = CancellationFlag::new();
+        cancel.cancel();
+        let out = runtime
+            .run_voice_turn_cancellable(req.clone(), &cancel)
+            .expect("cancelled turn must return a cancelled outcome");
+        assert_eq!(out.status, "cancelled");
+        assert_eq!(out.outcome, "CANCELLED");
+        assert_eq!(out.reason_code, VOICE_TURN_CANCELLED_REASON_CODE);
+        assert_eq!(out.turn_id, Some(req.turn_id));
+        assert!(out.response_text.is_empty());
+        assert!(out.stt_mode.is_empty());
+        {
+            let store = runtime.store.lock().expect("store lock must not poison");
+            assert!(
+                store.conversation_ledger().is_empty(),
+                "cancelled turn must not append transcript rows"
+            );
+        }
+
+        let out = runtime
+            .run_voice_turn_cancellable(req, &CancellationFlag::new())
+            .expect("uncancelled retry must run the turn");
+        assert_eq!(out.status, "ok");
+        assert_eq!(out.outcome, "FINAL");
+    }
+
+    #[test]
+    fn at_adapter_01b1_streaming_turn_matches_plain_turn_without_live_partials() {
+        let mut req = base_request();
+        req.user_text_final = Some("Summarize adapter readiness.".to_string());
+        let expected = AdapterRuntime::default()
+            .run_voice_turn(req.clone())
+            .expect("plain turn must succeed");
+
+        let runtime = AdapterRuntime::default();
+        let mut partials = Vec::new();
+        let out = runtime
+            .run_voice_turn_streaming(req, |chunk| partials.push(chunk.to_string()))
+            .expect("streaming turn must succeed");
+        assert_eq!(out.status, expected.status);
+        assert_eq!(out.outcome, expected.outcome);
+        assert_eq!(out.response_text, expected.response_text);
+        assert!(
+            partials.is_empty(),
+            "an upstream transcript has no live STT partials"
+        );
+        assert!(
+            runtime.store.try_lock().is_ok(),
+            "the store lock must be free once the streaming turn returns"
+        );
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn at_adapter_01c_voice_turn_and_sync_pass_emit_nested_stage_spans() {
+        let runtime = AdapterRuntime::default();
+        let mut req = base_request();
+        req.user_text_final = Some("Summarize adapter readiness.".to_string());
+        let out = runtime
+            .run_voice_turn(req)
+            .expect("valid request must succeed");
+        assert_eq!(out.status, "ok");
+        runtime
+            .run_device_artifact_sync_worker_pass(Some(5_000_000_000))
+            .expect("sync worker pass must succeed");
+
+        logs_assert(|lines: &[&str]| {
+            let turn_lines: Vec<&&str> = lines
+                .iter()
+                .filter(|line| line.contains("voice_turn{correlation_id="))
+                .collect();
+            for stage in ["ph1k_bundle", "ph1c_live", "ingress", "ph1d_commit"] {
+                let nested = format!("}}:{stage}{{correlation_id=");
+                if !turn_lines.iter().any(|line| line.contains(&nested)) {
+                    return Err(format!("no {stage} span under voice_turn"));
+                }
+            }
+            if !turn_lines
+                .iter()
+                .any(|line| line.contains("voice turn finished"))
+            {
+                return Err("voice_turn span closed without a finish event".to_string());
+            }
+            if !lines
+                .iter()
+                .any(|line| line.contains("sync_worker_pass{now_ns=5000000000}:sync_dispatch:"))
+            {
+                return Err("no sync_dispatch span under sync_worker_pass".to_string());
+            }
+            Ok(())
+        });
+    }
+
     #[test]
     fn at_wake_01_desktop_wake_without_enrollment_fails() {
         let runtime = AdapterRuntime::default();
@@ -31992,7 +40858,8 @@ mod tests {
         req.device_id = Some("adapter_wake_desktop_no_enroll".to_string());
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("desktop wake without enrollment must fail");
+            .expect_err("desktop wake without enrollment must fail")
+            .to_string();
         assert_eq!(err, "wake_not_enrolled");
     }
 
@@ -32005,7 +40872,8 @@ mod tests {
         req.device_id = Some("adapter_wake_android_no_enroll".to_string());
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("android wake without enrollment must fail");
+            .expect_err("android wake without enrollment must fail")
+            .to_string();
         assert_eq!(err, "wake_not_enrolled");
     }
 
@@ -32018,7 +40886,8 @@ mod tests {
         seed_wake_enrollment_complete_for_request(&runtime, &mut req, "at_wake_03");
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("ios wake must fail closed");
+            .expect_err("ios wake must fail closed")
+            .to_string();
         assert_eq!(err, "ios_wake_disabled");
     }
 
@@ -32035,7 +40904,8 @@ mod tests {
         }
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("degraded capture should reject via PH1.W inference");
+            .expect_err("degraded capture should reject via PH1.W inference")
+            .to_string();
         assert!(
             err.contains("wake_rejected"),
             "expected wake_rejected from PH1.W inference path, got {err}"
@@ -32089,7 +40959,8 @@ mod tests {
         }
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("rejected wake turn should fail");
+            .expect_err("rejected wake turn should fail")
+            .to_string();
         assert!(err.contains("wake_rejected"));
 
         let store = runtime.store.lock().expect("store lock must not poison");
@@ -32139,7 +41010,8 @@ mod tests {
 
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("desktop wake without detection alignment must fail closed");
+            .expect_err("desktop wake without detection alignment must fail closed")
+            .to_string();
         assert!(
             err.contains("wake_rejected"),
             "expected wake_rejected from PH1.W alignment gate, got {err}"
@@ -32189,7 +41061,8 @@ mod tests {
 
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("degraded wake evidence must still reject through PH1.W");
+            .expect_err("degraded wake evidence must still reject through PH1.W")
+            .to_string();
 
         assert!(
             err.contains("wake_rejected"),
@@ -32353,7 +41226,8 @@ mod tests {
 
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("iPhone wake word must remain disabled");
+            .expect_err("iPhone wake word must remain disabled")
+            .to_string();
         assert_eq!(err, "ios_wake_disabled");
     }
 
@@ -32420,7 +41294,8 @@ mod tests {
 
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("rejected wake must still fail closed");
+            .expect_err("rejected wake must still fail closed")
+            .to_string();
         assert!(
             err.contains("wake_rejected"),
             "expected wake_rejected fail-closed path, got {err}"
@@ -32518,6 +41393,7 @@ mod tests {
         let base_observation = build_live_voice_id_observation(
             &store,
             Some("tenant_a"),
+            TENANT_DELIMITER_DEFAULT,
             &guest_user,
             &owner_device,
             AppPlatform::Desktop,
@@ -32526,6 +41402,7 @@ mod tests {
         let strict_handoff_observation = activation_handoff_voice_id_observation(
             &store,
             Some("tenant_a"),
+            TENANT_DELIMITER_DEFAULT,
             &guest_user,
             &owner_device,
             base_observation,
@@ -32605,7 +41482,8 @@ mod tests {
 
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("iPhone wake word must remain disabled after Slice 2");
+            .expect_err("iPhone wake word must remain disabled after Slice 2")
+            .to_string();
         assert_eq!(err, "ios_wake_disabled");
     }
 
@@ -32926,6 +41804,7 @@ mod tests {
         let base_observation = build_live_voice_id_observation(
             &store,
             Some("tenant_a"),
+            TENANT_DELIMITER_DEFAULT,
             &guest_user,
             &owner_device,
             AppPlatform::Desktop,
@@ -32934,6 +41813,7 @@ mod tests {
         let strict_observation = activation_handoff_voice_id_observation(
             &store,
             Some("tenant_a"),
+            TENANT_DELIMITER_DEFAULT,
             &guest_user,
             &owner_device,
             base_observation,
@@ -33010,7 +41890,8 @@ mod tests {
 
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("iPhone wake word must remain disabled after Slice 3");
+            .expect_err("iPhone wake word must remain disabled after Slice 3")
+            .to_string();
         assert_eq!(err, "ios_wake_disabled");
     }
 
@@ -33294,7 +42175,8 @@ mod tests {
 
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("iPhone wake word must remain disabled after Slice 4");
+            .expect_err("iPhone wake word must remain disabled after Slice 4")
+            .to_string();
         assert_eq!(err, "ios_wake_disabled");
     }
 
@@ -34517,7 +43399,8 @@ mod tests {
         req.trigger = "SIDE_BUTTON".to_string();
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("side-button requires a later API/native trigger surface");
+            .expect_err("side-button requires a later API/native trigger surface")
+            .to_string();
         assert!(err.contains("invalid trigger"));
     }
 
@@ -34542,7 +43425,8 @@ mod tests {
         req.app_platform = "UNKNOWN_PLATFORM".to_string();
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("unknown platform must fail closed");
+            .expect_err("unknown platform must fail closed")
+            .to_string();
         assert!(err.contains("invalid app_platform"));
     }
 
@@ -34563,7 +43447,8 @@ mod tests {
         req.trigger = "UNKNOWN_ACTIVATION".to_string();
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("unknown activation must fail closed");
+            .expect_err("unknown activation must fail closed")
+            .to_string();
         assert!(err.contains("invalid trigger"));
     }
 
@@ -34650,7 +43535,8 @@ mod tests {
 
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("quiet controlled wake must not open a session");
+            .expect_err("quiet controlled wake must not open a session")
+            .to_string();
         assert!(
             err.contains("wake_rejected"),
             "quiet controlled wake should fail closed: {err}"
@@ -34765,6 +43651,8 @@ mod tests {
             MonotonicTimeNs(request.now_ns.unwrap_or(1)),
             request.tenant_id.as_deref(),
             Some(&device_id),
+            None,
+            &InterruptPhraseMatcher::built_in(),
         )
         .expect("desktop echo evidence bundle must build")
     }
@@ -34810,6 +43698,189 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ph1c_cross_talk_without_tts_hints_multi_speaker_and_emits_feedback() {
+        let mut req = base_request();
+        req.correlation_id = 10_205;
+        req.turn_id = 20_205;
+        req.user_text_final = Some("What is the weather in Singapore?".to_string());
+        if let Some(capture) = req.audio_capture_ref.as_mut() {
+            capture.tts_playback_active = Some(false);
+            capture.double_talk_bp = Some(8_200);
+        }
+
+        let bundle = desktop_echo_evidence_bundle_from_request(&req);
+        let ph1c_request = build_ph1c_live_request(&bundle, SessionState::Active)
+            .expect("ph1c request must build");
+        let overlap_hint = ph1c_request
+            .speaker_overlap_hint
+            .expect("overlap hint must be present");
+        assert_eq!(
+            overlap_hint.overlap_class,
+            SpeakerOverlapClass::MultiSpeaker
+        );
+
+        let runtime = AdapterRuntime::default();
+        runtime
+            .run_voice_turn(req)
+            .expect("cross-talk turn should succeed");
+        let store = runtime.store.lock().expect("store lock should succeed");
+        assert!(store
+            .ph1feedback_audit_rows(CorrelationId(10_205))
+            .iter()
+            .any(|row| feedback_event_type_matches(row, "VoiceIdMultiSpeaker")));
+    }
+
+    #[test]
+    fn ph1c_language_hint_confidence_follows_locale_source() {
+        let store = Ph1fStore::new_in_memory();
+        let now = MonotonicTimeNs(1);
+        let matcher = InterruptPhraseMatcher::built_in();
+        let hint_for = |request: &VoiceTurnAdapterRequest, prior: Option<&str>| {
+            let bundle =
+                build_ph1k_live_signal_bundle(&store, request, now, None, None, prior, &matcher)
+                    .expect("ph1k bundle must build");
+            let hint = build_ph1c_live_request(&bundle, SessionState::Active)
+                .expect("ph1c request must build")
+                .language_hint
+                .expect("language hint must be present");
+            (
+                bundle.locale_source,
+                hint.language_tag.as_str().to_string(),
+                hint.confidence,
+            )
+        };
+
+        let explicit = base_request();
+        assert_eq!(
+            hint_for(&explicit, Some("zh-CN")),
+            (
+                Ph1cLocaleSource::ClientExplicit,
+                "en-US".to_string(),
+                LanguageHintConfidence::High
+            )
+        );
+
+        let mut inherited = base_request();
+        if let Some(capture) = inherited.audio_capture_ref.as_mut() {
+            capture.locale_tag = None;
+        }
+        assert_eq!(
+            hint_for(&inherited, Some("zh-CN")),
+            (
+                Ph1cLocaleSource::PriorDetection,
+                "zh-CN".to_string(),
+                LanguageHintConfidence::Med
+            )
+        );
+        assert!(
+            build_ph1k_live_signal_bundle(&store, &inherited, now, None, None, None, &matcher)
+                .is_err()
+        );
+
+        let mut guessed = base_request();
+        guessed.app_platform = "DESKTOP".to_string();
+        guessed.trigger = "EXPLICIT".to_string();
+        guessed.audio_capture_ref = None;
+        guessed.user_text_final = Some("What time is it?".to_string());
+        let (source, _, confidence) = hint_for(&guessed, Some("zh-CN"));
+        assert_eq!(source, Ph1cLocaleSource::Guessed);
+        assert_eq!(confidence, LanguageHintConfidence::Low);
+    }
+
+    #[test]
+    fn ph1c_client_language_hint_overrides_capture_locale() {
+        let store = Ph1fStore::new_in_memory();
+        let now = MonotonicTimeNs(1);
+        let matcher = InterruptPhraseMatcher::built_in();
+        let hint_for = |request: &VoiceTurnAdapterRequest| {
+            let bundle =
+                build_ph1k_live_signal_bundle(&store, request, now, None, None, None, &matcher)
+                    .expect("ph1k bundle must build");
+            let hint = build_ph1c_live_request(&bundle, SessionState::Active)
+                .expect("ph1c request must build")
+                .language_hint
+                .expect("language hint must be present");
+            (hint.language_tag.as_str().to_string(), hint.confidence)
+        };
+
+        let absent = base_request();
+        assert_eq!(
+            hint_for(&absent),
+            ("en-US".to_string(), LanguageHintConfidence::High)
+        );
+
+        let mut hinted = base_request();
+        hinted.language_hint = Some("es-MX".to_string());
+        hinted.language_hint_confidence = Some("med".to_string());
+        assert_eq!(
+            hint_for(&hinted),
+            ("es-MX".to_string(), LanguageHintConfidence::Med)
+        );
+
+        hinted.language_hint_confidence = None;
+        assert_eq!(
+            hint_for(&hinted),
+            ("es-MX".to_string(), LanguageHintConfidence::High)
+        );
+
+        let runtime = AdapterRuntime::default();
+        for (language_hint, confidence) in [
+            (Some("en US"), None),
+            (Some("e"), None),
+            (Some("en--US"), None),
+            (Some("es-MX"), Some("CERTAIN")),
+            (None, Some("HIGH")),
+        ] {
+            let mut invalid = base_request();
+            invalid.language_hint = language_hint.map(str::to_string);
+            invalid.language_hint_confidence = confidence.map(str::to_string);
+            let err = runtime
+                .run_voice_turn(invalid)
+                .expect_err("malformed language hint must reject the turn");
+            assert!(err.to_string().contains("invalid language_hint"), "{err}");
+        }
+    }
+
+    #[test]
+    fn ph1c_overlap_confidence_signal_weighted_profile_rises_with_double_talk() {
+        static ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+        let _guard = ENV_LOCK
+            .get_or_init(|| Mutex::new(()))
+            .lock()
+            .expect("env lock poisoned");
+        let overlap_confidence_for = |double_talk_bp: u16| {
+            let mut req = base_request();
+            if let Some(capture) = req.audio_capture_ref.as_mut() {
+                capture.tts_playback_active = Some(false);
+                capture.double_talk_bp = Some(double_talk_bp);
+            }
+            let bundle = desktop_echo_evidence_bundle_from_request(&req);
+            let hint = build_ph1c_live_request(&bundle, SessionState::Active)
+                .expect("ph1c request must build")
+                .speaker_overlap_hint
+                .expect("overlap hint must be present");
+            assert_eq!(hint.overlap_class, SpeakerOverlapClass::MultiSpeaker);
+            hint.confidence.0
+        };
+
+        let legacy_moderate = overlap_confidence_for(6_800);
+        let legacy_high = overlap_confidence_for(9_800);
+        assert_eq!(legacy_moderate, legacy_high);
+
+        let _profile = ScopedEnvVar::set(
+            "SELENE_ADAPTER_PH1C_OVERLAP_CONFIDENCE_PROFILE",
+            "signal_weighted",
+        );
+        let weighted_moderate = overlap_confidence_for(6_800);
+        let weighted_high = overlap_confidence_for(9_800);
+        assert!(
+            weighted_high > weighted_moderate + 0.05,
+            "high={weighted_high} moderate={weighted_moderate}"
+        );
+        assert!(weighted_high <= 1.0);
+    }
+
     #[test]
     fn desktop_echo_evidence_no_static_fake_safe_capture() {
         let mut req = base_request();
@@ -35079,6 +44150,269 @@ mod tests {
         assert_eq!(closed.closed_at, Some(MonotonicTimeNs(34_000_000_000)));
     }
 
+    #[test]
+    fn stage6_close_session_api_closes_open_session_once() {
+        let runtime = AdapterRuntime::default();
+        let mut first = base_request();
+        first.correlation_id = 31_506;
+        first.turn_id = 41_506;
+        first.now_ns = Some(3_000_000_000);
+        first.app_platform = "DESKTOP".to_string();
+        first.trigger = "WAKE_WORD".to_string();
+        seed_wake_enrollment_complete_for_request(&runtime, &mut first, "stage6_api_close");
+        runtime
+            .run_voice_turn(first.clone())
+            .expect("wake turn must open session");
+
+        let actor_user_id = UserId::new(first.actor_user_id.clone()).expect("actor id must parse");
+        let device_id = first.device_id.clone().expect("device id must exist");
+        let session_id = {
+            let store = runtime.store.lock().expect("store lock must not poison");
+            let session = latest_canonical_session_for_actor(&store, &actor_user_id)
+                .expect("canonical session lookup must succeed")
+                .expect("first turn must persist session");
+            assert_eq!(session.session_state, SessionState::Active);
+            session.session_id
+        };
+
+        let closed = runtime
+            .close_session(&first.actor_user_id, &device_id, None, 4_000_000_000)
+            .expect("explicit close must succeed");
+        assert!(closed);
+        {
+            let store = runtime.store.lock().expect("store lock must not poison");
+            let session = store
+                .get_session(&session_id)
+                .expect("session row must remain archived");
+            assert_eq!(session.session_state, SessionState::Closed);
+            assert_eq!(session.closed_at, Some(MonotonicTimeNs(4_000_000_000)));
+        }
+
+        let closed_again = runtime
+            .close_session(&first.actor_user_id, &device_id, None, 5_000_000_000)
+            .expect("repeat close must succeed");
+        assert!(!closed_again);
+        let store = runtime.store.lock().expect("store lock must not poison");
+        assert_eq!(
+            store
+                .get_session(&session_id)
+                .expect("session row must remain archived")
+                .closed_at,
+            Some(MonotonicTimeNs(4_000_000_000))
+        );
+    }
+
+    #[test]
+    fn stage6_close_session_settles_outstanding_transcript_partials() {
+        for policy in [
+            AdapterTranscriptClosePolicy::Discard,
+            AdapterTranscriptClosePolicy::FinalizeLast,
+        ] {
+            let runtime = AdapterRuntime {
+                transcript_close_policy: policy,
+                ..AdapterRuntime::default()
+            };
+            let mut first = base_request();
+            first.correlation_id = 31_507;
+            first.turn_id = 41_507;
+            first.now_ns = Some(3_000_000_000);
+            first.app_platform = "DESKTOP".to_string();
+            first.trigger = "WAKE_WORD".to_string();
+            seed_wake_enrollment_complete_for_request(
+                &runtime,
+                &mut first,
+                "stage6_close_partials",
+            );
+            runtime
+                .run_voice_turn(first.clone())
+                .expect("wake turn must open session");
+
+            let actor_user_id =
+                UserId::new(first.actor_user_id.clone()).expect("actor id must parse");
+            let device_id = first.device_id.clone().expect("device id must exist");
+            let session_id = {
+                let store = runtime.store.lock().expect("store lock must not poison");
+                latest_canonical_session_for_actor(&store, &actor_user_id)
+                    .expect("canonical session lookup must succeed")
+                    .expect("first turn must persist session")
+                    .session_id
+            };
+            let pending_turn = TurnId(41_508);
+            for (text, at_ns) in [
+                ("book a table for", 3_500_000_000),
+                ("book a table for two", 3_600_000_000),
+            ] {
+                runtime
+                    .push_transcript_partial_event(
+                        CorrelationId(31_508),
+                        pending_turn,
+                        Some(session_id),
+                        &actor_user_id,
+                        PrivacyScope::PublicChat,
+                        AdapterTranscriptRole::User,
+                        AdapterTranscriptSource::Ph1C,
+                        text.to_string(),
+                        at_ns,
+                    )
+                    .expect("partial must record");
+            }
+            runtime
+                .push_transcript_partial_event(
+                    CorrelationId(31_509),
+                    TurnId(41_509),
+                    None,
+                    &actor_user_id,
+                    PrivacyScope::PublicChat,
+                    AdapterTranscriptRole::User,
+                    AdapterTranscriptSource::Ph1C,
+                    "unrelated partial".to_string(),
+                    3_700_000_000,
+                )
+                .expect("partial must record");
+
+            assert!(runtime
+                .close_session(&first.actor_user_id, &device_id, None, 4_000_000_000)
+                .expect("explicit close must succeed"));
+
+            let remaining = runtime
+                .transcript_state
+                .lock()
+                .expect("transcript lock must not poison")
+                .events
+                .clone();
+            assert_eq!(remaining.len(), 1);
+            assert_eq!(remaining[0].text, "unrelated partial");
+
+            let store = runtime.store.lock().expect("store lock must not poison");
+            let finals = store
+                .conversation_ledger()
+                .iter()
+                .filter(|row| row.turn_id == pending_turn)
+                .collect::<Vec<_>>();
+            match policy {
+                AdapterTranscriptClosePolicy::Discard => assert!(finals.is_empty()),
+                AdapterTranscriptClosePolicy::FinalizeLast => {
+                    assert_eq!(finals.len(), 1);
+                    assert_eq!(finals[0].text, "book a table for two");
+                    assert_eq!(finals[0].role, ConversationRole::User);
+                    assert_eq!(finals[0].source, ConversationSource::VoiceTranscript);
+                    assert_eq!(finals[0].session_id, Some(session_id));
+                }
+            }
+        }
+    }
+
+    fn push_retention_test_partial(
+        runtime: &AdapterRuntime,
+        turn_id: u64,
+        text: &str,
+        timestamp_ns: u64,
+    ) {
+        runtime
+            .push_transcript_partial_event(
+                CorrelationId(u128::from(turn_id)),
+                TurnId(turn_id),
+                None,
+                &UserId::new("tenant_a:user_adapter_test").unwrap(),
+                PrivacyScope::PublicChat,
+                AdapterTranscriptRole::User,
+                AdapterTranscriptSource::Ph1C,
+                text.to_string(),
+                timestamp_ns,
+            )
+            .expect("partial must record");
+    }
+
+    fn retained_transcript_texts(runtime: &AdapterRuntime) -> Vec<String> {
+        runtime
+            .transcript_state
+            .lock()
+            .expect("transcript lock must not poison")
+            .events
+            .iter()
+            .map(|event| event.text.clone())
+            .collect()
+    }
+
+    #[test]
+    fn stage6_transcript_retention_evicts_expired_partials_by_age() {
+        let runtime = AdapterRuntime {
+            transcript_retention_policy: AdapterTranscriptRetentionPolicy::new(
+                16,
+                Some(1_000_000_000),
+            ),
+            ..AdapterRuntime::default()
+        };
+        push_retention_test_partial(&runtime, 41_601, "stale turn", 1_000_000_000);
+        push_retention_test_partial(&runtime, 41_602, "live turn", 2_500_000_000);
+
+        assert_eq!(
+            runtime
+                .evict_expired_transcript_partials(2_000_000_000)
+                .expect("eviction must succeed"),
+            0
+        );
+        assert_eq!(
+            runtime
+                .evict_expired_transcript_partials(3_000_000_000)
+                .expect("eviction must succeed"),
+            1
+        );
+        assert_eq!(retained_transcript_texts(&runtime), vec!["live turn"]);
+
+        let unbounded = AdapterRuntime::default();
+        push_retention_test_partial(&unbounded, 41_601, "stale turn", 1);
+        assert_eq!(
+            unbounded
+                .evict_expired_transcript_partials(u64::MAX)
+                .expect("eviction must succeed"),
+            0
+        );
+    }
+
+    #[test]
+    fn stage6_transcript_retention_capacity_keeps_newest_partial_per_turn() {
+        let runtime = AdapterRuntime {
+            transcript_retention_policy: AdapterTranscriptRetentionPolicy::new(
+                3,
+                Some(1_000_000_000),
+            ),
+            ..AdapterRuntime::default()
+        };
+        push_retention_test_partial(&runtime, 41_701, "old turn", 1_000_000_000);
+        push_retention_test_partial(&runtime, 41_702, "book a", 2_600_000_000);
+        push_retention_test_partial(&runtime, 41_703, "what is", 2_700_000_000);
+        // Over capacity: the expired partial goes before any live one.
+        push_retention_test_partial(&runtime, 41_702, "book a table", 2_800_000_000);
+        assert_eq!(
+            retained_transcript_texts(&runtime),
+            vec!["book a", "what is", "book a table"]
+        );
+        // Nothing is expired now, so the replaced partial of turn 41_702 goes, not the
+        // newest partial of either open turn.
+        push_retention_test_partial(&runtime, 41_703, "what is the", 2_900_000_000);
+        assert_eq!(
+            retained_transcript_texts(&runtime),
+            vec!["what is", "book a table", "what is the"]
+        );
+        push_retention_test_partial(&runtime, 41_702, "book a table for", 3_000_000_000);
+        assert_eq!(
+            retained_transcript_texts(&runtime),
+            vec!["book a table", "what is the", "book a table for"]
+        );
+        push_retention_test_partial(&runtime, 41_704, "hello", 3_100_000_000);
+        assert_eq!(
+            retained_transcript_texts(&runtime),
+            vec!["what is the", "book a table for", "hello"]
+        );
+        // Only newest partials are left, so the oldest of them makes room.
+        push_retention_test_partial(&runtime, 41_705, "set a timer", 3_200_000_000);
+        assert_eq!(
+            retained_transcript_texts(&runtime),
+            vec!["book a table for", "hello", "set a timer"]
+        );
+    }
+
     #[test]
     fn at_l_04_cross_device_attach_reuses_canonical_session() {
         let runtime = AdapterRuntime::default();
@@ -35399,6 +44733,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn at_l_07b_duplicate_turn_replays_response_without_recommitting() {
+        let sink = Arc::new(RecordingTelemetrySink::default());
+        let runtime = AdapterRuntime {
+            telemetry_sink: sink.clone(),
+            ..AdapterRuntime::default()
+        };
+        let mut request = base_request();
+        request.correlation_id = 31_013;
+        request.turn_id = 41_013;
+        request.user_text_final = Some("Summarize adapter replay handling.".to_string());
+        let commit_counts = || {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            (
+                store.audit_events().len(),
+                store.conversation_ledger().len(),
+                sink.events.lock().expect("sink lock must not poison").len(),
+            )
+        };
+
+        let first = runtime
+            .run_voice_turn(request.clone())
+            .expect("first turn must succeed");
+        let committed = commit_counts();
+        request.now_ns = Some(9);
+        let second = runtime
+            .run_voice_turn(request.clone())
+            .expect("duplicate turn must replay");
+        assert_eq!(commit_counts(), committed);
+        assert_eq!(
+            serde_json::to_value(&second).unwrap(),
+            serde_json::to_value(VoiceTurnAdapterResponse {
+                session_attach_outcome: Some(SessionAttachOutcome::RetryReusedResult),
+                ..first.clone()
+            })
+            .unwrap()
+        );
+
+        let mut other_actor = request.clone();
+        other_actor.actor_user_id = "tenant_1:replay_other_actor".to_string();
+        other_actor.device_id = None;
+        runtime
+            .run_voice_turn(other_actor)
+            .expect("another actor's turn must run");
+        assert_ne!(commit_counts(), committed);
+
+        let mut cache = AdapterTurnReplayCache::default();
+        for turn in 0..=TURN_REPLAY_CACHE_MAX_ENTRIES as u64 {
+            if turn == TURN_REPLAY_CACHE_MAX_ENTRIES as u64 {
+                assert!(cache
+                    .get(
+                        (CorrelationId(1), TurnId(0)),
+                        &UserId::new("tenant_1:replay_actor").unwrap(),
+                        "fp",
+                    )
+                    .is_some());
+            }
+            cache.insert(
+                (CorrelationId(1), TurnId(turn)),
+                AdapterTurnReplayEntry {
+                    actor_user_id: UserId::new("tenant_1:replay_actor").unwrap(),
+                    request_fingerprint: "fp".to_string(),
+                    response: first.clone(),
+                },
+            );
+        }
+        assert_eq!(cache.responses.len(), TURN_REPLAY_CACHE_MAX_ENTRIES);
+        assert_eq!(cache.recency.len(), TURN_REPLAY_CACHE_MAX_ENTRIES);
+        assert!(cache.responses.contains_key(&(CorrelationId(1), TurnId(0))));
+        assert!(!cache.responses.contains_key(&(CorrelationId(1), TurnId(1))));
+    }
+
     #[test]
     fn at_l_08_session_lease_owner_blocks_conflicting_node_until_expiry() {
         let runtime = AdapterRuntime::default();
@@ -35805,6 +45211,84 @@ mod tests {
         assert_eq!(prepared.shape.memory_candidates_len, 0);
     }
 
+    #[test]
+    fn at_mem_02_saved_memories_load_as_ph1x_candidates_except_under_privacy_mode() {
+        let runtime = AdapterRuntime::default();
+        seed_identity_and_device_for_request(&runtime, &base_request());
+        let actor_user_id = UserId::new("tenant_a:user_adapter_test").unwrap();
+        let mut store = runtime.store.lock().expect("store lock must not poison");
+        for (idx, (key, value, sensitivity, use_policy)) in [
+            (
+                "at_mem_02.favorite_food",
+                "pizza",
+                MemorySensitivityFlag::Low,
+                MemoryUsePolicy::AlwaysUsable,
+            ),
+            (
+                "at_mem_02.home_city",
+                "Lisbon",
+                MemorySensitivityFlag::Low,
+                MemoryUsePolicy::ContextRelevantOnly,
+            ),
+            (
+                "at_mem_02.health_note",
+                "allergy",
+                MemorySensitivityFlag::Sensitive,
+                MemoryUsePolicy::AlwaysUsable,
+            ),
+            (
+                "at_mem_02.gift_idea",
+                "book",
+                MemorySensitivityFlag::Low,
+                MemoryUsePolicy::UserRequestedOnly,
+            ),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let memory_event = MemoryLedgerEvent::v1(
+                MemoryLedgerEventKind::Stored,
+                MonotonicTimeNs(1_000 + idx as u64),
+                MemoryKey::new(key.to_string()).expect("memory key must be valid"),
+                Some(MemoryValue::v1(value.to_string(), None).expect("memory value must validate")),
+                Some(format!("remember {value}")),
+                MemoryProvenance::v1(None, None).expect("memory provenance must validate"),
+                MemoryLayer::LongTerm,
+                sensitivity,
+                MemoryConfidence::High,
+                MemoryConsent::ExplicitRemember,
+                ReasonCodeId(0x4C00_0005),
+            )
+            .expect("memory ledger event must validate");
+            store
+                .append_memory_ledger_event(
+                    &actor_user_id,
+                    memory_event,
+                    use_policy,
+                    None,
+                    Some(format!("at_mem_02_memory_event_{idx}")),
+                )
+                .expect("memory event append must succeed");
+        }
+
+        let now = MonotonicTimeNs(9_000_000_000);
+        let candidates = load_ph1x_memory_candidates(&store, &actor_user_id, now, 8, false);
+        assert_eq!(
+            candidates
+                .iter()
+                .map(|candidate| candidate.memory_key.as_str())
+                .collect::<Vec<_>>(),
+            vec!["at_mem_02.home_city", "at_mem_02.favorite_food"]
+        );
+        assert_eq!(candidates[1].evidence_quote, "remember pizza");
+        assert_eq!(
+            load_ph1x_memory_candidates(&store, &actor_user_id, now, 1, false).len(),
+            1
+        );
+        assert!(load_ph1x_memory_candidates(&store, &actor_user_id, now, 8, true).is_empty());
+        assert!(load_ph1x_memory_candidates(&store, &actor_user_id, now, 0, false).is_empty());
+    }
+
     #[test]
     fn at_mem_04_memory_provenance_contains_session_id() {
         at_l_05_session_outputs_are_authoritative_and_reconcilable();
@@ -35949,6 +45433,7 @@ mod tests {
                 request.tenant_id.clone(),
                 &actor_user_id,
                 Some(&runtime_device_id),
+                TENANT_DELIMITER_DEFAULT,
             );
             let ph1k_bundle = build_ph1k_live_signal_bundle(
                 &store,
@@ -35956,6 +45441,8 @@ mod tests {
                 now,
                 tenant_id_for_ph1c.as_deref(),
                 Some(&runtime_device_id),
+                None,
+                &InterruptPhraseMatcher::built_in(),
             )
             .expect("ph1k live signal bundle must build");
             let wake_evaluation = evaluate_wake_for_turn(
@@ -35964,7 +45451,8 @@ mod tests {
                 &actor_user_id,
                 &runtime_device_id,
                 app_platform,
-                trigger,
+                AdapterWakeGatePolicy::mvp_default()
+                    .requires_session_open_step(trigger, app_platform),
                 &ph1k_bundle,
             )
             .expect("wake gate + inference should resolve for trigger prep");
@@ -35989,6 +45477,7 @@ mod tests {
                 &ph1k_bundle,
                 session_snapshot,
                 wake_evaluation.as_ref().map(|wake| wake.decision.clone()),
+                device_trust_level_from_history(&store, &runtime_device_id),
                 store
                     .get_device(&runtime_device_id)
                     .map(|device| device.user_id.clone()),
@@ -36106,7 +45595,8 @@ mod tests {
         req.app_platform = "CONSOLE".to_string();
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("invalid platform must fail");
+            .expect_err("invalid platform must fail")
+            .to_string();
         assert!(err.contains("invalid app_platform"));
     }
 
@@ -36963,7 +46453,7 @@ mod tests {
             );
             assert!(protected_out.provenance.is_none());
 
-            let transcript = runtime.ui_chat_transcript_report(Some(407_000));
+            let transcript = runtime.ui_chat_transcript_report(Some(407_000), None);
             assert!(transcript.messages.iter().any(|message| {
                 !message.finalized && message.role == "USER" && message.text == "Tell me a jo"
             }));
@@ -39110,6 +48600,122 @@ mod tests {
         assert!(out.provenance.is_some());
     }
 
+    struct CountingPh1xThreadStateStore<'a> {
+        store: &'a Ph1fStore,
+        reads: std::cell::Cell<usize>,
+    }
+
+    impl Ph1xThreadStateSource for CountingPh1xThreadStateStore<'_> {
+        fn ph1x_thread_state_ledger_len(&self) -> usize {
+            self.store.ph1x_thread_state_ledger_len()
+        }
+
+        fn ph1x_thread_state(&self, actor_user_id: &UserId, thread_key: &str) -> ThreadState {
+            self.reads.set(self.reads.get() + 1);
+            self.store.ph1x_thread_state(actor_user_id, thread_key)
+        }
+    }
+
+    #[test]
+    fn at_adapter_03c0_thread_state_cache_serves_repeat_turns_without_store_read() {
+        let runtime = AdapterRuntime::default();
+        let thread_request = |correlation_id: u64, turn_id: u64, text: &str| {
+            let mut req = base_request();
+            req.correlation_id = correlation_id;
+            req.turn_id = turn_id;
+            req.now_ns = Some(turn_id);
+            req.thread_key = Some("cache_thread".to_string());
+            req.user_text_final = Some(text.to_string());
+            req
+        };
+        let actor_user_id = UserId::new(base_request().actor_user_id).unwrap();
+        let thread_key = resolve_adapter_thread_key(Some("cache_thread"));
+        let load_counted = |runtime: &AdapterRuntime| {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            let counting = CountingPh1xThreadStateStore {
+                store: &store,
+                reads: std::cell::Cell::new(0),
+            };
+            let state = load_ph1x_thread_state_cached(
+                &runtime.ph1x_thread_state_cache,
+                &counting,
+                &actor_user_id,
+                &thread_key,
+            );
+            assert_eq!(
+                state,
+                load_ph1x_thread_state(&store, &actor_user_id, &thread_key)
+            );
+            counting.reads.get()
+        };
+
+        runtime
+            .run_voice_turn(thread_request(10_331, 20_331, "Tell me a fun fact."))
+            .expect("first turn should succeed");
+        assert_eq!(load_counted(&runtime), 0, "persist must write through");
+        runtime
+            .run_voice_turn(thread_request(10_332, 20_332, "Tell me another one."))
+            .expect("second turn should succeed");
+        assert_eq!(
+            load_counted(&runtime),
+            0,
+            "second turn must be served from the cache"
+        );
+
+        // A write that bypasses the adapter invalidates the cache.
+        let external = ThreadState::empty_v1()
+            .with_project_context(Some("proj_external".to_string()), Vec::new())
+            .expect("project context should be valid");
+        runtime
+            .store
+            .lock()
+            .expect("store lock should succeed")
+            .ph1x_thread_state_upsert_commit(
+                MonotonicTimeNs(20_333),
+                actor_user_id.clone(),
+                thread_key.clone(),
+                external.clone(),
+                ReasonCodeId(1),
+                "cache_external_write".to_string(),
+            )
+            .expect("external write should succeed");
+        assert_eq!(load_counted(&runtime), 1);
+        assert_eq!(load_counted(&runtime), 0);
+
+        // A restarted runtime has an empty cache and reads the store first.
+        let restarted = AdapterRuntime {
+            store: runtime.store.clone(),
+            ..AdapterRuntime::default()
+        };
+        assert_eq!(load_counted(&restarted), 1);
+    }
+
+    #[test]
+    fn at_adapter_03c0a_thread_state_cache_evicts_least_recently_used() {
+        let mut cache = AdapterPh1xThreadStateCache::with_capacity(2);
+        let user = UserId::new("cache_user").unwrap();
+        let state = |project: &str| {
+            ThreadState::empty_v1()
+                .with_project_context(Some(project.to_string()), Vec::new())
+                .expect("project context should be valid")
+        };
+        cache.insert(&user, "a", state("proj_a"));
+        cache.insert(&user, "b", state("proj_b"));
+        assert_eq!(cache.get(&user, "a"), Some(state("proj_a")));
+        cache.insert(&user, "c", state("proj_c"));
+        assert_eq!(cache.get(&user, "b"), None, "b was least recently used");
+        assert_eq!(cache.get(&user, "a"), Some(state("proj_a")));
+        assert_eq!(cache.get(&user, "c"), Some(state("proj_c")));
+
+        cache.remove(&user, "a");
+        assert_eq!(cache.get(&user, "a"), None);
+        cache.insert(&user, "d", state("proj_d"));
+        assert_eq!(cache.get(&user, "c"), Some(state("proj_c")));
+        assert_eq!(cache.get(&user, "d"), Some(state("proj_d")));
+        assert_eq!(cache.index.len(), 2);
+        assert_eq!(cache.slots.len(), 2);
+    }
+
     #[test]
     fn at_adapter_03c_thread_state_loader_round_trips_from_ph1f() {
         let runtime = AdapterRuntime::default();
@@ -39157,6 +48763,115 @@ mod tests {
         }
     }
 
+    #[test]
+    fn at_adapter_03c2_pre_fetched_tool_response_answers_pending_tool_without_dispatch() {
+        let runtime = AdapterRuntime::default();
+        let actor_user_id = UserId::new("tenant_a:user_adapter_test").unwrap();
+        let thread_key = resolve_adapter_thread_key(Some("weather_cache"));
+        let seed_pending_tool = |now: u64| {
+            let mut store = runtime.store.lock().expect("store lock should succeed");
+            ensure_actor_identity_and_device(
+                &mut store,
+                &actor_user_id,
+                None,
+                AppPlatform::Desktop,
+                MonotonicTimeNs(1),
+                true,
+            )
+            .expect("identity + device seed should succeed");
+            store
+                .ph1x_thread_state_upsert_commit(
+                    MonotonicTimeNs(now),
+                    actor_user_id.clone(),
+                    thread_key.clone(),
+                    KernelThreadState::v1(
+                        Some(PendingState::Tool {
+                            request_id: ToolRequestId(7_401),
+                            attempts: 1,
+                        }),
+                        None,
+                    ),
+                    ReasonCodeId(0x5800_7001),
+                    "adapter_thread_state_seed".to_string(),
+                )
+                .expect("thread state seed should commit");
+        };
+        let pre_fetched = VoiceTurnPreFetchedToolResponse {
+            request_id: 7_401,
+            query_hash: 7_402,
+            tool_kind: "weather".to_string(),
+            result_text: "Sunny and 22 degrees in Lisbon.".to_string(),
+            sources: vec![VoiceTurnProvenanceSource {
+                title: "Weather cache".to_string(),
+                url: "https://weather.example/lisbon".to_string(),
+            }],
+            retrieved_at_unix_ms: 1_700_000_000_000,
+        };
+        let request = |turn_id: u64, pre_fetched: VoiceTurnPreFetchedToolResponse| {
+            let mut req = base_request();
+            req.thread_key = Some("weather_cache".to_string());
+            req.correlation_id = turn_id - 10_000;
+            req.turn_id = turn_id;
+            req.now_ns = Some(turn_id);
+            req.user_text_final = Some("What's the weather in Lisbon?".to_string());
+            req.pre_fetched_tool_response = Some(pre_fetched);
+            req
+        };
+
+        // A new session clears pending thread state, so open the session first.
+        let mut open = request(20_400, pre_fetched.clone());
+        open.user_text_final = None;
+        open.pre_fetched_tool_response = None;
+        runtime
+            .run_voice_turn(open)
+            .expect("session open turn should succeed");
+        seed_pending_tool(20_400);
+        let out = runtime
+            .run_voice_turn(request(20_401, pre_fetched.clone()))
+            .expect("pre-fetched tool turn should succeed");
+        assert_eq!(out.next_move_kind, Some(VoiceTurnNextMove::Respond));
+        assert!(out
+            .response_text
+            .contains("Sunny and 22 degrees in Lisbon."));
+        let packet = runtime
+            .ingress
+            .debug_last_agent_input_packet()
+            .expect("pre-fetched tool turn should reach PH1.X");
+        assert_eq!(
+            packet.tool_response,
+            Some(pre_fetched_tool_response_to_ph1e(&pre_fetched).unwrap())
+        );
+        {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            let state = load_ph1x_thread_state(&store, &actor_user_id, &thread_key);
+            assert!(state.pending.is_none());
+        }
+
+        seed_pending_tool(20_402);
+        let stale = VoiceTurnPreFetchedToolResponse {
+            request_id: 7_400,
+            ..pre_fetched.clone()
+        };
+        runtime
+            .run_voice_turn(request(20_403, stale))
+            .expect("stale pre-fetched tool turn should still run");
+        let packet = runtime
+            .ingress
+            .debug_last_agent_input_packet()
+            .expect("stale pre-fetched tool turn should reach PH1.X");
+        assert_eq!(packet.tool_response, None);
+
+        let unsupported = VoiceTurnPreFetchedToolResponse {
+            tool_kind: "STOCKS".to_string(),
+            ..pre_fetched
+        };
+        let err = runtime
+            .run_voice_turn(request(20_404, unsupported))
+            .expect_err("malformed pre-fetched tool response must reject the turn")
+            .to_string();
+        assert!(err.contains("unsupported tool_kind 'STOCKS'"), "{err}");
+    }
+
     #[test]
     fn at_adapter_03d_cross_device_turns_share_same_thread_state_scope() {
         let runtime = AdapterRuntime::default();
@@ -39801,6 +49516,607 @@ mod tests {
         assert!(health.sync.improvement.builder_runs_total >= 1);
     }
 
+    #[test]
+    fn at_adapter_03g2_tenant_feature_flags_override_global_defaults() {
+        let runtime = AdapterRuntime {
+            ph1c_streaming_enabled: true,
+            auto_builder_enabled: true,
+            tenant_feature_flags: parse_tenant_feature_flags(
+                "tenant_b:ph1c_streaming_enabled=false, auto_builder_enabled=off;\
+                 tenant_c:ph1c_live_enabled=maybe;bad_entry",
+            ),
+            ..AdapterRuntime::default()
+        };
+
+        assert!(runtime.ph1c_streaming_enabled_for_tenant(Some("tenant_a")));
+        assert!(runtime.ph1c_streaming_enabled_for_tenant(None));
+        assert!(!runtime.ph1c_streaming_enabled_for_tenant(Some("tenant_b")));
+        assert!(runtime.auto_builder_enabled_for_tenant(Some("tenant_a")));
+        assert!(!runtime.auto_builder_enabled_for_tenant(Some("tenant_b")));
+        assert_eq!(
+            runtime.tenant_feature_flags.get("tenant_c"),
+            Some(&AdapterTenantFeatureFlags::default())
+        );
+        assert_eq!(
+            runtime.ph1c_live_enabled_for_tenant(Some("tenant_c")),
+            runtime.ph1c_live_enabled
+        );
+
+        let mut req = base_request();
+        req.correlation_id = 10_114;
+        req.turn_id = 20_114;
+        req.now_ns = Some(14);
+        req.tenant_id = Some("tenant_b".to_string());
+        req.actor_user_id = "tenant_b:user_adapter_test".to_string();
+        req.user_text_final = Some("Selene search the web for timeout".to_string());
+        let out = runtime
+            .run_voice_turn(req)
+            .expect("tool-fail turn should still return an adapter response");
+        assert_eq!(out.status, "ok");
+        let health = runtime
+            .health_report(Some(14))
+            .expect("health report should expose builder counters");
+        assert_eq!(health.sync.improvement.builder_runs_total, 0);
+        assert_eq!(
+            health.sync.improvement.last_builder_status.as_deref(),
+            Some("DISABLED")
+        );
+    }
+
+    #[test]
+    fn at_adapter_03g3_ph1k_feedback_policy_suppresses_default_signals() {
+        let mut req = base_request();
+        req.correlation_id = 10_116;
+        req.turn_id = 20_116;
+        req.user_text_final = Some("What time is it?".to_string());
+        if let Some(capture) = req.audio_capture_ref.as_mut() {
+            capture.tts_playback_active = Some(true);
+            capture.detection_confidence_bp = Some(5_000);
+        }
+        let bundle = desktop_echo_evidence_bundle_from_request(&req);
+        let default_policy = AdapterPh1kFeedbackPolicy::default();
+        assert_eq!(
+            ph1k_feedback_signal_kind(&bundle, &default_policy),
+            Some(InterruptFeedbackSignalKind::MissedLexicalTrigger)
+        );
+
+        let tenant_policies = parse_tenant_ph1k_feedback_policies(
+            "tenant_a:missed_lexical_trigger=false;tenant_b:min_confidence_delta=0.4;bad_entry",
+            default_policy,
+        );
+        let tenant_a = tenant_policies["tenant_a"];
+        assert!(!tenant_a.missed_lexical_trigger_enabled);
+        assert!(tenant_a.false_lexical_trigger_enabled);
+        assert_eq!(ph1k_feedback_signal_kind(&bundle, &tenant_a), None);
+        // 0.50 sits 0.35 below the phrase threshold, inside tenant_b's 0.4 ambiguity margin.
+        assert_eq!(
+            ph1k_feedback_signal_kind(&bundle, &tenant_policies["tenant_b"]),
+            None
+        );
+
+        let missed_rows = |runtime: &AdapterRuntime| {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            store
+                .ph1k_feedback_capture_rows()
+                .iter()
+                .filter(|row| {
+                    row.correlation_id == CorrelationId(10_116)
+                        && row.issue_kind == Ph1kFeedbackIssueKind::MissedInterrupt
+                })
+                .count()
+        };
+        let default_runtime = AdapterRuntime::default();
+        default_runtime
+            .run_voice_turn(req.clone())
+            .expect("default policy turn should succeed");
+        assert_eq!(missed_rows(&default_runtime), 1);
+
+        let tuned_runtime = AdapterRuntime {
+            tenant_ph1k_feedback_policies: tenant_policies,
+            ..AdapterRuntime::default()
+        };
+        tuned_runtime
+            .run_voice_turn(req)
+            .expect("tuned policy turn should succeed");
+        assert_eq!(missed_rows(&tuned_runtime), 0);
+    }
+
+    #[test]
+    fn at_adapter_03g3a_interrupt_min_confidence_depends_on_tts_state() {
+        let default_policy = parse_interrupt_confidence_policy("");
+        assert_eq!(default_policy, AdapterInterruptConfidencePolicy::default());
+        let policy = parse_interrupt_confidence_policy("playing=0.90, stopped=0.98, bogus=0.1");
+        assert_eq!(policy.min_confidence(true), 0.90);
+        assert_eq!(policy.min_confidence(false), 0.98);
+
+        let mut req = base_request();
+        if let Some(capture) = req.audio_capture_ref.as_mut() {
+            capture.t_end_ns = 200_000_001;
+            capture.t_candidate_start_ns = 150_000_001;
+            capture.t_confirmed_ns = 200_000_001;
+        }
+        let playing = desktop_echo_evidence_bundle_from_request(&req);
+        let confidence = playing
+            .interrupt_decision
+            .candidate
+            .as_ref()
+            .expect("a 200ms stop phrase during playback should be an interrupt candidate")
+            .phrase_confidence
+            .0;
+        assert!(confidence > 0.90 && confidence < 0.98);
+
+        let mut accepted = playing.clone();
+        apply_interrupt_confidence_policy(&mut accepted, &policy)
+            .expect("policy should apply during playback");
+        assert!(accepted.interrupt_decision.candidate.is_some());
+        assert_eq!(accepted.ph1c_handoff, playing.ph1c_handoff);
+
+        let mut stopped = playing.clone();
+        stopped.tts_playback.active = false;
+        apply_interrupt_confidence_policy(&mut stopped, &policy)
+            .expect("policy should apply after playback stops");
+        assert!(stopped.interrupt_decision.candidate.is_none());
+        assert_eq!(
+            stopped.interrupt_decision.reason_code,
+            selene_engines::ph1k::reason_codes::K_INTERRUPT_NOISE_GATE_REJECTED
+        );
+
+        let mut untouched = playing.clone();
+        apply_interrupt_confidence_policy(&mut untouched, &default_policy)
+            .expect("default policy should apply");
+        assert!(untouched.interrupt_decision.candidate.is_some());
+    }
+
+    #[test]
+    fn at_adapter_03g3a5_accepted_interrupt_candidate_reaches_ph1x_when_enabled() {
+        let mut req = base_request();
+        req.correlation_id = 10_117;
+        req.turn_id = 20_117;
+        req.user_text_final = Some("Stop, tell me the time in Tokyo instead".to_string());
+        if let Some(capture) = req.audio_capture_ref.as_mut() {
+            capture.t_end_ns = 200_000_001;
+            capture.t_candidate_start_ns = 150_000_001;
+            capture.t_confirmed_ns = 200_000_001;
+        }
+        let playing = desktop_echo_evidence_bundle_from_request(&req);
+        let candidate = playing
+            .interrupt_decision
+            .candidate
+            .clone()
+            .expect("the base request should carry an interrupt candidate during playback");
+        assert_eq!(
+            ph1x_interruption_from_ph1k_bundle(&playing),
+            Some(candidate.clone())
+        );
+        let mut stopped = playing.clone();
+        stopped.tts_playback.active = false;
+        assert_eq!(ph1x_interruption_from_ph1k_bundle(&stopped), None);
+
+        let runtime = AdapterRuntime {
+            ph1x_interruption_enabled: true,
+            ..AdapterRuntime::default()
+        };
+        let out = runtime
+            .run_voice_turn(req.clone())
+            .expect("interrupted turn should succeed");
+        assert_eq!(out.next_move_kind, Some(VoiceTurnNextMove::Clarify));
+        assert_eq!(
+            out.response_text,
+            "Should I continue the previous topic or switch to your new topic?"
+        );
+        let packet = runtime
+            .ingress
+            .debug_last_agent_input_packet()
+            .expect("interrupted turn should reach PH1.X");
+        assert_eq!(packet.interruption, Some(candidate));
+
+        let default_runtime = AdapterRuntime::default();
+        let out = default_runtime
+            .run_voice_turn(req)
+            .expect("default turn should succeed");
+        assert_ne!(
+            out.response_text,
+            "Should I continue the previous topic or switch to your new topic?"
+        );
+        let packet = default_runtime
+            .ingress
+            .debug_last_agent_input_packet()
+            .expect("default turn should reach PH1.X");
+        assert_eq!(packet.interruption, None);
+    }
+
+    fn interrupt_lexicon_binding(
+        matcher: &InterruptPhraseMatcher,
+        locale: &str,
+    ) -> InterruptLexiconPolicyBinding {
+        let default_binding = matcher.default_policy_binding();
+        InterruptLexiconPolicyBinding::v1(
+            default_binding.policy_profile_id,
+            default_binding.tenant_profile_id,
+            InterruptLocaleTag::new(locale).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn write_interrupt_lexicon_file(label: &str, json: &str) -> PathBuf {
+        let seed = system_time_now_ns();
+        let path = std::env::temp_dir().join(format!("selene_adapter_lexicon_{label}_{seed}.json"));
+        fs::write(&path, json).expect("lexicon file must write");
+        path
+    }
+
+    #[test]
+    fn at_adapter_03g3a2_interrupt_lexicon_file_adds_new_locale() {
+        let path = write_interrupt_lexicon_file("new_locale", r#"{"pt-BR": ["espera", "para"]}"#);
+        let lexicon = load_interrupt_lexicon(&path).expect("lexicon must load");
+        let _ = fs::remove_file(&path);
+        let matcher = build_interrupt_phrase_matcher(&lexicon, &BTreeMap::new());
+
+        let pt_br = interrupt_lexicon_binding(&matcher, "pt-BR");
+        assert!(InterruptPhraseMatcher::built_in()
+            .match_phrase(&pt_br, "espera")
+            .unwrap()
+            .is_none());
+        assert!(matcher.match_phrase(&pt_br, "Espera").unwrap().is_some());
+        assert!(matcher.match_phrase(&pt_br, "para").unwrap().is_some());
+
+        let en_us = interrupt_lexicon_binding(&matcher, "en-US");
+        assert_eq!(
+            matcher.match_phrase(&en_us, "stop").unwrap(),
+            InterruptPhraseMatcher::built_in()
+                .match_phrase(&en_us, "stop")
+                .unwrap()
+        );
+        assert!(matcher.match_phrase(&en_us, "espera").unwrap().is_none());
+    }
+
+    #[test]
+    fn at_adapter_03g3a3_interrupt_lexicon_file_extends_existing_locale() {
+        let path = write_interrupt_lexicon_file(
+            "extend_locale",
+            r#"{"en_us": ["never mind", "stop"], "fr-FR": ["attends"]}"#,
+        );
+        let lexicon = load_interrupt_lexicon(&path).expect("lexicon must load");
+        let _ = fs::remove_file(&path);
+        let matcher = build_interrupt_phrase_matcher(&lexicon, &BTreeMap::new());
+        let built_in = InterruptPhraseMatcher::built_in();
+
+        let en_us = interrupt_lexicon_binding(&matcher, "en-US");
+        assert!(matcher
+            .match_phrase(&en_us, "never mind")
+            .unwrap()
+            .is_some());
+        for phrase in ["wait", "stop", "hold on"] {
+            assert_eq!(
+                matcher.match_phrase(&en_us, phrase).unwrap(),
+                built_in.match_phrase(&en_us, phrase).unwrap(),
+                "built-in phrase '{phrase}' must keep its id"
+            );
+        }
+        assert_eq!(
+            build_interrupt_phrase_matcher(&lexicon, &BTreeMap::new()),
+            matcher,
+            "merging the same lexicon twice must be deterministic"
+        );
+
+        let bad = write_interrupt_lexicon_file("bad", r#"["wait"]"#);
+        assert!(load_interrupt_lexicon(&bad).is_err());
+        let _ = fs::remove_file(&bad);
+    }
+
+    #[test]
+    fn at_adapter_03g3a4_interrupt_locale_fallback_chain_borrows_related_phrases() {
+        let fallbacks = parse_interrupt_locale_fallbacks(" pt-BR: pt, es ,en ; bad ; fr-FR: ");
+        assert_eq!(
+            fallbacks,
+            BTreeMap::from([(
+                "pt-BR".to_string(),
+                vec!["pt".to_string(), "es".to_string(), "en".to_string()]
+            )])
+        );
+
+        let matcher = build_interrupt_phrase_matcher(&BTreeMap::new(), &fallbacks);
+        let built_in = InterruptPhraseMatcher::built_in();
+        let pt_br = interrupt_lexicon_binding(&matcher, "pt-BR");
+        let es_es = interrupt_lexicon_binding(&matcher, "es-ES");
+        assert!(built_in.match_phrase(&pt_br, "espera").unwrap().is_none());
+        assert_eq!(
+            matcher.match_phrase(&pt_br, "Espera").unwrap(),
+            built_in.match_phrase(&es_es, "espera").unwrap()
+        );
+        assert!(matcher.match_phrase(&pt_br, "wait").unwrap().is_none());
+
+        let lexicon = BTreeMap::from([("pt-BR".to_string(), vec!["para".to_string()])]);
+        let with_own = build_interrupt_phrase_matcher(&lexicon, &fallbacks);
+        assert!(with_own.match_phrase(&pt_br, "para").unwrap().is_some());
+        assert!(with_own.match_phrase(&pt_br, "espera").unwrap().is_none());
+
+        let english_only = BTreeMap::from([("pt-BR".to_string(), vec!["en".to_string()])]);
+        let matcher = build_interrupt_phrase_matcher(&BTreeMap::new(), &english_only);
+        assert!(matcher.match_phrase(&pt_br, "hold on").unwrap().is_some());
+    }
+
+    #[test]
+    fn at_adapter_03g3b_builder_input_includes_ph1k_feedback_when_enabled() {
+        let mut req = base_request();
+        req.correlation_id = 10_117;
+        req.turn_id = 20_117;
+        req.user_text_final = Some("What time is it?".to_string());
+        if let Some(capture) = req.audio_capture_ref.as_mut() {
+            capture.tts_playback_active = Some(true);
+            capture.detection_confidence_bp = Some(5_000);
+        }
+        let builder_correlation_id = CorrelationId(30_117);
+        let builder_turn_id = TurnId(40_117);
+        let entries = |runtime: &AdapterRuntime, tenant_id: Option<&str>| {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            runtime.builder_outcome_entries(
+                &store,
+                builder_correlation_id,
+                builder_turn_id,
+                MonotonicTimeNs(0),
+                tenant_id,
+                &[],
+            )
+        };
+
+        let default_runtime = AdapterRuntime::default();
+        assert!(!default_runtime.builder_ph1k_feedback_enabled);
+        default_runtime
+            .run_voice_turn(req.clone())
+            .expect("default turn should succeed");
+        assert!(entries(&default_runtime, Some("tenant_a")).is_empty());
+
+        let runtime = AdapterRuntime {
+            builder_ph1k_feedback_enabled: true,
+            ..AdapterRuntime::default()
+        };
+        runtime
+            .run_voice_turn(req)
+            .expect("feedback turn should succeed");
+        let tenant_entries = entries(&runtime, Some("tenant_a"));
+        assert!(tenant_entries.iter().any(|entry| {
+            entry.engine_id == "PH1.K"
+                && entry.outcome_type == "VOICE_PH1K_MISSED_INTERRUPT"
+                && entry.correlation_id == builder_correlation_id
+                && entry.turn_id == builder_turn_id
+        }));
+        assert_eq!(entries(&runtime, None), tenant_entries);
+        assert!(entries(&runtime, Some("tenant_b")).is_empty());
+        BuilderOfflineInput::v1(
+            builder_correlation_id,
+            builder_turn_id,
+            MonotonicTimeNs(0),
+            MonotonicTimeNs(1),
+            MonotonicTimeNs(1),
+            tenant_entries,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            true,
+        )
+        .expect("ph1k feedback entries must form a valid builder input");
+    }
+
+    #[test]
+    fn at_adapter_03g3c_transient_storage_errors_retry_until_commit_succeeds() {
+        struct FlakyFeedbackStore<'a> {
+            store: &'a mut Ph1fStore,
+            transient_failures_left: u32,
+            failure: StorageError,
+            attempts: u32,
+        }
+
+        impl FlakyFeedbackStore<'_> {
+            fn commit(
+                &mut self,
+                idempotency_key: &str,
+            ) -> Result<selene_kernel_contracts::ph1j::AuditEventId, StorageError> {
+                self.attempts += 1;
+                if self.transient_failures_left > 0 {
+                    self.transient_failures_left -= 1;
+                    return Err(self.failure.clone());
+                }
+                self.store.ph1feedback_event_commit(
+                    MonotonicTimeNs(1),
+                    "tenant_a".to_string(),
+                    CorrelationId(10_118),
+                    TurnId(20_118),
+                    None,
+                    UserId::new("tenant_a:user_adapter_test").expect("actor id must parse"),
+                    DeviceId::new("adapter_device_1").expect("device id must parse"),
+                    "ToolFail".to_string(),
+                    "ToolFail".to_string(),
+                    ReasonCodeId(1),
+                    idempotency_key.to_string(),
+                )
+            }
+        }
+
+        let transient = StorageError::ProofFailure {
+            class: ProofFailureClass::ProofStorageUnavailable,
+            detail: "proof ledger offline".to_string(),
+        };
+        let permanent = StorageError::AppendOnlyViolation {
+            table: "audit_events",
+        };
+        assert!(storage_error_is_transient(&transient));
+        assert!(!storage_error_is_transient(&permanent));
+
+        let runtime = AdapterRuntime::default();
+        runtime
+            .run_voice_turn(base_request())
+            .expect("seed turn should succeed");
+        assert_eq!(
+            runtime.storage_commit_retry_limit,
+            STORAGE_COMMIT_RETRY_LIMIT_DEFAULT
+        );
+        let mut store = runtime.store.lock().expect("store lock should succeed");
+        let correlation_id = CorrelationId(10_118);
+        let rows_before = store.ph1feedback_audit_rows(correlation_id).len();
+
+        let mut flaky = FlakyFeedbackStore {
+            store: &mut store,
+            transient_failures_left: 2,
+            failure: transient.clone(),
+            attempts: 0,
+        };
+        retry_transient_storage_commit(runtime.storage_commit_retry_limit, || {
+            flaky.commit("storage_retry_recovers")
+        })
+        .expect("commit should succeed once the transient failures clear");
+        assert_eq!(flaky.attempts, 3);
+
+        flaky.transient_failures_left = 3;
+        flaky.attempts = 0;
+        let err = retry_transient_storage_commit(runtime.storage_commit_retry_limit, || {
+            flaky.commit("storage_retry_exhausted")
+        })
+        .expect_err("commit must fail once the retry limit is spent");
+        assert!(storage_error_is_transient(&err));
+        assert_eq!(flaky.attempts, 3);
+
+        flaky.transient_failures_left = 1;
+        flaky.failure = permanent;
+        flaky.attempts = 0;
+        let err = retry_transient_storage_commit(runtime.storage_commit_retry_limit, || {
+            flaky.commit("storage_retry_permanent")
+        })
+        .expect_err("non-transient errors must fail immediately");
+        assert!(matches!(err, StorageError::AppendOnlyViolation { .. }));
+        assert_eq!(flaky.attempts, 1);
+
+        assert_eq!(
+            store.ph1feedback_audit_rows(correlation_id).len(),
+            rows_before + 1
+        );
+    }
+
+    #[test]
+    fn at_adapter_03g3d_configured_fallback_tenant_tags_untenanted_rows() {
+        assert_eq!(fallback_tenant_id_from(None), DEFAULT_FALLBACK_TENANT_ID);
+        assert_eq!(
+            fallback_tenant_id_from(Some("   ")),
+            DEFAULT_FALLBACK_TENANT_ID
+        );
+        assert_eq!(
+            fallback_tenant_id_from(Some(" tenant_unscoped ")),
+            "tenant_unscoped"
+        );
+
+        let runtime = AdapterRuntime {
+            fallback_tenant_id: fallback_tenant_id_from(Some("tenant_unscoped")),
+            ..AdapterRuntime::default()
+        };
+        let req = base_request();
+        runtime
+            .run_voice_turn(req.clone())
+            .expect("seed turn should succeed");
+        assert_eq!(
+            runtime.tenant_or_fallback(Some("tenant_a"), "test"),
+            "tenant_a"
+        );
+
+        let bundle = desktop_echo_evidence_bundle_from_request(&req);
+        let device_id = DeviceId::new("adapter_device_1").expect("device id must parse");
+        let mut store = runtime.store.lock().expect("store lock should succeed");
+        let rows_before = store.ph1k_runtime_event_rows().len();
+        runtime
+            .commit_ph1k_live_runtime_events(
+                &mut store,
+                MonotonicTimeNs(5),
+                CorrelationId(10_120),
+                TurnId(20_120),
+                None,
+                &device_id,
+                None,
+                &bundle,
+            )
+            .expect("untenanted ph1k runtime events should commit");
+        let new_rows = &store.ph1k_runtime_event_rows()[rows_before..];
+        assert!(!new_rows.is_empty());
+        assert!(new_rows
+            .iter()
+            .all(|row| row.tenant_id == "tenant_unscoped"));
+    }
+
+    #[test]
+    fn at_adapter_03g3e_far_future_now_ns_is_clamped_or_rejected_per_policy() {
+        let system_now_ns = 1_700_000_000_000_000_000_u64;
+        let far_future_ns = system_now_ns + 86_400_000_000_000;
+        let tolerance_ns = 60_000_000_000_u64;
+
+        let off = AdapterClockSkewPolicy::default();
+        assert_eq!(
+            off.resolve_now_ns(far_future_ns, system_now_ns),
+            Ok(far_future_ns)
+        );
+
+        let clamp = parse_clock_skew_policy(Some(" clamp "), 60_000, false);
+        assert_eq!(clamp.action, AdapterClockSkewAction::Clamp);
+        assert_eq!(
+            clamp.resolve_now_ns(far_future_ns, system_now_ns),
+            Ok(system_now_ns + tolerance_ns)
+        );
+        assert_eq!(
+            clamp.resolve_now_ns(1, system_now_ns),
+            Ok(system_now_ns - tolerance_ns)
+        );
+        assert_eq!(
+            clamp.resolve_now_ns(system_now_ns + 5, system_now_ns),
+            Ok(system_now_ns + 5)
+        );
+
+        let reject = parse_clock_skew_policy(Some("REJECT"), 60_000, false);
+        let err = reject
+            .resolve_now_ns(far_future_ns, system_now_ns)
+            .expect_err("far-future now_ns must be rejected");
+        assert!(err.contains("now_ns skewed from system clock by 86400000ms"));
+
+        let deterministic = parse_clock_skew_policy(Some("REJECT"), 60_000, true);
+        assert_eq!(
+            deterministic.resolve_now_ns(far_future_ns, system_now_ns),
+            Ok(far_future_ns)
+        );
+
+        let runtime = AdapterRuntime {
+            clock_skew_policy: reject,
+            ..AdapterRuntime::default()
+        };
+        let mut req = base_request();
+        req.correlation_id = 10_121;
+        req.turn_id = 20_121;
+        req.now_ns = Some(system_time_now_ns().saturating_add(86_400_000_000_000));
+        let err = runtime
+            .run_voice_turn(req)
+            .expect_err("far-future turn must be rejected")
+            .to_string();
+        assert!(err.contains("now_ns skewed from system clock"));
+    }
+
+    #[test]
+    fn at_adapter_03g4_tenant_delimiter_splits_slash_delimited_user_ids() {
+        assert_eq!(parse_tenant_delimiter("/"), Some('/'));
+        assert_eq!(parse_tenant_delimiter(" | "), Some('|'));
+        assert_eq!(parse_tenant_delimiter("x"), None);
+        assert_eq!(parse_tenant_delimiter("::"), None);
+        assert_eq!(parse_tenant_delimiter(""), None);
+
+        let slash_user = UserId::new("tenant_x/user_1").expect("user id must parse");
+        assert_eq!(
+            tenant_scope_from_user_id(&slash_user, '/'),
+            Some("tenant_x")
+        );
+        assert_eq!(tenant_scope_from_user_id(&slash_user, ':'), None);
+        let colon_user = UserId::new("tenant_a:user_1").expect("user id must parse");
+        assert_eq!(tenant_scope_from_user_id(&colon_user, '/'), None);
+        let empty_tenant = UserId::new("/user_1").expect("user id must parse");
+        assert_eq!(tenant_scope_from_user_id(&empty_tenant, '/'), None);
+    }
+
     #[test]
     fn at_adapter_03h_clarify_loop_emits_feedback_and_learn_signal_bundle() {
         let runtime = AdapterRuntime::default();
@@ -39864,6 +50180,365 @@ mod tests {
             .any(|row| { row.learn_signal_type == LearnSignalType::UserCorrection }));
     }
 
+    #[test]
+    fn at_adapter_03i1_declined_memory_permission_emits_memory_override_feedback() {
+        let runtime = AdapterRuntime::default();
+        let actor_user_id = UserId::new("tenant_a:user_adapter_test").unwrap();
+        // Open the session first; a new session clears any pending thread state.
+        let mut opener = base_request();
+        opener.correlation_id = 10_108;
+        opener.turn_id = 20_108;
+        opener.now_ns = Some(18);
+        opener.user_text_final = Some("What's my usual coffee order?".to_string());
+        runtime
+            .run_voice_turn(opener)
+            .expect("session-opening turn should succeed");
+        {
+            let mut store = runtime.store.lock().expect("store lock should succeed");
+            store
+                .ph1x_thread_state_upsert_commit(
+                    MonotonicTimeNs(19),
+                    actor_user_id.clone(),
+                    resolve_adapter_thread_key(None),
+                    KernelThreadState::v1(
+                        Some(PendingState::MemoryPermission {
+                            deferred_response_text: "Your usual order is a flat white.".to_string(),
+                            attempts: 1,
+                        }),
+                        None,
+                    ),
+                    ReasonCodeId(0x5800_7001),
+                    "adapter_memory_permission_seed".to_string(),
+                )
+                .expect("thread state seed should commit");
+        }
+        let mut req = base_request();
+        req.correlation_id = 10_109;
+        req.turn_id = 20_109;
+        req.now_ns = Some(20);
+        req.user_text_final = Some("no".to_string());
+
+        runtime
+            .run_voice_turn(req)
+            .expect("declined memory permission turn should succeed");
+
+        let correlation_id = CorrelationId(10_109);
+        let store = runtime.store.lock().expect("store lock should succeed");
+        let feedback_rows = store.ph1feedback_audit_rows(correlation_id);
+        assert!(feedback_rows.iter().any(|row| {
+            feedback_event_type_matches(row, "MemoryOverride")
+                && row.reason_code == reason_codes::ADAPTER_MEMORY_PERMISSION_DECLINED
+        }));
+        assert!(!feedback_rows
+            .iter()
+            .any(|row| feedback_event_type_matches(row, "ConfirmAbort")));
+    }
+
+    #[test]
+    fn at_adapter_03i2_correction_detection_uses_the_capture_locale() {
+        for (locale, text) in [
+            (Some("en-US"), "No, I meant weather in Singapore"),
+            (Some("es-ES"), "No, quería decir el tiempo en Madrid"),
+            (Some("es-MX"), "En realidad era para mañana"),
+            (Some("tr-TR"), "Aslında yarın için demek istedim"),
+            (Some("zh-CN"), "不对，我是说明天的天气"),
+            (Some("fr-FR"), "Actually, I meant tomorrow"),
+            (None, "Sorry, I meant tomorrow"),
+        ] {
+            assert!(
+                user_text_looks_like_correction(text, locale),
+                "{locale:?} correction must be detected: {text}"
+            );
+        }
+
+        for (locale, text) in [
+            (Some("en-US"), "What is the weather in Singapore"),
+            (Some("es-ES"), "¿Qué tiempo hace en Madrid?"),
+            (Some("tr-TR"), "Yarın hava nasıl olacak"),
+            (Some("zh-CN"), "明天的天气怎么样"),
+            (Some("zh-CN"), "Actually, I meant tomorrow"),
+        ] {
+            assert!(
+                !user_text_looks_like_correction(text, locale),
+                "{locale:?} text must not read as a correction: {text}"
+            );
+        }
+    }
+
+    #[test]
+    fn at_adapter_03i3_confirm_answers_use_the_turn_locale() {
+        let thread_state = ThreadState {
+            return_check_pending: true,
+            ..ThreadState::empty_v1()
+        };
+        for (locale, text, expected) in [
+            (Some("en-US"), "Yes, go ahead", Some(ConfirmAnswer::Yes)),
+            (None, "nope", Some(ConfirmAnswer::No)),
+            (Some("es-ES"), "Sí", Some(ConfirmAnswer::Yes)),
+            (Some("es-MX"), "no", Some(ConfirmAnswer::No)),
+            (Some("tr-TR"), "evet", Some(ConfirmAnswer::Yes)),
+            (Some("tr-TR"), "Hayır", Some(ConfirmAnswer::No)),
+            (Some("zh-CN"), "是", Some(ConfirmAnswer::Yes)),
+            (Some("zh-CN"), "不", Some(ConfirmAnswer::No)),
+            (Some("zh-CN"), "好的，就这样", Some(ConfirmAnswer::Yes)),
+            (Some("fr-FR"), "okay", Some(ConfirmAnswer::Yes)),
+            (Some("es-ES"), "yes", None),
+            (Some("es-ES"), "si mañana llueve", None),
+            (Some("tr-TR"), "belki", None),
+            (Some("zh-CN"), "明天再说吧", None),
+            (Some("en-US"), "maybe later", None),
+        ] {
+            assert_eq!(
+                infer_confirm_answer_from_user_text(&thread_state, Some(text), locale),
+                expected,
+                "{locale:?} confirm answer for {text:?}"
+            );
+        }
+
+        assert_eq!(
+            infer_confirm_answer_from_user_text(
+                &ThreadState::empty_v1(),
+                Some("evet"),
+                Some("tr-TR")
+            ),
+            None,
+            "no pending confirm means no confirm answer"
+        );
+    }
+
+    #[test]
+    fn at_adapter_03i2_read_only_incident_kind_counters_track_each_kind() {
+        let runtime = AdapterRuntime::default();
+        let read_only_incidents = |now_ns: u64| {
+            runtime
+                .health_report(Some(now_ns))
+                .expect("health report should expose read-only incident counters")
+                .sync
+                .improvement
+                .read_only_incidents
+        };
+        let expected_single = AdapterReadOnlyIncidentKindCounters {
+            incidents_total: 1,
+            feedback_events_emitted_total: 1,
+            learn_artifacts_emitted_total: 1,
+            suppressed_total: 0,
+        };
+        let unchanged = AdapterReadOnlyIncidentKindCounters::default();
+
+        for (correlation_id, turn_id, now_ns) in [(10_108, 20_108, 18), (10_109, 20_109, 19)] {
+            let mut clarify = base_request();
+            clarify.correlation_id = correlation_id;
+            clarify.turn_id = turn_id;
+            clarify.now_ns = Some(now_ns);
+            clarify.thread_key = Some("incident_kind_clarify_thread".to_string());
+            clarify.user_text_final = Some("Set reminder".to_string());
+            runtime
+                .run_voice_turn(clarify)
+                .expect("clarify turn should succeed");
+        }
+        let after_clarify = read_only_incidents(19);
+        assert_eq!(after_clarify.clarify_loop, expected_single);
+        assert_eq!(after_clarify.tool_fail, unchanged);
+        assert_eq!(after_clarify.user_correction, unchanged);
+
+        let mut tool_fail = base_request();
+        tool_fail.correlation_id = 10_110;
+        tool_fail.turn_id = 20_110;
+        tool_fail.now_ns = Some(20);
+        tool_fail.user_text_final = Some("Selene search the web for timeout".to_string());
+        runtime
+            .run_voice_turn(tool_fail)
+            .expect("tool-fail turn should succeed");
+        let after_tool_fail = read_only_incidents(20);
+        assert_eq!(after_tool_fail.clarify_loop, expected_single);
+        assert_eq!(after_tool_fail.tool_fail, expected_single);
+        assert_eq!(after_tool_fail.user_correction, unchanged);
+
+        let mut correction = base_request();
+        correction.correlation_id = 10_111;
+        correction.turn_id = 20_111;
+        correction.now_ns = Some(21);
+        correction.user_text_final = Some("No, I meant weather in Singapore".to_string());
+        runtime
+            .run_voice_turn(correction)
+            .expect("user-correction turn should succeed");
+        let after_correction = read_only_incidents(21);
+        assert_eq!(after_correction.clarify_loop, expected_single);
+        // The correction's weather lookup can also fail in tests, so only require
+        // the tool-fail counter not to move backwards here.
+        assert!(
+            after_correction.tool_fail.incidents_total >= after_tool_fail.tool_fail.incidents_total
+        );
+        assert_eq!(after_correction.user_correction, expected_single);
+    }
+
+    #[test]
+    fn at_adapter_03i3_privacy_mode_user_correction_redacts_evidence_ref() {
+        let runtime = AdapterRuntime::default();
+        let mut req = base_request();
+        req.correlation_id = 10_112;
+        req.turn_id = 20_112;
+        req.now_ns = Some(22);
+        req.thread_key = Some("privacy_correction_thread".to_string());
+        req.thread_policy_flags = Some(VoiceTurnThreadPolicyFlags {
+            privacy_mode: true,
+            do_not_disturb: false,
+            strict_safety: false,
+        });
+        req.user_text_final = Some("No, I meant weather in Singapore".to_string());
+
+        runtime
+            .run_voice_turn(req)
+            .expect("privacy-mode user-correction turn should succeed");
+
+        let store = runtime.store.lock().expect("store lock should succeed");
+        let correction_rows: Vec<_> = store
+            .ph1feedback_learn_signal_bundle_rows(CorrelationId(10_112))
+            .into_iter()
+            .filter(|row| row.learn_signal_type == LearnSignalType::UserCorrection)
+            .collect();
+        assert_eq!(correction_rows.len(), 1);
+        let evidence_ref = &correction_rows[0].evidence_ref;
+        assert!(evidence_ref.starts_with("user_text:sha256:"));
+        assert!(!evidence_ref.contains("Singapore"));
+        assert!(!evidence_ref.contains("I meant"));
+        assert_eq!(
+            read_only_user_text_evidence_ref("No, I meant weather in Singapore", false),
+            "No, I meant weather in Singapore"
+        );
+    }
+
+    #[test]
+    fn at_adapter_03i3b_read_only_incident_cooldown_suppresses_repeat_for_tenant() {
+        let runtime = AdapterRuntime {
+            auto_builder_enabled: true,
+            read_only_incident_cooldown_ns: 60_000_000_000,
+            ..AdapterRuntime::default()
+        };
+        let improvement = |now_ns: u64| {
+            runtime
+                .health_report(Some(now_ns))
+                .expect("health report should expose improvement counters")
+                .sync
+                .improvement
+        };
+        let run_tool_fail = |correlation_id: u64, turn_id: u64, now_ns: u64| {
+            let mut tool_fail = base_request();
+            tool_fail.correlation_id = correlation_id;
+            tool_fail.turn_id = turn_id;
+            tool_fail.now_ns = Some(now_ns);
+            tool_fail.user_text_final = Some("Selene search the web for timeout".to_string());
+            runtime
+                .run_voice_turn(tool_fail)
+                .expect("tool-fail turn should succeed");
+        };
+
+        run_tool_fail(10_113, 20_113, 30);
+        let after_first = improvement(30);
+        assert_eq!(after_first.read_only_incidents.tool_fail.incidents_total, 1);
+        assert_eq!(
+            after_first.read_only_incidents.tool_fail.suppressed_total,
+            0
+        );
+
+        run_tool_fail(10_114, 20_114, 31);
+        let after_repeat = improvement(31);
+        let tool_fail = &after_repeat.read_only_incidents.tool_fail;
+        assert_eq!(tool_fail.incidents_total, 2);
+        assert_eq!(tool_fail.suppressed_total, 1);
+        assert_eq!(tool_fail.feedback_events_emitted_total, 1);
+        assert_eq!(tool_fail.learn_artifacts_emitted_total, 1);
+        assert_eq!(
+            after_repeat.builder_runs_total,
+            after_first.builder_runs_total
+        );
+        assert_eq!(
+            after_repeat.last_builder_status.as_deref(),
+            Some("NO_READ_ONLY_INCIDENTS")
+        );
+        let store = runtime.store.lock().expect("store lock should succeed");
+        assert!(store
+            .outcome_utilization_ledger_rows()
+            .iter()
+            .all(|row| row.correlation_id != CorrelationId(10_114)
+                || row.outcome_type != ReadOnlyIncidentKind::ToolFail.outcome_type()));
+    }
+
+    #[test]
+    fn at_adapter_03i4_list_builder_artifacts_reads_back_builder_promoted_artifact() {
+        let runtime = AdapterRuntime::default();
+        {
+            let mut store = runtime.store.lock().expect("store lock should succeed");
+            store
+                .ph1learn_artifact_commit(
+                    MonotonicTimeNs(100),
+                    "tenant_a".to_string(),
+                    ArtifactScopeType::Tenant,
+                    "tenant_a".to_string(),
+                    ArtifactType::VoiceIdThresholdPack,
+                    ArtifactVersion(1),
+                    "learn_hash_v1".to_string(),
+                    "learn:voice_sync:replay_due:job_1".to_string(),
+                    "sync_feedback:replay_due:job_1".to_string(),
+                    ArtifactStatus::Deprecated,
+                    "builder_artifacts_learn_v1".to_string(),
+                )
+                .expect("learn artifact commit should succeed");
+            store
+                .ph1builder_active_artifact_commit(
+                    MonotonicTimeNs(200),
+                    "tenant_a".to_string(),
+                    ArtifactScopeType::Tenant,
+                    "tenant_a".to_string(),
+                    ArtifactType::VoiceIdThresholdPack,
+                    ArtifactVersion(2),
+                    "builder_hash_v2".to_string(),
+                    "builder:voice_id_threshold:v2".to_string(),
+                    "builder_release:proposal_1".to_string(),
+                    "builder_artifacts_active_v2".to_string(),
+                )
+                .expect("builder artifact commit should succeed");
+        }
+
+        let artifacts = runtime
+            .list_builder_artifacts("tenant_a", None, 10)
+            .expect("builder artifacts should list");
+        assert_eq!(artifacts.len(), 2);
+        assert_eq!(
+            artifacts[0],
+            BuilderArtifactView {
+                artifact_id: artifacts[0].artifact_id,
+                artifact_type: "VOICE_ID_THRESHOLD_PACK".to_string(),
+                artifact_version: 2,
+                package_hash: "builder_hash_v2".to_string(),
+                status: "ACTIVE".to_string(),
+                created_by: "PH1.BUILDER".to_string(),
+                payload_ref: "builder:voice_id_threshold:v2".to_string(),
+                provenance_ref: "builder_release:proposal_1".to_string(),
+                created_at_ns: 200,
+            }
+        );
+        assert_eq!(artifacts[1].status, "DEPRECATED");
+        assert_eq!(artifacts[1].created_by, "PH1.LEARN");
+
+        let newest = runtime
+            .list_builder_artifacts("tenant_a", Some("voice_id_threshold_pack".to_string()), 1)
+            .expect("filtered builder artifacts should list");
+        assert_eq!(newest.len(), 1);
+        assert_eq!(newest[0].package_hash, "builder_hash_v2");
+        assert!(runtime
+            .list_builder_artifacts("tenant_a", Some("WAKE_PACK".to_string()), 10)
+            .expect("empty artifact type should list")
+            .is_empty());
+        assert!(runtime
+            .list_builder_artifacts("tenant_b", None, 10)
+            .expect("other tenant should list")
+            .is_empty());
+        assert!(runtime
+            .list_builder_artifacts("tenant_a", Some("NOT_A_PACK".to_string()), 10)
+            .is_err());
+    }
+
     #[test]
     fn at_adapter_04_invalid_trigger_fails_fast() {
         let runtime = AdapterRuntime::default();
@@ -39871,7 +50546,8 @@ mod tests {
         req.trigger = "PUSH_TO_TALK".to_string();
         let err = runtime
             .run_voice_turn(req)
-            .expect_err("invalid trigger must fail");
+            .expect_err("invalid trigger must fail")
+            .to_string();
         assert!(err.contains("invalid trigger"));
     }
 
@@ -39916,6 +50592,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn at_adapter_05b_trusted_device_bypass_follows_onboarding_confirmed_primary_device() {
+        let profiles = build_embedding_gate_profiles_from_env_var_map(|key| match key {
+            "SELENE_VID_GATE_IOS_EXPLICIT" => Some("required_unless_trusted".to_string()),
+            _ => None,
+        })
+        .expect("profiles must parse")
+        .expect("override should be present");
+        let gate = profiles.ios_explicit;
+        assert_eq!(
+            gate,
+            VoiceIdentityEmbeddingGateProfile::required_unless_trusted_device()
+        );
+
+        let mut store = Ph1fStore::new_in_memory();
+        let inviter = UserId::new("tenant_1:trust_inviter").unwrap();
+        let confirmed_device = DeviceId::new("trust_confirmed_primary_device").unwrap();
+        let unknown_device = DeviceId::new("trust_unknown_device").unwrap();
+        seed_identity_and_device(&mut store, &inviter, &confirmed_device);
+        let (token_id, _) = seed_invite_link_for_click(&mut store, &inviter);
+        let token_id = TokenId::new(token_id).unwrap();
+        let opened_at = MonotonicTimeNs(10);
+        store
+            .ph1link_invite_open_activate_commit(
+                opened_at,
+                token_id.clone(),
+                "trust_fingerprint".to_string(),
+            )
+            .expect("link must activate");
+        let started = store
+            .ph1onb_session_start_draft(
+                MonotonicTimeNs(11),
+                token_id,
+                None,
+                Some("tenant_1".to_string()),
+                "trust_fingerprint".to_string(),
+                AppPlatform::Ios,
+                "legacy_app_instance".to_string(),
+                format!("legacy_nonce_{}", opened_at.0),
+                opened_at,
+            )
+            .expect("onboarding must start");
+        store
+            .ph1onb_terms_accept_commit(
+                MonotonicTimeNs(12),
+                started.onboarding_session_id.clone(),
+                "terms_v1".to_string(),
+                true,
+                "trust_terms".to_string(),
+            )
+            .expect("terms must be accepted");
+
+        assert_eq!(
+            device_trust_level_from_history(&store, &confirmed_device),
+            DeviceTrustLevel::Untrusted,
+            "a device is not trusted before onboarding confirms it"
+        );
+
+        store
+            .ph1onb_primary_device_confirm_commit(
+                MonotonicTimeNs(13),
+                started.onboarding_session_id,
+                confirmed_device.clone(),
+                ProofType::Passcode,
+                true,
+                "trust_primary_device".to_string(),
+            )
+            .expect("primary device must confirm");
+
+        let confirmed_trust = device_trust_level_from_history(&store, &confirmed_device);
+        let unknown_trust = device_trust_level_from_history(&store, &unknown_device);
+        assert_eq!(confirmed_trust, DeviceTrustLevel::Trusted);
+        assert_eq!(unknown_trust, DeviceTrustLevel::Untrusted);
+        assert!(!gate.requires_primary_embedding_for(confirmed_trust));
+        assert!(gate.requires_primary_embedding_for(unknown_trust));
+        assert!(VoiceIdentityEmbeddingGateProfile::required()
+            .requires_primary_embedding_for(confirmed_trust));
+    }
+
     #[test]
     fn at_adapter_06_embedding_gate_env_rejects_invalid_mode() {
         let err = build_embedding_gate_profiles_from_env_var_map(|key| {
@@ -39929,6 +50684,67 @@ mod tests {
         assert!(err.contains("SELENE_VID_GATE_ANDROID_WAKE"));
     }
 
+    #[test]
+    fn at_adapter_06b_wake_gate_policy_env_controls_open_step_per_trigger() {
+        let default_policy = build_wake_gate_policy_from_env_var_map(|_| None)
+            .expect("default wake gate policy must parse");
+        assert_eq!(default_policy, AdapterWakeGatePolicy::mvp_default());
+        assert!(default_policy
+            .requires_session_open_step(OsVoiceTrigger::WakeWord, AppPlatform::Android));
+        assert!(!default_policy
+            .requires_session_open_step(OsVoiceTrigger::Explicit, AppPlatform::Android));
+
+        let strict = build_wake_gate_policy_from_env_var_map(|key| match key {
+            "SELENE_WAKE_GATE_EXPLICIT" => Some("required".to_string()),
+            _ => None,
+        })
+        .expect("strict wake gate policy must parse");
+        assert!(strict.requires_session_open_step(OsVoiceTrigger::Explicit, AppPlatform::Android));
+        assert!(strict.requires_session_open_step(OsVoiceTrigger::WakeWord, AppPlatform::Android));
+
+        let trusted_desktop = build_wake_gate_policy_from_env_var_map(|key| match key {
+            "SELENE_WAKE_GATE_DESKTOP_TRUSTED" => Some("true".to_string()),
+            _ => None,
+        })
+        .expect("trusted desktop wake gate policy must parse");
+        assert!(!trusted_desktop
+            .requires_session_open_step(OsVoiceTrigger::WakeWord, AppPlatform::Desktop));
+        assert!(trusted_desktop
+            .requires_session_open_step(OsVoiceTrigger::WakeWord, AppPlatform::Android));
+
+        let err = build_wake_gate_policy_from_env_var_map(|key| {
+            (key == "SELENE_WAKE_GATE_WAKE_WORD").then(|| "sometimes".to_string())
+        })
+        .expect_err("invalid wake gate requirement must fail");
+        assert!(err.contains("SELENE_WAKE_GATE_WAKE_WORD"));
+    }
+
+    #[test]
+    fn at_adapter_06c_explicit_trigger_wake_gate_policy_threads_into_turn() {
+        let mut req = base_request();
+        req.correlation_id = 10_406;
+        req.turn_id = 20_406;
+        req.now_ns = Some(406);
+        req.user_text_final = Some("What time is it?".to_string());
+        let out = AdapterRuntime::default()
+            .run_voice_turn(req.clone())
+            .expect("explicit turn must skip the wake gate by default");
+        assert_eq!(out.status, "ok");
+
+        let strict_runtime = AdapterRuntime {
+            wake_gate_policy: AdapterWakeGatePolicy {
+                explicit: WakeGateRequirement::Required,
+                ..AdapterWakeGatePolicy::mvp_default()
+            },
+            ..AdapterRuntime::default()
+        };
+        let err = strict_runtime
+            .run_voice_turn(req)
+            .expect_err("explicit turn must run the wake gate when policy requires it")
+            .to_string();
+        assert!(err.contains("ios_wake_disabled"), "{err}");
+    }
+
     #[test]
     fn at_adapter_07_journal_persists_and_replays_voice_turns() {
         let seed = SystemTime::now()
@@ -39985,6 +50801,1005 @@ mod tests {
         let _ = std::fs::remove_file(journal_path);
     }
 
+    #[test]
+    fn at_adapter_07a_oversized_journal_entry_is_trimmed_to_replayable_form() {
+        let journal_path = temp_persistence_journal_path("journal_entry_cap");
+        let runtime_one = AdapterRuntime {
+            journal_entry_max_bytes: JOURNAL_ENTRY_MIN_MAX_BYTES,
+            ..AdapterRuntime::new_with_persistence(
+                AppServerIngressRuntime::default(),
+                Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+                journal_path.clone(),
+                true,
+            )
+            .expect("runtime with persistence must construct")
+        };
+        let mut req = base_request();
+        req.user_text_partial = Some("Summarize the journal ".repeat(100));
+        req.user_text_final = Some("Summarize adapter journal persistence.".to_string());
+        req.visual_input_ref = Some(VoiceTurnVisualInputRef {
+            turn_opt_in_enabled: false,
+            visible_tokens: (0..200)
+                .map(|idx| VoiceTurnVisualTokenRef {
+                    token: format!("visible_token_{idx:04}"),
+                    x: Some(idx),
+                    y: Some(idx),
+                    w: Some(10),
+                    h: Some(10),
+                })
+                .collect(),
+            ..VoiceTurnVisualInputRef::default()
+        });
+        assert!(matches!(
+            encode_journal_entry_within_cap(req.clone(), JOURNAL_ENTRY_DEFAULT_MAX_BYTES),
+            Ok(AdapterJournalEntryEncoding::Full(_))
+        ));
+        runtime_one
+            .run_voice_turn(req)
+            .expect("oversized turn must still succeed");
+
+        let journal = std::fs::read_to_string(&journal_path).expect("journal should be readable");
+        let lines = journal
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<_>>();
+        assert_eq!(lines.len(), 1);
+        assert!(lines[0].len() <= JOURNAL_ENTRY_MIN_MAX_BYTES);
+        let entry: AdapterJournalEntry =
+            serde_json::from_str(lines[0]).expect("trimmed journal entry must decode");
+        assert!(entry.request.user_text_partial.is_none());
+        assert_eq!(
+            entry.request.user_text_final.as_deref(),
+            Some("Summarize adapter journal persistence.")
+        );
+        assert!(entry
+            .request
+            .visual_input_ref
+            .is_some_and(|visual| visual.visible_tokens.is_empty()));
+        {
+            let state = runtime_one
+                .persistence
+                .as_ref()
+                .expect("persistence must be configured")
+                .state
+                .lock()
+                .expect("persistence state lock should succeed");
+            assert_eq!(state.legacy_journal_trimmed_entries, 1);
+            assert_eq!(state.legacy_journal_dropped_entries, 0);
+        }
+
+        let mut huge = base_request();
+        huge.user_text_final = Some("x".repeat(JOURNAL_ENTRY_MIN_MAX_BYTES));
+        assert!(matches!(
+            encode_journal_entry_within_cap(huge, JOURNAL_ENTRY_MIN_MAX_BYTES),
+            Ok(AdapterJournalEntryEncoding::Oversized(bytes)) if bytes > JOURNAL_ENTRY_MIN_MAX_BYTES
+        ));
+
+        drop(runtime_one);
+        AdapterRuntime::new_with_persistence(
+            AppServerIngressRuntime::default(),
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+            journal_path.clone(),
+            true,
+        )
+        .expect("trimmed journal entry must replay on restart");
+        let _ = std::fs::remove_file(adapter_persistence_state_path(&journal_path));
+        let _ = std::fs::remove_file(journal_path);
+    }
+
+    #[test]
+    fn at_adapter_07a2_journal_compaction_keeps_latest_entry_per_turn() {
+        let journal_path = temp_persistence_journal_path("journal_compaction");
+        let uncompacted_path = temp_persistence_journal_path("journal_compaction_copy");
+        let runtime_one = AdapterRuntime::new_with_persistence(
+            AppServerIngressRuntime::default(),
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+            journal_path.clone(),
+            true,
+        )
+        .expect("runtime with persistence must construct");
+        for (turn_id, now_ns, text) in [
+            (20_001, 3, "Summarize adapter journal compaction."),
+            (20_002, 4, "Summarize adapter journal compaction again."),
+        ] {
+            let mut req = base_request();
+            req.turn_id = turn_id;
+            req.now_ns = Some(now_ns);
+            req.user_text_final = Some(text.to_string());
+            runtime_one
+                .run_voice_turn(req)
+                .expect("journaled turn must succeed");
+        }
+        let journal = std::fs::read_to_string(&journal_path).expect("journal should be readable");
+        let first_line = journal
+            .lines()
+            .next()
+            .expect("journal must hold the first turn")
+            .to_string();
+        let duplicated = format!("{journal}{first_line}\n{first_line}\n");
+        std::fs::write(&journal_path, &duplicated).expect("duplicate entries must write");
+        std::fs::write(&uncompacted_path, &duplicated).expect("uncompacted copy must write");
+
+        let report = runtime_one
+            .compact_journal()
+            .expect("journal compaction must succeed");
+        assert_eq!(report.entries_before, 4);
+        assert_eq!(report.entries_after, 2);
+        let compacted =
+            std::fs::read_to_string(&journal_path).expect("compacted journal should be readable");
+        assert_eq!(
+            report.bytes_reclaimed,
+            (duplicated.len() - compacted.len()) as u64
+        );
+        let compacted_lines = compacted.lines().collect::<Vec<_>>();
+        assert_eq!(compacted_lines.len(), 3);
+        let header: AdapterJournalSnapshotHeader = serde_json::from_str(compacted_lines[0])
+            .expect("compacted journal must start with a snapshot header");
+        assert_eq!(header.schema_version, 1);
+        assert_eq!(header.snapshot.entry_count, 2);
+        assert_eq!(compacted_lines[1], first_line);
+        drop(runtime_one);
+
+        let replay = |path: &Path| {
+            let _ = std::fs::remove_file(adapter_persistence_state_path(path));
+            let runtime = AdapterRuntime::new_with_persistence(
+                AppServerIngressRuntime::default(),
+                Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+                path.to_path_buf(),
+                true,
+            )
+            .expect("journal must replay on restart");
+            let ledger = runtime
+                .store
+                .lock()
+                .expect("store lock should succeed")
+                .conversation_ledger()
+                .to_vec();
+            (
+                ledger,
+                runtime.ui_chat_transcript_report(Some(10), None).messages,
+            )
+        };
+        let (compacted_ledger, compacted_messages) = replay(&journal_path);
+        let (uncompacted_ledger, uncompacted_messages) = replay(&uncompacted_path);
+        assert!(!compacted_messages.is_empty());
+        assert_eq!(compacted_ledger, uncompacted_ledger);
+        assert_eq!(compacted_messages, uncompacted_messages);
+
+        for path in [journal_path, uncompacted_path] {
+            let _ = std::fs::remove_file(adapter_persistence_state_path(&path));
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    #[test]
+    fn at_adapter_07a3_journal_compaction_drops_erased_user_entries() {
+        let journal_path = temp_persistence_journal_path("journal_compaction_erase");
+        let runtime = AdapterRuntime::new_with_persistence(
+            AppServerIngressRuntime::default(),
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+            journal_path.clone(),
+            true,
+        )
+        .expect("runtime with persistence must construct");
+        let mut erased = base_request();
+        erased.user_text_final = Some("Forget what I say next.".to_string());
+        runtime
+            .run_voice_turn(erased.clone())
+            .expect("erased user's turn must succeed");
+        let mut kept = base_request();
+        kept.correlation_id = 10_002;
+        kept.turn_id = 20_002;
+        kept.now_ns = Some(4);
+        kept.actor_user_id = "tenant_a:user_adapter_test_07a3".to_string();
+        kept.device_id = Some("adapter_device_07a3".to_string());
+        kept.user_text_final = Some("Keep this one.".to_string());
+        runtime
+            .run_voice_turn(kept)
+            .expect("other user's turn must succeed");
+
+        runtime
+            .erase_user_transcript(
+                &UserId::new(erased.actor_user_id.clone()).expect("actor id must parse"),
+            )
+            .expect("erase must succeed");
+        let compacted =
+            std::fs::read_to_string(&journal_path).expect("compacted journal should be readable");
+        assert!(!compacted.contains(&format!("\"{}\"", erased.actor_user_id)));
+        assert!(compacted.contains("tenant_a:user_adapter_test_07a3"));
+
+        let _ = std::fs::remove_file(adapter_persistence_state_path(&journal_path));
+        let _ = std::fs::remove_file(journal_path);
+    }
+
+    #[test]
+    fn at_adapter_07a3b_journal_compaction_drops_users_erased_before_a_restart() {
+        let journal_path = temp_persistence_journal_path("journal_compaction_erase_restart");
+        let open_runtime = || {
+            AdapterRuntime::new_with_persistence(
+                AppServerIngressRuntime::default(),
+                Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+                journal_path.clone(),
+                true,
+            )
+            .expect("runtime with persistence must construct")
+        };
+        let runtime = open_runtime();
+        let mut erased = base_request();
+        erased.user_text_final = Some("Forget what I say next.".to_string());
+        runtime
+            .run_voice_turn(erased.clone())
+            .expect("erased user's turn must succeed");
+        let mut kept = base_request();
+        kept.correlation_id = 10_002;
+        kept.turn_id = 20_002;
+        kept.now_ns = Some(4);
+        kept.actor_user_id = "tenant_a:user_adapter_test_07a3b".to_string();
+        kept.device_id = Some("adapter_device_07a3b".to_string());
+        kept.user_text_final = Some("Keep this one.".to_string());
+        runtime
+            .run_voice_turn(kept)
+            .expect("other user's turn must succeed");
+        runtime
+            .erase_user_transcript(
+                &UserId::new(erased.actor_user_id.clone()).expect("actor id must parse"),
+            )
+            .expect("erase must succeed");
+        drop(runtime);
+
+        let restarted = open_runtime();
+        let report = restarted
+            .compact_journal()
+            .expect("journal compaction must succeed");
+        assert_eq!(report.entries_before, 1);
+        assert_eq!(report.entries_after, 1);
+        let compacted =
+            std::fs::read_to_string(&journal_path).expect("compacted journal should be readable");
+        assert!(!compacted.contains(&format!("\"{}\"", erased.actor_user_id)));
+        assert!(compacted.contains("tenant_a:user_adapter_test_07a3b"));
+
+        let _ = std::fs::remove_file(adapter_persistence_state_path(&journal_path));
+        let _ = std::fs::remove_file(journal_path);
+    }
+
+    #[test]
+    fn at_adapter_07a3c_erase_removes_raw_text_from_store_and_persisted_files() {
+        let journal_path = temp_persistence_journal_path("erase_redacts_persisted_text");
+        let open_runtime = || {
+            AdapterRuntime::new_with_persistence(
+                AppServerIngressRuntime::default(),
+                Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+                journal_path.clone(),
+                true,
+            )
+            .expect("runtime with persistence must construct")
+        };
+        let secret = "My locker code is seven one seven three.";
+        let runtime = open_runtime();
+        let mut erased = base_request();
+        erased.user_text_final = Some(secret.to_string());
+        runtime
+            .run_voice_turn(erased.clone())
+            .expect("erased user's turn must succeed");
+        runtime
+            .write_store_snapshot()
+            .expect("store snapshot must write");
+        let persisted_paths = [
+            journal_path.clone(),
+            adapter_persistence_state_path(&journal_path),
+            adapter_store_snapshot_path(&journal_path),
+        ];
+        for path in &persisted_paths {
+            let contents = std::fs::read_to_string(path).expect("persisted file should exist");
+            assert!(contents.contains(secret), "{} before erase", path.display());
+        }
+
+        runtime
+            .erase_user_transcript(
+                &UserId::new(erased.actor_user_id.clone()).expect("actor id must parse"),
+            )
+            .expect("erase must succeed");
+        assert!(!runtime
+            .store
+            .lock()
+            .expect("store lock should succeed")
+            .conversation_ledger()
+            .iter()
+            .any(|row| row.text == secret));
+        for path in &persisted_paths {
+            let contents = std::fs::read_to_string(path).expect("persisted file should exist");
+            assert!(!contents.contains(secret), "{} after erase", path.display());
+        }
+        drop(runtime);
+
+        let restarted = open_runtime();
+        assert!(!restarted
+            .store
+            .lock()
+            .expect("store lock should succeed")
+            .conversation_ledger()
+            .iter()
+            .any(|row| row.text == secret));
+
+        for path in persisted_paths {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    fn journal_with_two_checksummed_turns(label: &str) -> (PathBuf, Vec<String>) {
+        let journal_path = temp_persistence_journal_path(label);
+        let runtime = AdapterRuntime::new_with_persistence(
+            AppServerIngressRuntime::default(),
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+            journal_path.clone(),
+            true,
+        )
+        .expect("runtime with persistence must construct");
+        for (turn_id, now_ns, text) in [
+            (20_001, 3, "Summarize adapter journal checksums."),
+            (20_002, 4, "Summarize adapter journal checksums again."),
+        ] {
+            let mut req = base_request();
+            req.turn_id = turn_id;
+            req.now_ns = Some(now_ns);
+            req.user_text_final = Some(text.to_string());
+            runtime
+                .run_voice_turn(req)
+                .expect("journaled turn must succeed");
+        }
+        drop(runtime);
+        let journal = std::fs::read_to_string(&journal_path).expect("journal should be readable");
+        let lines = journal.lines().map(str::to_string).collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        for line in &lines {
+            let entry =
+                AdapterJournalEntry::decode(line).expect("fresh journal line checksum must verify");
+            assert_eq!(entry.schema_version, 2);
+            assert_eq!(
+                entry.request.request_schema_version,
+                VoiceTurnAdapterRequest::SCHEMA_VERSION
+            );
+        }
+        let _ = std::fs::remove_file(adapter_persistence_state_path(&journal_path));
+        (journal_path, lines)
+    }
+
+    fn replay_journal_for_test(journal_path: &Path) -> Result<AdapterRuntime, AdapterError> {
+        AdapterRuntime::new_with_persistence_with_config(
+            AppServerIngressRuntime::default(),
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+            journal_path.to_path_buf(),
+            AdapterConfig {
+                auto_builder_enabled: true,
+                ..AdapterConfig::from_env()
+            },
+        )
+    }
+
+    #[test]
+    fn at_adapter_07a3_journal_replay_skips_truncated_final_line() {
+        let (journal_path, lines) = journal_with_two_checksummed_turns("journal_torn_tail");
+        let torn = &lines[1][..lines[1].len() / 2];
+        std::fs::write(&journal_path, format!("{}\n{torn}", lines[0]))
+            .expect("torn journal must write");
+
+        let runtime = replay_journal_for_test(&journal_path)
+            .expect("a torn final line must not abort replay");
+        let replayed_turns = runtime
+            .store
+            .lock()
+            .expect("store lock should succeed")
+            .conversation_ledger()
+            .iter()
+            .map(|row| row.turn_id)
+            .collect::<BTreeSet<_>>();
+        assert_eq!(replayed_turns, BTreeSet::from([TurnId(20_001)]));
+        let journal = std::fs::read_to_string(&journal_path).expect("journal should be readable");
+        assert_eq!(journal, format!("{}\n", lines[0]));
+
+        let mut req = base_request();
+        req.turn_id = 20_003;
+        req.now_ns = Some(5);
+        req.user_text_final = Some("Summarize the journal after the torn tail.".to_string());
+        runtime
+            .run_voice_turn(req)
+            .expect("turn after torn tail must succeed");
+        drop(runtime);
+        let _ = std::fs::remove_file(adapter_persistence_state_path(&journal_path));
+        replay_journal_for_test(&journal_path)
+            .expect("journal appended after a torn tail must replay cleanly");
+        cleanup_persistence_files_for_test(&journal_path);
+    }
+
+    #[test]
+    fn at_adapter_07a4_journal_replay_rejects_corrupt_middle_line() {
+        let (journal_path, lines) = journal_with_two_checksummed_turns("journal_corrupt_middle");
+        let corrupted = lines[0].replacen(
+            "Summarize adapter journal checksums.",
+            "Summarize adapter journal checksumz.",
+            1,
+        );
+        assert_ne!(corrupted, lines[0]);
+        std::fs::write(&journal_path, format!("{corrupted}\n{}\n", lines[1]))
+            .expect("corrupt journal must write");
+
+        let Err(err) = replay_journal_for_test(&journal_path) else {
+            panic!("a corrupt non-terminal line must fail replay");
+        };
+        assert!(err.to_string().contains("corrupt at line 1"));
+        assert!(err.to_string().contains("checksum mismatch"));
+        let journal = std::fs::read_to_string(&journal_path).expect("journal should be readable");
+        assert_eq!(journal, format!("{corrupted}\n{}\n", lines[1]));
+        cleanup_persistence_files_for_test(&journal_path);
+    }
+
+    #[test]
+    fn at_adapter_07a5_journal_replay_reads_mixed_v1_and_v2_entries() {
+        let (journal_path, lines) = journal_with_two_checksummed_turns("journal_mixed_schema");
+        let mut v1_entry: serde_json::Value =
+            serde_json::from_str(&lines[0]).expect("journal line must decode as json");
+        let v1_object = v1_entry
+            .as_object_mut()
+            .expect("journal line must be an object");
+        v1_object.insert("schema_version".to_string(), serde_json::json!(1));
+        v1_object.remove("request_crc32");
+        let v1_line = serde_json::to_string(&v1_entry).expect("v1 line must encode");
+        std::fs::write(&journal_path, format!("{v1_line}\n{}\n", lines[1]))
+            .expect("mixed journal must write");
+
+        let runtime =
+            replay_journal_for_test(&journal_path).expect("mixed v1/v2 journal must replay");
+        let replayed_turns = runtime
+            .store
+            .lock()
+            .expect("store lock should succeed")
+            .conversation_ledger()
+            .iter()
+            .map(|row| row.turn_id)
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            replayed_turns,
+            BTreeSet::from([TurnId(20_001), TurnId(20_002)])
+        );
+        drop(runtime);
+        cleanup_persistence_files_for_test(&journal_path);
+    }
+
+    #[test]
+    fn at_adapter_07a10_journal_replay_migrates_pre_versioning_requests() {
+        let (journal_path, lines) = journal_with_two_checksummed_turns("journal_request_migration");
+        // A line as a build before request versioning wrote it: no schema version and none of
+        // the fields added since, with the checksum over those bytes.
+        let mut old_entry: serde_json::Value =
+            serde_json::from_str(&lines[0]).expect("journal line must decode as json");
+        let old_request = old_entry["request"]
+            .as_object_mut()
+            .expect("journal request must be an object");
+        for newer_field in [
+            "request_schema_version",
+            "model_override",
+            "pre_fetched_tool_response",
+            "language_hint",
+            "language_hint_confidence",
+        ] {
+            assert!(old_request.remove(newer_field).is_some());
+        }
+        let old_request_json =
+            serde_json::to_string(&old_entry["request"]).expect("old request must encode");
+        let old_line = format!(
+            "{{\"schema_version\":2,\"request\":{old_request_json},\"request_crc32\":{}}}",
+            crc32_ieee(old_request_json.as_bytes())
+        );
+
+        let migrated = migrate_request(
+            AdapterJournalEntry::decode(&old_line).expect("old line checksum must verify"),
+        )
+        .expect("old request must migrate");
+        assert_eq!(
+            migrated.request_schema_version,
+            VoiceTurnAdapterRequest::SCHEMA_VERSION
+        );
+        assert_eq!(migrated.turn_id, 20_001);
+        assert_eq!(
+            migrated.user_text_final.as_deref(),
+            Some("Summarize adapter journal checksums.")
+        );
+        assert!(migrated.model_override.is_none());
+        assert!(migrated.pre_fetched_tool_response.is_none());
+        assert!(migrated.language_hint.is_none());
+        assert!(migrated.language_hint_confidence.is_none());
+        let current = AdapterJournalEntry::decode(&lines[1]).expect("current line must decode");
+        let current_request_json =
+            serde_json::to_string(&current.request).expect("current request must encode");
+        let current_migrated = migrate_request(current).expect("current request must migrate");
+        assert_eq!(
+            serde_json::to_string(&current_migrated).expect("migrated request must encode"),
+            current_request_json,
+            "a current request must migrate unchanged"
+        );
+
+        std::fs::write(&journal_path, format!("{old_line}\n{}\n", lines[1]))
+            .expect("mixed-shape journal must write");
+        let runtime =
+            replay_journal_for_test(&journal_path).expect("old request shape must replay");
+        let replayed_turns = runtime
+            .store
+            .lock()
+            .expect("store lock should succeed")
+            .conversation_ledger()
+            .iter()
+            .map(|row| row.turn_id)
+            .collect::<BTreeSet<_>>();
+        assert_eq!(
+            replayed_turns,
+            BTreeSet::from([TurnId(20_001), TurnId(20_002)])
+        );
+        drop(runtime);
+
+        let mut future_entry: serde_json::Value =
+            serde_json::from_str(&lines[1]).expect("journal line must decode as json");
+        future_entry["request"]["request_schema_version"] =
+            serde_json::json!(VoiceTurnAdapterRequest::SCHEMA_VERSION + 1);
+        let future_request_json =
+            serde_json::to_string(&future_entry["request"]).expect("future request must encode");
+        let future_line = format!(
+            "{{\"schema_version\":2,\"request\":{future_request_json},\"request_crc32\":{}}}",
+            crc32_ieee(future_request_json.as_bytes())
+        );
+        std::fs::write(&journal_path, format!("{future_line}\n{}\n", lines[0]))
+            .expect("future-shape journal must write");
+        let _ = std::fs::remove_file(adapter_persistence_state_path(&journal_path));
+        let Err(err) = replay_journal_for_test(&journal_path) else {
+            panic!("a request newer than this build must fail replay");
+        };
+        assert!(err.to_string().contains("cannot replay line 1"));
+        assert!(err.to_string().contains("is newer than this build's"));
+        cleanup_persistence_files_for_test(&journal_path);
+    }
+
+    #[test]
+    fn at_adapter_07a6_voice_turn_batch_matches_sequential_turns_and_journals_once() {
+        let requests = (0..3_u64)
+            .map(|idx| {
+                let mut req = base_request();
+                req.correlation_id = 10_700 + idx;
+                req.turn_id = 20_700 + idx;
+                req.now_ns = Some(700 + idx);
+                req.user_text_final = Some(format!("Summarize batch turn {idx}."));
+                if idx == 1 {
+                    req.actor_user_id = String::new();
+                }
+                req
+            })
+            .collect::<Vec<_>>();
+        let runtime_for = |label: &str| {
+            let journal_path = temp_persistence_journal_path(label);
+            let runtime = AdapterRuntime::new_with_persistence(
+                AppServerIngressRuntime::default(),
+                Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+                journal_path.clone(),
+                false,
+            )
+            .expect("runtime with persistence must construct");
+            (runtime, journal_path)
+        };
+        let outcome_json = |result: &Result<VoiceTurnAdapterResponse, AdapterError>| match result {
+            Ok(response) => serde_json::to_value(response).expect("response must serialize"),
+            Err(err) => serde_json::json!({ "error": err.to_string() }),
+        };
+
+        let (sequential, sequential_journal) = runtime_for("batch_sequential");
+        let sequential_results = requests
+            .iter()
+            .cloned()
+            .map(|req| sequential.run_voice_turn(req))
+            .collect::<Vec<_>>();
+        let (batch, batch_journal) = runtime_for("batch_batched");
+        let batch_results = batch.run_voice_turn_batch(requests);
+
+        assert_eq!(batch_results.len(), 3);
+        assert!(batch_results[0].is_ok());
+        assert!(batch_results[1].is_err(), "bad turn must fail on its own");
+        assert!(batch_results[2].is_ok(), "later turns must still run");
+        assert_eq!(
+            batch_results.iter().map(outcome_json).collect::<Vec<_>>(),
+            sequential_results
+                .iter()
+                .map(outcome_json)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            batch.ui_chat_transcript_report(Some(800), None),
+            sequential.ui_chat_transcript_report(Some(800), None)
+        );
+        let sequential_lines =
+            std::fs::read_to_string(&sequential_journal).expect("journal must be readable");
+        let batch_lines =
+            std::fs::read_to_string(&batch_journal).expect("journal must be readable");
+        assert_eq!(batch_lines.lines().count(), 2);
+        assert_eq!(batch_lines, sequential_lines);
+
+        let _ = std::fs::remove_file(sequential_journal);
+        let _ = std::fs::remove_file(batch_journal);
+    }
+
+    #[test]
+    fn at_adapter_07a6c_voice_turn_batch_holds_the_store_lock_across_turns() {
+        // Records, as each turn opens its `voice_turn` span, whether the store could be locked.
+        struct StoreLockProbe {
+            store: Arc<Mutex<Ph1fStore>>,
+            next_id: AtomicU64,
+            free_at_turn_start: Arc<Mutex<Vec<bool>>>,
+        }
+        impl tracing::Subscriber for StoreLockProbe {
+            fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+                if span.metadata().name() == "voice_turn" {
+                    let free = !matches!(
+                        self.store.try_lock(),
+                        Err(std::sync::TryLockError::WouldBlock)
+                    );
+                    self.free_at_turn_start
+                        .lock()
+                        .expect("probe log lock must not poison")
+                        .push(free);
+                }
+                tracing::span::Id::from_u64(self.next_id.fetch_add(1, Ordering::SeqCst))
+            }
+            fn record(&self, _span: &tracing::span::Id, _values: &tracing::span::Record<'_>) {}
+            fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {
+            }
+            fn event(&self, _event: &tracing::Event<'_>) {}
+            fn enter(&self, _span: &tracing::span::Id) {}
+            fn exit(&self, _span: &tracing::span::Id) {}
+        }
+
+        let requests = (0..3_u64)
+            .map(|idx| {
+                let mut req = base_request();
+                req.correlation_id = 10_710 + idx;
+                req.turn_id = 20_710 + idx;
+                req.now_ns = Some(710 + idx);
+                req.user_text_final = Some(format!("Summarize locked batch turn {idx}."));
+                req
+            })
+            .collect::<Vec<_>>();
+        let probe_turns = |run: &dyn Fn(&AdapterRuntime)| {
+            let runtime = AdapterRuntime::default();
+            let free_at_turn_start = Arc::new(Mutex::new(Vec::new()));
+            let probe = StoreLockProbe {
+                store: Arc::clone(&runtime.store),
+                next_id: AtomicU64::new(1),
+                free_at_turn_start: Arc::clone(&free_at_turn_start),
+            };
+            tracing::subscriber::with_default(probe, || run(&runtime));
+            let free = free_at_turn_start
+                .lock()
+                .expect("probe log lock must not poison")
+                .clone();
+            free
+        };
+
+        let sequential = probe_turns(&|runtime| {
+            for req in requests.iter().cloned() {
+                runtime
+                    .run_voice_turn(req)
+                    .expect("sequential turn should succeed");
+            }
+        });
+        assert_eq!(sequential, vec![true, true, true]);
+        let batched = probe_turns(&|runtime| {
+            for result in runtime.run_voice_turn_batch(requests.clone()) {
+                result.expect("batch turn should succeed");
+            }
+        });
+        assert_eq!(
+            batched,
+            vec![false, false, false],
+            "every batch turn must start with the store already locked by the batch"
+        );
+    }
+
+    #[test]
+    fn at_adapter_07a6b_failed_batch_journal_write_leaves_replay_cache_untouched() {
+        let journal_path = temp_persistence_journal_path("batch_journal_write_fails");
+        let runtime = AdapterRuntime::new_with_persistence(
+            AppServerIngressRuntime::default(),
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+            journal_path.clone(),
+            false,
+        )
+        .expect("runtime with persistence must construct");
+        let mut req = base_request();
+        req.correlation_id = 10_760;
+        req.turn_id = 20_760;
+        req.now_ns = Some(760);
+        req.user_text_final = Some("Summarize the failed batch turn.".to_string());
+
+        let _ = std::fs::remove_file(&journal_path);
+        std::fs::create_dir_all(&journal_path).expect("journal path must become a directory");
+        let batch_results = runtime.run_voice_turn_batch(vec![req.clone()]);
+        assert_eq!(batch_results.len(), 1);
+        let err = batch_results[0]
+            .as_ref()
+            .expect_err("turn must report the failed journal write");
+        assert!(matches!(err, AdapterError::Persistence(_)));
+        assert!(runtime
+            .turn_replay_cache
+            .lock()
+            .expect("replay cache lock must not be poisoned")
+            .responses
+            .is_empty());
+
+        runtime
+            .run_voice_turn_batch(vec![req])
+            .remove(0)
+            .expect("resubmitted turn must be answered from the committed store result");
+        assert!(runtime
+            .turn_replay_cache
+            .lock()
+            .expect("replay cache lock must not be poisoned")
+            .responses
+            .is_empty());
+
+        let _ = std::fs::remove_dir(journal_path);
+    }
+
+    #[test]
+    fn at_adapter_07a7_journal_replay_cap_replays_only_newest_entries() {
+        let journal_path = temp_persistence_journal_path("journal_replay_cap");
+        let runtime_one = AdapterRuntime::from_config(
+            AdapterConfig {
+                journal_path: Some(journal_path.clone()),
+                auto_builder_enabled: false,
+                ..AdapterConfig::from_env()
+            },
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+        )
+        .expect("runtime with persistence must construct");
+        for idx in 0..4_u64 {
+            let mut req = base_request();
+            req.correlation_id = 10_770 + idx;
+            req.turn_id = 20_770 + idx;
+            req.now_ns = Some(770 + idx);
+            req.user_text_final = Some(format!("Summarize replay cap turn {idx}."));
+            runtime_one
+                .run_voice_turn(req)
+                .expect("journaled turn must succeed");
+        }
+        drop(runtime_one);
+        let journal = std::fs::read_to_string(&journal_path).expect("journal should be readable");
+        assert_eq!(journal.lines().count(), 4);
+
+        let runtime_two = AdapterRuntime::from_config(
+            AdapterConfig {
+                journal_path: Some(journal_path.clone()),
+                auto_builder_enabled: false,
+                journal_replay_max_entries: Some(2),
+                ..AdapterConfig::from_env()
+            },
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+        )
+        .expect("capped replay runtime must construct");
+        let notes = {
+            let persistence = runtime_two.persistence.as_ref().unwrap();
+            let state = persistence.state.lock().unwrap();
+            state
+                .audit_trail
+                .iter()
+                .filter(|entry| {
+                    entry.decision == AdapterPersistenceAuditDecision::LegacyJournalReplay
+                })
+                .filter_map(|entry| entry.note.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            notes,
+            vec![
+                "skipped 2 older legacy journal entries (replay cap 2)".to_string(),
+                "replayed legacy journal line 3".to_string(),
+                "replayed legacy journal line 4".to_string(),
+            ]
+        );
+        drop(runtime_two);
+        cleanup_persistence_files_for_test(&journal_path);
+    }
+
+    fn journal_replay_notes_for_test(runtime: &AdapterRuntime) -> Vec<String> {
+        let persistence = runtime.persistence.as_ref().unwrap();
+        let state = persistence.state.lock().unwrap();
+        state
+            .audit_trail
+            .iter()
+            .filter(|entry| entry.decision == AdapterPersistenceAuditDecision::LegacyJournalReplay)
+            .filter_map(|entry| entry.note.clone())
+            .collect()
+    }
+
+    fn store_json_for_test(runtime: &AdapterRuntime) -> serde_json::Value {
+        serde_json::to_value(&*runtime.store.lock().unwrap()).expect("store must encode")
+    }
+
+    fn run_snapshot_turns_for_test(runtime: &AdapterRuntime, range: std::ops::Range<u64>) {
+        for idx in range {
+            let mut req = base_request();
+            req.correlation_id = 10_780 + idx;
+            req.turn_id = 20_780 + idx;
+            req.now_ns = Some(780 + idx);
+            req.user_text_final = Some(format!("Summarize store snapshot turn {idx}."));
+            runtime
+                .run_voice_turn(req)
+                .expect("journaled turn must succeed");
+        }
+    }
+
+    fn journaled_runtime_for_test(journal_path: &Path) -> AdapterRuntime {
+        AdapterRuntime::from_config(
+            AdapterConfig {
+                journal_path: Some(journal_path.to_path_buf()),
+                auto_builder_enabled: false,
+                ..AdapterConfig::from_env()
+            },
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+        )
+        .expect("runtime with persistence must construct")
+    }
+
+    #[test]
+    fn at_adapter_07a8_store_snapshot_plus_tail_replay_matches_full_replay() {
+        let journal_path = temp_persistence_journal_path("store_snapshot_tail");
+        let full_replay_path = temp_persistence_journal_path("store_snapshot_full");
+        let runtime_one = journaled_runtime_for_test(&journal_path);
+        run_snapshot_turns_for_test(&runtime_one, 0..2);
+        assert_eq!(runtime_one.write_store_snapshot().unwrap(), 1);
+        run_snapshot_turns_for_test(&runtime_one, 2..4);
+        drop(runtime_one);
+        std::fs::copy(&journal_path, &full_replay_path).unwrap();
+        std::fs::copy(
+            adapter_persistence_state_path(&journal_path),
+            adapter_persistence_state_path(&full_replay_path),
+        )
+        .unwrap();
+
+        let full_replay = journaled_runtime_for_test(&full_replay_path);
+        assert_eq!(
+            journal_replay_notes_for_test(&full_replay),
+            (1..=4)
+                .map(|line| format!("replayed legacy journal line {line}"))
+                .collect::<Vec<_>>()
+        );
+
+        let snapshot_replay = journaled_runtime_for_test(&journal_path);
+        assert_eq!(
+            journal_replay_notes_for_test(&snapshot_replay),
+            vec![
+                "replayed legacy journal line 3".to_string(),
+                "replayed legacy journal line 4".to_string(),
+            ]
+        );
+        // Full replay rebuilds the first two turns' history rows on top of the conversation
+        // already restored from the state file, so their digests differ from the rows the
+        // snapshot kept. The tail rows and every other table match.
+        let snapshot_store = store_json_for_test(&snapshot_replay);
+        let full_store = store_json_for_test(&full_replay);
+        for (table, rows) in snapshot_store.as_object().unwrap() {
+            if !matches!(
+                table.as_str(),
+                "memory_threads_ledger" | "ph1x_thread_state_ledger"
+            ) {
+                assert_eq!(rows, &full_store[table], "{table}");
+                continue;
+            }
+            let tail_rows = |rows: &serde_json::Value| {
+                rows.as_array()
+                    .unwrap()
+                    .iter()
+                    .filter(|row| {
+                        let key = row["idempotency_key"].as_str().unwrap();
+                        key.contains("20782") || key.contains("20783")
+                    })
+                    .cloned()
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(
+                rows.as_array().unwrap().len(),
+                full_store[table].as_array().unwrap().len()
+            );
+            assert!(!tail_rows(rows).is_empty());
+            assert_eq!(tail_rows(rows), tail_rows(&full_store[table]), "{table}");
+        }
+        assert_eq!(snapshot_replay.write_store_snapshot().unwrap(), 2);
+
+        drop(full_replay);
+        drop(snapshot_replay);
+        cleanup_persistence_files_for_test(&journal_path);
+        cleanup_persistence_files_for_test(&full_replay_path);
+    }
+
+    #[test]
+    fn at_adapter_07a9_unusable_store_snapshot_falls_back_to_full_replay() {
+        let journal_path = temp_persistence_journal_path("store_snapshot_fallback");
+        let snapshot_path = adapter_store_snapshot_path(&journal_path);
+        let runtime_one = AdapterRuntime::from_config(
+            AdapterConfig {
+                journal_path: Some(journal_path.clone()),
+                auto_builder_enabled: false,
+                store_snapshot_every_entries: Some(2),
+                ..AdapterConfig::from_env()
+            },
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+        )
+        .expect("runtime with persistence must construct");
+        run_snapshot_turns_for_test(&runtime_one, 0..3);
+        drop(runtime_one);
+        let periodic: AdapterStoreSnapshot =
+            serde_json::from_slice(&std::fs::read(&snapshot_path).unwrap()).unwrap();
+        // The audit trail survives restarts, so each full replay adds the same three notes.
+        let full_replay_notes = |boots: usize| {
+            (0..boots)
+                .flat_map(|_| (1..=3).map(|line| format!("replayed legacy journal line {line}")))
+                .collect::<Vec<_>>()
+        };
+        std::fs::remove_file(&snapshot_path).unwrap();
+        let runtime = journaled_runtime_for_test(&journal_path);
+        assert_eq!(
+            journal_replay_notes_for_test(&runtime),
+            full_replay_notes(1)
+        );
+        let full_replay_store = store_json_for_test(&runtime);
+        drop(runtime);
+        assert_eq!(periodic.snapshot_seq, 1);
+        assert_eq!(
+            periodic.journal_len_bytes,
+            std::fs::read_to_string(&journal_path)
+                .unwrap()
+                .lines()
+                .take(2)
+                .map(|line| line.len() as u64 + 1)
+                .sum::<u64>()
+        );
+
+        let mut other_schema = periodic.clone();
+        other_schema.schema_version = ADAPTER_STORE_SNAPSHOT_SCHEMA_VERSION + 1;
+        std::fs::write(&snapshot_path, serde_json::to_vec(&other_schema).unwrap()).unwrap();
+        let runtime = journaled_runtime_for_test(&journal_path);
+        assert_eq!(
+            journal_replay_notes_for_test(&runtime),
+            full_replay_notes(2)
+        );
+        assert_eq!(store_json_for_test(&runtime), full_replay_store);
+        drop(runtime);
+
+        std::fs::write(&snapshot_path, b"{\"schema_version\":1,\"snapshot_seq\"").unwrap();
+        let runtime = journaled_runtime_for_test(&journal_path);
+        assert_eq!(
+            journal_replay_notes_for_test(&runtime),
+            full_replay_notes(3)
+        );
+        assert_eq!(store_json_for_test(&runtime), full_replay_store);
+        drop(runtime);
+
+        let mut rewritten_journal = periodic;
+        rewritten_journal.journal_prefix_crc32 ^= 1;
+        std::fs::write(
+            &snapshot_path,
+            serde_json::to_vec(&rewritten_journal).unwrap(),
+        )
+        .unwrap();
+        let runtime = journaled_runtime_for_test(&journal_path);
+        assert_eq!(
+            journal_replay_notes_for_test(&runtime),
+            full_replay_notes(4)
+        );
+        assert_eq!(store_json_for_test(&runtime), full_replay_store);
+        assert_eq!(runtime.write_store_snapshot().unwrap(), 2);
+        drop(runtime);
+
+        cleanup_persistence_files_for_test(&journal_path);
+    }
+
     #[test]
     fn at_adapter_07b_journal_replay_restores_thread_state_across_runtime_restart() {
         let seed = SystemTime::now()
@@ -40098,6 +51913,171 @@ mod tests {
         let _ = std::fs::remove_file(journal_path);
     }
 
+    #[test]
+    fn at_adapter_07c_persistence_bootstrap_rejects_directory_and_unwritable_paths() {
+        let bootstrap = |journal_path: PathBuf| {
+            AdapterRuntime::new_with_persistence(
+                AppServerIngressRuntime::default(),
+                Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+                journal_path,
+                false,
+            )
+        };
+
+        let directory_path = temp_persistence_journal_path("persistence_directory_path");
+        std::fs::create_dir_all(&directory_path).expect("test directory should be created");
+        let err = bootstrap(directory_path.clone())
+            .expect_err("a directory journal path must fail bootstrap")
+            .to_string();
+        assert!(err.contains("is a directory"), "{err}");
+        assert!(err.contains(&directory_path.display().to_string()), "{err}");
+        let _ = std::fs::remove_dir_all(&directory_path);
+
+        // procfs rejects new files even for root, so it stands in for a read-only mount.
+        let read_only_path = PathBuf::from("/proc/version");
+        if read_only_path.is_file() {
+            let err = bootstrap(read_only_path)
+                .expect_err("an unwritable journal path must fail bootstrap")
+                .to_string();
+            assert!(
+                err.contains("adapter persistence path '/proc/version' is not writable"),
+                "{err}"
+            );
+        }
+    }
+
+    #[test]
+    fn at_adapter_07d_from_config_routes_flags_and_journal_path_without_env() {
+        let journal_path = temp_persistence_journal_path("from_config");
+        let runtime = AdapterRuntime::from_config(
+            AdapterConfig {
+                journal_path: Some(journal_path.clone()),
+                auto_builder_enabled: false,
+                ph1c_live_enabled: false,
+                ph1c_streaming_enabled: false,
+                builder_min_outcome_entries: 4,
+                ..AdapterConfig::from_env()
+            },
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+        )
+        .expect("runtime from config must construct");
+        assert!(!runtime.auto_builder_enabled);
+        assert!(!runtime.ph1c_live_enabled_for_tenant(Some("tenant_a")));
+        assert!(!runtime.ph1c_streaming_enabled_for_tenant(Some("tenant_a")));
+        assert_eq!(runtime.builder_min_outcome_entries, 4);
+        assert_eq!(
+            runtime
+                .persistence
+                .as_ref()
+                .map(|persistence| persistence.legacy_journal_path.clone()),
+            Some(journal_path.clone())
+        );
+
+        let mut req = base_request();
+        req.user_text_final = Some("Summarize the configured adapter runtime.".to_string());
+        runtime
+            .run_voice_turn(req)
+            .expect("configured runtime turn must succeed");
+        let journal = std::fs::read_to_string(&journal_path).expect("journal should be readable");
+        assert_eq!(journal.lines().count(), 1);
+        drop(runtime);
+        cleanup_persistence_files_for_test(&journal_path);
+
+        let in_memory = AdapterRuntime::from_config(
+            AdapterConfig {
+                journal_path: None,
+                ..AdapterConfig::from_env()
+            },
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+        )
+        .expect("in-memory runtime from config must construct");
+        assert!(in_memory.persistence.is_none());
+    }
+
+    #[test]
+    fn at_adapter_07d2_default_config_and_policy_constructors_reach_runtime_without_env() {
+        let defaults = AdapterConfig::default();
+        assert!(defaults.journal_path.is_none());
+        assert_eq!(defaults.tenant_delimiter, ':');
+        assert!(!defaults.nlp_failure_strict);
+        assert_eq!(
+            defaults.voice_disabled_message,
+            VOICE_DISABLED_DEFAULT_MESSAGE
+        );
+        assert_eq!(
+            defaults.wake_gate_policy,
+            AdapterWakeGatePolicy::mvp_default()
+        );
+
+        let runtime = AdapterRuntime::from_config(
+            AdapterConfig {
+                tenant_delimiter: '/',
+                nlp_failure_strict: true,
+                voice_disabled_message: "Voice is off on this device.".to_string(),
+                provider_latency_policy: AdapterProviderLatencyPolicy::new(20, 800, 400),
+                wake_gate_policy: AdapterWakeGatePolicy::new(
+                    WakeGateRequirement::Required,
+                    WakeGateRequirement::Skipped,
+                    true,
+                ),
+                clock_skew_policy: AdapterClockSkewPolicy::new(
+                    AdapterClockSkewAction::Reject,
+                    1_000,
+                    false,
+                ),
+                ph1k_feedback_policy: AdapterPh1kFeedbackPolicy::new(true, false, true, 0.4),
+                tenant_feature_flags: BTreeMap::from([(
+                    "tenant_a".to_string(),
+                    AdapterTenantFeatureFlags::new(Some(false), None, None),
+                )]),
+                ..AdapterConfig::default()
+            },
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+        )
+        .expect("runtime from default config must construct");
+        assert!(runtime.persistence.is_none());
+        assert!(runtime.nlp_failure_strict);
+        assert_eq!(
+            runtime.voice_disabled_message,
+            "Voice is off on this device."
+        );
+        let slash_user = UserId::new("tenant_x/user_1").expect("user id must parse");
+        assert_eq!(
+            resolve_tenant_scope(None, &slash_user, None, runtime.tenant_delimiter).as_deref(),
+            Some("tenant_x")
+        );
+        assert_eq!(
+            runtime.provider_latency_policy,
+            AdapterProviderLatencyPolicy::new(20, 800, 800)
+        );
+        assert_eq!(
+            runtime.wake_gate_policy,
+            build_wake_gate_policy_from_env_var_map(|key| match key {
+                "SELENE_WAKE_GATE_EXPLICIT" => Some("required".to_string()),
+                "SELENE_WAKE_GATE_WAKE_WORD" => Some("skip".to_string()),
+                "SELENE_WAKE_GATE_DESKTOP_TRUSTED" => Some("true".to_string()),
+                _ => None,
+            })
+            .expect("wake gate env must parse")
+        );
+        assert_eq!(
+            runtime.clock_skew_policy,
+            parse_clock_skew_policy(Some("reject"), 1_000, false)
+        );
+        let mut parsed_feedback_policy = AdapterPh1kFeedbackPolicy::default();
+        apply_ph1k_feedback_policy_settings(
+            &mut parsed_feedback_policy,
+            "missed_lexical_trigger=false,min_confidence_delta=0.4",
+        );
+        assert_eq!(runtime.ph1k_feedback_policy, parsed_feedback_policy);
+        assert_eq!(
+            runtime.tenant_feature_flags,
+            parse_tenant_feature_flags("tenant_a:ph1c_live_enabled=false")
+        );
+        assert!(!runtime.ph1c_live_enabled_for_tenant(Some("tenant_a")));
+        assert!(runtime.ph1c_live_enabled_for_tenant(Some("tenant_b")));
+    }
+
     #[test]
     fn at_persistence_01_pending_operation_survives_restart() {
         let journal_path = temp_persistence_journal_path("pending_survives_restart");
@@ -41003,6 +52983,251 @@ mod tests {
         assert!(health.sync.worker.last_pass_at_ns.is_some());
     }
 
+    #[test]
+    fn at_adapter_09_1_metrics_prometheus_declares_each_metric_once_with_its_type() {
+        let runtime = AdapterRuntime::default();
+        runtime
+            .run_device_artifact_sync_worker_pass(Some(101))
+            .expect("sync worker pass should succeed");
+        {
+            let mut counters = runtime
+                .improvement_counters
+                .lock()
+                .expect("improvement counters lock must not poison");
+            counters.builder_runs_total = 4;
+            counters.builder_completed_total = 2;
+            counters.read_only_incidents.tool_fail.incidents_total = 3;
+            counters
+                .empty_responses
+                .unexpected_by_next_move
+                .insert("respond".to_string(), 2);
+        }
+        let text = runtime.metrics_prometheus();
+
+        let mut declared: BTreeMap<String, String> = BTreeMap::new();
+        for line in text.lines().filter(|line| line.starts_with("# TYPE ")) {
+            let mut parts = line["# TYPE ".len()..].split(' ');
+            let name = parts.next().expect("type line must name a metric");
+            let kind = parts.next().expect("type line must carry a type");
+            assert!(
+                declared
+                    .insert(name.to_string(), kind.to_string())
+                    .is_none(),
+                "{name} declared more than once"
+            );
+        }
+        for line in text.lines().filter(|line| !line.starts_with('#')) {
+            let name = line
+                .split(['{', ' '])
+                .next()
+                .expect("sample line must name a metric");
+            assert!(
+                declared.contains_key(name),
+                "{name} sampled without a type line"
+            );
+        }
+        for name in [
+            "sync_worker_passes_total",
+            "sync_dequeued_total",
+            "sync_acked_total",
+            "sync_retry_scheduled_total",
+            "sync_dead_lettered_total",
+            "feedback_events_emitted_total",
+            "learn_artifacts_emitted_total",
+            "builder_runs_total",
+            "builder_completed_total",
+            "builder_refused_total",
+            "builder_not_invoked_total",
+            "builder_errors_total",
+            "read_only_incidents_total",
+            "read_only_incident_feedback_events_total",
+            "read_only_incident_learn_artifacts_total",
+            "read_only_incidents_suppressed_total",
+            "empty_responses_total",
+        ] {
+            assert_eq!(
+                declared
+                    .get(&format!("selene_adapter_{name}"))
+                    .map(String::as_str),
+                Some("counter"),
+                "{name} must be a counter"
+            );
+        }
+        for name in [
+            "sync_queue_queued",
+            "sync_queue_in_flight",
+            "sync_queue_acked",
+            "sync_queue_dead_letter",
+            "sync_queue_replay_due",
+            "sync_queue_retry_pending",
+            "sync_queue_depth",
+            "sync_last_backoff_ms",
+            "builder_completion_ratio",
+        ] {
+            assert_eq!(
+                declared
+                    .get(&format!("selene_adapter_{name}"))
+                    .map(String::as_str),
+                Some("gauge"),
+                "{name} must be a gauge"
+            );
+        }
+        assert_eq!(declared.len(), 26);
+        assert!(text.contains("\nselene_adapter_sync_worker_passes_total 1\n"));
+        assert!(text.contains("\nselene_adapter_builder_completion_ratio 0.5\n"));
+        assert!(text.contains("\nselene_adapter_read_only_incidents_total{kind=\"tool_fail\"} 3\n"));
+        assert!(text.contains(
+            "\nselene_adapter_empty_responses_total{class=\"unexpected\",next_move=\"respond\"} 2\n"
+        ));
+    }
+
+    #[test]
+    fn at_adapter_09a_health_subscription_pushes_after_sync_pass() {
+        let runtime = AdapterRuntime::default();
+        let updates = runtime.subscribe_health();
+        runtime
+            .run_voice_turn(base_request())
+            .expect("voice turn should succeed");
+
+        runtime
+            .run_device_artifact_sync_worker_pass(Some(1_000))
+            .expect("sync worker pass should succeed");
+        let pushed = updates
+            .try_recv()
+            .expect("sync pass should push a health snapshot");
+        assert_eq!(pushed.status, "ok");
+        assert_eq!(pushed.sync.worker.last_pass_at_ns, Some(1_000));
+
+        runtime
+            .run_device_artifact_sync_worker_pass(Some(
+                1_000 + HEALTH_SUBSCRIPTION_COALESCE_WINDOW_NS,
+            ))
+            .expect("sync worker pass should succeed");
+        assert!(
+            updates.try_recv().is_err(),
+            "a pass without counter changes must not push"
+        );
+    }
+
+    #[test]
+    fn at_adapter_09b_long_idempotency_tokens_do_not_collide_after_truncation() {
+        let shared_prefix = "sync_".repeat(60);
+        let default_max_len = AdapterConfig::default().idempotency_token_max_len;
+        let first =
+            sanitize_idempotency_token(&format!("{shared_prefix}device_a"), default_max_len);
+        let second =
+            sanitize_idempotency_token(&format!("{shared_prefix}device_b"), default_max_len);
+        assert_eq!(first.len(), 128);
+        assert_eq!(second.len(), 128);
+        assert_ne!(first, second);
+        assert!(first.starts_with("sync_sync_"));
+
+        let short_first = sanitize_idempotency_token(&format!("{shared_prefix}a"), 40);
+        let short_second = sanitize_idempotency_token(&format!("{shared_prefix}b"), 40);
+        assert_eq!(short_first.len(), 40);
+        assert_ne!(short_first, short_second);
+
+        assert_eq!(
+            sanitize_idempotency_token("sync:short", default_max_len),
+            "sync_short"
+        );
+        assert_eq!(
+            sanitize_idempotency_token(&"x".repeat(40), 40),
+            "x".repeat(40)
+        );
+
+        let runtime = AdapterRuntime {
+            idempotency_token_max_len: 40,
+            ..AdapterRuntime::default()
+        };
+        let mut req = base_request();
+        req.thread_key = Some("long_thread_".repeat(8));
+        req.user_text_final = Some("Summarize adapter readiness.".to_string());
+        runtime
+            .run_voice_turn(req)
+            .expect("turn with a long thread key must succeed");
+        let store = runtime.store.lock().expect("store lock must not poison");
+        let thread_state_keys = store
+            .ph1x_thread_state_ledger_rows()
+            .iter()
+            .map(|row| row.idempotency_key.len())
+            .collect::<Vec<_>>();
+        assert!(!thread_state_keys.is_empty());
+        assert!(thread_state_keys.iter().all(|len| *len == 40));
+    }
+
+    #[test]
+    fn at_adapter_09c_concurrent_artifact_version_allocation_never_duplicates() {
+        let runtime = AdapterRuntime::default();
+        {
+            let mut store = runtime.store.lock().expect("store lock should succeed");
+            store
+                .ph1learn_artifact_commit(
+                    MonotonicTimeNs(10),
+                    "tenant_a".to_string(),
+                    ArtifactScopeType::Tenant,
+                    "tenant_a".to_string(),
+                    ArtifactType::VoiceIdProfileDeltaPack,
+                    ArtifactVersion(3),
+                    "seed_hash_v3".to_string(),
+                    "learn:voice_sync:seed".to_string(),
+                    "sync_feedback:seed".to_string(),
+                    ArtifactStatus::Deprecated,
+                    "artifact_version_seed_v3".to_string(),
+                )
+                .expect("seed artifact commit should succeed");
+        }
+
+        let handles = (0..8)
+            .map(|_| {
+                let runtime = runtime.clone();
+                std::thread::spawn(move || {
+                    (0..25)
+                        .map(|_| {
+                            let store = runtime.store.lock().expect("store lock should succeed");
+                            let tenant_a = runtime
+                                .allocate_artifact_version(
+                                    &store,
+                                    "tenant_a",
+                                    ArtifactType::VoiceIdProfileDeltaPack,
+                                )
+                                .expect("version allocation should succeed");
+                            drop(store);
+                            let tenant_b = runtime
+                                .allocate_artifact_version(
+                                    &Ph1fStore::new_in_memory(),
+                                    "tenant_b",
+                                    ArtifactType::VoiceIdProfileDeltaPack,
+                                )
+                                .expect("version allocation should succeed");
+                            (tenant_a, tenant_b)
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut tenant_a_versions = Vec::new();
+        let mut tenant_b_versions = Vec::new();
+        for handle in handles {
+            for (tenant_a, tenant_b) in handle.join().expect("allocation thread should finish") {
+                tenant_a_versions.push(tenant_a);
+                tenant_b_versions.push(tenant_b);
+            }
+        }
+        tenant_a_versions.sort_unstable();
+        tenant_b_versions.sort_unstable();
+        assert_eq!(tenant_a_versions, (4..204).collect::<Vec<u32>>());
+        assert_eq!(tenant_b_versions, (1..201).collect::<Vec<u32>>());
+
+        let store = runtime.store.lock().expect("store lock should succeed");
+        assert_eq!(
+            runtime
+                .allocate_artifact_version(&store, "tenant_a", ArtifactType::VoiceIdThresholdPack)
+                .expect("version allocation should succeed"),
+            1
+        );
+    }
+
     #[test]
     fn at_adapter_36_sync_retry_improvement_and_builder_observation_remain_downstream_only() {
         let runtime = AdapterRuntime::default();
@@ -41120,8 +53345,8 @@ mod tests {
                         && row.outcome_type == "VOICE_SYNC_RETRY"
                 })
                 .count();
-            let tenant_scope =
-                tenant_scope_from_user_id(&actor_user_id).expect("tenant scope must resolve");
+            let tenant_scope = tenant_scope_from_user_id(&actor_user_id, TENANT_DELIMITER_DEFAULT)
+                .expect("tenant scope must resolve");
             let learn_artifact_before = store
                 .ph1learn_artifact_rows(
                     ArtifactScopeType::Tenant,
@@ -41256,82 +53481,979 @@ mod tests {
         assert_eq!(health_after.sync.queue, queue_after);
 
         assert_eq!(
-            original_retry_row_after.sync_job_id,
-            original_retry_row_before.sync_job_id
-        );
-        assert_eq!(
-            original_retry_row_after.state,
-            original_retry_row_before.state
-        );
-        assert_eq!(
-            original_retry_row_after.attempt_count,
-            original_retry_row_before.attempt_count
-        );
-        assert_eq!(
-            original_retry_row_after.worker_id,
-            original_retry_row_before.worker_id
-        );
-        assert_eq!(
-            original_retry_row_after.last_error,
-            original_retry_row_before.last_error
+            original_retry_row_after.sync_job_id,
+            original_retry_row_before.sync_job_id
+        );
+        assert_eq!(
+            original_retry_row_after.state,
+            original_retry_row_before.state
+        );
+        assert_eq!(
+            original_retry_row_after.attempt_count,
+            original_retry_row_before.attempt_count
+        );
+        assert_eq!(
+            original_retry_row_after.worker_id,
+            original_retry_row_before.worker_id
+        );
+        assert_eq!(
+            original_retry_row_after.last_error,
+            original_retry_row_before.last_error
+        );
+        assert_eq!(
+            original_retry_row_after.lease_expires_at,
+            original_retry_row_before.lease_expires_at
+        );
+        assert_eq!(
+            original_retry_row_after.last_attempted_at,
+            original_retry_row_before.last_attempted_at
+        );
+        assert_eq!(queue_after.in_flight_count, queue_before.in_flight_count);
+        assert_eq!(
+            queue_after.retry_pending_count,
+            queue_before.retry_pending_count
+        );
+        assert_eq!(
+            queue_after.dead_letter_count,
+            queue_before.dead_letter_count
+        );
+        assert_eq!(queue_after.replay_due_count, queue_before.replay_due_count);
+        assert_eq!(queue_after.queued_count, queue_before.queued_count + 1);
+        assert_eq!(
+            new_queue_rows.len(),
+            1,
+            "retry improvement fan-out should append exactly one queued manifest row"
+        );
+        assert_ne!(new_queue_rows[0].sync_job_id, original_sync_job_id);
+        assert_eq!(new_queue_rows[0].state, MobileArtifactSyncState::Queued);
+        assert_eq!(new_queue_rows[0].attempt_count, 0);
+        assert_eq!(new_queue_rows[0].worker_id, None);
+        assert_eq!(new_queue_rows[0].last_error, None);
+        assert_eq!(new_queue_rows[0].last_attempted_at, None);
+        assert_eq!(new_queue_rows[0].lease_expires_at, None);
+    }
+
+    #[test]
+    fn at_adapter_10_ui_health_checks_order_is_locked() {
+        let runtime = AdapterRuntime::default();
+        let response = runtime
+            .ui_health_checks_report(Some(111))
+            .expect("ui health checks should succeed");
+        let order = response
+            .checks
+            .iter()
+            .map(|check| check.check_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            vec![
+                "VOICE",
+                "WAKE",
+                "SYNC",
+                "SYNC_REPLAY_DUE",
+                "SYNC_WORKER_LIVENESS",
+                "STT",
+                "TTS",
+                "DELIVERY",
+                "BUILDER",
+                "PROVIDER_LATENCY",
+                "MEMORY"
+            ]
+        );
+    }
+
+    #[test]
+    fn at_adapter_10b_provider_latency_check_escalates_on_rising_p95() {
+        let runtime = AdapterRuntime {
+            provider_latency_policy: AdapterProviderLatencyPolicy::new(10, 500, 1_000),
+            ..AdapterRuntime::default()
+        };
+        let provider_latency_check = |runtime: &AdapterRuntime| {
+            runtime
+                .ui_health_checks_report(Some(300))
+                .expect("ui health checks should succeed")
+                .checks
+                .into_iter()
+                .find(|check| check.check_id == "PROVIDER_LATENCY")
+                .expect("provider latency check must exist")
+        };
+
+        for latency_ms in [120, 140, 160, 180, 200, 220, 240, 260, 280, 300] {
+            runtime
+                .record_provider_latency_ms(latency_ms)
+                .expect("latency sample must record");
+        }
+        let health = runtime.health_report(Some(300)).expect("health report");
+        assert_eq!(health.provider_latency.status, "OK");
+        assert_eq!(health.provider_latency.p95_ms, Some(300));
+        assert_eq!(provider_latency_check(&runtime).status, "HEALTHY");
+
+        for latency_ms in [450, 500, 550, 600, 650, 700, 750, 800, 850, 900] {
+            runtime
+                .record_provider_latency_ms(latency_ms)
+                .expect("latency sample must record");
+        }
+        let health = runtime.health_report(Some(300)).expect("health report");
+        assert_eq!(health.provider_latency.status, "WARN");
+        assert_eq!(health.provider_latency.sample_count, 10);
+        assert_eq!(health.provider_latency.p95_ms, Some(900));
+        let check = provider_latency_check(&runtime);
+        assert_eq!(check.status, "AT_RISK");
+        assert_eq!(check.open_issue_count, 1);
+
+        for latency_ms in [
+            950, 1_100, 1_250, 1_400, 1_550, 1_700, 1_850, 2_000, 2_150, 2_300,
+        ] {
+            runtime
+                .record_provider_latency_ms(latency_ms)
+                .expect("latency sample must record");
+        }
+        let health = runtime.health_report(Some(300)).expect("health report");
+        assert_eq!(health.provider_latency.status, "CRITICAL");
+        assert_eq!(health.provider_latency.p95_ms, Some(2_300));
+        assert_eq!(provider_latency_check(&runtime).status, "CRITICAL");
+    }
+
+    #[test]
+    #[tracing_test::traced_test]
+    fn at_adapter_10b1_provider_latency_window_of_zero_is_rejected() {
+        let policy = AdapterProviderLatencyPolicy::new(0, 500, 1_000);
+        assert_eq!(policy, AdapterProviderLatencyPolicy::new(50, 500, 1_000));
+        assert!(logs_contain("provider latency window of 0 rejected"));
+
+        let runtime = AdapterRuntime {
+            provider_latency_policy: policy,
+            ..AdapterRuntime::default()
+        };
+        for latency_ms in [600, 700, 800, 900, 1_000] {
+            runtime
+                .record_provider_latency_ms(latency_ms)
+                .expect("latency sample must record");
+        }
+        let health = runtime.health_report(Some(300)).expect("health report");
+        assert_eq!(health.provider_latency.sample_count, 5);
+        assert_eq!(health.provider_latency.status, "CRITICAL");
+    }
+
+    #[test]
+    fn at_adapter_10b2_config_policies_build_without_env() {
+        let interrupt = AdapterInterruptConfidencePolicy::new(1.5, f32::NAN);
+        assert_eq!(interrupt.min_confidence(true), 1.0);
+        assert_eq!(
+            interrupt.min_confidence(false),
+            DEFAULT_MIN_INTERRUPT_PHRASE_CONFIDENCE
+        );
+
+        let vision = AdapterVisionSourceKindPolicy::new(true, false, true);
+        assert!(vision.allows(VisualSourceKind::Image));
+        assert!(!vision.allows(VisualSourceKind::Screenshot));
+
+        let breaker = AdapterProviderCircuitBreakerPolicy::new(0, 250);
+        assert_eq!(breaker.failure_threshold, 1);
+        assert_eq!(breaker.cooldown_ns, 250_000_000);
+
+        let adapter = EnvPh1dLiveAdapter::new(
+            " openai ",
+            "gpt-4o-mini",
+            "https://api.openai.com/v1/responses",
+            "test_key",
+            5_000,
+        )
+        .expect("openai adapter must build");
+        assert_eq!(adapter.provider_id, "openai");
+        assert!(matches!(
+            EnvPh1dLiveAdapter::new("other", "m", "https://x", "k", 5_000),
+            Err(AdapterError::Validation(_))
+        ));
+        assert!(matches!(
+            EnvPh1dLiveAdapter::new("openai", "m", "https://x", "k", 50),
+            Err(AdapterError::Validation(_))
+        ));
+
+        let runtime = AdapterRuntime::from_config(
+            AdapterConfig {
+                interrupt_confidence_policy: interrupt,
+                vision_source_kind_policy: vision,
+                provider_circuit_breaker_policy: breaker,
+                ph1d_live_adapter: Some(adapter),
+                ..AdapterConfig::default()
+            },
+            Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+        )
+        .expect("runtime must build from config");
+        assert_eq!(runtime.provider_circuit_breaker_policy, breaker);
+        assert_eq!(runtime.vision_source_kind_policy, vision);
+    }
+
+    #[test]
+    fn at_adapter_10c_sync_worker_liveness_check_escalates_when_passes_stall() {
+        let runtime = AdapterRuntime {
+            sync_worker_stale_after_ms: 1_000,
+            ..AdapterRuntime::default()
+        };
+        let liveness_check = |now_ns: u64| {
+            runtime
+                .ui_health_checks_report(Some(now_ns))
+                .expect("ui health checks should succeed")
+                .checks
+                .into_iter()
+                .find(|check| check.check_id == "SYNC_WORKER_LIVENESS")
+                .expect("sync worker liveness check must exist")
+        };
+
+        let before_first_pass = liveness_check(10_000_000_000);
+        assert_eq!(before_first_pass.status, "HEALTHY");
+        assert_eq!(before_first_pass.last_event_at_ns, None);
+
+        let last_pass_at_ns = 5_000_000_000;
+        runtime
+            .run_device_artifact_sync_worker_pass(Some(last_pass_at_ns))
+            .expect("sync worker pass must run");
+
+        let recent = liveness_check(last_pass_at_ns + 999_000_000);
+        assert_eq!(recent.status, "HEALTHY");
+        assert_eq!(recent.open_issue_count, 0);
+        assert_eq!(recent.last_event_at_ns, Some(last_pass_at_ns));
+
+        let stale = liveness_check(last_pass_at_ns + 1_000_000_000);
+        assert_eq!(stale.status, "AT_RISK");
+        assert_eq!(stale.open_issue_count, 1);
+
+        let stalled = liveness_check(last_pass_at_ns + 2_000_000_000);
+        assert_eq!(stalled.status, "CRITICAL");
+        assert_eq!(stalled.open_issue_count, 1);
+        assert_eq!(stalled.last_event_at_ns, Some(last_pass_at_ns));
+    }
+
+    #[test]
+    fn at_adapter_10c_sync_replay_due_check_escalates_with_age() {
+        let runtime = AdapterRuntime::default();
+        let mut request = base_request();
+        request.app_platform = "ANDROID".to_string();
+        request.trigger = "WAKE_WORD".to_string();
+        request.actor_user_id = "tenant_a:user_adapter_test_10c".to_string();
+        request.device_id = Some("adapter_android_device_10c".to_string());
+        seed_wake_enrollment_complete_for_request(&runtime, &mut request, "at_adapter_10c");
+
+        let dequeued_at = 1_000_000_000;
+        let lease_expires_at = {
+            let mut store = runtime.store.lock().expect("store lock should succeed");
+            let dequeued = store
+                .device_artifact_sync_dequeue_batch(
+                    MonotonicTimeNs(dequeued_at),
+                    1,
+                    1_000,
+                    "at_adapter_10c_worker".to_string(),
+                )
+                .expect("dequeue should succeed");
+            assert_eq!(dequeued.len(), 1);
+            dequeued[0].lease_expires_at.expect("lease must be set").0
+        };
+        let replay_due_check = |now_ns: u64| {
+            runtime
+                .ui_health_checks_report(Some(now_ns))
+                .expect("ui health checks should succeed")
+                .checks
+                .into_iter()
+                .find(|check| check.check_id == "SYNC_REPLAY_DUE")
+                .expect("replay-due check must exist")
+        };
+
+        assert_eq!(replay_due_check(lease_expires_at - 1).status, "HEALTHY");
+
+        let fresh = replay_due_check(lease_expires_at + 1_000_000_000);
+        assert_eq!(fresh.status, "AT_RISK");
+        assert_eq!(fresh.open_issue_count, 1);
+        let health = runtime
+            .health_report(Some(lease_expires_at + 1_000_000_000))
+            .expect("health report");
+        assert_eq!(health.sync.queue.replay_due_count, 1);
+        assert_eq!(
+            health.sync.queue.replay_due_oldest_at_ns,
+            Some(lease_expires_at)
+        );
+
+        let stale = replay_due_check(lease_expires_at + SYNC_REPLAY_DUE_CRITICAL_AGE_NS);
+        assert_eq!(stale.status, "CRITICAL");
+
+        let detail = runtime
+            .ui_health_detail_report("sync_replay_due", Some(lease_expires_at + 1))
+            .expect("replay-due detail should succeed");
+        assert_eq!(detail.selected_check_id, "SYNC_REPLAY_DUE");
+        assert_eq!(detail.issues.len(), 1);
+        assert_eq!(detail.issues[0].issue_id, "sync_replay_due");
+    }
+
+    #[test]
+    fn at_adapter_10d_aggregate_replay_due_issue_skips_device_feedback() {
+        let runtime = AdapterRuntime::default();
+        let mut store = Ph1fStore::new_in_memory();
+        let now = MonotonicTimeNs(2_000_000_000);
+        let correlation_id = CorrelationId(10_400);
+        let queue_after = AdapterSyncQueueCounters {
+            replay_due_count: 2,
+            ..AdapterSyncQueueCounters::default()
+        };
+
+        let issues = collect_sync_issue_records_for_pass(&store, now, &queue_after);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].issue_kind, SyncIssueKind::ReplayDue);
+        assert!(issues[0].device_id.is_none());
+
+        let emission = runtime
+            .emit_sync_improvement_events(
+                &mut store,
+                now,
+                correlation_id,
+                TurnId(1),
+                &DeviceArtifactSyncWorkerPassMetrics::default(),
+                &queue_after,
+            )
+            .expect("replay-due emission should succeed");
+        assert_eq!(emission.feedback_events_emitted, 0);
+        assert!(store.ph1feedback_audit_rows(correlation_id).is_empty());
+        assert!(emission
+            .builder_input_entries
+            .iter()
+            .any(|entry| entry.outcome_type == "VOICE_SYNC_REPLAY_DUE"));
+    }
+
+    #[test]
+    fn at_adapter_10e_builder_refusal_appears_in_health_timeline() {
+        let runtime = AdapterRuntime::default();
+        runtime
+            .record_builder_status(
+                MonotonicTimeNs(500),
+                "REFUSED_READ_ONLY:PATTERN:4660",
+                BuilderStatusKind::Refused,
+            )
+            .expect("builder status should record");
+
+        let detail = runtime
+            .ui_health_detail_report("BUILDER", Some(1_000))
+            .expect("builder detail should succeed");
+        assert_eq!(detail.active_issue_id.as_deref(), Some("builder_health"));
+        let refusal = detail
+            .timeline
+            .iter()
+            .find(|entry| entry.action_id == "BUILDER_RUN_REFUSED")
+            .expect("builder refusal must appear in the timeline");
+        assert_eq!(refusal.at_ns, Some(500));
+        assert_eq!(refusal.result, "REFUSED_READ_ONLY:PATTERN:4660");
+        assert_eq!(refusal.reason_code, "4660");
+
+        let health = runtime.health_report(Some(1_000)).expect("health report");
+        let tenant = TenantId::new("tenant_a".to_string()).expect("tenant id");
+        let event = synth_health_issue_events(
+            &health,
+            &tenant,
+            5_000_000_000,
+            &AdapterHealthIssueDeadlinePolicy::default(),
+            usize::MAX,
+        )
+        .into_iter()
+        .find(|event| event.issue_id == "builder_run_failed")
+        .expect("builder refusal must synthesize a health issue");
+        assert_eq!(event.owner_engine_id, "PH1.BUILDER");
+        assert_eq!(event.reason_code, ReasonCodeId(4660));
+    }
+
+    #[test]
+    fn at_adapter_10f_sync_retry_policy_backoff_is_visible_in_worker_counters() {
+        let mut executor = SimulationExecutor::default();
+        executor.set_device_sync_sender(DeviceArtifactSyncSenderRuntime::AlwaysFail {
+            message: "engine_b_flaky_network".to_string(),
+            retry_after_ms: 1_000,
+        });
+        let runtime = AdapterRuntime {
+            ingress: AppServerIngressRuntime::new(executor),
+            sync_retry_policy: SyncRetryPolicy {
+                max_attempts: 4,
+                base_backoff_ms: 3_000,
+                max_backoff_ms: 9_000,
+                jitter_pct: 0,
+            },
+            ..AdapterRuntime::default()
+        };
+        let mut request = base_request();
+        request.app_platform = "ANDROID".to_string();
+        request.trigger = "WAKE_WORD".to_string();
+        request.actor_user_id = "tenant_a:user_adapter_test_10f".to_string();
+        request.device_id = Some("adapter_android_device_10f".to_string());
+        seed_wake_enrollment_complete_for_request(&runtime, &mut request, "at_adapter_10f");
+        let sync_job_id = {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            let rows = store.device_artifact_sync_queue_rows();
+            assert_eq!(rows.len(), 1);
+            rows[0].sync_job_id.clone()
+        };
+        let seeded_row = || {
+            runtime
+                .store
+                .lock()
+                .expect("store lock should succeed")
+                .device_artifact_sync_queue_rows()
+                .iter()
+                .find(|row| row.sync_job_id == sync_job_id)
+                .cloned()
+                .expect("seeded queue row must exist")
+        };
+
+        let mut now_ns = 2_000_000_000;
+        let mut backoffs = Vec::new();
+        for pass in 0..3 {
+            runtime
+                .run_device_artifact_sync_worker_pass(Some(now_ns))
+                .expect("sync worker pass should succeed");
+            let worker = runtime
+                .health_report(Some(now_ns))
+                .expect("health report should succeed")
+                .sync
+                .worker;
+            assert!(worker.last_retry_scheduled_count > 0);
+            assert_eq!(worker.last_dead_lettered_count, 0);
+            if pass == 0 {
+                assert_eq!(worker.last_backoff_ms, 3_000);
+            }
+            let row = seeded_row();
+            assert_eq!(row.state, MobileArtifactSyncState::InFlight);
+            let next_attempt_at = row.lease_expires_at.expect("retry lease must be set").0;
+            backoffs.push((next_attempt_at - now_ns) / 1_000_000);
+            now_ns = next_attempt_at;
+        }
+        assert_eq!(backoffs, vec![3_000, 6_000, 9_000]);
+
+        runtime
+            .run_device_artifact_sync_worker_pass(Some(now_ns))
+            .expect("sync worker pass should succeed");
+        let row = seeded_row();
+        assert_eq!(row.attempt_count, 4);
+        assert_eq!(row.state, MobileArtifactSyncState::DeadLetter);
+        let health = runtime
+            .health_report(Some(now_ns))
+            .expect("health report should succeed");
+        assert!(health.sync.worker.last_dead_lettered_count > 0);
+        assert!(health.sync.queue.dead_letter_count > 0);
+    }
+
+    #[test]
+    fn at_adapter_10f2_dead_letter_list_and_requeue_replays_on_next_pass() {
+        let mut executor = SimulationExecutor::default();
+        executor.set_device_sync_sender(DeviceArtifactSyncSenderRuntime::AlwaysFail {
+            message: "engine_b_provider_outage".to_string(),
+            retry_after_ms: 1_000,
+        });
+        let runtime = AdapterRuntime {
+            ingress: AppServerIngressRuntime::new(executor),
+            sync_retry_policy: SyncRetryPolicy {
+                max_attempts: 1,
+                base_backoff_ms: 1_000,
+                max_backoff_ms: 1_000,
+                jitter_pct: 0,
+            },
+            ..AdapterRuntime::default()
+        };
+        let mut request = base_request();
+        request.app_platform = "ANDROID".to_string();
+        request.trigger = "WAKE_WORD".to_string();
+        request.actor_user_id = "tenant_a:user_adapter_test_10f2".to_string();
+        request.device_id = Some("adapter_android_device_10f2".to_string());
+        seed_wake_enrollment_complete_for_request(&runtime, &mut request, "at_adapter_10f2");
+        let queued = {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            store.device_artifact_sync_queue_rows()[0].clone()
+        };
+        assert!(runtime
+            .list_dead_letters()
+            .expect("dead letters should list")
+            .is_empty());
+
+        runtime
+            .run_device_artifact_sync_worker_pass(Some(2_000_000_000))
+            .expect("sync worker pass should succeed");
+        let dead_letters = runtime
+            .list_dead_letters()
+            .expect("dead letters should list");
+        assert_eq!(
+            dead_letters,
+            vec![DeadLetterRow {
+                sync_job_id: queued.sync_job_id.clone(),
+                sync_kind: format!("{:?}", queued.sync_kind),
+                attempt_count: 1,
+                last_error: dead_letters[0].last_error.clone(),
+                user_id: Some("tenant_a:user_adapter_test_10f2".to_string()),
+                device_id: "adapter_android_device_10f2".to_string(),
+            }]
+        );
+        assert!(dead_letters[0]
+            .last_error
+            .as_deref()
+            .is_some_and(|err| err.contains("engine_b_provider_outage")));
+
+        runtime
+            .requeue_dead_letter(&queued.sync_job_id)
+            .expect("dead-lettered job should requeue");
+        assert!(runtime
+            .list_dead_letters()
+            .expect("dead letters should list")
+            .is_empty());
+        let row = runtime
+            .store
+            .lock()
+            .expect("store lock should succeed")
+            .device_artifact_sync_queue_rows()[0]
+            .clone();
+        assert_eq!(row.state, MobileArtifactSyncState::Queued);
+        assert_eq!(row.attempt_count, 0);
+        assert!(matches!(
+            runtime.requeue_dead_letter(&queued.sync_job_id),
+            Err(AdapterError::Storage(_))
+        ));
+        assert!(matches!(
+            runtime.requeue_dead_letter("sync_job_unknown"),
+            Err(AdapterError::Storage(
+                StorageError::ForeignKeyViolation { .. }
+            ))
+        ));
+
+        // The outage is over: the same store behind a sender that acks.
+        let mut executor = SimulationExecutor::default();
+        executor.set_device_sync_sender(DeviceArtifactSyncSenderRuntime::LoopbackAck);
+        let recovered = AdapterRuntime {
+            ingress: AppServerIngressRuntime::new(executor),
+            ..runtime.clone()
+        };
+        recovered
+            .run_device_artifact_sync_worker_pass(Some(3_000_000_000))
+            .expect("sync worker pass should succeed");
+        let row = recovered
+            .store
+            .lock()
+            .expect("store lock should succeed")
+            .device_artifact_sync_queue_rows()[0]
+            .clone();
+        assert_eq!(row.state, MobileArtifactSyncState::Acked);
+        assert_eq!(row.attempt_count, 1);
+    }
+
+    #[test]
+    fn at_adapter_10g_builder_waits_for_min_outcome_entries() {
+        let runtime = AdapterRuntime {
+            auto_builder_enabled: true,
+            builder_min_outcome_entries: 2,
+            ..AdapterRuntime::default()
+        };
+        let mut store = Ph1fStore::new_in_memory();
+        let now = MonotonicTimeNs(2_000_000_000);
+        let correlation_id = CorrelationId(10_700);
+        let metrics = DeviceArtifactSyncWorkerPassMetrics::default();
+        let queue_after = AdapterSyncQueueCounters {
+            replay_due_count: 1,
+            ..AdapterSyncQueueCounters::default()
+        };
+        let emission = runtime
+            .emit_sync_improvement_events(
+                &mut store,
+                now,
+                correlation_id,
+                TurnId(1),
+                &metrics,
+                &queue_after,
+            )
+            .expect("replay-due emission should succeed");
+        assert_eq!(emission.builder_input_entries.len(), 1);
+
+        runtime
+            .maybe_run_builder_for_sync_improvements(
+                &mut store,
+                SyncImprovementBuilderContext {
+                    now,
+                    correlation_id,
+                    turn_id: TurnId(1),
+                    metrics: &metrics,
+                    queue_after: &queue_after,
+                    outcome_entries: &emission.builder_input_entries,
+                },
+            )
+            .expect("builder gate should succeed");
+
+        let improvement = runtime
+            .health_report(Some(now.0))
+            .expect("health report should succeed")
+            .sync
+            .improvement;
+        assert_eq!(
+            improvement.last_builder_status.as_deref(),
+            Some("NOT_INVOKED_INSUFFICIENT_SIGNAL")
+        );
+        assert_eq!(improvement.builder_runs_total, 0);
+        assert_eq!(improvement.builder_not_invoked_total, 1);
+    }
+
+    #[test]
+    fn at_adapter_10h_manual_builder_run_ignores_sync_severity() {
+        let runtime = AdapterRuntime {
+            auto_builder_enabled: false,
+            ..AdapterRuntime::default()
+        };
+        {
+            let mut store = runtime.store.lock().expect("store lock should succeed");
+            for (idx, created_at) in [1_000_u64, 2_000, 3_000, 9_000].into_iter().enumerate() {
+                store
+                    .append_outcome_utilization_ledger_row(OutcomeUtilizationLedgerRowInput {
+                        created_at: MonotonicTimeNs(created_at),
+                        correlation_id: CorrelationId(10_800 + idx as u128),
+                        turn_id: TurnId(1 + idx as u64),
+                        engine_id: "PH1.FEEDBACK".to_string(),
+                        outcome_type: "VOICE_SYNC_RETRY".to_string(),
+                        action_class: OsOutcomeActionClass::QueueLearn,
+                        consumed_by: "PH1.LEARN".to_string(),
+                        latency_cost_ms: 120,
+                        decision_delta: true,
+                        reason_code: ReasonCodeId(0xAD70_0001),
+                        idempotency_key: Some(format!("manual_builder_seed_{idx}")),
+                        telemetry_tags: BTreeMap::new(),
+                    })
+                    .expect("outcome row seed should succeed");
+            }
+        }
+
+        let outcome = runtime
+            .run_builder_over_window(1_000, 3_000)
+            .expect("manual builder run should succeed");
+        assert!(!matches!(
+            outcome,
+            BuilderOrchestrationOutcome::NotInvokedDisabled
+                | BuilderOrchestrationOutcome::NotInvokedNoSignals
+        ));
+        let improvement = runtime
+            .health_report(None)
+            .expect("health report should succeed")
+            .sync
+            .improvement;
+        assert_eq!(improvement.builder_runs_total, 1);
+        assert_eq!(
+            improvement.builder_completed_total + improvement.builder_refused_total,
+            1
+        );
+        assert!(improvement
+            .last_builder_status
+            .as_deref()
+            .is_some_and(|status| status.contains("_MANUAL")));
+
+        let empty = runtime
+            .run_builder_over_window(4_000, 8_000)
+            .expect("empty window run should succeed");
+        assert_eq!(empty, BuilderOrchestrationOutcome::NotInvokedNoSignals);
+        assert!(runtime.run_builder_over_window(3_000, 1_000).is_err());
+        let improvement = runtime
+            .health_report(None)
+            .expect("health report should succeed")
+            .sync
+            .improvement;
+        assert_eq!(improvement.builder_runs_total, 2);
+        assert_eq!(improvement.builder_not_invoked_total, 1);
+    }
+
+    fn tenant_access_request(label: &str, tenant: &str, turn_id: u64) -> VoiceTurnAdapterRequest {
+        let mut req = base_request();
+        req.correlation_id = turn_id - 10_000;
+        req.turn_id = turn_id;
+        req.now_ns = Some(9_000_000_000);
+        req.app_platform = "DESKTOP".to_string();
+        req.trigger = "WAKE_WORD".to_string();
+        req.actor_user_id = format!("{tenant}:user_{label}");
+        req.tenant_id = Some(tenant.to_string());
+        req.device_id = Some(format!("adapter_{label}"));
+        req
+    }
+
+    #[test]
+    fn at_adapter_10h_tenant_access_policy_admits_allowlisted_tenant() {
+        let runtime = AdapterRuntime {
+            tenant_access_policy: Arc::new(Mutex::new(AdapterTenantAccessPolicy::parse(
+                Some("tenant_a, tenant_b"),
+                Some("tenant_c"),
+            ))),
+            ..AdapterRuntime::default()
+        };
+        let mut req = tenant_access_request("at_10h_allowed", "tenant_a", 47_001);
+        seed_wake_enrollment_complete_for_request(&runtime, &mut req, "at_10h_allowed");
+        runtime
+            .run_voice_turn(req)
+            .expect("allowlisted tenant turn should succeed");
+    }
+
+    #[test]
+    fn at_adapter_10i_tenant_access_policy_blocks_denylisted_tenant() {
+        let runtime = AdapterRuntime {
+            tenant_access_policy: Arc::new(Mutex::new(AdapterTenantAccessPolicy::parse(
+                Some("tenant_a"),
+                Some("tenant_a"),
+            ))),
+            ..AdapterRuntime::default()
+        };
+        let mut req = tenant_access_request("at_10i_denied", "tenant_a", 47_002);
+        seed_wake_enrollment_complete_for_request(&runtime, &mut req, "at_10i_denied");
+        let err = runtime
+            .run_voice_turn(req)
+            .expect_err("denylisted tenant must be blocked")
+            .to_string();
+        assert!(err.starts_with("tenant_blocked"));
+        assert!(err.contains("reason=tenant_denylisted"));
+        assert!(err.contains(&format!(
+            "reason_code={}",
+            reason_codes::ADAPTER_TENANT_BLOCKED.0
+        )));
+
+        let store = runtime.store.lock().expect("store lock must not poison");
+        assert!(store.wake_learn_signal_rows().is_empty());
+        assert!(store.conversation_ledger().is_empty());
+    }
+
+    #[test]
+    fn at_adapter_10i1_blocked_tenant_gets_no_replayed_response() {
+        let runtime = AdapterRuntime::default();
+        let mut req = tenant_access_request("at_10i1_replay", "tenant_a", 47_003);
+        seed_wake_enrollment_complete_for_request(&runtime, &mut req, "at_10i1_replay");
+        runtime
+            .run_voice_turn(req.clone())
+            .expect("admitted tenant turn should succeed");
+
+        runtime
+            .set_tenant_access_policy(AdapterTenantAccessPolicy::parse(None, Some("tenant_a")))
+            .expect("policy should update");
+        let err = runtime
+            .run_voice_turn(req)
+            .expect_err("a blocked tenant must not get the cached response")
+            .to_string();
+        assert!(err.starts_with("tenant_blocked"), "{err}");
+    }
+
+    #[test]
+    fn at_adapter_10j_tenant_access_policy_blocks_tenant_absent_from_allowlist_until_reload() {
+        let runtime = AdapterRuntime {
+            tenant_access_policy: Arc::new(Mutex::new(AdapterTenantAccessPolicy::parse(
+                Some("tenant_b"),
+                None,
+            ))),
+            ..AdapterRuntime::default()
+        };
+        let mut req = tenant_access_request("at_10j_absent", "tenant_a", 47_003);
+        seed_wake_enrollment_complete_for_request(&runtime, &mut req, "at_10j_absent");
+        let err = runtime
+            .run_voice_turn(req.clone())
+            .expect_err("tenant missing from allowlist must be blocked")
+            .to_string();
+        assert!(err.starts_with("tenant_blocked"));
+        assert!(err.contains("reason=tenant_not_allowlisted"));
+        assert!(err.contains("tenant_id=tenant_a"));
+
+        runtime
+            .set_tenant_access_policy(AdapterTenantAccessPolicy::parse(
+                Some("tenant_a,tenant_b"),
+                None,
+            ))
+            .expect("tenant access policy reload should succeed");
+        runtime
+            .run_voice_turn(req)
+            .expect("reloaded allowlist should admit the tenant");
+    }
+
+    #[test]
+    fn at_adapter_10j2_tenant_rate_limit_rejects_exhausted_tenant_only() {
+        let runtime = AdapterRuntime {
+            tenant_rate_limiter: TenantRateLimiter::new(Some(AdapterTenantRateLimitPolicy::new(
+                1, 1,
+            ))),
+            ..AdapterRuntime::default()
+        };
+        let mut first = tenant_access_request("at_10j2_first", "tenant_a", 47_011);
+        seed_wake_enrollment_complete_for_request(&runtime, &mut first, "at_10j2_first");
+        runtime
+            .run_voice_turn(first)
+            .expect("first tenant_a turn fits in the burst");
+        let ledger_len = runtime
+            .store
+            .lock()
+            .expect("store lock must not poison")
+            .conversation_ledger()
+            .len();
+
+        let mut second = tenant_access_request("at_10j2_second", "tenant_a", 47_012);
+        seed_wake_enrollment_complete_for_request(&runtime, &mut second, "at_10j2_second");
+        let err = runtime
+            .run_voice_turn(second)
+            .expect_err("second tenant_a turn must be rate limited");
+        let AdapterError::RateLimited { retry_after_ms } = err else {
+            panic!("expected RateLimited, got {err:?}");
+        };
+        assert!((1..=1_000).contains(&retry_after_ms));
+        assert_eq!(
+            runtime
+                .store
+                .lock()
+                .expect("store lock must not poison")
+                .conversation_ledger()
+                .len(),
+            ledger_len
         );
+
+        let mut other = tenant_access_request("at_10j2_other", "tenant_b", 47_013);
+        seed_wake_enrollment_complete_for_request(&runtime, &mut other, "at_10j2_other");
+        runtime
+            .run_voice_turn(other)
+            .expect("tenant_b has its own bucket");
+    }
+
+    #[test]
+    fn at_adapter_10j3_tenant_rate_limit_refills_over_time() {
+        let limiter = TenantRateLimiter::new(Some(AdapterTenantRateLimitPolicy::new(2, 3)));
+        let start = 1_000_000_000;
+        for _ in 0..3 {
+            limiter
+                .try_acquire("tenant_a", start)
+                .expect("burst turns should pass");
+        }
         assert_eq!(
-            original_retry_row_after.lease_expires_at,
-            original_retry_row_before.lease_expires_at
+            limiter.try_acquire("tenant_a", start),
+            Err(AdapterError::RateLimited {
+                retry_after_ms: 500
+            })
         );
         assert_eq!(
-            original_retry_row_after.last_attempted_at,
-            original_retry_row_before.last_attempted_at
+            limiter.try_acquire("tenant_a", start + 200_000_000),
+            Err(AdapterError::RateLimited {
+                retry_after_ms: 300
+            })
         );
-        assert_eq!(queue_after.in_flight_count, queue_before.in_flight_count);
+        limiter
+            .try_acquire("tenant_a", start + 500_000_000)
+            .expect("half a second earns one turn at 2 per second");
+        assert!(limiter
+            .try_acquire("tenant_a", start + 500_000_000)
+            .is_err());
+        for _ in 0..3 {
+            limiter
+                .try_acquire("tenant_a", start + 60_000_000_000)
+                .expect("a long idle refills up to the burst");
+        }
+        assert!(limiter
+            .try_acquire("tenant_a", start + 60_000_000_000)
+            .is_err());
+        TenantRateLimiter::default()
+            .try_acquire("tenant_a", start)
+            .expect("no policy leaves turns unlimited");
+    }
+
+    #[test]
+    fn at_adapter_10k_unknown_app_platform_rejects_by_default_and_maps_when_configured() {
+        let strict = AdapterRuntime::default();
+        let mut req = base_request();
+        req.app_platform = "TVOS".to_string();
+        let err = strict
+            .run_voice_turn(req.clone())
+            .expect_err("unknown platform must be rejected by default")
+            .to_string();
+        assert!(err.contains("invalid app_platform 'TVOS'"));
+        assert!(strict.unknown_app_platform_counts().is_empty());
+
+        let mapped = AdapterRuntime {
+            unknown_app_platform_policy: AdapterUnknownAppPlatformPolicy::MapTo(AppPlatform::Ios),
+            ..AdapterRuntime::default()
+        };
+        mapped
+            .run_voice_turn(req)
+            .expect("unknown platform must run as the fallback platform");
+        let mut web = base_request();
+        web.correlation_id = 10_002;
+        web.turn_id = 20_002;
+        web.now_ns = Some(4);
+        web.app_platform = " web ".to_string();
+        mapped
+            .run_voice_turn_ingress(web)
+            .expect("unknown platform must pass the ingress envelope as the fallback platform");
         assert_eq!(
-            queue_after.retry_pending_count,
-            queue_before.retry_pending_count
+            mapped.unknown_app_platform_counts(),
+            BTreeMap::from([("TVOS".to_string(), 1), ("WEB".to_string(), 1)])
         );
         assert_eq!(
-            queue_after.dead_letter_count,
-            queue_before.dead_letter_count
+            AdapterUnknownAppPlatformPolicy::parse("desktop"),
+            Some(AdapterUnknownAppPlatformPolicy::MapTo(AppPlatform::Desktop))
         );
-        assert_eq!(queue_after.replay_due_count, queue_before.replay_due_count);
-        assert_eq!(queue_after.queued_count, queue_before.queued_count + 1);
         assert_eq!(
-            new_queue_rows.len(),
-            1,
-            "retry improvement fan-out should append exactly one queued manifest row"
+            AdapterUnknownAppPlatformPolicy::parse("reject"),
+            Some(AdapterUnknownAppPlatformPolicy::Reject)
         );
-        assert_ne!(new_queue_rows[0].sync_job_id, original_sync_job_id);
-        assert_eq!(new_queue_rows[0].state, MobileArtifactSyncState::Queued);
-        assert_eq!(new_queue_rows[0].attempt_count, 0);
-        assert_eq!(new_queue_rows[0].worker_id, None);
-        assert_eq!(new_queue_rows[0].last_error, None);
-        assert_eq!(new_queue_rows[0].last_attempted_at, None);
-        assert_eq!(new_queue_rows[0].lease_expires_at, None);
+        assert_eq!(AdapterUnknownAppPlatformPolicy::parse("tvos"), None);
     }
 
     #[test]
-    fn at_adapter_10_ui_health_checks_order_is_locked() {
+    fn at_adapter_11_ui_health_detail_rejects_unknown_check() {
         let runtime = AdapterRuntime::default();
-        let response = runtime
-            .ui_health_checks_report(Some(111))
-            .expect("ui health checks should succeed");
-        let order = response
-            .checks
-            .iter()
-            .map(|check| check.check_id.as_str())
-            .collect::<Vec<_>>();
-        assert_eq!(
-            order,
-            vec!["VOICE", "WAKE", "SYNC", "STT", "TTS", "DELIVERY", "BUILDER", "MEMORY"]
-        );
+        let err = runtime
+            .ui_health_detail_report("NOT_A_CHECK", Some(111))
+            .expect_err("unknown check id must fail")
+            .to_string();
+        assert!(err.contains("invalid health check id"));
     }
 
     #[test]
-    fn at_adapter_11_ui_health_detail_rejects_unknown_check() {
+    fn at_adapter_11a_poisoned_store_lock_returns_lock_poisoned_error() {
         let runtime = AdapterRuntime::default();
+        let store = runtime.store.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = store.lock().expect("store lock must be free");
+            panic!("poison the adapter store lock");
+        })
+        .join();
+
+        let err = runtime
+            .health_report(Some(111))
+            .expect_err("a poisoned store lock must fail the health report");
+        assert_eq!(err, AdapterError::LockPoisoned("store".to_string()));
+        assert_eq!(err.to_string(), "adapter store lock poisoned");
         let err = runtime
+            .erase_user_transcript(&UserId::new("tenant_a:user_poisoned").unwrap())
+            .expect_err("a poisoned store lock must fail transcript erasure");
+        assert!(matches!(err, AdapterError::LockPoisoned(ref lock) if lock == "store"));
+        assert_eq!(String::from(err), "adapter store lock poisoned");
+        assert_eq!(
+            runtime.list_dead_letters(),
+            Err(AdapterError::LockPoisoned("store".to_string()))
+        );
+
+        let err = AdapterRuntime::default()
             .ui_health_detail_report("NOT_A_CHECK", Some(111))
             .expect_err("unknown check id must fail");
-        assert!(err.contains("invalid health check id"));
+        assert!(matches!(err, AdapterError::Validation(_)), "{err:?}");
+    }
+
+    #[test]
+    fn at_adapter_11b_voice_turn_keeps_poisoned_lock_typed() {
+        let runtime = AdapterRuntime::default();
+        let store = runtime.store.clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = store.lock().expect("store lock must be free");
+            panic!("poison the adapter store lock");
+        })
+        .join();
+
+        let err = runtime
+            .run_voice_turn(base_request())
+            .expect_err("a poisoned store lock must fail the voice turn");
+        assert_eq!(err, AdapterError::LockPoisoned("store".to_string()));
+
+        let failure = classify_voice_turn_adapter_error(err.clone(), Some(7), None, None);
+        assert_eq!(failure.failure_class, FailureClass::RetryableRuntime);
+        assert_eq!(AdapterError::from(failure), err);
+
+        let storage = AdapterError::Storage(StorageError::ForeignKeyViolation {
+            table: "identities",
+            key: "tenant_a:user_missing".to_string(),
+        });
+        let failure = classify_voice_turn_adapter_error(storage.clone(), Some(7), None, None);
+        assert_eq!(AdapterError::from(failure), storage);
     }
 
     #[test]
@@ -41343,7 +54465,7 @@ mod tests {
         runtime
             .run_voice_turn(req)
             .expect("voice turn with transcript finals must succeed");
-        let response = runtime.ui_chat_transcript_report(Some(222));
+        let response = runtime.ui_chat_transcript_report(Some(222), None);
         assert_eq!(response.status, "ok");
         assert!(response.note.is_none());
         assert!(response.messages.iter().any(|message| {
@@ -41360,6 +54482,53 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn at_adapter_12a_privacy_mode_transcript_rows_use_configured_scope() {
+        let scopes_for = |runtime: &AdapterRuntime, privacy_mode: bool| {
+            let mut req = base_request();
+            req.thread_key = Some("privacy_scope_thread".to_string());
+            req.thread_policy_flags = Some(VoiceTurnThreadPolicyFlags {
+                privacy_mode,
+                do_not_disturb: false,
+                strict_safety: false,
+            });
+            req.user_text_final = Some("book payroll for Friday".to_string());
+            req.selene_text_final = Some("Done. Payroll reminder is prepared.".to_string());
+            runtime
+                .run_voice_turn(req)
+                .expect("voice turn with transcript finals must succeed");
+            let store = runtime.store.lock().expect("store lock should succeed");
+            store
+                .conversation_ledger()
+                .iter()
+                .filter(|row| row.correlation_id == CorrelationId(10_001))
+                .map(|row| row.privacy_scope)
+                .collect::<Vec<_>>()
+        };
+        let all_in = |scopes: Vec<PrivacyScope>, expected: PrivacyScope| {
+            !scopes.is_empty() && scopes.iter().all(|scope| *scope == expected)
+        };
+
+        assert!(all_in(
+            scopes_for(&AdapterRuntime::default(), true),
+            PrivacyScope::PrivateDelivery
+        ));
+        assert!(all_in(
+            scopes_for(&AdapterRuntime::default(), false),
+            PrivacyScope::PublicChat
+        ));
+        let public = AdapterRuntime {
+            privacy_mode_transcript_scope: PrivacyScope::PublicChat,
+            ..AdapterRuntime::default()
+        };
+        assert!(all_in(scopes_for(&public, true), PrivacyScope::PublicChat));
+        assert_eq!(
+            privacy_scope_from_label(" private_delivery "),
+            Some(PrivacyScope::PrivateDelivery)
+        );
+        assert_eq!(privacy_scope_from_label("secret"), None);
+    }
+
     #[test]
     fn at_adapter_13_partial_replaced_by_final_without_ghost_line() {
         let runtime = AdapterRuntime::default();
@@ -41368,7 +54537,7 @@ mod tests {
         runtime
             .run_voice_turn(req_partial)
             .expect("partial transcript turn must succeed");
-        let before = runtime.ui_chat_transcript_report(Some(333));
+        let before = runtime.ui_chat_transcript_report(Some(333), None);
         assert!(before
             .messages
             .iter()
@@ -41380,7 +54549,7 @@ mod tests {
         runtime
             .run_voice_turn(req_final)
             .expect("final transcript turn must succeed");
-        let after = runtime.ui_chat_transcript_report(Some(444));
+        let after = runtime.ui_chat_transcript_report(Some(444), None);
         assert!(after.messages.iter().any(|message| {
             message.finalized && message.text == "book payroll for Friday" && message.role == "USER"
         }));
@@ -41399,7 +54568,7 @@ mod tests {
         runtime
             .run_voice_turn(req)
             .expect("partial-only turn must succeed");
-        let response = runtime.ui_chat_transcript_report(Some(555));
+        let response = runtime.ui_chat_transcript_report(Some(555), None);
         assert!(response.messages.iter().any(|message| {
             !message.finalized
                 && message.role == "USER"
@@ -41414,6 +54583,134 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn at_adapter_14a_transcript_pages_interleaved_partials_and_finals_without_gaps() {
+        let runtime = AdapterRuntime::default();
+        for step in 0..6u64 {
+            let mut req = base_request();
+            req.correlation_id = 10_101 + step;
+            req.turn_id = 20_101 + step;
+            req.now_ns = Some(10 + step);
+            req.user_text_partial = Some(format!("partial user {step}"));
+            req.selene_text_partial = Some(format!("partial selene {step}"));
+            runtime
+                .run_voice_turn(req.clone())
+                .expect("partial transcript turn must succeed");
+            if step % 2 == 0 {
+                req.user_text_partial = None;
+                req.selene_text_partial = None;
+                req.user_text_final = Some(format!("final user {step}"));
+                req.selene_text_final = Some(format!("final selene {step}"));
+                runtime
+                    .run_voice_turn(req)
+                    .expect("final transcript turn must succeed");
+            }
+        }
+        let full = runtime.ui_chat_transcript_report(Some(777), None);
+        assert_eq!(full.status, "ok");
+        assert!(full.next_cursor.is_none());
+        assert!(full.messages.iter().any(|message| !message.finalized));
+        assert!(!full
+            .messages
+            .iter()
+            .any(|message| message.text.starts_with("partial") && message.text.ends_with(" 0")));
+
+        let mut filter = UiTranscriptFilter {
+            page_size: Some(3),
+            ..UiTranscriptFilter::default()
+        };
+        let mut paged = Vec::new();
+        for _ in 0..full.messages.len() {
+            let page = runtime.ui_chat_transcript_report(Some(778), Some(&filter));
+            assert_eq!(page.status, "ok");
+            assert!(page.messages.len() <= 3);
+            paged.extend(page.messages);
+            match page.next_cursor {
+                Some(cursor) => filter.cursor = Some(cursor),
+                None => break,
+            }
+        }
+        assert_eq!(paged, full.messages);
+
+        let selene_only = runtime.ui_chat_transcript_report(
+            Some(779),
+            Some(&UiTranscriptFilter {
+                correlation_id: Some(10_102),
+                role: Some("selene".to_string()),
+                ..UiTranscriptFilter::default()
+            }),
+        );
+        assert_eq!(selene_only.messages.len(), 1);
+        assert_eq!(selene_only.messages[0].text, "partial selene 1");
+
+        let bad_cursor = runtime.ui_chat_transcript_report(
+            Some(780),
+            Some(&UiTranscriptFilter {
+                cursor: Some("idx:4".to_string()),
+                ..UiTranscriptFilter::default()
+            }),
+        );
+        assert_eq!(bad_cursor.status, "error");
+        assert!(bad_cursor.messages.is_empty());
+    }
+
+    #[test]
+    fn at_adapter_14b_erase_user_transcript_hides_only_that_users_messages() {
+        let runtime = AdapterRuntime::default();
+        let mut erased = base_request();
+        erased.user_text_final = Some("my account number is 1234".to_string());
+        erased.selene_text_final = Some("Noted your account number.".to_string());
+        runtime
+            .run_voice_turn(erased.clone())
+            .expect("erased user's final turn must succeed");
+        let mut erased_partial = base_request();
+        erased_partial.correlation_id = 10_003;
+        erased_partial.turn_id = 20_003;
+        erased_partial.now_ns = Some(5);
+        erased_partial.user_text_partial = Some("my pin is".to_string());
+        runtime
+            .run_voice_turn(erased_partial)
+            .expect("erased user's partial turn must succeed");
+        let mut kept = base_request();
+        kept.correlation_id = 10_002;
+        kept.turn_id = 20_002;
+        kept.now_ns = Some(4);
+        kept.actor_user_id = "tenant_a:user_adapter_test_14b".to_string();
+        kept.device_id = Some("adapter_device_14b".to_string());
+        kept.user_text_final = Some("remind me about payroll".to_string());
+        runtime
+            .run_voice_turn(kept)
+            .expect("other user's turn must succeed");
+
+        let actor_user_id = UserId::new(erased.actor_user_id).expect("actor id must parse");
+        let report = runtime
+            .erase_user_transcript(&actor_user_id)
+            .expect("erase must succeed");
+        assert_eq!(report.ledger_rows_tombstoned, 2);
+        assert_eq!(report.partial_events_removed, 1);
+
+        let transcript = runtime.ui_chat_transcript_report(Some(600), None);
+        assert_eq!(transcript.status, "ok");
+        let texts = transcript
+            .messages
+            .iter()
+            .map(|message| message.text.as_str())
+            .collect::<Vec<_>>();
+        assert!(texts.contains(&"remind me about payroll"));
+        for erased_text in [
+            "my account number is 1234",
+            "Noted your account number.",
+            "my pin is",
+        ] {
+            assert!(!texts.contains(&erased_text));
+        }
+
+        let again = runtime
+            .erase_user_transcript(&actor_user_id)
+            .expect("repeat erase must succeed");
+        assert_eq!(again, EraseReport::default());
+    }
+
     #[test]
     fn at_adapter_15_report_query_clarify_then_remember_display_target() {
         let runtime = AdapterRuntime::default();
@@ -41449,6 +54746,117 @@ mod tests {
         assert!(remembered.requires_clarification.is_none());
     }
 
+    #[test]
+    fn at_adapter_15a_report_query_applies_configured_default_kind_and_scope() {
+        let runtime = AdapterRuntime {
+            default_report_kind: HealthReportKind::IssueStatus,
+            default_company_scope: HealthCompanyScope::CrossTenantTenantRows,
+            ..AdapterRuntime::default()
+        };
+        let mut omitted = base_report_query_request();
+        omitted.report_kind = None;
+        omitted.company_scope = None;
+        omitted.company_ids = Some(vec!["tenant_a".to_string()]);
+        let out = runtime.ui_health_report_query(omitted, Some(5_000_000_000));
+        assert_eq!(out.status, "ok");
+        let query = out
+            .normalized_query
+            .expect("normalized query must be returned");
+        assert!(query.starts_with("kind=IssueStatus;"));
+        assert!(query.contains("scope=CROSS_TENANT_TENANT_ROWS;"));
+
+        let explicit =
+            runtime.ui_health_report_query(base_report_query_request(), Some(5_000_000_001));
+        let query = explicit
+            .normalized_query
+            .expect("normalized query must be returned");
+        assert!(query.starts_with("kind=UnresolvedEscalated;"));
+        assert!(query.contains("scope=TENANT_ONLY;"));
+
+        assert_eq!(
+            report_kind_from_label(" issue_status "),
+            Some(HealthReportKind::IssueStatus)
+        );
+        assert_eq!(report_kind_from_label("WEEKLY"), None);
+        assert_eq!(company_scope_from_label("GLOBAL"), None);
+    }
+
+    #[test]
+    fn at_adapter_15b_report_query_widens_point_range_to_min_window() {
+        let now_ns = 100_000_000_000;
+        let runtime = AdapterRuntime {
+            health_range_grace: AdapterHealthRangeGrace {
+                min_window_ns: 60_000_000_000,
+                future_tolerance_ns: 30_000_000_000,
+            },
+            ..AdapterRuntime::default()
+        };
+        let mut point = base_report_query_request();
+        point.from_utc_ns = Some(now_ns);
+        point.to_utc_ns = Some(now_ns);
+        let out = runtime.ui_health_report_query(point.clone(), Some(now_ns));
+        assert_eq!(out.status, "ok");
+        let query = out
+            .normalized_query
+            .expect("normalized query must be returned");
+        assert!(query.contains(";from=40000000000;to=100000000000;"));
+
+        let strict = AdapterRuntime {
+            health_range_grace: AdapterHealthRangeGrace {
+                min_window_ns: 0,
+                future_tolerance_ns: 0,
+            },
+            ..AdapterRuntime::default()
+        };
+        let out = strict.ui_health_report_query(point, Some(now_ns));
+        let query = out
+            .normalized_query
+            .expect("normalized query must be returned");
+        assert!(query.contains(";from=100000000000;to=100000000000;"));
+    }
+
+    #[test]
+    fn at_adapter_15c_report_query_clamps_near_future_to_and_rejects_inverted_range() {
+        let now_ns = 100_000_000_000;
+        let runtime = AdapterRuntime {
+            health_range_grace: AdapterHealthRangeGrace {
+                min_window_ns: 60_000_000_000,
+                future_tolerance_ns: 30_000_000_000,
+            },
+            ..AdapterRuntime::default()
+        };
+        let mut near_future = base_report_query_request();
+        near_future.from_utc_ns = Some(1);
+        near_future.to_utc_ns = Some(now_ns + 10_000_000_000);
+        let out = runtime.ui_health_report_query(near_future, Some(now_ns));
+        assert_eq!(out.status, "ok");
+        let query = out
+            .normalized_query
+            .expect("normalized query must be returned");
+        assert!(query.contains(";from=1;to=100000000000;"));
+
+        let mut far_future = base_report_query_request();
+        far_future.from_utc_ns = Some(1);
+        far_future.to_utc_ns = Some(now_ns + 600_000_000_000);
+        let out = runtime.ui_health_report_query(far_future, Some(now_ns));
+        let query = out
+            .normalized_query
+            .expect("normalized query must be returned");
+        assert!(query.contains(";to=700000000000;"));
+
+        let mut inverted = base_report_query_request();
+        inverted.from_utc_ns = Some(now_ns);
+        inverted.to_utc_ns = Some(now_ns - 1);
+        let out = runtime.ui_health_report_query(inverted, Some(now_ns));
+        assert_eq!(out.status, "error");
+        assert_eq!(
+            out.reason_code,
+            health_reason_codes::PH1_HEALTH_DATE_RANGE_INVALID
+                .0
+                .to_string()
+        );
+    }
+
     #[test]
     fn at_adapter_16_report_query_context_supports_follow_up_patch() {
         let runtime = AdapterRuntime::default();
@@ -41471,6 +54879,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn at_adapter_16b_report_query_without_correlation_id_derives_stable_id() {
+        let mut first = base_report_query_request();
+        first.correlation_id = None;
+        let second = first.clone();
+        let derived = ui_health_report_correlation_id(&first);
+        assert!(derived > 0);
+        assert_eq!(derived, ui_health_report_correlation_id(&second));
+
+        let mut other_tenant = first.clone();
+        other_tenant.tenant_id = Some("tenant_b".to_string());
+        assert_ne!(derived, ui_health_report_correlation_id(&other_tenant));
+        assert_eq!(
+            ui_health_report_correlation_id(&base_report_query_request()),
+            10_001
+        );
+
+        let runtime = AdapterRuntime::default();
+        let out = runtime.ui_health_report_query(first, Some(5_000_000_200));
+        assert_eq!(out.status, "ok");
+        let replay = runtime.ui_health_report_query(second, Some(5_000_000_900));
+        assert_eq!(replay.status, "ok");
+    }
+
     fn sample_health_issues_for_filters() -> Vec<UiHealthIssueRow> {
         vec![
             UiHealthIssueRow {
@@ -41602,7 +55034,8 @@ mod tests {
         };
         let err = runtime
             .ui_health_detail_report_filtered("SYNC", filter, Some(100))
-            .expect_err("invalid date range must fail");
+            .expect_err("invalid date range must fail")
+            .to_string();
         assert!(err.contains("invalid health detail date range"));
     }
 
@@ -41654,7 +55087,19 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(
             order,
-            vec!["VOICE", "WAKE", "SYNC", "STT", "TTS", "DELIVERY", "BUILDER", "MEMORY"]
+            vec![
+                "VOICE",
+                "WAKE",
+                "SYNC",
+                "SYNC_REPLAY_DUE",
+                "SYNC_WORKER_LIVENESS",
+                "STT",
+                "TTS",
+                "DELIVERY",
+                "BUILDER",
+                "PROVIDER_LATENCY",
+                "MEMORY"
+            ]
         );
         assert!(checks
             .checks
@@ -41690,7 +55135,7 @@ mod tests {
             .run_voice_turn(text_turn)
             .expect("text turn should succeed");
 
-        let transcript = runtime.ui_chat_transcript_report(Some(20_003));
+        let transcript = runtime.ui_chat_transcript_report(Some(20_003), None);
         assert_eq!(transcript.status, "ok");
         let user_final_count = transcript
             .messages
@@ -41710,6 +55155,228 @@ mod tests {
         assert!(selene_final_count >= 2);
     }
 
+    #[test]
+    fn at_adapter_22b_system_and_tool_messages_are_attributed_in_transcript() {
+        let runtime = AdapterRuntime::default();
+        let mut turn = base_request();
+        turn.turn_id = 30_101;
+        turn.now_ns = Some(20_101);
+        turn.trigger = "EXPLICIT".to_string();
+        turn.user_text_final = Some("what is on my calendar today".to_string());
+        runtime
+            .run_voice_turn(turn.clone())
+            .expect("turn should succeed");
+
+        let system_message = |kind, text: &str| TranscriptSystemMessageInput {
+            correlation_id: turn.correlation_id,
+            turn_id: turn.turn_id,
+            session_id: None,
+            actor_user_id: turn.actor_user_id.clone(),
+            device_id: None,
+            kind,
+            text: text.to_string(),
+            now_ns: Some(20_102),
+        };
+        runtime
+            .record_transcript_system_message(system_message(
+                TranscriptSystemMessageKind::SystemNotice,
+                "Calendar sync resumed.",
+            ))
+            .expect("system notice should record");
+        runtime
+            .record_transcript_system_message(system_message(
+                TranscriptSystemMessageKind::ToolResult,
+                "calendar.list returned 2 events",
+            ))
+            .expect("tool result should record");
+
+        let transcript = runtime.ui_chat_transcript_report(Some(20_103), None);
+        assert_eq!(transcript.status, "ok");
+        assert!(transcript.messages.iter().any(|message| {
+            message.role == "SYSTEM"
+                && message.source == "SYSTEM.NOTICE"
+                && message.finalized
+                && message.text == "Calendar sync resumed."
+        }));
+        assert!(transcript.messages.iter().any(|message| {
+            message.role == "SYSTEM"
+                && message.source == "TOOL.RESULT"
+                && message.text == "calendar.list returned 2 events"
+        }));
+        assert!(!transcript
+            .messages
+            .iter()
+            .any(|message| message.role == "USER" && message.text.starts_with("calendar.list")));
+    }
+
+    #[test]
+    fn at_adapter_22b1_system_messages_survive_a_restart() {
+        let journal_path = temp_persistence_journal_path("system_message_restart");
+        let open_runtime = || {
+            AdapterRuntime::new_with_persistence(
+                AppServerIngressRuntime::default(),
+                Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+                journal_path.clone(),
+                false,
+            )
+            .expect("runtime with persistence must construct")
+        };
+        let mut turn = base_request();
+        turn.turn_id = 30_111;
+        turn.now_ns = Some(20_111);
+        turn.trigger = "EXPLICIT".to_string();
+        turn.user_text_final = Some("what is on my calendar today".to_string());
+        let runtime = open_runtime();
+        runtime
+            .run_voice_turn(turn.clone())
+            .expect("turn should succeed");
+        runtime
+            .record_transcript_system_message(TranscriptSystemMessageInput {
+                correlation_id: turn.correlation_id,
+                turn_id: turn.turn_id,
+                session_id: None,
+                actor_user_id: turn.actor_user_id.clone(),
+                device_id: None,
+                kind: TranscriptSystemMessageKind::ToolResult,
+                text: "calendar.list returned 2 events".to_string(),
+                now_ns: Some(20_112),
+            })
+            .expect("tool result should record");
+        drop(runtime);
+
+        let reopened = open_runtime();
+        let transcript = reopened.ui_chat_transcript_report(Some(20_113), None);
+        assert_eq!(transcript.status, "ok");
+        assert!(transcript.messages.iter().any(|message| {
+            message.role == "SYSTEM"
+                && message.source == "TOOL.RESULT"
+                && message.text == "calendar.list returned 2 events"
+        }));
+        assert!(transcript.messages.iter().any(
+            |message| message.role == "USER" && message.text == "what is on my calendar today"
+        ));
+
+        cleanup_persistence_files_for_test(&journal_path);
+    }
+
+    #[test]
+    fn at_adapter_22c_multi_part_user_messages_in_one_turn_all_persist() {
+        let runtime = AdapterRuntime::default();
+        let mut first_part = base_request();
+        first_part.turn_id = 30_102;
+        first_part.now_ns = Some(20_201);
+        first_part.trigger = "EXPLICIT".to_string();
+        first_part.user_text_final = Some("book a table for two".to_string());
+        runtime
+            .run_voice_turn(first_part.clone())
+            .expect("first part should succeed");
+        let mut second_part = first_part.clone();
+        second_part.now_ns = Some(20_202);
+        second_part.user_text_part_seq = Some(1);
+        second_part.user_text_final = Some("at seven tonight".to_string());
+        runtime
+            .run_voice_turn(second_part)
+            .expect("second part should succeed");
+
+        let store = runtime.store.lock().expect("store lock should succeed");
+        let user_texts = store
+            .conversation_ledger()
+            .iter()
+            .filter(|row| {
+                row.turn_id == TurnId(first_part.turn_id) && row.role == ConversationRole::User
+            })
+            .map(|row| row.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(user_texts, vec!["book a table for two", "at seven tonight"]);
+    }
+
+    #[test]
+    fn at_adapter_22d_final_diverging_from_low_latency_commit_emits_correction() {
+        let runtime = AdapterRuntime::default();
+        let mut early = base_request();
+        early.turn_id = 30_103;
+        early.now_ns = Some(20_301);
+        early.trigger = "EXPLICIT".to_string();
+        early.user_text_final = Some("send the report to ana".to_string());
+        runtime
+            .run_voice_turn(early.clone())
+            .expect("low-latency turn should succeed");
+        let correlation_id = CorrelationId(u128::from(early.correlation_id));
+        let turn_id = TurnId(early.turn_id);
+        {
+            let mut store = runtime.store.lock().expect("store lock should succeed");
+            store
+                .append_outcome_utilization_ledger_row(OutcomeUtilizationLedgerRowInput {
+                    created_at: MonotonicTimeNs(20_301),
+                    correlation_id,
+                    turn_id,
+                    engine_id: "PH1.C".to_string(),
+                    outcome_type: "PH1C_LIVE_TRANSCRIPT_OK_LOW_LATENCY".to_string(),
+                    action_class: OsOutcomeActionClass::AuditOnly,
+                    consumed_by: "PH1.C.SUPERIORITY".to_string(),
+                    latency_cost_ms: 0,
+                    decision_delta: true,
+                    reason_code: ReasonCodeId(0x4300_5101),
+                    idempotency_key: Some("at_adapter_22d_low_latency".to_string()),
+                    telemetry_tags: BTreeMap::new(),
+                })
+                .expect("low-latency outcome row should append");
+        }
+
+        let mut same_words = early.clone();
+        same_words.now_ns = Some(20_302);
+        same_words.user_text_final = Some("Send the report to Ana.".to_string());
+        runtime
+            .run_voice_turn(same_words)
+            .expect("punctuation-only final should succeed");
+        let mut corrected = early.clone();
+        corrected.now_ns = Some(20_303);
+        corrected.user_text_final = Some("send the report to anna".to_string());
+        runtime
+            .run_voice_turn(corrected)
+            .expect("corrected final should succeed");
+
+        let store = runtime.store.lock().expect("store lock should succeed");
+        let user_rows = store
+            .conversation_ledger()
+            .iter()
+            .filter(|row| row.turn_id == turn_id && row.role == ConversationRole::User)
+            .collect::<Vec<_>>();
+        assert_eq!(user_rows.len(), 2);
+        assert_eq!(user_rows[0].text, "send the report to ana");
+        assert_eq!(user_rows[1].text, "send the report to anna");
+        let corrections = store
+            .outcome_utilization_ledger_rows()
+            .iter()
+            .filter(|row| {
+                row.turn_id == turn_id && row.outcome_type == "PH1C_LIVE_TRANSCRIPT_CORRECTED"
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(corrections.len(), 1);
+        assert_eq!(
+            corrections[0]
+                .telemetry_tags
+                .get("original_conversation_turn_id"),
+            Some(&user_rows[0].conversation_turn_id.0.to_string())
+        );
+        assert_eq!(
+            corrections[0]
+                .telemetry_tags
+                .get("corrected_conversation_turn_id"),
+            Some(&user_rows[1].conversation_turn_id.0.to_string())
+        );
+        drop(store);
+
+        let transcript = runtime.ui_chat_transcript_report(Some(20_304), None);
+        let user_messages = transcript
+            .messages
+            .iter()
+            .filter(|message| message.role == "USER")
+            .map(|message| message.text.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(user_messages, vec!["send the report to anna"]);
+    }
+
     #[test]
     fn at_adapter_33_ph1c_live_bootstrap_gold_capture_and_telemetry_are_always_on() {
         let runtime = AdapterRuntime::default();
@@ -41757,6 +55424,268 @@ mod tests {
         assert!(telemetry_rows >= 1);
     }
 
+    #[test]
+    fn at_adapter_33b_telemetry_tags_appear_in_csv_and_store_rows() {
+        static ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+        let _guard = ENV_LOCK
+            .get_or_init(|| Mutex::new(()))
+            .lock()
+            .expect("env lock poisoned");
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock must be >= unix epoch")
+            .as_nanos();
+        let ph1c_csv = std::env::temp_dir().join(format!("selene_adapter_ph1c_tags_{seed}.csv"));
+        let ph1k_csv = std::env::temp_dir().join(format!("selene_adapter_ph1k_tags_{seed}.csv"));
+        let _ph1c_path = ScopedEnvVar::set(
+            "SELENE_PH1C_LIVE_TELEMETRY_PATH",
+            ph1c_csv.to_str().expect("temp path must be utf-8"),
+        );
+        let _ph1k_path = ScopedEnvVar::set(
+            "SELENE_PH1K_LIVE_EVAL_PATH",
+            ph1k_csv.to_str().expect("temp path must be utf-8"),
+        );
+        let _env = ScopedEnvVar::set("SELENE_TELEMETRY_ENV", "staging");
+        let _region = ScopedEnvVar::set("SELENE_TELEMETRY_REGION", "eu west 1");
+        let _deployment = ScopedEnvVar::set("SELENE_TELEMETRY_DEPLOYMENT_ID", "deploy-42");
+
+        let runtime = AdapterRuntime::default();
+        let mut req = base_request();
+        mark_request_as_live_desktop_capture_for_h417_tests(&mut req);
+        req.turn_id = 20_335;
+        req.now_ns = Some(33_005);
+        req.user_text_partial = None;
+        req.user_text_final = None;
+        req.selene_text_partial = None;
+        req.selene_text_final = None;
+        runtime
+            .run_voice_turn(req)
+            .expect("live voice turn should succeed");
+
+        let expected_suffix = ",staging,eu_west_1,deploy-42";
+        for csv_path in [&ph1c_csv, &ph1k_csv] {
+            let csv = std::fs::read_to_string(csv_path).expect("telemetry csv must exist");
+            let mut lines = csv.lines();
+            let header = lines.next().expect("telemetry csv must have a header");
+            assert!(header.ends_with(",env,region,deployment_id"));
+            let header_columns = header.split(',').count();
+            let rows = lines.collect::<Vec<_>>();
+            assert!(!rows.is_empty());
+            assert!(rows.iter().any(|row| row.ends_with(expected_suffix)));
+            assert!(rows
+                .iter()
+                .all(|row| row.split(',').count() == header_columns));
+            let _ = std::fs::remove_file(csv_path);
+        }
+
+        let store = runtime.store.lock().expect("store lock must not poison");
+        let row = store
+            .outcome_utilization_ledger_rows()
+            .iter()
+            .find(|row| row.turn_id == TurnId(20_335) && row.engine_id == "PH1.C")
+            .expect("PH1.C telemetry row must be stored");
+        assert_eq!(
+            row.telemetry_tags.get("env").map(String::as_str),
+            Some("staging")
+        );
+        assert_eq!(
+            row.telemetry_tags.get("region").map(String::as_str),
+            Some("eu_west_1")
+        );
+        assert_eq!(
+            row.telemetry_tags.get("deployment_id").map(String::as_str),
+            Some("deploy-42")
+        );
+    }
+
+    #[test]
+    fn at_adapter_33b2_telemetry_tags_come_from_config_and_match_the_csv_header() {
+        let tags = AdapterTelemetryTags {
+            env: "prod".to_string(),
+            region: "us_east_1".to_string(),
+            deployment_id: "deploy-7".to_string(),
+        };
+        let sink = Arc::new(RecordingTelemetrySink::default());
+        let runtime = AdapterRuntime {
+            telemetry_sink: sink.clone(),
+            telemetry_tags: tags.clone(),
+            ..AdapterRuntime::default()
+        };
+        let mut req = base_request();
+        mark_request_as_live_desktop_capture_for_h417_tests(&mut req);
+        req.turn_id = 20_337;
+        req.now_ns = Some(33_007);
+        req.user_text_partial = None;
+        req.user_text_final = None;
+        req.selene_text_partial = None;
+        req.selene_text_final = None;
+        runtime
+            .run_voice_turn(req)
+            .expect("live voice turn should succeed");
+        let events = sink.events.lock().expect("sink lock must not poison");
+        assert!(events.iter().any(|event| matches!(
+            event,
+            TelemetryEvent::Ph1cLive(ph1c)
+                if ph1c.turn_id == TurnId(20_337) && ph1c.telemetry_tags == tags.store_tags()
+        )));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            TelemetryEvent::Ph1kLiveEvalSnapshot { turn_id, telemetry_tags, .. }
+                if *turn_id == TurnId(20_337) && *telemetry_tags == tags.store_tags()
+        )));
+
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("clock must be >= unix epoch")
+            .as_nanos();
+        let legacy_csv =
+            std::env::temp_dir().join(format!("selene_adapter_legacy_tags_{seed}.csv"));
+        let tagged_csv =
+            std::env::temp_dir().join(format!("selene_adapter_tagged_tags_{seed}.csv"));
+        std::fs::write(
+            &legacy_csv,
+            "captured_at_ns,correlation_id,turn_id\n1,2,3\n",
+        )
+        .expect("legacy csv must be writable");
+        std::fs::write(
+            &tagged_csv,
+            "captured_at_ns,correlation_id,turn_id,env,region,deployment_id\n",
+        )
+        .expect("tagged csv must be writable");
+        let missing_csv =
+            std::env::temp_dir().join(format!("selene_adapter_missing_tags_{seed}.csv"));
+        assert_eq!(tags.csv_columns(&legacy_csv), "");
+        assert_eq!(tags.csv_columns(&tagged_csv), ",prod,us_east_1,deploy-7");
+        assert_eq!(tags.csv_columns(&missing_csv), ",prod,us_east_1,deploy-7");
+        let _ = std::fs::remove_file(legacy_csv);
+        let _ = std::fs::remove_file(tagged_csv);
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct CountingEvalCsvSink {
+        flushed_batches: Arc<Mutex<Vec<usize>>>,
+    }
+
+    impl EvalCsvSink for CountingEvalCsvSink {
+        fn append_rows(
+            &mut self,
+            _path: &Path,
+            _header: &str,
+            rows: &[String],
+        ) -> Result<(), String> {
+            self.flushed_batches
+                .lock()
+                .expect("sink lock must not poison")
+                .push(rows.len());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn at_adapter_33c_eval_csv_flush_policy_batches_writes() {
+        let push_rows = |policy: AdapterEvalCsvFlushPolicy| {
+            let sink = CountingEvalCsvSink::default();
+            let runtime = AdapterRuntime {
+                telemetry_sink: Arc::new(CsvTelemetrySink::with_eval_csv_sink(
+                    policy,
+                    Box::new(sink.clone()),
+                )),
+                ..AdapterRuntime::default()
+            };
+            for idx in 0..7 {
+                runtime
+                    .telemetry_sink
+                    .record(TelemetryEvent::Ph1kLiveEvalSnapshot {
+                        correlation_id: CorrelationId(33_300),
+                        turn_id: TurnId(idx + 1),
+                        csv_row: format!("row_{idx}\n"),
+                        telemetry_tags: BTreeMap::new(),
+                    });
+            }
+            let before_shutdown = sink
+                .flushed_batches
+                .lock()
+                .expect("sink lock must not poison")
+                .clone();
+            runtime.shutdown().expect("shutdown flush must succeed");
+            let after_shutdown = sink
+                .flushed_batches
+                .lock()
+                .expect("sink lock must not poison")
+                .clone();
+            (before_shutdown, after_shutdown)
+        };
+
+        let (per_row, _) = push_rows(AdapterEvalCsvFlushPolicy::PerRow);
+        assert_eq!(per_row, vec![1; 7]);
+
+        let (every_three, after_shutdown) = push_rows(AdapterEvalCsvFlushPolicy::EveryRows(3));
+        assert_eq!(every_three, vec![3, 3]);
+        assert_eq!(after_shutdown, vec![3, 3, 1]);
+
+        let (on_shutdown, after_shutdown) = push_rows(AdapterEvalCsvFlushPolicy::OnShutdown);
+        assert!(on_shutdown.is_empty());
+        assert_eq!(after_shutdown, vec![7]);
+
+        assert_eq!(
+            AdapterEvalCsvFlushPolicy::parse(" Every:50 "),
+            Some(AdapterEvalCsvFlushPolicy::EveryRows(50))
+        );
+        assert_eq!(AdapterEvalCsvFlushPolicy::parse("every:0"), None);
+    }
+
+    #[derive(Debug, Default)]
+    struct RecordingTelemetrySink {
+        events: Mutex<Vec<TelemetryEvent>>,
+    }
+
+    impl TelemetrySink for RecordingTelemetrySink {
+        fn record(&self, event: TelemetryEvent) {
+            self.events
+                .lock()
+                .expect("sink lock must not poison")
+                .push(event);
+        }
+    }
+
+    #[test]
+    fn at_adapter_33d_live_turn_telemetry_goes_to_configured_sink() {
+        let sink = Arc::new(RecordingTelemetrySink::default());
+        let runtime = AdapterRuntime {
+            telemetry_sink: sink.clone(),
+            ..AdapterRuntime::default()
+        };
+        let mut req = base_request();
+        mark_request_as_live_desktop_capture_for_h417_tests(&mut req);
+        req.turn_id = 20_336;
+        req.now_ns = Some(33_006);
+        req.user_text_partial = None;
+        req.user_text_final = None;
+        req.selene_text_partial = None;
+        req.selene_text_final = None;
+        runtime
+            .run_voice_turn(req)
+            .expect("live voice turn should succeed");
+
+        let events = sink.events.lock().expect("sink lock must not poison");
+        let ph1c = events
+            .iter()
+            .find_map(|event| match event {
+                TelemetryEvent::Ph1cLive(ph1c) if ph1c.turn_id == TurnId(20_336) => Some(ph1c),
+                _ => None,
+            })
+            .expect("PH1.C live telemetry must reach the sink");
+        assert!(ph1c.outcome_type.starts_with("PH1C_LIVE_TRANSCRIPT_"));
+        assert!(events.iter().any(|event| matches!(
+            event,
+            TelemetryEvent::Ph1kLiveEvalSnapshot { turn_id, csv_row, .. }
+                if *turn_id == TurnId(20_336) && csv_row.ends_with('\n')
+        )));
+
+        NullTelemetrySink.record(TelemetryEvent::Ph1cLive(ph1c.clone()));
+        assert!(NullTelemetrySink.flush().is_ok());
+    }
+
     #[test]
     fn at_adapter_34_ph1d_runtime_commit_writes_full_payload_contract() {
         let runtime = AdapterRuntime::default();
@@ -41802,6 +55731,87 @@ mod tests {
         }
     }
 
+    #[test]
+    fn at_adapter_34b_ph1d_analysis_outcome_renders_spoken_text() {
+        let analysis = Ph1dAnalysis::v1(
+            "route:analysis_required".to_string(),
+            ph1d_reason_codes::D_PROVIDER_OK,
+        )
+        .expect("analysis must build");
+
+        let spoken = response_text_with_ph1d_analysis(None, &analysis, None)
+            .expect("analysis outcome must produce response text");
+        assert_eq!(
+            spoken,
+            "I don't have an answer for that yet. Could you ask it another way?"
+        );
+        assert!(!spoken.contains("route:"));
+        assert!(!spoken.contains("moment"));
+        assert_eq!(
+            response_text_with_ph1d_analysis(Some("  ".to_string()), &analysis, None),
+            Some(spoken)
+        );
+        assert_eq!(
+            response_text_with_ph1d_analysis(
+                Some("Here is the answer.".to_string()),
+                &analysis,
+                None
+            )
+            .as_deref(),
+            Some("Here is the answer.")
+        );
+
+        let settled = Ph1dAnalysis::v1(
+            "user wants a comparison".to_string(),
+            ph1d_reason_codes::D_PROVIDER_OK,
+        )
+        .expect("analysis must build");
+        let spoken = ph1d_analysis_response_text(&settled, None);
+        assert!(!spoken.trim().is_empty());
+        assert!(!spoken.contains("comparison"));
+    }
+
+    #[test]
+    fn at_adapter_34c_live_voice_turn_resolves_ph1d_to_chat_and_speaks_the_answer() {
+        // run_voice_turn asks PH1.OS for a chat move, so PH1.D resolves a live turn to chat. The
+        // analysis line is only for turns whose OS decision is explain or wait.
+        assert!(ph1d_model_json_for_next_move(OsNextMove::Explain)
+            .contains("\"short_analysis\":\"route:analysis_required\""));
+        let runtime = AdapterRuntime::default();
+        let mut req = base_request();
+        req.correlation_id = 10_341;
+        req.turn_id = 20_341;
+        req.now_ns = Some(34_100);
+        req.user_text_final = Some("Tell me something encouraging".to_string());
+
+        let out = runtime
+            .run_voice_turn(req)
+            .expect("live voice turn must succeed");
+        assert_eq!(out.status, "ok");
+        assert!(!out.response_text.trim().is_empty());
+        let route_analysis = Ph1dAnalysis::v1(
+            "route:analysis_required".to_string(),
+            ph1d_reason_codes::D_PROVIDER_OK,
+        )
+        .expect("analysis must build");
+        assert_ne!(
+            out.response_text,
+            ph1d_analysis_response_text(&route_analysis, None)
+        );
+
+        let store = runtime.store.lock().expect("store lock should succeed");
+        let decision_key = selene_kernel_contracts::ph1j::PayloadKey::new("decision")
+            .expect("decision key is valid");
+        let decisions = store
+            .ph1d_audit_rows(CorrelationId(10_341))
+            .into_iter()
+            .filter_map(|row| row.payload_min.entries.get(&decision_key))
+            .map(|value| value.as_str().to_string())
+            .collect::<Vec<_>>();
+        assert!(decisions.iter().any(|decision| decision == "CHAT"));
+        assert!(!decisions.iter().any(|decision| decision == "ANALYSIS"));
+    }
+
     #[test]
     fn at_adapter_35_ph1d_provider_outcome_capture_emits_feedback_and_learn_rows() {
         let runtime = AdapterRuntime::default();
@@ -41874,7 +55884,14 @@ mod tests {
 
     #[test]
     fn at_health_11_follow_up_report_patch_reuses_context() {
-        let runtime = AdapterRuntime::default();
+        // The follow-up runs at a later `now`; clamping `to` would move the range and the context.
+        let runtime = AdapterRuntime {
+            health_range_grace: AdapterHealthRangeGrace {
+                future_tolerance_ns: 0,
+                ..AdapterHealthRangeGrace::default()
+            },
+            ..AdapterRuntime::default()
+        };
         let mut first_req = base_report_query_request();
         first_req.from_utc_ns = Some(8_000_000_000);
         first_req.to_utc_ns = Some(9_000_000_200);
@@ -41903,6 +55920,133 @@ mod tests {
         assert_ne!(second.rows, first_rows);
     }
 
+    #[test]
+    fn at_health_11c_report_changes_diff_against_last_seen_revision() {
+        let runtime = AdapterRuntime::default();
+        let mut req = base_report_query_request();
+        req.to_utc_ns = None;
+
+        let first = runtime
+            .health_report_changes(0, req.clone(), Some(4_000_000_000))
+            .expect("first health report changes must succeed");
+        assert!(!first.baseline_found);
+        assert_eq!(first.added_issue_ids, vec!["health_nominal".to_string()]);
+        assert!(first.resolved_issue_ids.is_empty());
+
+        {
+            let mut state = runtime
+                .health_report_snapshot_state
+                .lock()
+                .expect("snapshot lock must not be poisoned");
+            let rows = state
+                .rows_by_revision
+                .get_mut(&("tenant_a".to_string(), first.report_revision))
+                .expect("first snapshot must be retained");
+            let mut backlog = rows[0].clone();
+            backlog.issue_id = "sync_retry_backlog".to_string();
+            backlog.status = "OPEN".to_string();
+            *rows = vec![backlog];
+        }
+
+        let second = runtime
+            .health_report_changes(first.report_revision, req, Some(5_000_000_000))
+            .expect("second health report changes must succeed");
+        assert!(second.baseline_found);
+        assert_eq!(second.since_revision, first.report_revision);
+        assert!(second.report_revision > first.report_revision);
+        assert_eq!(second.added_issue_ids, vec!["health_nominal".to_string()]);
+        assert!(second.changed_issue_ids.is_empty());
+        assert_eq!(
+            second.resolved_issue_ids,
+            vec!["sync_retry_backlog".to_string()]
+        );
+    }
+
+    #[test]
+    fn at_health_11d_report_csv_export_escapes_free_text_and_skips_display_memory() {
+        let runtime = AdapterRuntime::default();
+        let mut req = base_report_query_request();
+        req.viewer_user_id = Some("viewer_csv".to_string());
+        req.page_size = Some(1);
+        let csv = runtime
+            .ui_health_report_export_csv(req.clone(), Some(4_000_000_000))
+            .expect("health report csv export must succeed");
+        let lines = csv.lines().collect::<Vec<_>>();
+        assert_eq!(lines[0], UI_HEALTH_REPORT_CSV_HEADER);
+        assert!(!runtime
+            .report_display_target_defaults
+            .lock()
+            .expect("display target lock must not be poisoned")
+            .contains_key("viewer_csv"));
+        req.page_size = Some(20);
+        let rows = runtime
+            .ui_health_report_query(req, Some(4_000_000_000))
+            .rows;
+        assert!(!rows.is_empty());
+        assert_eq!(lines.len(), rows.len() + 1);
+        for (line, row) in lines[1..].iter().zip(rows.iter()) {
+            assert_eq!(*line, ui_health_report_csv_row(row));
+        }
+
+        let row = UiHealthReportRow {
+            tenant_id: "tenant_a".to_string(),
+            issue_id: "sync_retry_backlog".to_string(),
+            owner_engine_id: "PH1.OS".to_string(),
+            severity: "WARN".to_string(),
+            status: "OPEN".to_string(),
+            latest_reason_code: "42".to_string(),
+            last_seen_at_ns: 7,
+            bcast_id: None,
+            ack_state: Some("PENDING".to_string()),
+            issue_fingerprint: None,
+            recurrence_observed: true,
+            impact_summary: Some("queue backed up, retries \"stuck\"".to_string()),
+            attempted_fix_actions: vec!["restart worker".to_string(), "drain".to_string()],
+            current_monitoring_evidence: Some("depth=12\nage=40s".to_string()),
+            unresolved_reason_exact: Some("awaiting \"provider\" ack, then replay".to_string()),
+        };
+        assert_eq!(
+            ui_health_report_csv_row(&row),
+            "tenant_a,sync_retry_backlog,PH1.OS,WARN,OPEN,42,7,,PENDING,,true,\
+             \"queue backed up, retries \"\"stuck\"\"\",restart worker;drain,\
+             \"depth=12\nage=40s\",\"awaiting \"\"provider\"\" ack, then replay\""
+        );
+    }
+
+    #[test]
+    fn at_health_11b_report_query_applies_configured_default_window() {
+        static ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+        let env_lock = ENV_LOCK.get_or_init(|| Mutex::new(()));
+        let _guard = env_lock.lock().expect("env lock poisoned");
+        let runtime = AdapterRuntime::default();
+        let mut req = base_report_query_request();
+        req.from_utc_ns = None;
+        req.to_utc_ns = None;
+
+        let scope = ScopedEnvVar::set("SELENE_ADAPTER_HEALTH_DEFAULT_WINDOW_NS", "1000000000");
+        let configured = runtime.ui_health_report_query(req.clone(), Some(9_000_000_000));
+        drop(scope);
+        assert_eq!(configured.status, "ok");
+        let normalized = configured
+            .normalized_query
+            .expect("normalized query must be present");
+        assert!(
+            normalized.contains("from=8000000000;to=9000000000;"),
+            "{normalized}"
+        );
+
+        let scope = ScopedEnvVar::set("SELENE_ADAPTER_HEALTH_DEFAULT_WINDOW_NS", "0");
+        let fallback = runtime.ui_health_report_query(req, Some(3_456_000_000_000_000));
+        drop(scope);
+        let normalized = fallback
+            .normalized_query
+            .expect("normalized query must be present");
+        assert!(
+            normalized.contains("from=864000000000000;to=3456000000000000;"),
+            "{normalized}"
+        );
+    }
+
     #[test]
     fn at_health_12_voice_wave_degraded_marker_is_wired() {
         assert!(app_ui_assets::APP_HTML.contains("voice-wave-state"));
@@ -41960,9 +56104,13 @@ mod tests {
 
     #[test]
     fn at_adapter_26_hui04_summary_strip_maps_runtime_summary_fields() {
-        let detail =
-            build_ui_health_detail_response(&synthetic_health_for_detail_tests(), "SYNC", 900)
-                .expect("detail build should succeed");
+        let detail = build_ui_health_detail_response(
+            &synthetic_health_for_detail_tests(),
+            "SYNC",
+            900,
+            &AdapterHealthIssueDeadlinePolicy::default(),
+        )
+        .expect("detail build should succeed");
         assert_eq!(detail.summary.open_issues, 3);
         assert_eq!(detail.summary.critical_open_count, 2);
         assert_eq!(detail.summary.escalated_24h_count, 1);
@@ -41976,9 +56124,13 @@ mod tests {
 
     #[test]
     fn at_adapter_27_hui05_primary_queue_table_columns_and_projection_locked() {
-        let detail =
-            build_ui_health_detail_response(&synthetic_health_for_detail_tests(), "SYNC", 901)
-                .expect("detail build should succeed");
+        let detail = build_ui_health_detail_response(
+            &synthetic_health_for_detail_tests(),
+            "SYNC",
+            901,
+            &AdapterHealthIssueDeadlinePolicy::default(),
+        )
+        .expect("detail build should succeed");
         assert!(detail.issues.iter().any(|issue| {
             !issue.severity.is_empty()
                 && !issue.issue_type.is_empty()
@@ -42002,9 +56154,13 @@ mod tests {
 
     #[test]
     fn at_adapter_28_hui06_detail_timeline_shows_reason_evidence_blocker_deadline() {
-        let detail =
-            build_ui_health_detail_response(&synthetic_health_for_detail_tests(), "SYNC", 902)
-                .expect("detail build should succeed");
+        let detail = build_ui_health_detail_response(
+            &synthetic_health_for_detail_tests(),
+            "SYNC",
+            902,
+            &AdapterHealthIssueDeadlinePolicy::default(),
+        )
+        .expect("detail build should succeed");
         assert!(detail.timeline.iter().any(|entry| {
             !entry.reason_code.is_empty()
                 && entry.evidence_ref.is_some()
@@ -42021,6 +56177,107 @@ mod tests {
         assert!(app_ui_assets::APP_JS.contains("Deadline:"));
     }
 
+    #[test]
+    fn at_adapter_28a_health_issue_deadline_follows_severity_policy() {
+        let deadlines = AdapterHealthIssueDeadlinePolicy {
+            critical_ns: 5 * 60 * 1_000_000_000,
+            warn_ns: 30 * 60 * 1_000_000_000,
+            info_ns: 60 * 60 * 1_000_000_000,
+        };
+        let health = synthetic_health_for_detail_tests();
+        let detail = build_ui_health_detail_response(&health, "SYNC", 903, &deadlines)
+            .expect("detail build should succeed");
+        let deadline_for = |issue_id: &str| {
+            detail
+                .issues
+                .iter()
+                .find(|issue| issue.issue_id == issue_id)
+                .and_then(|issue| issue.unresolved_deadline_at_ns)
+                .expect("issue must carry a deadline")
+        };
+        assert_eq!(
+            deadline_for("sync_dead_letter"),
+            500 + deadlines.critical_ns
+        );
+        assert_eq!(deadline_for("sync_retry_backlog"), 500 + deadlines.warn_ns);
+        assert!(deadline_for("sync_dead_letter") < deadline_for("sync_retry_backlog"));
+
+        let now_ns = 5_000_000_000;
+        let tenant = TenantId::new("tenant_a").expect("tenant id must be valid");
+        let events = synth_health_issue_events(&health, &tenant, now_ns, &deadlines, usize::MAX);
+        let event_deadline = |severity: HealthSeverity| {
+            events
+                .iter()
+                .find(|event| event.severity == severity)
+                .and_then(|event| event.unresolved_deadline_at)
+                .map(|deadline| deadline.0)
+                .expect("issue event must carry a deadline")
+        };
+        assert_eq!(
+            event_deadline(HealthSeverity::Critical),
+            now_ns + deadlines.critical_ns
+        );
+        assert_eq!(
+            event_deadline(HealthSeverity::Warn),
+            now_ns + deadlines.warn_ns
+        );
+
+        let default_deadlines = AdapterHealthIssueDeadlinePolicy::default();
+        assert_eq!(default_deadlines.critical_ns, 15 * 60 * 1_000_000_000);
+        assert_eq!(default_deadlines.warn_ns, default_deadlines.critical_ns);
+    }
+
+    #[test]
+    fn at_adapter_28b_synthesized_health_issues_are_capped_with_omission_marker() {
+        let health = synthetic_health_for_detail_tests();
+        let tenant = TenantId::new("tenant_a").expect("tenant id must be valid");
+        let deadlines = AdapterHealthIssueDeadlinePolicy::default();
+        let now_ns = 5_000_000_000;
+        let issue_ids = |events: &[HealthIssueEvent]| {
+            events
+                .iter()
+                .map(|event| event.issue_id.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let uncapped = synth_health_issue_events(&health, &tenant, now_ns, &deadlines, 3);
+        assert_eq!(
+            issue_ids(&uncapped),
+            vec!["sync_dead_letter", "sync_retry_backlog", "sync_replay_due"]
+        );
+
+        let capped = synth_health_issue_events(&health, &tenant, now_ns, &deadlines, 2);
+        assert_eq!(
+            issue_ids(&capped),
+            vec![
+                "sync_dead_letter",
+                "sync_replay_due",
+                "health_issues_omitted"
+            ]
+        );
+        let marker = capped.last().expect("omission marker must be present");
+        assert_eq!(marker.severity, HealthSeverity::Info);
+        assert_eq!(
+            marker.reason_code,
+            reason_codes::ADAPTER_HEALTH_ISSUES_OMITTED
+        );
+        assert_eq!(
+            marker.current_monitoring_evidence.as_deref(),
+            Some("omitted_issue_count=1 synth_issue_limit=2")
+        );
+        assert_eq!(
+            synth_health_issue_events(&health, &tenant, now_ns, &deadlines, 2),
+            capped,
+            "the capped issue set must be deterministic"
+        );
+
+        let single = synth_health_issue_events(&health, &tenant, now_ns, &deadlines, 1);
+        assert_eq!(
+            issue_ids(&single),
+            vec!["sync_dead_letter", "health_issues_omitted"]
+        );
+    }
+
     #[test]
     fn at_adapter_29_hui13_chat_shell_transcript_and_wave_layout_present() {
         let html = app_ui_assets::APP_HTML;
@@ -42117,6 +56374,635 @@ mod tests {
         );
     }
 
+    #[test]
+    fn at_adapter_38b_stt_mode_reports_which_stt_path_ran() {
+        // Android keeps audio-only explicit turns on the STT path; iOS treats
+        // them as activation-only handoffs.
+        let request = |turn_id: u64, user_text_final: Option<&str>| {
+            let mut req = base_request();
+            req.app_platform = "ANDROID".to_string();
+            req.correlation_id = turn_id;
+            req.turn_id = turn_id;
+            req.now_ns = Some(turn_id);
+            req.user_text_final = user_text_final.map(str::to_string);
+            req
+        };
+        let stt_mode_for = |runtime: AdapterRuntime, req: VoiceTurnAdapterRequest| {
+            runtime
+                .run_voice_turn(req)
+                .expect("voice turn should respond")
+                .stt_mode
+        };
+
+        assert_eq!(
+            stt_mode_for(
+                AdapterRuntime::default(),
+                request(38_201, Some("What time is it in Tokyo?"))
+            ),
+            "UPSTREAM"
+        );
+        assert_eq!(
+            stt_mode_for(
+                AdapterRuntime {
+                    ph1c_live_enabled: false,
+                    ..AdapterRuntime::default()
+                },
+                request(38_202, None)
+            ),
+            "SKIPPED_DISABLED"
+        );
+        assert_eq!(
+            stt_mode_for(
+                AdapterRuntime {
+                    ph1c_live_enabled: true,
+                    ph1d_live_adapter: None,
+                    ..AdapterRuntime::default()
+                },
+                request(38_203, None)
+            ),
+            "SKIPPED_NO_ADAPTER"
+        );
+        assert_eq!(
+            stt_mode_for(
+                AdapterRuntime {
+                    ph1c_live_enabled: true,
+                    ph1d_live_adapter: Some(slice3b_offline_adapter("gpt-5.5")),
+                    ..AdapterRuntime::default()
+                },
+                request(38_204, None)
+            ),
+            "LIVE"
+        );
+
+        let out = AdapterRuntime::default()
+            .run_voice_turn(h419_desktop_voice_request(
+                "stt-mode-quality-gate",
+                38_205,
+                "What time is it in Tokyo and",
+            ))
+            .expect("quality-gated voice turn should clarify");
+        assert_eq!(out.outcome, "CLARIFY");
+        assert_eq!(out.stt_mode, "SKIPPED_QUALITY_GATE");
+    }
+
+    #[test]
+    fn at_adapter_38c_snr_floor_skips_provider_and_guides_user() {
+        let runtime = AdapterRuntime {
+            ph1c_live_enabled: true,
+            ph1d_live_adapter: Some(slice3b_offline_adapter("gpt-5.5")),
+            stt_min_snr_db: Some(10.0),
+            ..AdapterRuntime::default()
+        };
+        let provider_calls = |runtime: &AdapterRuntime| {
+            runtime
+                .provider_latency_state
+                .lock()
+                .expect("provider latency lock")
+                .recent_latency_ms
+                .len()
+        };
+        let request = |turn_id: u64, snr_db_milli: i32| {
+            let mut req = base_request();
+            req.app_platform = "ANDROID".to_string();
+            req.correlation_id = turn_id;
+            req.turn_id = turn_id;
+            req.now_ns = Some(turn_id);
+            req.user_text_final = None;
+            if let Some(capture) = req.audio_capture_ref.as_mut() {
+                capture.snr_db_milli = Some(snr_db_milli);
+            }
+            req
+        };
+
+        let out = runtime
+            .run_voice_turn(request(38_301, 3_000))
+            .expect("sub-floor SNR turn should respond");
+        assert_eq!(out.outcome, "CLARIFY");
+        assert_eq!(out.stt_mode, "SKIPPED_LOW_SNR");
+        assert_eq!(
+            out.response_text,
+            "It's too noisy here\u{2014}try moving somewhere quieter."
+        );
+        assert_eq!(
+            out.reason_code,
+            reason_codes::ADAPTER_STT_SNR_BELOW_FLOOR.0.to_string()
+        );
+        assert_eq!(provider_calls(&runtime), 0);
+        {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            assert!(store
+                .ph1feedback_audit_rows(CorrelationId(38_301))
+                .iter()
+                .any(|row| row.reason_code == reason_codes::ADAPTER_STT_SNR_BELOW_FLOOR));
+        }
+
+        let out = runtime
+            .run_voice_turn(request(38_302, 22_000))
+            .expect("clean SNR turn should respond");
+        assert_eq!(out.stt_mode, "LIVE");
+    }
+
+    #[test]
+    fn at_adapter_38c2_stt_reject_reason_reaches_next_ph1x_turn_when_enabled() {
+        let runtime = AdapterRuntime {
+            ph1c_live_enabled: true,
+            ph1d_live_adapter: Some(slice3b_offline_adapter("gpt-5.5")),
+            stt_min_snr_db: Some(10.0),
+            ph1x_last_failure_enabled: true,
+            ..AdapterRuntime::default()
+        };
+        let request = |turn_id: u64, snr_db_milli: i32| {
+            let mut req = base_request();
+            req.app_platform = "ANDROID".to_string();
+            req.correlation_id = turn_id;
+            req.turn_id = turn_id;
+            req.now_ns = Some(turn_id);
+            req.user_text_final = None;
+            if let Some(capture) = req.audio_capture_ref.as_mut() {
+                capture.snr_db_milli = Some(snr_db_milli);
+            }
+            req
+        };
+
+        let out = runtime
+            .run_voice_turn(request(38_311, 3_000))
+            .expect("sub-floor SNR turn should respond");
+        assert_eq!(out.stt_mode, "SKIPPED_LOW_SNR");
+        assert!(runtime.ingress.debug_last_agent_input_packet().is_none());
+
+        runtime
+            .run_voice_turn(request(38_312, 22_000))
+            .expect("clean SNR turn should respond");
+        let packet = runtime
+            .ingress
+            .debug_last_agent_input_packet()
+            .expect("clean turn should reach PH1.X");
+        assert_eq!(
+            packet.last_failure_reason_code,
+            Some(reason_codes::ADAPTER_STT_SNR_BELOW_FLOOR)
+        );
+
+        runtime
+            .run_voice_turn(request(38_313, 22_000))
+            .expect("second clean turn should respond");
+        let packet = runtime
+            .ingress
+            .debug_last_agent_input_packet()
+            .expect("second clean turn should reach PH1.X");
+        assert_eq!(packet.last_failure_reason_code, None);
+
+        let default_runtime = AdapterRuntime {
+            ph1c_live_enabled: true,
+            ph1d_live_adapter: Some(slice3b_offline_adapter("gpt-5.5")),
+            stt_min_snr_db: Some(10.0),
+            ..AdapterRuntime::default()
+        };
+        default_runtime
+            .run_voice_turn(request(38_321, 3_000))
+            .expect("sub-floor SNR turn should respond");
+        default_runtime
+            .run_voice_turn(request(38_322, 22_000))
+            .expect("clean SNR turn should respond");
+        let packet = default_runtime
+            .ingress
+            .debug_last_agent_input_packet()
+            .expect("clean turn should reach PH1.X");
+        assert_eq!(packet.last_failure_reason_code, None);
+    }
+
+    #[test]
+    fn at_adapter_38d_provider_circuit_opens_and_short_circuits_live_turns() {
+        let runtime = AdapterRuntime {
+            ph1c_live_enabled: true,
+            ph1d_live_adapter: Some(slice3b_offline_adapter("gpt-5.5")),
+            provider_circuit_breaker_policy: AdapterProviderCircuitBreakerPolicy {
+                failure_threshold: 2,
+                cooldown_ns: 300_000_000,
+            },
+            ..AdapterRuntime::default()
+        };
+        let request = |turn_id: u64, now_ns: u64| {
+            let mut req = base_request();
+            req.app_platform = "ANDROID".to_string();
+            req.correlation_id = turn_id;
+            req.turn_id = turn_id;
+            req.now_ns = Some(now_ns);
+            req.user_text_final = None;
+            req
+        };
+
+        let out = runtime
+            .run_voice_turn(request(38_401, 1_000_000_000))
+            .expect("failing provider turn should respond");
+        assert_eq!(out.stt_mode, "LIVE");
+        let health = runtime.health_report(None).expect("health report");
+        assert_eq!(health.provider.circuit_state, "OPEN");
+        assert_eq!(health.provider.trips_total, 1);
+        assert_eq!(health.provider.failure_threshold, 2);
+        assert_eq!(health.provider.cooldown_ms, 300);
+
+        // The cooldown runs on the runtime clock, so a request stamped an hour ahead skips nothing.
+        let out = runtime
+            .run_voice_turn(request(38_402, 3_601_000_000_000))
+            .expect("circuit-open turn should respond");
+        assert_eq!(out.stt_mode, "SKIPPED_CIRCUIT_OPEN");
+        {
+            let store = runtime.store.lock().expect("store lock should succeed");
+            assert!(store
+                .ph1feedback_audit_rows(CorrelationId(38_402))
+                .iter()
+                .any(|row| row.reason_code == ph1c_reason_codes::STT_FAIL_PROVIDER_CIRCUIT_OPEN));
+        }
+        let short_circuited = runtime
+            .health_report(None)
+            .expect("health report")
+            .provider
+            .short_circuited_total;
+        assert_eq!(short_circuited, 1);
+
+        std::thread::sleep(std::time::Duration::from_millis(350));
+        let health = runtime.health_report(None).expect("health report");
+        assert_eq!(health.provider.circuit_state, "HALF_OPEN");
+        let out = runtime
+            .run_voice_turn(request(38_403, 3_602_000_000_000))
+            .expect("half-open probe turn should respond");
+        assert_eq!(out.stt_mode, "LIVE");
+        let health = runtime.health_report(None).expect("health report");
+        assert_eq!(health.provider.circuit_state, "OPEN");
+        assert_eq!(health.provider.trips_total, 2);
+    }
+
+    #[test]
+    fn at_adapter_38e_provider_circuit_recovers_after_successful_probe() {
+        struct ScriptedAdapter {
+            response: Ph1dProviderCallResponse,
+            succeed: std::cell::Cell<bool>,
+            calls: std::cell::Cell<u32>,
+        }
+        impl Ph1dProviderAdapter for ScriptedAdapter {
+            fn execute(
+                &self,
+                _req: &Ph1dProviderCallRequest,
+            ) -> Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError> {
+                self.calls.set(self.calls.get() + 1);
+                if self.succeed.get() {
+                    Ok(self.response.clone())
+                } else {
+                    Err(Ph1dProviderAdapterError::terminal("down".to_string()))
+                }
+            }
+        }
+
+        let (request, response) = slice3b_provider_request_response_pair("gpt-5.5");
+        let inner = ScriptedAdapter {
+            response,
+            succeed: std::cell::Cell::new(false),
+            calls: std::cell::Cell::new(0),
+        };
+        let policy = AdapterProviderCircuitBreakerPolicy {
+            failure_threshold: 2,
+            cooldown_ns: 1_000,
+        };
+        let state = Arc::new(Mutex::new(AdapterProviderCircuitState::default()));
+        let at = |now_ns: u64| {
+            CircuitBreakerProviderAdapter::new(&inner, policy, Arc::clone(&state), now_ns)
+        };
+        let circuit_state = |now_ns: u64| {
+            state
+                .lock()
+                .expect("circuit lock")
+                .health(policy, now_ns)
+                .circuit_state
+        };
+
+        assert!(at(10).execute(&request).is_err());
+        assert_eq!(circuit_state(10), "CLOSED");
+        assert!(at(20).execute(&request).is_err());
+        assert_eq!(circuit_state(20), "OPEN");
+        assert_eq!(inner.calls.get(), 2);
+
+        let err = at(500)
+            .execute(&request)
+            .expect_err("open circuit must short-circuit");
+        assert_eq!(err.message, "ph1d provider circuit open");
+        assert!(err.retryable, "an open circuit must let failover move on");
+        assert_eq!(inner.calls.get(), 2);
+
+        inner.succeed.set(true);
+        at(1_020)
+            .execute(&request)
+            .expect("half-open probe must reach the provider");
+        assert_eq!(inner.calls.get(), 3);
+        let health = state.lock().expect("circuit lock").health(policy, 1_021);
+        assert_eq!(health.circuit_state, "CLOSED");
+        assert_eq!(health.consecutive_failures, 0);
+        assert_eq!(health.trips_total, 1);
+        assert_eq!(health.short_circuited_total, 1);
+    }
+
+    struct FailoverScriptedAdapter {
+        outcome: Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError>,
+        delay_ms: u64,
+        calls: std::cell::Cell<u32>,
+    }
+
+    impl Ph1dProviderAdapter for &FailoverScriptedAdapter {
+        fn execute(
+            &self,
+            _req: &Ph1dProviderCallRequest,
+        ) -> Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError> {
+            self.calls.set(self.calls.get() + 1);
+            std::thread::sleep(std::time::Duration::from_millis(self.delay_ms));
+            self.outcome.clone()
+        }
+    }
+
+    fn failover_scripted_adapter(
+        outcome: Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError>,
+        delay_ms: u64,
+    ) -> FailoverScriptedAdapter {
+        FailoverScriptedAdapter {
+            outcome,
+            delay_ms,
+            calls: std::cell::Cell::new(0),
+        }
+    }
+
+    #[test]
+    fn at_adapter_38e1_failover_moves_to_next_provider_after_retryable_error() {
+        let (request, response) = slice3b_provider_request_response_pair("gpt-5.5");
+        let own_latency_ms = response.provider_latency_ms;
+        let first = failover_scripted_adapter(
+            Err(Ph1dProviderAdapterError::retryable("timeout".to_string())),
+            5,
+        );
+        let second = failover_scripted_adapter(Ok(response), 0);
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let failover = FailoverProviderAdapter::new(
+            vec![Box::new(&first), Box::new(&second)],
+            3,
+            Some(Arc::clone(&records)),
+        );
+
+        let out = failover
+            .execute(&request)
+            .expect("second provider must answer");
+        assert_eq!(first.calls.get(), 1);
+        assert_eq!(second.calls.get(), 1);
+        assert!(out.provider_latency_ms >= own_latency_ms + 5);
+
+        let records = records.lock().expect("records lock");
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].provider_status, Ph1dProviderStatus::Error);
+        assert_eq!(
+            records[0].reason_code,
+            reason_codes::ADAPTER_PROVIDER_FAILOVER_ATTEMPT_FAILED
+        );
+        assert!(records[0].provider_latency_ms >= 5);
+    }
+
+    #[test]
+    fn at_adapter_38e2_failover_stops_on_terminal_error_and_attempt_cap() {
+        let (request, response) = slice3b_provider_request_response_pair("gpt-5.5");
+        let down = || {
+            failover_scripted_adapter(
+                Err(Ph1dProviderAdapterError::retryable("down".to_string())),
+                0,
+            )
+        };
+        let (a, b, c) = (down(), down(), down());
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let failover = FailoverProviderAdapter::new(
+            vec![Box::new(&a), Box::new(&b), Box::new(&c)],
+            2,
+            Some(Arc::clone(&records)),
+        );
+        let err = failover
+            .execute(&request)
+            .expect_err("every provider failed");
+        assert!(err.retryable);
+        assert_eq!((a.calls.get(), b.calls.get(), c.calls.get()), (1, 1, 0));
+        assert_eq!(records.lock().expect("records lock").len(), 2);
+
+        let refused = failover_scripted_adapter(
+            Err(Ph1dProviderAdapterError::terminal("refused".to_string())),
+            0,
+        );
+        let healthy = failover_scripted_adapter(Ok(response), 0);
+        let failover =
+            FailoverProviderAdapter::new(vec![Box::new(&refused), Box::new(&healthy)], 3, None);
+        let err = failover
+            .execute(&request)
+            .expect_err("terminal error must stop failover");
+        assert_eq!(err.message, "refused");
+        assert_eq!(healthy.calls.get(), 0);
+    }
+
+    #[test]
+    fn at_adapter_38e3_open_primary_circuit_goes_straight_to_fallback() {
+        struct SttFallback {
+            calls: Arc<AtomicU64>,
+        }
+        impl Ph1dProviderAdapter for SttFallback {
+            fn execute(
+                &self,
+                req: &Ph1dProviderCallRequest,
+            ) -> Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                let normalized = serde_json::json!({
+                    "schema_version": 1,
+                    "provider_task": Ph1dProviderTask::SttTranscribe.as_str(),
+                    "text_output": "what is on my calendar today",
+                    "language_tag": "en",
+                    "confidence_bp": 9_500,
+                    "stable": true,
+                    "audio_output_ref": serde_json::Value::Null,
+                    "audio_content_type": serde_json::Value::Null,
+                    "estimated_duration_ms": serde_json::Value::Null,
+                });
+                Ph1dProviderCallResponse::v1(
+                    req.correlation_id,
+                    req.turn_id,
+                    req.request_id,
+                    req.idempotency_key.clone(),
+                    Some("fallback_call_1".to_string()),
+                    req.provider_id.clone(),
+                    req.provider_task,
+                    req.model_id.clone(),
+                    Ph1dProviderStatus::Ok,
+                    40,
+                    0,
+                    Some(9_500),
+                    Some(PH1D_PROVIDER_NORMALIZED_OUTPUT_SCHEMA_HASH_V1),
+                    Some(normalized.to_string()),
+                    Ph1dProviderValidationStatus::SchemaOk,
+                    ph1d_reason_codes::D_PROVIDER_OK,
+                )
+                .map_err(|err| Ph1dProviderAdapterError::terminal(format!("{err:?}")))
+            }
+        }
+
+        let fallback_calls = Arc::new(AtomicU64::new(0));
+        let runtime = AdapterRuntime {
+            ph1c_live_enabled: true,
+            ph1c_streaming_enabled: false,
+            ph1d_live_adapter: Some(slice3b_offline_adapter("gpt-5.5")),
+            ph1d_live_fallback_providers: vec![AdapterPh1dFallbackProvider::new(SttFallback {
+                calls: Arc::clone(&fallback_calls),
+            })],
+            provider_circuit_state: Arc::new(Mutex::new(AdapterProviderCircuitState {
+                open_until_ns: Some(u64::MAX),
+                ..AdapterProviderCircuitState::default()
+            })),
+            ..AdapterRuntime::default()
+        };
+        let mut req = base_request();
+        req.app_platform = "ANDROID".to_string();
+        req.correlation_id = 38_531;
+        req.turn_id = 38_531;
+        req.now_ns = Some(38_531);
+        req.user_text_final = None;
+
+        let out = runtime
+            .run_voice_turn(req)
+            .expect("fallback turn should respond");
+        assert_eq!(out.stt_mode, "LIVE");
+        assert!(fallback_calls.load(Ordering::SeqCst) >= 1);
+        let health = runtime.health_report(None).expect("health report");
+        assert_eq!(health.provider.circuit_state, "OPEN");
+        assert!(health.provider.short_circuited_total >= 1);
+        let store = runtime.store.lock().expect("store lock should succeed");
+        assert!(store
+            .conversation_ledger()
+            .iter()
+            .any(|row| row.correlation_id == CorrelationId(38_531)
+                && row.text == "what is on my calendar today"));
+    }
+
+    #[test]
+    fn at_adapter_38f_model_override_reaches_provider_only_when_allowlisted() {
+        let (endpoint, request_rx) =
+            spawn_openai_responses_endpoint_capturing_request("Research answers stay cited.");
+        with_isolated_device_vault(
+            "at-adapter-38f-model-override",
+            &[("openai_api_key", "test_openai_key")],
+            &[
+                ("SELENE_PH1D_LIVE_ADAPTER_ENABLED", "true"),
+                ("SELENE_PH1D_LIVE_PROVIDER_ID", "openai"),
+                ("SELENE_PH1D_LIVE_MODEL_ID", SLICE3C_APPROVED_PH1D_MODEL_ID),
+                ("OPENAI_MODEL", SLICE3C_APPROVED_PH1D_MODEL_ID),
+                ("OPENAI_RESPONSES_URL", endpoint.as_str()),
+                ("SELENE_CURL_CONNECT_TIMEOUT", "1"),
+                ("SELENE_CURL_MAX_TIME", "5"),
+            ],
+            || {
+                let allowlist = BTreeSet::from(["gpt-research".to_string()]);
+                let runtime = AdapterRuntime {
+                    model_override_allowlist: allowlist.clone(),
+                    ph1d_live_adapter: build_ph1d_live_adapter_from_env()
+                        .map(|adapter| adapter.with_model_override_allowlist(allowlist)),
+                    ..AdapterRuntime::default()
+                };
+                let mut req = base_request();
+                req.correlation_id = 306_001;
+                req.turn_id = 306_101;
+                req.app_platform = "DESKTOP".to_string();
+                req.audio_capture_ref = None;
+                req.user_text_final =
+                    Some("Explain why research answers need citations.".to_string());
+
+                let mut rejected = req.clone();
+                rejected.model_override = Some("gpt-unlisted".to_string());
+                let err = runtime
+                    .run_voice_turn(rejected)
+                    .expect_err("non-allowlisted override must be rejected")
+                    .to_string();
+                assert!(err.contains("model_override 'gpt-unlisted' is not allowlisted"));
+
+                req.model_override = Some("gpt-research".to_string());
+                let out = runtime
+                    .run_voice_turn(req)
+                    .expect("allowlisted override must complete");
+                assert_eq!(out.response_text, "Research answers stay cited.");
+
+                let request_text = request_rx
+                    .recv_timeout(std::time::Duration::from_secs(2))
+                    .expect("mock OpenAI server should capture request");
+                let body = request_text
+                    .split_once("\r\n\r\n")
+                    .map(|(_, body)| body.trim())
+                    .expect("captured HTTP request should include JSON body");
+                let payload: serde_json::Value =
+                    serde_json::from_str(body).expect("captured payload should parse");
+                assert_eq!(
+                    payload.get("model").and_then(|value| value.as_str()),
+                    Some("gpt-research")
+                );
+                let evidence_rows = runtime.ph1d_provider_transport_evidence_snapshot();
+                assert_eq!(evidence_rows.len(), 1);
+                assert_eq!(evidence_rows[0].expected_model_id, "gpt-research");
+                assert_eq!(evidence_rows[0].actual_model_id, "gpt-research");
+                assert!(!evidence_rows[0].unapproved_model_used);
+            },
+        );
+    }
+
+    #[test]
+    fn at_adapter_38g_live_adapter_refuses_models_outside_its_allowlist() {
+        let adapter = slice3b_offline_adapter(SLICE3C_APPROVED_PH1D_MODEL_ID);
+        let (request, response) = slice3b_provider_request_response_pair("gpt-unlisted");
+
+        let failure = adapter
+            .execute_with_error_evidence(&request)
+            .expect_err("unlisted model must not reach the provider");
+        assert!(!failure.error.retryable);
+        assert!(failure
+            .error
+            .message
+            .contains("adapter model_id does not match PH1.D request"));
+        assert!(adapter
+            .build_transport_evidence_for_provider_response(
+                &request,
+                &response,
+                "gpt-unlisted",
+                1,
+                1,
+                "ph1x:38g",
+                "ph1write:38g",
+            )
+            .is_err());
+        assert_eq!(
+            adapter.approved_model_id(None),
+            Ok(SLICE3C_APPROVED_PH1D_MODEL_ID)
+        );
+        assert_eq!(
+            adapter.approved_model_id(Some("gpt-unlisted")),
+            Err("model_override 'gpt-unlisted' is not allowlisted".to_string())
+        );
+
+        let adapter =
+            adapter.with_model_override_allowlist(BTreeSet::from(["gpt-unlisted".to_string()]));
+        assert_eq!(
+            adapter.approved_model_id(Some("gpt-unlisted")),
+            Ok("gpt-unlisted")
+        );
+        let evidence = adapter
+            .build_transport_evidence_for_provider_response(
+                &request,
+                &response,
+                adapter
+                    .approved_model_id(Some("gpt-unlisted"))
+                    .expect("allowlisted override"),
+                1,
+                1,
+                "ph1x:38g",
+                "ph1write:38g",
+            )
+            .expect("allowlisted override evidence should validate");
+        assert_eq!(evidence.expected_model_id, "gpt-unlisted");
+        assert!(!evidence.unapproved_model_used);
+    }
+
     fn slice3b_offline_adapter(model_id: &str) -> EnvPh1dLiveAdapter {
         EnvPh1dLiveAdapter {
             provider_id: "openai".to_string(),
@@ -42124,6 +57010,7 @@ mod tests {
             endpoint: "offline_endpoint".to_string(),
             api_key: "redacted_test_key".to_string(),
             timeout_ms: 1_000,
+            model_override_allowlist: BTreeSet::new(),
         }
     }
 
@@ -43187,6 +58074,343 @@ mod tests {
         assert!(!execution_outcome_is_public_no_intent(&normal_answer));
     }
 
+    #[test]
+    fn at_adapter_39a_wait_outcome_keeps_listening_without_closing_response() {
+        let mut wait = ph1d_public_no_intent_test_outcome("waiting_for_user");
+        wait.next_move = AppVoiceTurnNextMove::Wait;
+        let out = execution_outcome_to_adapter_response(
+            wait,
+            &AdapterRefusalMessages::default(),
+            VOICE_DISABLED_DEFAULT_MESSAGE,
+        );
+        assert_eq!(out.next_move, "wait");
+        assert!(out.keep_listening);
+        assert!(out.response_text.is_empty());
+        assert!(out.tts_text.is_empty());
+        let json = serde_json::to_value(&out).expect("response must serialize");
+        assert_eq!(json["keep_listening"], serde_json::Value::Bool(true));
+
+        let respond = execution_outcome_to_adapter_response(
+            ph1d_public_no_intent_test_outcome("Done."),
+            &AdapterRefusalMessages::default(),
+            VOICE_DISABLED_DEFAULT_MESSAGE,
+        );
+        assert_eq!(respond.next_move, "respond");
+        assert!(!respond.keep_listening);
+        assert_eq!(respond.response_text, "Done.");
+    }
+
+    #[test]
+    fn at_adapter_39a1_typed_next_move_and_outcome_match_string_labels() {
+        for (app_next_move, next_move, outcome) in [
+            (
+                AppVoiceTurnNextMove::NotInvokedDisabled,
+                VoiceTurnNextMove::Respond,
+                VoiceTurnOutcome::VoiceDisabled,
+            ),
+            (
+                AppVoiceTurnNextMove::Refused,
+                VoiceTurnNextMove::Respond,
+                VoiceTurnOutcome::Final,
+            ),
+            (
+                AppVoiceTurnNextMove::Confirm,
+                VoiceTurnNextMove::Clarify,
+                VoiceTurnOutcome::Final,
+            ),
+            (
+                AppVoiceTurnNextMove::Clarify,
+                VoiceTurnNextMove::Clarify,
+                VoiceTurnOutcome::Final,
+            ),
+            (
+                AppVoiceTurnNextMove::Respond,
+                VoiceTurnNextMove::Respond,
+                VoiceTurnOutcome::Final,
+            ),
+            (
+                AppVoiceTurnNextMove::Dispatch,
+                VoiceTurnNextMove::DispatchSim,
+                VoiceTurnOutcome::Final,
+            ),
+            (
+                AppVoiceTurnNextMove::Wait,
+                VoiceTurnNextMove::Wait,
+                VoiceTurnOutcome::Final,
+            ),
+        ] {
+            assert_eq!(VoiceTurnNextMove::from(app_next_move), next_move);
+            let mut execution = ph1d_public_no_intent_test_outcome("Done.");
+            execution.next_move = app_next_move;
+            let out = execution_outcome_to_adapter_response(
+                execution,
+                &AdapterRefusalMessages::default(),
+                VOICE_DISABLED_DEFAULT_MESSAGE,
+            );
+            assert_eq!(out.next_move_kind, Some(next_move));
+            assert_eq!(out.outcome_kind, Some(outcome));
+            assert_eq!(out.next_move, next_move.as_str());
+            assert_eq!(out.outcome, outcome.as_str());
+
+            let json = serde_json::to_value(&out).expect("response must serialize");
+            assert_eq!(json["next_move_kind"], json["next_move"]);
+            assert_eq!(json["outcome_kind"], json["outcome"]);
+            let round_trip: VoiceTurnAdapterResponse =
+                serde_json::from_value(json).expect("response must deserialize");
+            assert_eq!(round_trip.next_move_kind, Some(next_move));
+            assert_eq!(round_trip.outcome_kind, Some(outcome));
+        }
+
+        let mut json = serde_json::to_value(execution_outcome_to_adapter_response(
+            ph1d_public_no_intent_test_outcome("Done."),
+            &AdapterRefusalMessages::default(),
+            VOICE_DISABLED_DEFAULT_MESSAGE,
+        ))
+        .expect("response must serialize");
+        let fields = json.as_object_mut().expect("response must be an object");
+        fields.remove("next_move_kind");
+        fields.remove("outcome_kind");
+        let legacy: VoiceTurnAdapterResponse =
+            serde_json::from_value(json).expect("response without typed fields must deserialize");
+        assert_eq!(legacy.next_move_kind, None);
+        assert_eq!(legacy.outcome_kind, None);
+    }
+
+    #[test]
+    fn at_adapter_39b_empty_response_counters_split_wait_from_unexpected_silence() {
+        let runtime = AdapterRuntime::default();
+        let mut wait = ph1d_public_no_intent_test_outcome("waiting_for_user");
+        wait.next_move = AppVoiceTurnNextMove::Wait;
+        runtime.record_empty_response_turn(
+            39_201,
+            &execution_outcome_to_adapter_response(
+                wait,
+                &AdapterRefusalMessages::default(),
+                VOICE_DISABLED_DEFAULT_MESSAGE,
+            ),
+        );
+        runtime.record_empty_response_turn(
+            39_202,
+            &execution_outcome_to_adapter_response(
+                ph1d_public_no_intent_test_outcome("Done."),
+                &AdapterRefusalMessages::default(),
+                VOICE_DISABLED_DEFAULT_MESSAGE,
+            ),
+        );
+        let mut silent = ph1d_public_no_intent_test_outcome("placeholder");
+        silent.response_text = None;
+        let silent = execution_outcome_to_adapter_response(
+            silent,
+            &AdapterRefusalMessages::default(),
+            VOICE_DISABLED_DEFAULT_MESSAGE,
+        );
+        assert_eq!(silent.next_move, "respond");
+        assert!(silent.response_text.is_empty());
+        runtime.record_empty_response_turn(39_203, &silent);
+
+        let counters = runtime
+            .health_report(Some(39_204))
+            .expect("health report")
+            .sync
+            .improvement
+            .empty_responses;
+        assert_eq!(
+            counters.intentional_by_next_move,
+            BTreeMap::from([("wait".to_string(), 1)])
+        );
+        assert_eq!(
+            counters.unexpected_by_next_move,
+            BTreeMap::from([("respond".to_string(), 1)])
+        );
+    }
+
+    #[test]
+    fn at_adapter_39a2_voice_disabled_outcome_explains_itself() {
+        let mut disabled = ph1d_public_no_intent_test_outcome("unused");
+        disabled.next_move = AppVoiceTurnNextMove::NotInvokedDisabled;
+        disabled.ph1x_response = None;
+        disabled.response_text = None;
+        disabled.reason_code = None;
+        let out = execution_outcome_to_adapter_response(
+            disabled,
+            &AdapterRefusalMessages::default(),
+            VOICE_DISABLED_DEFAULT_MESSAGE,
+        );
+        assert_eq!(out.status, "ok");
+        assert_eq!(out.outcome, "VOICE_DISABLED");
+        assert!(out
+            .reason
+            .as_deref()
+            .is_some_and(|reason| reason.contains("NOT_INVOKED_DISABLED")));
+        assert_eq!(out.response_text, VOICE_DISABLED_DEFAULT_MESSAGE);
+        assert_eq!(out.tts_text, out.response_text);
+        let mut configured = ph1d_public_no_intent_test_outcome("unused");
+        configured.next_move = AppVoiceTurnNextMove::NotInvokedDisabled;
+        configured.response_text = None;
+        let out = execution_outcome_to_adapter_response(
+            configured,
+            &AdapterRefusalMessages::default(),
+            "Voice is off on this device.",
+        );
+        assert_eq!(out.response_text, "Voice is off on this device.");
+
+        assert_eq!(
+            voice_disabled_message_from(Some("  Voice is currently disabled for your account  ")),
+            "Voice is currently disabled for your account"
+        );
+        assert_eq!(
+            voice_disabled_message_from(Some("   ")),
+            VOICE_DISABLED_DEFAULT_MESSAGE
+        );
+    }
+
+    #[test]
+    fn at_adapter_39a2b_refused_outcome_hides_os_refusal_detail_from_client() {
+        let refuse = selene_kernel_contracts::ph1os::OsRefuse::v1(
+            selene_kernel_contracts::ph1os::OsCapabilityId::OsDecisionCompute,
+            ReasonCodeId(0x4F53_0001),
+            "clarify owner must be PH1.NLP when clarify_required=true".to_string(),
+        )
+        .unwrap();
+        let mut refused = ph1d_public_no_intent_test_outcome("unused");
+        refused.voice_outcome = OsVoiceLiveTurnOutcome::Refused(refuse.clone());
+        refused.next_move = AppVoiceTurnNextMove::Refused;
+        refused.ph1x_response = None;
+        refused.response_text = Some(refuse.message.clone());
+        refused.reason_code = Some(refuse.reason_code);
+        let out = execution_outcome_to_adapter_response(
+            refused.clone(),
+            &AdapterRefusalMessages::default(),
+            VOICE_DISABLED_DEFAULT_MESSAGE,
+        );
+        assert_eq!(out.reason.as_deref(), Some(REFUSAL_DEFAULT_MESSAGE));
+        assert_eq!(out.response_text, REFUSAL_DEFAULT_MESSAGE);
+        assert_eq!(out.tts_text, out.response_text);
+
+        assert_eq!(
+            AdapterRefusalMessages::parse("0x4F53_0001: Let's try that another way. ;*:Not now.")
+                .message_for(ReasonCodeId(0x4F53_0001)),
+            "Let's try that another way."
+        );
+        assert_eq!(
+            AdapterRefusalMessages::parse("1330839553:Decimal works too.")
+                .message_for(refuse.reason_code),
+            "Decimal works too."
+        );
+        assert_eq!(
+            AdapterRefusalMessages::parse("0x4F530001:;*:Not now.").message_for(ReasonCodeId(7)),
+            "Not now."
+        );
+        assert_eq!(
+            AdapterRefusalMessages::parse("bogus").message_for(ReasonCodeId(7)),
+            REFUSAL_DEFAULT_MESSAGE
+        );
+        let configured = AdapterRefusalMessages::parse("*:Not on this device.");
+        let out = execution_outcome_to_adapter_response(
+            refused.clone(),
+            &configured,
+            VOICE_DISABLED_DEFAULT_MESSAGE,
+        );
+        assert_eq!(out.response_text, "Not on this device.");
+        assert_eq!(out.reason.as_deref(), Some("Not on this device."));
+
+        let sink = Arc::new(RecordingTelemetrySink::default());
+        let runtime = AdapterRuntime {
+            telemetry_sink: sink.clone(),
+            ..AdapterRuntime::default()
+        };
+        runtime.emit_voice_refusal_telemetry(
+            MonotonicTimeNs(39_202),
+            CorrelationId(39_202),
+            TurnId(39_202),
+            &refused.voice_outcome,
+        );
+        let events = sink.events.lock().expect("sink lock must not poison");
+        assert_eq!(
+            *events,
+            vec![TelemetryEvent::VoiceRefused {
+                captured_at: MonotonicTimeNs(39_202),
+                correlation_id: CorrelationId(39_202),
+                turn_id: TurnId(39_202),
+                reason_code: ReasonCodeId(0x4F53_0001),
+                message: "clarify owner must be PH1.NLP when clarify_required=true".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn at_adapter_39a3_provenance_mode_shapes_response_sources() {
+        let sourced_response = || {
+            let mut out = execution_outcome_to_adapter_response(
+                ph1d_public_no_intent_test_outcome("It is sunny in Paris."),
+                &AdapterRefusalMessages::default(),
+                VOICE_DISABLED_DEFAULT_MESSAGE,
+            );
+            out.provenance = Some(VoiceTurnProvenance {
+                sources: vec![
+                    VoiceTurnProvenanceSource {
+                        title: "Weather Paris".to_string(),
+                        url: "https://weather.example/paris".to_string(),
+                    },
+                    VoiceTurnProvenanceSource {
+                        title: " ".to_string(),
+                        url: "https://forecast.example/fr".to_string(),
+                    },
+                ],
+                retrieved_at: 1_700_000_000_000,
+                cache_status: "miss".to_string(),
+            });
+            out
+        };
+
+        let mut structured = sourced_response();
+        apply_provenance_mode(&mut structured, VoiceTurnProvenanceMode::default());
+        assert_eq!(structured.response_text, "It is sunny in Paris.");
+        assert_eq!(
+            structured
+                .provenance
+                .as_ref()
+                .map(|provenance| provenance.sources.len()),
+            Some(2)
+        );
+
+        let mut none = sourced_response();
+        apply_provenance_mode(&mut none, VoiceTurnProvenanceMode::None);
+        assert!(none.provenance.is_none());
+        assert_eq!(none.response_text, "It is sunny in Paris.");
+
+        let mut inline = sourced_response();
+        apply_provenance_mode(&mut inline, VoiceTurnProvenanceMode::Inline);
+        assert_eq!(
+            inline.response_text,
+            "It is sunny in Paris.\n\nSources: [1] Weather Paris (https://weather.example/paris); [2] https://forecast.example/fr"
+        );
+        assert_eq!(inline.tts_text, "It is sunny in Paris.");
+        assert!(inline.provenance.is_some());
+
+        let mut unsourced = execution_outcome_to_adapter_response(
+            ph1d_public_no_intent_test_outcome("Done."),
+            &AdapterRefusalMessages::default(),
+            VOICE_DISABLED_DEFAULT_MESSAGE,
+        );
+        apply_provenance_mode(&mut unsourced, VoiceTurnProvenanceMode::Inline);
+        assert_eq!(unsourced.response_text, "Done.");
+
+        assert_eq!(
+            VoiceTurnProvenanceMode::parse(" inline "),
+            Ok(VoiceTurnProvenanceMode::Inline)
+        );
+        let mut req = base_request();
+        req.correlation_id = 10_119;
+        req.turn_id = 20_119;
+        req.provenance_mode = Some("VERBOSE".to_string());
+        let err = AdapterRuntime::default()
+            .run_voice_turn(req)
+            .expect_err("unknown provenance_mode must be rejected")
+            .to_string();
+        assert!(err.contains("invalid provenance_mode"));
+    }
+
     #[test]
     fn at_adapter_39b_public_general_prompt_uses_ph1d_without_governance_drift_leak() {
         let endpoint = spawn_openai_responses_endpoint_for_public_answer_test(
@@ -0,0 +1,406 @@
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use selene_engines::ph1d::{
+    reason_codes as ph1d_reason_codes, Ph1dProviderAdapter, Ph1dProviderAdapterError,
+};
+use selene_kernel_contracts::ph1d::{
+    Ph1dProviderCallRequest, Ph1dProviderCallResponse, Ph1dProviderStatus, Ph1dProviderTask,
+    Ph1dProviderValidationStatus, PH1D_PROVIDER_NORMALIZED_OUTPUT_SCHEMA_HASH_V1,
+};
+
+use crate::{
+    openai_error_details_from_body, openai_responses_parameter_compatibility,
+    output_language_from_content_type, ph1d_chat_json_from_provider_text,
+    ph1d_public_answer_system_instruction, safe_provider_error_detail, truncate_ascii,
+};
+
+const PROVIDER_LATENCY_MAX_MS: u128 = 120_000;
+const ERROR_BODY_MAX_BYTES: u64 = 4_096;
+
+/// PH1.D provider that POSTs `LlmInterpret` calls to an OpenAI-compatible
+/// `/chat/completions` endpoint. Each call uses the request's `timeout_ms`.
+#[derive(Clone)]
+pub struct HttpPh1dProviderAdapter {
+    endpoint: String,
+    api_key: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl fmt::Debug for HttpPh1dProviderAdapter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpPh1dProviderAdapter")
+            .field("endpoint", &self.endpoint)
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+impl HttpPh1dProviderAdapter {
+    pub fn new(endpoint: &str, api_key: Option<String>) -> Result<Self, String> {
+        let endpoint = endpoint.trim();
+        if !(endpoint.starts_with("http://") || endpoint.starts_with("https://")) {
+            return Err(format!(
+                "ph1d http provider endpoint must be an http(s) url: {}",
+                truncate_ascii(endpoint, 256)
+            ));
+        }
+        Ok(Self {
+            endpoint: endpoint.to_string(),
+            api_key: api_key
+                .map(|key| key.trim().to_string())
+                .filter(|key| !key.is_empty()),
+            agent: ureq::AgentBuilder::new().try_proxy_from_env(false).build(),
+        })
+    }
+
+    fn chat_completions_payload(
+        req: &Ph1dProviderCallRequest,
+        input: &str,
+        output_language: &str,
+    ) -> serde_json::Value {
+        let mut payload = serde_json::json!({
+            "model": req.model_id,
+            "messages": [
+                {
+                    "role": "system",
+                    "content": ph1d_public_answer_system_instruction(output_language)
+                },
+                {
+                    "role": "user",
+                    "content": input
+                }
+            ],
+            "max_tokens": 700
+        });
+        if let Some(temperature) =
+            openai_responses_parameter_compatibility(&req.model_id).temperature
+        {
+            if let Some(payload_object) = payload.as_object_mut() {
+                payload_object.insert("temperature".to_string(), serde_json::json!(temperature));
+            }
+        }
+        payload
+    }
+
+    fn post(
+        &self,
+        payload: serde_json::Value,
+        timeout: Duration,
+        input: &str,
+    ) -> Result<serde_json::Value, Ph1dProviderAdapterError> {
+        let mut request = self
+            .agent
+            .post(&self.endpoint)
+            .timeout(timeout)
+            .set("Content-Type", "application/json")
+            .set("Accept", "application/json");
+        if let Some(api_key) = self.api_key.as_deref() {
+            request = request.set("Authorization", &format!("Bearer {api_key}"));
+        }
+        let response = request
+            .send_json(payload)
+            .map_err(|err| provider_error_from_ureq(err, timeout, input))?;
+        response.into_json::<serde_json::Value>().map_err(|err| {
+            if err.kind() == std::io::ErrorKind::TimedOut {
+                timeout_error(timeout)
+            } else {
+                Ph1dProviderAdapterError::retryable(
+                    "ph1d http provider response json_parse".to_string(),
+                )
+            }
+        })
+    }
+}
+
+impl Ph1dProviderAdapter for HttpPh1dProviderAdapter {
+    fn execute(
+        &self,
+        req: &Ph1dProviderCallRequest,
+    ) -> Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError> {
+        if req.provider_task != Ph1dProviderTask::LlmInterpret {
+            return Err(Ph1dProviderAdapterError::terminal(format!(
+                "ph1d http provider adapter does not support provider_task={}",
+                req.provider_task.as_str()
+            )));
+        }
+        let input = req
+            .input_payload_inline
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                Ph1dProviderAdapterError::terminal(
+                    "ph1d llm interpret request missing input_payload_inline".to_string(),
+                )
+            })?;
+        let output_language = output_language_from_content_type(req.input_content_type.as_deref());
+        let payload = Self::chat_completions_payload(req, input, &output_language);
+        let timeout = Duration::from_millis(u64::from(req.timeout_ms));
+
+        let start = Instant::now();
+        let root = self.post(payload, timeout, input)?;
+        let latency_ms = start.elapsed().as_millis().min(PROVIDER_LATENCY_MAX_MS) as u32;
+
+        let provider_call_id = root
+            .get("id")
+            .and_then(|value| value.as_str())
+            .map(|value| truncate_ascii(value.trim(), 128))
+            .filter(|value| !value.is_empty());
+        let answer_text = root
+            .pointer("/choices/0/message/content")
+            .and_then(|value| value.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                Ph1dProviderAdapterError::retryable(
+                    "ph1d http provider response empty_output".to_string(),
+                )
+            })?;
+        let normalized_json = ph1d_chat_json_from_provider_text(answer_text).map_err(|err| {
+            Ph1dProviderAdapterError::terminal(format!(
+                "ph1d http provider normalized output rejected: {err}"
+            ))
+        })?;
+        Ph1dProviderCallResponse::v1(
+            req.correlation_id,
+            req.turn_id,
+            req.request_id,
+            req.idempotency_key.clone(),
+            provider_call_id,
+            req.provider_id.clone(),
+            req.provider_task,
+            req.model_id.clone(),
+            Ph1dProviderStatus::Ok,
+            latency_ms,
+            0,
+            Some(9_000),
+            Some(PH1D_PROVIDER_NORMALIZED_OUTPUT_SCHEMA_HASH_V1),
+            Some(normalized_json),
+            Ph1dProviderValidationStatus::SchemaOk,
+            ph1d_reason_codes::D_PROVIDER_OK,
+        )
+        .map_err(|err| {
+            Ph1dProviderAdapterError::terminal(format!(
+                "ph1d provider response contract failed: {err:?}"
+            ))
+        })
+    }
+}
+
+fn timeout_error(timeout: Duration) -> Ph1dProviderAdapterError {
+    Ph1dProviderAdapterError::retryable(format!(
+        "ph1d http provider timeout after {}ms",
+        timeout.as_millis()
+    ))
+}
+
+// 429 and 5xx are worth another attempt; any other 4xx means the request itself is wrong.
+fn provider_error_from_ureq(
+    err: ureq::Error,
+    timeout: Duration,
+    input: &str,
+) -> Ph1dProviderAdapterError {
+    match err {
+        ureq::Error::Status(status, response) => {
+            let mut body = String::new();
+            let _ = std::io::Read::read_to_string(
+                &mut std::io::Read::take(response.into_reader(), ERROR_BODY_MAX_BYTES),
+                &mut body,
+            );
+            let details = openai_error_details_from_body(&body, Some(input));
+            let message = format!(
+                "ph1d http provider failed http_status={} error_type={} error_code={} detail={}",
+                status,
+                details.error_type.as_deref().unwrap_or("unknown"),
+                details.error_code.as_deref().unwrap_or("none"),
+                details.sanitized_message.as_deref().unwrap_or("none")
+            );
+            if status == 429 || (500..=599).contains(&status) {
+                Ph1dProviderAdapterError::retryable(message)
+            } else {
+                Ph1dProviderAdapterError::terminal(message)
+            }
+        }
+        ureq::Error::Transport(transport) => {
+            let detail = transport.to_string();
+            let lower = detail.to_ascii_lowercase();
+            if lower.contains("timed out") || lower.contains("timeout") {
+                timeout_error(timeout)
+            } else {
+                Ph1dProviderAdapterError::retryable(format!(
+                    "ph1d http provider transport failed: {}",
+                    safe_provider_error_detail(&detail)
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EnvPh1dLiveAdapter;
+    use selene_kernel_contracts::ph1j::{CorrelationId, TurnId};
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    struct MockReply {
+        status: u16,
+        body: String,
+        delay: Duration,
+    }
+
+    fn spawn_server(reply: MockReply) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind should succeed");
+        let endpoint = format!(
+            "http://{}/v1/chat/completions",
+            listener.local_addr().expect("local addr should exist")
+        );
+        let join = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept should succeed");
+            let body = read_request_body(&mut stream);
+            thread::sleep(reply.delay);
+            let head = format!(
+                "HTTP/1.1 {} Status\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                reply.status,
+                reply.body.len()
+            );
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(reply.body.as_bytes());
+            let _ = stream.flush();
+            body
+        });
+        (endpoint, join)
+    }
+
+    fn read_request_body(stream: &mut TcpStream) -> String {
+        let mut reader = BufReader::new(stream);
+        let mut content_length = 0usize;
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 || line == "\r\n" {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.eq_ignore_ascii_case("content-length") {
+                    content_length = value.trim().parse().unwrap_or(0);
+                }
+            }
+        }
+        let mut body = vec![0u8; content_length];
+        let _ = reader.read_exact(&mut body);
+        String::from_utf8_lossy(&body).into_owned()
+    }
+
+    fn llm_interpret_request(timeout_ms: u32) -> Ph1dProviderCallRequest {
+        EnvPh1dLiveAdapter {
+            provider_id: "openai".to_string(),
+            model_id: "gpt-4o-mini".to_string(),
+            endpoint: "http://127.0.0.1:9/unused".to_string(),
+            api_key: "test-key".to_string(),
+            timeout_ms,
+            model_override_allowlist: Default::default(),
+        }
+        .build_llm_interpret_request_for_language(
+            CorrelationId(91_001),
+            TurnId(92_001),
+            "tenant_a",
+            "what is the capital of France?",
+            "en",
+            None,
+            crate::AdapterConfig::default().idempotency_token_max_len,
+        )
+        .expect("request must build")
+    }
+
+    #[test]
+    fn http_provider_maps_chat_completion_to_normalized_output() {
+        let (endpoint, server) = spawn_server(MockReply {
+            status: 200,
+            body: serde_json::json!({
+                "id": "chatcmpl-123",
+                "choices": [{
+                    "message": {
+                        "role": "assistant",
+                        "content": "{\"mode\":\"chat\",\"response_text\":\"Paris.\",\"reason_code\":1}"
+                    }
+                }]
+            })
+            .to_string(),
+            delay: Duration::from_millis(50),
+        });
+        let adapter = HttpPh1dProviderAdapter::new(&endpoint, Some("test-key".to_string()))
+            .expect("adapter must build");
+        let response = adapter
+            .execute(&llm_interpret_request(5_000))
+            .expect("chat completion must succeed");
+        let sent = server.join().expect("server thread must finish");
+        let sent: serde_json::Value = serde_json::from_str(&sent).expect("payload must be json");
+
+        assert_eq!(sent["model"], "gpt-4o-mini");
+        assert_eq!(
+            sent["messages"][1]["content"],
+            "what is the capital of France?"
+        );
+        assert_eq!(response.provider_call_id.as_deref(), Some("chatcmpl-123"));
+        assert_eq!(response.provider_status, Ph1dProviderStatus::Ok);
+        assert!(response.provider_latency_ms >= 50);
+        assert!(response
+            .normalized_output_json
+            .as_deref()
+            .is_some_and(|json| json.contains("\"response_text\":\"Paris.\"")));
+    }
+
+    #[test]
+    fn http_provider_times_out_with_retryable_error() {
+        let (endpoint, server) = spawn_server(MockReply {
+            status: 200,
+            body: "{}".to_string(),
+            delay: Duration::from_millis(1_000),
+        });
+        let adapter = HttpPh1dProviderAdapter::new(&endpoint, None).expect("adapter must build");
+        let start = Instant::now();
+        let err = adapter
+            .execute(&llm_interpret_request(200))
+            .expect_err("slow provider must time out");
+        assert!(start.elapsed() < Duration::from_millis(900));
+        assert!(err.retryable);
+        assert_eq!(err.message, "ph1d http provider timeout after 200ms");
+        let _ = server.join();
+    }
+
+    #[test]
+    fn http_provider_maps_429_to_retryable_and_400_to_terminal() {
+        let (endpoint, server) = spawn_server(MockReply {
+            status: 429,
+            body: r#"{"error":{"type":"rate_limit","code":"rate_limit_exceeded","message":"slow down"}}"#
+                .to_string(),
+            delay: Duration::ZERO,
+        });
+        let adapter = HttpPh1dProviderAdapter::new(&endpoint, None).expect("adapter must build");
+        let err = adapter
+            .execute(&llm_interpret_request(5_000))
+            .expect_err("429 must fail");
+        let _ = server.join();
+        assert!(err.retryable);
+        assert!(err.message.contains("http_status=429"));
+        assert!(err.message.contains("error_code=rate_limit_exceeded"));
+
+        let (endpoint, server) = spawn_server(MockReply {
+            status: 400,
+            body: r#"{"error":{"type":"invalid_request_error","message":"bad model"}}"#.to_string(),
+            delay: Duration::ZERO,
+        });
+        let adapter = HttpPh1dProviderAdapter::new(&endpoint, None).expect("adapter must build");
+        let err = adapter
+            .execute(&llm_interpret_request(5_000))
+            .expect_err("400 must fail");
+        let _ = server.join();
+        assert!(!err.retryable);
+        assert!(err.message.contains("http_status=400"));
+    }
+}
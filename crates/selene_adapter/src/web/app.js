@@ -1,6 +1,6 @@
 "use strict";
 
-const CHECK_ORDER = ["VOICE", "WAKE", "SYNC", "STT", "TTS", "DELIVERY", "BUILDER", "MEMORY"];
+const CHECK_ORDER = ["VOICE", "WAKE", "SYNC", "STT", "TTS", "DELIVERY", "BUILDER", "PROVIDER_LATENCY", "MEMORY"];
 const UI_STATE_CLASSES = [
   "state-unknown",
   "state-loading",
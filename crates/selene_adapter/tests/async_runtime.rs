@@ -0,0 +1,136 @@
+#![forbid(unsafe_code)]
+
+use std::sync::{Arc, Mutex};
+
+use selene_adapter::async_runtime::AsyncAdapterRuntime;
+use selene_adapter::desktop_mic_producer::synthetic_capture_ref_for_tests;
+use selene_adapter::{AdapterConfig, AdapterRuntime, VoiceTurnAdapterRequest};
+use selene_storage::ph1f::Ph1fStore;
+
+fn in_memory_runtime() -> AsyncAdapterRuntime {
+    let runtime = AdapterRuntime::from_config(
+        AdapterConfig {
+            journal_path: None,
+            auto_builder_enabled: false,
+            ..AdapterConfig::from_env()
+        },
+        Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+    )
+    .expect("in-memory runtime must construct");
+    AsyncAdapterRuntime::new(Arc::new(Mutex::new(runtime)))
+}
+
+fn turn_request(correlation_id: u64) -> VoiceTurnAdapterRequest {
+    let now_ns = 2_000_000_000 + correlation_id;
+    VoiceTurnAdapterRequest {
+        correlation_id,
+        turn_id: correlation_id,
+        device_turn_sequence: None,
+        app_platform: "DESKTOP".to_string(),
+        platform_version: None,
+        device_class: None,
+        runtime_client_version: None,
+        hardware_capability_profile: None,
+        network_profile: None,
+        claimed_capabilities: None,
+        integrity_status: None,
+        attestation_ref: None,
+        trigger: "EXPLICIT".to_string(),
+        actor_user_id: format!("tenant_1:async_actor_{correlation_id}"),
+        tenant_id: Some("tenant_1".to_string()),
+        device_id: Some(format!("async_device_{correlation_id}")),
+        now_ns: Some(now_ns),
+        thread_key: None,
+        project_id: None,
+        pinned_context_refs: None,
+        thread_policy_flags: None,
+        user_text_partial: None,
+        user_text_final: Some("What time is it?".to_string()),
+        selene_text_partial: None,
+        selene_text_final: None,
+        audio_capture_ref: Some(synthetic_capture_ref_for_tests(now_ns)),
+        visual_input_ref: None,
+        provenance_mode: None,
+        user_text_part_seq: None,
+        model_override: None,
+        pre_fetched_tool_response: None,
+        language_hint: None,
+        language_hint_confidence: None,
+        request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn async_runtime_completes_concurrent_turns_and_worker_passes() {
+    let runtime = in_memory_runtime();
+    let turns = (1..=8u64)
+        .map(|correlation_id| {
+            let runtime = runtime.clone();
+            tokio::spawn(async move {
+                let response = runtime.run_voice_turn(turn_request(correlation_id)).await;
+                (correlation_id, response)
+            })
+        })
+        .collect::<Vec<_>>();
+    let worker_pass = {
+        let runtime = runtime.clone();
+        tokio::spawn(async move {
+            runtime
+                .run_device_artifact_sync_worker_pass(Some(3_000_000_000))
+                .await
+        })
+    };
+
+    for turn in turns {
+        let (correlation_id, response) = turn.await.expect("turn task must not panic");
+        let response =
+            response.unwrap_or_else(|err| panic!("turn {correlation_id} must complete: {err}"));
+        assert_eq!(response.turn_id, Some(correlation_id));
+    }
+    worker_pass
+        .await
+        .expect("worker pass task must not panic")
+        .expect("worker pass must complete");
+
+    let health = runtime
+        .runtime()
+        .lock()
+        .expect("runtime lock must not be poisoned")
+        .health_report(Some(3_000_000_001))
+        .expect("health report must build");
+    assert_eq!(health.sync.worker.last_pass_at_ns, Some(3_000_000_000));
+}
+
+// The reactor thread stays free while a turn holds the runtime lock on the blocking pool.
+#[tokio::test(flavor = "current_thread")]
+async fn async_runtime_keeps_the_reactor_free_while_a_turn_waits_for_the_lock() {
+    let runtime = in_memory_runtime();
+    let shared = runtime.runtime();
+    let (held_tx, held_rx) = std::sync::mpsc::channel();
+    let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+    let holder = std::thread::spawn(move || {
+        let _held = shared.lock().expect("runtime lock must not be poisoned");
+        held_tx.send(()).expect("test must wait for the lock");
+        release_rx.recv().expect("test must release the lock");
+    });
+    held_rx
+        .recv()
+        .expect("holder thread must take the runtime lock");
+    let turn = tokio::spawn({
+        let runtime = runtime.clone();
+        async move { runtime.run_voice_turn(turn_request(21)).await }
+    });
+
+    for _ in 0..3 {
+        tokio::task::yield_now().await;
+    }
+    assert!(!turn.is_finished());
+    release_tx.send(()).expect("holder thread must be waiting");
+
+    let response = turn
+        .await
+        .expect("turn task must not panic")
+        .expect("turn must complete once the lock is released");
+    assert_eq!(response.turn_id, Some(21));
+    holder.join().expect("holder thread must not panic");
+}
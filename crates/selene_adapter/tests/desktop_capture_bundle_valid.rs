@@ -32,6 +32,13 @@ fn base_request_with_capture() -> VoiceTurnAdapterRequest {
         selene_text_final: None,
         audio_capture_ref: Some(synthetic_capture_ref_for_tests(2_000_000_000)),
         visual_input_ref: None,
+        provenance_mode: None,
+        user_text_part_seq: None,
+        model_override: None,
+        pre_fetched_tool_response: None,
+        language_hint: None,
+        language_hint_confidence: None,
+        request_schema_version: VoiceTurnAdapterRequest::SCHEMA_VERSION,
     }
 }
 
@@ -0,0 +1,104 @@
+#![forbid(unsafe_code)]
+
+use std::sync::{Arc, Mutex};
+
+use selene_adapter::grpc_api::voice_ingress_client::VoiceIngressClient;
+use selene_adapter::grpc_api::voice_ingress_server::VoiceIngressServer;
+use selene_adapter::grpc_api::UiHealthReportQueryRequest;
+use selene_adapter::grpc_service::GrpcVoiceIngress;
+use selene_adapter::{AdapterConfig, AdapterRuntime};
+use selene_storage::ph1f::Ph1fStore;
+use tonic::transport::server::TcpIncoming;
+use tonic::transport::Server;
+
+fn in_memory_runtime() -> AdapterRuntime {
+    AdapterRuntime::from_config(
+        AdapterConfig {
+            journal_path: None,
+            auto_builder_enabled: false,
+            ..AdapterConfig::from_env()
+        },
+        Arc::new(Mutex::new(Ph1fStore::new_in_memory())),
+    )
+    .expect("in-memory runtime must construct")
+}
+
+fn report_query_request(display_target: &str) -> UiHealthReportQueryRequest {
+    UiHealthReportQueryRequest {
+        correlation_id: 10_001,
+        turn_id: 20_001,
+        tenant_id: "tenant_a".to_string(),
+        viewer_user_id: "viewer_01".to_string(),
+        report_kind: "UNRESOLVED_ESCALATED".to_string(),
+        from_utc_ns: 1,
+        to_utc_ns: 5_000_000_000,
+        engine_owner_filter: String::new(),
+        company_scope: "TENANT_ONLY".to_string(),
+        company_ids: Vec::new(),
+        country_codes: Vec::new(),
+        escalated_only: false,
+        unresolved_only: false,
+        display_target: display_target.to_string(),
+        page_action: "FIRST".to_string(),
+        page_cursor: String::new(),
+        report_context_id: String::new(),
+        page_size: 20,
+    }
+}
+
+#[tokio::test]
+async fn grpc_health_report_query_round_trips_clarify_and_ok_replies() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+        .await
+        .expect("test listener must bind");
+    let addr = listener
+        .local_addr()
+        .expect("listener must have an address");
+    let incoming =
+        TcpIncoming::from_listener(listener, true, None).expect("listener must accept streams");
+    let service = GrpcVoiceIngress::new(Arc::new(Mutex::new(in_memory_runtime())));
+    let server = tokio::spawn(
+        Server::builder()
+            .add_service(VoiceIngressServer::new(service))
+            .serve_with_incoming(incoming),
+    );
+    let mut client = VoiceIngressClient::connect(format!("http://{addr}"))
+        .await
+        .expect("client must connect");
+
+    // A missing display target is a clarify outcome, not an RPC error.
+    let clarify = client
+        .ui_health_report_query(report_query_request(""))
+        .await
+        .expect("clarify must come back as a reply")
+        .into_inner();
+    assert!(!clarify.reason_code.is_empty());
+    assert!(!clarify.requires_clarification.is_empty());
+    assert!(clarify.display_target_applied.is_empty());
+
+    let ok = client
+        .ui_health_report_query(report_query_request("desktop"))
+        .await
+        .expect("report query must succeed")
+        .into_inner();
+    assert_eq!(ok.status, "ok");
+    assert!(!ok.reason_code.is_empty());
+    assert!(ok.requires_clarification.is_empty());
+    assert_eq!(ok.display_target_applied, "desktop");
+    assert_ne!(ok.reason_code, clarify.reason_code);
+    assert!(!ok.report_context_id.is_empty());
+    let paging = ok.paging.expect("ok reply must carry paging");
+    assert!(!paging.has_prev);
+    assert_eq!(paging.has_next, !paging.next_cursor.is_empty());
+
+    // The remembered display target fills in the next query that leaves it out.
+    let remembered = client
+        .ui_health_report_query(report_query_request(""))
+        .await
+        .expect("report query must succeed")
+        .into_inner();
+    assert_eq!(remembered.status, "ok");
+    assert_eq!(remembered.display_target_applied, "desktop");
+
+    server.abort();
+}
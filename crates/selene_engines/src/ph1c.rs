@@ -819,12 +819,36 @@ impl Ph1cRuntime {
         self.run_stream_via_live_provider_adapter_at_ms(req, live, adapter, unix_now_ms())
     }
 
+    /// Like `run_stream_via_live_provider_adapter`, but calls `on_partial` with each partial
+    /// chunk as soon as it is committed to the stream, before the final response is built.
+    /// The finalized chunk is not passed to `on_partial`; it arrives as the commit's response.
+    pub fn run_stream_via_live_provider_adapter_with_partials<A: Ph1dProviderAdapter>(
+        &self,
+        req: &Ph1cRequest,
+        live: &Ph1cLiveProviderContext,
+        adapter: &A,
+        on_partial: &mut dyn FnMut(&str),
+    ) -> Ph1cStreamCommit {
+        self.run_stream_with_partials_at_ms(req, live, adapter, unix_now_ms(), on_partial)
+    }
+
     fn run_stream_via_live_provider_adapter_at_ms<A: Ph1dProviderAdapter>(
         &self,
         req: &Ph1cRequest,
         live: &Ph1cLiveProviderContext,
         adapter: &A,
         now_ms: u64,
+    ) -> Ph1cStreamCommit {
+        self.run_stream_with_partials_at_ms(req, live, adapter, now_ms, &mut |_| {})
+    }
+
+    fn run_stream_with_partials_at_ms<A: Ph1dProviderAdapter>(
+        &self,
+        req: &Ph1cRequest,
+        live: &Ph1cLiveProviderContext,
+        adapter: &A,
+        now_ms: u64,
+        on_partial: &mut dyn FnMut(&str),
     ) -> Ph1cStreamCommit {
         if req.validate().is_err() || live.validate().is_err() {
             let reason = reason_codes::STT_FAIL_POLICY_RESTRICTED;
@@ -949,6 +973,10 @@ impl Ph1cRuntime {
                     }
                 };
 
+                if !frame.finalized {
+                    on_partial(&frame.partial.text_chunk);
+                }
+
                 if frame.finalized {
                     let response = self.run(req, &[frame.attempt]);
                     return Ph1cStreamCommit {
@@ -3501,6 +3529,67 @@ mod tests {
         }
     }
 
+    #[test]
+    fn at_c_5h_step4_streaming_partials_reach_callback_in_order_before_final() {
+        let req = req_with_duration(1600);
+        let mut cfg = Ph1cConfig::mvp_desktop_v1();
+        cfg.max_retries_per_provider = 0;
+        cfg.stream_max_revisions = 6;
+        cfg.stream_low_latency_confidence_min = 0.995;
+        cfg.stream_low_latency_min_chars = 20;
+        let rt = Ph1cRuntime::new(cfg);
+        let mut live =
+            Ph1cLiveProviderContext::mvp_openai_google_v1(2204, 3204, "tenant_a".to_string());
+        live.retry_budget = 0;
+
+        let adapter = ScriptedAdapter::new(vec![
+            AdapterAction::OkSttStream {
+                text: "set reminder",
+                language: "en",
+                confidence_bp: 9_100,
+                stable: false,
+                latency_ms: 60,
+                revision_id: 1,
+                finalized: false,
+            },
+            AdapterAction::OkSttStream {
+                text: "set reminder for tomorrow",
+                language: "en",
+                confidence_bp: 9_300,
+                stable: true,
+                latency_ms: 61,
+                revision_id: 2,
+                finalized: false,
+            },
+            AdapterAction::OkSttStream {
+                text: "set reminder for tomorrow at noon",
+                language: "en",
+                confidence_bp: 9_600,
+                stable: true,
+                latency_ms: 62,
+                revision_id: 3,
+                finalized: true,
+            },
+        ]);
+        let mut events = Vec::new();
+        let commit =
+            rt.run_stream_with_partials_at_ms(&req, &live, &adapter, 43_000, &mut |chunk| {
+                events.push(format!("partial:{chunk}"))
+            });
+        match commit.response {
+            Ph1cResponse::TranscriptOk(ok) => events.push(format!("final:{}", ok.transcript_text)),
+            other => panic!("expected finalized transcript_ok, got: {other:?}"),
+        }
+        assert_eq!(
+            events,
+            vec![
+                "partial:set reminder",
+                "partial:set reminder for tomorrow",
+                "final:set reminder for tomorrow at noon",
+            ]
+        );
+    }
+
     #[test]
     fn at_c_5h_step4_streaming_revision_gap_fails_closed() {
         let req = req_with_duration(1400);
@@ -1,6 +1,6 @@
 #![forbid(unsafe_code)]
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use selene_kernel_contracts::ph1c::Ph1kToPh1cHandoff;
 use selene_kernel_contracts::ph1feedback::{
@@ -837,6 +837,94 @@ impl InterruptPhraseMatcher {
         Self { profiles }
     }
 
+    /// Built-in phrases extended with extra per-locale phrases, such as a deployment lexicon.
+    /// Extras only add: a locale the built-ins already cover keeps its phrases and ids, and a
+    /// new locale gets its own set. New ids follow locale order, then list order.
+    pub fn built_in_with_extra_phrases(
+        extra: &BTreeMap<String, Vec<String>>,
+    ) -> Result<Self, ContractViolation> {
+        let mut matcher = Self::built_in();
+        let policy_profile_id =
+            InterruptPolicyProfileId::new(PH1K_INTERRUPT_POLICY_PROFILE_ID_DEFAULT)
+                .expect("default interrupt policy profile id must be valid");
+        let profile = matcher
+            .profiles
+            .get_mut(&policy_profile_id)
+            .expect("built-in interrupt policy profile must exist");
+        let mut next_phrase_id = profile
+            .by_locale
+            .values()
+            .flat_map(|by_phrase| by_phrase.values())
+            .map(|phrase_id| phrase_id.0)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(1);
+        for (locale, phrases) in extra {
+            let requested = InterruptLocaleTag::new(locale.trim())?;
+            let locale_tag = profile
+                .by_locale
+                .keys()
+                .find(|known| same_interrupt_locale(known, &requested))
+                .cloned()
+                .unwrap_or(requested);
+            let by_phrase = profile.by_locale.entry(locale_tag.clone()).or_default();
+            for phrase in phrases {
+                let normalized = normalize_interrupt_phrase_for_locale(&locale_tag, phrase)?;
+                by_phrase.entry(normalized).or_insert_with(|| {
+                    let phrase_id = InterruptPhraseId(next_phrase_id);
+                    next_phrase_id = next_phrase_id.saturating_add(1);
+                    phrase_id
+                });
+            }
+        }
+        Ok(matcher)
+    }
+
+    /// Lets locales without phrases of their own borrow another locale's set. Each entry maps a
+    /// locale to fallbacks tried in order, such as `pt-BR` -> `pt`, `es`, `en`. A fallback
+    /// matches a phrase-bearing locale by exact tag, else by language subtag. Only locales that
+    /// had phrases before this call can lend, and a locale that has phrases keeps them.
+    pub fn with_locale_fallbacks(
+        mut self,
+        fallbacks: &BTreeMap<String, Vec<String>>,
+    ) -> Result<Self, ContractViolation> {
+        let policy_profile_id =
+            InterruptPolicyProfileId::new(PH1K_INTERRUPT_POLICY_PROFILE_ID_DEFAULT)
+                .expect("default interrupt policy profile id must be valid");
+        let profile = self
+            .profiles
+            .get_mut(&policy_profile_id)
+            .expect("built-in interrupt policy profile must exist");
+        let lenders = profile.by_locale.clone();
+        for (locale, chain) in fallbacks {
+            let requested = InterruptLocaleTag::new(locale.trim())?;
+            if lenders
+                .keys()
+                .any(|known| same_interrupt_locale(known, &requested))
+            {
+                continue;
+            }
+            let mut lender = None;
+            for fallback in chain {
+                let wanted = InterruptLocaleTag::new(fallback.trim())?;
+                lender = interrupt_fallback_lender(&lenders, &wanted);
+                if lender.is_some() {
+                    break;
+                }
+            }
+            let Some(lender) = lender else {
+                continue;
+            };
+            let mut by_phrase = HashMap::new();
+            for (phrase, phrase_id) in &lenders[&lender] {
+                let normalized = normalize_interrupt_phrase_for_locale(&requested, phrase)?;
+                by_phrase.insert(normalized, *phrase_id);
+            }
+            profile.by_locale.insert(requested, by_phrase);
+        }
+        Ok(self)
+    }
+
     pub fn default_policy_binding(&self) -> InterruptLexiconPolicyBinding {
         InterruptLexiconPolicyBinding::v1(
             InterruptPolicyProfileId::new(PH1K_INTERRUPT_POLICY_PROFILE_ID_DEFAULT)
@@ -1584,6 +1672,35 @@ fn classify_risk_context_class(
     InterruptRiskContextClass::Low
 }
 
+fn same_interrupt_locale(lhs: &InterruptLocaleTag, rhs: &InterruptLocaleTag) -> bool {
+    lhs.as_str()
+        .replace('_', "-")
+        .eq_ignore_ascii_case(&rhs.as_str().replace('_', "-"))
+}
+
+fn interrupt_language_subtag(tag: &InterruptLocaleTag) -> String {
+    let key = tag.as_str().replace('_', "-").to_ascii_lowercase();
+    key.split('-').next().unwrap_or_default().to_string()
+}
+
+// Exact tag first; otherwise the alphabetically first locale with the same language subtag.
+fn interrupt_fallback_lender(
+    lenders: &HashMap<InterruptLocaleTag, HashMap<String, InterruptPhraseId>>,
+    wanted: &InterruptLocaleTag,
+) -> Option<InterruptLocaleTag> {
+    lenders
+        .keys()
+        .find(|known| same_interrupt_locale(known, wanted))
+        .or_else(|| {
+            let language = interrupt_language_subtag(wanted);
+            lenders
+                .keys()
+                .filter(|known| interrupt_language_subtag(known) == language)
+                .min_by(|a, b| a.as_str().cmp(b.as_str()))
+        })
+        .cloned()
+}
+
 fn built_in_interrupt_phrases_by_locale() -> Vec<(&'static str, Vec<&'static str>)> {
     vec![
         (
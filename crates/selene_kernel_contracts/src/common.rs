@@ -16,6 +16,7 @@ pub struct MonotonicTimeNs(pub u64);
 pub struct ReasonCodeId(pub u32);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SessionState {
     Closed,
     Open,
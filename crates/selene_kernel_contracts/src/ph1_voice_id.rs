@@ -35,6 +35,7 @@ impl Ph1VoiceIdImplementation {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpeakerId(String);
 
 impl SpeakerId {
@@ -451,6 +452,7 @@ impl Validate for Ph1VoiceIdRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VoiceEmbeddingCaptureRef {
     pub embedding_ref: String,
     pub embedding_model_id: String,
@@ -878,6 +880,7 @@ pub enum VoiceIdSimulationType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VoiceEnrollStatus {
     InProgress,
     Locked,
@@ -886,6 +889,7 @@ pub enum VoiceEnrollStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VoiceSampleResult {
     Pass,
     Fail,
@@ -43,12 +43,14 @@ pub enum AccessApprovalPrimitive {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessApReviewChannel {
     PhoneDesktop,
     ReadOutLoud,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessApRuleReviewAction {
     Agree,
     Disagree,
@@ -59,6 +61,7 @@ pub enum AccessApRuleReviewAction {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessApAuthoringConfirmationState {
     NeedsChannelChoice,
     ReviewInProgress,
@@ -60,6 +60,7 @@ fn validate_string_list_unique(
 macro_rules! string_contract_type {
     ($name:ident, $field:literal, $max_len:expr) => {
         #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
         pub struct $name(pub String);
 
         impl Validate for $name {
@@ -114,6 +115,7 @@ string_contract_type!(
 );
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArtifactVersion(pub u32);
 
 impl Validate for ArtifactVersion {
@@ -129,6 +131,7 @@ impl Validate for ArtifactVersion {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArtifactScopeType {
     Tenant,
     User,
@@ -136,6 +139,7 @@ pub enum ArtifactScopeType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArtifactType {
     WakePack,
     EmoAffectPack,
@@ -152,6 +156,7 @@ pub enum ArtifactType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArtifactStatus {
     Active,
     RolledBack,
@@ -250,6 +255,7 @@ impl Validate for ArtifactLedgerRowInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArtifactLedgerRow {
     pub schema_version: SchemaVersion,
     pub artifact_id: u64,
@@ -332,6 +338,7 @@ impl Validate for ArtifactLedgerRow {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArtifactTrustRootVersion(pub u32);
 
 impl Validate for ArtifactTrustRootVersion {
@@ -347,6 +354,7 @@ impl Validate for ArtifactTrustRootVersion {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArtifactTrustRootKind {
     RootAuthority,
     DomainAuthority,
@@ -354,6 +362,7 @@ pub enum ArtifactTrustRootKind {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArtifactTrustRootState {
     Draft,
     Active,
@@ -468,6 +477,7 @@ impl Validate for ArtifactTrustRootRegistryRowInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArtifactTrustRootRegistryRow {
     pub schema_version: SchemaVersion,
     pub trust_root_registry_row_id: u64,
@@ -1444,6 +1454,7 @@ impl Validate for TrustSetSnapshot {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArtifactVerificationOutcome {
     VerifiedFresh,
     VerifiedCached,
@@ -1452,6 +1463,7 @@ pub enum ArtifactVerificationOutcome {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArtifactVerificationFailureClass {
     HashMismatch,
     SignatureInvalid,
@@ -1727,6 +1739,7 @@ impl Validate for ArtifactTrustDecisionRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArtifactTrustProofEntry {
     pub proof_entry_ref: ArtifactTrustProofEntryRef,
     pub proof_record_ref: ArtifactTrustProofRecordRef,
@@ -1853,6 +1866,7 @@ impl Validate for ToolCacheRowInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToolCacheRow {
     pub schema_version: SchemaVersion,
     pub cache_id: u64,
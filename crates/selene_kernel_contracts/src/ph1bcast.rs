@@ -66,12 +66,14 @@ fn validate_opt_token(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastSimulationType {
     Draft,
     Commit,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastCapabilityId {
     DraftCreate,
     DeliverCommit,
@@ -99,6 +101,7 @@ impl BcastCapabilityId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BroadcastClassification {
     Simple,
     Priority,
@@ -106,6 +109,7 @@ pub enum BroadcastClassification {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastDeliveryMethod {
     SeleneApp,
     Sms,
@@ -115,12 +119,14 @@ pub enum BcastDeliveryMethod {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastRecipientRegion {
     Global,
     China,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastRecipientState {
     DraftCreated,
     Waiting,
@@ -134,6 +140,7 @@ pub enum BcastRecipientState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastAckStatus {
     Received,
     ActionConfirmed,
@@ -141,6 +148,7 @@ pub enum BcastAckStatus {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BroadcastId(String);
 
 impl BroadcastId {
@@ -162,6 +170,7 @@ impl Validate for BroadcastId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BroadcastRecipientId(String);
 
 impl BroadcastRecipientId {
@@ -183,6 +192,7 @@ impl Validate for BroadcastRecipientId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastDraftCreateRequest {
     pub tenant_id: TenantId,
     pub sender_user_id: UserId,
@@ -222,6 +232,7 @@ impl Validate for BcastDraftCreateRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastDeliverCommitRequest {
     pub tenant_id: TenantId,
     pub sender_user_id: UserId,
@@ -295,6 +306,7 @@ impl Validate for BcastDeliverCommitRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastDeferCommitRequest {
     pub tenant_id: TenantId,
     pub sender_user_id: UserId,
@@ -327,6 +339,7 @@ impl Validate for BcastDeferCommitRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastReminderFiredCommitRequest {
     pub tenant_id: TenantId,
     pub sender_user_id: UserId,
@@ -357,6 +370,7 @@ impl Validate for BcastReminderFiredCommitRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastAckCommitRequest {
     pub tenant_id: TenantId,
     pub recipient_user_id: UserId,
@@ -382,6 +396,7 @@ impl Validate for BcastAckCommitRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastEscalateCommitRequest {
     pub tenant_id: TenantId,
     pub sender_user_id: UserId,
@@ -412,6 +427,7 @@ impl Validate for BcastEscalateCommitRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastExpireCommitRequest {
     pub tenant_id: TenantId,
     pub sender_user_id: UserId,
@@ -436,6 +452,7 @@ impl Validate for BcastExpireCommitRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastCancelCommitRequest {
     pub tenant_id: TenantId,
     pub sender_user_id: UserId,
@@ -460,6 +477,7 @@ impl Validate for BcastCancelCommitRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastRequest {
     DraftCreate(BcastDraftCreateRequest),
     DeliverCommit(BcastDeliverCommitRequest),
@@ -528,6 +546,7 @@ impl Validate for BcastRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1BcastRequest {
     pub schema_version: SchemaVersion,
     pub correlation_id: CorrelationId,
@@ -574,6 +593,7 @@ impl Validate for Ph1BcastRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastDraftCreateResult {
     pub broadcast_id: BroadcastId,
     pub state: BcastRecipientState,
@@ -600,6 +620,7 @@ impl Validate for BcastDraftCreateResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastDeliverCommitResult {
     pub broadcast_id: BroadcastId,
     pub recipient_id: BroadcastRecipientId,
@@ -640,6 +661,7 @@ impl Validate for BcastDeliverCommitResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastDeferCommitResult {
     pub broadcast_id: BroadcastId,
     pub recipient_id: BroadcastRecipientId,
@@ -681,6 +703,7 @@ impl Validate for BcastDeferCommitResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastReminderFiredCommitResult {
     pub broadcast_id: BroadcastId,
     pub recipient_id: BroadcastRecipientId,
@@ -715,6 +738,7 @@ impl Validate for BcastReminderFiredCommitResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastAckCommitResult {
     pub broadcast_id: BroadcastId,
     pub recipient_id: BroadcastRecipientId,
@@ -744,6 +768,7 @@ impl Validate for BcastAckCommitResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastEscalateCommitResult {
     pub broadcast_id: BroadcastId,
     pub recipient_id: BroadcastRecipientId,
@@ -778,6 +803,7 @@ impl Validate for BcastEscalateCommitResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastExpireCommitResult {
     pub broadcast_id: BroadcastId,
     pub state: BcastRecipientState,
@@ -804,6 +830,7 @@ impl Validate for BcastExpireCommitResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastCancelCommitResult {
     pub broadcast_id: BroadcastId,
     pub state: BcastRecipientState,
@@ -830,6 +857,7 @@ impl Validate for BcastCancelCommitResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastOutcome {
     DraftCreate(BcastDraftCreateResult),
     DeliverCommit(BcastDeliverCommitResult),
@@ -857,6 +885,7 @@ impl Validate for BcastOutcome {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1BcastOk {
     pub schema_version: SchemaVersion,
     pub capability_id: BcastCapabilityId,
@@ -917,6 +946,7 @@ impl Validate for Ph1BcastOk {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1BcastRefuse {
     pub schema_version: SchemaVersion,
     pub capability_id: BcastCapabilityId,
@@ -965,6 +995,7 @@ impl Validate for Ph1BcastRefuse {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ph1BcastResponse {
     Ok(Ph1BcastOk),
     Refuse(Ph1BcastRefuse),
@@ -7,6 +7,7 @@ use crate::{ContractViolation, MonotonicTimeNs, ReasonCodeId, SchemaVersion, Val
 pub const PH1BUILDER_CONTRACT_VERSION: SchemaVersion = SchemaVersion(1);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BuilderChangeClass {
     ClassA,
     ClassB,
@@ -14,6 +15,7 @@ pub enum BuilderChangeClass {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BuilderProposalStatus {
     Draft,
     Validated,
@@ -23,6 +25,7 @@ pub enum BuilderProposalStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BuilderValidationRunStatus {
     Running,
     Passed,
@@ -30,6 +33,7 @@ pub enum BuilderValidationRunStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BuilderValidationGateId {
     BldG1,
     BldG2,
@@ -61,6 +65,7 @@ impl BuilderValidationGateId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderSignalWindow {
     pub schema_version: SchemaVersion,
     pub start_at: MonotonicTimeNs,
@@ -110,6 +115,7 @@ impl Validate for BuilderSignalWindow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderExpectedEffect {
     pub schema_version: SchemaVersion,
     pub latency_p95_delta_bp: i16,
@@ -166,6 +172,7 @@ impl Validate for BuilderExpectedEffect {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderLearningContext {
     pub schema_version: SchemaVersion,
     pub learning_report_id: String,
@@ -251,6 +258,7 @@ impl Validate for BuilderLearningContext {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderPatchProposal {
     pub schema_version: SchemaVersion,
     pub proposal_id: String,
@@ -373,6 +381,7 @@ impl Validate for BuilderPatchProposal {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderValidationRun {
     pub schema_version: SchemaVersion,
     pub run_id: String,
@@ -479,6 +488,7 @@ impl Validate for BuilderValidationRun {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderValidationGateResult {
     pub schema_version: SchemaVersion,
     pub run_id: String,
@@ -552,6 +562,7 @@ impl Validate for BuilderValidationGateResult {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BuilderApprovalStateStatus {
     Pending,
     Approved,
@@ -559,6 +570,7 @@ pub enum BuilderApprovalStateStatus {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderApprovalState {
     pub schema_version: SchemaVersion,
     pub approval_state_id: String,
@@ -729,6 +741,7 @@ impl Validate for BuilderApprovalState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BuilderReleaseStage {
     Staging,
     Canary,
@@ -739,6 +752,7 @@ pub enum BuilderReleaseStage {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BuilderReleaseStateStatus {
     Pending,
     Active,
@@ -748,6 +762,7 @@ pub enum BuilderReleaseStateStatus {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderReleaseState {
     pub schema_version: SchemaVersion,
     pub release_state_id: String,
@@ -890,12 +905,14 @@ impl Validate for BuilderReleaseState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BuilderPostDeployDecisionAction {
     Accept,
     Revert,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderMetricsSnapshot {
     pub schema_version: SchemaVersion,
     pub latency_p95_ms: u32,
@@ -963,6 +980,7 @@ impl Validate for BuilderMetricsSnapshot {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderPostDeployJudgeResult {
     pub schema_version: SchemaVersion,
     pub judge_result_id: String,
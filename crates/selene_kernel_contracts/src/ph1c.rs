@@ -40,6 +40,7 @@ impl Validate for SessionStateRef {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LanguageTag(String);
 
 impl LanguageTag {
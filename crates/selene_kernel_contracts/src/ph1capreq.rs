@@ -61,6 +61,7 @@ fn validate_opt_id(
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CapreqId(String);
 
 impl CapreqId {
@@ -82,6 +83,7 @@ impl Validate for CapreqId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CapabilityRequestAction {
     CreateDraft,
     SubmitForApproval,
@@ -92,6 +94,7 @@ pub enum CapabilityRequestAction {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CapabilityRequestStatus {
     Draft,
     PendingApproval,
@@ -187,6 +190,7 @@ impl Validate for CapabilityRequestLedgerEventInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CapabilityRequestLedgerEvent {
     pub schema_version: SchemaVersion,
     pub capreq_event_id: u64,
@@ -269,6 +273,7 @@ impl Validate for CapabilityRequestLedgerEvent {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CapabilityRequestCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
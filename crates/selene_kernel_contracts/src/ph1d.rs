@@ -76,6 +76,7 @@ impl Validate for PolicyContextRef {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ph1dProviderTask {
     LlmInterpret,
     OcrTextExtract,
@@ -50,12 +50,14 @@ fn validate_opt_token(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeliverySimulationType {
     Draft,
     Commit,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeliveryCapabilityId {
     Send,
     Status,
@@ -75,6 +77,7 @@ impl DeliveryCapabilityId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeliveryChannel {
     Sms,
     Email,
@@ -84,6 +87,7 @@ pub enum DeliveryChannel {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeliveryStatus {
     Sent,
     Pending,
@@ -93,6 +97,7 @@ pub enum DeliveryStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeliveryProviderHealthState {
     Healthy,
     Degraded,
@@ -100,6 +105,7 @@ pub enum DeliveryProviderHealthState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeliveryLatencyBucket {
     Low,
     Medium,
@@ -107,6 +113,7 @@ pub enum DeliveryLatencyBucket {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliverySendRequest {
     pub tenant_id: TenantId,
     pub message_id: String,
@@ -136,6 +143,7 @@ impl Validate for DeliverySendRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliveryStatusRequest {
     pub delivery_attempt_id: String,
     pub provider_ref: String,
@@ -160,6 +168,7 @@ impl Validate for DeliveryStatusRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliveryCancelRequest {
     pub delivery_attempt_id: String,
     pub provider_ref: String,
@@ -190,6 +199,7 @@ impl Validate for DeliveryCancelRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliveryProviderHealthCheckRequest {
     pub provider_ref: String,
     pub region_hint: Option<String>,
@@ -212,6 +222,7 @@ impl Validate for DeliveryProviderHealthCheckRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeliveryRequest {
     Send(DeliverySendRequest),
     Status(DeliveryStatusRequest),
@@ -260,6 +271,7 @@ impl Validate for DeliveryRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1DeliveryRequest {
     pub schema_version: SchemaVersion,
     pub correlation_id: CorrelationId,
@@ -346,6 +358,7 @@ impl Validate for Ph1DeliveryRequest {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliverySendResult {
     pub delivery_attempt_id: String,
     pub delivery_proof_ref: String,
@@ -382,6 +395,7 @@ impl Validate for DeliverySendResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliveryStatusResult {
     pub normalized_status: DeliveryStatus,
     pub provider_status_raw: String,
@@ -406,6 +420,7 @@ impl Validate for DeliveryStatusResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliveryCancelResult {
     pub canceled: bool,
     pub reason_code: ReasonCodeId,
@@ -424,6 +439,7 @@ impl Validate for DeliveryCancelResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliveryProviderHealthCheckResult {
     pub provider_health_state: DeliveryProviderHealthState,
     pub latency_bucket: DeliveryLatencyBucket,
@@ -443,6 +459,7 @@ impl Validate for DeliveryProviderHealthCheckResult {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeliveryOutcome {
     Send(DeliverySendResult),
     Status(DeliveryStatusResult),
@@ -473,6 +490,7 @@ impl Validate for DeliveryOutcome {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1DeliveryOk {
     pub capability_id: DeliveryCapabilityId,
     pub simulation_id: String,
@@ -522,6 +540,7 @@ impl Validate for Ph1DeliveryOk {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1DeliveryRefuse {
     pub capability_id: DeliveryCapabilityId,
     pub simulation_id: String,
@@ -562,6 +581,7 @@ impl Validate for Ph1DeliveryRefuse {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ph1DeliveryResponse {
     Ok(Ph1DeliveryOk),
     Refuse(Ph1DeliveryRefuse),
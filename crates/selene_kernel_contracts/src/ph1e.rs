@@ -6,6 +6,7 @@ use crate::{ContractViolation, ReasonCodeId, SchemaVersion, Validate};
 pub const PH1E_CONTRACT_VERSION: SchemaVersion = SchemaVersion(1);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ToolRequestId(pub u64);
 
 impl Validate for ToolRequestId {
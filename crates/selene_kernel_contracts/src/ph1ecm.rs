@@ -33,6 +33,7 @@ fn validate_opt_id(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CapabilityMapVersion(pub u32);
 
 impl Validate for CapabilityMapVersion {
@@ -48,6 +49,7 @@ impl Validate for CapabilityMapVersion {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EngineId(String);
 
 impl EngineId {
@@ -69,6 +71,7 @@ impl Validate for EngineId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CapabilityId(String);
 
 impl CapabilityId {
@@ -90,6 +93,7 @@ impl Validate for CapabilityId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CapabilityMapStatus {
     Draft,
     Active,
@@ -98,6 +102,7 @@ pub enum CapabilityMapStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AllowedCallers {
     SeleneOsOnly,
     SimulationOnly,
@@ -105,6 +110,7 @@ pub enum AllowedCallers {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SideEffectsMode {
     None,
     Declared,
@@ -223,6 +229,7 @@ impl Validate for EngineCapabilityMapEventInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EngineCapabilityMapEvent {
     pub schema_version: SchemaVersion,
     pub engine_capability_map_event_id: u64,
@@ -331,6 +338,7 @@ impl Validate for EngineCapabilityMapEvent {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EngineCapabilityMapCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
@@ -108,6 +108,7 @@ impl Validate for ConversationTurnId {
 pub enum ConversationRole {
     User,
     Selene,
+    System,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -116,6 +117,8 @@ pub enum ConversationSource {
     TypedText,
     SeleneOutput,
     Tombstone,
+    SystemNotice,
+    ToolResult,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -889,7 +892,9 @@ impl InternalHistoryEvidenceInput {
             ConversationSource::VoiceTranscript => InternalHistoryModality::Voice,
             ConversationSource::TypedText => InternalHistoryModality::Typed,
             ConversationSource::SeleneOutput => InternalHistoryModality::System,
-            ConversationSource::Tombstone => InternalHistoryModality::System,
+            ConversationSource::Tombstone
+            | ConversationSource::SystemNotice
+            | ConversationSource::ToolResult => InternalHistoryModality::System,
         };
         let speaker = match record.source {
             ConversationSource::TypedText => {
@@ -898,9 +903,10 @@ impl InternalHistoryEvidenceInput {
             ConversationSource::VoiceTranscript => {
                 SpeakerEvidenceRefs::voice_unknown(record.user_id.clone(), record.device_id.clone())
             }
-            ConversationSource::SeleneOutput | ConversationSource::Tombstone => {
-                SpeakerEvidenceRefs::none()
-            }
+            ConversationSource::SeleneOutput
+            | ConversationSource::Tombstone
+            | ConversationSource::SystemNotice
+            | ConversationSource::ToolResult => SpeakerEvidenceRefs::none(),
         };
         let input_refs = match record.source {
             ConversationSource::VoiceTranscript => {
@@ -909,9 +915,10 @@ impl InternalHistoryEvidenceInput {
             ConversationSource::TypedText => {
                 InputTranscriptEvidenceRefs::typed_committed(record.text_hash.clone())
             }
-            ConversationSource::SeleneOutput | ConversationSource::Tombstone => {
-                InputTranscriptEvidenceRefs::none()
-            }
+            ConversationSource::SeleneOutput
+            | ConversationSource::Tombstone
+            | ConversationSource::SystemNotice
+            | ConversationSource::ToolResult => InputTranscriptEvidenceRefs::none(),
         };
         let response_refs = match record.source {
             ConversationSource::SeleneOutput => {
@@ -919,7 +926,9 @@ impl InternalHistoryEvidenceInput {
             }
             ConversationSource::VoiceTranscript
             | ConversationSource::TypedText
-            | ConversationSource::Tombstone => ResponseSpokenEvidenceRefs::none(),
+            | ConversationSource::Tombstone
+            | ConversationSource::SystemNotice
+            | ConversationSource::ToolResult => ResponseSpokenEvidenceRefs::none(),
         };
         let mut refs = InternalHistoryEvidenceRefs::none();
         refs.replay_integrity_refs.push(format!(
@@ -1320,6 +1329,22 @@ impl Validate for ConversationTurnInput {
                     });
                 }
             }
+            ConversationSource::SystemNotice | ConversationSource::ToolResult => {
+                if self.role != ConversationRole::System {
+                    return Err(ContractViolation::InvalidValue {
+                        field: "conversation_turn_input.role",
+                        reason: "must be SYSTEM for system_notice/tool_result",
+                    });
+                }
+                if self.tombstone_of_conversation_turn_id.is_some()
+                    || self.tombstone_reason_code.is_some()
+                {
+                    return Err(ContractViolation::InvalidValue {
+                        field: "conversation_turn_input",
+                        reason: "tombstone fields must be None unless source=Tombstone",
+                    });
+                }
+            }
             ConversationSource::Tombstone => {
                 if self.role != ConversationRole::Selene {
                     return Err(ContractViolation::InvalidValue {
@@ -1496,6 +1521,26 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn at_f_contract_02b_system_sources_require_system_role() {
+        let mut input = base_input();
+        input.source = ConversationSource::ToolResult;
+        assert!(matches!(
+            input.validate(),
+            Err(ContractViolation::InvalidValue {
+                field: "conversation_turn_input.role",
+                ..
+            })
+        ));
+
+        input.role = ConversationRole::System;
+        assert!(input.validate().is_ok());
+        input.source = ConversationSource::SystemNotice;
+        assert!(input.validate().is_ok());
+        input.source = ConversationSource::TypedText;
+        assert!(input.validate().is_err());
+    }
+
     #[test]
     fn at_f_contract_03_record_from_input_roundtrip_is_valid() {
         let input = base_input();
@@ -23,6 +23,7 @@ impl FeedbackCapabilityId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FeedbackEventType {
     SttReject,
     SttRetry,
@@ -48,6 +49,7 @@ pub enum FeedbackEventType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FeedbackPathType {
     Defect,
     Improvement,
@@ -74,6 +76,7 @@ pub enum FeedbackSignalTarget {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FeedbackConfidenceBucket {
     High,
     Med,
@@ -82,6 +85,7 @@ pub enum FeedbackConfidenceBucket {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FeedbackToolStatus {
     Ok,
     Fail,
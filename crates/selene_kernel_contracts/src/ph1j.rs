@@ -160,6 +160,7 @@ impl Validate for TurnId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AuditEventId(pub u64);
 
 impl Validate for AuditEventId {
@@ -276,6 +277,7 @@ impl Validate for DeviceId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AuditEngine {
     Ph1K,
     Ph1W,
@@ -314,6 +316,7 @@ impl Validate for AuditEngine {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AuditEventType {
     GatePass,
     GateFail,
@@ -351,6 +354,7 @@ pub enum AuditEventType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AuditSeverity {
     Info,
     Warn,
@@ -358,6 +362,7 @@ pub enum AuditSeverity {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PayloadKey(String);
 
 fn is_ascii_lower_snake_key(s: &str) -> bool {
@@ -442,6 +447,7 @@ impl Validate for PayloadKey {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PayloadValue(String);
 
 impl PayloadValue {
@@ -486,6 +492,7 @@ impl Validate for PayloadValue {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AuditPayloadMin {
     pub schema_version: SchemaVersion,
     pub entries: BTreeMap<PayloadKey, PayloadValue>,
@@ -541,6 +548,7 @@ impl Validate for AuditPayloadMin {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AuditEvidenceRef {
     pub schema_version: SchemaVersion,
     pub transcript_hash: Option<String>,
@@ -801,6 +809,7 @@ impl Validate for AuditEventInput {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AuditEvent {
     pub schema_version: SchemaVersion,
     pub event_id: AuditEventId,
@@ -929,6 +938,7 @@ impl Validate for AuditEvent {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProofEventId(pub u64);
 
 impl Validate for ProofEventId {
@@ -1110,6 +1120,7 @@ impl ProofRetentionClass {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProofSignerIdentityMetadata {
     pub signer_identity: String,
     pub key_id: String,
@@ -1768,6 +1779,7 @@ impl Validate for ArtifactTrustProofEntryInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ArtifactTrustProofRecordEntry {
     pub linkage: ArtifactTrustProofEntry,
     pub artifact_verification_outcome: ArtifactVerificationOutcome,
@@ -1821,6 +1833,7 @@ impl Validate for ArtifactTrustProofRecordEntry {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CanonicalProofRecord {
     pub proof_schema_version: SchemaVersion,
     pub proof_event_id: ProofEventId,
@@ -2137,6 +2150,7 @@ impl Validate for ProofVerificationResult {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BenchmarkTargetStatus {
     DraftTarget,
     NotApplicableWithReason,
@@ -2158,6 +2172,7 @@ impl BenchmarkTargetStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BenchmarkComparisonOutcome {
     NotRun,
     NotApplicable,
@@ -2179,6 +2194,7 @@ impl BenchmarkComparisonOutcome {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BenchmarkTargetPacket {
     pub schema_version: SchemaVersion,
     pub benchmark_target_id: String,
@@ -2319,6 +2335,7 @@ impl Validate for BenchmarkTargetPacket {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BenchmarkResultPacket {
     pub schema_version: SchemaVersion,
     pub benchmark_result_id: String,
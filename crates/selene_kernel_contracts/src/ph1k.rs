@@ -68,6 +68,7 @@ impl FrameDurationMs {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Confidence(pub f32);
 
 impl Confidence {
@@ -342,6 +343,7 @@ fn expected_payload_bytes(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpeechLikeness(pub f32);
 
 impl SpeechLikeness {
@@ -412,6 +414,7 @@ pub enum DeviceHealth {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeviceRoute {
     BuiltIn,
     Usb,
@@ -572,6 +575,7 @@ impl Validate for TimingStats {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdvancedAudioQualityMetrics {
     pub snr_db: f32,
     pub clipping_ratio: f32,
@@ -732,6 +736,7 @@ impl Validate for DeviceReliabilityScoreInput {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VadDecisionConfidenceBand {
     High,
     Medium,
@@ -935,6 +940,7 @@ pub struct InterruptGates {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterruptGateConfidences {
     pub vad_confidence: Confidence,
     pub speech_likeness: SpeechLikeness,
@@ -944,6 +950,7 @@ pub struct InterruptGateConfidences {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InterruptCandidateConfidenceBand {
     High,
     Medium,
@@ -951,6 +958,7 @@ pub enum InterruptCandidateConfidenceBand {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CaptureQualityClass {
     Clear,
     Guarded,
@@ -959,6 +967,7 @@ pub enum CaptureQualityClass {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum EchoRiskClass {
     Low,
     Elevated,
@@ -966,6 +975,7 @@ pub enum EchoRiskClass {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NetworkStabilityClass {
     Stable,
     Flaky,
@@ -973,6 +983,7 @@ pub enum NetworkStabilityClass {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RecoverabilityClass {
     Fast,
     Guarded,
@@ -981,6 +992,7 @@ pub enum RecoverabilityClass {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DegradationClassBundle {
     pub capture_quality_class: CaptureQualityClass,
     pub echo_risk_class: EchoRiskClass,
@@ -1037,6 +1049,7 @@ impl DegradationClassBundle {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InterruptRiskContextClass {
     Low,
     Guarded,
@@ -1044,6 +1057,7 @@ pub enum InterruptRiskContextClass {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterruptDegradationContext {
     pub capture_degraded: bool,
     pub aec_unstable: bool,
@@ -1053,6 +1067,7 @@ pub struct InterruptDegradationContext {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterruptTimingMarkers {
     pub window_start: MonotonicTimeNs,
     pub window_end: MonotonicTimeNs,
@@ -1071,6 +1086,7 @@ impl Validate for InterruptTimingMarkers {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterruptSpeechWindowMetrics {
     pub voiced_window_ms: u32,
 }
@@ -1094,6 +1110,7 @@ impl Validate for InterruptSpeechWindowMetrics {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InterruptSubjectRelationConfidenceBundle {
     pub lexical_confidence: Confidence,
     pub vad_confidence: Confidence,
@@ -25,6 +25,7 @@ impl LearnCapabilityId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LearnSignalType {
     SttReject,
     UserCorrection,
@@ -66,12 +67,14 @@ pub fn is_wake_learn_signal_type(signal_type: LearnSignalType) -> bool {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WakeLearnTrigger {
     WakeWord,
     Explicit,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LearnArtifactTarget {
     KnowTenantGlossaryPack,
     PronLexiconPack,
@@ -87,6 +90,7 @@ pub enum LearnArtifactTarget {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LearnScope {
     User,
     Tenant,
@@ -40,6 +40,7 @@ fn hash_hex_64(s: &str) -> String {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimulationType {
     Draft,
     Commit,
@@ -47,6 +48,7 @@ pub enum SimulationType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InviteeType {
     Company,
     Customer,
@@ -79,6 +81,7 @@ impl AppPlatform {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LinkStatus {
     DraftCreated,
     Sent,
@@ -91,6 +94,7 @@ pub enum LinkStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DraftStatus {
     DraftCreated,
     DraftReady,
@@ -108,6 +112,7 @@ impl Validate for AppPlatform {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TokenId(String);
 
 impl TokenId {
@@ -148,6 +153,7 @@ impl Validate for TokenId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DraftId(String);
 
 impl DraftId {
@@ -188,6 +194,7 @@ impl Validate for DraftId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrefilledContextRef(String);
 
 impl PrefilledContextRef {
@@ -222,6 +229,7 @@ impl Validate for PrefilledContextRef {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PrefilledContext {
     pub schema_version: SchemaVersion,
     // Keep prefilled onboarding data minimal and policy-safe:
@@ -345,6 +353,7 @@ impl Validate for PrefilledContext {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinkRecord {
     pub schema_version: SchemaVersion,
     pub token_id: TokenId,
@@ -83,6 +83,7 @@ fn validate_memory_text_list(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryLayer {
     Working,
     Micro,
@@ -90,12 +91,14 @@ pub enum MemoryLayer {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemorySensitivityFlag {
     Low,
     Sensitive,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryUsePolicy {
     /// Safe: may be used freely (e.g., preferred name).
     AlwaysUsable,
@@ -108,6 +111,7 @@ pub enum MemoryUsePolicy {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryConsent {
     /// No explicit request/confirmation occurred for this entry.
     NotRequested,
@@ -120,6 +124,7 @@ pub enum MemoryConsent {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryConfidence {
     High,
     Med,
@@ -679,6 +684,7 @@ pub enum MemoryProvenanceTier {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemorySuppressionRuleKind {
     DoNotMention,
     DoNotRepeat,
@@ -686,6 +692,7 @@ pub enum MemorySuppressionRuleKind {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemorySuppressionTargetType {
     ThreadId,
     WorkOrderId,
@@ -693,6 +700,7 @@ pub enum MemorySuppressionTargetType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryGraphNodeKind {
     Entity,
     Project,
@@ -702,6 +710,7 @@ pub enum MemoryGraphNodeKind {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryGraphEdgeKind {
     MentionedWith,
     DependsOn,
@@ -725,6 +734,7 @@ pub enum PendingWorkStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryRetentionMode {
     Default,
     RememberEverything,
@@ -745,6 +755,7 @@ pub enum MemoryResumeAction {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryKey(String);
 
 impl MemoryKey {
@@ -771,6 +782,7 @@ impl MemoryKey {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryValue {
     pub verbatim: String,
     pub normalized: Option<String>,
@@ -820,6 +832,7 @@ impl Validate for MemoryValue {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryProvenance {
     pub schema_version: SchemaVersion,
     pub session_id: Option<SessionId>,
@@ -979,6 +992,7 @@ impl Validate for MemoryCommitDecision {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryLedgerEventKind {
     Stored,
     Updated,
@@ -986,6 +1000,7 @@ pub enum MemoryLedgerEventKind {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryLedgerEvent {
     pub schema_version: SchemaVersion,
     pub kind: MemoryLedgerEventKind,
@@ -1612,6 +1627,7 @@ impl Validate for PendingWorkItem {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemorySuppressionRule {
     pub schema_version: SchemaVersion,
     pub target_type: MemorySuppressionTargetType,
@@ -1713,6 +1729,7 @@ impl Validate for MemorySafeSummaryItem {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryEmotionalThreadState {
     pub schema_version: SchemaVersion,
     pub thread_key: String,
@@ -1793,6 +1810,7 @@ impl Validate for MemoryEmotionalThreadState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryMetricPayload {
     pub schema_version: SchemaVersion,
     pub context_bundle_bytes: u32,
@@ -1864,6 +1882,7 @@ impl Validate for MemoryMetricPayload {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryGraphNodeInput {
     pub schema_version: SchemaVersion,
     pub node_id: String,
@@ -1919,6 +1938,7 @@ impl Validate for MemoryGraphNodeInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryGraphEdgeInput {
     pub schema_version: SchemaVersion,
     pub edge_id: String,
@@ -1993,6 +2013,7 @@ impl Validate for MemoryGraphEdgeInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryThreadDigest {
     pub schema_version: SchemaVersion,
     pub thread_id: String,
@@ -6,6 +6,7 @@ use crate::{ContractViolation, ReasonCodeId, SchemaVersion, Validate};
 pub const PH1N_CONTRACT_VERSION: SchemaVersion = SchemaVersion(1);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OverallConfidence {
     High,
     Med,
@@ -13,6 +14,7 @@ pub enum OverallConfidence {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IntentType {
     CreateCalendarEvent,
     /// Governance/control: update tenant BCAST non-urgent follow-up wait policy.
@@ -59,6 +61,7 @@ pub enum IntentType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FieldKey {
     When,
     Task,
@@ -127,6 +130,7 @@ pub enum FieldKey {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SensitivityLevel {
     Public,
     Private,
@@ -134,6 +138,7 @@ pub enum SensitivityLevel {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AmbiguityFlag {
     ReferenceAmbiguous,
     RecipientAmbiguous,
@@ -143,6 +148,7 @@ pub enum AmbiguityFlag {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum RoutingHint {
     OnboardingStart,
     OnboardingConfirmIdentity,
@@ -151,9 +157,11 @@ pub enum RoutingHint {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TranscriptHash(pub u64);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TimeExpressionKind {
     DateKeyword,
     DateTimeLocal,
@@ -163,6 +171,7 @@ pub enum TimeExpressionKind {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeExpression {
     pub kind: TimeExpressionKind,
     /// Deterministic, bounded representation (may be relative like "tomorrow 15:00").
@@ -188,6 +197,7 @@ impl Validate for TimeExpression {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FieldValue {
     /// The exact span from `transcript_text` used as source-of-truth.
     pub original_span: String,
@@ -252,6 +262,7 @@ impl FieldValue {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntentField {
     pub key: FieldKey,
     pub value: FieldValue,
@@ -259,6 +270,7 @@ pub struct IntentField {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EvidenceSpan {
     pub field: FieldKey,
     pub transcript_hash: TranscriptHash,
@@ -301,6 +313,7 @@ impl Validate for EvidenceSpan {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntentDraft {
     pub schema_version: SchemaVersion,
     pub intent_type: IntentType,
@@ -22,12 +22,14 @@ pub const ONB_REQUIREMENT_BACKFILL_COMPLETE_COMMIT: &str =
     "ONB_REQUIREMENT_BACKFILL_COMPLETE_COMMIT";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimulationType {
     Draft,
     Commit,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OnboardingSessionId(String);
 
 impl OnboardingSessionId {
@@ -76,12 +78,14 @@ pub enum OnboardingNextStep {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TermsStatus {
     Accepted,
     Declined,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerificationStatus {
     Pending,
     Confirmed,
@@ -95,12 +99,14 @@ pub enum SenderVerifyDecision {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProofType {
     Biometric,
     Passcode,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OnboardingStatus {
     DraftCreated,
     TermsAccepted,
@@ -114,12 +120,14 @@ pub enum OnboardingStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BackfillRolloutScope {
     NewHiresOnly,
     CurrentAndNew,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BackfillCampaignState {
     DraftCreated,
     Running,
@@ -128,6 +136,7 @@ pub enum BackfillCampaignState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BackfillTargetStatus {
     Pending,
     Requested,
@@ -138,6 +147,7 @@ pub enum BackfillTargetStatus {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BackfillCampaignId(String);
 
 impl BackfillCampaignId {
@@ -35,6 +35,7 @@ pub enum OsNextMove {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OsOutcomeActionClass {
     ActNow,
     QueueLearn,
@@ -23,6 +23,7 @@ impl PaeCapabilityId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PaeRouteDomain {
     Stt,
     Tts,
@@ -31,6 +32,7 @@ pub enum PaeRouteDomain {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PaeMode {
     Shadow,
     Assist,
@@ -38,6 +40,7 @@ pub enum PaeMode {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PaeProviderSlot {
     Primary,
     Secondary,
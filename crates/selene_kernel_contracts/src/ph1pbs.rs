@@ -33,6 +33,7 @@ fn validate_opt_id(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlueprintVersion(pub u32);
 
 impl Validate for BlueprintVersion {
@@ -48,6 +49,7 @@ impl Validate for BlueprintVersion {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessId(String);
 
 impl ProcessId {
@@ -69,6 +71,7 @@ impl Validate for ProcessId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IntentType(String);
 
 impl IntentType {
@@ -90,6 +93,7 @@ impl Validate for IntentType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BlueprintStatus {
     Draft,
     Active,
@@ -197,6 +201,7 @@ impl Validate for ProcessBlueprintEventInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessBlueprintEvent {
     pub schema_version: SchemaVersion,
     pub process_blueprint_event_id: u64,
@@ -296,6 +301,7 @@ impl Validate for ProcessBlueprintEvent {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlueprintRegistryRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
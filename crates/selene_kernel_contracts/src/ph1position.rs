@@ -26,6 +26,7 @@ pub enum PositionSimulationType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionScheduleType {
     FullTime,
     PartTime,
@@ -34,6 +35,7 @@ pub enum PositionScheduleType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionLifecycleState {
     Draft,
     Active,
@@ -42,6 +44,7 @@ pub enum PositionLifecycleState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionLifecycleAction {
     CreateDraft,
     Activate,
@@ -71,6 +74,7 @@ pub enum PositionPolicyResult {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionRequirementFieldType {
     String,
     Integer,
@@ -81,12 +85,14 @@ pub enum PositionRequirementFieldType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionRequirementRuleType {
     Always,
     Conditional,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionRequirementSensitivity {
     Safe,
     Private,
@@ -94,6 +100,7 @@ pub enum PositionRequirementSensitivity {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionRequirementExposureRule {
     Speak,
     TextOnly,
@@ -101,6 +108,7 @@ pub enum PositionRequirementExposureRule {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionRequirementEvidenceMode {
     UserAnswer,
     DocRequired,
@@ -109,6 +117,7 @@ pub enum PositionRequirementEvidenceMode {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionSchemaApplyScope {
     NewHiresOnly,
     CurrentAndNew,
@@ -121,6 +130,7 @@ impl PositionSchemaApplyScope {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionRequirementFieldSpec {
     pub field_key: String,
     pub field_type: PositionRequirementFieldType,
@@ -183,6 +193,7 @@ impl Validate for PositionRequirementFieldSpec {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionSchemaSelectorSnapshot {
     pub company_size: Option<String>,
     pub industry_code: Option<String>,
@@ -209,6 +220,7 @@ impl Validate for PositionSchemaSelectorSnapshot {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TenantId(String);
 
 impl TenantId {
@@ -231,6 +243,7 @@ impl Validate for TenantId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionId(String);
 
 impl PositionId {
@@ -253,6 +266,7 @@ impl Validate for PositionId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
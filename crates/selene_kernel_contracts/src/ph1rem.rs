@@ -60,12 +60,14 @@ fn validate_opt_token(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReminderLocalTimeMode {
     FixedTimezone,
     LocalTime,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReminderPriorityLevel {
     Low,
     Normal,
@@ -74,6 +76,7 @@ pub enum ReminderPriorityLevel {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReminderType {
     Task,
     Meeting,
@@ -84,6 +87,7 @@ pub enum ReminderType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReminderChannel {
     Voice,
     Push,
@@ -93,6 +97,7 @@ pub enum ReminderChannel {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReminderState {
     Scheduled,
     Snoozed,
@@ -103,6 +108,7 @@ pub enum ReminderState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReminderDeliveryStatus {
     Delivered,
     DeferredQuietHours,
@@ -118,6 +124,7 @@ pub enum ReminderAckSource {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReminderId(String);
 
 impl ReminderId {
@@ -139,6 +146,7 @@ impl Validate for ReminderId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReminderOccurrenceId(String);
 
 impl ReminderOccurrenceId {
@@ -160,6 +168,7 @@ impl Validate for ReminderOccurrenceId {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReminderDeliveryAttemptId(String);
 
 impl ReminderDeliveryAttemptId {
@@ -14,6 +14,7 @@ use crate::{ContractViolation, MonotonicTimeNs, ReasonCodeId, SchemaVersion, Val
 pub const PH1SELFHEAL_CONTRACT_VERSION: SchemaVersion = SchemaVersion(1);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FailureContainmentAction {
     FailClosedRefuse,
     ClarifyRequired,
@@ -23,6 +24,7 @@ pub enum FailureContainmentAction {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProblemCardState {
     Open,
     Verifying,
@@ -31,6 +33,7 @@ pub enum ProblemCardState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FixSource {
     Learn,
     Pae,
@@ -38,6 +41,7 @@ pub enum FixSource {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum FixKind {
     Artifact,
     RoutingPolicy,
@@ -45,12 +49,14 @@ pub enum FixKind {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelfHealValidationStatus {
     Ok,
     Fail,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PromotionDecisionAction {
     Promote,
     Demote,
@@ -59,6 +65,7 @@ pub enum PromotionDecisionAction {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FailureProviderContext {
     pub route_domain: PaeRouteDomain,
     pub provider_slot: PaeProviderSlot,
@@ -103,6 +110,7 @@ impl Validate for FailureProviderContext {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FailureEvent {
     pub schema_version: SchemaVersion,
     pub failure_id: String,
@@ -258,6 +266,7 @@ impl Validate for FailureEvent {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProblemCard {
     pub schema_version: SchemaVersion,
     pub problem_id: String,
@@ -444,6 +453,7 @@ impl Validate for ProblemCard {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixCard {
     pub schema_version: SchemaVersion,
     pub fix_id: String,
@@ -645,6 +655,7 @@ impl Validate for FixCard {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PromotionDecision {
     pub schema_version: SchemaVersion,
     pub decision_id: String,
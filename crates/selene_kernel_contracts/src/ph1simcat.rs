@@ -33,6 +33,7 @@ fn validate_opt_id(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimulationVersion(pub u32);
 
 impl Validate for SimulationVersion {
@@ -48,6 +49,7 @@ impl Validate for SimulationVersion {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimulationId(String);
 
 impl SimulationId {
@@ -69,6 +71,7 @@ impl Validate for SimulationId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimulationType {
     Draft,
     Commit,
@@ -76,6 +79,7 @@ pub enum SimulationType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimulationStatus {
     Draft,
     Active,
@@ -181,6 +185,7 @@ impl Validate for SimulationCatalogEventInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimulationCatalogEvent {
     pub schema_version: SchemaVersion,
     pub simulation_catalog_event_id: u64,
@@ -277,6 +282,7 @@ impl Validate for SimulationCatalogEvent {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimulationCatalogCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
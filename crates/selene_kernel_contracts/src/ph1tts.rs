@@ -9,6 +9,7 @@ pub const PH1TTS_CONTRACT_VERSION: SchemaVersion = SchemaVersion(1);
 pub const PH1TTS_ENGINE_ID: &str = "PH1.TTS";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnswerId(pub u128);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -375,6 +375,7 @@ pub enum WakeSimulationType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WakeEnrollStatus {
     InProgress,
     Pending,
@@ -383,6 +384,7 @@ pub enum WakeEnrollStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WakeSampleResult {
     Pass,
     Fail,
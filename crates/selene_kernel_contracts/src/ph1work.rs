@@ -34,6 +34,7 @@ fn validate_opt_id(
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorkOrderId(String);
 
 impl WorkOrderId {
@@ -55,6 +56,7 @@ impl Validate for WorkOrderId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WorkOrderStatus {
     Draft,
     Clarify,
@@ -171,6 +173,7 @@ impl Validate for WorkOrderLedgerEventInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorkOrderLedgerEvent {
     pub schema_version: SchemaVersion,
     pub work_order_event_id: u64,
@@ -273,6 +276,7 @@ impl Validate for WorkOrderLedgerEvent {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorkOrderCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
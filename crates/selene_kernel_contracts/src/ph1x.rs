@@ -1321,6 +1321,7 @@ impl StepUpActionClass {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StepUpChallengeMethod {
     DeviceBiometric,
     DevicePasscode,
@@ -1454,6 +1455,7 @@ impl Validate for IdentityContext {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ResumeBuffer {
     pub schema_version: SchemaVersion,
     /// Interrupted answer identifier (ties Resume Buffer to TTS playback).
@@ -1540,6 +1542,7 @@ impl Validate for ResumeBuffer {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PendingState {
     Clarify {
         missing_field: FieldKey,
@@ -1674,6 +1677,7 @@ impl Validate for PendingState {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThreadState {
     pub schema_version: SchemaVersion,
     pub pending: Option<PendingState>,
@@ -1701,6 +1705,7 @@ pub struct ThreadState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ThreadPolicyFlags {
     pub schema_version: SchemaVersion,
     pub force_privacy_mode: bool,
@@ -1709,6 +1714,7 @@ pub struct ThreadPolicyFlags {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LastTurnRouteClass {
     PublicChat,
     ToolTime,
@@ -1719,6 +1725,7 @@ pub enum LastTurnRouteClass {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LastTurnContext {
     pub schema_version: SchemaVersion,
     pub route_class: LastTurnRouteClass,
@@ -2079,6 +2086,7 @@ impl Validate for ThreadState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IdentityPromptState {
     pub schema_version: SchemaVersion,
     pub prompted_in_session: bool,
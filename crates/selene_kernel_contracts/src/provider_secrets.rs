@@ -88,6 +88,7 @@ impl ProviderSecretId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConsentScope {
     WakeTraining,
     VoiceIdEnrollment,
@@ -113,6 +114,7 @@ impl ConsentScope {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConsentDecisionState {
     Granted,
     Denied,
@@ -130,6 +132,7 @@ impl ConsentDecisionState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsentStatePacket {
     pub schema_version: SchemaVersion,
     pub consent_state_id: String,
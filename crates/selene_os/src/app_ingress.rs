@@ -121,7 +121,7 @@ use selene_storage::ph1f::{
     IdentityRecord, IdentityStatus, Ph1fStore, SessionRecord as StoredSessionRecord, StorageError,
 };
 
-use crate::device_artifact_sync::DeviceArtifactSyncWorkerPassMetrics;
+use crate::device_artifact_sync::{DeviceArtifactSyncWorkerPassMetrics, SyncRetryPolicy};
 use crate::ph1comp::Ph1CompRuntime;
 use crate::ph1j::{Ph1jRuntime, ProtectedProofWriteRequest};
 use crate::ph1onb::{OnbVoiceEnrollFinalize, OnbVoiceEnrollLiveRequest, OnbVoiceEnrollSampleStep};
@@ -5118,6 +5118,24 @@ impl AppServerIngressRuntime {
             )
     }
 
+    pub fn run_device_artifact_sync_worker_pass_with_retry_policy(
+        &self,
+        store: &mut Ph1fStore,
+        now: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        retry_policy: &SyncRetryPolicy,
+    ) -> Result<DeviceArtifactSyncWorkerPassMetrics, StorageError> {
+        self.executor
+            .execute_device_artifact_sync_worker_pass_with_retry_policy(
+                store,
+                now,
+                correlation_id,
+                turn_id,
+                retry_policy,
+            )
+    }
+
     pub fn run_wake_profile_availability_refresh(
         &self,
         store: &mut Ph1fStore,
@@ -5289,7 +5307,8 @@ impl AppServerIngressRuntime {
         } else if identity_state_allows_memory_scope(&identity_state)
             && canonical_forwarded_voice_identity_confirmed(&runtime_execution_envelope)?
         {
-            self.executor
+            let mut candidates = self
+                .executor
                 .collect_context_memory_candidates_for_voice_turn(
                     store,
                     x_build.now,
@@ -5299,7 +5318,9 @@ impl AppServerIngressRuntime {
                     x_build.policy_context_ref,
                     topic_hint,
                 )
-                .unwrap_or_default()
+                .unwrap_or_default();
+            merge_supplied_memory_candidates(&mut candidates, &x_build.memory_candidates);
+            candidates
         } else {
             Vec::new()
         };
@@ -5751,6 +5772,7 @@ fn runtime_execution_envelope_with_voice_turn_proof(
 const GOVERNED_SUBSYSTEM_MEMORY_ENGINE: &str = "MEMORY_ENGINE";
 const GOVERNED_SUBSYSTEM_AUTHORITY_LAYER: &str = "AUTHORITY_LAYER";
 const GOVERNED_SUBSYSTEM_IDENTITY_VOICE_ENGINE: &str = "IDENTITY_VOICE_ENGINE";
+const PH1X_MEMORY_CANDIDATES_MAX: usize = 32;
 
 fn missing_simulation_runtime_execution_envelope(
     ph1comp_runtime: &Ph1CompRuntime,
@@ -5782,6 +5804,26 @@ fn identity_state_allows_memory_scope(identity_state: &IdentityExecutionState) -
         && identity_state.recovery_state == IdentityRecoveryState::None
 }
 
+// Candidates the caller loaded into the PH1.X build input join the PH1.M recall results for a
+// confirmed speaker, skipping keys recall already returned and stopping at the PH1.X cap.
+fn merge_supplied_memory_candidates(
+    candidates: &mut Vec<MemoryCandidate>,
+    supplied: &[MemoryCandidate],
+) {
+    for candidate in supplied {
+        if candidates.len() >= PH1X_MEMORY_CANDIDATES_MAX {
+            break;
+        }
+        if candidates
+            .iter()
+            .any(|existing| existing.memory_key == candidate.memory_key)
+        {
+            continue;
+        }
+        candidates.push(candidate.clone());
+    }
+}
+
 fn governance_quarantines_subsystem(
     governance_state: &GovernanceExecutionState,
     subsystem_id: &str,
@@ -17156,6 +17198,74 @@ mod tests {
         run5_voice_builder_uses_confirmed_identity_memory_context_to_resolve_tom_contact();
     }
 
+    #[test]
+    fn at_mem_02b_confirmed_identity_keeps_supplied_candidates() {
+        let runtime = AppServerIngressRuntime::default();
+        let actor_user_id = UserId::new("tenant_1:run5_supplied_user").unwrap();
+        let device_id = DeviceId::new("run5_supplied_device_1").unwrap();
+        let mut store = Ph1fStore::new_in_memory();
+        seed_actor(&mut store, &actor_user_id, &device_id);
+
+        let request = AppVoiceIngressRequest::v1(
+            CorrelationId(9503),
+            TurnId(9603),
+            AppPlatform::Desktop,
+            OsVoiceTrigger::Explicit,
+            sample_voice_id_request(MonotonicTimeNs(3), actor_user_id.clone()),
+            actor_user_id.clone(),
+            Some("tenant_1".to_string()),
+            Some(device_id),
+            Vec::new(),
+            no_observation(),
+        )
+        .unwrap();
+        let outcome = runtime.run_voice_turn(&mut store, request).unwrap();
+        let OsVoiceLiveTurnOutcome::Forwarded(mut forwarded) = outcome else {
+            panic!("expected forwarded voice turn");
+        };
+        forwarded.voice_identity_assertion = confirmed_voice_assertion(actor_user_id);
+        recanonicalize_forwarded_bundle_for_tests(&mut forwarded);
+
+        let x_build = AppVoicePh1xBuildInput {
+            now: MonotonicTimeNs(7),
+            thread_key: None,
+            thread_state: ThreadState::empty_v1(),
+            session_state: SessionState::Active,
+            policy_context_ref: PolicyContextRef::v1(false, false, SafetyTier::Standard),
+            memory_candidates: vec![
+                external_injected_memory_candidate(),
+                external_injected_memory_candidate(),
+            ],
+            confirm_answer: None,
+            nlp_output: Some(invite_link_draft_missing_contact("Tom", "tenant_1")),
+            tool_response: None,
+            interruption: None,
+            locale: None,
+            language_packet: None,
+            last_failure_reason_code: None,
+        };
+
+        let ph1x_request = runtime
+            .build_ph1x_request_for_forwarded_voice(
+                &mut store,
+                ForwardedVoicePh1xRequestInput {
+                    correlation_id: CorrelationId(9503),
+                    turn_id: TurnId(9603),
+                    app_platform: AppPlatform::Desktop,
+                    forwarded: &forwarded,
+                    request_session_id: None,
+                    tenant_id: Some("tenant_1"),
+                    x_build,
+                },
+            )
+            .unwrap();
+        assert_eq!(ph1x_request.memory_candidates.len(), 1);
+        assert_eq!(
+            ph1x_request.memory_candidates[0].memory_key.as_str(),
+            "invite_contact_tom_sms"
+        );
+    }
+
     #[test]
     fn at_mem_03_confirmed_identity_wrong_user_never_sees_other_users_memory() {
         let runtime = AppServerIngressRuntime::default();
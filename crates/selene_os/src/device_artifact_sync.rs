@@ -18,6 +18,9 @@ pub const DEVICE_SYNC_WORKER_MAX_ITEMS: u16 = 16;
 pub const DEVICE_SYNC_WORKER_LEASE_MS: u32 = 30_000;
 pub const DEVICE_SYNC_RETRY_AFTER_MS_DEFAULT: u32 = 30_000;
 pub const DEVICE_SYNC_MAX_ATTEMPTS_DEFAULT: u16 = 5;
+pub const DEVICE_SYNC_RETRY_BACKOFF_MIN_MS: u32 = 1_000;
+pub const DEVICE_SYNC_RETRY_BACKOFF_MAX_MS: u32 = 300_000;
+pub const DEVICE_SYNC_RETRY_JITTER_PCT_MAX: u8 = 50;
 pub const DEVICE_SYNC_PULL_RETRY_AFTER_MS_DEFAULT: u32 = 30_000;
 pub const DEVICE_SYNC_PULL_CACHE_DIR_DEFAULT: &str = ".runtime/device_artifact_cache";
 pub const WAKE_ARTIFACT_REASON_HASH_MISMATCH: ReasonCodeId = ReasonCodeId(0x57A0_5101);
@@ -45,6 +48,7 @@ pub struct DeviceArtifactSyncWorkerPassMetrics {
     pub apply_rollback_count: u16,
     pub apply_noop_count: u16,
     pub pull_error_count: u16,
+    pub last_backoff_ms: u32,
     pub queue_after: DeviceArtifactSyncQueueMetrics,
 }
 
@@ -58,6 +62,91 @@ pub struct DeviceArtifactPullApplyMetrics {
     pub pull_error_count: u16,
 }
 
+/// Retry scheduling for retryable sync sends. With `base_backoff_ms == 0` every retry
+/// waits for the sender's `retry_after_ms` hint, which is the historical flat schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncRetryPolicy {
+    pub max_attempts: u16,
+    pub base_backoff_ms: u32,
+    pub max_backoff_ms: u32,
+    pub jitter_pct: u8,
+}
+
+impl Default for SyncRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: DEVICE_SYNC_MAX_ATTEMPTS_DEFAULT,
+            base_backoff_ms: 0,
+            max_backoff_ms: DEVICE_SYNC_RETRY_BACKOFF_MAX_MS,
+            jitter_pct: 0,
+        }
+    }
+}
+
+impl SyncRetryPolicy {
+    pub fn from_env() -> Self {
+        let base_backoff_ms = env::var("SELENE_ENGINE_B_SYNC_BASE_BACKOFF_MS")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .filter(|v| *v <= DEVICE_SYNC_RETRY_BACKOFF_MAX_MS)
+            .unwrap_or(0);
+        let max_backoff_ms = env::var("SELENE_ENGINE_B_SYNC_MAX_BACKOFF_MS")
+            .ok()
+            .and_then(|v| v.parse::<u32>().ok())
+            .filter(|v| {
+                (DEVICE_SYNC_RETRY_BACKOFF_MIN_MS..=DEVICE_SYNC_RETRY_BACKOFF_MAX_MS).contains(v)
+            })
+            .unwrap_or(DEVICE_SYNC_RETRY_BACKOFF_MAX_MS);
+        let jitter_pct = env::var("SELENE_ENGINE_B_SYNC_BACKOFF_JITTER_PCT")
+            .ok()
+            .and_then(|v| v.parse::<u8>().ok())
+            .filter(|v| *v <= DEVICE_SYNC_RETRY_JITTER_PCT_MAX)
+            .unwrap_or(0);
+        Self {
+            max_attempts: device_sync_max_attempts_from_env(),
+            base_backoff_ms,
+            max_backoff_ms,
+            jitter_pct,
+        }
+    }
+
+    /// Delay before the next attempt after `attempt` (1-based) failed. The exponential
+    /// step is capped at `max_backoff_ms`, reduced by a jitter derived from `jitter_key`
+    /// so replays stay deterministic, and never shorter than the sender's hint.
+    pub fn backoff_ms(&self, attempt: u16, retry_after_hint_ms: u32, jitter_key: &str) -> u32 {
+        let hint_ms = retry_after_hint_ms.clamp(
+            DEVICE_SYNC_RETRY_BACKOFF_MIN_MS,
+            DEVICE_SYNC_RETRY_BACKOFF_MAX_MS,
+        );
+        if self.base_backoff_ms == 0 {
+            return hint_ms;
+        }
+        let cap_ms = self.max_backoff_ms.clamp(
+            DEVICE_SYNC_RETRY_BACKOFF_MIN_MS,
+            DEVICE_SYNC_RETRY_BACKOFF_MAX_MS,
+        );
+        let shift = u32::from(attempt.saturating_sub(1)).min(20);
+        let step_ms = u64::from(self.base_backoff_ms)
+            .saturating_mul(1u64 << shift)
+            .min(u64::from(cap_ms));
+        let jitter_pct = u64::from(self.jitter_pct.min(DEVICE_SYNC_RETRY_JITTER_PCT_MAX));
+        let jitter_span_ms = step_ms.saturating_mul(jitter_pct) / 100;
+        let jitter_ms = if jitter_span_ms == 0 {
+            0
+        } else {
+            let digest = Sha256::digest(format!("{jitter_key}:{attempt}").as_bytes());
+            let mut seed = [0u8; 8];
+            seed.copy_from_slice(&digest[..8]);
+            u64::from_be_bytes(seed) % (jitter_span_ms + 1)
+        };
+        let backoff_ms = u32::try_from(step_ms - jitter_ms).unwrap_or(cap_ms);
+        backoff_ms.max(hint_ms).clamp(
+            DEVICE_SYNC_RETRY_BACKOFF_MIN_MS,
+            DEVICE_SYNC_RETRY_BACKOFF_MAX_MS,
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct DeviceArtifactPullRequest {
     pub schema_version: u8,
@@ -461,6 +550,22 @@ pub fn run_device_artifact_sync_worker_pass_with_metrics(
     now: MonotonicTimeNs,
     worker_id: String,
     sender: &DeviceArtifactSyncSenderRuntime,
+) -> Result<DeviceArtifactSyncWorkerPassMetrics, StorageError> {
+    run_device_artifact_sync_worker_pass_with_retry_policy(
+        store,
+        now,
+        worker_id,
+        sender,
+        &SyncRetryPolicy::from_env(),
+    )
+}
+
+pub fn run_device_artifact_sync_worker_pass_with_retry_policy(
+    store: &mut Ph1fStore,
+    now: MonotonicTimeNs,
+    worker_id: String,
+    sender: &DeviceArtifactSyncSenderRuntime,
+    retry_policy: &SyncRetryPolicy,
 ) -> Result<DeviceArtifactSyncWorkerPassMetrics, StorageError> {
     let pull_runtime = DeviceArtifactPullRuntime::from_env_or_disabled();
     run_device_artifact_sync_worker_pass_with_metrics_internal(
@@ -469,7 +574,7 @@ pub fn run_device_artifact_sync_worker_pass_with_metrics(
         worker_id,
         sender,
         &pull_runtime,
-        device_sync_max_attempts_from_env(),
+        retry_policy,
     )
 }
 
@@ -479,7 +584,7 @@ fn run_device_artifact_sync_worker_pass_with_metrics_internal(
     worker_id: String,
     sender: &DeviceArtifactSyncSenderRuntime,
     pull_runtime: &DeviceArtifactPullRuntime,
-    max_attempts: u16,
+    retry_policy: &SyncRetryPolicy,
 ) -> Result<DeviceArtifactSyncWorkerPassMetrics, StorageError> {
     let pull_metrics = run_device_artifact_pull_apply_pass_internal(
         store,
@@ -488,7 +593,7 @@ fn run_device_artifact_sync_worker_pass_with_metrics_internal(
         pull_runtime,
         None,
     )?;
-    let max_attempts = max_attempts.max(1);
+    let max_attempts = retry_policy.max_attempts.max(1);
     let dequeued = store.device_artifact_sync_dequeue_batch(
         now,
         DEVICE_SYNC_WORKER_MAX_ITEMS,
@@ -563,13 +668,19 @@ fn run_device_artifact_sync_worker_pass_with_metrics_internal(
                     )?;
                     metrics.dead_lettered_count = metrics.dead_lettered_count.saturating_add(1);
                 } else {
+                    let backoff_ms = retry_policy.backoff_ms(
+                        row.attempt_count,
+                        err.retry_after_ms,
+                        &row.sync_job_id,
+                    );
                     store.device_artifact_sync_fail_commit(
                         now,
                         &row.sync_job_id,
                         Some(worker_id.as_str()),
                         err.message,
-                        err.retry_after_ms,
+                        backoff_ms,
                     )?;
+                    metrics.last_backoff_ms = backoff_ms;
                     metrics.retry_scheduled_count = metrics.retry_scheduled_count.saturating_add(1);
                 }
             }
@@ -1437,6 +1548,13 @@ mod tests {
             .unwrap();
     }
 
+    fn retry_policy(max_attempts: u16) -> SyncRetryPolicy {
+        SyncRetryPolicy {
+            max_attempts,
+            ..SyncRetryPolicy::default()
+        }
+    }
+
     fn seed_wake_learn_signal(
         store: &mut Ph1fStore,
         now: MonotonicTimeNs,
@@ -1483,7 +1601,7 @@ mod tests {
             "worker_sync_ok".to_string(),
             &DeviceArtifactSyncSenderRuntime::LoopbackAck,
             &DeviceArtifactPullRuntime::Disabled,
-            5,
+            &retry_policy(5),
         )
         .unwrap();
 
@@ -1512,7 +1630,7 @@ mod tests {
             "worker_sync_retry".to_string(),
             &DeviceArtifactSyncSenderRuntime::always_fail_for_tests("engine_b_timeout", 5_000),
             &DeviceArtifactPullRuntime::Disabled,
-            3,
+            &retry_policy(3),
         )
         .unwrap();
 
@@ -1543,7 +1661,7 @@ mod tests {
             "worker_sync_dead".to_string(),
             &DeviceArtifactSyncSenderRuntime::always_fail_for_tests("engine_b_down", 5_000),
             &DeviceArtifactPullRuntime::Disabled,
-            1,
+            &retry_policy(1),
         )
         .unwrap();
 
@@ -1561,6 +1679,95 @@ mod tests {
         assert_eq!(metrics.queue_after.dead_letter_count, 1);
     }
 
+    #[test]
+    fn at_device_sync_worker_04_retry_policy_backs_off_exponentially_until_dead_letter() {
+        let mut store = Ph1fStore::new_in_memory();
+        let u = user("tenant_1:user_sync_backoff");
+        let d = device("device_sync_backoff");
+        seed_identity_and_device(&mut store, &u, &d);
+        let onb = seed_onboarding_session(&mut store, &u, "fp_sync_backoff");
+        let receipt = seed_voice_sync_receipt(&mut store, &onb, &d, "backoff");
+        let sender = DeviceArtifactSyncSenderRuntime::always_fail_for_tests("engine_b_busy", 1_000);
+        let policy = SyncRetryPolicy {
+            max_attempts: 5,
+            base_backoff_ms: 2_000,
+            max_backoff_ms: 10_000,
+            jitter_pct: 0,
+        };
+
+        let mut now = MonotonicTimeNs(400);
+        let mut backoffs = Vec::new();
+        for _ in 0..4 {
+            let metrics = run_device_artifact_sync_worker_pass_with_metrics_internal(
+                &mut store,
+                now,
+                "worker_sync_backoff".to_string(),
+                &sender,
+                &DeviceArtifactPullRuntime::Disabled,
+                &policy,
+            )
+            .unwrap();
+            assert_eq!(metrics.retry_scheduled_count, 1);
+            assert_eq!(metrics.dead_lettered_count, 0);
+            let row = store
+                .mobile_artifact_sync_queue_row_for_receipt(&receipt)
+                .expect("queue row must exist");
+            assert_eq!(row.state, MobileArtifactSyncState::InFlight);
+            assert_eq!(
+                row.lease_expires_at,
+                Some(MonotonicTimeNs(
+                    now.0 + u64::from(metrics.last_backoff_ms) * 1_000_000
+                ))
+            );
+            backoffs.push(metrics.last_backoff_ms);
+            now = row.lease_expires_at.unwrap();
+        }
+        assert_eq!(backoffs, vec![2_000, 4_000, 8_000, 10_000]);
+
+        let metrics = run_device_artifact_sync_worker_pass_with_metrics_internal(
+            &mut store,
+            now,
+            "worker_sync_backoff".to_string(),
+            &sender,
+            &DeviceArtifactPullRuntime::Disabled,
+            &policy,
+        )
+        .unwrap();
+        let row = store
+            .mobile_artifact_sync_queue_row_for_receipt(&receipt)
+            .expect("queue row must exist");
+        assert_eq!(row.attempt_count, 5);
+        assert_eq!(row.state, MobileArtifactSyncState::DeadLetter);
+        assert_eq!(metrics.retry_scheduled_count, 0);
+        assert_eq!(metrics.dead_lettered_count, 1);
+        assert_eq!(metrics.last_backoff_ms, 0);
+    }
+
+    #[test]
+    fn at_device_sync_worker_05_retry_policy_default_and_jitter_bounds() {
+        let flat = SyncRetryPolicy::default();
+        assert_eq!(flat.backoff_ms(1, 5_000, "job"), 5_000);
+        assert_eq!(flat.backoff_ms(4, 5_000, "job"), 5_000);
+
+        let jittered = SyncRetryPolicy {
+            max_attempts: 8,
+            base_backoff_ms: 4_000,
+            max_backoff_ms: 60_000,
+            jitter_pct: 25,
+        };
+        for attempt in 1..=6u16 {
+            let step = (4_000u32 << (attempt - 1)).min(60_000);
+            let backoff = jittered.backoff_ms(attempt, 1_000, "sync_job_jitter");
+            assert!(backoff <= step);
+            assert!(backoff >= step - step / 4);
+            assert_eq!(
+                backoff,
+                jittered.backoff_ms(attempt, 1_000, "sync_job_jitter")
+            );
+        }
+        assert_eq!(jittered.backoff_ms(1, 9_000, "sync_job_jitter"), 9_000);
+    }
+
     #[test]
     fn at_device_sync_pull_apply_01_hash_mismatch_rolls_back_and_preserves_last_known_good() {
         let mut store = Ph1fStore::new_in_memory();
@@ -1770,7 +1977,7 @@ mod tests {
             "worker_wakelearn_ack".to_string(),
             &DeviceArtifactSyncSenderRuntime::LoopbackAck,
             &DeviceArtifactPullRuntime::Disabled,
-            3,
+            &retry_policy(3),
         )
         .unwrap();
         let row = store
@@ -1802,7 +2009,7 @@ mod tests {
             "worker_wakelearn_retry".to_string(),
             &DeviceArtifactSyncSenderRuntime::always_fail_for_tests("nack_retryable", 7_000),
             &DeviceArtifactPullRuntime::Disabled,
-            3,
+            &retry_policy(3),
         )
         .unwrap();
         let row = store
@@ -1834,7 +2041,7 @@ mod tests {
             "worker_wakelearn_fatal".to_string(),
             &DeviceArtifactSyncSenderRuntime::always_fatal_nack_for_tests("nack_fatal"),
             &DeviceArtifactPullRuntime::Disabled,
-            5,
+            &retry_policy(5),
         )
         .unwrap();
         let row = store
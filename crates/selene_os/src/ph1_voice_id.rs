@@ -9,12 +9,13 @@ use selene_engines::ph1_voice_id::{
     VoiceIdObservation as EngineVoiceIdObservation,
 };
 use selene_kernel_contracts::ph1_voice_id::{
-    IdentityTierV2, Ph1VoiceIdRequest, Ph1VoiceIdResponse, Ph1VoiceIdSimOk, Ph1VoiceIdSimRequest,
-    Ph1VoiceIdSimResponse, UserId, VoiceEnrollStatus as ContractVoiceEnrollStatus,
-    VoiceEnrollmentSessionId, VoiceIdDecision, VoiceIdEnrollCompleteResult,
-    VoiceIdEnrollDeferResult, VoiceIdEnrollSampleResult, VoiceIdEnrollStartResult,
-    VoiceIdSimulationRequest, VoiceIdentityV2, VoiceSampleResult as ContractVoiceSampleResult,
-    DEFAULT_CONF_HIGH_BP, DEFAULT_CONF_MID_BP, PH1VOICEID_IMPLEMENTATION_ID,
+    DeviceTrustLevel, IdentityTierV2, Ph1VoiceIdRequest, Ph1VoiceIdResponse, Ph1VoiceIdSimOk,
+    Ph1VoiceIdSimRequest, Ph1VoiceIdSimResponse, UserId,
+    VoiceEnrollStatus as ContractVoiceEnrollStatus, VoiceEnrollmentSessionId, VoiceIdDecision,
+    VoiceIdEnrollCompleteResult, VoiceIdEnrollDeferResult, VoiceIdEnrollSampleResult,
+    VoiceIdEnrollStartResult, VoiceIdSimulationRequest, VoiceIdentityV2,
+    VoiceSampleResult as ContractVoiceSampleResult, DEFAULT_CONF_HIGH_BP, DEFAULT_CONF_MID_BP,
+    PH1VOICEID_IMPLEMENTATION_ID,
 };
 use selene_kernel_contracts::ph1art::{
     ArtifactScopeType, ArtifactStatus, ArtifactType, ArtifactVersion,
@@ -509,20 +510,36 @@ pub enum VoiceIdentityChannel {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct VoiceIdentityEmbeddingGateProfile {
     pub require_primary_embedding: bool,
+    // Onboarding-confirmed primary devices may skip a required gate; unknown devices never do.
+    pub trusted_device_bypass: bool,
 }
 
 impl VoiceIdentityEmbeddingGateProfile {
     pub const fn required() -> Self {
         Self {
             require_primary_embedding: true,
+            trusted_device_bypass: false,
         }
     }
 
     pub const fn optional() -> Self {
         Self {
             require_primary_embedding: false,
+            trusted_device_bypass: false,
         }
     }
+
+    pub const fn required_unless_trusted_device() -> Self {
+        Self {
+            require_primary_embedding: true,
+            trusted_device_bypass: true,
+        }
+    }
+
+    pub fn requires_primary_embedding_for(self, device_trust_level: DeviceTrustLevel) -> bool {
+        self.require_primary_embedding
+            && !(self.trusted_device_bypass && device_trust_level == DeviceTrustLevel::Trusted)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -659,7 +676,9 @@ impl VoiceIdentityEmbeddingGateProfiles {
 }
 
 fn gate_profile_label(profile: VoiceIdentityEmbeddingGateProfile) -> &'static str {
-    if profile.require_primary_embedding {
+    if profile.require_primary_embedding && profile.trusted_device_bypass {
+        "required_unless_trusted"
+    } else if profile.require_primary_embedding {
         "required"
     } else {
         "optional"
@@ -672,10 +691,13 @@ fn parse_gate_profile_field(
 ) -> Result<VoiceIdentityEmbeddingGateProfile, ContractViolation> {
     match value {
         "required" => Ok(VoiceIdentityEmbeddingGateProfile::required()),
+        "required_unless_trusted" => {
+            Ok(VoiceIdentityEmbeddingGateProfile::required_unless_trusted_device())
+        }
         "optional" => Ok(VoiceIdentityEmbeddingGateProfile::optional()),
         _ => Err(ContractViolation::InvalidValue {
             field,
-            reason: "must be required|optional",
+            reason: "must be required|optional|required_unless_trusted",
         }),
     }
 }
@@ -793,6 +815,9 @@ impl VoiceIdentityRuntimeContext {
 pub struct Ph1VoiceIdLiveConfig {
     pub embedding_gate_profiles: VoiceIdentityEmbeddingGateGovernedConfig,
     pub contract_migration: VoiceIdContractMigrationConfig,
+    // Device trust is derived from onboarding history, so most devices arrive untrusted;
+    // step-up on those stays opt-in and trust only relaxes the embedding gate by default.
+    pub require_reauth_on_untrusted_device: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -845,6 +870,7 @@ impl Ph1VoiceIdLiveConfig {
         Self {
             embedding_gate_profiles: VoiceIdentityEmbeddingGateGovernedConfig::mvp_v1_phone_first(),
             contract_migration: VoiceIdContractMigrationConfig::mvp_default(),
+            require_reauth_on_untrusted_device: false,
         }
     }
 
@@ -984,7 +1010,8 @@ impl Ph1VoiceIdLiveRuntime {
         let mut config = EngineVoiceIdConfig::mvp_v1();
         config.require_primary_embedding = self
             .embedding_gate_profile_for(context)
-            .require_primary_embedding;
+            .requires_primary_embedding_for(req.device_trust_level);
+        config.require_reauth_on_untrusted_device = self.config.require_reauth_on_untrusted_device;
         let mut runtime =
             EngineVoiceIdRuntime::new(config, enrolled).map_err(StorageError::ContractViolation)?;
         Ok(runtime.run(req, obs))
@@ -2345,13 +2345,22 @@ where
 
         let source_engine = analyzer_bundle.source_engine();
         let provider_req = build_ocr_provider_request(&self.config, analyzer_bundle)?;
-        let provider_resp = match self.adapter.execute(&provider_req) {
-            Ok(resp) => resp,
-            Err(Ph1dProviderAdapterError { .. }) => {
-                return Ok(OsOcrRouteOutcome::Refused(os_ocr_refuse(
-                    reason_codes::PH1_OS_OCR_ROUTE_PROVIDER_ERROR,
-                    "ocr provider transport/adapter error".to_string(),
-                )?))
+        // A retryable adapter error is tried again, at most `retry_budget` more times.
+        let max_attempts = u32::from(self.config.retry_budget) + 1;
+        let mut attempts = 0;
+        let provider_resp = loop {
+            attempts += 1;
+            match self.adapter.execute(&provider_req) {
+                Ok(resp) => break resp,
+                Err(Ph1dProviderAdapterError {
+                    retryable: true, ..
+                }) if attempts < max_attempts => {}
+                Err(Ph1dProviderAdapterError { .. }) => {
+                    return Ok(OsOcrRouteOutcome::Refused(os_ocr_refuse(
+                        reason_codes::PH1_OS_OCR_ROUTE_PROVIDER_ERROR,
+                        format!("ocr provider transport/adapter error after {attempts} attempts"),
+                    )?))
+                }
             }
         };
 
@@ -5918,6 +5927,74 @@ mod tests {
         );
     }
 
+    #[derive(Debug, Clone)]
+    struct FlakyOcrAdapter {
+        inner: RecordingOcrAdapter,
+        failures_left: Rc<RefCell<u32>>,
+        retryable: bool,
+    }
+
+    impl Ph1dProviderAdapter for FlakyOcrAdapter {
+        fn execute(
+            &self,
+            req: &Ph1dProviderCallRequest,
+        ) -> Result<Ph1dProviderCallResponse, Ph1dProviderAdapterError> {
+            let mut failures_left = self.failures_left.borrow_mut();
+            if *failures_left > 0 {
+                *failures_left -= 1;
+                self.inner.seen_requests.borrow_mut().push(req.clone());
+                return Err(Ph1dProviderAdapterError {
+                    message: "ocr provider unavailable".to_string(),
+                    retryable: self.retryable,
+                });
+            }
+            self.inner.execute(req)
+        }
+    }
+
+    #[test]
+    fn at_os_26a_ocr_handoff_retries_transient_provider_errors_within_budget() {
+        let flaky = |failures: u32, retryable: bool| FlakyOcrAdapter {
+            inner: RecordingOcrAdapter::new(OcrAdapterMode::SchemaOk),
+            failures_left: Rc::new(RefCell::new(failures)),
+            retryable,
+        };
+        let run = |adapter: &FlakyOcrAdapter, retry_budget: u8| {
+            let mut config = Ph1OsOcrRouteConfig::openai_default();
+            config.retry_budget = retry_budget;
+            Ph1OsOcrRouteWiring::new(config, adapter.clone())
+                .unwrap()
+                .run_handoff(&OsOcrAnalyzerForwardBundle::Vision(
+                    sample_vision_forward_bundle(),
+                ))
+                .unwrap()
+        };
+
+        let adapter = flaky(2, true);
+        let OsOcrRouteOutcome::Forwarded(bundle) = run(&adapter, 2) else {
+            panic!("ocr route must succeed within its retry budget");
+        };
+        assert_eq!(bundle.extracted_text, "invoice total due 123.45");
+        assert_eq!(adapter.inner.seen_requests.borrow().len(), 3);
+
+        let adapter = flaky(2, true);
+        let OsOcrRouteOutcome::Refused(refuse) = run(&adapter, 1) else {
+            panic!("exhausted retries must refuse");
+        };
+        assert_eq!(
+            refuse.reason_code,
+            reason_codes::PH1_OS_OCR_ROUTE_PROVIDER_ERROR
+        );
+        assert_eq!(
+            refuse.message,
+            "ocr provider transport/adapter error after 2 attempts"
+        );
+
+        let adapter = flaky(1, false);
+        assert!(matches!(run(&adapter, 2), OsOcrRouteOutcome::Refused(_)));
+        assert_eq!(adapter.inner.seen_requests.borrow().len(), 1);
+    }
+
     #[derive(Debug, Clone)]
     struct OcrBridgeContextEngine;
 
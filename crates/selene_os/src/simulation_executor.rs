@@ -72,7 +72,7 @@ use selene_storage::ph1f::{
 };
 
 use crate::device_artifact_sync::{
-    self, DeviceArtifactSyncSenderRuntime, DeviceArtifactSyncWorkerPassMetrics,
+    self, DeviceArtifactSyncSenderRuntime, DeviceArtifactSyncWorkerPassMetrics, SyncRetryPolicy,
 };
 use crate::ph1_voice_id::{
     Ph1VoiceIdLiveConfig, Ph1VoiceIdLiveRuntime, Ph1VoiceIdRuntime, VoiceIdentityChannel,
@@ -775,13 +775,31 @@ impl SimulationExecutor {
         now: MonotonicTimeNs,
         correlation_id: CorrelationId,
         turn_id: TurnId,
+    ) -> Result<DeviceArtifactSyncWorkerPassMetrics, StorageError> {
+        self.run_device_artifact_sync_worker_pass_with_retry_policy(
+            store,
+            now,
+            correlation_id,
+            turn_id,
+            &SyncRetryPolicy::from_env(),
+        )
+    }
+
+    fn run_device_artifact_sync_worker_pass_with_retry_policy(
+        &self,
+        store: &mut Ph1fStore,
+        now: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        retry_policy: &SyncRetryPolicy,
     ) -> Result<DeviceArtifactSyncWorkerPassMetrics, StorageError> {
         let worker_id = format!("sim_device_sync_worker_{}_{}", correlation_id.0, turn_id.0);
-        device_artifact_sync::run_device_artifact_sync_worker_pass_with_metrics(
+        device_artifact_sync::run_device_artifact_sync_worker_pass_with_retry_policy(
             store,
             now,
             worker_id,
             &self.device_sync_sender,
+            retry_policy,
         )
     }
 
@@ -805,6 +823,23 @@ impl SimulationExecutor {
         self.run_device_artifact_sync_worker_pass_with_metrics(store, now, correlation_id, turn_id)
     }
 
+    pub fn execute_device_artifact_sync_worker_pass_with_retry_policy(
+        &self,
+        store: &mut Ph1fStore,
+        now: MonotonicTimeNs,
+        correlation_id: CorrelationId,
+        turn_id: TurnId,
+        retry_policy: &SyncRetryPolicy,
+    ) -> Result<DeviceArtifactSyncWorkerPassMetrics, StorageError> {
+        self.run_device_artifact_sync_worker_pass_with_retry_policy(
+            store,
+            now,
+            correlation_id,
+            turn_id,
+            retry_policy,
+        )
+    }
+
     pub fn execute_voice_id(
         &self,
         store: &mut Ph1fStore,
@@ -149,6 +149,39 @@ impl From<ContractViolation> for StorageError {
     }
 }
 
+impl std::fmt::Display for StorageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageError::ForeignKeyViolation { table, key } => {
+                write!(f, "{table}: foreign key violation for '{key}'")
+            }
+            StorageError::DuplicateKey { table, key } => {
+                write!(f, "{table}: duplicate key '{key}'")
+            }
+            StorageError::AppendOnlyViolation { table } => {
+                write!(f, "{table}: append-only violation")
+            }
+            StorageError::ProofFailure { class, detail } => {
+                write!(f, "{}: {detail}", class.as_str())
+            }
+            StorageError::ContractViolation(ContractViolation::InvalidValue { field, reason }) => {
+                write!(f, "invalid {field}: {reason}")
+            }
+            StorageError::ContractViolation(ContractViolation::InvalidRange {
+                field,
+                min,
+                max,
+                got,
+            }) => write!(f, "{field} out of range [{min}, {max}]: got {got}"),
+            StorageError::ContractViolation(ContractViolation::NotFinite { field }) => {
+                write!(f, "{field} must be finite")
+            }
+        }
+    }
+}
+
+impl std::error::Error for StorageError {}
+
 fn fnv1a64(bytes: &[u8]) -> u64 {
     // FNV-1a 64-bit (stable across platforms, deterministic).
     const OFFSET: u64 = 0xcbf29ce484222325;
@@ -692,12 +725,14 @@ fn windows_overlap(
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IdentityStatus {
     Active,
     Disabled,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IdentityRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -727,6 +762,7 @@ impl IdentityRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeviceRecord {
     pub schema_version: SchemaVersion,
     pub device_id: DeviceId,
@@ -790,6 +826,7 @@ impl Validate for DeviceRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SessionRecord {
     pub schema_version: SchemaVersion,
     pub session_id: SessionId,
@@ -1089,6 +1126,7 @@ impl Validate for SessionRecord {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryLedgerRow {
     pub ledger_id: u64,
     pub user_id: UserId,
@@ -1098,6 +1136,7 @@ pub struct MemoryLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryCurrentRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1143,6 +1182,7 @@ impl MemoryCurrentRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MemoryThreadEventKind {
     ThreadDigestUpsert,
     ThreadResolved,
@@ -1150,6 +1190,7 @@ pub enum MemoryThreadEventKind {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemorySuppressionRuleRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1158,6 +1199,7 @@ pub struct MemorySuppressionRuleRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryEmotionalThreadLedgerRow {
     pub schema_version: SchemaVersion,
     pub emotional_thread_event_id: u64,
@@ -1168,6 +1210,7 @@ pub struct MemoryEmotionalThreadLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryEmotionalThreadCurrentRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1175,6 +1218,7 @@ pub struct MemoryEmotionalThreadCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryMetricLedgerRow {
     pub schema_version: SchemaVersion,
     pub memory_metric_event_id: u64,
@@ -1186,6 +1230,7 @@ pub struct MemoryMetricLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryThreadLedgerRow {
     pub schema_version: SchemaVersion,
     pub memory_thread_event_id: u64,
@@ -1198,6 +1243,7 @@ pub struct MemoryThreadLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryThreadCurrentRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1208,6 +1254,7 @@ pub struct MemoryThreadCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryThreadRefRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1217,6 +1264,7 @@ pub struct MemoryThreadRefRecord {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1xThreadStateLedgerRow {
     pub schema_version: SchemaVersion,
     pub ph1x_thread_state_event_id: u64,
@@ -1229,6 +1277,7 @@ pub struct Ph1xThreadStateLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1xThreadStateCurrentRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1239,6 +1288,7 @@ pub struct Ph1xThreadStateCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryGraphNodeRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1247,6 +1297,7 @@ pub struct MemoryGraphNodeRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryGraphEdgeRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1255,6 +1306,7 @@ pub struct MemoryGraphEdgeRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryArchiveIndexRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1266,6 +1318,7 @@ pub struct MemoryArchiveIndexRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryRetentionPreferenceRecord {
     pub schema_version: SchemaVersion,
     pub user_id: UserId,
@@ -1276,6 +1329,7 @@ pub struct MemoryRetentionPreferenceRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OutcomeUtilizationLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1290,6 +1344,7 @@ pub struct OutcomeUtilizationLedgerRow {
     pub decision_delta: bool,
     pub reason_code: ReasonCodeId,
     pub idempotency_key: Option<String>,
+    pub telemetry_tags: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1305,9 +1360,11 @@ pub struct OutcomeUtilizationLedgerRowInput {
     pub decision_delta: bool,
     pub reason_code: ReasonCodeId,
     pub idempotency_key: Option<String>,
+    pub telemetry_tags: BTreeMap<String, String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AgentExecutionLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1354,6 +1411,7 @@ pub struct AgentExecutionLedgerRowInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AgentExecutionCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: String,
@@ -1376,6 +1434,7 @@ pub struct AgentExecutionCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderProposalLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1390,6 +1449,7 @@ pub struct BuilderProposalLedgerRowInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderValidationRunLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1397,6 +1457,7 @@ pub struct BuilderValidationRunLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderValidationGateResultLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1404,6 +1465,7 @@ pub struct BuilderValidationGateResultLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderApprovalStateLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1411,6 +1473,7 @@ pub struct BuilderApprovalStateLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderReleaseStateLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1418,6 +1481,7 @@ pub struct BuilderReleaseStateLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BuilderPostDeployJudgeResultLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1425,6 +1489,7 @@ pub struct BuilderPostDeployJudgeResultLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelfHealFailureEventLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1432,6 +1497,7 @@ pub struct SelfHealFailureEventLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelfHealProblemCardLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1439,6 +1505,7 @@ pub struct SelfHealProblemCardLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelfHealFixCardLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1446,6 +1513,7 @@ pub struct SelfHealFixCardLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelfHealPromotionDecisionLedgerRow {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
@@ -1453,13 +1521,42 @@ pub struct SelfHealPromotionDecisionLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsentStateRecord {
     pub schema_version: SchemaVersion,
     pub row_id: u64,
     pub packet: ConsentStatePacket,
 }
 
+// JSON map keys must be strings, so tuple-keyed indexes go through a store snapshot as a list of
+// `[key, value]` pairs.
+#[cfg(feature = "serde")]
+mod snapshot_map_entries {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S, K, V>(map: &BTreeMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        K: Serialize,
+        V: Serialize,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub fn deserialize<'de, D, K, V>(deserializer: D) -> Result<BTreeMap<K, V>, D::Error>
+    where
+        D: Deserializer<'de>,
+        K: Deserialize<'de> + Ord,
+        V: Deserialize<'de>,
+    {
+        Vec::<(K, V)>::deserialize(deserializer).map(|entries| entries.into_iter().collect())
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1fStore {
     identities: BTreeMap<UserId, IdentityRecord>,
     devices: BTreeMap<DeviceId, DeviceRecord>,
@@ -1469,8 +1566,10 @@ pub struct Ph1fStore {
     session_lifecycle_idempotency_index: BTreeSet<(SessionId, String)>,
 
     memory_ledger: Vec<MemoryLedgerRow>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_current: BTreeMap<(UserId, MemoryKey), MemoryCurrentRecord>,
     // PH1.M vNext persistence slices.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_suppression_rules_current: BTreeMap<
         (
             UserId,
@@ -1481,6 +1580,7 @@ pub struct Ph1fStore {
         MemorySuppressionRuleRecord,
     >,
     // Idempotency: (user_id, target_type, target_id, rule_kind, idempotency_key) -> applied_changed.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_suppression_idempotency_index: BTreeMap<
         (
             UserId,
@@ -1492,42 +1592,59 @@ pub struct Ph1fStore {
         bool,
     >,
     emotional_threads_ledger: Vec<MemoryEmotionalThreadLedgerRow>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     emotional_threads_current: BTreeMap<(UserId, String), MemoryEmotionalThreadCurrentRecord>,
     // Idempotency: (user_id, thread_key, idempotency_key) -> emotional_thread_event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     emotional_threads_idempotency_index: BTreeMap<(UserId, String, String), u64>,
     memory_metrics_ledger: Vec<MemoryMetricLedgerRow>,
     // Idempotency: (user_id, idempotency_key) -> memory_metric_event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_metrics_idempotency_index: BTreeMap<(UserId, String), u64>,
     memory_threads_ledger: Vec<MemoryThreadLedgerRow>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_threads_current: BTreeMap<(UserId, String), MemoryThreadCurrentRecord>,
     // Idempotency: (user_id, idempotency_key) -> (memory_thread_event_id, stored)
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_threads_idempotency_index: BTreeMap<(UserId, String), (u64, bool)>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_thread_refs_current: BTreeMap<(UserId, String, u64), MemoryThreadRefRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_graph_nodes_current: BTreeMap<(UserId, String), MemoryGraphNodeRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_graph_edges_current: BTreeMap<(UserId, String), MemoryGraphEdgeRecord>,
     // Idempotency: (user_id, idempotency_key) -> graph_update_count
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_graph_idempotency_index: BTreeMap<(UserId, String), u16>,
     // Deterministic uniqueness: (user_id, from_node_id, to_node_id, edge_kind)
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_graph_edge_uniqueness: BTreeMap<(UserId, String, String, String), String>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_archive_index_current: BTreeMap<(UserId, String), MemoryArchiveIndexRecord>,
     memory_retention_preferences: BTreeMap<UserId, MemoryRetentionPreferenceRecord>,
     // Idempotency: (user_id, idempotency_key) -> updated_at
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_retention_idempotency_index: BTreeMap<(UserId, String), MonotonicTimeNs>,
 
     conversation_ledger: Vec<ConversationTurnRecord>,
     internal_history_evidence_ledger: Vec<InternalHistoryEvidenceRecord>,
     ph1x_thread_state_ledger: Vec<Ph1xThreadStateLedgerRow>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     ph1x_thread_state_current: BTreeMap<(UserId, String), Ph1xThreadStateCurrentRecord>,
     // Idempotency: (user_id, thread_key, idempotency_key) -> ph1x_thread_state_event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     ph1x_thread_state_idempotency_index: BTreeMap<(UserId, String, String), u64>,
     outcome_utilization_ledger: Vec<OutcomeUtilizationLedgerRow>,
     // Idempotency: (correlation_id, turn_id, engine_id, outcome_type, idempotency_key) -> row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     outcome_utilization_idempotency_index:
         BTreeMap<(CorrelationId, TurnId, String, String, String), u64>,
     // 38 Phase-1 Agent Execution Core append-only execution proof + current projection.
     agent_execution_ledger: Vec<AgentExecutionLedgerRow>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     agent_execution_current: BTreeMap<(String, UserId, String), AgentExecutionCurrentRecord>,
     // Idempotency: (tenant_id, user_id, thread_key, idempotency_key) -> row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     agent_execution_idempotency_index: BTreeMap<(String, UserId, String, String), u64>,
     // 14.7.6 self-healing append-only card chain persistence.
     self_heal_failure_event_ledger: Vec<SelfHealFailureEventLedgerRow>,
@@ -1540,9 +1657,13 @@ pub struct Ph1fStore {
     self_heal_fix_id_index: BTreeMap<String, u64>,
     self_heal_decision_id_index: BTreeMap<String, u64>,
     // Idempotency indices: (card_id, idempotency_key) -> row_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     self_heal_failure_idempotency_index: BTreeMap<(String, String), u64>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     self_heal_problem_idempotency_index: BTreeMap<(String, String), u64>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     self_heal_fix_idempotency_index: BTreeMap<(String, String), u64>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     self_heal_decision_idempotency_index: BTreeMap<(String, String), u64>,
     // Builder Selene pipeline append-only tables (proposal -> validation run -> gate results).
     builder_proposal_ledger: Vec<BuilderProposalLedgerRow>,
@@ -1552,26 +1673,33 @@ pub struct Ph1fStore {
     builder_release_state_ledger: Vec<BuilderReleaseStateLedgerRow>,
     builder_post_deploy_judge_result_ledger: Vec<BuilderPostDeployJudgeResultLedgerRow>,
     // Idempotency: (source_signal_hash, idempotency_key) -> proposal_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     builder_proposal_idempotency_index: BTreeMap<(String, String), u64>,
     // Uniqueness: proposal_id -> proposal_row_id
     builder_proposal_id_index: BTreeMap<String, u64>,
     // Idempotency: (proposal_id, idempotency_key) -> run_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     builder_validation_run_idempotency_index: BTreeMap<(String, String), u64>,
     // Uniqueness: run_id -> run_row_id
     builder_validation_run_id_index: BTreeMap<String, u64>,
     // Uniqueness: (run_id, gate_id) -> gate_result_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     builder_validation_gate_result_unique_index: BTreeMap<(String, String), u64>,
     // Idempotency: (run_id, gate_id, idempotency_key) -> gate_result_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     builder_validation_gate_result_idempotency_index: BTreeMap<(String, String, String), u64>,
     // Idempotency: (proposal_id, idempotency_key) -> approval_state_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     builder_approval_state_idempotency_index: BTreeMap<(String, String), u64>,
     // Uniqueness: approval_state_id -> approval_state_row_id
     builder_approval_state_id_index: BTreeMap<String, u64>,
     // Idempotency: (proposal_id, idempotency_key) -> release_state_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     builder_release_state_idempotency_index: BTreeMap<(String, String), u64>,
     // Uniqueness: release_state_id -> release_state_row_id
     builder_release_state_id_index: BTreeMap<String, u64>,
     // Idempotency: (proposal_id, idempotency_key) -> judge_result_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     builder_post_deploy_judge_result_idempotency_index: BTreeMap<(String, String), u64>,
     // Uniqueness: judge_result_id -> judge_result_row_id
     builder_post_deploy_judge_result_id_index: BTreeMap<String, u64>,
@@ -1581,15 +1709,19 @@ pub struct Ph1fStore {
     next_link_seq: u64,
     // Idempotency detection for link draft generation:
     // (inviter_user_id, payload_hash, expiration_policy_id) -> token_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     link_draft_idempotency_index: BTreeMap<(UserId, String, Option<String>), TokenId>,
     // Idempotency: (draft_id, idempotency_key) -> (draft_status, missing_required_fields)
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     link_draft_update_idempotency_index: BTreeMap<(DraftId, String), (DraftStatus, Vec<String>)>,
     // Idempotency: (token_id, idempotency_key) -> open/activate result tuple
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     link_open_activate_idempotency_index: BTreeMap<(TokenId, String), LinkOpenActivateResultParts>,
 
     // Additional PH1.LINK simulations (v1): recovery, forward-block attempts, role proposals,
     // dual-role conflict escalation.
     // Idempotency: (expired_link_id, idempotency_key) -> new_link_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     link_recovery_idempotency_index: BTreeMap<(TokenId, String), TokenId>,
     // Idempotency: record (token_id, presented_device_fingerprint_hash) once.
     link_forward_block_attempts: BTreeSet<(TokenId, String)>,
@@ -1597,11 +1729,13 @@ pub struct Ph1fStore {
     // Role proposal drafts (sandbox).
     link_role_proposals: BTreeMap<String, LinkRoleProposalRecord>,
     // Idempotency: (tenant_id, proposal_text_hash) -> role_proposal_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     link_role_proposal_idempotency_index: BTreeMap<(Option<String>, String), String>,
 
     // Dual-role conflict escalation drafts (sandbox).
     link_dual_role_conflict_cases: BTreeMap<String, LinkDualRoleConflictCaseRecord>,
     // Idempotency: (tenant_id, token_id, note_hash) -> escalation_case_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     link_dual_role_conflict_idempotency_index:
         BTreeMap<(Option<String>, Option<TokenId>, String), String>,
 
@@ -1612,81 +1746,111 @@ pub struct Ph1fStore {
     // Idempotency: ONB_SESSION_START_DRAFT is idempotent on token_id.
     onboarding_session_by_link: BTreeMap<TokenId, OnboardingSessionId>,
     // Idempotency: (session_id + idempotency_key) per commit simulation.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_terms_idempotency_index: BTreeMap<(OnboardingSessionId, String), TermsStatus>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_photo_idempotency_index: BTreeMap<(OnboardingSessionId, String), String>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_sender_verify_idempotency_index:
         BTreeMap<(OnboardingSessionId, String), VerificationStatus>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_primary_device_idempotency_index: BTreeMap<(OnboardingSessionId, String), bool>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_platform_setup_idempotency_index: BTreeMap<(OnboardingSessionId, String), Vec<String>>,
     // Idempotency: (user_id + role_id + idempotency_key) for access instance create.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_access_instance_idempotency_index: BTreeMap<(UserId, String, String), String>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_complete_idempotency_index: BTreeMap<(OnboardingSessionId, String), OnboardingStatus>,
+    // Completed onboarding -> provisioned access-engine instance, kept for provisioning audits.
+    onb_access_instance_links: BTreeMap<OnboardingSessionId, OnboardingAccessInstanceLinkRecord>,
     // Backfill campaign state and idempotency indexes.
     onb_requirement_backfill_campaigns:
         BTreeMap<BackfillCampaignId, OnbRequirementBackfillCampaignRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_requirement_backfill_targets:
         BTreeMap<(BackfillCampaignId, UserId), OnbRequirementBackfillTargetRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_requirement_backfill_start_idempotency_index:
         BTreeMap<(String, String, String, String), BackfillCampaignId>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_requirement_backfill_notify_idempotency_index:
         BTreeMap<(BackfillCampaignId, UserId, String), BackfillTargetStatus>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     onb_requirement_backfill_complete_idempotency_index:
         BTreeMap<(BackfillCampaignId, String), (u32, u32)>,
 
     // ------------------------
     // PH1.ACCESS.001 + PH2.ACCESS.002 (Access/Authority).
     // ------------------------
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_instances: BTreeMap<(String, UserId), AccessInstanceRecord>,
     access_instances_by_id: BTreeMap<String, (String, UserId)>,
     // Idempotency: (tenant_id, user_id, idempotency_key) -> access_instance_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_instance_idempotency_index: BTreeMap<(String, UserId, String), String>,
     // Append-only override lifecycle rows.
     access_overrides: Vec<AccessOverrideRecord>,
     // Idempotency: (tenant_id, access_instance_id, idempotency_key) -> override_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_override_idempotency_index: BTreeMap<(String, String, String), String>,
     access_ap_authoring_review_ledger: Vec<AccessApAuthoringReviewLedgerRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_ap_authoring_review_current:
         BTreeMap<(String, String, String), AccessApAuthoringReviewCurrentRecord>,
     // Idempotency: (scope_key, access_profile_id, schema_version_id, review_channel, idempotency_key) -> review_event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_ap_authoring_review_channel_idempotency_index:
         BTreeMap<(String, String, String, String, String), u64>,
     // Idempotency: (scope_key, access_profile_id, schema_version_id, confirmation_state, idempotency_key) -> review_event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_ap_authoring_confirm_idempotency_index:
         BTreeMap<(String, String, String, String, String), u64>,
     access_ap_rule_review_action_ledger: Vec<AccessApRuleReviewActionRecord>,
     // Idempotency: (scope_key, access_profile_id, schema_version_id, action, suggested_rule_ref_or_empty, idempotency_key) -> review_action_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_ap_rule_review_action_idempotency_index:
         BTreeMap<(String, String, String, String, String, String), u64>,
     access_ap_schema_ledger: Vec<AccessApSchemaLedgerRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_ap_schema_current: BTreeMap<(String, String), AccessApSchemaCurrentRecord>,
     // Idempotency: (scope_key, access_profile_id, schema_version_id, event_action, idempotency_key) -> event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_ap_schema_idempotency_index:
         BTreeMap<(String, String, String, AccessSchemaEventAction, String), u64>,
     access_overlay_ledger: Vec<AccessOverlayRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_overlay_current: BTreeMap<(String, String), AccessOverlayCurrentRecord>,
     // Idempotency: (tenant_id, overlay_id, overlay_version_id, event_action, idempotency_key) -> event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_overlay_idempotency_index:
         BTreeMap<(String, String, String, AccessSchemaEventAction, String), u64>,
     access_board_policy_ledger: Vec<AccessBoardPolicyRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_board_policy_current: BTreeMap<(String, String), AccessBoardPolicyCurrentRecord>,
     // Idempotency: (tenant_id, board_policy_id, policy_version_id, event_action, idempotency_key) -> event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_board_policy_idempotency_index:
         BTreeMap<(String, String, String, AccessSchemaEventAction, String), u64>,
     access_board_votes_ledger: Vec<AccessBoardVoteRecord>,
     // Idempotency: (tenant_id, escalation_case_id, voter_user_id, idempotency_key) -> vote_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     access_board_vote_idempotency_index: BTreeMap<(String, String, UserId, String), u64>,
 
     // ------------------------
     // PH1.K (Voice Runtime I/O).
     // ------------------------
     ph1k_runtime_events: Vec<Ph1kRuntimeEventRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     ph1k_runtime_current: BTreeMap<(String, DeviceId), Ph1kRuntimeCurrentRecord>,
     // Idempotency: (tenant_id, device_id, event_kind, idempotency_key) -> event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     ph1k_runtime_event_idempotency_index:
         BTreeMap<(String, DeviceId, Ph1kRuntimeEventKind, String), u64>,
     // PH1.K interruption-failure captures routed into PH1.FEEDBACK.
     ph1k_feedback_captures: Vec<Ph1kFeedbackCaptureRecord>,
     // Idempotency: (tenant_id, idempotency_key) -> capture_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     ph1k_feedback_capture_idempotency_index: BTreeMap<(String, String), u64>,
     // Governed PH1.PAE ladder state (`SHADOW -> ASSIST -> LEAD`) for PH1.K feedback routing.
     ph1k_feedback_pae_mode_by_tenant: BTreeMap<String, PaeMode>,
@@ -1713,6 +1877,7 @@ pub struct Ph1fStore {
     // PH1.FEEDBACK -> PH1.LEARN validated signal bundles (deduped + provenance-bound).
     ph1feedback_learn_signal_bundles: Vec<FeedbackLearnSignalBundleRecord>,
     // Idempotency: (tenant_id, idempotency_key) -> bundle_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     ph1feedback_learn_signal_bundle_idempotency_index: BTreeMap<(String, String), u64>,
     // Deterministic isolation guard: one tenant binding per user-scoped PH1.LEARN artifacts.
     ph1learn_user_tenant_bindings: BTreeMap<UserId, String>,
@@ -1720,29 +1885,38 @@ pub struct Ph1fStore {
     // ------------------------
     // PH1.ONB.BIZ + PH1.POSITION (tenant/company + position truth).
     // ------------------------
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     tenant_companies: BTreeMap<(TenantId, String), TenantCompanyRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     positions: BTreeMap<(TenantId, PositionId), PositionRecord>,
     position_lifecycle_events: Vec<PositionLifecycleEventRecord>,
     // Idempotency indexes for position simulations.
     // (tenant_id, company_id, position_title, department, jurisdiction, idempotency_key) -> position_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     position_create_idempotency_index:
         BTreeMap<(TenantId, String, String, String, String, String), PositionId>,
     // (tenant_id, position_id, idempotency_key) -> lifecycle_state
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     position_activate_idempotency_index:
         BTreeMap<(TenantId, PositionId, String), PositionLifecycleState>,
     // (tenant_id, position_id, requested_state, idempotency_key) -> lifecycle_state
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     position_retire_suspend_idempotency_index:
         BTreeMap<(TenantId, PositionId, PositionLifecycleState, String), PositionLifecycleState>,
     position_requirements_schema_ledger: Vec<PositionRequirementsSchemaLedgerRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     position_requirements_schema_current:
         BTreeMap<(TenantId, PositionId), PositionRequirementsSchemaCurrentRecord>,
     // (tenant_id, position_id, schema_version_id, idempotency_key) -> schema_event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     position_requirements_schema_create_idempotency_index:
         BTreeMap<(TenantId, PositionId, String, String), u64>,
     // (tenant_id, position_id, schema_version_id, idempotency_key) -> schema_event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     position_requirements_schema_update_idempotency_index:
         BTreeMap<(TenantId, PositionId, String, String), u64>,
     // (tenant_id, position_id, schema_version_id, idempotency_key) -> apply_scope
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     position_requirements_schema_activate_idempotency_index:
         BTreeMap<(TenantId, PositionId, String, String), PositionSchemaApplyScope>,
 
@@ -1753,37 +1927,48 @@ pub struct Ph1fStore {
     wake_enrollment_samples: Vec<WakeEnrollmentSampleRecord>,
     wake_runtime_events: Vec<WakeRuntimeEventRecord>,
     wake_learn_signals: Vec<WakeLearnSignalRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_profile_bindings: BTreeMap<(UserId, DeviceId), String>,
     wake_artifact_apply_ledger: Vec<WakeArtifactApplyRecord>,
     wake_artifact_apply_current: BTreeMap<DeviceId, WakeArtifactApplyCurrentRecord>,
     // Idempotency: (device_id, artifact_version, state, idempotency_key) -> apply_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_artifact_apply_idempotency_index:
         BTreeMap<(DeviceId, ArtifactVersion, WakeArtifactApplyState, String), u64>,
     // Blocklist projection: (device_id, artifact_version) -> rollback reason.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_artifact_blocked_versions: BTreeMap<(DeviceId, ArtifactVersion), ReasonCodeId>,
     // Wake candidate/promotion governance ledger and projections.
     wake_promotion_ledger: Vec<WakePromotionRecord>,
     wake_promotion_current: BTreeMap<ArtifactVersion, WakePromotionCurrentRecord>,
     wake_promotion_active_artifact_version: Option<ArtifactVersion>,
     // Idempotency: (artifact_version, state, idempotency_key) -> promotion_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_promotion_idempotency_index: BTreeMap<(ArtifactVersion, WakePromotionState, String), u64>,
     // Blocklist projection: artifact_version -> block reason.
     wake_promotion_blocked_versions: BTreeMap<ArtifactVersion, ReasonCodeId>,
     // Idempotency: (artifact_version, idempotency_key) -> decision_ref.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_promotion_revalidation_idempotency_index: BTreeMap<(ArtifactVersion, String), String>,
 
     // Idempotency indexes for wake simulations.
     // (user_id, device_id, idempotency_key) -> wake_enrollment_session_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_start_idempotency_index: BTreeMap<(UserId, DeviceId, String), String>,
     // (wake_enrollment_session_id, idempotency_key) -> sample_seq
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_sample_idempotency_index: BTreeMap<(String, String), u16>,
     // (wake_enrollment_session_id, idempotency_key) -> wake_profile_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_complete_idempotency_index: BTreeMap<(String, String), String>,
     // (wake_enrollment_session_id, idempotency_key) -> wake_enroll_status
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_defer_idempotency_index: BTreeMap<(String, String), WakeEnrollStatus>,
     // (device_id, idempotency_key) -> wake_event_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_runtime_event_idempotency_index: BTreeMap<(DeviceId, String), String>,
     // (device_id, signal_id) -> wake_learn_signal_row_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     wake_learn_signal_index: BTreeMap<(DeviceId, String), u64>,
     // receipt_ref -> wake_learn_signal_row_id
     wake_learn_signal_receipt_index: BTreeMap<String, u64>,
@@ -1794,30 +1979,38 @@ pub struct Ph1fStore {
     voice_enrollment_sessions: BTreeMap<String, VoiceEnrollmentSessionRecord>,
     voice_enrollment_samples: Vec<VoiceEnrollmentSampleRecord>,
     voice_profiles: BTreeMap<String, VoiceProfileRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     voice_profile_bindings: BTreeMap<(OnboardingSessionId, DeviceId), String>,
     person_profiles: BTreeMap<String, PersonProfileRecord>,
     person_profile_by_voice_profile_ref: BTreeMap<String, String>,
     person_profile_by_actor_user_ref: BTreeMap<String, String>,
     local_voice_caches: BTreeMap<String, LocalVoiceCacheRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     local_voice_cache_by_device_voice: BTreeMap<(String, String), String>,
     device_sync_postures: BTreeMap<String, DeviceSyncPostureRecord>,
     device_sync_posture_by_device_ref: BTreeMap<String, String>,
     device_revocation_ledger: Vec<DeviceRevocationPostureRecord>,
     voice_artifact_revocation_ledger: Vec<VoiceArtifactRevocationRecord>,
     // Unique revocation binding: (tenant_id, artifact_type, artifact_version) -> revocation_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     voice_artifact_revoked_version_index: BTreeMap<(String, ArtifactType, ArtifactVersion), u64>,
     // Idempotency: (tenant_id, artifact_type, artifact_version, idempotency_key) -> revocation_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     voice_artifact_revocation_idempotency_index:
         BTreeMap<(String, ArtifactType, ArtifactVersion, String), u64>,
 
     // Idempotency indexes for voice-id enrollment simulations.
     // (onboarding_session_id, device_id) -> voice_enrollment_session_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     voice_start_idempotency_index: BTreeMap<(OnboardingSessionId, DeviceId), String>,
     // (voice_enrollment_session_id, attempt_index, idempotency_key) -> sample_seq
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     voice_sample_idempotency_index: BTreeMap<(String, u16, String), u16>,
     // (voice_enrollment_session_id, idempotency_key) -> voice_profile_id
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     voice_complete_idempotency_index: BTreeMap<(String, String), String>,
     // (voice_enrollment_session_id, idempotency_key) -> voice_enroll_status
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     voice_defer_idempotency_index: BTreeMap<(String, String), VoiceEnrollStatus>,
 
     // ------------------------
@@ -1832,8 +2025,10 @@ pub struct Ph1fStore {
     // PBS tables (blueprint_registry + process_blueprints).
     // ------------------------
     process_blueprint_events: Vec<ProcessBlueprintEvent>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     blueprint_registry: BTreeMap<(TenantId, IntentType), BlueprintRegistryRecord>,
     // Idempotency dedupe: (tenant_id, process_id, blueprint_version, idempotency_key) -> event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     process_blueprint_idempotency_index:
         BTreeMap<(TenantId, ProcessId, BlueprintVersion, String), u64>,
 
@@ -1841,8 +2036,10 @@ pub struct Ph1fStore {
     // Simulation Catalog tables (`simulation_catalog` ledger + current projection).
     // ------------------------
     simulation_catalog_events: Vec<SimulationCatalogEvent>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     simulation_catalog_current: BTreeMap<(TenantId, SimulationId), SimulationCatalogCurrentRecord>,
     // Idempotency dedupe: (tenant_id, simulation_id, simulation_version, idempotency_key) -> event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     simulation_catalog_idempotency_index:
         BTreeMap<(TenantId, SimulationId, SimulationVersion, String), u64>,
 
@@ -1850,9 +2047,11 @@ pub struct Ph1fStore {
     // Engine Capability Maps tables (`engine_capability_maps` ledger + current projection).
     // ------------------------
     engine_capability_map_events: Vec<EngineCapabilityMapEvent>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     engine_capability_maps_current:
         BTreeMap<(TenantId, EngineId, CapabilityId), EngineCapabilityMapCurrentRecord>,
     // Idempotency dedupe: (tenant_id, engine_id, capability_id, capability_map_version, idempotency_key) -> event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     engine_capability_map_idempotency_index: BTreeMap<
         (
             TenantId,
@@ -1869,9 +2068,11 @@ pub struct Ph1fStore {
     // ------------------------
     artifacts_ledger_rows: Vec<ArtifactLedgerRow>,
     // Unique scope binding: (scope_type, scope_id, artifact_type, artifact_version) -> artifact_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     artifacts_scope_version_index:
         BTreeMap<(ArtifactScopeType, String, ArtifactType, ArtifactVersion), u64>,
     // Idempotency dedupe: (scope_type, scope_id, artifact_type, artifact_version, idempotency_key) -> artifact_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     artifacts_idempotency_index: BTreeMap<
         (
             ArtifactScopeType,
@@ -1884,20 +2085,25 @@ pub struct Ph1fStore {
     >,
     artifact_trust_root_registry_rows: Vec<ArtifactTrustRootRegistryRow>,
     // Unique trust-root binding: (trust_root_id, trust_root_version) -> trust_root_registry_row_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     artifact_trust_root_version_index: BTreeMap<(String, ArtifactTrustRootVersion), u64>,
     // Idempotency dedupe: (trust_root_id, trust_root_version, idempotency_key) -> trust_root_registry_row_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     artifact_trust_root_idempotency_index:
         BTreeMap<(String, ArtifactTrustRootVersion, String), u64>,
     tool_cache_rows: BTreeMap<u64, ToolCacheRow>,
     // Upsert index: (tool_name, query_hash, locale) -> cache_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     tool_cache_lookup_index: BTreeMap<(String, String, String), u64>,
 
     // ------------------------
     // Selene OS core WorkOrder persistence tables.
     // ------------------------
     work_order_ledger: Vec<WorkOrderLedgerEvent>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     work_orders_current: BTreeMap<(TenantId, WorkOrderId), WorkOrderCurrentRecord>,
     // Idempotency dedupe for ledger writes: (tenant_id, work_order_id, idempotency_key).
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     work_order_ledger_idempotency_index: BTreeMap<(TenantId, WorkOrderId, String), u64>,
 
     // ------------------------
@@ -1905,12 +2111,15 @@ pub struct Ph1fStore {
     // append-only ledgers + current projections + idempotency indexes.
     // ------------------------
     bcast_recipient_lifecycle_ledger: Vec<BcastRecipientLifecycleLedgerRow>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     bcast_broadcasts_current: BTreeMap<(TenantId, BroadcastId), BcastBroadcastCurrentRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     bcast_recipients_current: BTreeMap<
         (TenantId, BroadcastId, BroadcastRecipientId),
         BcastRecipientLifecycleCurrentRecord,
     >,
     // Idempotency: (tenant_id, action, broadcast_id_or_empty, recipient_or_actor, idempotency_key) -> event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     bcast_recipient_lifecycle_idempotency_index:
         BTreeMap<(TenantId, String, String, String, String), u64>,
     // Primary-key uniqueness: bcast_event_id -> event_idx.
@@ -1919,18 +2128,21 @@ pub struct Ph1fStore {
     delivery_attempts_ledger: Vec<DeliveryAttemptLedgerRow>,
     delivery_attempts_current: BTreeMap<String, DeliveryAttemptCurrentRecord>,
     // Idempotency: (tenant_id, message_id, recipient, idempotency_key) -> delivery_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     delivery_send_idempotency_index: BTreeMap<(TenantId, String, String, String), u64>,
     // Primary-key uniqueness: delivery_event_id -> event_idx.
     delivery_attempt_event_lookup: BTreeMap<u64, usize>,
     bcast_policy_ledger: Vec<BcastPolicyLedgerRow>,
     bcast_policy_current: BTreeMap<TenantId, BcastPolicyCurrentRecord>,
     // Idempotency: (tenant_id, idempotency_key) -> bcast_policy_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     bcast_policy_idempotency_index: BTreeMap<(TenantId, String), u64>,
     // Primary-key uniqueness: bcast_policy_event_id -> event_idx.
     bcast_policy_event_lookup: BTreeMap<u64, usize>,
     bcast_wait_policy_ledger: Vec<BcastWaitPolicyLedgerRow>,
     bcast_wait_policy_current: BTreeMap<TenantId, BcastWaitPolicyCurrentRecord>,
     // Idempotency: (tenant_id, idempotency_key) -> bcast_wait_policy_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     bcast_wait_policy_idempotency_index: BTreeMap<(TenantId, String), u64>,
     // Primary-key uniqueness: bcast_wait_policy_event_id -> event_idx.
     bcast_wait_policy_event_lookup: BTreeMap<u64, usize>,
@@ -1938,6 +2150,7 @@ pub struct Ph1fStore {
     bcast_urgent_followup_policy_current:
         BTreeMap<TenantId, BcastUrgentFollowupPolicyCurrentRecord>,
     // Idempotency: (tenant_id, idempotency_key) -> bcast_urgent_followup_policy_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     bcast_urgent_followup_policy_idempotency_index: BTreeMap<(TenantId, String), u64>,
     // Primary-key uniqueness: bcast_urgent_followup_policy_event_id -> event_idx.
     bcast_urgent_followup_policy_event_lookup: BTreeMap<u64, usize>,
@@ -1950,11 +2163,14 @@ pub struct Ph1fStore {
     reminder_occurrences: BTreeMap<ReminderOccurrenceId, ReminderOccurrenceRecord>,
     reminder_delivery_attempts: Vec<ReminderDeliveryAttemptRecord>,
     // Idempotency: (tenant_id, user_id, resolved_due_at, reminder_type, idempotency_key) -> reminder_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     reminder_schedule_idempotency_index:
         BTreeMap<(TenantId, UserId, MonotonicTimeNs, ReminderType, String), ReminderId>,
     // Idempotency: (tenant_id, reminder_id, idempotency_key, action) -> reminder_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     reminder_update_idempotency_index: BTreeMap<(TenantId, ReminderId, String, String), ReminderId>,
     // Idempotency: (tenant_id, reminder_id, occurrence_id, delivery_attempt_id, action) -> attempt_idx.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     reminder_delivery_idempotency_index: BTreeMap<
         (
             TenantId,
@@ -1979,17 +2195,21 @@ pub struct Ph1fStore {
     // PH1.CAPREQ tables (`capreq_ledger` ledger + `capreq_current` projection).
     // ------------------------
     capreq_ledger_events: Vec<CapabilityRequestLedgerEvent>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     capreq_current: BTreeMap<(TenantId, CapreqId), CapabilityRequestCurrentRecord>,
     // Idempotency dedupe: (tenant_id, capreq_id, idempotency_key) -> event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     capreq_idempotency_index: BTreeMap<(TenantId, CapreqId, String), u64>,
 
     audit_events: Vec<AuditEvent>,
     proof_ledger: Vec<CanonicalProofRecord>,
     proof_record_lookup: BTreeMap<ProofEventId, usize>,
     proof_request_index: BTreeMap<String, Vec<ProofEventId>>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     proof_session_turn_index: BTreeMap<(SessionId, TurnId), Vec<ProofEventId>>,
     proof_idempotency_index: BTreeMap<String, ProofEventId>,
     consent_state_ledger: Vec<ConsentStateRecord>,
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     consent_state_current: BTreeMap<(String, ConsentScope), usize>,
     consent_state_id_index: BTreeMap<String, usize>,
     consent_state_idempotency_index: BTreeMap<String, usize>,
@@ -2047,20 +2267,25 @@ pub struct Ph1fStore {
     next_voice_artifact_revocation_event_id: u64,
 
     // Idempotency detection for memory ledger writes: (user_id, key) -> ledger_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     memory_idempotency_index: BTreeMap<(UserId, String), u64>,
 
     // Idempotency detection for conversation writes: (correlation_id, key) -> conversation_turn_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     conversation_idempotency_index: BTreeMap<(CorrelationId, String), ConversationTurnId>,
 
     // Idempotency detection for immutable internal-history evidence:
     // (correlation_id, key) -> internal_history_event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     internal_history_idempotency_index: BTreeMap<(CorrelationId, String), InternalHistoryEventId>,
 
     // Idempotency detection for audit emissions (canonical scope):
     // (tenant_id, work_order_id, idempotency_key) -> event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     audit_idempotency_index_scoped: BTreeMap<(String, String, String), AuditEventId>,
     // Backward-compatible fallback for events that do not carry tenant/work-order scope:
     // (correlation_id, idempotency_key) -> event_id.
+    #[cfg_attr(feature = "serde", serde(with = "snapshot_map_entries"))]
     audit_idempotency_index_legacy: BTreeMap<(CorrelationId, String), AuditEventId>,
 
     // Prevent "silent deletes": track which memory keys have been forgotten (tombstones).
@@ -2068,6 +2293,7 @@ pub struct Ph1fStore {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinkRoleProposalRecord {
     pub schema_version: SchemaVersion,
     pub role_proposal_id: String,
@@ -2077,6 +2303,7 @@ pub struct LinkRoleProposalRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinkDualRoleConflictCaseRecord {
     pub schema_version: SchemaVersion,
     pub escalation_case_id: String,
@@ -2099,7 +2326,18 @@ type LinkOpenActivateResultParts = (
     Option<PrefilledContextRef>,
 );
 
+/// Links a completed onboarding session to the access-engine instance it provisioned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OnboardingAccessInstanceLinkRecord {
+    pub onboarding_session_id: OnboardingSessionId,
+    pub access_engine_instance_id: String,
+    pub tenant_id: Option<String>,
+    pub completed_at: MonotonicTimeNs,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OnboardingSessionRecord {
     pub schema_version: SchemaVersion,
     pub onboarding_session_id: OnboardingSessionId,
@@ -2166,6 +2404,7 @@ pub struct OnbPlatformSetupReceiptOutcome {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessMode {
     R,
     W,
@@ -2174,6 +2413,7 @@ pub enum AccessMode {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessVerificationLevel {
     None,
     PasscodeTime,
@@ -2182,6 +2422,7 @@ pub enum AccessVerificationLevel {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessDeviceTrustLevel {
     Dtl1,
     Dtl2,
@@ -2190,6 +2431,7 @@ pub enum AccessDeviceTrustLevel {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessLifecycleState {
     Restricted,
     Active,
@@ -2197,6 +2439,7 @@ pub enum AccessLifecycleState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessInstanceRecord {
     pub schema_version: SchemaVersion,
     pub access_instance_id: String,
@@ -2222,6 +2465,7 @@ pub struct AccessInstanceRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessOverrideType {
     OneShot,
     Temporary,
@@ -2230,6 +2474,7 @@ pub enum AccessOverrideType {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessOverrideStatus {
     Active,
     Expired,
@@ -2237,6 +2482,7 @@ pub enum AccessOverrideStatus {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessOverrideRecord {
     pub schema_version: SchemaVersion,
     pub override_id: String,
@@ -2279,12 +2525,14 @@ pub struct AccessGateDecisionRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessSchemaScope {
     Global,
     Tenant,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessSchemaEventAction {
     CreateDraft,
     UpdateDraft,
@@ -2293,6 +2541,7 @@ pub enum AccessSchemaEventAction {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessSchemaLifecycleState {
     Draft,
     Active,
@@ -2300,12 +2549,14 @@ pub enum AccessSchemaLifecycleState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessApAuthoringReviewEventKind {
     ReviewChannelCommit,
     ConfirmationCommit,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessApSchemaLedgerRecord {
     pub schema_version: SchemaVersion,
     pub event_id: u64,
@@ -2326,6 +2577,7 @@ pub struct AccessApSchemaLedgerRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessApSchemaCurrentRecord {
     pub schema_version: SchemaVersion,
     pub scope_key: String,
@@ -2337,6 +2589,7 @@ pub struct AccessApSchemaCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessApAuthoringReviewLedgerRecord {
     pub schema_version: SchemaVersion,
     pub review_event_id: u64,
@@ -2355,6 +2608,7 @@ pub struct AccessApAuthoringReviewLedgerRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessApAuthoringReviewCurrentRecord {
     pub schema_version: SchemaVersion,
     pub scope_key: String,
@@ -2368,6 +2622,7 @@ pub struct AccessApAuthoringReviewCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessApRuleReviewActionRecord {
     pub schema_version: SchemaVersion,
     pub review_action_row_id: u64,
@@ -2388,6 +2643,7 @@ pub struct AccessApRuleReviewActionRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessOverlayRecord {
     pub schema_version: SchemaVersion,
     pub event_id: u64,
@@ -2404,6 +2660,7 @@ pub struct AccessOverlayRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessOverlayCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: String,
@@ -2415,6 +2672,7 @@ pub struct AccessOverlayCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessBoardPolicyRecord {
     pub schema_version: SchemaVersion,
     pub event_id: u64,
@@ -2431,6 +2689,7 @@ pub struct AccessBoardPolicyRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessBoardPolicyCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: String,
@@ -2442,12 +2701,14 @@ pub struct AccessBoardPolicyCurrentRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AccessBoardVoteValue {
     Approve,
     Reject,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AccessBoardVoteRecord {
     pub schema_version: SchemaVersion,
     pub vote_row_id: u64,
@@ -2484,6 +2745,7 @@ pub struct Ph1cTranscriptRejectCommitResult {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ph1kRuntimeEventKind {
     StreamRefs,
     VadEvent,
@@ -2495,6 +2757,7 @@ pub enum Ph1kRuntimeEventKind {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ph1kDeviceHealth {
     Healthy,
     Degraded,
@@ -2502,6 +2765,7 @@ pub enum Ph1kDeviceHealth {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1kInterruptCandidateExtendedFields {
     pub trigger_phrase_id: u32,
     pub trigger_locale: String,
@@ -2526,6 +2790,7 @@ pub struct Ph1kInterruptCandidateExtendedFields {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1kRuntimeEventRecord {
     pub schema_version: SchemaVersion,
     pub event_id: u64,
@@ -2558,6 +2823,7 @@ pub struct Ph1kRuntimeEventRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1kRuntimeCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: String,
@@ -2603,6 +2869,7 @@ pub struct Ph1kRuntimeCurrentRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Ph1kFeedbackIssueKind {
     FalseInterrupt,
     MissedInterrupt,
@@ -2626,6 +2893,7 @@ pub struct Ph1kFeedbackCaptureInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Ph1kFeedbackCaptureRecord {
     pub schema_version: SchemaVersion,
     pub capture_id: u64,
@@ -2690,6 +2958,7 @@ const PH1K_STEP14_ROLLING_WINDOW_NS: u64 = 3_600_000_000_000;
 const PH1K_PAE_PROMOTION_ENGINE_ID: &str = "PH1.PAE";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TenantCompanyLifecycleState {
     Draft,
     Active,
@@ -2698,6 +2967,7 @@ pub enum TenantCompanyLifecycleState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TenantCompanyRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
@@ -2710,6 +2980,7 @@ pub struct TenantCompanyRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionLifecycleEventRecord {
     pub schema_version: SchemaVersion,
     pub event_id: u64,
@@ -2726,6 +2997,7 @@ pub struct PositionLifecycleEventRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PositionRequirementsSchemaLedgerAction {
     CreateDraft,
     UpdateCommit,
@@ -2734,6 +3006,7 @@ pub enum PositionRequirementsSchemaLedgerAction {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionRequirementsSchemaLedgerRecord {
     pub schema_version: SchemaVersion,
     pub schema_event_id: u64,
@@ -2753,6 +3026,7 @@ pub struct PositionRequirementsSchemaLedgerRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PositionRequirementsSchemaCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
@@ -2767,6 +3041,7 @@ pub struct PositionRequirementsSchemaCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OnbRequirementBackfillCampaignRecord {
     pub schema_version: SchemaVersion,
     pub campaign_id: BackfillCampaignId,
@@ -2784,6 +3059,7 @@ pub struct OnbRequirementBackfillCampaignRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OnbRequirementBackfillTargetRecord {
     pub schema_version: SchemaVersion,
     pub target_row_id: u64,
@@ -2803,6 +3079,7 @@ const W_ENROLL_REASON_MAX_ATTEMPTS: ReasonCodeId = ReasonCodeId(0x5700_0201);
 const W_ENROLL_REASON_TIMEOUT: ReasonCodeId = ReasonCodeId(0x5700_0202);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WakeEnrollStatus {
     InProgress,
     Pending,
@@ -2811,12 +3088,14 @@ pub enum WakeEnrollStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WakeSampleResult {
     Pass,
     Fail,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WakeEnrollmentSessionRecord {
     pub schema_version: SchemaVersion,
     pub wake_enrollment_session_id: String,
@@ -2839,6 +3118,7 @@ pub struct WakeEnrollmentSessionRecord {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WakeEnrollmentSampleRecord {
     pub schema_version: SchemaVersion,
     pub wake_enrollment_session_id: String,
@@ -2858,6 +3138,7 @@ pub struct WakeEnrollmentSampleRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WakeRuntimeEventRecord {
     pub schema_version: SchemaVersion,
     pub wake_event_id: String,
@@ -2881,6 +3162,7 @@ pub struct WakeRuntimeEventRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WakeLearnSignalRecord {
     pub schema_version: SchemaVersion,
     pub wake_learn_signal_row_id: u64,
@@ -2923,6 +3205,7 @@ const VID_LOCK_MAX_HOLDOUT_FAR_BP: u16 = 120;
 const VID_LOCK_MIN_CONFUSION_MARGIN_BP: u16 = 450;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VoiceEnrollStatus {
     InProgress,
     Locked,
@@ -2931,6 +3214,7 @@ pub enum VoiceEnrollStatus {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum VoiceSampleResult {
     Pass,
     Fail,
@@ -2963,6 +3247,7 @@ struct VoiceEnrollmentLockMetrics {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VoiceEnrollmentSessionRecord {
     pub schema_version: SchemaVersion,
     pub voice_enrollment_session_id: String,
@@ -2985,6 +3270,7 @@ pub struct VoiceEnrollmentSessionRecord {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VoiceEnrollmentSampleRecord {
     pub schema_version: SchemaVersion,
     pub sample_seq: u16,
@@ -3004,6 +3290,7 @@ pub struct VoiceEnrollmentSampleRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VoiceProfileRecord {
     pub schema_version: SchemaVersion,
     pub voice_profile_id: String,
@@ -3014,6 +3301,7 @@ pub struct VoiceProfileRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PersonProfileStatus {
     Active,
     PendingOnboarding,
@@ -3040,6 +3328,7 @@ impl PersonProfileStatus {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PersonProfileRecord {
     pub schema_version: SchemaVersion,
     pub person_profile_id: String,
@@ -3076,6 +3365,7 @@ pub struct PersonProfileUpsertInput {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LocalVoiceCacheStatus {
     Active,
     Stale,
@@ -3101,6 +3391,7 @@ impl LocalVoiceCacheStatus {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LocalVoiceCacheRecord {
     pub schema_version: SchemaVersion,
     pub local_voice_cache_id: String,
@@ -3149,6 +3440,7 @@ pub enum LocalVoiceRecognitionDisposition {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeviceSyncStatus {
     Current,
     CheckDue,
@@ -3197,6 +3489,7 @@ pub enum DeviceSyncDecision {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeviceSyncPostureRecord {
     pub schema_version: SchemaVersion,
     pub device_sync_posture_id: String,
@@ -3233,6 +3526,7 @@ pub struct DeviceSyncPostureUpsertInput {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DeviceRevocationKind {
     LostDevice,
     DeviceRevoked,
@@ -3267,6 +3561,7 @@ impl DeviceRevocationKind {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeviceRevocationPostureRecord {
     pub schema_version: SchemaVersion,
     pub revocation_event_id: u64,
@@ -3294,6 +3589,7 @@ pub struct DeviceRevocationPostureInput {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VoiceArtifactRevocationRecord {
     pub schema_version: SchemaVersion,
     pub revocation_event_id: u64,
@@ -3307,6 +3603,7 @@ pub struct VoiceArtifactRevocationRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FeedbackLearnSignalBundleRecord {
     pub schema_version: SchemaVersion,
     pub bundle_id: u64,
@@ -3327,6 +3624,7 @@ pub struct FeedbackLearnSignalBundleRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MobileArtifactSyncKind {
     WakeProfile,
     VoiceProfile,
@@ -3337,6 +3635,7 @@ pub enum MobileArtifactSyncKind {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MobileArtifactSyncState {
     Queued,
     InFlight,
@@ -3345,6 +3644,7 @@ pub enum MobileArtifactSyncState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MobileArtifactSyncQueueRecord {
     pub schema_version: SchemaVersion,
     pub sync_job_id: String,
@@ -3366,6 +3666,7 @@ pub struct MobileArtifactSyncQueueRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WakeArtifactApplyState {
     Staged,
     Active,
@@ -3373,6 +3674,7 @@ pub enum WakeArtifactApplyState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WakeArtifactApplyRecord {
     pub schema_version: SchemaVersion,
     pub apply_event_id: u64,
@@ -3389,6 +3691,7 @@ pub struct WakeArtifactApplyRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WakeArtifactApplyCurrentRecord {
     pub schema_version: SchemaVersion,
     pub device_id: DeviceId,
@@ -3400,6 +3703,7 @@ pub struct WakeArtifactApplyCurrentRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WakePromotionState {
     Candidate,
     Shadow,
@@ -3410,6 +3714,7 @@ pub enum WakePromotionState {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WakePromotionRecord {
     pub schema_version: SchemaVersion,
     pub promotion_event_id: u64,
@@ -3425,6 +3730,7 @@ pub struct WakePromotionRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WakePromotionCurrentRecord {
     pub schema_version: SchemaVersion,
     pub artifact_version: ArtifactVersion,
@@ -3438,6 +3744,7 @@ pub struct WakePromotionCurrentRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastRecipientLifecycleAction {
     DraftCreate,
     DeliverCommit,
@@ -3465,6 +3772,7 @@ impl BcastRecipientLifecycleAction {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastRecipientLifecycleLedgerRow {
     pub schema_version: SchemaVersion,
     pub bcast_event_id: u64,
@@ -3484,6 +3792,7 @@ pub struct BcastRecipientLifecycleLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastBroadcastCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
@@ -3496,6 +3805,7 @@ pub struct BcastBroadcastCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastRecipientLifecycleCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
@@ -3509,6 +3819,7 @@ pub struct BcastRecipientLifecycleCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliveryAttemptLedgerRow {
     pub schema_version: SchemaVersion,
     pub delivery_event_id: u64,
@@ -3528,6 +3839,7 @@ pub struct DeliveryAttemptLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeliveryAttemptCurrentRecord {
     pub schema_version: SchemaVersion,
     pub delivery_attempt_id: String,
@@ -3544,6 +3856,7 @@ pub struct DeliveryAttemptCurrentRecord {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BcastPolicySettingKey {
     NonUrgentWaitSeconds,
     UrgentFollowupMode,
@@ -3590,6 +3903,7 @@ impl BcastPolicyUpdateValue {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastPolicyLedgerRow {
     pub schema_version: SchemaVersion,
     pub bcast_policy_event_id: u64,
@@ -3610,6 +3924,7 @@ pub struct BcastPolicyLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastPolicyCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
@@ -3627,6 +3942,7 @@ pub struct BcastPolicyCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastWaitPolicyLedgerRow {
     pub schema_version: SchemaVersion,
     pub bcast_wait_policy_event_id: u64,
@@ -3641,6 +3957,7 @@ pub struct BcastWaitPolicyLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastWaitPolicyCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
@@ -3655,6 +3972,7 @@ pub struct BcastWaitPolicyCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastUrgentFollowupPolicyLedgerRow {
     pub schema_version: SchemaVersion,
     pub bcast_urgent_followup_policy_event_id: u64,
@@ -3669,6 +3987,7 @@ pub struct BcastUrgentFollowupPolicyLedgerRow {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BcastUrgentFollowupPolicyCurrentRecord {
     pub schema_version: SchemaVersion,
     pub tenant_id: TenantId,
@@ -3683,6 +4002,7 @@ pub struct BcastUrgentFollowupPolicyCurrentRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReminderRecord {
     pub schema_version: SchemaVersion,
     pub reminder_id: ReminderId,
@@ -3705,6 +4025,7 @@ pub struct ReminderRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReminderOccurrenceRecord {
     pub schema_version: SchemaVersion,
     pub occurrence_id: ReminderOccurrenceId,
@@ -3720,6 +4041,7 @@ pub struct ReminderOccurrenceRecord {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReminderDeliveryAttemptRecord {
     pub schema_version: SchemaVersion,
     pub attempt_id: ReminderDeliveryAttemptId,
@@ -3818,6 +4140,7 @@ impl Ph1fStore {
             onb_platform_setup_idempotency_index: BTreeMap::new(),
             onb_access_instance_idempotency_index: BTreeMap::new(),
             onb_complete_idempotency_index: BTreeMap::new(),
+            onb_access_instance_links: BTreeMap::new(),
             onb_requirement_backfill_campaigns: BTreeMap::new(),
             onb_requirement_backfill_targets: BTreeMap::new(),
             onb_requirement_backfill_start_idempotency_index: BTreeMap::new(),
@@ -4917,6 +5240,33 @@ impl Ph1fStore {
         })
     }
 
+    /// Drops the user's `thread_ids` from the current thread and thread ref tables and replaces
+    /// the title and summary of their ledger digests with the `[REDACTED]` placeholder, for
+    /// threads built from an erased transcript. Returns the number of ledger rows redacted.
+    pub fn redact_memory_threads(
+        &mut self,
+        user_id: &UserId,
+        thread_ids: &BTreeSet<String>,
+    ) -> usize {
+        self.memory_threads_current
+            .retain(|(u, thread_id), _| !(u == user_id && thread_ids.contains(thread_id)));
+        self.memory_thread_refs_current
+            .retain(|(u, thread_id, _), _| !(u == user_id && thread_ids.contains(thread_id)));
+        let mut redacted = 0;
+        for row in self
+            .memory_threads_ledger
+            .iter_mut()
+            .filter(|row| row.user_id == *user_id && thread_ids.contains(&row.digest.thread_id))
+        {
+            if row.digest.thread_title != "[REDACTED]" {
+                row.digest.thread_title = "[REDACTED]".to_string();
+                row.digest.summary_bullets = vec!["[REDACTED]".to_string()];
+                redacted += 1;
+            }
+        }
+        redacted
+    }
+
     pub fn ph1m_graph_upsert_commit(
         &mut self,
         user_id: &UserId,
@@ -5237,6 +5587,23 @@ impl Ph1fStore {
                 },
             ));
         }
+        if input.telemetry_tags.len() > 8
+            || input.telemetry_tags.iter().any(|(key, value)| {
+                key.trim().is_empty()
+                    || key.len() > 64
+                    || !is_token_safe_ascii(key)
+                    || value.trim().is_empty()
+                    || value.len() > 64
+                    || !is_token_safe_ascii(value)
+            })
+        {
+            return Err(StorageError::ContractViolation(
+                ContractViolation::InvalidValue {
+                    field: "outcome_utilization_ledger.telemetry_tags",
+                    reason: "must be <= 8 token-safe ASCII key/value pairs of <= 64 chars",
+                },
+            ));
+        }
 
         if let Some(k) = &input.idempotency_key {
             if k.trim().is_empty() || k.len() > 128 || !is_token_safe_ascii(k) {
@@ -5276,6 +5643,7 @@ impl Ph1fStore {
             decision_delta: input.decision_delta,
             reason_code: input.reason_code,
             idempotency_key: input.idempotency_key.clone(),
+            telemetry_tags: input.telemetry_tags.clone(),
         };
         self.outcome_utilization_ledger.push(row);
 
@@ -6587,6 +6955,26 @@ impl Ph1fStore {
         })
     }
 
+    /// Replaces the text of every conversation row a `Tombstone` row points at with the
+    /// `[REDACTED]` placeholder, so an erased turn no longer holds its raw text. `text_hash` is
+    /// kept because internal history evidence refers to it. Returns the rows that changed.
+    pub fn redact_tombstoned_conversation_turns(&mut self) -> Vec<ConversationTurnRecord> {
+        let tombstoned = self
+            .conversation_ledger
+            .iter()
+            .filter(|row| row.source == ConversationSource::Tombstone)
+            .filter_map(|row| row.tombstone_of_conversation_turn_id)
+            .collect::<BTreeSet<_>>();
+        let mut redacted = Vec::new();
+        for row in &mut self.conversation_ledger {
+            if tombstoned.contains(&row.conversation_turn_id) && row.text != "[REDACTED]" {
+                row.text = "[REDACTED]".to_string();
+                redacted.push(row.clone());
+            }
+        }
+        redacted
+    }
+
     pub fn attempt_overwrite_memory_ledger_row(
         &mut self,
         _ledger_id: u64,
@@ -13455,6 +13843,17 @@ impl Ph1fStore {
 
         self.onb_complete_idempotency_index
             .insert(idx, OnboardingStatus::Complete);
+        if let Some(access_engine_instance_id) = access_engine_instance_id {
+            self.onb_access_instance_links.insert(
+                onboarding_session_id.clone(),
+                OnboardingAccessInstanceLinkRecord {
+                    onboarding_session_id: onboarding_session_id.clone(),
+                    access_engine_instance_id,
+                    tenant_id,
+                    completed_at: now,
+                },
+            );
+        }
 
         Ok(OnbCompleteResult::v1(
             onboarding_session_id,
@@ -13819,6 +14218,13 @@ impl Ph1fStore {
         &self.onboarding_sessions
     }
 
+    pub fn ph1onb_access_instance_link(
+        &self,
+        onboarding_session_id: &OnboardingSessionId,
+    ) -> Option<&OnboardingAccessInstanceLinkRecord> {
+        self.onb_access_instance_links.get(onboarding_session_id)
+    }
+
     pub fn ph1onb_ask_missing_field_turn(
         &mut self,
         now: MonotonicTimeNs,
@@ -14683,6 +15089,46 @@ impl Ph1fStore {
         Ok(())
     }
 
+    /// Puts a dead-lettered row back in the queue with a fresh attempt budget, for operators
+    /// replaying jobs once the cause of the failures is fixed. Only DEAD_LETTER rows qualify.
+    pub fn mobile_artifact_sync_requeue_dead_letter_commit(
+        &mut self,
+        sync_job_id: &str,
+    ) -> Result<(), StorageError> {
+        if sync_job_id.trim().is_empty() || sync_job_id.len() > 128 || !sync_job_id.is_ascii() {
+            return Err(StorageError::ContractViolation(
+                ContractViolation::InvalidValue {
+                    field: "mobile_artifact_sync_requeue_dead_letter_commit.sync_job_id",
+                    reason: "must be non-empty ASCII and <= 128 chars",
+                },
+            ));
+        }
+
+        let row = self
+            .mobile_artifact_sync_queue
+            .iter_mut()
+            .find(|row| row.sync_job_id == sync_job_id)
+            .ok_or(StorageError::ForeignKeyViolation {
+                table: "mobile_artifact_sync_queue.sync_job_id",
+                key: sync_job_id.to_string(),
+            })?;
+        if row.state != MobileArtifactSyncState::DeadLetter {
+            return Err(StorageError::ContractViolation(
+                ContractViolation::InvalidValue {
+                    field: "mobile_artifact_sync_requeue_dead_letter_commit.state",
+                    reason: "row must be DEAD_LETTER before requeue",
+                },
+            ));
+        }
+
+        row.state = MobileArtifactSyncState::Queued;
+        row.attempt_count = 0;
+        row.lease_expires_at = None;
+        row.last_error = None;
+        row.worker_id = None;
+        Ok(())
+    }
+
     // Device-generic alias methods.
     // These intentionally route to the historical mobile queue implementation so callers
     // can use one API for phone + desktop artifact sync without schema churn.
@@ -14747,6 +15193,13 @@ impl Ph1fStore {
         self.mobile_artifact_sync_dead_letter_commit(now, sync_job_id, worker_id, last_error)
     }
 
+    pub fn device_artifact_sync_requeue_dead_letter_commit(
+        &mut self,
+        sync_job_id: &str,
+    ) -> Result<(), StorageError> {
+        self.mobile_artifact_sync_requeue_dead_letter_commit(sync_job_id)
+    }
+
     pub fn device_artifact_sync_known_device_ids(&self) -> Vec<DeviceId> {
         self.devices.keys().cloned().collect()
     }
@@ -22431,6 +22884,10 @@ impl Ph1fStore {
                     LearnSignalType::UserCorrection
                 )
                 | (FeedbackEventType::ClarifyLoop, LearnSignalType::ClarifyLoop)
+                | (
+                    FeedbackEventType::MemoryOverride,
+                    LearnSignalType::UserCorrection
+                )
                 | (FeedbackEventType::ToolFail, LearnSignalType::ToolFail)
                 | (
                     FeedbackEventType::DeliverySwitch,
@@ -26230,6 +26687,7 @@ mod tests {
             decision_delta: true,
             reason_code: ReasonCodeId(101),
             idempotency_key: idempotency_key.map(str::to_string),
+            telemetry_tags: BTreeMap::new(),
         }
     }
 
@@ -27303,6 +27761,13 @@ mod tests {
             .audit_events_by_correlation(corr)
             .iter()
             .any(|e| e.event_type == AuditEventType::JRedactApplied));
+
+        let redacted = s.redact_tombstoned_conversation_turns();
+        assert_eq!(redacted.len(), 1);
+        assert_eq!(redacted[0].conversation_turn_id, original);
+        assert_eq!(s.conversation_ledger()[0].text, "[REDACTED]");
+        assert_eq!(s.conversation_ledger()[0].text_hash, "hash_secret");
+        assert!(s.redact_tombstoned_conversation_turns().is_empty());
     }
 
     #[test]
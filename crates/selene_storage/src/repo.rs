@@ -533,6 +533,11 @@ pub trait Ph1MobileArtifactSyncRepo {
         last_error: String,
     ) -> Result<(), StorageError>;
 
+    fn mobile_artifact_sync_requeue_dead_letter_commit_row(
+        &mut self,
+        sync_job_id: &str,
+    ) -> Result<(), StorageError>;
+
     fn mobile_artifact_sync_convergence_state_for_receipt(
         &self,
         receipt_ref: &str,
@@ -2805,6 +2810,13 @@ impl Ph1MobileArtifactSyncRepo for Ph1fStore {
     ) -> Result<(), StorageError> {
         self.mobile_artifact_sync_dead_letter_commit(now, sync_job_id, worker_id, last_error)
     }
+
+    fn mobile_artifact_sync_requeue_dead_letter_commit_row(
+        &mut self,
+        sync_job_id: &str,
+    ) -> Result<(), StorageError> {
+        self.mobile_artifact_sync_requeue_dead_letter_commit(sync_job_id)
+    }
 }
 
 impl Ph1AccessPh2AccessRepo for Ph1fStore {
@@ -526,6 +526,17 @@ fn at_m_db_08_thread_archive_fk_and_append_only() {
         s.ph1m_attempt_overwrite_thread_ledger_row(event_id),
         Err(StorageError::AppendOnlyViolation { .. })
     ));
+
+    let thread_ids = std::collections::BTreeSet::from(["thread:japan_trip".to_string()]);
+    assert_eq!(s.redact_memory_threads(&user_id, &thread_ids), 1);
+    assert!(s.ph1m_thread_current_row(&user_id, "thread:japan_trip").is_none());
+    assert!(s
+        .ph1m_thread_ref_rows_for_thread(&user_id, "thread:japan_trip")
+        .is_empty());
+    let digest = &s.ph1m_thread_ledger_rows()[0].digest;
+    assert_eq!(digest.thread_title, "[REDACTED]");
+    assert_eq!(digest.summary_bullets, vec!["[REDACTED]".to_string()]);
+    assert_eq!(s.redact_memory_threads(&user_id, &thread_ids), 0);
 }
 
 #[test]
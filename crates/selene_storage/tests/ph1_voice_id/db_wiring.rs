@@ -942,6 +942,101 @@ fn at_vid_db_09b_mobile_sync_dead_letter_commit_moves_row_out_of_replay() {
     assert!(none_left.is_empty());
 }
 
+#[test]
+fn at_vid_db_09b2_mobile_sync_requeue_dead_letter_resets_attempts() {
+    let mut s = Ph1fStore::new_in_memory();
+    let u = user("tenant_a:user_sync_requeue");
+    let d = device("tenant_a_device_sync_requeue");
+    seed_identity_device(&mut s, u.clone(), d.clone());
+    let onb = seed_onboarding_session(&mut s, u, "fp_sync_requeue", 1_120);
+
+    let started = s
+        .ph1vid_enroll_start_draft_row(MonotonicTimeNs(1_130), onb, d, true, 8, 120_000, 2)
+        .unwrap();
+    s.ph1vid_enroll_sample_commit_row(
+        MonotonicTimeNs(1_131),
+        started.voice_enrollment_session_id.clone(),
+        "sample_requeue_1".to_string(),
+        1,
+        1_340,
+        0.92,
+        17.4,
+        0.4,
+        0.0,
+        "vid-requeue-sample-1".to_string(),
+    )
+    .unwrap();
+    s.ph1vid_enroll_sample_commit_row(
+        MonotonicTimeNs(1_132),
+        started.voice_enrollment_session_id.clone(),
+        "sample_requeue_2".to_string(),
+        2,
+        1_350,
+        0.93,
+        17.8,
+        0.3,
+        0.0,
+        "vid-requeue-sample-2".to_string(),
+    )
+    .unwrap();
+    let completed = s
+        .ph1vid_enroll_complete_commit_row(
+            MonotonicTimeNs(1_133),
+            started.voice_enrollment_session_id,
+            "vid-requeue-complete".to_string(),
+        )
+        .unwrap();
+    let receipt = completed
+        .voice_artifact_sync_receipt_ref
+        .expect("voice receipt must exist");
+    let sync_job_id = s
+        .mobile_artifact_sync_queue_row_for_receipt(&receipt)
+        .expect("sync queue row must exist")
+        .sync_job_id
+        .clone();
+
+    assert!(s
+        .mobile_artifact_sync_requeue_dead_letter_commit(&sync_job_id)
+        .is_err());
+    s.mobile_artifact_sync_dequeue_batch(
+        MonotonicTimeNs(1_134),
+        1,
+        30_000,
+        "worker_vid_requeue".to_string(),
+    )
+    .unwrap();
+    s.mobile_artifact_sync_dead_letter_commit(
+        MonotonicTimeNs(1_135),
+        &sync_job_id,
+        Some("worker_vid_requeue"),
+        "provider outage".to_string(),
+    )
+    .unwrap();
+
+    s.mobile_artifact_sync_requeue_dead_letter_commit(&sync_job_id)
+        .unwrap();
+    let row = s
+        .mobile_artifact_sync_queue_row_for_receipt(&receipt)
+        .expect("requeued row should still exist");
+    assert_eq!(row.state, MobileArtifactSyncState::Queued);
+    assert_eq!(row.attempt_count, 0);
+    assert_eq!(row.last_error, None);
+    assert_eq!(row.worker_id, None);
+    assert!(s.mobile_artifact_sync_dead_letter_rows().is_empty());
+
+    let redelivered = s
+        .mobile_artifact_sync_dequeue_batch(
+            MonotonicTimeNs(1_136),
+            1,
+            1_000,
+            "worker_vid_after_requeue".to_string(),
+        )
+        .unwrap();
+    assert_eq!(redelivered.len(), 1);
+    assert_eq!(redelivered[0].sync_job_id, sync_job_id);
+    assert_eq!(redelivered[0].attempt_count, 1);
+}
+
 #[test]
 fn at_vid_db_09c_mobile_sync_retry_replay_ack_converges_to_cloud_truth() {
     let mut s = Ph1fStore::new_in_memory();